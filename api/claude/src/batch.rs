@@ -225,6 +225,100 @@ mod private
     pub message : String,
   }
 
+  /// Outcome of one entry in the results JSONL file
+  #[ derive( Deserialize ) ]
+  #[ serde( tag = "type", rename_all = "snake_case" ) ]
+  enum BatchResultOutcome
+  {
+    Succeeded { message : crate::CreateMessageResponse },
+    Errored { error : crate::ApiErrorWrap },
+    Canceled,
+    Expired,
+  }
+
+  /// Decode one line of a batch results JSONL file into its `custom_id` and response
+  ///
+  /// Entries that did not succeed are returned as `AnthropicError::Api`, with the
+  /// error type from the API ( or `canceled` / `expired` ) and the `custom_id`
+  /// in the message.
+  ///
+  /// # Errors
+  ///
+  /// Returns `AnthropicError::Api` for errored, canceled and expired entries, and
+  /// `AnthropicError::Parsing` if the line is not a valid result entry
+  pub fn decode_batch_result_line( line : &str ) -> crate::AnthropicResult< ( String, crate::CreateMessageResponse ) >
+  {
+    let mut entry : serde_json::Value = serde_json::from_str( line )
+      .map_err( | e | crate::AnthropicError::Parsing( format!( "Failed to parse batch result line : {e}" ) ) )?;
+
+    let custom_id = entry.get( "custom_id" )
+      .and_then( serde_json::Value::as_str )
+      .ok_or_else( || crate::AnthropicError::Parsing( "Batch result line has no custom_id".to_string() ) )?
+      .to_string();
+
+    let outcome : BatchResultOutcome = serde_json::from_value( entry[ "result" ].take() )
+      .map_err( | e | crate::AnthropicError::Parsing( format!( "Failed to parse batch result for custom_id '{custom_id}' : {e}" ) ) )?;
+
+    let ( error_type, message ) = match outcome
+    {
+      BatchResultOutcome::Succeeded { message } => return Ok( ( custom_id, message ) ),
+      BatchResultOutcome::Errored { error } => ( error.error.r#type, format!( "custom_id '{custom_id}' : {}", error.error.message ) ),
+      BatchResultOutcome::Canceled => ( "canceled".to_string(), format!( "custom_id '{custom_id}' was canceled before processing" ) ),
+      BatchResultOutcome::Expired => ( "expired".to_string(), format!( "custom_id '{custom_id}' expired before processing" ) ),
+    };
+
+    Err( crate::AnthropicError::Api( crate::AnthropicApiError { r#type : error_type, message } ) )
+  }
+
+  /// Decode a batch results JSONL body, arriving in arbitrary chunks, line by line
+  ///
+  /// Lines may be split across chunks, and the last line may lack a trailing
+  /// newline. Each line is decoded with [`decode_batch_result_line`] and blank
+  /// lines are skipped, so entries that did not succeed and undecodable lines are
+  /// yielded as errors without ending the stream. An error from `chunks` is
+  /// yielded last.
+  pub fn decode_batch_result_chunks< S, B >( chunks : S )
+  -> impl futures_core::Stream< Item = crate::AnthropicResult< ( String, crate::CreateMessageResponse ) > >
+  where
+    S : futures_core::Stream< Item = crate::AnthropicResult< B > >,
+    B : AsRef< [ u8 ] >,
+  {
+    // State : the chunks still being read ( `None` once finished ) and bytes not yet split into lines
+    futures_util::stream::unfold( ( Some( Box::pin( chunks ) ), Vec::< u8 >::new() ), | ( mut chunks, mut buffer ) | async move
+    {
+      loop
+      {
+        let line_end = buffer.iter().position( | byte | *byte == b'\n' );
+        let line : Vec< u8 > = match ( line_end, &mut chunks )
+        {
+          ( Some( end ), _ ) => buffer.drain( ..=end ).collect(),
+          ( None, Some( body ) ) =>
+          {
+            match futures_util::StreamExt::next( body ).await
+            {
+              Some( Ok( chunk ) ) => buffer.extend_from_slice( chunk.as_ref() ),
+              Some( Err( error ) ) => return Some( ( Err( error ), ( None, Vec::new() ) ) ),
+              None => chunks = None,
+            }
+            continue;
+          },
+          ( None, None ) if buffer.is_empty() => return None,
+          ( None, None ) => core::mem::take( &mut buffer ),
+        };
+
+        let Ok( line ) = core::str::from_utf8( &line ) else
+        {
+          return Some( ( Err( crate::AnthropicError::Parsing( "Batch result line is not valid UTF-8".to_string() ) ), ( chunks, buffer ) ) );
+        };
+        if line.trim().is_empty()
+        {
+          continue;
+        }
+        return Some( ( decode_batch_result_line( line.trim() ), ( chunks, buffer ) ) );
+      }
+    } )
+  }
+
   /// Batch list response
   #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq, Eq ) ]
   pub struct BatchListResponse
@@ -253,6 +347,8 @@ crate::mod_interface!
     BatchResult,
     BatchResultError,
     BatchListResponse,
+    decode_batch_result_line,
+    decode_batch_result_chunks,
   };
 }
 
@@ -37,6 +37,9 @@ mod private
     #[ allow( dead_code ) ] // Will be used when authentication is fully integrated
     auth_rate_limiting_enabled : bool,
     // Automatic retry configuration removed per governing principle - use explicit retry methods
+    /// Retry policy consulted only by `execute_with_optional_retries`, never by API methods
+    #[ cfg( feature = "retry-logic" ) ]
+    retry_config : Option< crate::RetryConfig >,
     // Automatic circuit breaker removed per governing principle - use explicit health monitoring methods
     // Automatic rate limiting removed per governing principle - use explicit rate limit information access
   }
@@ -107,7 +110,8 @@ mod private
         performance_monitor : None,
         #[ cfg( feature = "authentication" ) ]
         auth_rate_limiting_enabled : false,
-        // retry_config field removed per governing principle
+        #[ cfg( feature = "retry-logic" ) ]
+        retry_config : None,
         // circuit_breaker field removed per governing principle
         // rate_limiter field removed per governing principle
       }
@@ -177,6 +181,53 @@ mod private
     // Automatic retry configuration methods removed per governing principle
     // Use explicit retry methods on individual requests instead
 
+    /// Set the retry policy for `execute_with_optional_retries`
+    ///
+    /// API methods such as `create_message` never consult it.
+    #[ cfg( feature = "retry-logic" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_retry_config( mut self, retry_config : crate::RetryConfig ) -> Self
+    {
+      self.retry_config = Some( retry_config );
+      self
+    }
+
+    /// Get the retry policy for `execute_with_optional_retries`
+    #[ cfg( feature = "retry-logic" ) ]
+    #[ inline ]
+    #[ must_use ]
+    pub fn retry_config( &self ) -> Option< &crate::RetryConfig >
+    {
+      self.retry_config.as_ref()
+    }
+
+    /// Run an operation, retrying only when retry support is compiled in and configured
+    ///
+    /// Single call site regardless of enabled features : with the `retry-logic` feature
+    /// and a policy set via `with_retry_config`, errors the policy deems retryable are
+    /// retried with exponential backoff; otherwise the operation runs exactly once and
+    /// no retry machinery is involved.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last attempt
+    #[ inline ]
+    pub async fn execute_with_optional_retries< F, Fut, T >( &self, operation : F ) -> AnthropicResult< T >
+    where
+      F : Fn() -> Fut,
+      Fut : core::future::Future< Output = AnthropicResult< T > >,
+    {
+      #[ cfg( feature = "retry-logic" ) ]
+      if let Some( retry_config ) = &self.retry_config
+      {
+        let strategy = crate::RetryStrategy::exponential_backoff_with_config( retry_config.clone() );
+        return crate::RetryExecutor::new( strategy ).execute( operation ).await;
+      }
+
+      operation().await
+    }
+
     // Automatic circuit breaker configuration methods removed per governing principle
     // Use explicit health monitoring methods instead
 
@@ -244,6 +295,8 @@ mod private
 
       // Automatic circuit breaker checks removed per governing principle - use explicit health monitoring methods
 
+      request.validate_with_output_limits( &self.config.output_limits )?;
+
       let url = format!( "{}/v1/messages", self.config.base_url );
 
       let headers = build_headers( &self.secret, &self.config );
@@ -458,6 +511,53 @@ mod private
       handle_response::< crate::BatchResponse >( response ).await
     }
 
+    /// Stream the results of an ended batch as `( custom_id, response )` pairs
+    ///
+    /// Retrieves the batch to find its `results_url`, then downloads the results
+    /// JSONL file from it incrementally and decodes it with
+    /// [`crate::decode_batch_result_chunks`]. Entries that errored, were canceled or
+    /// expired, and lines that cannot be decoded, are yielded as errors without
+    /// ending the stream; a failure reading the body is yielded last. The stream
+    /// is not `Unpin`; pin it ( e.g. with `Box::pin` ) before polling.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch ID is empty, the batch has no `results_url`
+    /// yet ( it has not ended ), a request fails, or the API returns an error
+    #[ cfg( all( feature = "batch-processing", feature = "error-handling" ) ) ]
+    #[ inline ]
+    pub async fn results_stream( &self, batch_id : &str )
+    -> AnthropicResult< impl futures_core::Stream< Item = AnthropicResult< ( String, CreateMessageResponse ) > > >
+    {
+      let batch = self.retrieve_batch( batch_id ).await?;
+      let Some( url ) = batch.results_url.as_deref() else
+      {
+        return Err( AnthropicError::InvalidArgument( format!( "batch '{batch_id}' has no results_url; results are available once it has ended" ) ) );
+      };
+      let headers = build_headers( &self.secret, &self.config );
+
+      let response = self.http
+        .get( url )
+        .headers( headers )
+        .send()
+        .await
+        .map_err( AnthropicError::from )?;
+
+      let response = ensure_success( response ).await?;
+
+      let chunks = futures_util::stream::unfold( Some( response ), | response | async move
+      {
+        let mut response = response?;
+        match response.chunk().await
+        {
+          Ok( Some( chunk ) ) => Some( ( Ok( chunk ), Some( response ) ) ),
+          Ok( None ) => None,
+          Err( error ) => Some( ( Err( AnthropicError::from( error ) ), None ) ),
+        }
+      } );
+      Ok( crate::decode_batch_result_chunks( chunks ) )
+    }
+
     /// Create a message with context for error tracking
     ///
     /// # Errors
@@ -33,6 +33,21 @@ mod private
   pub const MIN_MAX_TOKENS : u32 = 1;
   /// Maximum allowed `max_tokens` value
   pub const MAX_MAX_TOKENS : u32 = 200_000;
+  /// Known output token ceilings per model family, matched as described on `ModelOutputLimits`
+  pub const KNOWN_MODEL_OUTPUT_LIMITS : &[ ( &str, u32 ) ] = &[
+    ( "claude-sonnet-4-5", 64_000 ),
+    ( "claude-haiku-4-5", 64_000 ),
+    ( "claude-opus-4-5", 64_000 ),
+    ( "claude-opus-4-1", 32_000 ),
+    ( "claude-opus-4", 32_000 ),
+    ( "claude-sonnet-4", 64_000 ),
+    ( "claude-3-7-sonnet", 64_000 ),
+    ( "claude-3-5-sonnet", 8_192 ),
+    ( "claude-3-5-haiku", 8_192 ),
+    ( "claude-3-opus", 4_096 ),
+    ( "claude-3-sonnet", 4_096 ),
+    ( "claude-3-haiku", 4_096 ),
+  ];
   /// Minimum allowed temperature value
   pub const MIN_TEMPERATURE : f32 = 0.0;
   /// Maximum allowed temperature value
@@ -50,6 +65,8 @@ mod private
     pub request_timeout : Duration,
     /// User agent string
     pub user_agent : String,
+    /// Per-model output ceilings checked before a message request is sent
+    pub output_limits : ModelOutputLimits,
   }
 
   // No Default implementation - explicit configuration required
@@ -83,6 +100,7 @@ mod private
         api_version : ANTHROPIC_API_VERSION.to_string(),
         request_timeout : Duration::from_secs( 60 ), // Recommended for most use cases
         user_agent : ANTHROPIC_USER_AGENT.to_string(),
+        output_limits : ModelOutputLimits::known(),
       }
     }
 
@@ -100,6 +118,7 @@ mod private
         api_version,
         request_timeout,
         user_agent,
+        output_limits : ModelOutputLimits::known(),
       }
     }
 
@@ -118,6 +137,14 @@ mod private
       self.request_timeout = timeout;
       self
     }
+
+    /// Set the per-model output ceilings checked before sending
+    #[ must_use ]
+    pub fn with_output_limits( mut self, output_limits : ModelOutputLimits ) -> Self
+    {
+      self.output_limits = output_limits;
+      self
+    }
   }
 
   /// Builder for client configuration requiring explicit values
@@ -128,6 +155,7 @@ mod private
     api_version : Option< String >,
     request_timeout : Option< Duration >,
     user_agent : Option< String >,
+    output_limits : ModelOutputLimits,
   }
 
   impl Default for ClientConfigBuilder 
@@ -191,6 +219,70 @@ mod private
     pub cache_control : Option< CacheControl >,
   }
 
+  /// Per-model output token ceilings used to reject oversized `max_tokens` before sending
+  ///
+  /// An entry matches a model id equal to it, or the same id followed by a snapshot
+  /// suffix (`-20241022`, `@20241022` or `-latest`), so `claude-3-5-haiku-20241022`
+  /// resolves to the `claude-3-5-haiku` entry. When several entries match, the longest
+  /// wins, so an exact override takes precedence. A newer model that only shares a
+  /// family prefix (e.g. `claude-opus-4-5` against `claude-opus-4`) does not match, and
+  /// models without a matching entry are not checked.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct ModelOutputLimits
+  {
+    limits : Vec< ( String, u32 ) >,
+  }
+
+  impl ModelOutputLimits
+  {
+    /// Create a table with no entries (no per-model checks)
+    #[ must_use ]
+    pub fn empty() -> Self
+    {
+      Self { limits : Vec::new() }
+    }
+
+    /// Create a table from `KNOWN_MODEL_OUTPUT_LIMITS`
+    #[ must_use ]
+    pub fn known() -> Self
+    {
+      Self
+      {
+        limits : KNOWN_MODEL_OUTPUT_LIMITS
+        .iter()
+        .map( | ( prefix, limit ) | ( ( *prefix ).to_string(), *limit ) )
+        .collect(),
+      }
+    }
+
+    /// Set the output ceiling for a model id prefix, replacing any existing entry
+    #[ must_use ]
+    pub fn with_limit< S : Into< String > >( mut self, model_prefix : S, max_output_tokens : u32 ) -> Self
+    {
+      let model_prefix = model_prefix.into();
+      self.limits.retain( | ( prefix, _ ) | *prefix != model_prefix );
+      self.limits.push( ( model_prefix, max_output_tokens ) );
+      self
+    }
+
+    /// Output ceiling for `model`, if any entry matches
+    pub fn limit_for( &self, model : &str ) -> Option< u32 >
+    {
+      self.limits
+      .iter()
+      .filter( | ( prefix, _ ) | model.strip_prefix( prefix.as_str() ).is_some_and( is_snapshot_suffix ) )
+      .max_by_key( | ( prefix, _ ) | prefix.len() )
+      .map( | ( _, limit ) | *limit )
+    }
+  }
+
+  /// Whether `suffix` is empty or names a snapshot of a model (`-20241022`, `@20241022`, `-latest`)
+  fn is_snapshot_suffix( suffix : &str ) -> bool
+  {
+    let Some( snapshot ) = suffix.strip_prefix( [ '-', '@' ] ) else { return suffix.is_empty() };
+    snapshot == "latest" || ( snapshot.len() == 8 && snapshot.bytes().all( | byte | byte.is_ascii_digit() ) )
+  }
+
   /// Request to create a message
   #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
   pub struct CreateMessageRequest
@@ -254,6 +346,23 @@ mod private
     ///
     /// Panics if `tool_choice` is specific but `tools` is None (internal consistency error)
     pub fn validate( &self ) -> AnthropicResult< () >
+    {
+      self.validate_with_output_limits( &ModelOutputLimits::known() )
+    }
+
+    /// Validate the request parameters against an explicit per-model output ceiling table
+    ///
+    /// Same checks as `validate`, with `max_tokens` additionally checked against the
+    /// ceiling `limits` holds for the request model.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails for any parameter
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tool_choice` is specific but `tools` is None (internal consistency error)
+    pub fn validate_with_output_limits( &self, limits : &ModelOutputLimits ) -> AnthropicResult< () >
     {
       if self.model.trim().is_empty()
       {
@@ -267,6 +376,16 @@ mod private
         ) );
       }
 
+      if let Some( limit ) = limits.limit_for( &self.model )
+      {
+        if self.max_tokens > limit
+        {
+          return Err( AnthropicError::InvalidRequest(
+            format!( "max_tokens {} exceeds the output limit of {limit} for model '{}'", self.max_tokens, self.model )
+          ) );
+        }
+      }
+
       if self.messages.is_empty()
       {
         return Err( AnthropicError::InvalidRequest( "At least one message is required".to_string() ) );
@@ -401,13 +520,44 @@ mod private
 
   impl CreateMessageResponse
   {
-    /// Get the first text content from the response
-    pub fn text( &self ) -> Option< &str >
+    /// Get all text content from the response, concatenated in block order
+    ///
+    /// Non-text blocks (e.g. `tool_use`) are skipped; an empty string is returned
+    /// when the response carries no text.
+    pub fn text( &self ) -> String
     {
       self.content
         .iter()
-        .find( | content | content.r#type == "text" )
-        .and_then( | content | content.text.as_deref() )
+        .filter( | content | content.r#type == "text" )
+        .filter_map( | content | content.text.as_deref() )
+        .collect()
+    }
+
+    /// Borrow every `tool_use` block of the response, in block order
+    ///
+    /// Nothing is cloned. A block missing its `id` or `name` yields an empty string
+    /// and one missing its `input` yields `null`, so every `tool_use` block is kept.
+    #[ cfg( feature = "tools" ) ]
+    pub fn tool_uses( &self ) -> Vec< ToolUseRef< '_ > >
+    {
+      static NULL : serde_json::Value = serde_json::Value::Null;
+
+      self.content
+        .iter()
+        .filter( | content | content.r#type == "tool_use" )
+        .map( | content | ToolUseRef
+        {
+          id : content.id.as_deref().unwrap_or_default(),
+          name : content.name.as_deref().unwrap_or_default(),
+          input : content.input.as_ref().unwrap_or( &NULL ),
+        } )
+        .collect()
+    }
+
+    /// Get the reason the model stopped generating, if reported
+    pub fn stop_reason( &self ) -> Option< &str >
+    {
+      self.stop_reason.as_deref()
     }
 
     /// Check if the response was truncated due to `max_tokens`
@@ -417,6 +567,19 @@ mod private
     }
   }
 
+  /// Borrowed view of a `tool_use` block, returned by [`CreateMessageResponse::tool_uses`]
+  #[ cfg( feature = "tools" ) ]
+  #[ derive( Debug, Clone, Copy, PartialEq ) ]
+  pub struct ToolUseRef< 'a >
+  {
+    /// Tool use ID, referenced by the matching `tool_result`
+    pub id : &'a str,
+    /// Name of the tool to call
+    pub name : &'a str,
+    /// Tool input
+    pub input : &'a serde_json::Value,
+  }
+
   /// Content in response
   #[ derive( Debug, Clone, Serialize, Deserialize, PartialEq ) ]
   pub struct ResponseContent
@@ -426,6 +589,15 @@ mod private
     /// Text content (only present for text content)
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub text : Option< String >,
+    /// Tool use ID (only present for `tool_use` content)
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub id : Option< String >,
+    /// Tool name (only present for `tool_use` content)
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub name : Option< String >,
+    /// Tool input parameters (only present for `tool_use` content)
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub input : Option< serde_json::Value >,
   }
 
   /// Usage statistics
@@ -848,6 +1020,26 @@ mod private
   /// - **Direct Error Exposure**: Anthropic's error responses are preserved and accessible
   /// - **Zero Error Intelligence**: No automatic error categorization or modification
   pub async fn handle_response< T : for< 'de > Deserialize< 'de > >( response : reqwest::Response ) -> AnthropicResult< T >
+  {
+    let response = ensure_success( response ).await?;
+
+    let response_text = response.text().await.map_err( AnthropicError::from )?;
+
+    let parsed_response : T = serde_json::from_str( &response_text )
+      .map_err( | e | AnthropicError::Parsing( format!( "Failed to parse response : {e}" ) ) )?;
+
+    Ok( parsed_response )
+  }
+
+  /// Return the response unchanged if its status is successful, otherwise its error
+  ///
+  /// Used by [`handle_response`] and by callers that read the body incrementally.
+  ///
+  /// # Errors
+  ///
+  /// Returns `AnthropicError::RateLimit` for 429 responses, `AnthropicError::Api` for
+  /// other error responses with an API error body, and an HTTP error otherwise.
+  pub async fn ensure_success( response : reqwest::Response ) -> AnthropicResult< reqwest::Response >
   {
     let status = response.status();
 
@@ -904,12 +1096,7 @@ mod private
       ) );
     }
 
-    let response_text = response.text().await.map_err( AnthropicError::from )?;
-
-    let parsed_response : T = serde_json::from_str( &response_text )
-      .map_err( | e | AnthropicError::Parsing( format!( "Failed to parse response : {e}" ) ) )?;
-
-    Ok( parsed_response )
+    Ok( response )
   }
 }
 
@@ -922,9 +1109,13 @@ crate::mod_interface!
   exposed use SystemContent;
   exposed use SystemInstructions;
   exposed use CreateMessageRequest;
+  exposed use ModelOutputLimits;
+  exposed use KNOWN_MODEL_OUTPUT_LIMITS;
   exposed use CreateMessageRequestBuilder;
   exposed use CreateMessageResponse;
   exposed use ResponseContent;
+  #[ cfg( feature = "tools" ) ]
+  exposed use ToolUseRef;
   exposed use Usage;
   #[ cfg( feature = "count-tokens" ) ]
   exposed use CountMessageTokensRequest;
@@ -936,4 +1127,5 @@ crate::mod_interface!
 
   orphan use build_headers;
   orphan use handle_response;
+  orphan use ensure_success;
 }
@@ -14,6 +14,7 @@ impl ClientConfigBuilder
       api_version : None,
       request_timeout : None,
       user_agent : None,
+      output_limits : ModelOutputLimits::known(),
     }
   }
 
@@ -26,6 +27,7 @@ impl ClientConfigBuilder
       api_version : Some( ANTHROPIC_API_VERSION.to_string() ),
       request_timeout : Some( Duration::from_secs( 60 ) ),
       user_agent : Some( ANTHROPIC_USER_AGENT.to_string() ),
+      output_limits : ModelOutputLimits::known(),
     }
   }
 
@@ -61,6 +63,14 @@ impl ClientConfigBuilder
     self
   }
 
+  /// Set the per-model output ceilings checked before sending (defaults to the known table)
+  #[ must_use ]
+  pub fn output_limits( mut self, output_limits : ModelOutputLimits ) -> Self
+  {
+    self.output_limits = output_limits;
+    self
+  }
+
   /// Build the configuration (requires all values to be explicitly set)
   ///
   /// # Errors
@@ -86,6 +96,7 @@ impl ClientConfigBuilder
       api_version,
       request_timeout,
       user_agent,
+      output_limits : self.output_limits,
     })
   }
 }
@@ -342,7 +342,7 @@ mod private
       let generation_time = start_time.elapsed();
 
       // Extract content from response
-      let content = response.text().unwrap_or( "" ).to_string();
+      let content = response.text();
 
       Ok( ContentGenerationResponse
       {
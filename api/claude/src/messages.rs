@@ -6,4 +6,5 @@ crate::mod_interface!
 {
   layer content;
   layer tools_and_messages;
+  layer universal;
 }
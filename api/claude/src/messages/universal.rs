@@ -0,0 +1,163 @@
+//! Provider-neutral chat messages
+//!
+//! `UniversalMessage` carries a role and plain text so a conversation can be
+//! moved between providers. The same type, with the same role mapping, exists in
+//! the Gemini, `OpenAI` and Ollama crates.
+
+mod private
+{
+  use super::super::content::orphan::*;
+  use super::super::tools_and_messages::orphan::*;
+
+  /// Provider-neutral message role
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+  pub enum UniversalRole
+  {
+    /// System instructions
+    System,
+    /// User turn
+    User,
+    /// Assistant ( model ) turn
+    Assistant,
+  }
+
+  impl UniversalRole
+  {
+    /// Canonical role name ( `system`, `user` or `assistant` )
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        Self::System => "system",
+        Self::User => "user",
+        Self::Assistant => "assistant",
+      }
+    }
+
+    /// Map a provider role name to a neutral role
+    ///
+    /// `assistant` and `model` map to `Assistant`, `system` and `developer` to
+    /// `System`; any other name is treated as `User`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_provider_role( role : &str ) -> Self
+    {
+      match role
+      {
+        "assistant" | "model" => Self::Assistant,
+        "system" | "developer" => Self::System,
+        _ => Self::User,
+      }
+    }
+  }
+
+  /// Provider-neutral text message
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct UniversalMessage
+  {
+    /// Message role
+    pub role : UniversalRole,
+    /// Message text
+    pub content : String,
+  }
+
+  impl UniversalMessage
+  {
+    /// Create a message with the given role
+    #[ inline ]
+    #[ must_use ]
+    pub fn new< S : Into< String > >( role : UniversalRole, content : S ) -> Self
+    {
+      Self { role, content : content.into() }
+    }
+
+    /// Create a system message
+    #[ inline ]
+    #[ must_use ]
+    pub fn system< S : Into< String > >( content : S ) -> Self
+    {
+      Self::new( UniversalRole::System, content )
+    }
+
+    /// Create a user message
+    #[ inline ]
+    #[ must_use ]
+    pub fn user< S : Into< String > >( content : S ) -> Self
+    {
+      Self::new( UniversalRole::User, content )
+    }
+
+    /// Create an assistant message
+    #[ inline ]
+    #[ must_use ]
+    pub fn assistant< S : Into< String > >( content : S ) -> Self
+    {
+      Self::new( UniversalRole::Assistant, content )
+    }
+  }
+
+  impl From< UniversalRole > for Role
+  {
+    #[ inline ]
+    fn from( role : UniversalRole ) -> Self
+    {
+      match role
+      {
+        UniversalRole::System => Role::System,
+        UniversalRole::User => Role::User,
+        UniversalRole::Assistant => Role::Assistant,
+      }
+    }
+  }
+
+  impl From< Role > for UniversalRole
+  {
+    #[ inline ]
+    fn from( role : Role ) -> Self
+    {
+      match role
+      {
+        Role::System => UniversalRole::System,
+        Role::User => UniversalRole::User,
+        Role::Assistant => UniversalRole::Assistant,
+      }
+    }
+  }
+
+  /// Converts to a single text block
+  impl From< UniversalMessage > for Message
+  {
+    #[ inline ]
+    fn from( message : UniversalMessage ) -> Self
+    {
+      Self
+      {
+        role : message.role.into(),
+        content : vec![ Content::new_text( message.content ) ],
+        cache_control : None,
+      }
+    }
+  }
+
+  /// Concatenates text blocks; image and tool blocks are dropped
+  impl From< Message > for UniversalMessage
+  {
+    #[ inline ]
+    fn from( message : Message ) -> Self
+    {
+      Self
+      {
+        role : message.role.into(),
+        content : message.content.iter().filter_map( Content::text ).collect(),
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use UniversalRole;
+  exposed use UniversalMessage;
+}
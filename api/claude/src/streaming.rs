@@ -7,6 +7,7 @@ crate::mod_interface!
 {
   layer types;
   layer client_impl;
+  layer usage;
 }
 
 #[ cfg( not( feature = "streaming" ) ) ]
@@ -34,7 +34,7 @@ mod private
       use tokio_stream::wrappers::UnboundedReceiverStream;
       
       // Validate the request
-      request.validate()?;
+      request.validate_with_output_limits( &self.config().output_limits )?;
       
       let url = format!( "{}/v1/messages", self.base_url() );
       
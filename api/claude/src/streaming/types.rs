@@ -503,6 +503,16 @@ mod private
       /// Index of the content block
       index : usize,
     },
+    /// Message delta event carrying top-level message changes and cumulative output usage
+    MessageDelta
+    {
+      /// Stop reason, once known
+      stop_reason : Option< String >,
+      /// Stop sequence, if one was hit
+      stop_sequence : Option< String >,
+      /// Cumulative output tokens reported so far
+      output_tokens : u32,
+    },
     /// Message stop event
     MessageStop,
     /// Error event
@@ -547,6 +557,14 @@ mod private
       Self::ContentBlockStop { index }
     }
 
+    /// Create a message delta event
+    #[ inline ]
+    #[ must_use ]
+    pub fn message_delta( stop_reason : Option< String >, output_tokens : u32 ) -> Self
+    {
+      Self::MessageDelta { stop_reason, stop_sequence : None, output_tokens }
+    }
+
     /// Create a message stop event
     #[ inline ]
     #[ must_use ]
@@ -595,6 +613,14 @@ mod private
       matches!( self, StreamEvent::ContentBlockStop { .. } )
     }
 
+    /// Check if this is a message delta event
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_message_delta( &self ) -> bool
+    {
+      matches!( self, StreamEvent::MessageDelta { .. } )
+    }
+
     /// Check if this is a message stop event
     #[ inline ]
     #[ must_use ]
@@ -684,6 +710,7 @@ mod private
           delta.validate()
         },
         StreamEvent::ContentBlockStop { .. } |
+        StreamEvent::MessageDelta { .. } |
         StreamEvent::MessageStop |
         StreamEvent::Error { .. } =>
         {
@@ -761,6 +788,7 @@ mod private
       "content_block_start" => parse_content_block_start( data ),
       "content_block_delta" => parse_content_block_delta( data ),
       "content_block_stop" => parse_content_block_stop( data ),
+      "message_delta" => parse_message_delta( data ),
       "message_stop" => Ok( StreamEvent::MessageStop ),
       "error" => parse_error_event( data ),
       _ => parse_unknown_event( event_type ),
@@ -881,6 +909,47 @@ mod private
     Ok( StreamEvent::ContentBlockStop { index : event_data.index } )
   }
 
+  /// Parse `message_delta` event
+  fn parse_message_delta( data : &str ) -> AnthropicResult< StreamEvent >
+  {
+    #[ derive( Deserialize ) ]
+    struct MessageDeltaData
+    {
+      #[ serde( default ) ]
+      delta : MessageDeltaFields,
+      usage : MessageDeltaUsage,
+    }
+
+    #[ derive( Deserialize, Default ) ]
+    struct MessageDeltaFields
+    {
+      stop_reason : Option< String >,
+      stop_sequence : Option< String >,
+    }
+
+    #[ derive( Deserialize ) ]
+    struct MessageDeltaUsage
+    {
+      output_tokens : u32,
+    }
+
+    let event_data : MessageDeltaData = serde_json::from_str( data )
+      .map_err( | e | 
+      {
+        #[ cfg( feature = "error-handling" ) ]
+        return AnthropicError::Parsing( format!( "Failed to parse message_delta : {e}" ) );
+        #[ cfg( not( feature = "error-handling" ) ) ]
+        return crate::error_tools::Error::msg( format!( "Failed to parse message_delta : {e}" ) );
+      } )?;
+
+    Ok( StreamEvent::MessageDelta
+    {
+      stop_reason : event_data.delta.stop_reason,
+      stop_sequence : event_data.delta.stop_sequence,
+      output_tokens : event_data.usage.output_tokens,
+    } )
+  }
+
   /// Parse error event
   fn parse_error_event( data : &str ) -> AnthropicResult< StreamEvent >
   {
@@ -901,9 +970,9 @@ mod private
   fn parse_unknown_event( event_type : &str ) -> AnthropicResult< StreamEvent >
   {
     #[ cfg( feature = "error-handling" ) ]
-    return Err( AnthropicError::Parsing( format!( "Unknown event type : '{event_type}'. Supported types : message_start, content_block_start, content_block_delta, content_block_stop, message_stop, error" ) ) );
+    return Err( AnthropicError::Parsing( format!( "Unknown event type : '{event_type}'. Supported types : message_start, content_block_start, content_block_delta, content_block_stop, message_delta, message_stop, error" ) ) );
     #[ cfg( not( feature = "error-handling" ) ) ]
-    return Err( crate::error_tools::Error::msg( format!( "Unknown event type : '{event_type}'. Supported types : message_start, content_block_start, content_block_delta, content_block_stop, message_stop, error" ) ) );
+    return Err( crate::error_tools::Error::msg( format!( "Unknown event type : '{event_type}'. Supported types : message_start, content_block_start, content_block_delta, content_block_stop, message_delta, message_stop, error" ) ) );
   }
 
   /// Stream of Server-Sent Events
@@ -0,0 +1,165 @@
+//! Streaming usage tracking
+//!
+//! `MessageStream` wraps an event stream and accumulates token usage from
+//! `message_start` (input) and `message_delta` (cumulative output) events.
+
+#[ cfg( feature = "streaming" ) ]
+mod private
+{
+  #[ cfg( feature = "error-handling" ) ]
+  use crate::error::AnthropicResult;
+
+  #[ cfg( not( feature = "error-handling" ) ) ]
+  type AnthropicResult< T > = Result< T, crate::error_tools::Error >;
+
+  use super::super::types::orphan::*;
+  use core::pin::Pin;
+  use core::task::{ Context, Poll };
+  use futures::Stream;
+
+  /// Explicit per-model token pricing used to compute streaming spend
+  ///
+  /// No prices are built in; callers supply the rates for the model they stream from.
+  #[ derive( Debug, Clone, Copy, PartialEq ) ]
+  pub struct ClaudePricing
+  {
+    /// Cost per million input tokens
+    pub input_cost_per_million : f64,
+    /// Cost per million output tokens
+    pub output_cost_per_million : f64,
+  }
+
+  impl ClaudePricing
+  {
+    /// Create pricing from per-million input and output token rates
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( input_cost_per_million : f64, output_cost_per_million : f64 ) -> Self
+    {
+      Self { input_cost_per_million, output_cost_per_million }
+    }
+
+    /// Compute the cost of the given usage
+    #[ inline ]
+    #[ must_use ]
+    pub fn cost( &self, usage : &crate::Usage ) -> f64
+    {
+      f64::from( usage.input_tokens ) / 1_000_000.0 * self.input_cost_per_million
+      + f64::from( usage.output_tokens ) / 1_000_000.0 * self.output_cost_per_million
+    }
+  }
+
+  /// Event stream wrapper that tracks cumulative token usage as events flow
+  ///
+  /// Events are passed through unchanged; usage is updated from `message_start`
+  /// and `message_delta` events before each is yielded.
+  pub struct MessageStream< S = EventStream >
+  where
+    S : Stream< Item = AnthropicResult< StreamEvent > > + Unpin,
+  {
+    inner : S,
+    usage : crate::Usage,
+  }
+
+  impl< S > core::fmt::Debug for MessageStream< S >
+  where
+    S : Stream< Item = AnthropicResult< StreamEvent > > + Unpin,
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      f.debug_struct( "MessageStream" )
+        .field( "usage", &self.usage )
+        .finish_non_exhaustive()
+    }
+  }
+
+  impl< S > MessageStream< S >
+  where
+    S : Stream< Item = AnthropicResult< StreamEvent > > + Unpin,
+  {
+    /// Wrap an event stream with usage tracking
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( inner : S ) -> Self
+    {
+      Self
+      {
+        inner,
+        usage : crate::Usage
+        {
+          input_tokens : 0,
+          output_tokens : 0,
+          cache_creation_input_tokens : None,
+          cache_read_input_tokens : None,
+        },
+      }
+    }
+
+    /// Cumulative usage observed so far
+    #[ inline ]
+    #[ must_use ]
+    pub fn usage_snapshot( &self ) -> crate::Usage
+    {
+      self.usage.clone()
+    }
+
+    /// Spend for the usage observed so far under `pricing`
+    #[ inline ]
+    #[ must_use ]
+    pub fn cost( &self, pricing : &ClaudePricing ) -> f64
+    {
+      pricing.cost( &self.usage )
+    }
+
+    /// Consume the wrapper, returning the inner stream
+    #[ inline ]
+    #[ must_use ]
+    pub fn into_inner( self ) -> S
+    {
+      self.inner
+    }
+
+    fn record( &mut self, event : &StreamEvent )
+    {
+      match event
+      {
+        StreamEvent::MessageStart { message } =>
+        {
+          self.usage = message.usage.clone();
+        },
+        StreamEvent::MessageDelta { output_tokens, .. } =>
+        {
+          // `message_delta` usage is cumulative for the message
+          self.usage.output_tokens = self.usage.output_tokens.max( *output_tokens );
+        },
+        _ => {},
+      }
+    }
+  }
+
+  impl< S > Stream for MessageStream< S >
+  where
+    S : Stream< Item = AnthropicResult< StreamEvent > > + Unpin,
+  {
+    type Item = AnthropicResult< StreamEvent >;
+
+    #[ inline ]
+    fn poll_next( mut self : Pin< &mut Self >, cx : &mut Context< '_ > ) -> Poll< Option< Self::Item > >
+    {
+      let polled = Pin::new( &mut self.inner ).poll_next( cx );
+      if let Poll::Ready( Some( Ok( event ) ) ) = &polled
+      {
+        self.record( event );
+      }
+      polled
+    }
+  }
+}
+
+#[ cfg( feature = "streaming" ) ]
+crate::mod_interface!
+{
+  exposed use ClaudePricing;
+  exposed use MessageStream;
+}
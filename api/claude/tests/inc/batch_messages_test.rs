@@ -8,6 +8,9 @@
 //! - Batch status retrieval
 //! - Batch listing with pagination
 //! - Batch cancellation
+//! - Decoding of mixed-success batch result lines
+//! - Reassembly of result lines split across body chunks
+//! - Streaming results of a batch that has not ended fails clearly
 //! - Error handling for batch operations
 
 #[ cfg( all( feature = "batch-processing", feature = "error-handling" ) ) ]
@@ -171,6 +174,108 @@ mod batch_api_tests
     assert!( json.contains( "\"max_tokens\"" ) );
   }
 
+  #[ test ]
+  fn test_decode_mixed_batch_results()
+  {
+    // One entry per result type, as in a results JSONL file
+    let fixture = r#"{"custom_id":"math-001","result":{"type":"succeeded","message":{"id":"msg_01","type":"message","role":"assistant","model":"claude-sonnet-4-5-20250929","content":[{"type":"text","text":"4"}],"stop_reason":"end_turn","stop_sequence":null,"usage":{"input_tokens":12,"output_tokens":1}}}}
+{"custom_id":"math-002","result":{"type":"errored","error":{"type":"error","error":{"type":"invalid_request_error","message":"max_tokens: must be greater than 0"}}}}
+{"custom_id":"math-003","result":{"type":"canceled"}}
+{"custom_id":"math-004","result":{"type":"expired"}}
+{"custom_id":"math-005","result":{"type":"succeeded"}}
+not json"#;
+
+    let results : Vec< _ > = fixture.lines().map( the_module::decode_batch_result_line ).collect();
+    assert_eq!( results.len(), 6 );
+
+    let ( custom_id, message ) = results[ 0 ].as_ref().expect( "succeeded entry should decode" );
+    assert_eq!( custom_id, "math-001" );
+    assert_eq!( message.text(), "4" );
+    assert_eq!( message.usage.output_tokens, 1 );
+
+    let api_error = | index : usize | match &results[ index ]
+    {
+      Err( the_module::AnthropicError::Api( error ) ) => error.clone(),
+      other => panic!( "expected an API error, got {other:?}" ),
+    };
+    let errored = api_error( 1 );
+    assert_eq!( errored.r#type, "invalid_request_error" );
+    assert!( errored.message.contains( "math-002" ), "{errored}" );
+    assert!( errored.message.contains( "max_tokens" ), "{errored}" );
+    assert_eq!( api_error( 2 ).r#type, "canceled" );
+    assert!( api_error( 2 ).message.contains( "math-003" ) );
+    assert_eq!( api_error( 3 ).r#type, "expired" );
+
+    for index in [ 4, 5 ]
+    {
+      assert!( matches!( results[ index ], Err( the_module::AnthropicError::Parsing( _ ) ) ), "{:?}", results[ index ] );
+    }
+    assert!( results[ 4 ].as_ref().unwrap_err().to_string().contains( "math-005" ) );
+  }
+
+  /// A results JSONL file with a succeeded, a canceled and an expired entry, without a final newline
+  const RESULTS_FIXTURE : &str = concat!(
+    r#"{"custom_id":"math-001","result":{"type":"succeeded","message":{"id":"msg_01","type":"message","role":"assistant","model":"claude-sonnet-4-5-20250929","content":[{"type":"text","text":"4"}],"stop_reason":"end_turn","stop_sequence":null,"usage":{"input_tokens":12,"output_tokens":1}}}}"#, "\n",
+    "\n",
+    r#"{"custom_id":"math-003","result":{"type":"canceled"}}"#, "\n",
+    r#"{"custom_id":"math-004","result":{"type":"expired"}}"#,
+  );
+
+  fn custom_ids( results : &[ the_module::AnthropicResult< ( String, the_module::CreateMessageResponse ) > ] ) -> Vec< String >
+  {
+    results.iter().map( | result | match result
+    {
+      Ok( ( custom_id, _ ) ) => custom_id.clone(),
+      Err( error ) => error.to_string(),
+    } ).collect()
+  }
+
+  fn assert_fixture_results( results : &[ the_module::AnthropicResult< ( String, the_module::CreateMessageResponse ) > ] )
+  {
+    assert_eq!( results.len(), 3, "{:?}", custom_ids( results ) );
+    let ( custom_id, message ) = results[ 0 ].as_ref().expect( "succeeded entry should decode" );
+    assert_eq!( custom_id, "math-001" );
+    assert_eq!( message.text(), "4" );
+    assert!( matches!( &results[ 1 ], Err( the_module::AnthropicError::Api( error ) ) if error.r#type == "canceled" ), "{:?}", results[ 1 ] );
+    assert!( matches!( &results[ 2 ], Err( the_module::AnthropicError::Api( error ) ) if error.r#type == "expired" ), "{:?}", results[ 2 ] );
+  }
+
+  #[ tokio::test ]
+  async fn test_decode_result_lines_split_across_chunks()
+  {
+    use futures_util::StreamExt;
+
+    // Cut the file mid-line, right after a newline and inside the final line
+    let bytes = RESULTS_FIXTURE.as_bytes();
+    let first_end = RESULTS_FIXTURE.find( '\n' ).unwrap();
+    let cuts = [ 0, 17, first_end + 1, first_end + 5, bytes.len() - 9, bytes.len() ];
+    let chunks : Vec< the_module::AnthropicResult< Vec< u8 > > > = cuts.windows( 2 ).map( | cut | Ok( bytes[ cut[ 0 ]..cut[ 1 ] ].to_vec() ) ).collect();
+
+    let results : Vec< _ > = the_module::decode_batch_result_chunks( futures_util::stream::iter( chunks ) ).collect().await;
+
+    assert_fixture_results( &results );
+  }
+
+  #[ tokio::test ]
+  async fn test_decode_result_chunks_yields_body_error_last()
+  {
+    use futures_util::StreamExt;
+
+    let first_end = RESULTS_FIXTURE.find( '\n' ).unwrap();
+    let chunks = vec!
+    [
+      Ok( RESULTS_FIXTURE.as_bytes()[ ..first_end + 10 ].to_vec() ),
+      Err( the_module::AnthropicError::Stream( "connection reset".to_string() ) ),
+      Ok( b"never read".to_vec() ),
+    ];
+
+    let results : Vec< _ > = the_module::decode_batch_result_chunks( futures_util::stream::iter( chunks ) ).collect().await;
+
+    assert_eq!( results.len(), 2, "{:?}", custom_ids( &results ) );
+    assert_eq!( results[ 0 ].as_ref().expect( "complete line should decode" ).0, "math-001" );
+    assert!( matches!( &results[ 1 ], Err( the_module::AnthropicError::Stream( message ) ) if message == "connection reset" ), "{:?}", results[ 1 ] );
+  }
+
   #[ cfg( feature = "integration" ) ]
   #[ tokio::test ]
   #[ ignore = "Requires workspace secrets file" ]
@@ -268,4 +373,37 @@ async fn integration_batch_retrieve_invalid_id()
     let result = client.retrieve_batch( "batch_nonexistent" ).await;
     assert!( result.is_err() );
   }
+
+  #[ cfg( feature = "integration" ) ]
+  #[ tokio::test ]
+  #[ ignore = "Requires workspace secrets file" ]
+async fn integration_results_stream_before_batch_ends()
+  {
+    let client = the_module::Client::from_workspace()
+      .expect( "Failed to create client from workspace secrets" );
+
+    let message_request = the_module::CreateMessageRequest::builder()
+      .model( the_module::RECOMMENDED_MODEL.to_string() )
+      .max_tokens( 50 )
+      .message( the_module::Message::user( "What is 2+2?".to_string() ) )
+      .build();
+    let batch = client.create_messages_batch( the_module::CreateBatchRequest::new( vec![
+      the_module::BatchRequestItem::new( "math-001".to_string(), message_request ),
+    ] ) ).await
+      .expect( "Failed to create batch" );
+
+    // A freshly created batch is still processing, so it has no results_url
+    let result = client.results_stream( &batch.id ).await;
+    client.cancel_batch( &batch.id ).await.expect( "Failed to cancel batch" );
+
+    match result
+    {
+      Err( the_module::AnthropicError::InvalidArgument( message ) ) => assert!( message.contains( &batch.id ), "{message}" ),
+      Err( error ) => panic!( "expected a missing results_url error, got {error:?}" ),
+      Ok( _ ) => panic!( "a processing batch should have no results yet" ),
+    }
+
+    // An empty batch ID is rejected before any request
+    assert!( matches!( client.results_stream( "" ).await, Err( the_module::AnthropicError::InvalidArgument( _ ) ) ) );
+  }
 }
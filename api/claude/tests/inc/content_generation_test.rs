@@ -212,6 +212,9 @@ async fn test_response_structure_parsing()
     {
       r#type : "text".to_string(),
       text : Some( "Generated content here".to_string() ),
+      id : None,
+      name : None,
+      input : None,
     }
   ];
 
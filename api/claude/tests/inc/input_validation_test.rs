@@ -124,9 +124,10 @@ fn test_max_tokens_at_boundaries()
     tool_choice : None,
   };
 
+  // Global ceiling is checked on a model without a per-model output limit
   let request_max = the_module::CreateMessageRequest
   {
-    model : "claude-3-5-haiku-20241022".to_string(),
+    model : "custom-model".to_string(),
     max_tokens : 200_000,
     messages : vec![ the_module::Message::user( "Hello" ) ],
     system : None,
@@ -611,6 +612,91 @@ fn test_system_instructions_valid()
   assert!( instructions.validate().is_ok() );
 }
 
+// ============================================================================
+// UNIT TESTS - PER-MODEL OUTPUT LIMITS
+// ============================================================================
+
+fn request_for( model : &str, max_tokens : u32 ) -> the_module::CreateMessageRequest
+{
+  the_module::CreateMessageRequest
+  {
+    model : model.to_string(),
+    max_tokens,
+    messages : vec![ the_module::Message::user( "Hello" ) ],
+    system : None,
+    temperature : None,
+    stream : None,
+    #[ cfg( feature = "tools" ) ]
+    tools : None,
+    #[ cfg( feature = "tools" ) ]
+    tool_choice : None,
+  }
+}
+
+#[ test ]
+fn test_max_tokens_above_known_model_limit()
+{
+  // claude-3-5-haiku caps output at 8192 tokens
+  let result = request_for( "claude-3-5-haiku-20241022", 8_193 ).validate();
+
+  assert!( result.is_err() );
+  assert!( result.unwrap_err().to_string().contains( "exceeds the output limit of 8192" ) );
+  assert!( request_for( "claude-3-5-haiku-20241022", 8_192 ).validate().is_ok() );
+}
+
+#[ test ]
+fn test_max_tokens_zero_on_known_model()
+{
+  let result = request_for( "claude-sonnet-4-5-20250929", 0 ).validate();
+
+  assert!( result.is_err() );
+  assert!( result.unwrap_err().to_string().contains( "max_tokens must be between" ) );
+}
+
+#[ test ]
+fn test_model_output_limits_override()
+{
+  let limits = the_module::ModelOutputLimits::known()
+    .with_limit( "claude-3-5-haiku-20241022", 1_000 )
+    .with_limit( "custom-model", 500 );
+
+  assert_eq!( limits.limit_for( "claude-3-5-haiku-20241022" ), Some( 1_000 ) );
+  assert_eq!( limits.limit_for( "claude-3-5-haiku-latest" ), Some( 8_192 ) );
+  assert_eq!( limits.limit_for( "unknown-model" ), None );
+
+  assert!( request_for( "claude-3-5-haiku-20241022", 2_000 ).validate_with_output_limits( &limits ).is_err() );
+  assert!( request_for( "custom-model", 501 ).validate_with_output_limits( &limits ).is_err() );
+  assert!( request_for( "claude-3-5-haiku-20241022", 50_000 )
+    .validate_with_output_limits( &the_module::ModelOutputLimits::empty() )
+    .is_ok() );
+}
+
+#[ test ]
+fn test_family_limit_does_not_cap_newer_models()
+{
+  let limits = the_module::ModelOutputLimits::known();
+
+  assert_eq!( limits.limit_for( "claude-opus-4-5-20251101" ), Some( 64_000 ) );
+  assert_eq!( limits.limit_for( "claude-opus-4-20250514" ), Some( 32_000 ) );
+  assert_eq!( limits.limit_for( "claude-opus-4-1@20250805" ), Some( 32_000 ) );
+  assert_eq!( limits.limit_for( "claude-opus-4-6" ), None );
+  assert!( request_for( "claude-opus-4-5-20251101", 40_000 ).validate().is_ok() );
+}
+
+#[ tokio::test ]
+async fn test_client_output_limits_checked_before_sending()
+{
+  let secret = the_module::Secret::new( "sk-ant-api03-example".to_string() ).unwrap();
+  let config = the_module::ClientConfig::recommended()
+    .with_base_url( "http://127.0.0.1:9".to_string() )
+    .with_output_limits( the_module::ModelOutputLimits::empty().with_limit( "claude-sonnet-4-5", 1_000 ) );
+  let client = the_module::Client::with_config( secret, config );
+
+  // Rejected locally, so the unreachable base URL is never contacted
+  let error = client.create_message( request_for( "claude-sonnet-4-5-20250929", 2_000 ) ).await.unwrap_err();
+  assert!( error.to_string().contains( "exceeds the output limit of 1000" ), "{error}" );
+}
+
 // ============================================================================
 // INTEGRATION TESTS - VALIDATION BEFORE API CALLS
 // ============================================================================
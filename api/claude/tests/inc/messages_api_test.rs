@@ -245,12 +245,84 @@ async fn test_response_content_structure()
   {
     r#type : "text".to_string(),
     text : Some( "This is a response".to_string() ),
+    id : None,
+    name : None,
+    input : None,
   };
   
   assert_eq!( content.r#type, "text" );
   assert_eq!( content.text.as_deref(), Some( "This is a response" ) );
 }
 
+#[ test ]
+fn test_create_message_response_accessors()
+{
+  // Test text, tool_use and stop_reason accessors on a mixed-content response
+  let response : the_module::CreateMessageResponse = serde_json::from_value( serde_json::json!(
+  {
+    "id" : "msg_mixed",
+    "type" : "message",
+    "role" : "assistant",
+    "content" :
+    [
+      { "type" : "text", "text" : "Let me check " },
+      { "type" : "tool_use", "id" : "toolu_1", "name" : "get_weather", "input" : { "city" : "Paris" } },
+      { "type" : "text", "text" : "the weather." },
+      { "type" : "tool_use", "id" : "toolu_2", "name" : "get_time", "input" : {} }
+    ],
+    "model" : "claude-sonnet-4-5-20250929",
+    "stop_reason" : "tool_use",
+    "stop_sequence" : null,
+    "usage" : { "input_tokens" : 20, "output_tokens" : 30 }
+  } ) ).expect( "Mixed content response must deserialize" );
+
+  assert_eq!( response.text(), "Let me check the weather." );
+  assert_eq!( response.stop_reason(), Some( "tool_use" ) );
+
+  #[ cfg( feature = "tools" ) ]
+  {
+    let tool_uses = response.tool_uses();
+    assert_eq!( tool_uses.len(), 2 );
+    assert_eq!( tool_uses[ 0 ].id, "toolu_1" );
+    assert_eq!( tool_uses[ 0 ].name, "get_weather" );
+    assert_eq!( tool_uses[ 0 ].input[ "city" ], "Paris" );
+    assert_eq!( tool_uses[ 1 ].name, "get_time" );
+  }
+}
+
+#[ test ]
+fn test_create_message_response_accessors_without_text()
+{
+  let response : the_module::CreateMessageResponse = serde_json::from_value( serde_json::json!(
+  {
+    "id" : "msg_tool_only",
+    "type" : "message",
+    "role" : "assistant",
+    "content" :
+    [
+      { "type" : "tool_use", "id" : "toolu_1", "name" : "noop", "input" : {} },
+      { "type" : "tool_use", "id" : "toolu_2", "name" : "noop" }
+    ],
+    "model" : "claude-sonnet-4-5-20250929",
+    "stop_reason" : null,
+    "stop_sequence" : null,
+    "usage" : { "input_tokens" : 1, "output_tokens" : 1 }
+  } ) ).expect( "Tool-only response must deserialize" );
+
+  assert_eq!( response.text(), "" );
+  assert_eq!( response.stop_reason(), None );
+
+  #[ cfg( feature = "tools" ) ]
+  {
+    let tool_uses = response.tool_uses();
+    assert_eq!( tool_uses.len(), 2 );
+    // Views borrow from the response instead of cloning it
+    assert!( core::ptr::eq( tool_uses[ 0 ].input, response.content[ 0 ].input.as_ref().unwrap() ) );
+    assert_eq!( tool_uses[ 1 ].id, "toolu_2" );
+    assert!( tool_uses[ 1 ].input.is_null() );
+  }
+}
+
 #[ tokio::test ]
 async fn test_usage_statistics()
 {
@@ -281,6 +353,9 @@ async fn test_create_message_response_structure()
       {
         r#type : "text".to_string(),
         text : Some( "Test response".to_string() ),
+        id : None,
+        name : None,
+        input : None,
       }
     ],
     model : "claude-sonnet-4-5-20250929".to_string(),
@@ -405,4 +480,26 @@ async fn integration_messages_api_real_serialization_roundtrip()
   println!( "✅ Messages API serialization integration test passed!" );
   println!( "   Request/response roundtrip successful" );
   println!( "   Response : {content_text}" );
-}
\ No newline at end of file
+}
+#[ test ]
+fn test_universal_message_round_trip()
+{
+  use the_module::{ UniversalMessage, UniversalRole };
+
+  let conversation = vec!
+  [
+    UniversalMessage::user( "What is the capital of France?" ),
+    UniversalMessage::assistant( "Paris." ),
+  ];
+
+  let messages : Vec< the_module::Message > = conversation.iter().cloned().map( Into::into ).collect();
+  assert_eq!( messages[ 0 ].role, the_module::Role::User );
+  assert_eq!( messages[ 1 ].role, the_module::Role::Assistant );
+  assert_eq!( messages[ 1 ].content, vec![ the_module::Content::new_text( "Paris." ) ] );
+
+  let back : Vec< UniversalMessage > = messages.into_iter().map( Into::into ).collect();
+  assert_eq!( back, conversation );
+
+  assert_eq!( UniversalRole::from_provider_role( "model" ), UniversalRole::Assistant );
+  assert_eq!( UniversalMessage::from( the_module::Message::from( UniversalMessage::system( "Be brief" ) ) ).role, UniversalRole::System );
+}
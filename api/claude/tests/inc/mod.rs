@@ -32,6 +32,8 @@ mod examples_validation_test;
 mod experiment;
 mod fallback_behavior_integration_test;
 mod messages_api_test;
+#[ cfg( feature = "error-handling" ) ]
+mod optional_retries_test;
 mod model_management_test;
 mod performance_test;
 mod performance_monitoring_test;
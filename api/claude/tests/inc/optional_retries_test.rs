@@ -0,0 +1,75 @@
+//!
+//! Tests for `Client::execute_with_optional_retries`
+//!
+//! The operation fails twice with a transient error before succeeding. Without a
+//! retry policy it runs exactly once; with one it is retried until it succeeds
+//! or the policy's attempt limit is reached.
+//!
+
+use super::*;
+use std::sync::atomic::{ AtomicU32, Ordering };
+
+fn client() -> the_module::Client
+{
+  the_module::Client::new( the_module::Secret::new( "sk-ant-api03-test-key".to_string() ).unwrap() )
+}
+
+/// Fails with a transient error on the first two calls, then returns the attempt number
+async fn flaky( attempts : &AtomicU32 ) -> the_module::AnthropicResult< u32 >
+{
+  let attempt = attempts.fetch_add( 1, Ordering::SeqCst ) + 1;
+  if attempt <= 2
+  {
+    Err( the_module::AnthropicError::Internal( "temporary failure".to_string() ) )
+  }
+  else
+  {
+    Ok( attempt )
+  }
+}
+
+/// Test that the operation runs once when no retry policy is configured
+#[ tokio::test ]
+async fn test_single_attempt_without_retry_config()
+{
+  let client = client();
+  let attempts = AtomicU32::new( 0 );
+
+  let result = client.execute_with_optional_retries( || flaky( &attempts ) ).await;
+
+  assert!( result.is_err() );
+  assert_eq!( attempts.load( Ordering::SeqCst ), 1 );
+}
+
+/// Test that a configured retry policy retries transient failures
+#[ cfg( feature = "retry-logic" ) ]
+#[ tokio::test ]
+async fn test_retries_when_configured()
+{
+  let client = client().with_retry_config
+  (
+    the_module::RetryConfig::with_explicit_config( 3, 1, 5, 2.0, false )
+  );
+  let attempts = AtomicU32::new( 0 );
+
+  let result = client.execute_with_optional_retries( || flaky( &attempts ) ).await;
+
+  assert_eq!( result.unwrap(), 3 );
+  assert_eq!( attempts.load( Ordering::SeqCst ), 3 );
+}
+
+/// Test that retries stop at the policy's attempt limit
+#[ cfg( feature = "retry-logic" ) ]
+#[ tokio::test ]
+async fn test_gives_up_after_max_attempts()
+{
+  let client = client().with_retry_config
+  (
+    the_module::RetryConfig::with_explicit_config( 2, 1, 5, 2.0, false )
+  );
+  let attempts = AtomicU32::new( 0 );
+
+  assert!( client.execute_with_optional_retries( || flaky( &attempts ) ).await.is_err() );
+  assert_eq!( attempts.load( Ordering::SeqCst ), 2 );
+  assert!( client.retry_config().is_some() );
+}
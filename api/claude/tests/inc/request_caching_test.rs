@@ -133,6 +133,9 @@ mod request_caching_functionality_tests
       {
         r#type : "text".to_string(),
         text : Some( "Cached response".to_string() ),
+        id : None,
+        name : None,
+        input : None,
       } ],
       model : "claude-3-5-haiku-20241022".to_string(),
       stop_reason : Some( "end_turn".to_string() ),
@@ -188,6 +191,9 @@ mod request_caching_functionality_tests
       {
         r#type : "text".to_string(),
         text : Some( "This will expire".to_string() ),
+        id : None,
+        name : None,
+        input : None,
       } ],
       model : "claude-3-5-haiku-20241022".to_string(),
       stop_reason : Some( "end_turn".to_string() ),
@@ -276,6 +282,9 @@ mod request_caching_functionality_tests
         {
           r#type : "text".to_string(),
           text : Some( format!( "Response {}", i + 1 ) ),
+          id : None,
+          name : None,
+          input : None,
         } ],
         model : "claude-3-5-haiku-20241022".to_string(),
         stop_reason : Some( "end_turn".to_string() ),
@@ -328,6 +337,9 @@ mod request_caching_functionality_tests
       {
         r#type : "text".to_string(),
         text : Some( "To be invalidated".to_string() ),
+        id : None,
+        name : None,
+        input : None,
       } ],
       model : "claude-3-5-haiku-20241022".to_string(),
       stop_reason : Some( "end_turn".to_string() ),
@@ -385,6 +397,9 @@ mod request_caching_functionality_tests
       {
         r#type : "text".to_string(),
         text : Some( "Metrics response".to_string() ),
+        id : None,
+        name : None,
+        input : None,
       } ],
       model : "claude-3-5-haiku-20241022".to_string(),
       stop_reason : Some( "end_turn".to_string() ),
@@ -493,6 +508,9 @@ mod request_caching_integration_tests
         {
           r#type : "text".to_string(),
           text : Some( format!( "Response {}", i ) ),
+          id : None,
+          name : None,
+          input : None,
         } ],
         model : "claude-3-5-haiku-20241022".to_string(),
         stop_reason : Some( "end_turn".to_string() ),
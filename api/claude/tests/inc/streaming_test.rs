@@ -84,6 +84,65 @@ async fn test_streaming_with_vision_structure()
   assert!( request.messages[0].has_images() );
 }
 
+// ============================================================================
+// UNIT TESTS - STREAMING USAGE TRACKING
+// ============================================================================
+
+#[ cfg( feature = "streaming" ) ]
+const USAGE_SSE : &str = r#"event : message_start
+data : {"id": "msg_usage", "type": "message", "role": "assistant", "content": [], "model": "claude-sonnet-4-5-20250929", "stop_reason": null, "stop_sequence": null, "usage": {"input_tokens": 1200, "output_tokens": 1}}
+
+event : content_block_delta
+data : {"index": 0, "delta": {"type": "text_delta", "text": "Hello"}}
+
+event : message_delta
+data : {"delta": {"stop_reason": null, "stop_sequence": null}, "usage": {"output_tokens": 40}}
+
+event : message_delta
+data : {"delta": {"stop_reason": "end_turn", "stop_sequence": null}, "usage": {"output_tokens": 500}}
+
+event : message_stop
+data : {}
+
+"#;
+
+#[ cfg( feature = "streaming" ) ]
+#[ tokio::test ]
+async fn test_message_stream_tracks_running_usage()
+{
+  use futures::StreamExt;
+
+  let events = the_module::parse_sse_events( USAGE_SSE ).expect( "SSE fixture must parse" );
+  assert_eq!( events.len(), 5 );
+  assert!( events[ 3 ].is_message_delta() );
+
+  let mut stream = the_module::MessageStream::new( futures::stream::iter( events.into_iter().map( Ok ) ) );
+  assert_eq!( stream.usage_snapshot().total_tokens(), 0 );
+
+  // message_start : input usage
+  stream.next().await.expect( "event" ).expect( "ok" );
+  assert_eq!( stream.usage_snapshot().input_tokens, 1200 );
+  assert_eq!( stream.usage_snapshot().output_tokens, 1 );
+
+  // content delta leaves usage untouched
+  stream.next().await.expect( "event" ).expect( "ok" );
+  assert_eq!( stream.usage_snapshot().output_tokens, 1 );
+
+  // message_delta : cumulative output usage
+  stream.next().await.expect( "event" ).expect( "ok" );
+  assert_eq!( stream.usage_snapshot().output_tokens, 40 );
+
+  while stream.next().await.is_some() {}
+
+  let usage = stream.usage_snapshot();
+  assert_eq!( usage.input_tokens, 1200 );
+  assert_eq!( usage.output_tokens, 500 );
+
+  let pricing = the_module::ClaudePricing::new( 3.0, 15.0 );
+  let expected = 1200.0 / 1_000_000.0 * 3.0 + 500.0 / 1_000_000.0 * 15.0;
+  assert!( ( stream.cost( &pricing ) - expected ).abs() < 1e-12 );
+}
+
 // ============================================================================
 // INTEGRATION TESTS - REAL API STREAMING
 // ============================================================================
@@ -63,6 +63,8 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     }),
     safety_settings: Some( vec!
     [
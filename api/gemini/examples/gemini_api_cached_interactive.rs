@@ -254,6 +254,8 @@ println!("📦 Created cache : {} (ID: {})",
         top_k: Some(40),
         candidate_count: Some(1),
         stop_sequences: None,
+        response_mime_type: None,
+        response_schema: None,
       }),
       safety_settings: None,
       tools: None,
@@ -377,7 +379,7 @@ println!("📦 Created cache : {} (ID: {})",
       }
     }
 
-    Err(Error::ApiError("No response generated".to_string()))
+    Err(Error::ApiError("No response generated".to_string(), None))
   }
 
   /// Check if cache optimization is needed
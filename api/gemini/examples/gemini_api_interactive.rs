@@ -103,6 +103,8 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
         top_k: Some( 40 ),
         candidate_count: Some( 1 ),
         stop_sequences: None,
+        response_mime_type: None,
+        response_schema: None,
       }),
       safety_settings: None,
       tools: None,
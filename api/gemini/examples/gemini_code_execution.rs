@@ -227,6 +227,8 @@ config: &ExecutionExampleConfig,
         function_response: None,
         file_data: None,
         video_metadata: None,
+        executable_code: None,
+        code_execution_result: None,
       } ],
       role: "user".to_string(),
     } ],
@@ -237,6 +239,8 @@ config: &ExecutionExampleConfig,
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 2048 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     } ),
     safety_settings: None,
     tools: Some( tools ),
@@ -238,8 +238,8 @@ println!( "   Safety settings configured with {settings_len} categories" );
   [
   api_gemini ::error::Error::AuthenticationError( "API key missing".to_string() ),
   api_gemini ::error::Error::NetworkError( "Connection timeout".to_string() ),
-  api_gemini ::error::Error::RateLimitError( "Too many requests".to_string() ),
-  api_gemini ::error::Error::InvalidArgument( "Invalid model name".to_string() ),
+  api_gemini ::error::Error::RateLimitError( "Too many requests".to_string(), None ),
+  api_gemini ::error::Error::InvalidArgument( "Invalid model name".to_string(), None ),
   ];
 
   for error in &errors
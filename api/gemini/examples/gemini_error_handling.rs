@@ -64,7 +64,7 @@ println!( "Attempt {attempt} for {operation_name}" );
     println!( "Error on attempt {attempt}: {error:?}" );
 
         // Check if error is retryable
-        let should_retry = matches!( &error, api_gemini::error::Error::RateLimitError( _ ) | api_gemini::error::Error::NetworkError( _ ) | api_gemini::error::Error::ServerError( _ ) );
+        let should_retry = matches!( &error, api_gemini::error::Error::RateLimitError( .. ) | api_gemini::error::Error::NetworkError( _ ) | api_gemini::error::Error::ServerError( _ ) );
 
         if !should_retry || attempt >= config.max_attempts
         {
@@ -72,7 +72,7 @@ println!( "Attempt {attempt} for {operation_name}" );
         }
 
         // Special handling for rate limits
-        if let api_gemini::error::Error::RateLimitError( msg ) = &error
+        if let api_gemini::error::Error::RateLimitError( msg, _ ) = &error
         {
         println!( "Rate limit hit : {msg}. Waiting longer..." );
           delay = config.max_delay; // Use max delay for rate limits
@@ -167,7 +167,7 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
     Ok( _ ) => println!( "Unexpected success" ),
     Err( e ) => match e
     {
-      api_gemini ::error::Error::ApiError( msg ) =>
+      api_gemini ::error::Error::ApiError( msg, _ ) =>
       {
       println!( "API error (invalid model): {msg}" );
         println!( "Solution: Use client.models().list() to see available models" );
@@ -209,6 +209,8 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 100 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     }),
     safety_settings: None,
     tools: None,
@@ -279,12 +281,12 @@ Err( e ) => println!( "Failed after {} attempts : {:?}", retry_config.max_attemp
     Ok( _ ) => println!( "Unexpected success" ),
     Err( e ) => match e
     {
-      api_gemini ::error::Error::InvalidArgument( msg ) =>
+      api_gemini ::error::Error::InvalidArgument( msg, _ ) =>
       {
       println!( "Invalid argument error : {msg}" );
         println!( "Solution: Ensure request has at least one content item" );
       },
-      api_gemini ::error::Error::ApiError( msg ) =>
+      api_gemini ::error::Error::ApiError( msg, _ ) =>
       {
       println!( "API rejected malformed request : {msg}" );
       },
@@ -338,6 +340,8 @@ Err( e ) => println!( "Failed after {} attempts : {:?}", retry_config.max_attemp
           candidate_count: Some( 1 ),
           max_output_tokens: Some( 512 ),
           stop_sequences: None,
+          response_mime_type: None,
+          response_schema: None,
         }),
         safety_settings: None,
         tools: None,
@@ -404,7 +408,7 @@ Err( e ) => println!( "Failed after {} attempts : {:?}", retry_config.max_attemp
 
       match error
       {
-        api_gemini ::error::Error::RateLimitError( _ ) => self.rate_limit_errors += 1,
+        api_gemini ::error::Error::RateLimitError( .. ) => self.rate_limit_errors += 1,
         api_gemini ::error::Error::NetworkError( _ ) => self.network_errors += 1,
         _ => self.other_errors += 1,
       }
@@ -896,6 +896,8 @@ impl FunctionCallingAgent
           candidate_count: Some( 1 ),
           max_output_tokens: Some( 2048 ),
           stop_sequences: None,
+          response_mime_type: None,
+          response_schema: None,
         }),
         safety_settings: None,
         tools: Some( tools ),
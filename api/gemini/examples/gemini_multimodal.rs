@@ -93,6 +93,8 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     }),
     safety_settings: None,
     tools: None,
@@ -213,6 +215,8 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     }),
     safety_settings: None,
     tools: None,
@@ -299,6 +303,8 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 512 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     }),
     safety_settings: None,
     tools: None,
@@ -182,7 +182,7 @@ async fn demonstrate_error_resilience() -> Result< (), Box< dyn core::error::Err
   {
     Ok( _response ) => println!( "✅ Request succeeded" ),
   Err( Error::TimeoutError( msg ) ) => println!( "⏱️ Timeout handled gracefully : {msg}" ),
-    Err( Error::RateLimitError( msg ) ) => {
+    Err( Error::RateLimitError( msg, _ ) ) => {
     println!( "🚦 Rate limit detected : {msg}" );
       println!( "💡 Application can implement exponential backoff" );
     },
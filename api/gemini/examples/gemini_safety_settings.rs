@@ -47,6 +47,8 @@ async fn main() -> Result< (), Box< dyn core::error::Error > >
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 512 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     }),
     safety_settings: None, // Using default safety settings
     tools: None,
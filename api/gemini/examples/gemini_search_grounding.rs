@@ -198,6 +198,8 @@ println!( "{}", "=".repeat( 80 ) );
         function_response: None,
         file_data: None,
         video_metadata: None,
+        executable_code: None,
+        code_execution_result: None,
       } ],
       role: "user".to_string(),
     } ],
@@ -208,6 +210,8 @@ println!( "{}", "=".repeat( 80 ) );
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 2048 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     } ),
     safety_settings: None,
     tools: Some( vec![ search_tool ] ),
@@ -434,6 +438,8 @@ println!( "\n🧪 Test Query {} of {}", i + 1, test_queries.len() );
           function_response: None,
           file_data: None,
           video_metadata: None,
+          executable_code: None,
+          code_execution_result: None,
         } ],
         role: "user".to_string(),
       } ],
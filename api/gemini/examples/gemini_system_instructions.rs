@@ -371,6 +371,8 @@ conversation_history: Option< &Vec< Content > >,
       function_response: None,
       file_data: None,
       video_metadata: None,
+      executable_code: None,
+      code_execution_result: None,
     } ],
   };
 
@@ -384,6 +386,8 @@ conversation_history: Option< &Vec< Content > >,
       function_response: None,
       file_data: None,
       video_metadata: None,
+      executable_code: None,
+      code_execution_result: None,
     } ],
     role: "user".to_string(),
   } );
@@ -397,6 +401,8 @@ conversation_history: Option< &Vec< Content > >,
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     } ),
     safety_settings: None,
     tools: None,
@@ -147,19 +147,19 @@ impl< 'a > BatchApi< 'a >
         BatchJobState::Failed =>
         {
           return Err( Error::ApiError(
-            status.error.unwrap_or_else( || "Batch job failed".to_string() )
+            status.error.unwrap_or_else( || "Batch job failed".to_string() ), None
           ) );
         }
         BatchJobState::Cancelled =>
         {
-          return Err( Error::ApiError( "Batch job was cancelled".to_string() ) );
+          return Err( Error::ApiError( "Batch job was cancelled".to_string(), None ) );
         }
         BatchJobState::Pending | BatchJobState::Running =>
         {
           // Check timeout
           if start.elapsed().unwrap_or( Duration::ZERO ) > timeout
           {
-            return Err( Error::ApiError( "Batch job timeout".to_string() ) );
+            return Err( Error::ApiError( "Batch job timeout".to_string(), None ) );
           }
 
           // Wait before next poll
@@ -372,18 +372,18 @@ impl< 'a > BatchApi< 'a >
         BatchJobState::Failed =>
         {
           return Err( Error::ApiError(
-            status.error.unwrap_or_else( || "Batch job failed".to_string() )
+            status.error.unwrap_or_else( || "Batch job failed".to_string() ), None
           ) );
         }
         BatchJobState::Cancelled =>
         {
-          return Err( Error::ApiError( "Batch job was cancelled".to_string() ) );
+          return Err( Error::ApiError( "Batch job was cancelled".to_string(), None ) );
         }
         BatchJobState::Pending | BatchJobState::Running =>
         {
           if start.elapsed().unwrap_or( Duration::ZERO ) > timeout
           {
-            return Err( Error::ApiError( "Batch job timeout".to_string() ) );
+            return Err( Error::ApiError( "Batch job timeout".to_string(), None ) );
           }
 
           tokio ::time::sleep( poll_interval ).await;
@@ -4,6 +4,8 @@
 use super::super::Client;
 #[ cfg( feature = "chat" ) ]
 use super::conversation_builder::ConversationBuilder;
+#[ cfg( feature = "chat" ) ]
+use super::chat_session::ChatSession;
 
 /// API handle for chat completion operations.
 #[ cfg( feature = "chat" ) ]
@@ -58,11 +60,11 @@ impl ChatApi< '_ >
       .await
       .map_err( | e | match e
       {
-        crate ::error::Error::ApiError( msg ) if msg.contains( "400" ) => 
+        crate ::error::Error::ApiError( msg, _ ) if msg.contains( "400" ) => 
           crate ::error::Error::InvalidArgument( 
-            format!( "Chat completion request failed : {msg}. Please check message format and roles." )
+            format!( "Chat completion request failed : {msg}. Please check message format and roles." ), None
           ),
-        crate ::error::Error::ApiError( msg ) if msg.contains( "401" ) || msg.contains( "403" ) => 
+        crate ::error::Error::ApiError( msg, _ ) if msg.contains( "401" ) || msg.contains( "403" ) => 
           crate ::error::Error::AuthenticationError( 
             format!( "Chat completion authentication failed : {msg}. Please verify your API key has chat permissions." )
           ),
@@ -94,7 +96,7 @@ impl ChatApi< '_ >
     if request.messages.is_empty()
     {
       return Err( crate::error::Error::InvalidArgument( 
-        "Chat completion requires at least one message. Please provide a non-empty messages array.".to_string() 
+        "Chat completion requires at least one message. Please provide a non-empty messages array.".to_string(), None 
       ) );
     }
 
@@ -109,7 +111,7 @@ impl ChatApi< '_ >
       if message.content.is_empty()
       {
         return Err( crate::error::Error::InvalidArgument( 
-          format!( "Message at index {index} has empty content. All messages must have non-empty content." )
+          format!( "Message at index {index} has empty content. All messages must have non-empty content." ), None
         ) );
       }
 
@@ -119,7 +121,7 @@ impl ChatApi< '_ >
           if system_instruction.is_some()
           {
             return Err( crate::error::Error::InvalidArgument( 
-              "Multiple system messages found. Only one system message is allowed per conversation.".to_string() 
+              "Multiple system messages found. Only one system message is allowed per conversation.".to_string(), None 
             ) );
           }
           system_instruction = Some( message.content.clone() );
@@ -148,7 +150,7 @@ impl ChatApi< '_ >
         },
         invalid_role => {
           return Err( crate::error::Error::InvalidArgument( 
-            format!( "Invalid message role '{invalid_role}' at index {index}. Valid roles are : 'user', 'assistant', 'system'." )
+            format!( "Invalid message role '{invalid_role}' at index {index}. Valid roles are : 'user', 'assistant', 'system'." ), None
           ) );
         },
       }
@@ -158,7 +160,7 @@ impl ChatApi< '_ >
     if !request.messages.iter().any( | msg | msg.role == "user" )
     {
       return Err( crate::error::Error::InvalidArgument( 
-        "Chat completion requires at least one user message to generate a response.".to_string() 
+        "Chat completion requires at least one user message to generate a response.".to_string(), None 
       ) );
     }
 
@@ -237,7 +239,7 @@ impl ChatApi< '_ >
     {
       return Err( crate::error::Error::ApiError( 
         format!( "No response candidates generated for model '{}'. This may indicate content filtering or server issues.", 
-          request.model )
+          request.model ), None
       ) );
     }
 
@@ -346,11 +348,11 @@ impl ChatApi< '_ >
       .await
       .map_err( | e | match e
       {
-        crate ::error::Error::ApiError( msg ) if msg.contains( "400" ) => 
+        crate ::error::Error::ApiError( msg, _ ) if msg.contains( "400" ) => 
           crate ::error::Error::InvalidArgument( 
-            format!( "Chat streaming request failed : {msg}. Please check message format and roles." )
+            format!( "Chat streaming request failed : {msg}. Please check message format and roles." ), None
           ),
-        crate ::error::Error::ApiError( msg ) if msg.contains( "401" ) || msg.contains( "403" ) => 
+        crate ::error::Error::ApiError( msg, _ ) if msg.contains( "401" ) || msg.contains( "403" ) => 
           crate ::error::Error::AuthenticationError( 
             format!( "Chat streaming authentication failed : {msg}. Please verify your API key has streaming permissions." )
           ),
@@ -464,3 +466,18 @@ impl ChatApi< '_ >
     } )
   }
 }
+
+#[ cfg( feature = "chat" ) ]
+impl< 'a > ChatApi< 'a >
+{
+  /// Start a multi-turn chat session for the given model.
+  ///
+  /// The returned [`ChatSession`] keeps the conversation history in memory and
+  /// sends it with every turn.
+  #[ inline ]
+  #[ must_use ]
+  pub fn session( &self, model : &str ) -> ChatSession< 'a >
+  {
+    ChatSession::new( self.client, model )
+  }
+}
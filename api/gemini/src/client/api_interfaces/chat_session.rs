@@ -0,0 +1,313 @@
+//! Multi-turn chat session that keeps conversation history between calls.
+
+#[ cfg( feature = "chat" ) ]
+use super::super::Client;
+
+/// Multi-turn conversation with history owned by the caller.
+///
+/// The session holds the `contents` history, model name, and default generation
+/// settings in memory only. Each [`ChatSession::send`] appends the user turn,
+/// calls `generateContent` with the full history, and appends the model reply.
+/// Nothing is persisted and no state is kept on the client.
+#[ cfg( feature = "chat" ) ]
+#[ derive( Debug ) ]
+pub struct ChatSession< 'a >
+{
+  pub( crate ) client : &'a Client,
+  pub( crate ) model : String,
+  pub( crate ) history : Vec< crate::models::Content >,
+  pub( crate ) generation_config : Option< crate::models::GenerationConfig >,
+  pub( crate ) keep_partial_on_error : bool,
+}
+
+#[ cfg( feature = "chat" ) ]
+impl< 'a > ChatSession< 'a >
+{
+  /// Create an empty session for the given model.
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( client : &'a Client, model : &str ) -> Self
+  {
+    Self
+    {
+      client,
+      model : model.to_string(),
+      history : Vec::new(),
+      generation_config : None,
+      keep_partial_on_error : false,
+    }
+  }
+
+  /// Set the generation config sent with every turn.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_generation_config( mut self, config : crate::models::GenerationConfig ) -> Self
+  {
+    self.generation_config = Some( config );
+    self
+  }
+
+  /// Keep the partial model turn in the history when a streamed reply fails mid-way.
+  ///
+  /// Disabled by default, in which case only the user turn of a failed stream is kept.
+  #[ inline ]
+  #[ must_use ]
+  pub fn keep_partial_on_error( mut self, keep : bool ) -> Self
+  {
+    self.keep_partial_on_error = keep;
+    self
+  }
+
+  /// Send a user message and record the model reply in the history.
+  ///
+  /// On failure the history is left unchanged, so the call can be retried.
+  ///
+  /// # Errors
+  ///
+  /// - [`crate::error::Error::InvalidArgument`] - Empty message text
+  /// - [`crate::error::Error::ApiError`] - The response contained no candidate
+  /// - Any error returned by `generate_content`
+  #[ inline ]
+  pub async fn send( &mut self, text : &str ) -> Result< crate::models::GenerateContentResponse, crate::error::Error >
+  {
+    if text.trim().is_empty()
+    {
+      return Err( crate::error::Error::InvalidArgument( "Chat message text cannot be empty".to_string(), None ) );
+    }
+
+    let request = self.request_with_user_turn( text );
+
+    let response = self.client
+      .models()
+      .by_name( &self.model )
+      .generate_content( &request )
+      .await?;
+
+    let reply = response.candidates
+      .first()
+      .map( | candidate | candidate.content.clone() )
+      .ok_or_else( || crate::error::Error::ApiError(
+        format!( "No candidate returned from model '{}' for chat turn", self.model ), None
+      ) )?;
+
+    self.history = request.contents;
+    self.history.push( crate::models::Content { role : "model".to_string(), ..reply } );
+    Ok( response )
+  }
+
+  /// Send a user message and stream the model reply as text fragments.
+  ///
+  /// The history is updated when the stream ends: after successful completion the
+  /// user turn and the assembled model turn are appended. If the stream yields an
+  /// error, the user turn is still recorded and the partial model turn is discarded
+  /// unless [`Self::keep_partial_on_error`] is enabled. Dropping the stream before it
+  /// ends leaves the history unchanged.
+  ///
+  /// # Errors
+  ///
+  /// - [`crate::error::Error::InvalidArgument`] - Empty message text
+  /// - Any error returned by `generate_content_stream` while opening the stream;
+  ///   the history is unchanged in that case
+  #[ cfg( feature = "streaming" ) ]
+  #[ inline ]
+  pub async fn send_streaming( &mut self, text : &str )
+  -> Result< impl futures::Stream< Item = Result< String, crate::error::Error > > + use< '_, 'a >, crate::error::Error >
+  {
+    if text.trim().is_empty()
+    {
+      return Err( crate::error::Error::InvalidArgument( "Chat message text cannot be empty".to_string(), None ) );
+    }
+
+    let request = self.request_with_user_turn( text );
+    let stream = self.client
+      .models()
+      .by_name( &self.model )
+      .generate_content_stream( &request )
+      .await?;
+
+    Ok( self.record_streamed_turn( request.contents, stream ) )
+  }
+
+  /// Forward text fragments from `stream`, committing `contents` plus the model turn when it ends.
+  #[ cfg( feature = "streaming" ) ]
+  pub( crate ) fn record_streamed_turn< S >( &mut self, contents : Vec< crate::models::Content >, stream : S )
+  -> impl futures::Stream< Item = Result< String, crate::error::Error > > + use< '_, 'a, S >
+  where
+    S : futures::Stream< Item = Result< crate::models::StreamingResponse, crate::error::Error > > + 'a,
+  {
+    use futures::StreamExt;
+
+    async_stream::stream!
+    {
+      let mut stream = Box::pin( stream );
+      let mut reply = String::new();
+
+      while let Some( chunk ) = stream.next().await
+      {
+        match chunk
+        {
+          Ok( chunk ) =>
+          {
+            let fragment : String = chunk.candidates
+              .as_ref()
+              .and_then( | candidates | candidates.first() )
+              .map( | candidate | candidate.content.parts.iter().filter_map( | part | part.text.as_deref() ).collect() )
+              .unwrap_or_default();
+            if !fragment.is_empty()
+            {
+              reply.push_str( &fragment );
+              yield Ok( fragment );
+            }
+          },
+          Err( error ) =>
+          {
+            let keep_partial = self.keep_partial_on_error && !reply.is_empty();
+            self.commit_turn( contents, keep_partial.then_some( reply ) );
+            yield Err( error );
+            return;
+          },
+        }
+      }
+
+      self.commit_turn( contents, Some( reply ) );
+    }
+  }
+
+  /// Replace the history with `contents` and append the model reply, if any.
+  #[ cfg( feature = "streaming" ) ]
+  fn commit_turn( &mut self, contents : Vec< crate::models::Content >, reply : Option< String > )
+  {
+    self.history = contents;
+    if let Some( reply ) = reply
+    {
+      self.history.push( crate::models::Content
+      {
+        parts : vec![ crate::models::Part { text : Some( reply ), ..Default::default() } ],
+        role : "model".to_string(),
+      } );
+    }
+  }
+
+  /// Build a request carrying the history followed by a new user turn.
+  fn request_with_user_turn( &self, text : &str ) -> crate::models::GenerateContentRequest
+  {
+    let mut contents = self.history.clone();
+    contents.push( crate::models::Content
+    {
+      parts : vec![ crate::models::Part { text : Some( text.to_string() ), ..Default::default() } ],
+      role : "user".to_string(),
+    } );
+
+    crate::models::GenerateContentRequest
+    {
+      contents,
+      generation_config : self.generation_config.clone(),
+      ..Default::default()
+    }
+  }
+
+  /// Conversation history, alternating user and model turns.
+  #[ inline ]
+  #[ must_use ]
+  pub fn history( &self ) -> &[ crate::models::Content ]
+  {
+    &self.history
+  }
+
+  /// Forget all turns while keeping the model and generation settings.
+  #[ inline ]
+  pub fn clear( &mut self )
+  {
+    self.history.clear();
+  }
+
+  /// Model name used for every turn.
+  #[ inline ]
+  #[ must_use ]
+  pub fn model( &self ) -> &str
+  {
+    &self.model
+  }
+}
+
+#[ cfg( all( test, feature = "chat", feature = "streaming" ) ) ]
+mod tests
+{
+  use super::*;
+  use futures::StreamExt;
+  use crate::models::{ Candidate, Content, Part, StreamingResponse };
+
+  fn chunk( text : &str ) -> Result< StreamingResponse, crate::error::Error >
+  {
+    Ok( StreamingResponse
+    {
+      candidates : Some( vec![ Candidate
+      {
+        content : Content { parts : vec![ Part { text : Some( text.to_string() ), ..Default::default() } ], role : "model".to_string() },
+        finish_reason : None,
+        safety_ratings : None,
+        citation_metadata : None,
+        token_count : None,
+        index : Some( 0 ),
+      } ] ),
+      usage_metadata : None,
+      prompt_feedback : None,
+      grounding_metadata : None,
+      is_final : Some( false ),
+      error : None,
+    } )
+  }
+
+  fn client() -> Client
+  {
+    Client::builder().api_key( "test-key".to_string() ).build().expect( "client should build" )
+  }
+
+  async fn run( session : &mut ChatSession< '_ >, chunks : Vec< Result< StreamingResponse, crate::error::Error > > ) -> Vec< Result< String, crate::error::Error > >
+  {
+    let contents = session.request_with_user_turn( "Tell me a story" ).contents;
+    session.record_streamed_turn( contents, futures::stream::iter( chunks ) ).collect().await
+  }
+
+  #[ tokio::test ]
+  async fn mid_stream_error_records_user_turn_only()
+  {
+    let client = client();
+    let mut session = ChatSession::new( &client, "gemini-2.5-flash" );
+
+    let items = run( &mut session, vec![ chunk( "Once" ), Err( crate::error::Error::NetworkError( "reset".to_string() ) ) ] ).await;
+
+    assert_eq!( items.len(), 2 );
+    assert!( items[ 1 ].is_err() );
+    assert_eq!( session.history().len(), 1 );
+    assert_eq!( session.history()[ 0 ].role, "user" );
+  }
+
+  #[ tokio::test ]
+  async fn mid_stream_error_keeps_partial_turn_when_configured()
+  {
+    let client = client();
+    let mut session = ChatSession::new( &client, "gemini-2.5-flash" ).keep_partial_on_error( true );
+
+    run( &mut session, vec![ chunk( "Once" ), chunk( " upon" ), Err( crate::error::Error::NetworkError( "reset".to_string() ) ) ] ).await;
+
+    assert_eq!( session.history().len(), 2 );
+    assert_eq!( session.history()[ 1 ].parts[ 0 ].text.as_deref(), Some( "Once upon" ) );
+  }
+
+  #[ tokio::test ]
+  async fn dropped_stream_leaves_history_unchanged()
+  {
+    let client = client();
+    let mut session = ChatSession::new( &client, "gemini-2.5-flash" );
+    let contents = session.request_with_user_turn( "Tell me a story" ).contents;
+
+    {
+      let stream = session.record_streamed_turn( contents, futures::stream::iter( vec![ chunk( "Once" ), chunk( " upon" ) ] ) );
+      futures::pin_mut!( stream );
+      assert_eq!( stream.next().await.unwrap().unwrap(), "Once" );
+    }
+
+    assert!( session.history().is_empty() );
+  }
+}
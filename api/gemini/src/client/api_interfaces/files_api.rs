@@ -94,7 +94,7 @@ impl FilesApi< '_ >
     {
       let status = response.status();
       let text = response.text().await.unwrap_or_else( |_| "Failed to read error response".to_string() );
-      Err( Error::ApiError( format!( "HTTP {status}: {text}" ) ) )
+      Err( Error::ApiError( format!( "HTTP {status}: {text}" ), None ) )
     }
   }
 
@@ -281,7 +281,7 @@ impl FilesApi< '_ >
     {
       let status = response.status();
       let text = response.text().await.unwrap_or_else( |_| "Failed to read error response".to_string() );
-      Err( Error::ApiError( format!( "HTTP {status}: {text}" ) ) )
+      Err( Error::ApiError( format!( "HTTP {status}: {text}" ), None ) )
     }
   }
 }
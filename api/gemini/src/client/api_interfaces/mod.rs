@@ -14,6 +14,9 @@ mod chat_api;
 #[ cfg( feature = "chat" ) ]
 mod conversation_builder;
 
+#[ cfg( feature = "chat" ) ]
+mod chat_session;
+
 pub use models_api::ModelsApi;
 pub use tuned_models_api::TunedModelsApi;
 pub use files_api::FilesApi;
@@ -24,3 +27,6 @@ pub use chat_api::ChatApi;
 
 #[ cfg( feature = "chat" ) ]
 pub use conversation_builder::{ ConversationBuilder, ConversationSummary };
+
+#[ cfg( feature = "chat" ) ]
+pub use chat_session::ChatSession;
@@ -248,13 +248,13 @@ use super::super::Client;
       // Validate input parameters
       if model_name.trim().is_empty()
       {
-        return Err( Error::InvalidArgument( "Model name cannot be empty".to_string() ) );
+        return Err( Error::InvalidArgument( "Model name cannot be empty".to_string(), None ) );
       }
 
       // Validate the request structure
       if let Err( validation_error ) = crate::validation::validate_batch_count_tokens_request( request )
       {
-        return Err( Error::InvalidArgument( format!( "Invalid request : {validation_error}" ) ) );
+        return Err( Error::InvalidArgument( format!( "Invalid request : {validation_error}" ), None ) );
       }
 
       let url = format!( "{}/v1beta/models/{model_name}:batchCountTokens", self.client.base_url );
@@ -285,7 +285,7 @@ use super::super::Client;
     ///
     /// Returns an [`AnalyzeTokensResponse`] containing:
     /// - `total_tokens`: Total token count across all content
-    /// - `breakdown`: Optional detailed breakdown by content type
+    /// - `breakdown`: Optional detailed breakdown by content type and by role
     /// - `cost_estimate`: Optional cost estimation based on token usage
     /// - `optimization_suggestions`: Optional suggestions for reducing token usage
     ///
@@ -347,18 +347,18 @@ use super::super::Client;
       // Validate input parameters
       if model_name.trim().is_empty()
       {
-        return Err( Error::InvalidArgument( "Model name cannot be empty".to_string() ) );
+        return Err( Error::InvalidArgument( "Model name cannot be empty".to_string(), None ) );
       }
 
       // Validate the request structure
       if let Err( validation_error ) = crate::validation::validate_analyze_tokens_request( request )
       {
-        return Err( Error::InvalidArgument( format!( "Invalid request : {validation_error}" ) ) );
+        return Err( Error::InvalidArgument( format!( "Invalid request : {validation_error}" ), None ) );
       }
 
       let url = format!( "{}/v1beta/models/{model_name}:analyzeTokens", self.client.base_url );
 
-      crate ::internal::http::execute_legacy::< crate::models::AnalyzeTokensRequest, crate::models::AnalyzeTokensResponse >
+      let mut response = crate ::internal::http::execute_legacy::< crate::models::AnalyzeTokensRequest, crate::models::AnalyzeTokensResponse >
       (
         &self.client.http,
         reqwest ::Method::POST,
@@ -366,7 +366,17 @@ use super::super::Client;
         &self.client.api_key,
         Some( request ),
       )
-      .await
+      .await?;
+
+      if let Some( breakdown ) = response.token_breakdown.as_mut()
+      {
+        if breakdown.by_role.is_none()
+        {
+          breakdown.by_role = Some( request.role_token_breakdown( response.total_tokens ) );
+        }
+      }
+
+      Ok( response )
     }
 
     /// Compare multiple models across various criteria.
@@ -435,7 +445,7 @@ use super::super::Client;
       // Validate the request structure
       if let Err( validation_error ) = crate::validation::validate_compare_models_request( request )
       {
-        return Err( Error::InvalidArgument( format!( "Invalid request : {validation_error}" ) ) );
+        return Err( Error::InvalidArgument( format!( "Invalid request : {validation_error}" ), None ) );
       }
 
       let url = format!( "{}/v1beta/models:compare", self.client.base_url );
@@ -515,7 +525,7 @@ use super::super::Client;
       // Validate the request structure
       if let Err( validation_error ) = crate::validation::validate_get_recommendations_request( request )
       {
-        return Err( Error::InvalidArgument( format!( "Invalid request : {validation_error}" ) ) );
+        return Err( Error::InvalidArgument( format!( "Invalid request : {validation_error}" ), None ) );
       }
 
       let url = format!( "{}/v1beta/models:recommend", self.client.base_url );
@@ -594,7 +604,7 @@ use super::super::Client;
       // Validate the request structure
       if let Err( validation_error ) = crate::validation::validate_advanced_filter_request( request )
       {
-        return Err( Error::InvalidArgument( format!( "Invalid request : {validation_error}" ) ) );
+        return Err( Error::InvalidArgument( format!( "Invalid request : {validation_error}" ), None ) );
       }
 
       let url = format!( "{}/v1beta/models:filter", self.client.base_url );
@@ -673,7 +683,7 @@ use super::super::Client;
       // Validate the request structure
       if let Err( validation_error ) = crate::validation::validate_model_status_request( request )
       {
-        return Err( Error::InvalidArgument( format!( "Invalid request : {validation_error}" ) ) );
+        return Err( Error::InvalidArgument( format!( "Invalid request : {validation_error}" ), None ) );
       }
 
       let url = format!( "{}/v1beta/models:status", self.client.base_url );
@@ -290,7 +290,7 @@ impl TunedModelsApi< '_ >
     else
     {
       let error_text = response.text().await.unwrap_or_else( |_| "Failed to read error response".to_string() );
-      Err( Error::ApiError( format!( "Failed to delete tuned model : {error_text}" ) ) )
+      Err( Error::ApiError( format!( "Failed to delete tuned model : {error_text}" ), None ) )
     }
   }
 }
@@ -27,6 +27,10 @@ mod presets;
     base_url : String,
     api_key : Option< String >,
     timeout : Duration,
+    pool_max_idle_per_host : Option< usize >,
+    pool_idle_timeout : Option< Duration >,
+    http2_prior_knowledge : bool,
+    proxy : Option< reqwest::Proxy >,
     #[ cfg( feature = "retry" ) ]
     max_retries : u32,
     #[ cfg( feature = "retry" ) ]
@@ -43,6 +47,8 @@ mod presets;
     enable_retry_metrics : bool,
     #[ cfg( feature = "retry" ) ]
     max_elapsed_time : Option< Duration >,
+    #[ cfg( feature = "retry" ) ]
+    retry_non_idempotent : bool,
     #[ cfg( feature = "circuit_breaker" ) ]
     enable_circuit_breaker : bool,
     #[ cfg( feature = "circuit_breaker" ) ]
@@ -73,8 +79,15 @@ mod presets;
     rate_limit_bucket_size : usize,
     #[ cfg( feature = "rate_limiting" ) ]
     enable_rate_limiting_metrics : bool,
+    #[ cfg( feature = "rate_limiting" ) ]
+    token_rate_limit : Option< u32 >,
     #[ cfg( feature = "compression" ) ]
     compression_config : Option< crate::internal::http::compression::CompressionConfig >,
+    request_signer : Option< std::sync::Arc< dyn crate::internal::http::RequestSigner > >,
+    extra_headers : Vec< ( String, String ) >,
+    interceptor : Option< std::sync::Arc< dyn crate::internal::http::Interceptor > >,
+    user_agent : Option< String >,
+    app_identity : Option< ( String, String ) >,
   }
 
   impl Default for ClientBuilder
@@ -98,6 +111,10 @@ mod presets;
           base_url : "https://generativelanguage.googleapis.com".to_string(),
           api_key : None,
           timeout : Duration::from_secs( 30 ),
+          pool_max_idle_per_host : None,
+          pool_idle_timeout : None,
+          http2_prior_knowledge : false,
+          proxy : None,
           #[ cfg( feature = "retry" ) ]
           max_retries : 3,
           #[ cfg( feature = "retry" ) ]
@@ -114,6 +131,8 @@ mod presets;
           enable_retry_metrics : false,
           #[ cfg( feature = "retry" ) ]
           max_elapsed_time : Some( Duration::from_secs( 60 ) ),
+          #[ cfg( feature = "retry" ) ]
+          retry_non_idempotent : false,
           #[ cfg( feature = "circuit_breaker" ) ]
           enable_circuit_breaker : false,
           #[ cfg( feature = "circuit_breaker" ) ]
@@ -144,8 +163,15 @@ mod presets;
           rate_limit_bucket_size : 10,
           #[ cfg( feature = "rate_limiting" ) ]
           enable_rate_limiting_metrics : false,
+          #[ cfg( feature = "rate_limiting" ) ]
+          token_rate_limit : None,
           #[ cfg( feature = "compression" ) ]
           compression_config : None,
+          request_signer : None,
+          extra_headers : Vec::new(),
+          interceptor : None,
+          user_agent : None,
+          app_identity : None,
         }
     }
 
@@ -166,6 +192,14 @@ mod presets;
           return Err( Error::AuthenticationError( "API key cannot be empty".to_string() ) );
         }
 
+        // Validate connection pool configuration
+        if self.pool_max_idle_per_host == Some( 0 )
+        {
+          return Err( Error::InvalidArgument(
+            "Pool max idle connections per host must be greater than 0".to_string(), None
+          ) );
+        }
+
         // Validate retry configuration when retry feature is enabled
         #[ cfg( feature = "retry" ) ]
         {
@@ -173,7 +207,7 @@ mod presets;
           if self.backoff_multiplier <= 1.0
           {
             return Err( Error::InvalidArgument(
-              format!( "Backoff multiplier must be greater than 1.0, got : {0}", self.backoff_multiplier )
+              format!( "Backoff multiplier must be greater than 1.0, got : {0}", self.backoff_multiplier ), None
             ) );
           }
 
@@ -181,7 +215,7 @@ mod presets;
           if self.base_delay >= self.max_delay
           {
             return Err( Error::InvalidArgument(
-              "Base delay must be less than max delay".to_string()
+              "Base delay must be less than max delay".to_string(), None
             ) );
           }
         }
@@ -195,7 +229,7 @@ mod presets;
             if self.circuit_breaker_failure_threshold == 0
             {
               return Err( Error::InvalidArgument(
-                "Circuit breaker failure threshold must be greater than 0".to_string()
+                "Circuit breaker failure threshold must be greater than 0".to_string(), None
               ) );
             }
 
@@ -203,7 +237,7 @@ mod presets;
             if self.circuit_breaker_success_threshold == 0
             {
               return Err( Error::InvalidArgument(
-                "Circuit breaker success threshold must be greater than 0".to_string()
+                "Circuit breaker success threshold must be greater than 0".to_string(), None
               ) );
             }
 
@@ -211,7 +245,7 @@ mod presets;
             if self.circuit_breaker_timeout.is_zero()
             {
               return Err( Error::InvalidArgument(
-                "Circuit breaker timeout must be greater than 0".to_string()
+                "Circuit breaker timeout must be greater than 0".to_string(), None
               ) );
             }
           }
@@ -226,7 +260,7 @@ mod presets;
             if self.cache_ttl.is_zero()
             {
               return Err( Error::InvalidArgument(
-                "Cache TTL must be greater than 0".to_string()
+                "Cache TTL must be greater than 0".to_string(), None
               ) );
             }
 
@@ -234,7 +268,7 @@ mod presets;
             if self.cache_max_size == 0
             {
               return Err( Error::InvalidArgument(
-                "Cache max size must be greater than 0".to_string()
+                "Cache max size must be greater than 0".to_string(), None
               ) );
             }
           }
@@ -243,13 +277,20 @@ mod presets;
         // Validate rate limiting configuration when rate limiting feature is enabled
         #[ cfg( feature = "rate_limiting" ) ]
         {
+          if self.token_rate_limit == Some( 0 )
+          {
+            return Err( Error::InvalidArgument(
+              "Token rate limit must be greater than 0 tokens per minute".to_string(), None
+            ) );
+          }
+
           if self.enable_rate_limiting
           {
             // Validate requests per second
             if self.rate_limit_requests_per_second <= 0.0
             {
               return Err( Error::InvalidArgument(
-                "Rate limit requests per second must be greater than 0.0".to_string()
+                "Rate limit requests per second must be greater than 0.0".to_string(), None
               ) );
             }
 
@@ -257,7 +298,7 @@ mod presets;
             if self.rate_limit_bucket_size == 0
             {
               return Err( Error::InvalidArgument(
-                "Rate limit bucket size must be greater than 0".to_string()
+                "Rate limit bucket size must be greater than 0".to_string(), None
               ) );
             }
 
@@ -267,15 +308,56 @@ mod presets;
               "token_bucket" | "sliding_window" | "adaptive" => {},
               invalid => {
                 return Err( Error::InvalidArgument(
-                  format!( "Invalid rate limiting algorithm '{invalid}'. Valid options : 'token_bucket', 'sliding_window', 'adaptive'" )
+                  format!( "Invalid rate limiting algorithm '{invalid}'. Valid options : 'token_bucket', 'sliding_window', 'adaptive'" ), None
                 ) );
               }
             }
           }
         }
 
-        let http_client = reqwest::Client::builder()
-          .timeout( self.timeout )
+        let mut user_agent = self.user_agent.unwrap_or_else( || crate::internal::http::DEFAULT_USER_AGENT.to_string() );
+        if let Some( ( name, version ) ) = &self.app_identity
+        {
+          user_agent = format!( "{user_agent} {name}/{version}" );
+        }
+        if reqwest::header::HeaderValue::from_str( &user_agent ).is_err()
+        {
+          return Err( Error::InvalidArgument(
+            format!( "User-Agent '{user_agent}' is not a valid header value" ), None
+          ) );
+        }
+
+        let mut http_builder = reqwest::Client::builder()
+          .timeout( self.timeout );
+
+        if let Some( max_idle ) = self.pool_max_idle_per_host
+        {
+          http_builder = http_builder.pool_max_idle_per_host( max_idle );
+        }
+
+        if let Some( idle_timeout ) = self.pool_idle_timeout
+        {
+          http_builder = http_builder.pool_idle_timeout( idle_timeout );
+        }
+
+        if self.http2_prior_knowledge
+        {
+          http_builder = http_builder.http2_prior_knowledge();
+        }
+
+        if let Some( proxy ) = self.proxy
+        {
+          http_builder = http_builder.proxy( proxy );
+        }
+
+        #[ cfg( feature = "diagnostics_timing" ) ]
+        {
+          http_builder = http_builder
+            .dns_resolver( std::sync::Arc::new( crate::internal::http::TimingResolver ) )
+            .connector_layer( crate::internal::http::ConnectTimingLayer );
+        }
+
+        let http_client = http_builder
           .build()
           .map_err( |e| Error::NetworkError( format!( "Failed to create HTTP client : {e}" ) ) )?;
 
@@ -293,6 +375,30 @@ mod presets;
           None
         };
 
+        #[ cfg( feature = "circuit_breaker" ) ]
+        let circuit_breaker = ( self.enable_circuit_breaker && self.circuit_breaker_failure_threshold > 0 ).then( ||
+        {
+          let config = crate::internal::http::CircuitBreakerConfig {
+            failure_threshold : self.circuit_breaker_failure_threshold,
+            timeout : self.circuit_breaker_timeout,
+            success_threshold : self.circuit_breaker_success_threshold,
+            enable_metrics : self.enable_circuit_breaker_metrics,
+          };
+          crate::internal::http::ClientCircuitBreaker::new( config, self.circuit_breaker_shared_state )
+        } );
+
+        #[ cfg( feature = "rate_limiting" ) ]
+        let rate_limiter = self.enable_rate_limiting.then( ||
+        {
+          let config = crate::internal::http::RateLimitingConfig {
+            requests_per_second : self.rate_limit_requests_per_second,
+            bucket_size : self.rate_limit_bucket_size,
+            algorithm : self.rate_limit_algorithm.clone(),
+            enable_metrics : self.enable_rate_limiting_metrics,
+          };
+          std::sync::Arc::new( crate::internal::http::RateLimit::new( config ) )
+        } );
+
         Ok( Client
         {
           api_key,
@@ -315,6 +421,8 @@ mod presets;
           enable_retry_metrics : self.enable_retry_metrics,
           #[ cfg( feature = "retry" ) ]
           max_elapsed_time : self.max_elapsed_time,
+          #[ cfg( feature = "retry" ) ]
+          retry_non_idempotent : self.retry_non_idempotent,
           #[ cfg( feature = "circuit_breaker" ) ]
           enable_circuit_breaker : self.enable_circuit_breaker,
           #[ cfg( feature = "circuit_breaker" ) ]
@@ -327,6 +435,8 @@ mod presets;
           enable_circuit_breaker_metrics : self.enable_circuit_breaker_metrics,
           #[ cfg( feature = "circuit_breaker" ) ]
           circuit_breaker_shared_state : self.circuit_breaker_shared_state,
+          #[ cfg( feature = "circuit_breaker" ) ]
+          circuit_breaker,
           #[ cfg( feature = "caching" ) ]
           enable_request_cache : self.enable_request_cache,
           #[ cfg( feature = "caching" ) ]
@@ -347,8 +457,17 @@ mod presets;
           rate_limit_bucket_size : self.rate_limit_bucket_size,
           #[ cfg( feature = "rate_limiting" ) ]
           enable_rate_limiting_metrics : self.enable_rate_limiting_metrics,
+          #[ cfg( feature = "rate_limiting" ) ]
+          rate_limiter,
+          #[ cfg( feature = "rate_limiting" ) ]
+          token_rate_limiter : self.token_rate_limit.map( | tpm | std::sync::Arc::new( crate::internal::http::TokenRateLimiter::new( tpm ) ) ),
           #[ cfg( feature = "compression" ) ]
           compression_config : self.compression_config,
+          request_signer : self.request_signer,
+          extra_headers : self.extra_headers,
+          interceptor : self.interceptor,
+          user_agent,
+          default_model : None,
         } )
     }
   }
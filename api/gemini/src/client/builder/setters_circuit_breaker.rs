@@ -83,9 +83,9 @@ impl ClientBuilder
 
   /// Enables or disables shared circuit breaker state.
   ///
-  /// When enabled, circuit breaker state will be shared across
-  /// multiple client instances. When disabled, each client has
-  /// its own isolated circuit state.
+  /// When enabled, all clones of the built client share one circuit
+  /// breaker, so a circuit opened through one handle is open for all.
+  /// When disabled, each clone starts with its own closed breaker.
   #[ must_use ]
   #[ inline ]
   pub fn circuit_breaker_shared_state( mut self, shared : bool ) -> Self
@@ -38,7 +38,7 @@ impl ClientBuilder
   /// Available algorithms:
   /// - "`token_bucket"`: Token bucket with burst capacity
   /// - "`sliding_window"`: Sliding window algorithm
-  /// - "adaptive": Adaptive rate limiting based on response times
+  /// - "adaptive": Token bucket that slows down on 429 responses and recovers on success
   ///
   /// # Arguments
   ///
@@ -67,6 +67,19 @@ impl ClientBuilder
     self
   }
 
+  /// Limits estimated token usage to `tokens_per_minute`.
+  ///
+  /// The budget is shared by all clones of the built client. Reserve tokens with
+  /// `Client::acquire_tokens` before each request, passing your own estimate.
+  /// Independent of `enable_rate_limiting`, which limits requests per second.
+  #[ must_use ]
+  #[ inline ]
+  pub fn with_token_rate_limit( mut self, tokens_per_minute : u32 ) -> Self
+  {
+    self.token_rate_limit = Some( tokens_per_minute );
+    self
+  }
+
   /// Enables or disables rate limiting metrics collection.
   ///
   /// When enabled, collects metrics about:
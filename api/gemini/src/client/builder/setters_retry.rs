@@ -97,4 +97,18 @@ impl ClientBuilder
     self.max_elapsed_time = Some( max_elapsed_time );
     self
   }
+
+  /// Allows retrying requests that are not known to be idempotent.
+  ///
+  /// By default only idempotent calls are replayed after a transient failure:
+  /// `GET` requests and side-effect free `POST` calls such as content generation.
+  /// Enabling this also retries mutations (uploads, job creation), which may
+  /// duplicate side effects if the original request reached the server.
+  #[ must_use ]
+  #[ inline ]
+  pub fn retry_non_idempotent( mut self, retry_non_idempotent : bool ) -> Self
+  {
+    self.retry_non_idempotent = retry_non_idempotent;
+    self
+  }
 }
@@ -157,21 +157,26 @@ use super::Client;
         if self.backoff_multiplier <= 1.0
         {
           return Err( Error::InvalidArgument(
-            format!( "Backoff multiplier must be greater than 1.0, got : {0}", self.backoff_multiplier )
+            format!( "Backoff multiplier must be greater than 1.0, got : {0}", self.backoff_multiplier ), None
           ) );
         }
 
         if self.base_delay >= self.max_delay
         {
           return Err( Error::InvalidArgument(
-            "Base delay must be less than max delay".to_string()
+            "Base delay must be less than max delay".to_string(), None
           ) );
         }
       }
 
       // Build HTTP client
-      let http = reqwest::Client::builder()
-        .timeout( self.timeout )
+      let http_builder = reqwest::Client::builder()
+        .timeout( self.timeout );
+      #[ cfg( feature = "diagnostics_timing" ) ]
+      let http_builder = http_builder
+        .dns_resolver( std::sync::Arc::new( crate::internal::http::TimingResolver ) )
+        .connector_layer( crate::internal::http::ConnectTimingLayer );
+      let http = http_builder
         .build()
         .map_err( | e | Error::NetworkError( format!( "Failed to create HTTP client : {e}" ) ) )?;
 
@@ -189,6 +194,30 @@ use super::Client;
         None
       };
 
+      #[ cfg( feature = "circuit_breaker" ) ]
+      let circuit_breaker = ( self.enable_circuit_breaker && self.circuit_breaker_failure_threshold > 0 ).then( ||
+      {
+        let config = crate::internal::http::CircuitBreakerConfig {
+          failure_threshold : self.circuit_breaker_failure_threshold,
+          timeout : self.circuit_breaker_timeout,
+          success_threshold : self.circuit_breaker_success_threshold,
+          enable_metrics : false, // Simplified for former version
+        };
+        crate::internal::http::ClientCircuitBreaker::new( config, false )
+      } );
+
+      #[ cfg( feature = "rate_limiting" ) ]
+      let rate_limiter = ( self.enable_rate_limiting && self.rate_limit_requests_per_second > 0.0 ).then( ||
+      {
+        let config = crate::internal::http::RateLimitingConfig {
+          requests_per_second : self.rate_limit_requests_per_second,
+          bucket_size : self.rate_limit_bucket_size,
+          algorithm : self.rate_limit_algorithm.clone(),
+          enable_metrics : false, // Simplified for former version
+        };
+        std::sync::Arc::new( crate::internal::http::RateLimit::new( config ) )
+      } );
+
       // Create the client instance
       Ok( Client
       {
@@ -212,6 +241,8 @@ use super::Client;
         enable_retry_metrics : self.enable_retry_metrics,
         #[ cfg( feature = "retry" ) ]
         max_elapsed_time : self.max_elapsed_time,
+        #[ cfg( feature = "retry" ) ]
+        retry_non_idempotent : false, // Not configurable in former version for simplicity
         #[ cfg( feature = "circuit_breaker" ) ]
         enable_circuit_breaker : self.enable_circuit_breaker,
         #[ cfg( feature = "circuit_breaker" ) ]
@@ -224,6 +255,8 @@ use super::Client;
         enable_circuit_breaker_metrics : false, // Simplified for former version
         #[ cfg( feature = "circuit_breaker" ) ]
         circuit_breaker_shared_state : false, // Simplified for former version
+        #[ cfg( feature = "circuit_breaker" ) ]
+        circuit_breaker,
         #[ cfg( feature = "caching" ) ]
         enable_request_cache : self.enable_request_cache,
         #[ cfg( feature = "caching" ) ]
@@ -244,8 +277,17 @@ use super::Client;
         rate_limit_bucket_size : self.rate_limit_bucket_size,
         #[ cfg( feature = "rate_limiting" ) ]
         enable_rate_limiting_metrics : false, // Simplified for former version
+        #[ cfg( feature = "rate_limiting" ) ]
+        rate_limiter,
+        #[ cfg( feature = "rate_limiting" ) ]
+        token_rate_limiter : None,
         #[ cfg( feature = "compression" ) ]
         compression_config : None, // Not configurable in former version for simplicity
+        request_signer : None,
+        extra_headers : Vec::new(),
+        interceptor : None,
+        user_agent : crate::internal::http::DEFAULT_USER_AGENT.to_string(),
+        default_model : None,
       } )
     }
   }
@@ -68,6 +68,8 @@ use super::sync::SyncClientBuilder;
     pub( crate ) enable_retry_metrics : bool,
     #[ cfg( feature = "retry" ) ]
     pub( crate ) max_elapsed_time : Option< Duration >,
+    #[ cfg( feature = "retry" ) ]
+    pub( crate ) retry_non_idempotent : bool,
     #[ cfg( feature = "circuit_breaker" ) ]
     // xxx : @team : Integrate circuit breaker from internal/http.rs into Client API
     // Circuit breaker is fully implemented in internal::http::CircuitBreaker
@@ -88,9 +90,11 @@ use super::sync::SyncClientBuilder;
     #[ allow( dead_code ) ]
     pub( crate ) enable_circuit_breaker_metrics : bool,
     #[ cfg( feature = "circuit_breaker" ) ]
-    // xxx : @team : Implement Arc-based shared circuit breaker state across client instances
     #[ allow( dead_code ) ]
     pub( crate ) circuit_breaker_shared_state : bool,
+    #[ cfg( feature = "circuit_breaker" ) ]
+    /// Circuit breaker consulted before each request, present when enabled
+    pub( crate ) circuit_breaker : Option< crate::internal::http::ClientCircuitBreaker >,
     #[ cfg( feature = "caching" ) ]
     // xxx : @team : Implement general HTTP response caching layer
     // Domain-specific caches exist (WorkspaceCache, SemanticCache, MediaCache)
@@ -126,12 +130,27 @@ use super::sync::SyncClientBuilder;
     #[ allow( dead_code ) ]
     pub( crate ) rate_limit_bucket_size : usize,
     #[ cfg( feature = "rate_limiting" ) ]
-    // xxx : @team : Expose rate limiting metrics through Client::get_rate_limiter_metrics()
     #[ allow( dead_code ) ]
     pub( crate ) enable_rate_limiting_metrics : bool,
+    #[ cfg( feature = "rate_limiting" ) ]
+    /// Request rate limiter shared by all clones of the client, present when enabled
+    pub( crate ) rate_limiter : Option< std::sync::Arc< crate::internal::http::RateLimit > >,
+    #[ cfg( feature = "rate_limiting" ) ]
+    /// Tokens-per-minute budget shared by all clones of the client
+    pub( crate ) token_rate_limiter : Option< std::sync::Arc< crate::internal::http::TokenRateLimiter > >,
     #[ cfg( feature = "compression" ) ]
     /// Compression configuration for request/response optimization
     pub( crate ) compression_config : Option< crate::internal::http::compression::CompressionConfig >,
+    /// Signer adding authentication headers to every request
+    pub( crate ) request_signer : Option< std::sync::Arc< dyn crate::internal::http::RequestSigner > >,
+    /// Additional headers sent with every request
+    pub( crate ) extra_headers : Vec< ( String, String ) >,
+    /// Interceptor invoked around every request
+    pub( crate ) interceptor : Option< std::sync::Arc< dyn crate::internal::http::Interceptor > >,
+    /// `User-Agent` header sent with every request
+    pub( crate ) user_agent : String,
+    /// Model used by [`Client::generate`]
+    pub( crate ) default_model : Option< String >,
   }
 
   impl Client
@@ -159,6 +178,24 @@ use super::sync::SyncClientBuilder;
         SyncClientBuilder::new()
     }
 
+      /// Create a client whose requests are answered by `mock` instead of the network.
+      ///
+      /// The client uses a placeholder API key and the default configuration. To combine
+      /// the mock with other settings, pass it to [`ClientBuilder::with_interceptor`].
+      ///
+      /// # Errors
+      ///
+      /// Returns an error if the client cannot be built.
+    #[ cfg( feature = "testing" ) ]
+    #[ inline ]
+    pub fn with_mock_transport( mock : crate::testing::MockTransport ) -> Result< Client, Error >
+    {
+        Self::builder()
+        .api_key( "mock-api-key".to_string() )
+        .with_interceptor( mock )
+        .build()
+    }
+
       /// Create a new client using the `GEMINI_API_KEY` from workspace secrets or environment.
       ///
       /// This method attempts to load the API key in the following order:
@@ -217,6 +254,146 @@ use super::sync::SyncClientBuilder;
           .build()
     }
 
+      /// Returns a clone of this client that uses `model` for [`Client::generate`].
+      ///
+      /// The clone shares the HTTP connection pool and all configuration. Explicit
+      /// `models().by_name()` calls are unaffected by the default.
+      ///
+      /// # Examples
+      ///
+      /// ```rust,no_run
+      /// use api_gemini::client::Client;
+      ///
+      /// let client = Client::new()?;
+      /// let flash = client.with_default_model( "gemini-2.5-flash" );
+      /// assert_eq!( flash.default_model(), Some( "gemini-2.5-flash" ) );
+      /// # Ok::<(), Box< dyn std::error::Error > >(())
+      /// ```
+    #[ must_use ]
+    #[ inline ]
+    pub fn with_default_model( &self, model : impl Into< String > ) -> Client
+    {
+        Client
+        {
+          default_model : Some( model.into() ),
+          ..self.clone()
+        }
+    }
+
+      /// The default model set by [`Client::with_default_model`], if any.
+    #[ must_use ]
+    #[ inline ]
+    pub fn default_model( &self ) -> Option< &str >
+    {
+        self.default_model.as_deref()
+    }
+
+      /// Current state of this client's circuit breaker, `None` when it is disabled.
+      ///
+      /// With `circuit_breaker_shared_state` enabled, all clones of the client report
+      /// the same state.
+    #[ cfg( feature = "circuit_breaker" ) ]
+    #[ must_use ]
+    #[ inline ]
+    pub fn circuit_breaker_state( &self ) -> Option< crate::internal::http::CircuitBreakerState >
+    {
+        self.circuit_breaker.as_ref().map( | handle | handle.breaker().state() )
+    }
+
+      /// Metrics of this client's request rate limiter, `None` when rate limiting is disabled.
+      ///
+      /// Under the adaptive algorithm, `effective_rate` reflects the 429 responses seen
+      /// by this client and its clones.
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ must_use ]
+    #[ inline ]
+    pub fn rate_limiter_metrics( &self ) -> Option< crate::internal::http::RateLimitingMetrics >
+    {
+        self.rate_limiter.as_ref().map( | limiter | limiter.get_metrics() )
+    }
+
+      /// Token budget set with `ClientBuilder::with_token_rate_limit`, if any.
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ must_use ]
+    #[ inline ]
+    pub fn token_rate_limiter( &self ) -> Option< &crate::internal::http::TokenRateLimiter >
+    {
+        self.token_rate_limiter.as_deref()
+    }
+
+      /// Reserve `estimated` tokens from the per-minute budget before sending a request.
+      ///
+      /// Waits until the budget has room. Returns immediately when no token rate limit
+      /// is configured. The estimate is the caller's; the client does not count tokens.
+      ///
+      /// # Errors
+      ///
+      /// Returns `Error::InvalidArgument` if `estimated` exceeds the whole budget.
+    #[ cfg( feature = "rate_limiting" ) ]
+    #[ inline ]
+    pub async fn acquire_tokens( &self, estimated : u32 ) -> Result< (), Error >
+    {
+        match &self.token_rate_limiter
+        {
+          Some( limiter ) => limiter.acquire_tokens( estimated ).await,
+          None => Ok( () ),
+        }
+    }
+
+      /// Generate content with the default model.
+      ///
+      /// Equivalent to `self.models().by_name( default ).generate_content( request )`.
+      ///
+      /// # Errors
+      ///
+      /// Returns `Error::InvalidArgument` if no default model is set, otherwise any
+      /// error returned by `ModelApi::generate_content`.
+    #[ inline ]
+    pub async fn generate
+    (
+      &self,
+      request : &crate::models::GenerateContentRequest,
+    )
+    -> Result< crate::models::GenerateContentResponse, Error >
+    {
+        let Some( model ) = self.default_model.as_deref() else
+        {
+          return Err( Error::InvalidArgument(
+            "No default model set. Use Client::with_default_model or models().by_name()".to_string(), None
+          ) );
+        };
+
+        self.models().by_name( model ).generate_content( request ).await
+    }
+
+      /// Generate content for many requests, running at most `max_concurrent` at a time.
+      ///
+      /// Each request is sent to the model named by its tag, so the same prompt can be
+      /// fanned out to several models or many prompts sent to one. Results are returned
+      /// in input order, paired with their tag; a failed request does not affect the
+      /// others. A `max_concurrent` of 0 is treated as 1.
+    #[ inline ]
+    pub async fn generate_batch
+    (
+      &self,
+      requests : Vec< ( String, crate::models::GenerateContentRequest ) >,
+      max_concurrent : usize,
+    )
+    -> Vec< ( String, Result< crate::models::GenerateContentResponse, Error > ) >
+    {
+        use futures::StreamExt;
+
+        futures::stream::iter( requests )
+        .map( | ( model, request ) | async move
+        {
+          let result = self.models().by_name( &model ).generate_content( &request ).await;
+          ( model, result )
+        } )
+        .buffered( max_concurrent.max( 1 ) )
+        .collect()
+        .await
+    }
+
       /// Load API key from workspace `secret/-secrets.sh` file using `workspace_tools`.
       ///
       /// This method uses `workspace_tools` to properly locate and load secrets from the
@@ -326,6 +503,9 @@ use super::sync::SyncClientBuilder;
 
       /// Handle API error responses and convert to appropriate Error types
       ///
+      /// When `message` is a Gemini error body, its parsed details are attached to
+      /// the returned error.
+      ///
       /// # Errors
       ///
       /// Always returns an error based on the provided status code and message.
@@ -340,11 +520,15 @@ use super::sync::SyncClientBuilder;
         {
           format!( "{status} {status_text}: {message}" )
         };
+        let details = serde_json::from_str::< crate::error::ApiErrorResponse >( message )
+          .ok()
+          .map( | response | Box::new( response.error ) );
 
         match status
         {
+          429 => Err( Error::RateLimitError( error_message, details ) ),
           500..=599 => Err( Error::ServerError( error_message ) ),
-          _ => Err( Error::ApiError( error_message ) ),
+          _ => Err( Error::ApiError( error_message, details ) ),
         }
     }
 
@@ -363,6 +547,7 @@ use super::sync::SyncClientBuilder;
           backoff_multiplier : self.backoff_multiplier,
           enable_jitter : self.enable_jitter,
           max_elapsed_time : self.max_elapsed_time,
+          retry_non_idempotent : self.retry_non_idempotent,
         } )
       }
     }
@@ -371,17 +556,12 @@ use super::sync::SyncClientBuilder;
     #[ cfg( feature = "circuit_breaker" ) ]
     pub( crate ) fn to_circuit_breaker_config( &self ) -> Option< crate::internal::http::CircuitBreakerConfig >
     {
-      if self.circuit_breaker_failure_threshold == 0
-      {
-        None
-      } else {
-        Some( crate::internal::http::CircuitBreakerConfig {
-          failure_threshold : self.circuit_breaker_failure_threshold,
-          timeout : self.circuit_breaker_timeout,
-          success_threshold : self.circuit_breaker_success_threshold,
-          enable_metrics : self.enable_circuit_breaker_metrics,
-        } )
-      }
+      ( self.enable_circuit_breaker && self.circuit_breaker_failure_threshold > 0 ).then( || crate::internal::http::CircuitBreakerConfig {
+        failure_threshold : self.circuit_breaker_failure_threshold,
+        timeout : self.circuit_breaker_timeout,
+        success_threshold : self.circuit_breaker_success_threshold,
+        enable_metrics : self.enable_circuit_breaker_metrics,
+      } )
     }
 
     /// Convert client rate limiting configuration into HTTP layer `RateLimitingConfig`
@@ -28,7 +28,7 @@ impl Client
   #[ inline ]
   pub fn current_config( &self ) -> crate::models::config::DynamicConfig
   {
-      crate ::models::config::DynamicConfig::builder()
+      let builder = crate::models::config::DynamicConfig::builder()
           .timeout( self.timeout )
           .retry_attempts( {
               #[ cfg( feature = "retry" ) ]
@@ -61,7 +61,27 @@ impl Client
               #[ cfg( not( feature = "retry" ) ) ]
               { 2.0 }
           } )
-          .source_priority( 60 ) // Client config has medium-high priority
+          .source_priority( 60 ); // Client config has medium-high priority
+
+      // Reliability settings are reported only while the component is active
+      #[ cfg( feature = "circuit_breaker" ) ]
+      let builder = match &self.circuit_breaker
+      {
+          Some( _ ) => builder
+              .circuit_breaker_failure_threshold( self.circuit_breaker_failure_threshold )
+              .circuit_breaker_timeout( self.circuit_breaker_timeout ),
+          None => builder,
+      };
+      #[ cfg( feature = "rate_limiting" ) ]
+      let builder = match self.to_rate_limiting_config()
+      {
+          Some( config ) => builder
+              .rate_limit_requests_per_second( config.requests_per_second )
+              .rate_limit_bucket_size( config.bucket_size ),
+          None => builder,
+      };
+
+      builder
           .build()
           .unwrap_or_else( | _ | crate::models::config::DynamicConfig::default() )
   }
@@ -78,6 +98,9 @@ impl Client
       // Update the base URL
       self.base_url = config.base_url;
 
+      // The HTTP layer applies the client timeout per request, so the shared HTTP client is reused
+      self.timeout = config.timeout;
+
       // Update retry configuration if retry feature is enabled
       #[ cfg( feature = "retry" ) ]
       {
@@ -88,8 +111,36 @@ impl Client
           self.backoff_multiplier = config.backoff_multiplier;
       }
 
-      // Note : timeout would need to be applied to the HTTP client
-      // For now, we store the configuration but don't recreate the HTTP client
+      #[ cfg( feature = "circuit_breaker" ) ]
+      {
+          let failure_threshold = config.circuit_breaker_failure_threshold.unwrap_or( self.circuit_breaker_failure_threshold );
+          let timeout = config.circuit_breaker_timeout.unwrap_or( self.circuit_breaker_timeout );
+          if ( failure_threshold, timeout ) != ( self.circuit_breaker_failure_threshold, self.circuit_breaker_timeout )
+          {
+              self.circuit_breaker_failure_threshold = failure_threshold;
+              self.circuit_breaker_timeout = timeout;
+              // The breaker holds its thresholds, so a new one is needed; it starts closed
+              self.circuit_breaker = self.to_circuit_breaker_config()
+                  .map( | breaker_config | crate::internal::http::ClientCircuitBreaker::new( breaker_config, self.circuit_breaker_shared_state ) );
+          }
+      }
+
+      #[ cfg( feature = "rate_limiting" ) ]
+      {
+          let requests_per_second = config.rate_limit_requests_per_second.unwrap_or( self.rate_limit_requests_per_second );
+          let bucket_size = config.rate_limit_bucket_size.unwrap_or( self.rate_limit_bucket_size );
+          if ( requests_per_second, bucket_size ) != ( self.rate_limit_requests_per_second, self.rate_limit_bucket_size )
+          {
+              self.rate_limit_requests_per_second = requests_per_second;
+              self.rate_limit_bucket_size = bucket_size;
+              // The limiter holds its rate, so a new one is needed; it starts with a full bucket
+              if self.rate_limiter.is_some()
+              {
+                  self.rate_limiter = self.to_rate_limiting_config()
+                      .map( | limiter_config | std::sync::Arc::new( crate::internal::http::RateLimit::new( limiter_config ) ) );
+              }
+          }
+      }
 
       Ok( () )
   }
@@ -31,7 +31,7 @@ mod private
 
   #[ cfg( feature = "chat" ) ]
   pub use super::api_interfaces::{
-    ChatApi, ConversationBuilder, ConversationSummary,
+    ChatApi, ConversationBuilder, ConversationSummary, ChatSession,
   };
 }
 
@@ -54,4 +54,6 @@ mod private
   exposed use private::ConversationBuilder;
   #[ cfg( feature = "chat" ) ]
   exposed use private::ConversationSummary;
+  #[ cfg( feature = "chat" ) ]
+  exposed use private::ChatSession;
 }
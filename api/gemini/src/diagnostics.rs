@@ -10,6 +10,7 @@
 //! - **Pretty-formatted Output**: Readable curl commands with proper JSON formatting
 //! - **API Key Placeholders**: Clear indication of where API keys should be added
 //! - **Error Resilience**: Robust handling of serialization failures
+//! - **Compact Media**: Inline data payloads are shown as `<N bytes>` instead of raw base64
 //! 
 //! # Usage
 //!
@@ -47,7 +48,7 @@ pub trait AsCurl
   fn as_curl_with_options( &self, options : &CurlOptions ) -> String;
 }
 
-use crate::models::{ GenerateContentRequest, EmbedContentRequest, Blob };
+use crate::models::{ GenerateContentRequest, EmbedContentRequest, Blob, Part };
 
 /// Type alias for compatibility with test code
 pub type InlineData = Blob;
@@ -202,6 +203,22 @@ mod curl_helpers
     }
   }
   
+  /// Replace inline data payloads with their decoded size
+  ///
+  /// Media payloads make curl commands unwieldy, and malformed base64 is shown
+  /// as such rather than passed through verbatim.
+  pub fn summarize_inline_data( parts : &mut [ Part ] )
+  {
+    for blob in parts.iter_mut().filter_map( | part | part.inline_data.as_mut() )
+    {
+      blob.data = match blob.validate()
+      {
+        Ok( size ) => format!( "<{size} bytes>" ),
+        Err( _ ) => format!( "<invalid base64, {} chars>", blob.data.len() ),
+      };
+    }
+  }
+
   /// Handle JSON serialization with error recovery
   pub fn safe_json_serialize< T: serde::Serialize >( value : &T ) -> String
   {
@@ -223,7 +240,16 @@ impl AsCurl for GenerateContentRequest
   fn as_curl_with_options( &self, options : &CurlOptions ) -> String
   {
     let url = "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.5-flash:generateContent";
-    let json_body = curl_helpers::safe_json_serialize( self );
+    let mut request = self.clone();
+    for content in &mut request.contents
+    {
+      curl_helpers ::summarize_inline_data( &mut content.parts );
+    }
+    if let Some( instruction ) = &mut request.system_instruction
+    {
+      curl_helpers ::summarize_inline_data( &mut instruction.parts );
+    }
+    let json_body = curl_helpers::safe_json_serialize( &request );
     curl_helpers ::generate_curl_command( url, &json_body, options )
   }
 }
@@ -240,7 +266,9 @@ impl AsCurl for EmbedContentRequest
   fn as_curl_with_options( &self, options : &CurlOptions ) -> String
   {
     let url = "https://generativelanguage.googleapis.com/v1beta/models/text-embedding-004:embedContent";
-    let json_body = curl_helpers::safe_json_serialize( self );
+    let mut request = self.clone();
+    curl_helpers ::summarize_inline_data( &mut request.content.parts );
+    let json_body = curl_helpers::safe_json_serialize( &request );
     curl_helpers ::generate_curl_command( url, &json_body, options )
   }
 }
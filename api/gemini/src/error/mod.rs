@@ -16,20 +16,27 @@ mod private
       RequestBuilding( String ),
 
       /// API returned an error response.
+      ///
+      /// The second field carries the structured error body when the API returned one.
       #[ error( "API error : {0}" ) ]
-      ApiError( String ),
+      ApiError( String, Option< Box< ApiErrorDetails > > ),
 
       /// Authentication failed.
       #[ error( "Authentication error : {0}" ) ]
       AuthenticationError( String ),
 
       /// Rate limit has been exceeded.
+      ///
+      /// The second field carries the structured error body when the API returned one,
+      /// including any `RetryInfo` and quota details.
       #[ error( "Rate limit exceeded : {0}" ) ]
-      RateLimitError( String ),
+      RateLimitError( String, Option< Box< ApiErrorDetails > > ),
 
       /// Invalid argument provided.
+      ///
+      /// The second field carries the structured error body when the API returned one.
       #[ error( "Invalid argument : {0}" ) ]
-      InvalidArgument( String ),
+      InvalidArgument( String, Option< Box< ApiErrorDetails > > ),
 
       /// Server returned an error.
       #[ error( "Server error : {0}" ) ]
@@ -112,6 +119,28 @@ mod private
       QuotaExceeded( String ),
   }
 
+  impl Error
+  {
+    /// Structured error details returned by the API, if any.
+    ///
+    /// Available for [`Error::ApiError`], [`Error::InvalidArgument`] and
+    /// [`Error::RateLimitError`] produced from a parsed Gemini error body, allowing
+    /// callers to branch on `status` (for example `RESOURCE_EXHAUSTED` vs
+    /// `INVALID_ARGUMENT`) instead of matching message text.
+    #[ must_use ]
+    #[ inline ]
+    pub fn api_details( &self ) -> Option< &ApiErrorDetails >
+    {
+      match self
+      {
+        Error::ApiError( _, details )
+        | Error::InvalidArgument( _, details )
+        | Error::RateLimitError( _, details ) => details.as_deref(),
+        _ => None,
+      }
+    }
+  }
+
   impl From< std::io::Error > for Error
   {
     #[ inline ]
@@ -153,7 +182,7 @@ mod private
         }
         else if err.status() == Some( reqwest::StatusCode::TOO_MANY_REQUESTS )
         {
-          Error::RateLimitError( format!( "Rate limit exceeded : {err}" ) )
+          Error::RateLimitError( format!( "Rate limit exceeded : {err}" ), None )
         }
         else
         {
@@ -172,7 +201,7 @@ mod private
   }
 
   /// Details about an API error.
-  #[ derive( Debug, Deserialize, Serialize ) ]
+  #[ derive( Debug, Clone, PartialEq, Deserialize, Serialize ) ]
   #[ serde( rename_all = "camelCase" ) ]
   pub struct ApiErrorDetails
   {
@@ -180,8 +209,29 @@ mod private
       pub code : i32,
       /// Error message.
       pub message : String,
-      /// Optional status string.
+      /// Optional status string (e.g. `INVALID_ARGUMENT`, `RESOURCE_EXHAUSTED`).
       pub status : Option< String >,
+      /// Additional typed detail objects (`@type`-tagged) attached by the API.
+      #[ serde( default, skip_serializing_if = "Vec::is_empty" ) ]
+      pub details : Vec< serde_json::Value >,
+  }
+
+  impl ApiErrorDetails
+  {
+    /// Server-suggested delay before retrying, from a `google.rpc.RetryInfo` detail.
+    ///
+    /// Gemini attaches one to `RESOURCE_EXHAUSTED` errors as a duration string such
+    /// as `"37s"` or `"1.5s"`.
+    #[ must_use ]
+    #[ inline ]
+    pub fn retry_delay( &self ) -> Option< core::time::Duration >
+    {
+      self.details
+      .iter()
+      .filter( | detail | detail.get( "@type" ).and_then( serde_json::Value::as_str ).is_some_and( | kind | kind.ends_with( "google.rpc.RetryInfo" ) ) )
+      .find_map( | detail | detail.get( "retryDelay" )?.as_str()?.strip_suffix( 's' )?.parse::< f64 >().ok() )
+      .and_then( | seconds | core::time::Duration::try_from_secs_f64( seconds ).ok() )
+    }
   }
 }
 
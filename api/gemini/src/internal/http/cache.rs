@@ -51,8 +51,8 @@ impl CacheKey
   {
     let body_hash = if let Some( body ) = body
     {
-      // Hash the serialized body
-      let json = serde_json::to_string( body ).unwrap_or_default();
+      // Hash the canonical serialization so map ordering cannot change the key
+      let json = serde_json::to_value( body ).map( | value | canonical_json( &value ) ).unwrap_or_default();
       let mut hasher = DefaultHasher::new();
       json.hash( &mut hasher );
       hasher.finish()
@@ -68,6 +68,56 @@ impl CacheKey
   }
 }
 
+/// Serialize a JSON value with object keys sorted recursively
+///
+/// Logically identical bodies produce the same string regardless of the order
+/// their maps ( e.g. `HashMap` fields ) were serialized in.
+fn canonical_json( value : &serde_json::Value ) -> String
+{
+  let mut out = String::new();
+  write_canonical_json( value, &mut out );
+  out
+}
+
+fn write_canonical_json( value : &serde_json::Value, out : &mut String )
+{
+  match value
+  {
+    serde_json::Value::Object( map ) =>
+    {
+      let mut entries : Vec< _ > = map.iter().collect();
+      entries.sort_unstable_by( | ( a, _ ), ( b, _ ) | a.cmp( b ) );
+
+      out.push( '{' );
+      for ( index, ( key, value ) ) in entries.into_iter().enumerate()
+      {
+        if index > 0
+        {
+          out.push( ',' );
+        }
+        out.push_str( &serde_json::Value::String( key.clone() ).to_string() );
+        out.push( ':' );
+        write_canonical_json( value, out );
+      }
+      out.push( '}' );
+    },
+    serde_json::Value::Array( items ) =>
+    {
+      out.push( '[' );
+      for ( index, item ) in items.iter().enumerate()
+      {
+        if index > 0
+        {
+          out.push( ',' );
+        }
+        write_canonical_json( item, out );
+      }
+      out.push( ']' );
+    },
+    scalar => out.push_str( &scalar.to_string() ),
+  }
+}
+
 /// Cache entry with TTL and LRU tracking
 #[ derive( Debug, Clone ) ]
 struct CacheEntry
@@ -374,6 +424,52 @@ mod tests
     assert_ne!( key1, key3 );
   }
 
+  #[ test ]
+  fn test_cache_key_ignores_map_order()
+  {
+    #[ derive( Serialize ) ]
+    struct Request
+    {
+      model : String,
+      labels : HashMap< String, String >,
+    }
+
+    // Each map has its own random hasher state, so they iterate in different orders
+    let mut forward = HashMap::new();
+    let mut backward = HashMap::new();
+    let pairs : Vec< ( String, String ) > = ( 0..16 ).map( | i | ( format!( "key{i}" ), format!( "value{i}" ) ) ).collect();
+    for ( k, v ) in &pairs
+    {
+      forward.insert( k.clone(), v.clone() );
+    }
+    for ( k, v ) in pairs.iter().rev()
+    {
+      backward.insert( k.clone(), v.clone() );
+    }
+
+    let a = Request { model : "gemini-2.5-flash".to_string(), labels : forward };
+    let b = Request { model : "gemini-2.5-flash".to_string(), labels : backward };
+
+    let key_a = CacheKey::new( &Method::POST, "https://api.example.com/generate", Some( &a ) );
+    let key_b = CacheKey::new( &Method::POST, "https://api.example.com/generate", Some( &b ) );
+    assert_eq!( key_a, key_b );
+
+    let mut different = b;
+    different.labels.insert( "extra".to_string(), "value".to_string() );
+    let key_c = CacheKey::new( &Method::POST, "https://api.example.com/generate", Some( &different ) );
+    assert_ne!( key_a, key_c );
+  }
+
+  #[ test ]
+  fn test_canonical_json_sorts_nested_keys()
+  {
+    let a : serde_json::Value = serde_json::from_str( r#"{"b":{"y":1,"x":[{"d":2,"c":"s"}]},"a":null}"# ).unwrap();
+    let b : serde_json::Value = serde_json::from_str( r#"{"a":null,"b":{"x":[{"c":"s","d":2}],"y":1}}"# ).unwrap();
+
+    assert_eq!( canonical_json( &a ), r#"{"a":null,"b":{"x":[{"c":"s","d":2}],"y":1}}"# );
+    assert_eq!( canonical_json( &a ), canonical_json( &b ) );
+  }
+
   #[ test ]
   fn test_cache_entry_expiration()
   {
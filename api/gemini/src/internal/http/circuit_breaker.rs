@@ -55,8 +55,10 @@ pub struct CircuitBreakerMetrics
   pub last_opened : Option< Instant >,
 }
 
+/// Callback invoked with the `( old, new )` states on every circuit transition
+pub type StateChangeCallback = Arc< dyn Fn( CircuitBreakerState, CircuitBreakerState ) + Send + Sync >;
+
 /// Circuit breaker instance with state management
-#[ derive( Debug ) ]
 pub struct CircuitBreaker
 {
   config : CircuitBreakerConfig,
@@ -64,6 +66,22 @@ pub struct CircuitBreaker
   consecutive_failures : Arc< Mutex< u32 > >,
   consecutive_successes : Arc< Mutex< u32 > >,
   metrics : Arc< Mutex< CircuitBreakerMetrics > >,
+  state_listeners : Mutex< Vec< StateChangeCallback > >,
+}
+
+impl core::fmt::Debug for CircuitBreaker
+{
+  fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+  {
+    f.debug_struct( "CircuitBreaker" )
+      .field( "config", &self.config )
+      .field( "state", &self.state )
+      .field( "consecutive_failures", &self.consecutive_failures )
+      .field( "consecutive_successes", &self.consecutive_successes )
+      .field( "metrics", &self.metrics )
+      .field( "state_listeners", &self.state_listeners.lock().map( | l | l.len() ).unwrap_or( 0 ) )
+      .finish()
+  }
 }
 
 impl CircuitBreaker
@@ -84,11 +102,46 @@ impl CircuitBreaker
         current_state : CircuitBreakerState::Closed,
         last_opened : None,
       } ) ),
+      state_listeners : Mutex::new( Vec::new() ),
+    }
+  }
+
+  /// Register a callback fired with `( old, new )` on every state transition
+  ///
+  /// Callbacks run synchronously on the thread that caused the transition, after the
+  /// breaker's own locks are released, so they may query the breaker. Keep them short;
+  /// they delay the request that triggered the transition.
+  pub fn on_state_change< F >( &self, callback : F )
+  where
+    F : Fn( CircuitBreakerState, CircuitBreakerState ) + Send + Sync + 'static,
+  {
+    self.state_listeners.lock().unwrap().push( Arc::new( callback ) );
+  }
+
+  /// Notify registered callbacks of a transition
+  fn notify_state_change( &self, transition : Option< ( CircuitBreakerState, CircuitBreakerState ) > )
+  {
+    if let Some( ( old, new ) ) = transition
+    {
+      // Release the lock first, so callbacks may register listeners or query the breaker
+      let listeners = self.state_listeners.lock().unwrap().clone();
+      for listener in &listeners
+      {
+        listener( old.clone(), new.clone() );
+      }
     }
   }
 
   /// Check if a request should be allowed through the circuit breaker
   pub fn should_allow_request( &self ) -> bool
+  {
+    let ( allowed, transition ) = self.check_request();
+    self.notify_state_change( transition );
+    allowed
+  }
+
+  /// Admission decision plus the transition it caused, if any
+  fn check_request( &self ) -> ( bool, Option< ( CircuitBreakerState, CircuitBreakerState ) > )
   {
     let mut state = self.state.lock().unwrap();
     let mut metrics = self.metrics.lock().unwrap();
@@ -97,31 +150,31 @@ impl CircuitBreaker
 
     match *state
     {
-      CircuitBreakerState::Closed => true,
+      CircuitBreakerState::Closed => ( true, None ),
       CircuitBreakerState::Open( opened_at ) => {
         if opened_at.elapsed() >= self.config.timeout
         {
           // Transition to half-open
-          *state = CircuitBreakerState::HalfOpen;
+          let old = core::mem::replace( &mut *state, CircuitBreakerState::HalfOpen );
           metrics.current_state = CircuitBreakerState::HalfOpen;
           metrics.state_transitions += 1;
 
           #[ cfg( feature = "logging" ) ]
           info!( "Circuit breaker transitioning to half-open state" );
 
-          true // Allow the test request
+          ( true, Some( ( old, CircuitBreakerState::HalfOpen ) ) ) // Allow the test request
         } else {
           metrics.blocked_requests += 1;
 
           #[ cfg( feature = "logging" ) ]
           debug!( "Circuit breaker is open, blocking request" );
 
-          false
+          ( false, None )
         }
       },
       CircuitBreakerState::HalfOpen => {
         // In half-open state, allow requests but they will be closely monitored
-        true
+        ( true, None )
       }
     }
   }
@@ -134,6 +187,7 @@ impl CircuitBreaker
     let mut consecutive_successes = self.consecutive_successes.lock().unwrap();
     let mut metrics = self.metrics.lock().unwrap();
 
+    let mut transition = None;
     *consecutive_failures = 0;
     *consecutive_successes += 1;
 
@@ -144,6 +198,7 @@ impl CircuitBreaker
         {
           // Close the circuit
           *state = CircuitBreakerState::Closed;
+          transition = Some( ( CircuitBreakerState::HalfOpen, CircuitBreakerState::Closed ) );
           *consecutive_successes = 0;
           metrics.current_state = CircuitBreakerState::Closed;
           metrics.state_transitions += 1;
@@ -160,6 +215,9 @@ impl CircuitBreaker
         }
       }
     }
+
+    drop( ( state, consecutive_failures, consecutive_successes, metrics ) );
+    self.notify_state_change( transition );
   }
 
   /// Record a failed request
@@ -170,6 +228,7 @@ impl CircuitBreaker
     let mut consecutive_successes = self.consecutive_successes.lock().unwrap();
     let mut metrics = self.metrics.lock().unwrap();
 
+    let mut transition = None;
     *consecutive_failures += 1;
     *consecutive_successes = 0;
     metrics.failed_requests += 1;
@@ -181,7 +240,8 @@ impl CircuitBreaker
       {
         CircuitBreakerState::Closed | CircuitBreakerState::HalfOpen => {
           let now = Instant::now();
-          *state = CircuitBreakerState::Open( now );
+          let old = core::mem::replace( &mut *state, CircuitBreakerState::Open( now ) );
+          transition = Some( ( old, CircuitBreakerState::Open( now ) ) );
           metrics.current_state = CircuitBreakerState::Open( now );
           metrics.last_opened = Some( now );
           metrics.state_transitions += 1;
@@ -195,6 +255,9 @@ impl CircuitBreaker
         _ => {}
       }
     }
+
+    drop( ( state, consecutive_failures, consecutive_successes, metrics ) );
+    self.notify_state_change( transition );
   }
 
   /// Get current circuit breaker metrics
@@ -202,6 +265,64 @@ impl CircuitBreaker
   {
     self.metrics.lock().unwrap().clone()
   }
+
+  /// Get the current circuit state
+  pub fn state( &self ) -> CircuitBreakerState
+  {
+    self.state.lock().unwrap().clone()
+  }
+
+  /// Get the configuration this breaker was created with
+  pub fn config( &self ) -> &CircuitBreakerConfig
+  {
+    &self.config
+  }
+}
+
+/// Circuit breaker owned by a `Client`
+///
+/// In shared mode every clone of the client holds the same breaker, so a backend
+/// failing through one handle opens the circuit for all of them. Otherwise each
+/// clone starts with its own closed breaker built from the same configuration.
+#[ derive( Debug ) ]
+pub struct ClientCircuitBreaker
+{
+  breaker : Arc< CircuitBreaker >,
+  shared : bool,
+}
+
+impl ClientCircuitBreaker
+{
+  /// Create a breaker, shared across client clones when `shared` is set
+  pub fn new( config : CircuitBreakerConfig, shared : bool ) -> Self
+  {
+    Self { breaker : Arc::new( CircuitBreaker::new( config ) ), shared }
+  }
+
+  /// The breaker consulted before each request
+  pub fn breaker( &self ) -> &CircuitBreaker
+  {
+    &self.breaker
+  }
+
+  /// Whether clones share this breaker's state
+  pub fn is_shared( &self ) -> bool
+  {
+    self.shared
+  }
+}
+
+impl Clone for ClientCircuitBreaker
+{
+  fn clone( &self ) -> Self
+  {
+    if self.shared
+    {
+      Self { breaker : self.breaker.clone(), shared : true }
+    } else {
+      Self::new( self.breaker.config().clone(), false )
+    }
+  }
 }
 
 /// Classify if an error should trigger circuit breaker failure counting
@@ -210,7 +331,7 @@ pub fn is_circuit_breaker_error( error : &Error ) -> bool
   matches!( error,
     Error::NetworkError( _ ) |
     Error::ServerError( _ ) |
-    Error::RateLimitError( _ ) |
+    Error::RateLimitError( .. ) |
     Error::TimeoutError( _ )
   )
 }
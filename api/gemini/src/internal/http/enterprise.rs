@@ -7,10 +7,10 @@ use crate::error::Error;
 use super::HttpConfig;
 
 #[ cfg( feature = "retry" ) ]
-use super::retry::{ RetryConfig, is_retryable_error, calculate_retry_delay };
+use super::retry::{ RetryConfig, is_retryable_error, is_retry_allowed, calculate_retry_delay };
 
 #[ cfg( feature = "circuit_breaker" ) ]
-use super::circuit_breaker::{ CircuitBreaker, is_circuit_breaker_error };
+use super::circuit_breaker::{ CircuitBreaker, ClientCircuitBreaker, is_circuit_breaker_error };
 
 #[ cfg( feature = "rate_limiting" ) ]
 use super::rate_limiter::RateLimit;
@@ -21,6 +21,10 @@ use super::cache::{ RequestCache, execute_with_cache };
 /// Execute an HTTP request with optional retry, circuit breaker, and rate limiting logic based on client configuration
 /// This function provides a unified interface that automatically uses enterprise reliability features
 /// when available, falling back to legacy execution when features are disabled or unavailable
+///
+/// Retries only replay idempotent requests, as derived from the method and endpoint
+/// ( see [`super::retry::is_idempotent_request`] ). Use
+/// [`execute_with_optional_retries_idempotent`] to mark a call explicitly.
 pub async fn execute_with_optional_retries< T, R >
 (
   full_client : &crate::client::Client,
@@ -34,39 +38,39 @@ where
   T: Serialize,
   R: Serialize + for< 'de > Deserialize< 'de >,
 {
-  // Use HTTP config with logging enabled in test environment
-  #[ allow(unused_mut) ] // May not be mutated depending on feature flags
-  let mut http_config = HttpConfig::new();
-
-  // Enable logging during tests when logging feature is active
-  #[ cfg( all( feature = "logging", test ) ) ]
-  {
-    http_config.enable_logging = true;
-  }
-
-  // Also enable logging if GEMINI_ENABLE_HTTP_LOGGING environment variable is set
-  #[ cfg( feature = "logging" ) ]
-  if std::env::var( "GEMINI_ENABLE_HTTP_LOGGING" ).is_ok()
-  {
-    http_config.enable_logging = true;
-  }
+  execute_with_optional_retries_idempotent( full_client, method, url, api_key, body, None ).await
+}
 
-  // Add compression configuration if available
-  #[ cfg( feature = "compression" ) ]
-  {
-    http_config.compression_config = full_client.compression_config.clone();
-  }
+/// Execute an HTTP request like [`execute_with_optional_retries`] with an explicit idempotency override
+///
+/// Pass `Some( true )` to allow replaying a call, `Some( false )` for calls that must never
+/// be replayed, or `None` to derive it from the method and endpoint.
+pub async fn execute_with_optional_retries_idempotent< T, R >
+(
+  full_client : &crate::client::Client,
+  method : Method,
+  url : &str,
+  api_key : &str,
+  body : Option< &T >,
+  idempotent : Option< bool >,
+)
+-> Result< R, Error >
+where
+  T: Serialize,
+  R: Serialize + for< 'de > Deserialize< 'de >,
+{
+  let http_config = client_http_config( full_client );
 
-  // Create instances for each configured feature
+  // The rate limiter and circuit breaker live on the client, so their state persists across calls
   #[ cfg( feature = "rate_limiting" ) ]
-  let rate_limiter = full_client.to_rate_limiting_config().map( |config| RateLimit::new( config ) );
+  let rate_limiter = full_client.rate_limiter.as_deref();
   #[ cfg( not( feature = "rate_limiting" ) ) ]
-  let rate_limiter : Option< () > = None;
+  let rate_limiter : Option< &() > = None;
 
   #[ cfg( feature = "circuit_breaker" ) ]
-  let circuit_breaker = full_client.to_circuit_breaker_config().map( |config| CircuitBreaker::new( config ) );
+  let circuit_breaker = full_client.circuit_breaker.as_ref().map( ClientCircuitBreaker::breaker );
   #[ cfg( not( feature = "circuit_breaker" ) ) ]
-  let circuit_breaker : Option< () > = None;
+  let circuit_breaker : Option< &() > = None;
 
   #[ cfg( feature = "retry" ) ]
   let retry_config = full_client.to_retry_config();
@@ -86,13 +90,54 @@ where
     api_key,
     body,
     &http_config,
-    rate_limiter.as_ref(),
-    circuit_breaker.as_ref(),
+    rate_limiter,
+    circuit_breaker,
     retry_config.as_ref(),
     cache,
+    idempotent,
   ).await
 }
 
+/// Build the per-request HTTP configuration from the client's current settings
+pub( crate ) fn client_http_config( full_client : &crate::client::Client ) -> HttpConfig
+{
+  // Use HTTP config with logging enabled in test environment
+  #[ allow(unused_mut) ] // May not be mutated depending on feature flags
+  let mut http_config = HttpConfig::new();
+
+  // Enable logging during tests when logging feature is active
+  #[ cfg( all( feature = "logging", test ) ) ]
+  {
+    http_config.enable_logging = true;
+  }
+
+  // Also enable logging if GEMINI_ENABLE_HTTP_LOGGING environment variable is set
+  #[ cfg( feature = "logging" ) ]
+  if std::env::var( "GEMINI_ENABLE_HTTP_LOGGING" ).is_ok()
+  {
+    http_config.enable_logging = true;
+  }
+
+  // Add compression configuration if available
+  #[ cfg( feature = "compression" ) ]
+  {
+    http_config.compression_config = full_client.compression_config.clone();
+  }
+
+  // The client timeout can change at runtime through `Client::apply_config`
+  if !full_client.timeout.is_zero()
+  {
+    http_config = http_config.with_timeout_duration( full_client.timeout );
+  }
+
+  http_config.request_signer = full_client.request_signer.clone();
+  http_config.extra_headers = full_client.extra_headers.clone();
+  http_config.interceptor = full_client.interceptor.clone();
+  http_config.user_agent.clone_from( &full_client.user_agent );
+
+  http_config
+}
+
 /// Execute an HTTP request with enterprise reliability features (rate limiting, circuit breaker, retry, caching)
 pub( crate ) async fn execute_with_enterprise_features< T, R >
 (
@@ -118,6 +163,8 @@ pub( crate ) async fn execute_with_enterprise_features< T, R >
   cache : Option< &RequestCache >,
   #[ cfg( not( feature = "caching" ) ) ]
   _cache : Option< &() >,
+  #[ allow( unused_variables ) ]
+  idempotent : Option< bool >,
 )
 -> Result< R, Error >
 where
@@ -152,6 +199,13 @@ where
     #[ cfg( not( feature = "caching" ) ) ]
     let result = super::execute( client, method.clone(), url, api_key, body, config ).await;
 
+    // Feed server responses back to the rate limiter
+    #[ cfg( feature = "rate_limiting" ) ]
+    if let Some( rl ) = rate_limiter
+    {
+      rl.record_outcome( &result );
+    }
+
     // Record circuit breaker results
     #[ cfg( feature = "circuit_breaker" ) ]
     if let Some( cb ) = circuit_breaker
@@ -170,7 +224,7 @@ where
   // Use retry logic if available
   #[ cfg( feature = "retry" ) ]
   {
-    if let Some( retry_cfg ) = retry_config
+    if let Some( retry_cfg ) = retry_config.filter( | cfg | is_retry_allowed( &method, url, idempotent, cfg ) )
     {
       let start_time = std::time::Instant::now();
       let mut attempt = 1;
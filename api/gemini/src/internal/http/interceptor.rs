@@ -0,0 +1,265 @@
+//! Request/response interception for testing and instrumentation
+
+use reqwest::Method;
+use serde::{ Deserialize, Serialize };
+
+use crate::error::Error;
+
+/// Observes and rewrites requests and their responses
+///
+/// An interceptor is configured explicitly with `ClientBuilder::with_interceptor`
+/// and is invoked by every request the client executes. `before_request` runs
+/// after the request is fully built ( and signed ), so it sees exactly what would
+/// be sent and may change headers, body or URL, or answer the request itself with
+/// [`RequestParts::respond_with`] to skip the network entirely. `after_response`
+/// runs before the response body is deserialized.
+///
+/// # Examples
+///
+/// ```rust
+/// use api_gemini::{ Interceptor, RequestParts, ResponseParts };
+///
+/// #[ derive( Debug ) ]
+/// struct Tracing;
+///
+/// impl Interceptor for Tracing
+/// {
+///   fn before_request( &self, request : &mut RequestParts )
+///   {
+///     request.set_header( "traceparent", "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01" );
+///   }
+///
+///   fn after_response( &self, response : &ResponseParts )
+///   {
+///     println!( "HTTP {} ( {} bytes )", response.status, response.body.len() );
+///   }
+/// }
+/// ```
+pub trait Interceptor : Send + Sync + core::fmt::Debug
+{
+  /// Inspect or modify a request before it is sent
+  fn before_request( &self, request : &mut RequestParts );
+
+  /// Inspect a response before it is deserialized
+  ///
+  /// With the `diagnostics_timing` feature, responses from the server carry their
+  /// [`RequestMetrics`] in `response.metrics`.
+  fn after_response( &self, response : &ResponseParts );
+
+  /// Inspect a completed exchange, after `after_response`
+  ///
+  /// `request` is the request as sent, after `before_request`. Useful for
+  /// interceptors that need to pair requests with their responses, such as
+  /// recorders. Does nothing by default.
+  #[ inline ]
+  fn after_exchange( &self, request : &RequestParts, response : &ResponseParts )
+  {
+    let _ = ( request, response );
+  }
+}
+
+/// The parts of an outgoing request visible to an [`Interceptor`]
+///
+/// The `url` excludes the API key query parameter, which is re-attached when
+/// the request is sent. Headers with non-UTF-8 values are omitted.
+#[ derive( Debug, Clone ) ]
+pub struct RequestParts
+{
+  /// HTTP method
+  pub method : Method,
+  /// Request URL without the API key
+  pub url : String,
+  /// Request headers in insertion order
+  pub headers : Vec< ( String, String ) >,
+  /// Serialized ( and possibly compressed ) request body
+  pub body : Option< Vec< u8 > >,
+  response : Option< ResponseParts >,
+}
+
+impl RequestParts
+{
+  /// Capture the parts of a built request, stripping the API key from the URL
+  pub( crate ) fn from_request( request : &reqwest::Request ) -> Self
+  {
+    let mut url = request.url().clone();
+    let query : Vec< ( String, String ) > = url.query_pairs()
+      .filter( | ( name, _ ) | name != "key" )
+      .map( | ( name, value ) | ( name.into_owned(), value.into_owned() ) )
+      .collect();
+    if query.is_empty()
+    {
+      url.set_query( None );
+    }
+    else
+    {
+      url.query_pairs_mut().clear().extend_pairs( query );
+    }
+
+    let headers = request.headers().iter()
+      .filter_map( | ( name, value ) | value.to_str().ok().map( | value | ( name.as_str().to_string(), value.to_string() ) ) )
+      .collect();
+
+    Self
+    {
+      method : request.method().clone(),
+      url : url.to_string(),
+      headers,
+      body : request.body().and_then( reqwest::Body::as_bytes ).map( < [ u8 ] >::to_vec ),
+      response : None,
+    }
+  }
+
+  /// Value of the first header named `name` ( case-insensitive )
+  #[ inline ]
+  #[ must_use ]
+  pub fn header( &self, name : &str ) -> Option< &str >
+  {
+    self.headers.iter()
+      .find( | ( header, _ ) | header.eq_ignore_ascii_case( name ) )
+      .map( | ( _, value ) | value.as_str() )
+  }
+
+  /// Replace every header named `name` ( case-insensitive ) with a single value
+  #[ inline ]
+  pub fn set_header( &mut self, name : impl Into< String >, value : impl Into< String > )
+  {
+    let name = name.into();
+    self.headers.retain( | ( header, _ ) | !header.eq_ignore_ascii_case( &name ) );
+    self.headers.push( ( name, value.into() ) );
+  }
+
+  /// Answer the request with `response` instead of sending it
+  ///
+  /// The response goes through `after_response` and deserialization exactly like
+  /// one received from the server, which makes fixture-driven tests deterministic.
+  #[ inline ]
+  pub fn respond_with( &mut self, response : ResponseParts )
+  {
+    self.response = Some( response );
+  }
+
+  /// Take the response set with [`RequestParts::respond_with`], if any
+  pub( crate ) fn take_response( &mut self ) -> Option< ResponseParts >
+  {
+    self.response.take()
+  }
+
+  /// Rebuild `original` from these parts, carrying over its API key and timeout
+  pub( crate ) fn into_request( self, original : &reqwest::Request ) -> Result< reqwest::Request, Error >
+  {
+    use reqwest::header::{ HeaderName, HeaderValue };
+
+    let mut url = reqwest::Url::parse( &self.url )
+      .map_err( |e| Error::RequestBuilding( format!( "Interceptor produced an invalid URL '{}': {e}", self.url ) ) )?;
+    if let Some( ( _, key ) ) = original.url().query_pairs().find( | ( name, _ ) | name == "key" )
+    {
+      url.query_pairs_mut().append_pair( "key", &key );
+    }
+
+    let mut request = reqwest::Request::new( self.method, url );
+    *request.timeout_mut() = original.timeout().copied();
+
+    for ( name, value ) in self.headers
+    {
+      let header_name = HeaderName::from_bytes( name.as_bytes() )
+        .map_err( |e| Error::RequestBuilding( format!( "Interceptor produced an invalid header name '{name}': {e}" ) ) )?;
+      let header_value = HeaderValue::from_str( &value )
+        .map_err( |e| Error::RequestBuilding( format!( "Interceptor produced an invalid value for header '{name}': {e}" ) ) )?;
+      request.headers_mut().append( header_name, header_value );
+    }
+
+    *request.body_mut() = self.body.map( reqwest::Body::from );
+    Ok( request )
+  }
+}
+
+/// The parts of a response visible to an [`Interceptor`]
+#[ derive( Debug, Clone, PartialEq, Eq, Serialize, Deserialize ) ]
+pub struct ResponseParts
+{
+  /// HTTP status code
+  pub status : u16,
+  /// Response headers in arrival order
+  pub headers : Vec< ( String, String ) >,
+  /// Response body text
+  pub body : String,
+  /// Timing of the exchange, for responses received from the server
+  ///
+  /// `None` for responses supplied with [`RequestParts::respond_with`], and without
+  /// the `diagnostics_timing` feature. Not serialized.
+  #[ serde( skip ) ]
+  pub metrics : Option< RequestMetrics >,
+}
+
+/// Timing phases of a request (requires the `diagnostics_timing` feature)
+///
+/// `dns` and `connect` are `None` when the request reused a pooled connection;
+/// `dns` is also `None` for IP address hosts and when a proxy resolves the host.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+pub struct RequestMetrics
+{
+  /// Resolving the host name of a new connection
+  pub dns : Option< core::time::Duration >,
+  /// Opening a new connection after resolution : TCP connect and, for HTTPS, the TLS handshake
+  pub connect : Option< core::time::Duration >,
+  /// From the connection being ready until the response headers arrived
+  pub time_to_first_byte : core::time::Duration,
+  /// Reading the response body after the headers arrived
+  pub download : core::time::Duration,
+  /// The whole exchange, the sum of the other phases
+  pub total : core::time::Duration,
+}
+
+impl ResponseParts
+{
+  /// Create a response with the given status and body and no headers
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( status : u16, body : impl Into< String > ) -> Self
+  {
+    Self { status, headers : Vec::new(), body : body.into(), metrics : None }
+  }
+
+  /// Add a header
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_header( mut self, name : impl Into< String >, value : impl Into< String > ) -> Self
+  {
+    self.headers.push( ( name.into(), value.into() ) );
+    self
+  }
+}
+
+#[ cfg( test ) ]
+mod tests
+{
+  use super::*;
+  use core::time::Duration;
+
+  fn header_values< 'a >( request : &'a reqwest::Request, name : &str ) -> Vec< &'a str >
+  {
+    request.headers().get_all( name ).iter().map( | value | value.to_str().unwrap() ).collect()
+  }
+
+  #[ test ]
+  fn test_rebuilt_request_carries_header_changes()
+  {
+    let original = reqwest::Client::new()
+    .get( "http://127.0.0.1:9/v1beta/models?key=test-key" )
+    .header( "User-Agent", "api-gemini-rust/0.2.0" )
+    .timeout( Duration::from_secs( 5 ) )
+    .build()
+    .unwrap();
+
+    let mut parts = RequestParts::from_request( &original );
+    assert_eq!( parts.url, "http://127.0.0.1:9/v1beta/models" );
+    parts.set_header( "X-Test-Run", "interceptor" );
+    parts.set_header( "user-agent", "intercepted-agent" );
+    let request = parts.into_request( &original ).unwrap();
+
+    assert_eq!( request.url().as_str(), "http://127.0.0.1:9/v1beta/models?key=test-key" );
+    assert_eq!( header_values( &request, "x-test-run" ), vec![ "interceptor" ] );
+    assert_eq!( header_values( &request, "user-agent" ), vec![ "intercepted-agent" ] );
+    assert_eq!( request.timeout(), Some( &Duration::from_secs( 5 ) ) );
+  }
+}
@@ -24,21 +24,35 @@ pub mod cache;
 #[ cfg( feature = "compression" ) ]
 pub mod compression;
 pub mod enterprise;
+pub mod pipeline;
+pub mod signing;
+pub mod interceptor;
+#[ cfg( feature = "diagnostics_timing" ) ]
+pub mod timing;
 
 // Re-export types
 #[ cfg( feature = "retry" ) ]
-pub use retry::{ RetryConfig, RetryMetrics, execute_with_retries };
+pub use retry::{ RetryConfig, RetryMetrics, IDEMPOTENT_ACTIONS, execute_with_retries, is_idempotent_method, is_idempotent_request, is_retry_allowed };
 
 #[ cfg( feature = "circuit_breaker" ) ]
-pub use circuit_breaker::{ CircuitBreakerConfig, CircuitBreakerState, CircuitBreakerMetrics, CircuitBreaker, execute_with_circuit_breaker };
+pub use circuit_breaker::{ CircuitBreakerConfig, CircuitBreakerState, CircuitBreakerMetrics, CircuitBreaker, ClientCircuitBreaker, StateChangeCallback, execute_with_circuit_breaker };
 
 #[ cfg( feature = "rate_limiting" ) ]
-pub use rate_limiter::{ RateLimitingConfig, RateLimiter, RateLimitingMetrics, RateLimit, execute_with_rate_limiting };
+pub use rate_limiter::{ RateLimitingConfig, RateLimiter, RateLimitingMetrics, RateLimit, TokenRateLimiter, execute_with_rate_limiting, ADAPTIVE_DECREASE_FACTOR, ADAPTIVE_INCREASE_STEP, ADAPTIVE_MIN_FRACTION };
 
 #[ cfg( feature = "caching" ) ]
 pub use cache::{ CacheConfig, CacheMetrics, RequestCache, execute_with_cache };
 
-pub use enterprise::execute_with_optional_retries;
+pub use enterprise::{ execute_with_optional_retries, execute_with_optional_retries_idempotent };
+
+pub use pipeline::{ ReliabilityPipeline, ReliabilityPipelineBuilder, PipelineStage, StageObserver };
+
+pub use signing::RequestSigner;
+
+pub use interceptor::{ Interceptor, RequestMetrics, RequestParts, ResponseParts };
+
+#[ cfg( feature = "diagnostics_timing" ) ]
+pub use timing::{ ConnectTimingLayer, TimingResolver };
 
 /// Configuration for HTTP requests
 ///
@@ -48,16 +62,44 @@ pub use enterprise::execute_with_optional_retries;
 pub struct HttpConfig
 {
   /// Request timeout in seconds (default : 30)
+  ///
+  /// Until a timeout is set with [`HttpConfig::with_timeout`] or
+  /// [`HttpConfig::with_timeout_duration`], the default value leaves the HTTP
+  /// client's own timeout in place; see [`HttpConfig::request_timeout`].
   pub timeout_seconds : u64,
   /// Whether to enable verbose logging (requires 'logging' feature)
   pub enable_logging : bool,
   /// Maximum content length for logging (to avoid logging huge responses)
   pub max_log_content_length : usize,
+  /// Maximum accepted response body size in bytes, for streamed bodies too (`None` reads bodies of any size)
+  pub max_response_bytes : Option< usize >,
   /// Compression configuration for request/response optimization
   #[ cfg( feature = "compression" ) ]
   pub compression_config : Option< compression::CompressionConfig >,
+  /// Signer invoked on every request to add authentication headers
+  pub request_signer : Option< std::sync::Arc< dyn RequestSigner > >,
+  /// Additional headers sent with every request ( e.g. `X-Request-ID` )
+  ///
+  /// Invalid names or values, and headers managed by the client ( see
+  /// [`RESERVED_HEADERS`] ), are skipped with a logged warning.
+  pub extra_headers : Vec< ( String, String ) >,
+  /// Interceptor invoked around every request
+  pub interceptor : Option< std::sync::Arc< dyn Interceptor > >,
+  /// `User-Agent` header sent with every request (default : [`DEFAULT_USER_AGENT`])
+  pub user_agent : String,
+  /// Timeout set through a setter, at full precision
+  timeout : Option< Duration >,
 }
 
+/// Default of `HttpConfig::timeout_seconds`
+pub const DEFAULT_TIMEOUT_SECONDS : u64 = 30;
+
+/// `User-Agent` sent when none is configured
+pub const DEFAULT_USER_AGENT : &str = "api-gemini-rust/0.2.0";
+
+/// Headers set by the client that `HttpConfig::extra_headers` cannot override
+pub const RESERVED_HEADERS : &[ &str ] = &[ "content-type", "content-encoding", "user-agent", "x-goog-api-key" ];
+
 impl HttpConfig
 {
   /// Create default HTTP configuration
@@ -66,11 +108,17 @@ impl HttpConfig
   pub fn new() -> Self
   {
     Self {
-      timeout_seconds : 30,
+      timeout_seconds : DEFAULT_TIMEOUT_SECONDS,
       enable_logging : false,
       max_log_content_length : 1024,
+      max_response_bytes : None,
       #[ cfg( feature = "compression" ) ]
       compression_config : None,
+      request_signer : None,
+      extra_headers : Vec::new(),
+      interceptor : None,
+      user_agent : DEFAULT_USER_AGENT.to_string(),
+      timeout : None,
     }
   }
 
@@ -80,9 +128,33 @@ impl HttpConfig
   pub fn with_timeout( mut self, timeout_seconds : u64 ) -> Self
   {
     self.timeout_seconds = timeout_seconds;
+    self.timeout = Some( Duration::from_secs( timeout_seconds ) );
     self
   }
 
+  /// Set request timeout with sub-second precision
+  ///
+  /// Takes precedence over `timeout_seconds`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_timeout_duration( mut self, timeout : Duration ) -> Self
+  {
+    self.timeout = Some( timeout );
+    self
+  }
+
+  /// Timeout applied to each request, `None` when the HTTP client's own timeout applies
+  ///
+  /// A timeout set through a setter always applies, including one of exactly
+  /// [`DEFAULT_TIMEOUT_SECONDS`]. A value written directly to `timeout_seconds`
+  /// applies when it differs from the default.
+  #[ inline ]
+  #[ must_use ]
+  pub fn request_timeout( &self ) -> Option< Duration >
+  {
+    self.timeout.or_else( || ( self.timeout_seconds != DEFAULT_TIMEOUT_SECONDS ).then( || Duration::from_secs( self.timeout_seconds ) ) )
+  }
+
   /// Enable verbose logging (requires 'logging' feature)
   #[ inline ]
   #[ must_use ]
@@ -92,6 +164,51 @@ impl HttpConfig
     self
   }
 
+  /// Limit the response body size, failing requests whose body exceeds `max_bytes`
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_max_response_bytes( mut self, max_bytes : usize ) -> Self
+  {
+    self.max_response_bytes = Some( max_bytes );
+    self
+  }
+
+  /// Add a header sent with every request
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_header( mut self, name : impl Into< String >, value : impl Into< String > ) -> Self
+  {
+    self.extra_headers.push( ( name.into(), value.into() ) );
+    self
+  }
+
+  /// Send `user_agent` as the `User-Agent` header
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_user_agent( mut self, user_agent : impl Into< String > ) -> Self
+  {
+    self.user_agent = user_agent.into();
+    self
+  }
+
+  /// Sign every request with the given signer
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_request_signer( mut self, signer : std::sync::Arc< dyn RequestSigner > ) -> Self
+  {
+    self.request_signer = Some( signer );
+    self
+  }
+
+  /// Invoke `interceptor` around every request
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_interceptor( mut self, interceptor : std::sync::Arc< dyn Interceptor > ) -> Self
+  {
+    self.interceptor = Some( interceptor );
+    self
+  }
+
   /// Set compression configuration (requires 'compression' feature)
   #[ cfg( feature = "compression" ) ]
   #[ inline ]
@@ -114,6 +231,32 @@ impl Default for HttpConfig
 
 /// Execute an HTTP request with JSON serialization/deserialization
 ///
+/// Like [`execute_with_metrics`], discarding the metrics.
+///
+/// # Errors
+///
+/// Returns the same errors as [`execute_with_metrics`].
+#[ inline ]
+pub async fn execute< T, R >
+(
+  client : &Client,
+  method : Method,
+  url : &str,
+  api_key : &str,
+  body : Option< &T >,
+  config : &HttpConfig,
+)
+->
+Result< R, Error >
+where
+  T : Serialize,
+  R : for< 'de > Deserialize< 'de >,
+{
+  execute_with_metrics( client, method, url, api_key, body, config ).await.map( | ( response, _ ) | response )
+}
+
+/// Execute an HTTP request with JSON serialization/deserialization, returning its timing
+///
 /// This function handles the complete HTTP request lifecycle with enhanced
 /// error handling, performance monitoring, and optional structured logging.
 ///
@@ -125,6 +268,7 @@ impl Default for HttpConfig
 /// - Structured logging (when 'logging' feature is enabled)
 /// - Enhanced error messages with context
 /// - Proper handling of API key authentication
+/// - Phase timing of the exchange (when 'diagnostics_timing' feature is enabled)
 ///
 /// # Performance
 ///
@@ -133,6 +277,9 @@ impl Default for HttpConfig
 /// - Efficient JSON handling with streaming where possible
 /// - Request timing to monitor API performance
 ///
+/// The [`RequestMetrics`] are `None` without the `diagnostics_timing` feature and
+/// for responses supplied by an interceptor.
+///
 /// # Errors
 ///
 /// This function returns specific error types for different failure scenarios:
@@ -153,7 +300,7 @@ impl Default for HttpConfig
   )
 ) ) ]
 #[ inline ]
-pub async fn execute< T, R >
+pub async fn execute_with_metrics< T, R >
 (
   client : &Client,
   method : Method,
@@ -163,7 +310,7 @@ pub async fn execute< T, R >
   config : &HttpConfig,
 )
 ->
-Result< R, Error >
+Result< ( R, Option< RequestMetrics > ), Error >
 where
   T : Serialize,
   R : for< 'de > Deserialize< 'de >,
@@ -193,15 +340,7 @@ where
   // Build the request with enhanced configuration
   let request = build_request( client, method, url, api_key, body, config )?;
 
-  // Execute the request with timing
-  let response = send_request( client, request, config ).await?;
-
-  // Capture response metadata before processing
-  let status_code = response.status().as_u16();
-  let response_size = response.content_length().unwrap_or( 0 );
-
-  // Process the response with comprehensive error handling
-  let result = process_response::< R >( response, config ).await;
+  let ( status_code, response_size, result ) = exchange::< R >( client, request, config ).await?;
 
   let elapsed = start_time.elapsed();
   let duration_ms = elapsed.as_secs_f64() * 1000.0;
@@ -231,13 +370,13 @@ where
       Err( error ) => {
         let error_type = match error
         {
-          Error::ApiError( _ ) => "ApiError",
+          Error::ApiError( _, _ ) => "ApiError",
           Error::AuthenticationError( _ ) => "AuthenticationError",
           Error::NetworkError( _ ) => "NetworkError",
           Error::SerializationError( _ ) => "SerializationError",
           Error::DeserializationError( _ ) => "DeserializationError",
-          Error::InvalidArgument( _ ) => "InvalidArgument",
-          Error::RateLimitError( _ ) => "RateLimitError",
+          Error::InvalidArgument( _, _ ) => "InvalidArgument",
+          Error::RateLimitError( .. ) => "RateLimitError",
           Error::ServerError( _ ) => "ServerError",
           Error::RequestBuilding( _ ) => "RequestBuilding",
           _ => "UnknownError",
@@ -309,15 +448,16 @@ where
 
   // Create request builder - only apply timeout if not already set on client
   let mut request_builder = client
-    .request( method, url )
+    .request( method.clone(), url )
     .query( &[ ( "key", api_key ) ] )
     .header( "Content-Type", "application/json" )
-    .header( "User-Agent", "api-gemini-rust/0.2.0" );
+    .header( "User-Agent", &config.user_agent );
+
+  request_builder = apply_extra_headers( request_builder, config );
 
-  // Only set timeout if it's different from default (indicating explicit config)
-  if config.timeout_seconds != 30
+  if let Some( timeout ) = config.request_timeout()
   {
-    request_builder = request_builder.timeout( Duration::from_secs( config.timeout_seconds ) );
+    request_builder = request_builder.timeout( timeout );
   }
 
   // Serialize and attach body if provided
@@ -398,8 +538,13 @@ where
       }
     }
 
+    request_builder = apply_request_signer( request_builder, config, &method, url, &final_body );
     request_builder = request_builder.body( final_body );
   }
+  else
+  {
+    request_builder = apply_request_signer( request_builder, config, &method, url, &[] );
+  }
 
   request_builder.build()
     .map_err( |e| Error::RequestBuilding(
@@ -407,6 +552,64 @@ where
     ) )
 }
 
+/// Add `HttpConfig::extra_headers`, skipping invalid and reserved headers
+fn apply_extra_headers( mut request_builder : reqwest::RequestBuilder, config : &HttpConfig ) -> reqwest::RequestBuilder
+{
+  use reqwest::header::{ HeaderName, HeaderValue };
+
+  for ( name, value ) in &config.extra_headers
+  {
+    let header_name = match HeaderName::from_bytes( name.as_bytes() )
+    {
+      Ok( header_name ) if !RESERVED_HEADERS.contains( &header_name.as_str() ) => header_name,
+      _ =>
+      {
+        #[ cfg( feature = "logging" ) ]
+        if config.enable_logging
+        {
+          warn!( header = %name, "Skipping invalid or reserved extra header name" );
+        }
+        continue;
+      },
+    };
+
+    let Ok( header_value ) = HeaderValue::from_str( value ) else
+    {
+      #[ cfg( feature = "logging" ) ]
+      if config.enable_logging
+      {
+        warn!( header = %name, "Skipping extra header with invalid value" );
+      }
+      continue;
+    };
+
+    request_builder = request_builder.header( header_name, header_value );
+  }
+
+  request_builder
+}
+
+/// Add the headers produced by the configured `RequestSigner`, if any
+fn apply_request_signer
+(
+  request_builder : reqwest::RequestBuilder,
+  config : &HttpConfig,
+  method : &Method,
+  url : &str,
+  body : &[ u8 ],
+)
+-> reqwest::RequestBuilder
+{
+  let Some( signer ) = &config.request_signer else
+  {
+    return request_builder;
+  };
+
+  signer.sign( method, url, body )
+    .into_iter()
+    .fold( request_builder, | builder, ( name, value ) | builder.header( name, value ) )
+}
+
 /// Send an HTTP request with comprehensive error handling
 ///
 /// This function handles the actual request transmission and captures
@@ -437,7 +640,11 @@ async fn send_request
       // Enhanced error classification
       if e.is_timeout()
       {
-        Error::NetworkError( format!( "Request timeout after {}s : {}", config.timeout_seconds, e ) )
+        match config.request_timeout()
+        {
+          Some( timeout ) => Error::NetworkError( format!( "Request timeout after {}ms : {}", timeout.as_millis(), e ) ),
+          None => Error::NetworkError( format!( "Request timeout : {e}" ) ),
+        }
       } else if e.is_connect()
       {
         Error::NetworkError( format!( "Connection failed to {url}: {e}" ) )
@@ -450,16 +657,194 @@ async fn send_request
     } )
 }
 
-/// Process HTTP response with comprehensive error handling and deserialization
+/// Send a request, through the interceptor if one is configured, returning the
+/// status, body size and result with the exchange's metrics
+///
+/// Errors building or sending the request are returned directly, like in
+/// [`execute`]; errors reading or parsing the response are part of the result.
+async fn exchange< R >
+(
+  client : &Client,
+  request : reqwest::Request,
+  config : &HttpConfig,
+)
+-> Result< ( u16, u64, Result< ( R, Option< RequestMetrics > ), Error > ), Error >
+where
+  R : for< 'de > Deserialize< 'de >,
+{
+  let Some( interceptor ) = &config.interceptor else
+  {
+    return Ok( match receive_parts( client, request, config ).await?
+    {
+      ( _, Ok( response ) ) => parse_parts( response, config ),
+      ( status, Err( error ) ) => ( status, 0, Err( error ) ),
+    } );
+  };
+
+  let mut parts = RequestParts::from_request( &request );
+  interceptor.before_request( &mut parts );
+
+  let response = match parts.take_response()
+  {
+    Some( response ) => response,
+    None => match receive_parts( client, parts.clone().into_request( &request )?, config ).await?
+    {
+      ( _, Ok( response ) ) => response,
+      ( status, Err( error ) ) => return Ok( ( status, 0, Err( error ) ) ),
+    },
+  };
+
+  interceptor.after_response( &response );
+  interceptor.after_exchange( &parts, &response );
+
+  Ok( parse_parts( response, config ) )
+}
+
+/// Deserialize a received response, returning its status, body size and result with its metrics
+fn parse_parts< R >( response : ResponseParts, config : &HttpConfig ) -> ( u16, u64, Result< ( R, Option< RequestMetrics > ), Error > )
+where
+  R : for< 'de > Deserialize< 'de >,
+{
+  let response_size = response.body.len() as u64;
+  let metrics = response.metrics;
+  let result = parse_response_text( response.status, response.body, config ).map( | parsed | ( parsed, metrics ) );
+  ( response.status, response_size, result )
+}
+
+/// Send `request` and read its response into [`ResponseParts`]
 ///
-/// This function handles response processing including:
-/// - Status code analysis and error classification
-/// - API error response parsing
-/// - JSON deserialization with error context
-/// - Structured logging of response details
-async fn process_response< R >
+/// The parts carry timing metrics with the `diagnostics_timing` feature. Errors
+/// sending the request are returned directly; errors reading the body are
+/// returned with the response status.
+async fn receive_parts
 (
-  response : reqwest::Response,
+  client : &Client,
+  request : reqwest::Request,
+  config : &HttpConfig,
+)
+-> Result< ( u16, Result< ResponseParts, Error > ), Error >
+{
+  #[ cfg( feature = "diagnostics_timing" ) ]
+  let ( response, stopwatch ) = timing::Stopwatch::send( send_request( client, request, config ) ).await;
+  #[ cfg( not( feature = "diagnostics_timing" ) ) ]
+  let response = send_request( client, request, config ).await;
+
+  let response = response?;
+  let status = response.status().as_u16();
+  let headers = response.headers().iter()
+    .filter_map( | ( name, value ) | value.to_str().ok().map( | value | ( name.as_str().to_string(), value.to_string() ) ) )
+    .collect();
+
+  let parts = read_response_text( response, config ).await.map( | body |
+  {
+    #[ cfg( feature = "diagnostics_timing" ) ]
+    let metrics = Some( stopwatch.stop() );
+    #[ cfg( not( feature = "diagnostics_timing" ) ) ]
+    let metrics = None;
+    ResponseParts { status, headers, body, metrics }
+  } );
+  Ok( ( status, parts ) )
+}
+
+/// Body of a response to a request sent with [`execute_stream`]
+#[ cfg( feature = "streaming" ) ]
+#[ derive( Debug ) ]
+pub( crate ) enum StreamBody
+{
+  /// Not read yet, so consumers can start before the body arrives, with the
+  /// `HttpConfig::max_response_bytes` limit to enforce while reading it
+  Pending( reqwest::Response, Option< usize > ),
+  /// Already read, because an interceptor had to see it
+  Ready( String ),
+}
+
+#[ cfg( feature = "streaming" ) ]
+impl StreamBody
+{
+  /// Read the whole body, enforcing the response size limit
+  pub( crate ) async fn text( self ) -> Result< String, Error >
+  {
+    match self
+    {
+      Self::Pending( response, Some( limit ) ) => read_body_limited( response, limit ).await,
+      Self::Pending( response, None ) => response.text().await
+        .map_err( |e| Error::NetworkError( format!( "Failed to read streaming response : {e}" ) ) ),
+      Self::Ready( body ) => Ok( body ),
+    }
+  }
+}
+
+/// Send a streaming request, returning its status and unparsed body
+///
+/// The request is built by the same path as [`execute`], so it carries the extra
+/// headers and signature, and goes through the interceptor. `headers` are added
+/// after signing. With an interceptor the body is read before returning, so
+/// `after_response` sees it like for any other request. Either way the body is
+/// held to `HttpConfig::max_response_bytes`.
+///
+/// # Errors
+///
+/// Returns [`Error::RequestBuilding`] or [`Error::NetworkError`] if the request
+/// cannot be built or sent; non-success statuses are returned to the caller.
+#[ cfg( feature = "streaming" ) ]
+pub( crate ) async fn execute_stream< T >
+(
+  client : &Client,
+  url : &str,
+  api_key : &str,
+  body : &T,
+  headers : &[ ( &'static str, &'static str ) ],
+  config : &HttpConfig,
+)
+-> Result< ( u16, StreamBody ), Error >
+where
+  T : Serialize,
+{
+  let mut request = build_request( client, Method::POST, url, api_key, Some( body ), config )?;
+  for ( name, value ) in headers
+  {
+    request.headers_mut().insert( *name, reqwest::header::HeaderValue::from_static( *value ) );
+  }
+
+  let Some( interceptor ) = &config.interceptor else
+  {
+    let response = send_request( client, request, config ).await?;
+    return Ok( ( response.status().as_u16(), StreamBody::Pending( response, config.max_response_bytes ) ) );
+  };
+
+  let mut parts = RequestParts::from_request( &request );
+  interceptor.before_request( &mut parts );
+
+  let response = match parts.take_response()
+  {
+    Some( response ) => response,
+    None => receive_parts( client, parts.clone().into_request( &request )?, config ).await?.1?,
+  };
+
+  interceptor.after_response( &response );
+  interceptor.after_exchange( &parts, &response );
+
+  Ok( ( response.status, StreamBody::Ready( response.body ) ) )
+}
+
+/// Read the response body, enforcing `HttpConfig::max_response_bytes`
+async fn read_response_text( response : reqwest::Response, config : &HttpConfig ) -> Result< String, Error >
+{
+  match config.max_response_bytes
+  {
+    Some( limit ) => read_body_limited( response, limit ).await,
+    None => response.text().await
+      .map_err( |e| Error::NetworkError(
+        format!( "Failed to read response body : {e}" )
+      ) ),
+  }
+}
+
+/// Classify a response body by status and deserialize it on success
+fn parse_response_text< R >
+(
+  status_code : u16,
+  response_text : String,
   #[ allow( unused_variables ) ]
   config : &HttpConfig,
 )
@@ -467,21 +852,12 @@ async fn process_response< R >
 where
   R : for< 'de > Deserialize< 'de >,
 {
-  let status = response.status();
-  let status_code = status.as_u16();
-
   #[ cfg( feature = "logging" ) ]
   if config.enable_logging
   {
-    debug!( "Received response with status : {}", status );
+    debug!( "Received response with status : {}", status_code );
   }
 
-  // Get response body text for processing
-  let response_text = response.text().await
-    .map_err( |e| Error::NetworkError(
-      format!( "Failed to read response body : {e}" )
-    ) )?;
-
   #[ cfg( feature = "logging" ) ]
   if config.enable_logging
   {
@@ -494,7 +870,7 @@ where
     debug!( "Response body : {}", log_content );
   }
 
-  if status.is_success()
+  if ( 200..300 ).contains( &status_code )
   {
     // Successful response - deserialize JSON
     serde_json ::from_str( &response_text )
@@ -525,6 +901,41 @@ where
   }
 }
 
+/// Read a response body while enforcing a maximum size
+///
+/// The declared `Content-Length` is checked first so oversized responses are
+/// rejected before any data is read; the body is then consumed chunk by chunk
+/// with a running total to catch chunked or mis-declared responses.
+async fn read_body_limited( mut response : reqwest::Response, limit : usize ) -> Result< String, Error >
+{
+  let exceeded = | size : String | Error::DeserializationError(
+    format!( "Response body of {size} bytes exceeds the configured limit of {limit} bytes" )
+  );
+
+  if let Some( declared ) = response.content_length()
+  {
+    if usize::try_from( declared ).map_or( true, | declared | declared > limit )
+    {
+      return Err( exceeded( declared.to_string() ) );
+    }
+  }
+
+  let mut body = Vec::new();
+  while let Some( chunk ) = response.chunk().await
+    .map_err( |e| Error::NetworkError(
+      format!( "Failed to read response body : {e}" )
+    ) )?
+  {
+    if body.len() + chunk.len() > limit
+    {
+      return Err( exceeded( format!( "at least {}", body.len() + chunk.len() ) ) );
+    }
+    body.extend_from_slice( &chunk );
+  }
+
+  Ok( String::from_utf8_lossy( &body ).into_owned() )
+}
+
 /// Classify and create appropriate error types from HTTP error responses
 ///
 /// This function provides comprehensive error classification based on:
@@ -552,12 +963,13 @@ fn classify_error_response( status_code : u16, response_text : &str ) -> Result<
     }
     else
     {
+      let details = Some( Box::new( api_error.error ) );
       match status_code
       {
-        400 => Err( Error::InvalidArgument( error_message ) ),
-        429 => Err( Error::RateLimitError( error_message ) ),
+        400 => Err( Error::InvalidArgument( error_message, details ) ),
+        429 => Err( Error::RateLimitError( error_message, details ) ),
         500..=599 => Err( Error::ServerError( error_message ) ),
-        _ => Err( Error::ApiError( error_message ) ),
+        _ => Err( Error::ApiError( error_message, details ) ),
       }
     }
   }
@@ -577,10 +989,10 @@ fn classify_error_response( status_code : u16, response_text : &str ) -> Result<
     {
       match status_code
       {
-        400 => Err( Error::InvalidArgument( error_message ) ),
-        429 => Err( Error::RateLimitError( error_message ) ),
+        400 => Err( Error::InvalidArgument( error_message, None ) ),
+        429 => Err( Error::RateLimitError( error_message, None ) ),
         500..=599 => Err( Error::ServerError( error_message ) ),
-        _ => Err( Error::ApiError( error_message ) ),
+        _ => Err( Error::ApiError( error_message, None ) ),
       }
     }
   }
@@ -606,6 +1018,50 @@ fn is_authentication_error( message : &str ) -> bool
   msg_lower.contains( "quota exceeded" )
 }
 
+/// Execute an HTTP request like [`execute`] with an optional per-call timeout
+///
+/// `timeout_override` bounds this call alone, so one slow request ( e.g. a long
+/// generation ) does not force raising the timeout for every request. The
+/// transport timeout is widened to cover the override, and the whole call is
+/// raced against it. `None` behaves exactly like [`execute`].
+///
+/// # Errors
+///
+/// Returns [`Error::TimeoutError`] when the override elapses, distinct from the
+/// [`Error::NetworkError`] reported when the transport timeout expires, and
+/// otherwise the same errors as [`execute`].
+#[ inline ]
+pub async fn execute_with_timeout< T, R >
+(
+  client : &Client,
+  method : Method,
+  url : &str,
+  api_key : &str,
+  body : Option< &T >,
+  config : &HttpConfig,
+  timeout_override : Option< Duration >,
+)
+->
+Result< R, Error >
+where
+  T : Serialize,
+  R : for< 'de > Deserialize< 'de >,
+{
+  let Some( timeout ) = timeout_override else
+  {
+    return execute( client, method, url, api_key, body, config ).await;
+  };
+
+  // Keep the transport timeout from firing before the override
+  let widened = timeout + Duration::from_secs( 1 );
+  let transport = config.request_timeout().map_or( widened, | configured | configured.max( widened ) );
+  let config = config.clone().with_timeout_duration( transport );
+
+  tokio::time::timeout( timeout, execute( client, method, url, api_key, body, &config ) )
+  .await
+  .map_err( | _ | Error::TimeoutError( format!( "Request exceeded per-call timeout of {}ms", timeout.as_millis() ) ) )?
+}
+
 /// Backward compatibility wrapper for the original execute function
 ///
 /// This function maintains the original API while internally using the new
@@ -708,3 +1164,172 @@ where
 // Type alias for never type until it's stabilized
 #[ allow( non_camel_case_types ) ]
 type never = core::convert::Infallible;
+
+#[ cfg( test ) ]
+mod tests
+{
+  use super::*;
+  use std::hash::{ Hash, Hasher };
+  use std::collections::hash_map::DefaultHasher;
+
+  /// Keyed hash standing in for an HMAC
+  #[ derive( Debug ) ]
+  struct FakeHmacSigner
+  {
+    secret : String,
+  }
+
+  impl FakeHmacSigner
+  {
+    fn signature( &self, method : &Method, url : &str, body : &[ u8 ] ) -> String
+    {
+      let mut hasher = DefaultHasher::new();
+      self.secret.hash( &mut hasher );
+      method.as_str().hash( &mut hasher );
+      url.hash( &mut hasher );
+      body.hash( &mut hasher );
+      format!( "{:016x}", hasher.finish() )
+    }
+  }
+
+  impl RequestSigner for FakeHmacSigner
+  {
+    fn sign( &self, method : &Method, url : &str, body : &[ u8 ] ) -> Vec< ( String, String ) >
+    {
+      vec![ ( "X-Signature".to_string(), self.signature( method, url, body ) ) ]
+    }
+  }
+
+  const URL : &str = "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.5-flash:generateContent";
+
+  fn signed_config() -> HttpConfig
+  {
+    HttpConfig::new().with_request_signer( std::sync::Arc::new( FakeHmacSigner { secret : "shared-secret".to_string() } ) )
+  }
+
+  fn header( request : &reqwest::Request, name : &str ) -> Option< String >
+  {
+    request.headers().get( name ).map( | value | value.to_str().unwrap().to_string() )
+  }
+
+  #[ test ]
+  fn test_signature_header_reflects_body()
+  {
+    let client = Client::new();
+    let config = signed_config();
+    let signer = FakeHmacSigner { secret : "shared-secret".to_string() };
+
+    let body = serde_json::json!( { "contents" : [ { "parts" : [ { "text" : "Hello" } ] } ] } );
+    let request = build_request( &client, Method::POST, URL, "test-key", Some( &body ), &config ).unwrap();
+
+    let sent = request.body().and_then( reqwest::Body::as_bytes ).unwrap();
+    let expected = signer.signature( &Method::POST, URL, sent );
+    assert_eq!( header( &request, "X-Signature" ), Some( expected ) );
+
+    let other_body = serde_json::json!( { "contents" : [ { "parts" : [ { "text" : "Goodbye" } ] } ] } );
+    let other = build_request( &client, Method::POST, URL, "test-key", Some( &other_body ), &config ).unwrap();
+    assert_ne!( header( &request, "X-Signature" ), header( &other, "X-Signature" ) );
+  }
+
+  #[ test ]
+  fn test_signer_invoked_without_body()
+  {
+    let client = Client::new();
+    let signer = FakeHmacSigner { secret : "shared-secret".to_string() };
+
+    let request = build_request( &client, Method::GET, URL, "test-key", None::< &() >, &signed_config() ).unwrap();
+    assert_eq!( header( &request, "X-Signature" ), Some( signer.signature( &Method::GET, URL, &[] ) ) );
+  }
+
+  #[ test ]
+  fn test_extra_headers_attached()
+  {
+    let client = Client::new();
+    let config = HttpConfig::new()
+      .with_header( "X-Request-ID", "req-1234" )
+      .with_header( "baggage", "tenant=acme" );
+
+    let request = build_request( &client, Method::POST, URL, "test-key", Some( &serde_json::json!( {} ) ), &config ).unwrap();
+    assert_eq!( header( &request, "x-request-id" ).as_deref(), Some( "req-1234" ) );
+    assert_eq!( header( &request, "baggage" ).as_deref(), Some( "tenant=acme" ) );
+    assert_eq!( header( &request, "content-type" ).as_deref(), Some( "application/json" ) );
+  }
+
+  #[ test ]
+  fn test_invalid_and_reserved_extra_headers_skipped()
+  {
+    let client = Client::new();
+    let config = HttpConfig::new()
+      .with_header( "Bad Header", "value" )
+      .with_header( "X-Newline", "line\nbreak" )
+      .with_header( "Content-Type", "text/plain" )
+      .with_header( "x-goog-api-key", "other-key" )
+      .with_header( "X-Valid", "ok" );
+
+    let request = build_request( &client, Method::GET, URL, "test-key", None::< &() >, &config ).unwrap();
+    assert!( request.headers().get( "x-newline" ).is_none() );
+    assert!( request.headers().get( "x-goog-api-key" ).is_none() );
+    assert_eq!( request.headers().get_all( "content-type" ).iter().count(), 1 );
+    assert_eq!( header( &request, "content-type" ).as_deref(), Some( "application/json" ) );
+    assert_eq!( header( &request, "x-valid" ).as_deref(), Some( "ok" ) );
+    assert_eq!( request.url().query(), Some( "key=test-key" ) );
+  }
+
+  #[ test ]
+  fn test_no_signature_without_signer()
+  {
+    let client = Client::new();
+    let body = serde_json::json!( { "contents" : [] } );
+
+    let request = build_request( &client, Method::POST, URL, "test-key", Some( &body ), &HttpConfig::new() ).unwrap();
+    assert!( request.headers().get( "X-Signature" ).is_none() );
+  }
+
+  /// Answer one request on a local socket with a chunked body of `len` bytes
+  #[ cfg( feature = "streaming" ) ]
+  fn serve_chunked( len : usize ) -> String
+  {
+    use std::io::{ Read, Write };
+
+    let listener = std::net::TcpListener::bind( "127.0.0.1:0" ).unwrap();
+    let address = listener.local_addr().unwrap();
+    std::thread::spawn( move ||
+    {
+      let ( mut socket, _ ) = listener.accept().unwrap();
+      let _ = socket.read( &mut [ 0_u8; 4096 ] );
+      let body = "a".repeat( len );
+      let response = format!(
+        "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n{len:x}\r\n{body}\r\n0\r\n\r\n"
+      );
+      let _ = socket.write_all( response.as_bytes() );
+    } );
+    format!( "http://{address}/v1beta/models/gemini-2.5-flash:streamGenerateContent" )
+  }
+
+  #[ cfg( feature = "streaming" ) ]
+  #[ tokio::test ]
+  async fn test_streamed_body_respects_size_limit()
+  {
+    let url = serve_chunked( 2048 );
+    let config = HttpConfig::new().with_max_response_bytes( 1024 );
+
+    let ( status, body ) = execute_stream( &Client::new(), &url, "test-key", &serde_json::json!( {} ), &[], &config ).await.unwrap();
+    assert_eq!( status, 200 );
+    match body.text().await
+    {
+      Err( Error::DeserializationError( msg ) ) => assert!( msg.contains( "limit of 1024 bytes" ), "unexpected message : {msg}" ),
+      other => panic!( "Expected DeserializationError for oversized streamed body, got : {other:?}" ),
+    }
+  }
+
+  #[ cfg( feature = "streaming" ) ]
+  #[ tokio::test ]
+  async fn test_streamed_body_under_limit_is_read()
+  {
+    let url = serve_chunked( 512 );
+    let config = HttpConfig::new().with_max_response_bytes( 1024 );
+
+    let ( _, body ) = execute_stream( &Client::new(), &url, "test-key", &serde_json::json!( {} ), &[], &config ).await.unwrap();
+    assert_eq!( body.text().await.unwrap().len(), 512 );
+  }
+}
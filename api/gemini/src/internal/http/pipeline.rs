@@ -0,0 +1,329 @@
+//! Composable reliability pipeline over the retry, circuit breaker, rate limiting and caching stages
+
+use core::future::Future;
+use std::sync::Arc;
+
+use reqwest::Method;
+use serde::{ Serialize, Deserialize };
+
+use crate::error::Error;
+
+#[ cfg( feature = "retry" ) ]
+use super::retry::{ RetryConfig, is_retryable_error, is_retry_allowed, calculate_retry_delay };
+
+#[ cfg( feature = "circuit_breaker" ) ]
+use super::circuit_breaker::{ CircuitBreaker, is_circuit_breaker_error };
+
+#[ cfg( feature = "rate_limiting" ) ]
+use super::rate_limiter::RateLimit;
+
+#[ cfg( feature = "caching" ) ]
+use super::cache::RequestCache;
+
+/// Stage of a [`ReliabilityPipeline`], reported to the stage observer as it is entered
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+pub enum PipelineStage
+{
+  /// Rate limiter admission check
+  RateLimit,
+  /// Circuit breaker check
+  CircuitBreaker,
+  /// Retry loop around the remaining stages
+  Retry,
+  /// Cache lookup, once per attempt
+  Cache,
+  /// The wrapped operation itself
+  Operation,
+}
+
+/// Callback invoked each time the pipeline enters a stage
+pub type StageObserver = Arc< dyn Fn( PipelineStage ) + Send + Sync >;
+
+/// Runs an operation through the enabled reliability features in a fixed order
+///
+/// Stages, outermost first:
+///
+/// 1. **Rate limit** – admits the call once. Retries are paced by the retry
+///    backoff and do not draw further tokens, so a retried call cannot be
+///    rejected halfway through by its own earlier attempts.
+/// 2. **Circuit breaker** – fails fast while the circuit is open, before any
+///    attempt is spent on a backend known to be down. It records the outcome
+///    of the whole retried call, so one flaky call counts as one failure.
+/// 3. **Retry** – replays transient failures of idempotent requests, as
+///    decided by `is_retry_allowed` from the method and endpoint.
+/// 4. **Cache** – consulted on every attempt of a GET request, innermost so
+///    that only successful responses are stored. A hit returns without
+///    running the operation.
+///
+/// A stage whose feature is disabled is compiled out; a stage that is not
+/// configured on the builder is skipped.
+#[ derive( Clone, Default ) ]
+pub struct ReliabilityPipeline
+{
+  #[ cfg( feature = "rate_limiting" ) ]
+  rate_limiter : Option< Arc< RateLimit > >,
+  #[ cfg( feature = "circuit_breaker" ) ]
+  circuit_breaker : Option< Arc< CircuitBreaker > >,
+  #[ cfg( feature = "retry" ) ]
+  retry_config : Option< RetryConfig >,
+  #[ cfg( feature = "caching" ) ]
+  cache : Option< Arc< RequestCache > >,
+  observer : Option< StageObserver >,
+}
+
+impl core::fmt::Debug for ReliabilityPipeline
+{
+  fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+  {
+    let mut debug = f.debug_struct( "ReliabilityPipeline" );
+    #[ cfg( feature = "rate_limiting" ) ]
+    debug.field( "rate_limiter", &self.rate_limiter.is_some() );
+    #[ cfg( feature = "circuit_breaker" ) ]
+    debug.field( "circuit_breaker", &self.circuit_breaker.is_some() );
+    #[ cfg( feature = "retry" ) ]
+    debug.field( "retry_config", &self.retry_config );
+    #[ cfg( feature = "caching" ) ]
+    debug.field( "cache", &self.cache.is_some() );
+    debug.field( "observer", &self.observer.is_some() ).finish()
+  }
+}
+
+impl ReliabilityPipeline
+{
+  /// Start building a pipeline with no stages configured
+  #[ inline ]
+  #[ must_use ]
+  pub fn builder() -> ReliabilityPipelineBuilder
+  {
+    ReliabilityPipelineBuilder::default()
+  }
+
+  /// Execute `operation` through the configured stages
+  ///
+  /// `method`, `url` and `body` identify the request for the retry policy and
+  /// the cache key; `operation` performs it and may be invoked several times
+  /// when retrying.
+  ///
+  /// # Errors
+  ///
+  /// Returns [`Error::RateLimited`] when the rate limiter rejects the call,
+  /// [`Error::CircuitBreakerOpen`] when the circuit is open, or the error of
+  /// the last attempt.
+  #[ allow( unused_variables ) ]
+  pub async fn execute< T, R, F, Fut >
+  (
+    &self,
+    method : &Method,
+    url : &str,
+    body : Option< &T >,
+    operation : F,
+  )
+  -> Result< R, Error >
+  where
+    T: Serialize,
+    R: Serialize + for< 'de > Deserialize< 'de >,
+    F: Fn() -> Fut,
+    Fut: Future< Output = Result< R, Error > >,
+  {
+    #[ cfg( feature = "rate_limiting" ) ]
+    if let Some( rl ) = &self.rate_limiter
+    {
+      self.enter( PipelineStage::RateLimit );
+      if !rl.should_allow_request().await
+      {
+        return Err( Error::RateLimited( "Rate limit exceeded".to_string() ) );
+      }
+    }
+
+    let result = self.guarded( method, url, body, &operation ).await;
+
+    // Feed server responses back to the rate limiter
+    #[ cfg( feature = "rate_limiting" ) ]
+    if let Some( rl ) = &self.rate_limiter
+    {
+      rl.record_outcome( &result );
+    }
+
+    result
+  }
+
+  /// Circuit breaker stage
+  async fn guarded< T, R, F, Fut >( &self, method : &Method, url : &str, body : Option< &T >, operation : &F ) -> Result< R, Error >
+  where
+    T: Serialize,
+    R: Serialize + for< 'de > Deserialize< 'de >,
+    F: Fn() -> Fut,
+    Fut: Future< Output = Result< R, Error > >,
+  {
+    #[ cfg( feature = "circuit_breaker" ) ]
+    if let Some( cb ) = &self.circuit_breaker
+    {
+      self.enter( PipelineStage::CircuitBreaker );
+      if !cb.should_allow_request()
+      {
+        return Err( Error::CircuitBreakerOpen( "Circuit breaker is open".to_string() ) );
+      }
+
+      let result = self.retried( method, url, body, operation ).await;
+      match &result
+      {
+        Ok( _ ) => cb.record_success(),
+        Err( error ) if is_circuit_breaker_error( error ) => cb.record_failure(),
+        _ => {} // Dont count non-circuit-breaker errors
+      }
+      return result;
+    }
+
+    self.retried( method, url, body, operation ).await
+  }
+
+  /// Retry stage
+  async fn retried< T, R, F, Fut >( &self, method : &Method, url : &str, body : Option< &T >, operation : &F ) -> Result< R, Error >
+  where
+    T: Serialize,
+    R: Serialize + for< 'de > Deserialize< 'de >,
+    F: Fn() -> Fut,
+    Fut: Future< Output = Result< R, Error > >,
+  {
+    #[ cfg( feature = "retry" ) ]
+    if let Some( retry_cfg ) = self.retry_config.as_ref().filter( | cfg | is_retry_allowed( method, url, None, cfg ) )
+    {
+      self.enter( PipelineStage::Retry );
+      let start_time = std::time::Instant::now();
+      let mut attempt = 1;
+
+      loop
+      {
+        match self.attempt( method, url, body, operation ).await
+        {
+          Ok( response ) => return Ok( response ),
+          Err( error ) =>
+          {
+            if !is_retryable_error( &error ) || attempt > retry_cfg.max_retries
+            {
+              return Err( error );
+            }
+            if retry_cfg.max_elapsed_time.is_some_and( | max_elapsed | start_time.elapsed() >= max_elapsed )
+            {
+              return Err( error );
+            }
+
+            tokio::time::sleep( calculate_retry_delay( attempt, retry_cfg ) ).await;
+            attempt += 1;
+          }
+        }
+      }
+    }
+
+    self.attempt( method, url, body, operation ).await
+  }
+
+  /// Cache stage and the operation itself
+  #[ allow( unused_variables ) ]
+  async fn attempt< T, R, F, Fut >( &self, method : &Method, url : &str, body : Option< &T >, operation : &F ) -> Result< R, Error >
+  where
+    T: Serialize,
+    R: Serialize + for< 'de > Deserialize< 'de >,
+    F: Fn() -> Fut,
+    Fut: Future< Output = Result< R, Error > >,
+  {
+    #[ cfg( feature = "caching" ) ]
+    // Only cache GET requests, as in `execute_with_cache`
+    if let Some( cache ) = self.cache.as_ref().filter( | _ | *method == Method::GET )
+    {
+      self.enter( PipelineStage::Cache );
+      if let Some( cached_response ) = cache.get::< T, R >( method, url, body )
+      {
+        return Ok( cached_response );
+      }
+
+      self.enter( PipelineStage::Operation );
+      let result = operation().await;
+      if let Ok( response ) = &result
+      {
+        cache.put( method, url, body, response );
+      }
+      return result;
+    }
+
+    self.enter( PipelineStage::Operation );
+    operation().await
+  }
+
+  fn enter( &self, stage : PipelineStage )
+  {
+    if let Some( observer ) = &self.observer
+    {
+      observer( stage );
+    }
+  }
+}
+
+/// Builder for [`ReliabilityPipeline`]
+#[ derive( Debug, Default ) ]
+pub struct ReliabilityPipelineBuilder
+{
+  pipeline : ReliabilityPipeline,
+}
+
+impl ReliabilityPipelineBuilder
+{
+  /// Admit calls through `rate_limiter`
+  #[ cfg( feature = "rate_limiting" ) ]
+  #[ inline ]
+  #[ must_use ]
+  pub fn rate_limit( mut self, rate_limiter : Arc< RateLimit > ) -> Self
+  {
+    self.pipeline.rate_limiter = Some( rate_limiter );
+    self
+  }
+
+  /// Guard calls with `circuit_breaker`
+  #[ cfg( feature = "circuit_breaker" ) ]
+  #[ inline ]
+  #[ must_use ]
+  pub fn circuit_breaker( mut self, circuit_breaker : Arc< CircuitBreaker > ) -> Self
+  {
+    self.pipeline.circuit_breaker = Some( circuit_breaker );
+    self
+  }
+
+  /// Retry transient failures according to `config`
+  #[ cfg( feature = "retry" ) ]
+  #[ inline ]
+  #[ must_use ]
+  pub fn retry( mut self, config : RetryConfig ) -> Self
+  {
+    self.pipeline.retry_config = Some( config );
+    self
+  }
+
+  /// Serve repeated requests from `cache`
+  #[ cfg( feature = "caching" ) ]
+  #[ inline ]
+  #[ must_use ]
+  pub fn cache( mut self, cache : Arc< RequestCache > ) -> Self
+  {
+    self.pipeline.cache = Some( cache );
+    self
+  }
+
+  /// Invoke `observer` each time a stage is entered, e.g. for tracing
+  #[ inline ]
+  #[ must_use ]
+  pub fn on_stage< F >( mut self, observer : F ) -> Self
+  where
+    F: Fn( PipelineStage ) + Send + Sync + 'static,
+  {
+    self.pipeline.observer = Some( Arc::new( observer ) );
+    self
+  }
+
+  /// Finish building the pipeline
+  #[ inline ]
+  #[ must_use ]
+  pub fn build( self ) -> ReliabilityPipeline
+  {
+    self.pipeline
+  }
+}
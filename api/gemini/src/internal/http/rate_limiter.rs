@@ -12,11 +12,20 @@ use crate::error::Error;
 #[ cfg( feature = "logging" ) ]
 use tracing::{ warn, debug };
 
+/// Fraction of the current rate kept after a 429 under the adaptive algorithm
+pub const ADAPTIVE_DECREASE_FACTOR : f64 = 0.5;
+
+/// Fraction of the ceiling added back per successful request under the adaptive algorithm
+pub const ADAPTIVE_INCREASE_STEP : f64 = 0.05;
+
+/// Lowest fraction of the ceiling the adaptive algorithm will reduce the rate to
+pub const ADAPTIVE_MIN_FRACTION : f64 = 0.05;
+
 /// Rate limiting configuration extracted from client for HTTP layer usage
 #[ derive( Debug, Clone ) ]
 pub struct RateLimitingConfig
 {
-  /// Maximum requests per second
+  /// Maximum requests per second; the ceiling for the adaptive algorithm
   pub requests_per_second : f64,
   /// Token bucket size (burst capacity)
   pub bucket_size : usize,
@@ -44,6 +53,19 @@ pub enum RateLimiter
     /// Queue of request timestamps
     requests : VecDeque< Instant >,
   },
+  /// Token bucket whose refill rate follows server feedback
+  ///
+  /// A 429 multiplies the rate by `ADAPTIVE_DECREASE_FACTOR`; each success adds
+  /// `ADAPTIVE_INCREASE_STEP` of the configured ceiling back, never exceeding it.
+  Adaptive
+  {
+    /// Current available tokens
+    tokens : f64,
+    /// Last refill timestamp
+    last_refill : Instant,
+    /// Refill rate currently in effect, in requests per second
+    effective_rate : f64,
+  },
 }
 
 /// Rate limiting metrics for monitoring
@@ -62,6 +84,10 @@ pub struct RateLimitingMetrics
   pub available_tokens : f64,
   /// Requests in current window (for sliding window)
   pub window_requests : usize,
+  /// Refill rate currently in effect; below the configured rate only while adaptive backs off
+  pub effective_rate : f64,
+  /// Number of 429 responses reported through `RateLimit::record_rate_limited`
+  pub server_rate_limits : u64,
 }
 
 /// Rate limiting instance with state management
@@ -83,6 +109,11 @@ impl RateLimit
       "sliding_window" => RateLimiter::SlidingWindow {
         requests : VecDeque::new(),
       },
+      "adaptive" => RateLimiter::Adaptive {
+        tokens : config.bucket_size as f64,
+        last_refill : Instant::now(),
+        effective_rate : config.requests_per_second,
+      },
       _ => RateLimiter::TokenBucket {
         tokens : config.bucket_size as f64,
         last_refill : Instant::now(),
@@ -99,6 +130,8 @@ impl RateLimit
         current_algorithm : config.algorithm,
         available_tokens : config.bucket_size as f64,
         window_requests : 0,
+        effective_rate : config.requests_per_second,
+        server_rate_limits : 0,
       } ) ),
     }
   }
@@ -114,25 +147,14 @@ impl RateLimit
     let allowed = match &mut *limiter
     {
       RateLimiter::TokenBucket { tokens, last_refill } => {
-        let now = Instant::now();
-        let elapsed = now.duration_since( *last_refill ).as_secs_f64();
-
-        // Refill tokens based on elapsed time
-        *tokens += elapsed * self.config.requests_per_second;
-        *tokens = tokens.min( self.config.bucket_size as f64 );
-        *last_refill = now;
-
-        if *tokens >= 1.0
-        {
-          *tokens -= 1.0;
-          metrics.available_tokens = *tokens;
-          true
-        } else {
-          #[ cfg( feature = "logging" ) ]
-          debug!( "Rate limit exceeded : {} tokens available", *tokens );
-          metrics.available_tokens = *tokens;
-          false
-        }
+        let allowed = Self::take_token( tokens, last_refill, self.config.requests_per_second, self.config.bucket_size );
+        metrics.available_tokens = *tokens;
+        allowed
+      },
+      RateLimiter::Adaptive { tokens, last_refill, effective_rate } => {
+        let allowed = Self::take_token( tokens, last_refill, *effective_rate, self.config.bucket_size );
+        metrics.available_tokens = *tokens;
+        allowed
       },
       RateLimiter::SlidingWindow { requests } => {
         let now = Instant::now();
@@ -173,6 +195,75 @@ impl RateLimit
     allowed
   }
 
+  /// Refill a token bucket at `rate` and take one token if available
+  fn take_token( tokens : &mut f64, last_refill : &mut Instant, rate : f64, bucket_size : usize ) -> bool
+  {
+    let now = Instant::now();
+    let elapsed = now.duration_since( *last_refill ).as_secs_f64();
+
+    // Refill tokens based on elapsed time
+    *tokens += elapsed * rate;
+    *tokens = tokens.min( bucket_size as f64 );
+    *last_refill = now;
+
+    if *tokens >= 1.0
+    {
+      *tokens -= 1.0;
+      true
+    } else {
+      #[ cfg( feature = "logging" ) ]
+      debug!( "Rate limit exceeded : {} tokens available", *tokens );
+      false
+    }
+  }
+
+  /// Report a request the server accepted
+  ///
+  /// Under the adaptive algorithm this raises the effective rate by one step toward
+  /// the configured ceiling; other algorithms ignore it.
+  pub fn record_success( &self )
+  {
+    let mut limiter = self.limiter.lock().unwrap();
+    if let RateLimiter::Adaptive { effective_rate, .. } = &mut *limiter
+    {
+      let ceiling = self.config.requests_per_second;
+      *effective_rate = ( *effective_rate + ceiling * ADAPTIVE_INCREASE_STEP ).min( ceiling );
+      self.metrics.lock().unwrap().effective_rate = *effective_rate;
+    }
+  }
+
+  /// Report a request the server rejected with 429
+  ///
+  /// Under the adaptive algorithm this cuts the effective rate by
+  /// `ADAPTIVE_DECREASE_FACTOR`, down to `ADAPTIVE_MIN_FRACTION` of the ceiling.
+  pub fn record_rate_limited( &self )
+  {
+    let mut limiter = self.limiter.lock().unwrap();
+    let mut metrics = self.metrics.lock().unwrap();
+    metrics.server_rate_limits += 1;
+
+    if let RateLimiter::Adaptive { effective_rate, .. } = &mut *limiter
+    {
+      let floor = self.config.requests_per_second * ADAPTIVE_MIN_FRACTION;
+      *effective_rate = ( *effective_rate * ADAPTIVE_DECREASE_FACTOR ).max( floor );
+      metrics.effective_rate = *effective_rate;
+
+      #[ cfg( feature = "logging" ) ]
+      warn!( "Server rate limit received, reducing request rate to {:.3}/s", *effective_rate );
+    }
+  }
+
+  /// Report the outcome of a request that passed this limiter
+  pub fn record_outcome< R >( &self, result : &Result< R, Error > )
+  {
+    match result
+    {
+      Ok( _ ) => self.record_success(),
+      Err( Error::RateLimitError( .. ) ) => self.record_rate_limited(),
+      Err( _ ) => {},
+    }
+  }
+
   /// Get current rate limiting metrics
   pub fn get_metrics( &self ) -> RateLimitingMetrics
   {
@@ -195,12 +286,156 @@ impl RateLimit
       RateLimiter::SlidingWindow { requests } => {
         requests.clear();
         metrics.window_requests = 0;
+      },
+      RateLimiter::Adaptive { tokens, last_refill, effective_rate } => {
+        *tokens = self.config.bucket_size as f64;
+        *last_refill = Instant::now();
+        *effective_rate = self.config.requests_per_second;
+        metrics.available_tokens = *tokens;
+        metrics.effective_rate = *effective_rate;
       }
     }
 
     metrics.total_requests = 0;
     metrics.limited_requests = 0;
     metrics.allowed_requests = 0;
+    metrics.server_rate_limits = 0;
+  }
+}
+
+/// Tokens-per-minute limiter driven by caller-supplied estimates
+///
+/// Each `acquire_tokens` call records its estimate in a rolling window; a call
+/// that would push the window total over the budget waits until enough earlier
+/// usage has rolled out. The limiter never counts tokens itself.
+#[ derive( Debug ) ]
+pub struct TokenRateLimiter
+{
+  tokens_per_window : u32,
+  window : Duration,
+  usage : Mutex< VecDeque< ( Instant, u32 ) > >,
+}
+
+impl TokenRateLimiter
+{
+  /// Create a limiter allowing `tokens_per_minute` tokens in any 60 second window
+  pub fn new( tokens_per_minute : u32 ) -> Self
+  {
+    Self {
+      tokens_per_window : tokens_per_minute,
+      window : Duration::from_secs( 60 ),
+      usage : Mutex::new( VecDeque::new() ),
+    }
+  }
+
+  /// Use a window other than one minute, keeping the same token budget
+  #[ must_use ]
+  pub fn with_window( mut self, window : Duration ) -> Self
+  {
+    self.window = window;
+    self
+  }
+
+  /// Token budget per window
+  pub fn budget( &self ) -> u32
+  {
+    self.tokens_per_window
+  }
+
+  /// Length of the rolling window
+  pub fn window( &self ) -> Duration
+  {
+    self.window
+  }
+
+  /// Tokens still available in the current window
+  pub fn available_tokens( &self ) -> u32
+  {
+    let mut usage = self.usage.lock().unwrap();
+    let used = Self::prune( &mut usage, self.window, Instant::now() );
+    self.tokens_per_window.saturating_sub( used )
+  }
+
+  /// Reserve `estimated` tokens without waiting, returning whether they fit
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidArgument` if `estimated` exceeds the whole budget.
+  pub fn try_acquire_tokens( &self, estimated : u32 ) -> Result< bool, Error >
+  {
+    self.check_estimate( estimated )?;
+    Ok( self.reserve( estimated ).is_none() )
+  }
+
+  /// Reserve `estimated` tokens, waiting until the window has room for them
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidArgument` if `estimated` exceeds the whole budget,
+  /// since such a request could never be admitted.
+  pub async fn acquire_tokens( &self, estimated : u32 ) -> Result< (), Error >
+  {
+    self.check_estimate( estimated )?;
+
+    while let Some( wait ) = self.reserve( estimated )
+    {
+      #[ cfg( feature = "logging" ) ]
+      debug!( "Token budget exhausted, waiting {:?} for {} tokens", wait, estimated );
+
+      tokio::time::sleep( wait ).await;
+    }
+
+    Ok( () )
+  }
+
+  /// Reject estimates that can never fit in the budget
+  fn check_estimate( &self, estimated : u32 ) -> Result< (), Error >
+  {
+    if estimated > self.tokens_per_window
+    {
+      return Err( Error::InvalidArgument(
+        format!( "Estimated {estimated} tokens exceed the budget of {} per window", self.tokens_per_window ),
+        None
+      ) );
+    }
+    Ok( () )
+  }
+
+  /// Record the usage if it fits, otherwise return how long until enough usage expires
+  fn reserve( &self, estimated : u32 ) -> Option< Duration >
+  {
+    let now = Instant::now();
+    let mut usage = self.usage.lock().unwrap();
+    let used = Self::prune( &mut usage, self.window, now );
+
+    if used.saturating_add( estimated ) <= self.tokens_per_window
+    {
+      usage.push_back( ( now, estimated ) );
+      return None;
+    }
+
+    // Find the oldest entry whose expiry frees enough room
+    let mut freed = 0_u32;
+    for ( at, tokens ) in usage.iter()
+    {
+      freed = freed.saturating_add( *tokens );
+      if used - freed + estimated <= self.tokens_per_window
+      {
+        return Some( ( *at + self.window ).saturating_duration_since( now ) );
+      }
+    }
+
+    Some( self.window )
+  }
+
+  /// Drop usage older than the window and return the remaining total
+  fn prune( usage : &mut VecDeque< ( Instant, u32 ) >, window : Duration, now : Instant ) -> u32
+  {
+    while usage.front().is_some_and( | ( at, _ ) | now.duration_since( *at ) >= window )
+    {
+      usage.pop_front();
+    }
+    usage.iter().fold( 0_u32, | total, ( _, tokens ) | total.saturating_add( *tokens ) )
   }
 }
 
@@ -237,5 +472,7 @@ where
   }
 
   // Execute the request
-  super ::execute( client, method, url, api_key, body, config ).await
+  let result = super::execute( client, method, url, api_key, body, config ).await;
+  rl.record_outcome( &result );
+  result
 }
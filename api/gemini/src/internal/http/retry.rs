@@ -28,6 +28,11 @@ pub struct RetryConfig
   pub enable_jitter : bool,
   /// Maximum total elapsed time for all retries
   pub max_elapsed_time : Option< Duration >,
+  /// Whether requests that are not known to be idempotent may be retried
+  ///
+  /// When `false` (the default), only idempotent requests are replayed (see
+  /// [`is_idempotent_request`]), or calls explicitly marked idempotent by the caller.
+  pub retry_non_idempotent : bool,
 }
 
 /// Retry metrics for tracking retry behavior
@@ -53,24 +58,73 @@ pub fn is_retryable_error( error : &Error ) -> bool
     Error::NetworkError( _ ) => true,
     Error::ServerError( _ ) => true,
     Error::TimeoutError( _ ) => true,
-    Error::RateLimitError( _ ) => true,
+    Error::RateLimitError( .. ) => true,
 
     // Non-retryable errors (permanent failures)
     Error::AuthenticationError( _ ) => false,
-    Error::InvalidArgument( _ ) => false,
+    Error::InvalidArgument( _, _ ) => false,
     Error::DeserializationError( _ ) => false,
     Error::SerializationError( _ ) => false,
     Error::RequestBuilding( _ ) => false,
     Error::NotFound( _ ) => false,
 
     // API errors could be either, but typically should not be retried
-    Error::ApiError( _ ) => false,
+    Error::ApiError( _, _ ) => false,
 
     // Unknown errors and other types default to non-retryable for safety
     _ => false,
   }
 }
 
+/// Determines if an HTTP method is idempotent by default for retry purposes
+///
+/// Only `GET` and `HEAD` are treated as safe to replay. Other methods (notably `POST`)
+/// must be explicitly marked idempotent by the caller to be retried.
+pub fn is_idempotent_method( method : &Method ) -> bool
+{
+  matches!( *method, Method::GET | Method::HEAD )
+}
+
+/// Gemini custom methods ( the `:action` suffix of the URL ) that are safe to replay
+///
+/// They use `POST` only to carry a request body: the result is computed from that
+/// body and nothing is created or changed on the server, so repeating one after a
+/// transient failure cannot duplicate side effects.
+pub const IDEMPOTENT_ACTIONS : &[ &str ] = &
+[
+  "generateContent",
+  "streamGenerateContent",
+  "countTokens",
+  "embedContent",
+  "batchEmbedContents",
+];
+
+/// Determines if a request is idempotent for retry purposes
+///
+/// `GET` and `HEAD` requests are idempotent, as are `POST` requests to one of the
+/// [`IDEMPOTENT_ACTIONS`].
+pub fn is_idempotent_request( method : &Method, url : &str ) -> bool
+{
+  if is_idempotent_method( method )
+  {
+    return true;
+  }
+
+  let path = url.split( [ '?', '#' ] ).next().unwrap_or_default();
+  let action = path.rsplit( '/' ).next().and_then( | segment | segment.split_once( ':' ) ).map( | ( _, action ) | action );
+  *method == Method::POST && action.is_some_and( | action | IDEMPOTENT_ACTIONS.contains( &action ) )
+}
+
+/// Determines if a request may be replayed after a retryable failure
+///
+/// `idempotent` is the per-call override; when `None`, idempotency is derived from
+/// the method and endpoint (see [`is_idempotent_request`]).
+/// `RetryConfig::retry_non_idempotent` allows retrying regardless.
+pub fn is_retry_allowed( method : &Method, url : &str, idempotent : Option< bool >, config : &RetryConfig ) -> bool
+{
+  config.retry_non_idempotent || idempotent.unwrap_or_else( || is_idempotent_request( method, url ) )
+}
+
 /// Calculate retry delay with exponential backoff and optional jitter
 pub fn calculate_retry_delay(
   attempt : u32,
@@ -100,6 +154,9 @@ pub fn calculate_retry_delay(
 }
 
 /// Execute HTTP request with retry logic
+///
+/// `idempotent` overrides whether this call may be replayed; `None` derives it from
+/// the method and endpoint (see [`is_retry_allowed`]). Non-idempotent calls are attempted once.
 pub async fn execute_with_retries< T, R >
 (
   client : &Client,
@@ -109,6 +166,7 @@ pub async fn execute_with_retries< T, R >
   body : Option< &T >,
   config : &super::HttpConfig,
   retry_config : Option< &RetryConfig >,
+  idempotent : Option< bool >,
 )
 -> Result< R, Error >
 where
@@ -120,6 +178,12 @@ where
     return super::execute( client, method.clone(), url, api_key, body, config ).await;
   };
 
+  if !is_retry_allowed( &method, url, idempotent, retry_config )
+  {
+    // Replaying a non-idempotent call could duplicate side effects
+    return super::execute( client, method, url, api_key, body, config ).await;
+  }
+
   let start_time = Instant::now();
   let mut attempt = 1;
   let mut _last_error = None;
@@ -0,0 +1,41 @@
+//! Request signing for gateways that authenticate requests by signature
+
+use reqwest::Method;
+
+/// Computes authentication headers for an outgoing request
+///
+/// Some deployments front the API with a gateway that requires a signature
+/// ( typically an HMAC ) over the request body. A signer is configured
+/// explicitly with `ClientBuilder::with_request_signer` and is invoked once per
+/// request, after the body is serialized and compressed, so the signature
+/// covers exactly the bytes sent.
+///
+/// # Examples
+///
+/// ```rust
+/// use api_gemini::RequestSigner;
+/// use reqwest::Method;
+///
+/// #[ derive( Debug ) ]
+/// struct GatewaySigner
+/// {
+///   key_id : String,
+/// }
+///
+/// impl RequestSigner for GatewaySigner
+/// {
+///   fn sign( &self, method : &Method, url : &str, body : &[ u8 ] ) -> Vec< ( String, String ) >
+///   {
+///     let signature = format!( "{method}:{url}:{}", body.len() ); // replace with a real HMAC
+///     vec![ ( "X-Key-Id".to_string(), self.key_id.clone() ), ( "X-Signature".to_string(), signature ) ]
+///   }
+/// }
+/// ```
+pub trait RequestSigner : Send + Sync + core::fmt::Debug
+{
+  /// Return the headers to add to the request
+  ///
+  /// `body` is empty for requests without a body. The `url` excludes the API
+  /// key query parameter.
+  fn sign( &self, method : &Method, url : &str, body : &[ u8 ] ) -> Vec< ( String, String ) >;
+}
@@ -0,0 +1,172 @@
+//! Phase timing for [`RequestMetrics`] (requires the `diagnostics_timing` feature)
+//!
+//! reqwest resolves and connects inside its connector, out of sight of the
+//! request future. [`TimingResolver`] and [`ConnectTimingLayer`] are installed on
+//! the HTTP client by `ClientBuilder::build` and record into the phases of the
+//! request being sent, found through a task-local set by [`Stopwatch::send`].
+//! Requests on a pooled connection record neither phase.
+
+use super::RequestMetrics;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{ Context, Poll };
+use core::time::Duration;
+use std::net::ToSocketAddrs;
+use std::sync::{ Arc, Mutex, PoisonError };
+use std::time::Instant;
+
+/// Connection setup observed while sending one request
+#[ derive( Debug, Default, Clone, Copy ) ]
+struct ConnectionPhases
+{
+  /// DNS resolution
+  dns : Option< Duration >,
+  /// The whole connector call : DNS resolution, TCP connect and TLS handshake
+  connector : Option< Duration >,
+}
+
+type PhasesSlot = Arc< Mutex< ConnectionPhases > >;
+
+type BoxError = Box< dyn std::error::Error + Send + Sync >;
+
+tokio::task_local!
+{
+  static PHASES : PhasesSlot;
+}
+
+/// The slot of the request being sent on this task, if any
+fn current_slot() -> Option< PhasesSlot >
+{
+  PHASES.try_with( Clone::clone ).ok()
+}
+
+fn record( slot : Option< &PhasesSlot >, update : impl FnOnce( &mut ConnectionPhases ) )
+{
+  if let Some( slot ) = slot
+  {
+    update( &mut slot.lock().unwrap_or_else( PoisonError::into_inner ) );
+  }
+}
+
+/// Timing of one exchange, from sending the request until its body is read
+#[ derive( Debug ) ]
+pub( crate ) struct Stopwatch
+{
+  sent : Instant,
+  headers : Duration,
+  phases : ConnectionPhases,
+}
+
+impl Stopwatch
+{
+  /// Await `sending`, recording connection setup and the time until the response headers arrived
+  pub( crate ) async fn send< F : Future >( sending : F ) -> ( F::Output, Self )
+  {
+    let sent = Instant::now();
+    let slot = PhasesSlot::default();
+    let output = PHASES.scope( slot.clone(), sending ).await;
+    let headers = sent.elapsed();
+    let phases = *slot.lock().unwrap_or_else( PoisonError::into_inner );
+    ( output, Self { sent, headers, phases } )
+  }
+
+  /// Metrics of the exchange, once the body has been read
+  pub( crate ) fn stop( &self ) -> RequestMetrics
+  {
+    let total = self.sent.elapsed();
+    let dns = self.phases.dns;
+    let connect = self.phases.connector.map( | connector | connector.saturating_sub( dns.unwrap_or_default() ) );
+    let setup = dns.unwrap_or_default() + connect.unwrap_or_default();
+    RequestMetrics
+    {
+      dns,
+      connect,
+      time_to_first_byte : self.headers.saturating_sub( setup ),
+      download : total.saturating_sub( self.headers ),
+      total,
+    }
+  }
+}
+
+/// System resolver that records how long resolution took
+///
+/// Resolves with `getaddrinfo` on a blocking thread, like reqwest's default resolver.
+#[ derive( Debug, Default, Clone, Copy ) ]
+pub struct TimingResolver;
+
+impl reqwest::dns::Resolve for TimingResolver
+{
+  #[ inline ]
+  fn resolve( &self, name : reqwest::dns::Name ) -> reqwest::dns::Resolving
+  {
+    let slot = current_slot();
+    let host = name.as_str().to_string();
+    Box::pin( async move
+    {
+      let started = Instant::now();
+      let addresses = tokio::task::spawn_blocking( move || ( host.as_str(), 0 ).to_socket_addrs().map( Iterator::collect::< Vec< _ > > ) )
+      .await
+      .map_err( | error | Box::new( error ) as BoxError )??;
+      record( slot.as_ref(), | phases | phases.dns = Some( started.elapsed() ) );
+      let addresses : reqwest::dns::Addrs = Box::new( addresses.into_iter() );
+      Ok::< _, BoxError >( addresses )
+    } )
+  }
+}
+
+/// Connector layer that records how long opening a connection took
+///
+/// Wraps reqwest's whole connector, so the recorded time covers DNS resolution,
+/// TCP connect and, for HTTPS, the TLS handshake.
+#[ derive( Debug, Default, Clone, Copy ) ]
+pub struct ConnectTimingLayer;
+
+impl< S > tower_layer::Layer< S > for ConnectTimingLayer
+{
+  type Service = ConnectTiming< S >;
+
+  #[ inline ]
+  fn layer( &self, inner : S ) -> Self::Service
+  {
+    ConnectTiming { inner }
+  }
+}
+
+/// Connector service produced by [`ConnectTimingLayer`]
+#[ derive( Debug, Clone ) ]
+pub struct ConnectTiming< S >
+{
+  inner : S,
+}
+
+impl< S, Target > tower_service::Service< Target > for ConnectTiming< S >
+where
+  S : tower_service::Service< Target >,
+  S::Future : Send + 'static,
+  S::Response : 'static,
+  S::Error : 'static,
+{
+  type Response = S::Response;
+  type Error = S::Error;
+  type Future = Pin< Box< dyn Future< Output = Result< S::Response, S::Error > > + Send > >;
+
+  #[ inline ]
+  fn poll_ready( &mut self, cx : &mut Context< '_ > ) -> Poll< Result< (), S::Error > >
+  {
+    self.inner.poll_ready( cx )
+  }
+
+  #[ inline ]
+  fn call( &mut self, target : Target ) -> Self::Future
+  {
+    let slot = current_slot();
+    let started = Instant::now();
+    let connecting = self.inner.call( target );
+    Box::pin( async move
+    {
+      let connection = connecting.await;
+      record( slot.as_ref(), | phases | phases.connector = Some( started.elapsed() ) );
+      connection
+    } )
+  }
+}
@@ -90,9 +90,19 @@ pub mod templates;
 #[ cfg( feature = "buffered_streaming" ) ]
 pub mod buffered_streaming;
 
+/// Deterministic mock transport for offline testing
+#[ cfg( feature = "testing" ) ]
+pub mod testing;
+
 // Re-export key types at the top level for easier access
 pub use models::*;
 
+// Re-export request signing so signers can be implemented without reaching into `internal`
+pub use internal::http::RequestSigner;
+
+// Re-export interception types so interceptors can be implemented without reaching into `internal`
+pub use internal::http::{ Interceptor, RequestMetrics, RequestParts, ResponseParts };
+
 // Re-export compression types when feature is enabled
 #[ cfg( feature = "compression" ) ]
 pub use internal::http::compression::{ CompressionConfig, CompressionAlgorithm };
@@ -122,6 +132,7 @@ pub use models::streaming_control::
   StreamMetrics,
   StreamMetricsSnapshot,
   BufferStrategy,
+  OverflowPolicy,
   MetricsLevel,
   ControllableStream,
   ControllableStreamBuilder,
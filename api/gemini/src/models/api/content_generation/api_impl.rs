@@ -103,7 +103,7 @@ impl ModelApi< '_ >
     if request.contents.is_empty()
     {
       return Err( Error::InvalidArgument( 
-        "Generate content request cannot have empty contents. Please provide at least one content item.".to_string()
+        "Generate content request cannot have empty contents. Please provide at least one content item.".to_string(), None
       ) );
     }
 
@@ -124,6 +124,55 @@ impl ModelApi< '_ >
     .await
     .map_err( |e| self.enhance_model_operation_error( "generate content", e ) )
   }
+  /// Generates content with a timeout for this call only.
+  ///
+  /// Use this for a request expected to run longer ( or shorter ) than the
+  /// client-wide timeout, e.g. a long generation, without changing the timeout
+  /// of every other request. The request is sent once, bypassing the client's
+  /// retry, circuit breaker, rate limiting and caching, so `timeout` bounds the
+  /// total time spent.
+  ///
+  /// # Errors
+  ///
+  /// - [`Error::TimeoutError`] - No response within `timeout`
+  /// - Otherwise the same errors as [`Self::generate_content`]
+  #[ inline ]
+  pub async fn generate_content_with_timeout
+  (
+    &self,
+    request : &crate::models::GenerateContentRequest,
+    timeout : core::time::Duration,
+  )
+  ->
+  Result< crate::models::GenerateContentResponse, Error >
+  {
+    if request.contents.is_empty()
+    {
+      return Err( Error::InvalidArgument(
+        "Generate content request cannot have empty contents. Please provide at least one content item.".to_string(), None
+      ) );
+    }
+
+    let url = format!(
+      "{}/v1beta/models/{}:generateContent",
+      self.client.base_url,
+      self.model_id
+    );
+
+    http ::execute_with_timeout
+    (
+      &self.client.http,
+      Method::POST,
+      &url,
+      &self.client.api_key,
+      Some( request ),
+      &http::enterprise::client_http_config( self.client ),
+      Some( timeout ),
+    )
+    .await
+    .map_err( |e| self.enhance_model_operation_error( "generate content", e ) )
+  }
+
   /// Generates content with retry logic and exponential backoff.
   ///
   /// This method is similar to [`Self::generate_content`] but includes automatic retry
@@ -194,7 +243,7 @@ impl ModelApi< '_ >
       if current_attempt >= max_retries
       {
         return Err( backoff::Error::permanent( 
-          Error::ApiError( format!( "Maximum retry attempts ({max_retries}) exceeded" ) )
+          Error::ApiError( format!( "Maximum retry attempts ({max_retries}) exceeded" ), None )
         ) );
       }
 
@@ -207,20 +256,20 @@ impl ModelApi< '_ >
           match &error
           {
             // API errors from rate limiting (429) are retryable  
-            Error::ApiError( msg ) if msg.contains( "429" ) || msg.contains( "Rate limit" ) => 
+            Error::ApiError( msg, _ ) if msg.contains( "429" ) || msg.contains( "Rate limit" ) => 
             {
               Err( backoff::Error::transient( error ) )
             },
             
             // HTTP 5xx server errors are retryable
-            Error::ApiError( msg ) if msg.contains( "502" ) || msg.contains( "503" ) || 
+            Error::ApiError( msg, _ ) if msg.contains( "502" ) || msg.contains( "503" ) || 
                                       msg.contains( "504" ) || msg.contains( "408" ) => 
             {
               Err( backoff::Error::transient( error ) )
             },
             
             // Timeout errors are retryable
-            Error::ApiError( msg ) if msg.contains( "timeout" ) || msg.contains( "Timeout" ) => 
+            Error::ApiError( msg, _ ) if msg.contains( "timeout" ) || msg.contains( "Timeout" ) => 
             {
               Err( backoff::Error::transient( error ) )
             },
@@ -329,15 +378,90 @@ impl ModelApi< '_ >
     // Validate request
     Self::validate_generate_content_request( request )?;
     
-    // Build streaming request
-    let stream_request = self.build_streaming_request( request );
-    
     // Execute streaming request
-    let response = self.execute_streaming_request( stream_request ).await?;
+    let response = self.execute_streaming_request( request ).await?;
     
     // Process streaming response with optimized parsing
     Ok( Self::process_streaming_response( response ) )
   }
+  /// Generates content via streaming, delivering each text fragment to a callback.
+  ///
+  /// This is a convenience bridge over [`Self::generate_content_stream`] for callers
+  /// that do not want to drive a `Stream` themselves. `on_token` receives every text
+  /// fragment as it is parsed, `on_done` receives the assembled response once the
+  /// stream completes, and the same assembled response is returned.
+  ///
+  /// # Errors
+  ///
+  /// Returns the same errors as [`Self::generate_content_stream`], plus any error
+  /// yielded while consuming the stream.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// # use api_gemini::{ client::Client, GenerateContentRequest, Content, Part };
+  /// # #[ tokio::main ]
+  /// # async fn main() -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::new()?;
+  /// let models_api = client.models();
+  /// let model = models_api.by_name( "gemini-2.5-flash" );
+  ///
+  /// let request = GenerateContentRequest {
+  ///   contents : vec![ Content {
+  ///     parts : vec![ Part { text : Some( "Tell me a story".to_string() ), ..Default::default() } ],
+  ///     role : "user".to_string(),
+  ///   } ],
+  ///   ..Default::default()
+  /// };
+  ///
+  /// let response = model.generate_content_streaming_callback(
+  ///   &request,
+  ///   | token | print!( "{}", token ),
+  ///   | done | println!( "\nCandidates : {}", done.candidates.len() ),
+  /// ).await?;
+  /// # Ok( () )
+  /// # }
+  /// ```
+  #[ cfg( feature = "streaming" ) ]
+  #[ inline ]
+  pub async fn generate_content_streaming_callback
+  (
+    &self,
+    request : &crate::models::GenerateContentRequest,
+    on_token : impl FnMut( &str ),
+    on_done : impl FnOnce( GenerateContentResponse ),
+  )
+  ->
+  Result< GenerateContentResponse, Error >
+  {
+    let stream = self.generate_content_stream( request ).await?;
+    crate ::models::drive_stream_with_callbacks( stream, on_token, on_done ).await
+  }
+
+  /// Generates content via streaming, resuming the stream after connection drops.
+  ///
+  /// See [`crate::models::ResilientStream`] for the resumption strategy and limits.
+  /// Request validation errors are yielded as the first stream item.
+  #[ cfg( feature = "streaming" ) ]
+  #[ inline ]
+  pub fn generate_content_stream_resilient
+  (
+    &self,
+    request : &crate::models::GenerateContentRequest,
+    resilient : &crate::models::ResilientStream,
+  )
+  -> impl futures::Stream< Item = Result< crate::models::ResilientChunk, Error > > + '_
+  {
+    let connect = move | request : crate::models::GenerateContentRequest | async move
+    {
+      Self::validate_generate_content_request( &request )?;
+      let response = self.execute_streaming_request( &request ).await?;
+      Ok( Self::process_streaming_response( response ) )
+    };
+
+    resilient.stream( request.clone(), connect )
+  }
+
   /// Create a streaming request builder for more ergonomic API usage.
   ///
   /// # Examples
@@ -401,7 +525,7 @@ impl ModelApi< '_ >
   /// # Ok( () )
   /// # }
   /// ```
-  #[ cfg( feature = "streaming" ) ]
+  #[ cfg( all( feature = "streaming", feature = "streaming_control" ) ) ]
   #[ must_use ]
   #[ inline ]
   pub fn stream_controllable( &self ) -> crate::models::streaming_control::ControllableStreamBuilder< '_ >
@@ -470,7 +594,7 @@ impl ModelApi< '_ >
       .cloned()
       .ok_or_else( || Error::ApiError( 
         format!( "No text content returned from model '{}'. The model may have been blocked by safety filters or returned an unexpected response format.", 
-          self.model_id )
+          self.model_id ), None
       ) )
   }
   /// Generates content with custom generation configuration.
@@ -553,7 +677,7 @@ impl ModelApi< '_ >
       .cloned()
       .ok_or_else( || Error::ApiError( 
         format!( "No text content returned from model '{}'. The response may have been blocked by safety filters.", 
-          self.model_id )
+          self.model_id ), None
       ) )
   }
   /// Continues a multi-turn conversation by adding a user message.
@@ -646,7 +770,7 @@ impl ModelApi< '_ >
       .cloned()
       .ok_or_else( || Error::ApiError( 
         format!( "No text content returned from model '{}' during conversation.", 
-          self.model_id )
+          self.model_id ), None
       ) )?;
 
     // Add assistant response to conversation history
@@ -810,16 +934,19 @@ impl ModelApi< '_ >
     if request.contents.is_empty()
     {
       return Err( Error::InvalidArgument( 
-        "Generate content request cannot have empty contents. Please provide at least one content item.".to_string()
+        "Generate content request cannot have empty contents. Please provide at least one content item.".to_string(), None
       ) );
     }
     Ok( () )
   }
 
-  /// Build streaming HTTP request with optimized headers
+  /// Send a streaming request with enhanced error handling
+  ///
+  /// The request is built by the shared HTTP layer, so it carries the configured
+  /// extra headers and signature and goes through the interceptor.
   #[ cfg( feature = "streaming" ) ]
   #[ inline ]
-  fn build_streaming_request( &self, request : &crate::models::GenerateContentRequest ) -> reqwest::RequestBuilder
+  async fn execute_streaming_request( &self, request : &crate::models::GenerateContentRequest ) -> Result< crate::internal::http::StreamBody, Error >
   {
     let url = format!(
       "{}/v1beta/models/{}:streamGenerateContent",
@@ -827,34 +954,23 @@ impl ModelApi< '_ >
       self.model_id
     );
 
-    // Use client's configured HTTP client for connection reuse
     // Gemini API returns newline-delimited JSON (NDJSON) for streaming
-    self.client.http
-      .post( &url )
-      .header( "Accept", "application/json" )
-      .header( "Cache-Control", "no-cache" )
-      .header( "Connection", "keep-alive" )
-      .header( "User-Agent", "api_gemini/0.2.0" )
-      .query( &[ ( "key", &*self.client.api_key ) ] )
-      .json( request )
-  }
+    let headers = [
+      ( "accept", "application/json" ),
+      ( "cache-control", "no-cache" ),
+      ( "connection", "keep-alive" ),
+    ];
+    let http_config = crate::internal::http::enterprise::client_http_config( self.client );
 
-  /// Execute streaming request with enhanced error handling
-  #[ cfg( feature = "streaming" ) ]
-  #[ inline ]
-  async fn execute_streaming_request( &self, request : reqwest::RequestBuilder ) -> Result< reqwest::Response, Error >
-  {
-    let response = request
-      .send()
+    let ( status, body ) = crate::internal::http::execute_stream( &self.client.http, &url, &self.client.api_key, request, &headers, &http_config )
       .await
-      .map_err( |e| self.enhance_model_operation_error( "initiate streaming content generation", e.into() ) )?;
+      .map_err( |e| self.enhance_model_operation_error( "initiate streaming content generation", e ) )?;
 
-    if !response.status().is_success()
+    if !( 200..300 ).contains( &status )
     {
-      let status = response.status();
-      let error_text = response.text().await.unwrap_or_else( |_| "Unknown error".to_string() );
-      
-      let enhanced_error = match status.as_u16()
+      let error_text = body.text().await.unwrap_or_else( |_| "Unknown error".to_string() );
+
+      let enhanced_error = match status
       {
         429 => format!( "Rate limit exceeded for streaming requests. Please reduce request frequency. Details : {error_text}" ),
         500..=599 => format!( "Server error during streaming request ({status}). This may be temporary - please retry. Details : {error_text}" ),
@@ -862,11 +978,11 @@ impl ModelApi< '_ >
         403 => format!( "Streaming requests not authorized for your API key. Please check permissions. Details : {error_text}" ),
         _ => format!( "Streaming request failed with HTTP {status}. Details : {error_text}" ),
       };
-      
-      return Err( Error::ApiError( enhanced_error ) );
+
+      return Err( Error::ApiError( enhanced_error, None ) );
     }
 
-    Ok( response )
+    Ok( body )
   }
 
   /// Process Gemini streaming response by buffering and parsing as JSON array.
@@ -917,7 +1033,7 @@ impl ModelApi< '_ >
   /// and response sizes are limited by API constraints.
   #[ cfg( feature = "streaming" ) ]
   #[ inline ]
-  fn process_streaming_response( response : reqwest::Response ) -> impl futures::Stream< Item = Result< crate::models::StreamingResponse, Error > >
+  fn process_streaming_response( response : crate::internal::http::StreamBody ) -> impl futures::Stream< Item = Result< crate::models::StreamingResponse, Error > >
   {
     // Gemini API returns a JSON array : [{...response1...}, {...response2...}]
     // We need to buffer the entire response and parse as array
@@ -933,13 +1049,12 @@ impl ModelApi< '_ >
     // `-D warnings` flag caught them during ctest3 verification.
     async_stream ::stream!
     {
-      // Collect all bytes
-      let bytes_result = response.bytes().await;
+      // Read the whole body
+      let text_result = response.text().await;
 
-      match bytes_result
+      match text_result
       {
-        Ok( bytes ) => {
-          let text = String::from_utf8_lossy( &bytes );
+        Ok( text ) => {
 
           // Parse as JSON array of GenerateContentResponse
           match serde_json::from_str::< Vec< crate::models::GenerateContentResponse > >( &text )
@@ -956,6 +1071,8 @@ impl ModelApi< '_ >
                 let streaming_response = crate::models::StreamingResponse {
                   candidates : Some( api_response.candidates ),
                   usage_metadata : api_response.usage_metadata,
+                  prompt_feedback : api_response.prompt_feedback,
+                  grounding_metadata : api_response.grounding_metadata,
                   is_final : Some( is_final ),
                   error : None,
                 };
@@ -967,6 +1084,8 @@ impl ModelApi< '_ >
               yield Ok( crate::models::StreamingResponse {
                 candidates : None,
                 usage_metadata : None,
+                prompt_feedback : None,
+                grounding_metadata : None,
                 is_final : Some( true ),
                 error : None,
               } );
@@ -977,7 +1096,7 @@ impl ModelApi< '_ >
           }
         },
         Err( network_error ) => {
-          yield Err( Error::NetworkError( format!( "Failed to read streaming response : {network_error}" ) ) );
+          yield Err( network_error );
         }
       }
     }
@@ -112,6 +112,39 @@ impl< 'a > GenerationRequestBuilder< 'a >
     self
   }
 
+  /// Constrains the output to JSON matching the schema of `T`.
+  ///
+  /// The schema is derived with `schemars` in OpenAPI 3.0 form with all subschemas
+  /// inlined, as the Gemini `responseSchema` field does not resolve references.
+  /// Sets `response_mime_type` to `application/json` and `response_schema` to the schema.
+  #[ cfg( feature = "json_schema" ) ]
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_json_schema< T : schemars::JsonSchema >( mut self ) -> Self
+  {
+    let mut schema = schemars::generate::SchemaSettings::openapi3()
+    .with( | settings | settings.inline_subschemas = true )
+    .into_generator()
+    .into_root_schema_for::< T >();
+    schema.remove( "$schema" );
+
+    self.ensure_generation_config();
+    if let Some( ref mut config ) = self.request.generation_config
+    {
+      config.response_mime_type = Some( "application/json".to_string() );
+      config.response_schema = Some( schema.to_value() );
+    }
+    self
+  }
+
+  /// Returns the request built so far.
+  #[ inline ]
+  #[ must_use ]
+  pub fn request( &self ) -> &crate::models::GenerateContentRequest
+  {
+    &self.request
+  }
+
   /// Executes the configured generation request.
   ///
   /// # Returns
@@ -151,7 +184,7 @@ impl< 'a > GenerationRequestBuilder< 'a >
       .and_then( |part| part.text.as_ref() )
       .cloned()
       .ok_or_else( || Error::ApiError( 
-        format!( "No text content returned from model '{model_id}'." )
+        format!( "No text content returned from model '{model_id}'." ), None
       ) )
   }
 
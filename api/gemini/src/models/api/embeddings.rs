@@ -90,7 +90,7 @@ impl ModelApi< '_ >
     if request.content.parts.is_empty()
     {
       return Err( Error::InvalidArgument( 
-        "Embed content request cannot have empty content parts. Please provide text to embed.".to_string()
+        "Embed content request cannot have empty content parts. Please provide text to embed.".to_string(), None
       ) );
     }
 
@@ -102,7 +102,7 @@ impl ModelApi< '_ >
     if !has_content
     {
       return Err( Error::InvalidArgument( 
-        "Embed content request must contain at least one text part with non-empty content.".to_string()
+        "Embed content request must contain at least one text part with non-empty content.".to_string(), None
       ) );
     }
 
@@ -184,7 +184,7 @@ impl ModelApi< '_ >
     {
       Err( Error::ApiError( 
         format!( "No embedding values returned from model '{}'. The request may have been invalid or the model may not support embeddings.", 
-          self.model_id )
+          self.model_id ), None
       ) )
     } else {
       Ok( values )
@@ -269,7 +269,7 @@ impl ModelApi< '_ >
     {
       Err( Error::ApiError( 
         format!( "No embedding values returned from model '{}' for task type '{}'.", 
-          self.model_id, task_type )
+          self.model_id, task_type ), None
       ) )
     } else {
       Ok( values )
@@ -419,14 +419,14 @@ impl ModelApi< '_ >
     {
       return Err( Error::InvalidArgument( 
         format!( "Embedding dimensions must match : {} vs {}", 
-          embedding1.len(), embedding2.len() )
+          embedding1.len(), embedding2.len() ), None
       ) );
     }
 
     if embedding1.is_empty()
     {
       return Err( Error::InvalidArgument( 
-        "Cannot compute similarity for empty embeddings".to_string()
+        "Cannot compute similarity for empty embeddings".to_string(), None
       ) );
     }
 
@@ -444,7 +444,7 @@ impl ModelApi< '_ >
     if magnitude1 == 0.0 || magnitude2 == 0.0
     {
       return Err( Error::InvalidArgument( 
-        "Cannot compute similarity for zero vectors".to_string()
+        "Cannot compute similarity for zero vectors".to_string(), None
       ) );
     }
 
@@ -143,7 +143,7 @@ impl< 'a > EmbeddingRequestBuilder< 'a >
     if values.is_empty()
     {
       Err( Error::ApiError(
-        format!( "No embedding values returned from model '{model_id}'." )
+        format!( "No embedding values returned from model '{model_id}'." ), None
       ) )
     } else {
       Ok( values )
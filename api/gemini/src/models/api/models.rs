@@ -147,7 +147,7 @@ impl ModelsApi< '_ >
     if clean_model_id.is_empty()
     {
       return Err( Error::InvalidArgument(
-        "Model ID cannot be empty. Please provide a valid model identifier.".to_string()
+        "Model ID cannot be empty. Please provide a valid model identifier.".to_string(), None
       ) );
     }
 
@@ -216,6 +216,34 @@ impl ModelsApi< '_ >
     }
   }
 
+  /// Creates a handle for a model after normalizing its name.
+  ///
+  /// Unlike [`Self::by_name`], which uses the name verbatim, this applies
+  /// [`ModelName::normalize`](crate::models::ModelName::normalize) first, so
+  /// `"models/gemini-2.5-flash"` and `" gemini-2.5-flash "` address the same model.
+  /// To resolve `-latest` aliases, normalize with
+  /// [`ModelName::normalize_with_known`](crate::models::ModelName::normalize_with_known)
+  /// and pass the result here.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// # use api_gemini::client::Client;
+  /// # fn main() -> Result< (), Box< dyn std::error::Error > > {
+  /// let client = Client::new()?;
+  /// let models_api = client.models();
+  /// let model = models_api.by_name_normalized( "models/gemini-2.5-flash" );
+  /// # Ok( () )
+  /// # }
+  /// ```
+  #[ must_use ]
+  #[ inline ]
+  pub fn by_name_normalized( &self, model_id : &str ) -> ModelApi< '_ >
+  {
+    let normalized = crate::models::ModelName::normalize( model_id );
+    self.by_name( crate::models::ModelName::id( &normalized ) )
+  }
+
   /// Enhance errors from list operation with additional context.
   fn enhance_list_error( error : Error ) -> Error
   {
@@ -252,7 +280,7 @@ impl ModelsApi< '_ >
         ),
       Error::ServerError( ref msg ) if msg.contains( "404" ) || msg.contains( "not found" ) =>
         Error::InvalidArgument(
-          format!( "Model '{model_id}' not found. Please check the model ID and try again. Use models().list() to see available models." )
+          format!( "Model '{model_id}' not found. Please check the model ID and try again. Use models().list() to see available models." ), None
         ),
       Error::ServerError( ref msg ) =>
         Error::ServerError(
@@ -609,10 +637,11 @@ impl ModelApi< '_ >
   Result< crate::models::CountTokensResponse, Error >
   {
     // Validate request before sending
-    if request.contents.is_empty()
+    let contents = request.generate_content_request.as_ref().map_or( &request.contents, | generate_request | &generate_request.contents );
+    if contents.is_empty()
     {
       return Err( Error::InvalidArgument(
-        "Count tokens request cannot have empty contents. Please provide at least one content item.".to_string()
+        "Count tokens request cannot have empty contents. Please provide at least one content item.".to_string(), None
       ) );
     }
 
@@ -622,13 +651,25 @@ impl ModelApi< '_ >
       self.model_id
     );
 
+    // The API accepts either `contents` or `generateContentRequest`, and the latter must name the model
+    let body = match &request.generate_content_request
+    {
+      Some( generate_request ) =>
+      {
+        let mut generate_request = serde_json::to_value( generate_request )?;
+        generate_request[ "model" ] = serde_json::Value::String( format!( "models/{}", self.model_id ) );
+        serde_json::json!( { "generateContentRequest" : generate_request } )
+      },
+      None => serde_json::to_value( request )?,
+    };
+
     http ::execute_with_optional_retries
     (
       self.client,
       Method::POST,
       &url,
       &self.client.api_key,
-      Some( request ),
+      Some( &body ),
     )
     .await
     .map_err( |e| self.enhance_model_operation_error( "count tokens", e ) )
@@ -649,7 +690,7 @@ impl ModelApi< '_ >
         ),
       Error::ServerError( ref msg ) if msg.contains( "404" ) || msg.contains( "not found" ) =>
         Error::InvalidArgument(
-          format!( "Model '{}' not found while trying to {operation}. Please check the model ID.", self.model_id )
+          format!( "Model '{}' not found while trying to {operation}. Please check the model ID.", self.model_id ), None
         ),
       Error::ServerError( ref msg ) =>
         Error::ServerError(
@@ -316,6 +316,50 @@ impl Drop for HotReloadHandle
   }
 }
 
+/// Handle for a file watch started with `ConfigManager::watch_file`
+///
+/// Dropping the handle stops the filesystem watcher and the reload task.
+#[ cfg( feature = "dynamic_configuration" ) ]
+pub struct WatchHandle
+{
+  _watcher : notify::RecommendedWatcher,
+  task : tokio::task::JoinHandle< () >,
+  path : std::path::PathBuf,
+}
+
+#[ cfg( feature = "dynamic_configuration" ) ]
+impl WatchHandle
+{
+  pub( crate ) fn new( watcher : notify::RecommendedWatcher, task : tokio::task::JoinHandle< () >, path : std::path::PathBuf ) -> Self
+  {
+    Self { _watcher : watcher, task, path }
+  }
+
+  /// Path of the watched configuration file
+  pub fn path( &self ) -> &std::path::Path
+  {
+    &self.path
+  }
+}
+
+#[ cfg( feature = "dynamic_configuration" ) ]
+impl core::fmt::Debug for WatchHandle
+{
+  fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+  {
+    f.debug_struct( "WatchHandle" ).field( "path", &self.path ).finish_non_exhaustive()
+  }
+}
+
+#[ cfg( feature = "dynamic_configuration" ) ]
+impl Drop for WatchHandle
+{
+  fn drop( &mut self )
+  {
+    self.task.abort();
+  }
+}
+
 /// Metrics for hot-reloading operations
 #[ cfg( feature = "dynamic_configuration" ) ]
 #[ derive( Debug ) ]
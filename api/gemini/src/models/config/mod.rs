@@ -29,7 +29,7 @@ mod private
   use std::time::{ SystemTime, Instant };
   use tokio::sync::broadcast;
 
-  pub use super::propagation::{ ConfigManagerOptions, ConfigMetrics, ConfigMetricsReport, ConfigHealthStatus, ConfigSyncContext, SyncStatus, ConfigChangeListener };
+  pub use super::propagation::{ ConfigManagerOptions, ConfigMetrics, ConfigMetricsReport, ConfigHealthStatus, ConfigSyncContext, SyncStatus, ConfigChangeListener, ConfigSubsystem, ConfigApplyReport };
   pub use super::versioning::{ ConfigHistoryEntry, ConfigChangeType, ConfigChangeEvent, ConfigDelta };
   pub use super::rollback::RollbackAnalysis;
 
@@ -51,6 +51,18 @@ mod private
     pub base_retry_delay : Duration,
     /// Backoff multiplier for retry delays
     pub backoff_multiplier : f64,
+    /// Consecutive failures before the circuit breaker opens; `None` keeps the client's setting
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub circuit_breaker_failure_threshold : Option< u32 >,
+    /// Time the circuit breaker stays open before probing; `None` keeps the client's setting
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub circuit_breaker_timeout : Option< Duration >,
+    /// Rate limit in requests per second; `None` keeps the client's setting
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub rate_limit_requests_per_second : Option< f64 >,
+    /// Rate limiter burst size; `None` keeps the client's setting
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub rate_limit_bucket_size : Option< usize >,
     /// Configuration source priority (higher = more important)
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub source_priority : Option< u8 >,
@@ -73,6 +85,10 @@ mod private
       self.max_retry_delay.hash( state );
       self.base_retry_delay.hash( state );
       self.backoff_multiplier.to_bits().hash( state );
+      self.circuit_breaker_failure_threshold.hash( state );
+      self.circuit_breaker_timeout.hash( state );
+      self.rate_limit_requests_per_second.map( f64::to_bits ).hash( state );
+      self.rate_limit_bucket_size.hash( state );
       self.source_priority.hash( state );
       for ( k, v ) in &self.tags
       {
@@ -187,6 +203,10 @@ mod private
         max_retry_delay : Duration::from_millis( file_config.max_retry_delay_ms.unwrap_or( 30000 ) ),
         base_retry_delay : Duration::from_millis( file_config.base_retry_delay_ms.unwrap_or( 100 ) ),
         backoff_multiplier : file_config.backoff_multiplier.unwrap_or( 2.0 ),
+        circuit_breaker_failure_threshold : file_config.circuit_breaker_failure_threshold,
+        circuit_breaker_timeout : file_config.circuit_breaker_timeout_ms.map( Duration::from_millis ),
+        rate_limit_requests_per_second : file_config.rate_limit_requests_per_second,
+        rate_limit_bucket_size : file_config.rate_limit_bucket_size,
         source_priority : Some( 75 ),
         tags : HashMap::new(),
         validation_hash : None,
@@ -209,6 +229,10 @@ mod private
         max_retry_delay : Duration::from_secs( 30 ),
         base_retry_delay : Duration::from_millis( 100 ),
         backoff_multiplier : 2.0,
+        circuit_breaker_failure_threshold : None,
+        circuit_breaker_timeout : None,
+        rate_limit_requests_per_second : None,
+        rate_limit_bucket_size : None,
         source_priority : Some( 50 ),
         tags : HashMap::new(),
         validation_hash : None,
@@ -227,6 +251,10 @@ mod private
     max_retry_delay_ms : Option< u64 >,
     base_retry_delay_ms : Option< u64 >,
     backoff_multiplier : Option< f64 >,
+    circuit_breaker_failure_threshold : Option< u32 >,
+    circuit_breaker_timeout_ms : Option< u64 >,
+    rate_limit_requests_per_second : Option< f64 >,
+    rate_limit_bucket_size : Option< usize >,
   }
 
   /// Builder for DynamicConfig with validation
@@ -295,6 +323,34 @@ mod private
       self
     }
 
+    /// Set the consecutive failures before the circuit breaker opens
+    pub fn circuit_breaker_failure_threshold( mut self, threshold : u32 ) -> Self
+    {
+      self.config.circuit_breaker_failure_threshold = Some( threshold );
+      self
+    }
+
+    /// Set how long the circuit breaker stays open before probing
+    pub fn circuit_breaker_timeout( mut self, timeout : Duration ) -> Self
+    {
+      self.config.circuit_breaker_timeout = Some( timeout );
+      self
+    }
+
+    /// Set the rate limit in requests per second
+    pub fn rate_limit_requests_per_second( mut self, requests_per_second : f64 ) -> Self
+    {
+      self.config.rate_limit_requests_per_second = Some( requests_per_second );
+      self
+    }
+
+    /// Set the rate limiter burst size
+    pub fn rate_limit_bucket_size( mut self, bucket_size : usize ) -> Self
+    {
+      self.config.rate_limit_bucket_size = Some( bucket_size );
+      self
+    }
+
     /// Set configuration source priority (higher = more important, max 100)
     pub fn source_priority( mut self, priority : u8 ) -> Self
     {
@@ -346,6 +402,27 @@ mod private
         ) );
       }
 
+      if config.circuit_breaker_failure_threshold == Some( 0 )
+      {
+        return Err( crate::error::Error::ConfigurationError(
+          "Circuit breaker failure threshold must be greater than 0".to_string()
+        ) );
+      }
+
+      if config.rate_limit_requests_per_second.is_some_and( | rps | rps.is_nan() || rps <= 0.0 )
+      {
+        return Err( crate::error::Error::ConfigurationError(
+          "Rate limit must be greater than 0 requests per second".to_string()
+        ) );
+      }
+
+      if config.rate_limit_bucket_size == Some( 0 )
+      {
+        return Err( crate::error::Error::ConfigurationError(
+          "Rate limit bucket size must be greater than 0".to_string()
+        ) );
+      }
+
       if config.base_url.is_empty()
       {
         return Err( crate::error::Error::ConfigurationError(
@@ -456,6 +533,10 @@ mod private
     }
   }
 
+  /// Delay between a watched file changing and reloading it
+  #[ cfg( feature = "dynamic_configuration" ) ]
+  const WATCH_SETTLE_DELAY : Duration = Duration::from_millis( 100 );
+
   /// Configuration management interface for the client with optimizations
   #[ allow( missing_debug_implementations ) ] // Cannot derive Debug due to function pointers
   pub struct ConfigManager
@@ -520,7 +601,16 @@ mod private
     }
 
     /// Apply configuration update with metrics and history management
-    pub async fn apply_update( &self, mut config_update : ConfigUpdate ) -> Result< crate::client::Client, crate::error::Error >
+    pub async fn apply_update( &self, config_update : ConfigUpdate ) -> Result< crate::client::Client, crate::error::Error >
+    {
+      self.apply_update_with_report( config_update ).await.map( | report | report.client )
+    }
+
+    /// Apply configuration update and report which client subsystems picked up new settings
+    ///
+    /// The returned client carries the new endpoint, timeout and retry settings, which the
+    /// HTTP layer reads on every request, so they take effect without rebuilding the client.
+    pub async fn apply_update_with_report( &self, mut config_update : ConfigUpdate ) -> Result< ConfigApplyReport, crate::error::Error >
     {
       let start_time = Instant::now();
 
@@ -540,7 +630,9 @@ mod private
           let new_config = config_update.new_config.clone();
 
           // Apply the configuration
+          let previous_config = config_update.client.current_config();
           let new_client = config_update.apply().await?;
+          let updated = ConfigSubsystem::changed_between( &previous_config, &new_client.current_config() );
 
           // Record successful update
           let duration_us = start_time.elapsed().as_micros() as u64;
@@ -558,7 +650,7 @@ mod private
           // Perform cleanup if needed
           self.cleanup_if_needed().await;
 
-          Ok( new_client )
+          Ok( ConfigApplyReport { client : new_client, updated } )
         },
         Err( e ) => {
           self.metrics.record_failed_update();
@@ -575,6 +667,82 @@ mod private
       self.apply_update( config_update ).await
     }
 
+    /// Watch a configuration file and apply it through `load_from_file` whenever it changes
+    ///
+    /// Must be called from within a Tokio runtime. Each change goes through the regular
+    /// update path, so history, metrics and `on_change` listeners see it like any other
+    /// update. Saves that cannot be parsed or fail validation are skipped and the current
+    /// configuration is kept. Watching stops when the returned handle is dropped.
+    #[ cfg( feature = "dynamic_configuration" ) ]
+    pub fn watch_file< P: AsRef< Path > >( &self, path : P ) -> Result< super::WatchHandle, crate::error::Error >
+    {
+      use notify::{ Watcher, RecursiveMode };
+
+      let path = path.as_ref().to_path_buf();
+      let file_name = path.file_name()
+        .map( ToOwned::to_owned )
+        .ok_or_else( || crate::error::Error::ConfigurationError(
+          format!( "Cannot watch '{}' : not a file path", path.display() )
+        ) )?;
+      let directory = match path.parent()
+      {
+        Some( parent ) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => std::path::PathBuf::from( "." ),
+      };
+      let runtime = tokio::runtime::Handle::try_current()
+        .map_err( | _ | crate::error::Error::ConfigurationError(
+          "watch_file must be called from within a Tokio runtime".to_string()
+        ) )?;
+
+      // Watch the directory rather than the file so editors that replace the file on save are still seen
+      let ( sender, mut receiver ) = tokio::sync::mpsc::unbounded_channel::< () >();
+      let mut watcher = notify::recommended_watcher( move | res : notify::Result< notify::Event > | {
+        if let Ok( event ) = res
+        {
+          let relevant = event.kind.is_create() || event.kind.is_modify();
+          if relevant && event.paths.iter().any( | p | p.file_name() == Some( file_name.as_os_str() ) )
+          {
+            let _ = sender.send( () );
+          }
+        }
+      } )
+      .map_err( | e | crate::error::Error::ConfigurationError( format!( "Failed to create file watcher : {}", e ) ) )?;
+      watcher.watch( &directory, RecursiveMode::NonRecursive )
+        .map_err( | e | crate::error::Error::ConfigurationError(
+          format!( "Failed to watch '{}' : {}", directory.display(), e )
+        ) )?;
+
+      let manager = self.share();
+      let watched = path.clone();
+      let task = runtime.spawn( async move {
+        while receiver.recv().await.is_some()
+        {
+          // A single save usually produces several events; let them settle and reload once
+          tokio::time::sleep( WATCH_SETTLE_DELAY ).await;
+          while receiver.try_recv().is_ok() {}
+
+          let _ = manager.load_from_file( &watched ).await;
+        }
+      } );
+
+      Ok( super::WatchHandle::new( watcher, task, path ) )
+    }
+
+    /// Create a manager sharing this one's client, history, listeners and metrics
+    #[ cfg( feature = "dynamic_configuration" ) ]
+    fn share( &self ) -> Self
+    {
+      Self {
+        client : self.client.clone(),
+        history : self.history.clone(),
+        listeners : self.listeners.clone(),
+        options : self.options.clone(),
+        metrics : self.metrics.clone(),
+        last_cleanup : self.last_cleanup.clone(),
+        sync_context : self.sync_context.clone(),
+      }
+    }
+
     /// Analyze the impact of rolling back to the previous configuration
     pub fn analyze_previous_rollback( &self ) -> Result< RollbackAnalysis, crate::error::Error >
     {
@@ -688,7 +856,8 @@ mod private
           let original_size = history[ i ].size_bytes;
           let compressed_size = delta.memory_footprint();
 
-          if compressed_size < original_size
+          // Only rely on a delta that reconstructs the entry exactly
+          if compressed_size < original_size && delta.reconstructs( previous_config, current_config )
           {
             memory_saved += original_size - compressed_size;
             history[ i ].delta = Some( delta );
@@ -704,6 +873,61 @@ mod private
       Ok( memory_saved )
     }
 
+    /// Export the configuration recorded under `version_id` as JSON
+    ///
+    /// The output can be checked into version control or copied to another environment
+    /// and loaded there with `ConfigManager::import_config`.
+    pub fn export_version( &self, version_id : &str ) -> Result< String, crate::error::Error >
+    {
+      let config = {
+        let history = self.history.read().unwrap();
+        history.iter().find( | e | e.version_id == version_id )
+          .map( | e | e.config.clone() )
+          .ok_or_else( || crate::error::Error::ConfigurationError(
+            format!( "Configuration version '{}' not found", version_id )
+          ) )?
+      };
+
+      serde_json::to_string_pretty( &config )
+        .map_err( | e | crate::error::Error::SerializationError(
+          format!( "Failed to export configuration version '{}' : {}", version_id, e )
+        ) )
+    }
+
+    /// Parse and validate a configuration produced by `export_version`
+    ///
+    /// The result is not applied; pass it to `update` to roll it out.
+    pub fn import_config( json : &str ) -> Result< DynamicConfig, crate::error::Error >
+    {
+      let config : DynamicConfig = serde_json::from_str( json )
+        .map_err( | e | crate::error::Error::ConfigurationError(
+          format!( "Failed to parse exported configuration : {}", e )
+        ) )?;
+
+      DynamicConfigBuilder::new().validate_config( &config )?;
+      Ok( config )
+    }
+
+    /// Check that every compressed history entry can be reconstructed from its predecessor
+    ///
+    /// The oldest retained entry is skipped, since its predecessor may have been trimmed.
+    pub fn verify_history_integrity( &self ) -> Result< (), crate::error::Error >
+    {
+      let history = self.history.read().unwrap();
+
+      for pair in history.windows( 2 )
+      {
+        if !pair[ 1 ].verify_delta( &pair[ 0 ].config )
+        {
+          return Err( crate::error::Error::ConfigurationError(
+            format!( "History entry '{}' does not match its delta from '{}'", pair[ 1 ].version_id, pair[ 0 ].version_id )
+          ) );
+        }
+      }
+
+      Ok( () )
+    }
+
     /// Get configuration history with optimized read access
     pub fn history( &self ) -> Vec< ConfigHistoryEntry >
     {
@@ -335,6 +335,83 @@ pub struct ConfigMetricsReport
   pub timestamp : SystemTime,
 }
 
+/// Client subsystem whose live settings are driven by `DynamicConfig`
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+pub enum ConfigSubsystem
+{
+  /// Base URL used to build request URLs
+  Endpoint,
+  /// Per-request timeout applied by the HTTP layer
+  Timeout,
+  /// Retry attempts, delays, jitter and backoff multiplier
+  Retry,
+  /// Circuit breaker failure threshold and open timeout
+  CircuitBreaker,
+  /// Rate limiter requests per second and bucket size
+  RateLimit,
+}
+
+impl ConfigSubsystem
+{
+  /// Subsystems whose settings differ between two configurations
+  pub fn changed_between( old : &DynamicConfig, new : &DynamicConfig ) -> Vec< Self >
+  {
+    let mut changed = Vec::new();
+
+    if old.base_url != new.base_url
+    {
+      changed.push( Self::Endpoint );
+    }
+
+    if old.timeout != new.timeout
+    {
+      changed.push( Self::Timeout );
+    }
+
+    if old.retry_attempts != new.retry_attempts
+      || old.enable_jitter != new.enable_jitter
+      || old.max_retry_delay != new.max_retry_delay
+      || old.base_retry_delay != new.base_retry_delay
+      || ( old.backoff_multiplier - new.backoff_multiplier ).abs() > f64::EPSILON
+    {
+      changed.push( Self::Retry );
+    }
+
+    if old.circuit_breaker_failure_threshold != new.circuit_breaker_failure_threshold
+      || old.circuit_breaker_timeout != new.circuit_breaker_timeout
+    {
+      changed.push( Self::CircuitBreaker );
+    }
+
+    if old.rate_limit_requests_per_second != new.rate_limit_requests_per_second
+      || old.rate_limit_bucket_size != new.rate_limit_bucket_size
+    {
+      changed.push( Self::RateLimit );
+    }
+
+    changed
+  }
+}
+
+/// Outcome of applying a configuration update through `ConfigManager::apply_update_with_report`
+#[ derive( Debug, Clone ) ]
+pub struct ConfigApplyReport
+{
+  /// Client with the new configuration applied
+  pub client : crate::client::Client,
+  /// Subsystems whose live settings changed; empty when the update was a no-op
+  pub updated : Vec< ConfigSubsystem >,
+}
+
+impl ConfigApplyReport
+{
+  /// Whether the given subsystem picked up new settings
+  pub fn was_updated( &self, subsystem : ConfigSubsystem ) -> bool
+  {
+    self.updated.contains( &subsystem )
+  }
+}
+
 /// Health status of the configuration management system
 #[ derive( Debug, Clone ) ]
 pub enum ConfigHealthStatus
@@ -94,6 +94,16 @@ impl RollbackAnalysis
     {
       changed_fields.push( "backoff_multiplier".to_string() );
     }
+    if current_config.circuit_breaker_failure_threshold != target_config.circuit_breaker_failure_threshold
+      || current_config.circuit_breaker_timeout != target_config.circuit_breaker_timeout
+    {
+      changed_fields.push( "circuit_breaker".to_string() );
+    }
+    if current_config.rate_limit_requests_per_second != target_config.rate_limit_requests_per_second
+      || current_config.rate_limit_bucket_size != target_config.rate_limit_bucket_size
+    {
+      changed_fields.push( "rate_limit".to_string() );
+    }
     if current_config.source_priority != target_config.source_priority
     {
       changed_fields.push( "source_priority".to_string() );
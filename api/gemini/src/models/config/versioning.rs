@@ -115,6 +115,22 @@ impl ConfigDelta
     {
       changed_fields.insert( "backoff_multiplier".to_string(), serde_json::to_value( &new_config.backoff_multiplier ).unwrap() );
     }
+    if old_config.circuit_breaker_failure_threshold != new_config.circuit_breaker_failure_threshold
+    {
+      changed_fields.insert( "circuit_breaker_failure_threshold".to_string(), serde_json::to_value( &new_config.circuit_breaker_failure_threshold ).unwrap() );
+    }
+    if old_config.circuit_breaker_timeout != new_config.circuit_breaker_timeout
+    {
+      changed_fields.insert( "circuit_breaker_timeout".to_string(), serde_json::to_value( &new_config.circuit_breaker_timeout ).unwrap() );
+    }
+    if old_config.rate_limit_requests_per_second != new_config.rate_limit_requests_per_second
+    {
+      changed_fields.insert( "rate_limit_requests_per_second".to_string(), serde_json::to_value( &new_config.rate_limit_requests_per_second ).unwrap() );
+    }
+    if old_config.rate_limit_bucket_size != new_config.rate_limit_bucket_size
+    {
+      changed_fields.insert( "rate_limit_bucket_size".to_string(), serde_json::to_value( &new_config.rate_limit_bucket_size ).unwrap() );
+    }
     if old_config.source_priority != new_config.source_priority
     {
       changed_fields.insert( "source_priority".to_string(), serde_json::to_value( &new_config.source_priority ).unwrap() );
@@ -159,24 +175,66 @@ impl ConfigDelta
   {
     let mut new_config = base_config.clone();
 
-    // Apply field changes
     for ( field, value ) in &self.changed_fields
     {
-      match field.as_str()
-      {
-        "timeout" => new_config.timeout = serde_json::from_value( value.clone() )?,
-        "retry_attempts" => new_config.retry_attempts = serde_json::from_value( value.clone() )?,
-        "base_url" => new_config.base_url = serde_json::from_value( value.clone() )?,
-        "enable_jitter" => new_config.enable_jitter = serde_json::from_value( value.clone() )?,
-        "max_retry_delay" => new_config.max_retry_delay = serde_json::from_value( value.clone() )?,
-        "base_retry_delay" => new_config.base_retry_delay = serde_json::from_value( value.clone() )?,
-        "backoff_multiplier" => new_config.backoff_multiplier = serde_json::from_value( value.clone() )?,
-        "source_priority" => new_config.source_priority = serde_json::from_value( value.clone() )?,
-        _ => {} // Ignore unknown fields
-      }
+      Self::apply_field( &mut new_config, field, value )?;
     }
 
-    // Apply tag changes
+    self.apply_tags( &mut new_config );
+    Ok( new_config )
+  }
+
+  /// Apply delta to a configuration, leaving fields whose values cannot be decoded unchanged
+  ///
+  /// Unlike `apply_delta` this never fails; a corrupt delta instead yields a configuration
+  /// that differs from the one it was meant to reconstruct, which `reconstructs` detects.
+  pub fn apply( &self, base : &DynamicConfig ) -> DynamicConfig
+  {
+    let mut new_config = base.clone();
+
+    for ( field, value ) in &self.changed_fields
+    {
+      let _ = Self::apply_field( &mut new_config, field, value );
+    }
+
+    self.apply_tags( &mut new_config );
+    new_config
+  }
+
+  /// Check that applying this delta to `base` yields exactly `target`
+  ///
+  /// The validation cache key is not part of the comparison.
+  pub fn reconstructs( &self, base : &DynamicConfig, target : &DynamicConfig ) -> bool
+  {
+    let reconstructed = self.apply( base );
+    reconstructed == DynamicConfig { validation_hash : None, ..target.clone() }
+  }
+
+  /// Set a single changed field on a configuration
+  fn apply_field( config : &mut DynamicConfig, field : &str, value : &serde_json::Value ) -> Result< (), serde_json::Error >
+  {
+    match field
+    {
+      "timeout" => config.timeout = serde_json::from_value( value.clone() )?,
+      "retry_attempts" => config.retry_attempts = serde_json::from_value( value.clone() )?,
+      "base_url" => config.base_url = serde_json::from_value( value.clone() )?,
+      "enable_jitter" => config.enable_jitter = serde_json::from_value( value.clone() )?,
+      "max_retry_delay" => config.max_retry_delay = serde_json::from_value( value.clone() )?,
+      "base_retry_delay" => config.base_retry_delay = serde_json::from_value( value.clone() )?,
+      "backoff_multiplier" => config.backoff_multiplier = serde_json::from_value( value.clone() )?,
+      "circuit_breaker_failure_threshold" => config.circuit_breaker_failure_threshold = serde_json::from_value( value.clone() )?,
+      "circuit_breaker_timeout" => config.circuit_breaker_timeout = serde_json::from_value( value.clone() )?,
+      "rate_limit_requests_per_second" => config.rate_limit_requests_per_second = serde_json::from_value( value.clone() )?,
+      "rate_limit_bucket_size" => config.rate_limit_bucket_size = serde_json::from_value( value.clone() )?,
+      "source_priority" => config.source_priority = serde_json::from_value( value.clone() )?,
+      _ => {} // Ignore unknown fields
+    }
+    Ok( () )
+  }
+
+  /// Apply tag changes and invalidate the validation cache
+  fn apply_tags( &self, new_config : &mut DynamicConfig )
+  {
     for ( key, change ) in &self.tag_changes
     {
       match change
@@ -192,8 +250,6 @@ impl ConfigDelta
 
     // Invalidate validation cache since configuration changed
     new_config.validation_hash = None;
-
-    Ok( new_config )
   }
 
   /// Calculate memory footprint of this delta
@@ -275,6 +331,12 @@ impl ConfigHistoryEntry
     self.delta.is_some()
   }
 
+  /// Check that this entry's delta, if any, reconstructs its configuration from `previous`
+  pub fn verify_delta( &self, previous : &DynamicConfig ) -> bool
+  {
+    self.delta.as_ref().map_or( true, | delta | delta.reconstructs( previous, &self.config ) )
+  }
+
   /// Reconstruct configuration from delta if available
   pub fn reconstruct_config( &self, base_config : &DynamicConfig ) -> Result< DynamicConfig, serde_json::Error >
   {
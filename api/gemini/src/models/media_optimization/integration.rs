@@ -91,7 +91,7 @@ impl< 'a > OptimizedMediaApi< 'a >
       let generator = ThumbnailGenerator::new( thumbnail_config.clone() );
       generator.generate_thumbnail( file_data, mime_type ).await
     } else {
-      Err( crate::error::Error::ApiError( "Thumbnail generation not configured".to_string() ) )
+      Err( crate::error::Error::ApiError( "Thumbnail generation not configured".to_string(), None ) )
     }
   }
 
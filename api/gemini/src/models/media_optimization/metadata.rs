@@ -364,7 +364,7 @@ impl ThumbnailGenerator
   {
     if !self.config.enabled
     {
-      return Err( crate::error::Error::ApiError( "Thumbnail generation disabled".to_string() ) );
+      return Err( crate::error::Error::ApiError( "Thumbnail generation disabled".to_string(), None ) );
     }
 
     // For this implementation, we'll create a simple placeholder thumbnail
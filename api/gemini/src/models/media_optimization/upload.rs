@@ -235,7 +235,7 @@ impl MediaProcessingPipeline
     // Basic file signature validation
     if file_data.is_empty()
     {
-      return Err( crate::error::Error::ApiError( "Empty file data".to_string() ) );
+      return Err( crate::error::Error::ApiError( "Empty file data".to_string(), None ) );
     }
 
     // Check for basic file signatures
@@ -255,7 +255,7 @@ impl MediaProcessingPipeline
           return Ok( () );
         }
         return Err( crate::error::Error::ApiError(
-          format!( "File signature doesn't match declared MIME type : {}", declared_mime_type )
+          format!( "File signature doesn't match declared MIME type : {}", declared_mime_type ), None
         ) );
       }
     }
@@ -263,7 +263,7 @@ impl MediaProcessingPipeline
     // For other types, just check if reasonable
     if file_data.len() > 100 * 1024 * 1024  // 100MB limit
     {
-      return Err( crate::error::Error::ApiError( "File too large".to_string() ) );
+      return Err( crate::error::Error::ApiError( "File too large".to_string(), None ) );
     }
 
     Ok( () )
@@ -334,7 +334,7 @@ impl MediaProcessingPipeline
   {
     // Read file data
     let file_data = std::fs::read( file_path )
-      .map_err( | e | crate::error::Error::ApiError( format!( "Failed to read file : {}", e ) ) )?;
+      .map_err( | e | crate::error::Error::ApiError( format!( "Failed to read file : {}", e ), None ) )?;
 
     // Detect MIME type from file extension
     let mime_type = match file_path.extension().and_then( | ext | ext.to_str() )
@@ -358,7 +358,7 @@ impl MediaProcessingPipeline
   {
     // For now, return a placeholder implementation
     // In a real implementation, this would fetch the file from the API
-    Err( crate::error::Error::ApiError( "Download functionality not implemented yet".to_string() ) )
+    Err( crate::error::Error::ApiError( "Download functionality not implemented yet".to_string(), None ) )
   }
 
   /// Process data stream
@@ -372,6 +372,6 @@ impl MediaProcessingPipeline
   {
     // For now, return a placeholder implementation
     // In a real implementation, this would process the stream chunks
-    Err( crate::error::Error::ApiError( "Stream processing functionality not implemented yet".to_string() ) )
+    Err( crate::error::Error::ApiError( "Stream processing functionality not implemented yet".to_string(), None ) )
   }
 }
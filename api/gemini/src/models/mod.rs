@@ -19,6 +19,7 @@ pub mod config;
 pub mod failover;
 
 /// Streaming control for fine-grained stream management.
+#[ cfg( feature = "streaming_control" ) ]
 pub mod streaming_control;
 
 /// WebSocket streaming for real-time bidirectional communication.
@@ -53,6 +54,7 @@ mod private
   pub use super::types::function::*;
   pub use super::types::code_execution::*;
   pub use super::types::tuning::*;
+  pub use super::types::universal::*;
 }
 
 ::mod_interface::mod_interface!
@@ -60,13 +62,16 @@ mod private
   // Core model types
   exposed use private::Model;
   exposed use private::ListModelsResponse;
+  exposed use private::ModelName;
 
   // Content generation types
   exposed use private::GenerateContentRequest;
+  exposed use private::ContextTrim;
   exposed use private::GenerateContentResponse;
   exposed use private::GenerationConfig;
   exposed use private::SafetySetting;
   exposed use private::PromptFeedback;
+  exposed use private::BlockReason;
   exposed use private::UsageMetadata;
   exposed use private::BatchGenerateContentRequest;
   exposed use private::BatchGenerateContentResponse;
@@ -105,13 +110,18 @@ mod private
 
   // Content structure types
   exposed use private::Content;
+  exposed use private::ContentBuilder;
   exposed use private::Part;
+  exposed use private::UniversalMessage;
+  exposed use private::UniversalRole;
   exposed use private::Blob;
   exposed use private::FileData;
   exposed use private::FunctionCall;
   exposed use private::FunctionResponse;
   exposed use private::Candidate;
   exposed use private::SafetyRating;
+  exposed use private::SafetyRatings;
+  exposed use private::HarmProbability;
   exposed use private::CitationMetadata;
   exposed use private::CitationSource;
   exposed use private::SystemInstruction;
@@ -121,6 +131,12 @@ mod private
   exposed use private::StreamingResponse;
   #[ cfg( feature = "streaming" ) ]
   exposed use private::StreamingRequestBuilder;
+  #[ cfg( feature = "streaming" ) ]
+  exposed use private::drive_stream_with_callbacks;
+  #[ cfg( feature = "streaming" ) ]
+  exposed use private::ResilientStream;
+  #[ cfg( feature = "streaming" ) ]
+  exposed use private::ResilientChunk;
 
   // Chat types (feature-gated)
   #[ cfg( feature = "chat" ) ]
@@ -157,6 +173,7 @@ mod private
   exposed use private::GroundingChunk;
   exposed use private::GroundingSupport;
   exposed use private::SearchEntryPoint;
+  exposed use private::CitationStyle;
 
   // Function calling types
   exposed use private::Tool;
@@ -170,6 +187,8 @@ mod private
   exposed use private::CodeExecution;
   exposed use private::CodeExecutionConfig;
   exposed use private::CodeExecutionResult;
+  exposed use private::ExecutableCode;
+  exposed use private::CodeExecutionOutcome;
 
   // Model tuning types
   exposed use private::CreateTunedModelRequest;
@@ -179,6 +198,7 @@ mod private
   exposed use private::Dataset;
   exposed use private::TuningExamples;
   exposed use private::TuningExample;
+  exposed use private::ImportWarning;
   exposed use private::Hyperparameters;
   exposed use private::TunedModelSource;
   exposed use private::ListTunedModelsResponse;
@@ -188,11 +208,12 @@ mod private
   exposed use health::{ HealthStatus, HealthCheckResult, HealthCheckConfig, HealthCheckStrategy, HealthCheckBuilder };
   exposed use config::{ DynamicConfig, DynamicConfigBuilder, ConfigChangeType, ConfigChangeEvent, ConfigHistoryEntry, ConfigUpdate, ConfigManager, ConfigChangeListener };
   exposed use failover::{ FailoverConfig, FailoverConfigBuilder, FailoverStrategy, EndpointHealth, FailoverMetrics, FailoverManager, FailoverBuilder };
-  exposed use streaming_control::{ StreamState, StreamControlConfig, StreamControlConfigBuilder, StreamMetrics, StreamMetricsSnapshot, BufferStrategy, MetricsLevel, ControllableStream, ControllableStreamBuilder };
+  #[ cfg( feature = "streaming_control" ) ]
+  exposed use streaming_control::{ StreamState, StreamControlConfig, StreamControlConfigBuilder, StreamMetrics, StreamMetricsSnapshot, BufferStrategy, OverflowPolicy, MetricsLevel, ControllableStream, ControllableStreamBuilder };
   exposed use websocket_streaming::{ WebSocketConnectionState, WebSocketConfig, WebSocketConfigBuilder, WebSocketPoolConfig, WebSocketPoolConfigBuilder, WebSocketMessage, WebSocketMetrics, WebSocketConnection, WebSocketStreamBuilder };
   exposed use websocket_streaming_optimized::{ ConnectionPool, MessageSerializerType, ConnectionPoolStats, OptimizedWebSocketConfig, ConnectionPoolConfig, MessageOptimizationConfig, WebSocketMonitoringConfig, ResourceManagementConfig, SerializationFormat, OptimizedConnectionPool, OptimizedWebSocketConnection, ConnectionMetrics, ConnectionHealthChecker, OptimizedWebSocketStreamingApi, StreamingMetrics };
-  exposed use model_tuning::{ TrainingJobState, HyperparameterConfig, HyperparameterConfigBuilder, LoRAConfig, LoRAConfigBuilder, TrainingObjective, TrainingMetrics, ModelCheckpoint, TrainingProgress, TrainingJob, FineTuningBuilder };
-  exposed use model_deployment::{ DeploymentState, DeploymentEnvironment, DeploymentStrategy, ScalingConfig, ScalingConfigBuilder, ResourceConfig, ResourceConfigBuilder, DeploymentHealthCheckConfig, DeploymentHealthCheckConfigBuilder, MonitoringConfig, MonitoringConfigBuilder, ContainerConfig, ContainerConfigBuilder, OrchestrationConfig, DeploymentMetrics, ModelDeployment, DeploymentBuilder, DeploymentSummary, DeploymentCache, IntelligentScaler, ScalingDecision, PerformanceOptimizer, OptimizationRecommendation, OptimizationCategory, OptimizationPriority, ImpactEstimate, ImplementationEffort };
+  exposed use model_tuning::{ TrainingJobState, HyperparameterConfig, HyperparameterConfigBuilder, LoRAConfig, LoRAConfigBuilder, LoRAEstimate, TrainingObjective, TrainingMetrics, TrainingMetricsHistory, ModelCheckpoint, TrainingProgress, TrainingJob, FineTuningBuilder };
+  exposed use model_deployment::{ DeploymentState, DeploymentEnvironment, DeploymentStrategy, ScalingConfig, ScalingConfigBuilder, ResourceConfig, ResourceConfigBuilder, DeploymentHealthCheckConfig, DeploymentHealthCheckConfigBuilder, MonitoringConfig, MonitoringConfigBuilder, ContainerConfig, ContainerConfigBuilder, OrchestrationConfig, DeploymentMetrics, ModelDeployment, DeploymentBuilder, DeploymentSummary, DeploymentCache, CanaryRouter, CanaryVariant, IntelligentScaler, ScalingDecision, PredictiveScalingConfig, ProjectedLoad, PerformanceOptimizer, OptimizationRecommendation, OptimizationCategory, OptimizationPriority, ImpactEstimate, ImplementationEffort };
   exposed use media_optimization::{ MediaProcessingConfig, MediaRetryConfig, ThumbnailConfig, ThumbnailFormat, MediaCache, MediaCacheStats, MediaCacheStatsReport, MediaProcessingPipeline, MediaProcessingMetrics, ProcessedMediaResult, ProcessedMediaMetadata, MediaProcessingMetricsReport, ThumbnailGenerator, OptimizedMediaApi };
   exposed use semantic_retrieval_optimized::{ VectorIndex, CacheStrategy, VectorSearchResult, IndexStats, CacheStats, FlatVectorIndex, AdaptiveLruCache, OptimizedRetrievalConfig, OptimizedIndexType, CacheConfig, CacheWarmingStrategy, SearchOptimizationConfig, MonitoringConfig as OptimizedMonitoringConfig, OptimizedSemanticRetrievalApi, PerformanceMetrics as OptimizedPerformanceMetrics };
   exposed use batch::{ BatchJobState, BatchJob, BatchJobStatus, BatchBillingMetadata, BatchJobResults, BatchEmbeddingResults, BatchJobList, CreateBatchJobRequest, CreateBatchEmbeddingRequest };
@@ -254,14 +254,128 @@ impl ResourceConfig
   }
 }
 
+/// Predictive scale-up configuration
+///
+/// Demand is measured in requests per second : the observed request rate plus
+/// the queued requests that must be drained within `queue_drain_time`.
+#[ derive( Debug, Clone ) ]
+pub struct PredictiveScalingConfig
+{
+  /// How far ahead the request-rate and queue-depth trends are projected
+  pub lookahead : Duration,
+  /// Requests per second a single instance handles at saturation
+  pub capacity_per_instance : f64,
+  /// Fraction of instance capacity to plan for (0.0 - 1.0]
+  pub target_utilization : f64,
+  /// Time within which queued requests should be drained
+  pub queue_drain_time : Duration,
+  /// Only samples this recent (relative to the latest one) form the trend
+  pub trend_window : Duration,
+}
+
+impl Default for PredictiveScalingConfig
+{
+  fn default() -> Self
+  {
+    Self {
+      lookahead : Duration::from_secs( 300 ),    // 5 minutes
+      capacity_per_instance : 10.0,
+      target_utilization : 0.8,
+      queue_drain_time : Duration::from_secs( 30 ),
+      trend_window : Duration::from_secs( 600 ), // 10 minutes
+    }
+  }
+}
+
+impl PredictiveScalingConfig
+{
+  /// Validate the predictive scaling configuration
+  pub fn validate( &self ) -> Result< (), crate::error::Error >
+  {
+    if self.lookahead.is_zero()
+    {
+      return Err( crate::error::Error::ConfigurationError(
+        "Lookahead must be greater than 0".to_string()
+      ) );
+    }
+
+    if self.capacity_per_instance <= 0.0
+    {
+      return Err( crate::error::Error::ConfigurationError(
+        "Capacity per instance must be greater than 0".to_string()
+      ) );
+    }
+
+    if self.target_utilization <= 0.0 || self.target_utilization > 1.0
+    {
+      return Err( crate::error::Error::ConfigurationError(
+        "Target utilization must be between 0 and 1".to_string()
+      ) );
+    }
+
+    if self.queue_drain_time.is_zero()
+    {
+      return Err( crate::error::Error::ConfigurationError(
+        "Queue drain time must be greater than 0".to_string()
+      ) );
+    }
+
+    Ok( () )
+  }
+}
+
+/// Load projected by predictive scaling
+#[ derive( Debug, Clone, PartialEq ) ]
+pub struct ProjectedLoad
+{
+  /// Request rate expected at the end of the lookahead, in requests per second
+  pub request_rate : f64,
+  /// Queue depth expected at the end of the lookahead
+  pub queue_depth : f64,
+  /// Projection horizon
+  pub lookahead : Duration,
+  /// Time until the current instances saturate if the trend holds;
+  /// `None` when demand is not rising
+  pub time_to_saturation : Option< Duration >,
+}
+
+/// Request-rate and queue-depth observation
+#[ derive( Debug, Clone, Copy ) ]
+struct LoadSample
+{
+  at : SystemTime,
+  request_rate : f64,
+  queue_depth : usize,
+}
+
+/// Least-squares line through `points`, as ( slope, value at `at` )
+fn linear_trend( points : &[ ( f64, f64 ) ], at : f64 ) -> ( f64, f64 )
+{
+  let n = points.len() as f64;
+  let mean_x = points.iter().map( | ( x, _ ) | x ).sum::< f64 >() / n;
+  let mean_y = points.iter().map( | ( _, y ) | y ).sum::< f64 >() / n;
+  let variance : f64 = points.iter().map( | ( x, _ ) | ( x - mean_x ).powi( 2 ) ).sum();
+  let slope = if variance > 0.0
+  {
+    points.iter().map( | ( x, y ) | ( x - mean_x ) * ( y - mean_y ) ).sum::< f64 >() / variance
+  } else {
+    0.0
+  };
+  ( slope, mean_y + slope * ( at - mean_x ) )
+}
+
 /// Intelligent scaling algorithms for resource optimization
 #[ derive( Debug, Clone ) ]
 pub struct IntelligentScaler
 {
   /// Scaling configuration
   config : ScalingConfig,
+  /// Predictive scale-up configuration
+  predictive : PredictiveScalingConfig,
   /// Historical metrics for prediction
   metrics_history : Arc< Mutex< Vec< ( SystemTime, DeploymentMetrics ) > > >,
+  /// Request-rate and queue-depth history for predictive scale-up
+  load_history : Arc< Mutex< Vec< LoadSample > > >,
   /// Last scaling action timestamp
   last_scaling_action : Arc< Mutex< Option< SystemTime > > >,
   /// Prediction model parameters
@@ -275,12 +389,28 @@ impl IntelligentScaler
   {
     Self {
       config,
+      predictive : PredictiveScalingConfig::default(),
       metrics_history : Arc::new( Mutex::new( Vec::new() ) ),
+      load_history : Arc::new( Mutex::new( Vec::new() ) ),
       last_scaling_action : Arc::new( Mutex::new( None ) ),
       prediction_window_minutes : 15, // 15-minute prediction window
     }
   }
 
+  /// Use `config` for predictive scale-up
+  pub fn with_predictive_config( mut self, config : PredictiveScalingConfig ) -> Result< Self, crate::error::Error >
+  {
+    config.validate()?;
+    self.predictive = config;
+    Ok( self )
+  }
+
+  /// Get predictive scale-up configuration
+  pub fn predictive_config( &self ) -> &PredictiveScalingConfig
+  {
+    &self.predictive
+  }
+
   /// Record metrics for scaling decisions
   pub fn record_metrics( &self, metrics : &DeploymentMetrics )
   {
@@ -347,6 +477,7 @@ impl IntelligentScaler
             target_instances : recommended_instances.min( self.config.max_instances ),
             reason : format!( "CPU: {:.1}%, Memory : {:.1}%, Target CPU: {:.1}%",
               cpu_util, memory_util, self.config.target_cpu_utilization ),
+            projected_load : None,
           } );
         }
       }
@@ -423,6 +554,121 @@ impl IntelligentScaler
   {
     *self.last_scaling_action.lock().unwrap() = Some( SystemTime::now() );
   }
+
+  /// Record the current request rate and queue depth for predictive scale-up
+  pub fn record_load( &self, request_rate : f64, queue_depth : usize )
+  {
+    self.record_load_at( SystemTime::now(), request_rate, queue_depth );
+  }
+
+  /// Record a request rate and queue depth observed at `at`
+  pub fn record_load_at( &self, at : SystemTime, request_rate : f64, queue_depth : usize )
+  {
+    let mut history = self.load_history.lock().unwrap();
+    history.push( LoadSample { at, request_rate, queue_depth } );
+
+    // Keep only recent history (last 24 hours)
+    let cutoff = at - Duration::from_secs( 24 * 60 * 60 );
+    history.retain( | sample | sample.at > cutoff );
+  }
+
+  /// Recommend scaling up before the current instances saturate
+  ///
+  /// Fits linear trends to the recorded request rate and queue depth, projects
+  /// them `lookahead` past the latest sample, and recommends the instance count
+  /// that serves the projected demand at the target utilization. This is a
+  /// recommendation only; nothing is applied until it is passed to
+  /// `ModelDeployment::execute_scaling`.
+  pub fn predict_scale_up( &self, current_instances : usize ) -> Option< ScalingDecision >
+  {
+    if current_instances >= self.config.max_instances
+    {
+      return None;
+    }
+
+    if let Some( last_action ) = *self.last_scaling_action.lock().unwrap()
+    {
+      let time_since_last = SystemTime::now()
+        .duration_since( last_action )
+        .unwrap_or_default();
+
+      if time_since_last < self.config.scale_up_cooldown
+      {
+        return None; // Still in cooldown
+      }
+    }
+
+    let history = self.load_history.lock().unwrap();
+    let latest = history.last()?.at;
+    let window_start = latest - self.predictive.trend_window;
+    let samples : Vec< LoadSample > = history
+      .iter()
+      .filter( | sample | sample.at >= window_start )
+      .copied()
+      .collect();
+    drop( history );
+
+    if samples.len() < 2
+    {
+      return None;
+    }
+
+    let seconds = | sample : &LoadSample | sample.at.duration_since( window_start ).unwrap_or_default().as_secs_f64();
+    let now = seconds( samples.last()? );
+    let horizon = now + self.predictive.lookahead.as_secs_f64();
+    let rates : Vec< _ > = samples.iter().map( | sample | ( seconds( sample ), sample.request_rate ) ).collect();
+    let queues : Vec< _ > = samples.iter().map( | sample | ( seconds( sample ), sample.queue_depth as f64 ) ).collect();
+
+    let ( rate_slope, current_rate ) = linear_trend( &rates, now );
+    let ( queue_slope, current_queue ) = linear_trend( &queues, now );
+    let projected_rate = ( current_rate + rate_slope * ( horizon - now ) ).max( 0.0 );
+    let projected_queue = ( current_queue + queue_slope * ( horizon - now ) ).max( 0.0 );
+
+    let drain_secs = self.predictive.queue_drain_time.as_secs_f64();
+    let projected_demand = projected_rate + projected_queue / drain_secs;
+    let planned_capacity = self.predictive.capacity_per_instance * self.predictive.target_utilization;
+    let required_instances = ( ( projected_demand / planned_capacity ).ceil() as usize )
+      .max( self.config.min_instances )
+      .min( self.config.max_instances );
+
+    if required_instances <= current_instances
+    {
+      return None;
+    }
+
+    // Saturation : demand reaches the full capacity of the current instances
+    let saturation_demand = current_instances as f64 * self.predictive.capacity_per_instance;
+    let current_demand = current_rate.max( 0.0 ) + current_queue.max( 0.0 ) / drain_secs;
+    let demand_slope = rate_slope + queue_slope / drain_secs;
+    let time_to_saturation = if current_demand >= saturation_demand
+    {
+      Some( Duration::ZERO )
+    } else if demand_slope > 0.0 {
+      Some( Duration::from_secs_f64( ( saturation_demand - current_demand ) / demand_slope ) )
+    } else {
+      None
+    };
+
+    let saturation = match time_to_saturation
+    {
+      Some( eta ) if eta.is_zero() => "current instances are saturated".to_string(),
+      Some( eta ) => format!( "current instances saturate in ~{}s", eta.as_secs() ),
+      None => "demand exceeds target utilization".to_string(),
+    };
+
+    Some( ScalingDecision::ScaleUp {
+      target_instances : required_instances,
+      reason : format!( "Projected {:.1} req/s with queue depth {:.0} in {}s needs {} instances at {:.0}% utilization; {}",
+        projected_rate, projected_queue, self.predictive.lookahead.as_secs(), required_instances,
+        self.predictive.target_utilization * 100.0, saturation ),
+      projected_load : Some( ProjectedLoad {
+        request_rate : projected_rate,
+        queue_depth : projected_queue,
+        lookahead : self.predictive.lookahead,
+        time_to_saturation,
+      } ),
+    } )
+  }
 }
 
 /// Scaling decision recommendation
@@ -435,6 +681,8 @@ pub enum ScalingDecision
     target_instances : usize,
     /// Reason for scaling up
     reason : String,
+    /// Projected load behind a predictive scale-up; `None` for reactive decisions
+    projected_load : Option< ProjectedLoad >,
   },
   /// Scale down to target instance count
   ScaleDown {
@@ -572,6 +572,28 @@ impl PerformanceOptimizer
     self.recommendations.lock().unwrap().clone()
   }
 
+  /// Add a recommendation from an external analysis
+  pub fn add_recommendation( &self, recommendation : OptimizationRecommendation )
+  {
+    self.recommendations.lock().unwrap().push( recommendation );
+  }
+
+  /// Get all recommendations ranked by `OptimizationRecommendation::score`
+  ///
+  /// Highest score first; equal scores are ordered by priority, then impact,
+  /// and otherwise keep the order they were recorded in.
+  pub fn prioritized_recommendations( &self ) -> Vec< OptimizationRecommendation >
+  {
+    let mut recommendations = self.get_recommendations();
+    recommendations.sort_by( | a, b |
+    {
+      b.score().cmp( &a.score() )
+        .then_with( || b.priority.cmp( &a.priority ) )
+        .then_with( || b.estimated_impact.weight().cmp( &a.estimated_impact.weight() ) )
+    } );
+    recommendations
+  }
+
   /// Clear recommendations
   pub fn clear_recommendations( &self )
   {
@@ -597,6 +619,18 @@ pub struct OptimizationRecommendation
   pub implementation_effort : ImplementationEffort,
 }
 
+impl OptimizationRecommendation
+{
+  /// Composite ranking score from impact and effort, in `1..=9`
+  ///
+  /// Impact weight (1-3) times inverted effort weight (3-1), so a high-impact,
+  /// low-effort change scores 9 and a low-impact, high-effort change scores 1.
+  pub fn score( &self ) -> u32
+  {
+    self.estimated_impact.weight() * ( 4 - self.implementation_effort.weight() )
+  }
+}
+
 /// Categories of optimization recommendations
 #[ derive( Debug, Clone, PartialEq, Eq ) ]
 pub enum OptimizationCategory
@@ -639,6 +673,20 @@ pub enum ImpactEstimate
   High,
 }
 
+impl ImpactEstimate
+{
+  /// Impact magnitude : 1 (low) to 3 (high)
+  pub fn weight( &self ) -> u32
+  {
+    match self
+    {
+      Self::Low => 1,
+      Self::Medium => 2,
+      Self::High => 3,
+    }
+  }
+}
+
 /// Implementation effort estimation
 #[ derive( Debug, Clone, PartialEq, Eq ) ]
 pub enum ImplementationEffort
@@ -650,3 +698,17 @@ pub enum ImplementationEffort
   /// High effort
   High,
 }
+
+impl ImplementationEffort
+{
+  /// Effort magnitude : 1 (low) to 3 (high)
+  pub fn weight( &self ) -> u32
+  {
+    match self
+    {
+      Self::Low => 1,
+      Self::Medium => 2,
+      Self::High => 3,
+    }
+  }
+}
@@ -1,7 +1,8 @@
 //! Model deployment and hosting capabilities for production environments.
 //!
 //! This module provides comprehensive deployment management including orchestration,
-//! scaling, monitoring, and deployment strategies (blue-green, canary, rolling).
+//! scaling, monitoring, and deployment strategies (blue-green, canary, rolling),
+//! including sticky canary traffic splitting with `CanaryRouter`.
 
 // Module declarations
 pub mod strategies;
@@ -83,7 +84,7 @@ mod private
   }
 
   // Re-exports from submodules
-  pub use super::strategies::{ DeploymentStrategy, DeploymentCache };
+  pub use super::strategies::{ DeploymentStrategy, DeploymentCache, CanaryRouter, CanaryVariant };
   pub use super::auto_scaling::{
     ScalingConfig,
     ScalingConfigBuilder,
@@ -91,6 +92,8 @@ mod private
     ResourceConfigBuilder,
     IntelligentScaler,
     ScalingDecision,
+    PredictiveScalingConfig,
+    ProjectedLoad,
   };
   pub use super::health::{
     DeploymentHealthCheckConfig,
@@ -135,8 +138,12 @@ mod private
   exposed use private::DeploymentBuilder;
   exposed use private::DeploymentSummary;
   exposed use private::DeploymentCache;
+  exposed use private::CanaryRouter;
+  exposed use private::CanaryVariant;
   exposed use private::IntelligentScaler;
   exposed use private::ScalingDecision;
+  exposed use private::PredictiveScalingConfig;
+  exposed use private::ProjectedLoad;
   exposed use private::PerformanceOptimizer;
   exposed use private::OptimizationRecommendation;
   exposed use private::OptimizationCategory;
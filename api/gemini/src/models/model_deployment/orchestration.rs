@@ -286,7 +286,7 @@ impl ModelDeployment
   {
     match decision
     {
-      ScalingDecision::ScaleUp { target_instances, reason } => {
+      ScalingDecision::ScaleUp { target_instances, reason, .. } => {
         tracing ::info!( "Scaling up deployment {} to {} instances : {}",
           self.deployment_id, target_instances, reason );
 
@@ -367,7 +367,7 @@ impl ModelDeployment
     if !matches!( current_state, DeploymentState::Active )
     {
       return Err( crate::error::Error::ApiError(
-        format!( "Cannot scale deployment in state : {:?}", current_state )
+        format!( "Cannot scale deployment in state : {:?}", current_state ), None
       ) );
     }
 
@@ -586,7 +586,7 @@ impl< 'a > DeploymentBuilder< 'a >
   {
     // Validate required fields
     let name = self.name.ok_or_else( ||
-      crate ::error::Error::ApiError( "Deployment name is required".to_string() )
+      crate ::error::Error::ApiError( "Deployment name is required".to_string(), None )
     )?;
 
     let version = self.version.unwrap_or_else( || "1.0.0".to_string() );
@@ -6,6 +6,7 @@ use std::time::SystemTime;
 use std::collections::HashMap;
 
 use super::DeploymentSummary;
+use super::orchestration::ModelDeployment;
 
 /// Deployment strategy types
 #[ derive( Debug, Clone ) ]
@@ -34,6 +35,121 @@ pub enum DeploymentStrategy
   },
 }
 
+/// Variant chosen by a `CanaryRouter`
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+pub enum CanaryVariant
+{
+  /// The stable deployment
+  Stable,
+  /// The canary deployment
+  Canary,
+}
+
+/// Splits traffic between a stable and a canary deployment
+///
+/// Each request is routed by a caller-supplied key (a user or session id).
+/// The key is hashed with FNV-1a, which is stable across processes and Rust
+/// versions, so a key always lands on the same variant for a given split, and
+/// raising the split only moves keys from stable to canary.
+#[ derive( Debug, Clone ) ]
+pub struct CanaryRouter
+{
+  stable : Arc< ModelDeployment >,
+  canary : Arc< ModelDeployment >,
+  /// Canary share in basis points (0 - 10 000)
+  canary_basis_points : u64,
+}
+
+impl CanaryRouter
+{
+  /// Route `canary_percentage` percent of keys to `canary` and the rest to `stable`
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::ConfigurationError` if the percentage is not within 0 - 100.
+  pub fn new( stable : Arc< ModelDeployment >, canary : Arc< ModelDeployment >, canary_percentage : f64 ) -> Result< Self, crate::error::Error >
+  {
+    if !( 0.0..=100.0 ).contains( &canary_percentage )
+    {
+      return Err( crate::error::Error::ConfigurationError(
+        format!( "Canary percentage must be between 0 and 100, got {canary_percentage}" )
+      ) );
+    }
+
+    Ok( Self {
+      stable,
+      canary,
+      canary_basis_points : ( canary_percentage * 100.0 ).round() as u64,
+    } )
+  }
+
+  /// Create a router from a `DeploymentStrategy::Canary`, using its traffic percentage
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::ConfigurationError` if the strategy is not `Canary` or its
+  /// percentage is not within 0 - 100.
+  pub fn from_strategy( stable : Arc< ModelDeployment >, canary : Arc< ModelDeployment >, strategy : &DeploymentStrategy ) -> Result< Self, crate::error::Error >
+  {
+    match strategy
+    {
+      DeploymentStrategy::Canary { traffic_percentage, .. } => Self::new( stable, canary, *traffic_percentage ),
+      other => Err( crate::error::Error::ConfigurationError(
+        format!( "Canary routing needs a canary strategy, got {other:?}" )
+      ) ),
+    }
+  }
+
+  /// Percentage of keys routed to the canary
+  pub fn canary_percentage( &self ) -> f64
+  {
+    self.canary_basis_points as f64 / 100.0
+  }
+
+  /// Variant that requests with `key` are routed to
+  pub fn variant( &self, key : &str ) -> CanaryVariant
+  {
+    if fnv1a( key.as_bytes() ) % 10_000 < self.canary_basis_points
+    {
+      CanaryVariant::Canary
+    }
+    else
+    {
+      CanaryVariant::Stable
+    }
+  }
+
+  /// Deployment that requests with `key` are routed to
+  pub fn route( &self, key : &str ) -> &Arc< ModelDeployment >
+  {
+    match self.variant( key )
+    {
+      CanaryVariant::Stable => &self.stable,
+      CanaryVariant::Canary => &self.canary,
+    }
+  }
+
+  /// The stable deployment
+  pub fn stable( &self ) -> &Arc< ModelDeployment >
+  {
+    &self.stable
+  }
+
+  /// The canary deployment
+  pub fn canary( &self ) -> &Arc< ModelDeployment >
+  {
+    &self.canary
+  }
+}
+
+/// 64-bit FNV-1a hash
+fn fnv1a( bytes : &[ u8 ] ) -> u64
+{
+  const OFFSET_BASIS : u64 = 0xcbf2_9ce4_8422_2325;
+  const PRIME : u64 = 0x0100_0000_01b3;
+  bytes.iter().fold( OFFSET_BASIS, | hash, byte | ( hash ^ u64::from( *byte ) ).wrapping_mul( PRIME ) )
+}
+
 /// Deployment caching strategy for performance optimization
 #[ derive( Debug ) ]
 pub struct DeploymentCache
@@ -310,6 +310,39 @@ mod private
     }
   }
 
+  /// Hidden size per layer in the transformer shape assumed by `LoRAConfig::estimate_parameters`
+  const LORA_HIDDEN_PER_LAYER : f64 = 128.0;
+  /// Bytes per frozen base parameter (bf16)
+  const LORA_BASE_PARAM_BYTES : u64 = 2;
+  /// Bytes per adapter parameter at rest (fp32)
+  const LORA_ADAPTER_PARAM_BYTES : u64 = 4;
+  /// Bytes per adapter parameter while training : fp32 weight, gradient and two Adam moments
+  const LORA_TRAINING_PARAM_BYTES : u64 = 16;
+
+  /// Size estimate for a LoRA configuration applied to a base model
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct LoRAEstimate
+  {
+    /// Inferred hidden size of the base model
+    pub hidden_size : u64,
+    /// Inferred number of transformer layers
+    pub layers : u64,
+    /// Trainable adapter parameters
+    pub trainable_parameters : u64,
+    /// Trainable parameters as a fraction of the base model
+    pub trainable_fraction : f64,
+    /// Adapter output scaling (`alpha / rank`)
+    pub scaling : f64,
+    /// Parameters served at inference : the base count when weights are merged,
+    /// base plus adapters otherwise
+    pub inference_parameters : u64,
+    /// Size of the saved adapter weights in bytes
+    pub adapter_bytes : u64,
+    /// Approximate training memory in bytes : frozen base weights plus adapter
+    /// weights, gradients and optimizer state (activations excluded)
+    pub training_memory_bytes : u64,
+  }
+
   impl LoRAConfig
   {
     /// Create a new LoRA config builder
@@ -317,6 +350,46 @@ mod private
     {
       LoRAConfigBuilder::new()
     }
+
+    /// Estimate adapter size and training memory for a base model of `base_params` parameters
+    ///
+    /// The base model is assumed to be a standard transformer with
+    /// `12 * layers * hidden²` parameters and 128 hidden units per layer, which
+    /// fixes `hidden = ∛( 32 * base_params / 3 )`. Every target module is taken
+    /// as a `hidden × hidden` projection in each layer, so LoRA adds
+    /// `2 * rank * hidden` parameters per module per layer.
+    pub fn estimate_parameters( &self, base_params : u64 ) -> LoRAEstimate
+    {
+      let hidden_size = ( ( 32.0 * base_params as f64 / 3.0 ).cbrt().round() as u64 ).max( 1 );
+      let layers = ( ( hidden_size as f64 / LORA_HIDDEN_PER_LAYER ).round() as u64 ).max( 1 );
+      let trainable_parameters = layers
+        * self.target_modules.len() as u64
+        * 2 * self.rank as u64 * hidden_size;
+
+      let trainable_fraction = if base_params == 0
+      {
+        0.0
+      } else {
+        trainable_parameters as f64 / base_params as f64
+      };
+      let inference_parameters = if self.merge_weights
+      {
+        base_params
+      } else {
+        base_params + trainable_parameters
+      };
+
+      LoRAEstimate {
+        hidden_size,
+        layers,
+        trainable_parameters,
+        trainable_fraction,
+        scaling : self.alpha / self.rank as f64,
+        inference_parameters,
+        adapter_bytes : trainable_parameters * LORA_ADAPTER_PARAM_BYTES,
+        training_memory_bytes : base_params * LORA_BASE_PARAM_BYTES + trainable_parameters * LORA_TRAINING_PARAM_BYTES,
+      }
+    }
   }
 
   /// Training objective types
@@ -366,6 +439,8 @@ mod private
     pub memory_usage_mb : f64,
     /// Elapsed training time in seconds
     pub elapsed_time_seconds : f64,
+    /// Validation loss, when this step ran an evaluation
+    pub validation_loss : Option< f64 >,
   }
 
   impl Default for TrainingMetrics
@@ -381,10 +456,81 @@ mod private
         throughput_tokens_per_second : 0.0,
         memory_usage_mb : 0.0,
         elapsed_time_seconds : 0.0,
+        validation_loss : None,
       }
     }
   }
 
+  /// Metrics recorded over the course of a training job
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct TrainingMetricsHistory
+  {
+    /// Recorded metrics, oldest first
+    entries : Vec< TrainingMetrics >,
+  }
+
+  impl TrainingMetricsHistory
+  {
+    /// Create an empty history
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Record a metrics snapshot
+    pub fn record( &mut self, metrics : TrainingMetrics )
+    {
+      self.entries.push( metrics );
+    }
+
+    /// All recorded metrics, oldest first
+    pub fn entries( &self ) -> &[ TrainingMetrics ]
+    {
+      &self.entries
+    }
+
+    /// Validation losses of the recorded evaluations, oldest first
+    pub fn validation_losses( &self ) -> Vec< f64 >
+    {
+      self.entries.iter().filter_map( | metrics | metrics.validation_loss ).collect()
+    }
+
+    /// Lowest validation loss so far
+    pub fn best_validation_loss( &self ) -> Option< f64 >
+    {
+      self.validation_losses().into_iter().reduce( f64::min )
+    }
+
+    /// Recommend stopping when validation loss has not improved on the best
+    /// loss so far by more than `min_delta` for `patience` consecutive evaluations
+    ///
+    /// This is a recommendation only; cancelling the job is up to the caller.
+    /// A `patience` of zero is treated as one.
+    pub fn should_early_stop( &self, patience : usize, min_delta : f64 ) -> bool
+    {
+      let losses = self.validation_losses();
+      let Some( ( first, rest ) ) = losses.split_first() else
+      {
+        return false;
+      };
+
+      let mut best = *first;
+      let mut without_improvement = 0;
+      for &loss in rest
+      {
+        if loss < best - min_delta
+        {
+          best = loss;
+          without_improvement = 0;
+        } else {
+          without_improvement += 1;
+        }
+      }
+
+      without_improvement >= patience.max( 1 )
+    }
+  }
+
   /// Model checkpoint information
   #[ derive( Debug, Clone ) ]
   pub struct ModelCheckpoint
@@ -415,6 +561,62 @@ mod private
     pub metrics : TrainingMetrics,
     /// Estimated time remaining
     pub estimated_time_remaining : Option< Duration >,
+    /// Total number of training steps
+    pub total_steps : usize,
+  }
+
+  impl TrainingProgress
+  {
+    /// Create a progress snapshot from the current metrics and the total step count
+    ///
+    /// The completion percentage and estimated time remaining are derived from
+    /// `metrics.step` and `metrics.elapsed_time_seconds`.
+    pub fn new( metrics : TrainingMetrics, total_steps : usize ) -> Self
+    {
+      let percentage = if total_steps == 0
+      {
+        0.0
+      } else {
+        ( metrics.step.min( total_steps ) as f64 / total_steps as f64 ) * 100.0
+      };
+
+      let mut progress = Self {
+        percentage,
+        metrics,
+        estimated_time_remaining : None,
+        total_steps,
+      };
+      progress.estimated_time_remaining = progress.eta();
+      progress
+    }
+
+    /// Average training speed so far, `None` until a step has completed
+    pub fn steps_per_second( &self ) -> Option< f64 >
+    {
+      if self.metrics.step == 0 || self.metrics.elapsed_time_seconds <= 0.0
+      {
+        return None;
+      }
+
+      Some( self.metrics.step as f64 / self.metrics.elapsed_time_seconds )
+    }
+
+    /// Estimated time until the last step completes at the average speed so far
+    ///
+    /// Returns `None` until a step has completed or when the total step count
+    /// is unknown (zero).
+    pub fn eta( &self ) -> Option< Duration >
+    {
+      if self.total_steps == 0
+      {
+        return None;
+      }
+
+      let steps_per_second = self.steps_per_second()?;
+      let remaining_steps = self.total_steps.saturating_sub( self.metrics.step );
+
+      Some( Duration::from_secs_f64( remaining_steps as f64 / steps_per_second ) )
+    }
   }
 
   /// Training job management
@@ -428,6 +630,8 @@ mod private
     config : HyperparameterConfig,
     /// Current metrics
     metrics : Arc< Mutex< TrainingMetrics > >,
+    /// Metrics recorded so far
+    metrics_history : Arc< Mutex< TrainingMetricsHistory > >,
     /// Progress notifications
     progress_tx : broadcast::Sender< TrainingProgress >,
     /// Checkpoints
@@ -446,6 +650,7 @@ mod private
         state : Arc::new( Mutex::new( TrainingJobState::Pending ) ),
         config,
         metrics : Arc::new( Mutex::new( TrainingMetrics::default() ) ),
+        metrics_history : Arc::new( Mutex::new( TrainingMetricsHistory::new() ) ),
         progress_tx,
         checkpoints : Arc::new( Mutex::new( Vec::new() ) ),
       }
@@ -463,6 +668,19 @@ mod private
       self.metrics.lock().unwrap().clone()
     }
 
+    /// Record new training metrics as the current metrics and in the history
+    pub fn record_metrics( &self, metrics : TrainingMetrics )
+    {
+      self.metrics_history.lock().unwrap().record( metrics.clone() );
+      *self.metrics.lock().unwrap() = metrics;
+    }
+
+    /// Get the metrics recorded so far
+    pub fn get_metrics_history( &self ) -> TrainingMetricsHistory
+    {
+      self.metrics_history.lock().unwrap().clone()
+    }
+
     /// Subscribe to training progress updates
     pub fn subscribe_progress( &self ) -> broadcast::Receiver< TrainingProgress >
     {
@@ -503,7 +721,7 @@ mod private
       if current_state != TrainingJobState::Paused
       {
         return Err( crate::error::Error::ApiError(
-          format!( "Cannot resume job in state : {:?}", current_state )
+          format!( "Cannot resume job in state : {:?}", current_state ), None
         ) );
       }
 
@@ -671,7 +889,7 @@ mod private
       if self.training_data.is_none()
       {
         return Err( crate::error::Error::ApiError(
-          "Training data is required for fine-tuning".to_string()
+          "Training data is required for fine-tuning".to_string(), None
         ) );
       }
 
@@ -694,8 +912,10 @@ mod private
   exposed use private::HyperparameterConfigBuilder;
   exposed use private::LoRAConfig;
   exposed use private::LoRAConfigBuilder;
+  exposed use private::LoRAEstimate;
   exposed use private::TrainingObjective;
   exposed use private::TrainingMetrics;
+  exposed use private::TrainingMetricsHistory;
   exposed use private::ModelCheckpoint;
   exposed use private::TrainingProgress;
   exposed use private::TrainingJob;
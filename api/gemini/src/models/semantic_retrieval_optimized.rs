@@ -207,7 +207,7 @@ mod private
     {
       if vector.len() != self.dimensions
       {
-        return Err( crate::error::Error::InvalidArgument( format!( "Vector dimension mismatch : expected {}, got {}", self.dimensions, vector.len() ) ) );
+        return Err( crate::error::Error::InvalidArgument( format!( "Vector dimension mismatch : expected {}, got {}", self.dimensions, vector.len() ), None ) );
       }
 
       self.vectors.insert( id.to_string(), ( vector.to_vec(), metadata ) );
@@ -229,7 +229,7 @@ mod private
 
       if query_vector.len() != self.dimensions
       {
-        return Err( crate::error::Error::InvalidArgument( format!( "Query vector dimension mismatch : expected {}, got {}", self.dimensions, query_vector.len() ) ) );
+        return Err( crate::error::Error::InvalidArgument( format!( "Query vector dimension mismatch : expected {}, got {}", self.dimensions, query_vector.len() ), None ) );
       }
 
       let mut results : Vec< VectorSearchResult > = Vec::new();
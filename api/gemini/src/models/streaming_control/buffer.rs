@@ -17,6 +17,21 @@ pub enum BufferStrategy
   },
 }
 
+/// Policy applied when the pause buffer reaches `max_buffered_chunks`
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Default ) ]
+pub enum OverflowPolicy
+{
+  /// Cancel the stream (default)
+  #[ default ]
+  Cancel,
+  /// Discard the oldest buffered chunk to make room for the incoming one
+  DropOldest,
+  /// Discard the incoming chunk and keep the buffer unchanged
+  DropNewest,
+  /// Stop reading from the source until the stream is resumed (backpressure)
+  Block,
+}
+
 /// Efficient buffer implementation based on strategy
 pub( crate ) enum StreamBuffer< T >
 {
@@ -82,6 +97,29 @@ impl< T > StreamBuffer< T >
     }
   }
 
+  pub fn pop_front( &mut self ) -> Option< Result< T, crate::error::Error > >
+  {
+    match self
+    {
+      Self::Vector( vec ) => ( !vec.is_empty() ).then( || vec.remove( 0 ) ),
+      Self::Circular( deque ) => deque.pop_front(),
+      Self::Chunked { chunks, current_chunk, .. } => {
+        match chunks.first_mut()
+        {
+          Some( first ) => {
+            let item = first.remove( 0 );
+            if first.is_empty()
+            {
+              chunks.remove( 0 );
+            }
+            Some( item )
+          },
+          None => ( !current_chunk.is_empty() ).then( || current_chunk.remove( 0 ) ),
+        }
+      },
+    }
+  }
+
   pub fn len( &self ) -> usize
   {
     match self
@@ -17,10 +17,11 @@ mod operations;
 
 use serde::{ Deserialize, Serialize };
 use core::time::Duration;
+use std::time::Instant;
 use core::sync::atomic::{ AtomicU64, AtomicUsize, Ordering };
 
 // Re-export buffer types
-pub use buffer::BufferStrategy;
+pub use buffer::{ BufferStrategy, OverflowPolicy };
 
 // Re-export operation types
 pub use operations::
@@ -114,6 +115,8 @@ pub struct StreamControlConfig
   pub control_operation_timeout : Duration,
   /// Buffer management strategy for better memory usage
   pub buffer_strategy : BufferStrategy,
+  /// What to do when `max_buffered_chunks` is reached during pause
+  pub overflow_policy : OverflowPolicy,
   /// Metrics collection level (affects performance vs observability trade-off)
   pub metrics_level : MetricsLevel,
   /// Whether to use event-driven timeout handling (more efficient)
@@ -132,6 +135,7 @@ impl Default for StreamControlConfig
       max_buffered_chunks : 100,
       control_operation_timeout : Duration::from_millis( 100 ), // Fast control response
       buffer_strategy : BufferStrategy::Circular, // More memory efficient
+      overflow_policy : OverflowPolicy::Cancel,
       metrics_level : MetricsLevel::Basic, // Balanced performance/observability
       event_driven_timeouts : true, // More efficient timeout handling
     }
@@ -211,6 +215,15 @@ impl StreamControlConfigBuilder
     self
   }
 
+  /// Set the policy applied when the pause buffer is full
+  #[ inline ]
+  #[ must_use ]
+  pub fn overflow_policy( mut self, policy : OverflowPolicy ) -> Self
+  {
+    self.config.overflow_policy = policy;
+    self
+  }
+
   /// Set metrics collection level
   #[ inline ]
   #[ must_use ]
@@ -322,10 +335,12 @@ pub struct StreamMetrics
   pub avg_control_response_time_us : AtomicU64,
   /// Number of control operations performed
   pub control_operations : AtomicU64,
-  /// Number of buffer overflows (when pause buffer reached max capacity)
+  /// Number of buffer overflows (chunks dropped, or the stream cancelled, because the pause buffer was full)
   pub buffer_overflows : AtomicU64,
   /// Number of items sent through the stream
   pub items_sent : AtomicU64,
+  /// When metrics collection started
+  pub started_at : Instant,
 }
 
 impl StreamMetrics
@@ -346,6 +361,7 @@ impl StreamMetrics
       control_operations : AtomicU64::new( 0 ),
       buffer_overflows : AtomicU64::new( 0 ),
       items_sent : AtomicU64::new( 0 ),
+      started_at : Instant::now(),
     }
   }
 }
@@ -378,6 +394,47 @@ pub struct StreamMetricsSnapshot
   pub items_sent : u64,
 }
 
+impl StreamMetricsSnapshot
+{
+  /// Average bytes received per second over `elapsed`, or 0 for a zero duration
+  #[ must_use ]
+  #[ inline ]
+  pub fn bytes_per_second( &self, elapsed : Duration ) -> f64
+  {
+    Self::rate( self.bytes_received, elapsed )
+  }
+
+  /// Average chunks received per second over `elapsed`, or 0 for a zero duration
+  #[ must_use ]
+  #[ inline ]
+  pub fn chunks_per_second( &self, elapsed : Duration ) -> f64
+  {
+    Self::rate( self.total_chunks, elapsed )
+  }
+
+  /// Average size of a received chunk in bytes, or 0 if no chunks were received
+  #[ must_use ]
+  #[ inline ]
+  pub fn average_chunk_size( &self ) -> f64
+  {
+    if self.total_chunks == 0
+    {
+      return 0.0;
+    }
+    self.bytes_received as f64 / self.total_chunks as f64
+  }
+
+  fn rate( count : u64, elapsed : Duration ) -> f64
+  {
+    let seconds = elapsed.as_secs_f64();
+    if seconds == 0.0
+    {
+      return 0.0;
+    }
+    count as f64 / seconds
+  }
+}
+
 impl Default for StreamMetrics
 {
   fn default() -> Self
@@ -394,12 +451,20 @@ impl Default for StreamMetrics
       control_operations : AtomicU64::new( 0 ),
       buffer_overflows : AtomicU64::new( 0 ),
       items_sent : AtomicU64::new( 0 ),
+      started_at : Instant::now(),
     }
   }
 }
 
 impl StreamMetrics
 {
+  /// Time elapsed since metrics collection started, for use with snapshot rate helpers
+  #[ inline ]
+  pub fn elapsed_since_start( &self ) -> Duration
+  {
+    self.started_at.elapsed()
+  }
+
   /// Create a snapshot of the current metrics
   pub fn snapshot( &self ) -> StreamMetricsSnapshot
   {
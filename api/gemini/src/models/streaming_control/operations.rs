@@ -5,9 +5,10 @@ use std::time::Instant;
 use std::sync::{ Arc, Mutex };
 use core::sync::atomic::{ AtomicU8, Ordering };
 use tokio::sync::{ mpsc, oneshot, Notify };
+use tokio_util::sync::CancellationToken;
 use futures::StreamExt;
 
-use super::{ StreamState, StreamControlConfig, StreamMetrics, StreamMetricsSnapshot, MetricsLevel };
+use super::{ StreamState, StreamControlConfig, StreamMetrics, StreamMetricsSnapshot, MetricsLevel, OverflowPolicy };
 use super::buffer::StreamBuffer;
 
 /// Control commands for stream management with timing information
@@ -71,6 +72,21 @@ where
     stream : std::pin::Pin< Box< dyn futures::Stream< Item = Result< T, crate::error::Error > > + Send > >,
     config : StreamControlConfig
   ) -> Self
+  {
+    Self::with_cancellation_token( stream, config, None )
+  }
+
+  /// Create a controllable stream that is also cancelled when an external token fires
+  ///
+  /// Cancelling the token moves the stream to `StreamState::Cancelled` and stops the
+  /// management task, releasing the source stream. With `auto_cleanup` enabled any
+  /// chunks buffered during a pause are discarded; otherwise they are still delivered
+  /// before the stream ends.
+  pub fn with_cancellation_token(
+    stream : std::pin::Pin< Box< dyn futures::Stream< Item = Result< T, crate::error::Error > > + Send > >,
+    config : StreamControlConfig,
+    cancellation_token : Option< CancellationToken >,
+  ) -> Self
   {
     let ( control_tx, control_rx ) = mpsc::unbounded_channel();
     let ( data_tx, data_rx ) = mpsc::unbounded_channel();
@@ -86,6 +102,8 @@ where
     let metrics_clone = metrics.clone();
     let config_clone = config_arc.clone();
     let timeout_notify_clone = timeout_notify.clone();
+    // A fresh token is never cancelled, so streams without an external token behave as before
+    let cancellation_token = cancellation_token.unwrap_or_default();
 
     tokio ::spawn( async move {
       Self::manage_stream_optimized(
@@ -95,7 +113,8 @@ where
         state_clone,
         metrics_clone,
         config_clone,
-        timeout_notify_clone
+        timeout_notify_clone,
+        cancellation_token,
       ).await;
     });
 
@@ -118,6 +137,7 @@ where
     metrics : Arc< StreamMetrics >,
     config : Arc< Mutex< StreamControlConfig > >,
     timeout_notify : Arc< Notify >,
+    cancellation_token : CancellationToken,
   )
   {
     let mut is_paused = false;
@@ -163,7 +183,7 @@ where
                 Ok( () )
               } else {
                 Err( crate::error::Error::ApiError(
-                  format!( "Cannot pause stream in state : {:?}", current_state )
+                  format!( "Cannot pause stream in state : {:?}", current_state ), None
                 ) )
               };
               let _ = response_tx.send( result );
@@ -209,7 +229,7 @@ where
                 Ok( () )
               } else {
                 Err( crate::error::Error::ApiError(
-                  format!( "Cannot resume stream in state : {:?}", current_state )
+                  format!( "Cannot resume stream in state : {:?}", current_state ), None
                 ) )
               };
               let _ = response_tx.send( result );
@@ -249,7 +269,7 @@ where
                 let _ = response_tx.send( Ok( () ) );
               } else {
                 let _ = response_tx.send( Err( crate::error::Error::ApiError(
-                  "Unable to update config : config is locked".to_string()
+                  "Unable to update config : config is locked".to_string(), None
                 ) ) );
               }
             },
@@ -258,8 +278,11 @@ where
           }
         },
 
-        // Handle stream data
-        item = stream.next() => {
+        // Handle stream data (not polled while a full buffer applies backpressure)
+        item = stream.next(), if !( is_paused
+          && current_config.overflow_policy == OverflowPolicy::Block
+          && buffer.len() >= current_config.max_buffered_chunks ) =>
+        {
           if !is_paused
           {
             match item
@@ -298,24 +321,9 @@ where
             match item
             {
               Some( Ok( data ) ) => {
-                if buffer.len() < current_config.max_buffered_chunks
+                let overflowed = buffer.len() >= current_config.max_buffered_chunks;
+                if overflowed && current_config.overflow_policy == OverflowPolicy::Cancel
                 {
-                  buffer.push( Ok( data ) );
-                  let new_size = buffer.len();
-
-                  if current_config.metrics_level != MetricsLevel::None
-                  {
-                    metrics.buffer_size.store( new_size, Ordering::Relaxed );
-
-                    // Update peak buffer size
-                    let current_peak = metrics.peak_buffer_size.load( Ordering::Relaxed );
-                    if new_size > current_peak
-                    {
-                      metrics.peak_buffer_size.store( new_size, Ordering::Relaxed );
-                    }
-                  }
-                } else {
-                  // Buffer overflow - cancel stream
                   state.store( StreamState::Cancelled.to_u8(), Ordering::Release );
                   if current_config.metrics_level != MetricsLevel::None
                   {
@@ -324,6 +332,36 @@ where
                   }
                   break ();
                 }
+
+                if !overflowed
+                {
+                  buffer.push( Ok( data ) );
+                }
+                else if current_config.overflow_policy == OverflowPolicy::DropOldest
+                {
+                  buffer.pop_front();
+                  buffer.push( Ok( data ) );
+                }
+                // DropNewest discards `data`; Block never polls a full buffer
+
+                let new_size = buffer.len();
+
+                if current_config.metrics_level != MetricsLevel::None
+                {
+                  metrics.buffer_size.store( new_size, Ordering::Relaxed );
+
+                  // Update peak buffer size
+                  let current_peak = metrics.peak_buffer_size.load( Ordering::Relaxed );
+                  if new_size > current_peak
+                  {
+                    metrics.peak_buffer_size.store( new_size, Ordering::Relaxed );
+                  }
+
+                  if overflowed
+                  {
+                    metrics.buffer_overflows.fetch_add( 1, Ordering::Relaxed );
+                  }
+                }
               },
               Some( Err( error ) ) => {
                 state.store( StreamState::Error.to_u8(), Ordering::Release );
@@ -363,6 +401,33 @@ where
           }
         },
 
+        // External cancellation (e.g. parent task shutdown)
+        () = cancellation_token.cancelled() =>
+        {
+          state.store( StreamState::Cancelled.to_u8(), Ordering::Release );
+          if current_config.metrics_level != MetricsLevel::None
+          {
+            metrics.state_changes.fetch_add( 1, Ordering::Relaxed );
+          }
+
+          let buffered_items = buffer.drain_all();
+          if !current_config.auto_cleanup
+          {
+            for item in buffered_items
+            {
+              if data_tx.send( item ).is_err()
+              {
+                break; // Receiver dropped
+              }
+            }
+          }
+          if current_config.metrics_level != MetricsLevel::None
+          {
+            metrics.buffer_size.store( 0, Ordering::Relaxed );
+          }
+          break ();
+        },
+
         // Handle timeout notifications from event-driven timeout monitor
         _ = timeout_notify.notified(), if current_config.event_driven_timeouts =>
         {
@@ -450,7 +515,7 @@ where
     let start_time = Instant::now();
 
     self.control_tx.send( StreamCommand::Pause { response_tx, start_time } )
-      .map_err( |_| crate::error::Error::ApiError( "Stream control channel closed".to_string() ) )?;
+      .map_err( |_| crate::error::Error::ApiError( "Stream control channel closed".to_string(), None ) )?;
 
     // Wait for response with timeout
     let config_timeout = {
@@ -460,8 +525,8 @@ where
 
     tokio ::time::timeout( config_timeout, response_rx )
       .await
-      .map_err( |_| crate::error::Error::ApiError( "Pause operation timed out".to_string() ) )?
-      .map_err( |_| crate::error::Error::ApiError( "Pause operation channel closed".to_string() ) )?
+      .map_err( |_| crate::error::Error::ApiError( "Pause operation timed out".to_string(), None ) )?
+      .map_err( |_| crate::error::Error::ApiError( "Pause operation channel closed".to_string(), None ) )?
   }
 
   /// Resume the stream with optimized response handling
@@ -471,7 +536,7 @@ where
     let start_time = Instant::now();
 
     self.control_tx.send( StreamCommand::Resume { response_tx, start_time } )
-      .map_err( |_| crate::error::Error::ApiError( "Stream control channel closed".to_string() ) )?;
+      .map_err( |_| crate::error::Error::ApiError( "Stream control channel closed".to_string(), None ) )?;
 
     // Wait for response with timeout
     let config_timeout = {
@@ -481,8 +546,8 @@ where
 
     tokio ::time::timeout( config_timeout, response_rx )
       .await
-      .map_err( |_| crate::error::Error::ApiError( "Resume operation timed out".to_string() ) )?
-      .map_err( |_| crate::error::Error::ApiError( "Resume operation channel closed".to_string() ) )?
+      .map_err( |_| crate::error::Error::ApiError( "Resume operation timed out".to_string(), None ) )?
+      .map_err( |_| crate::error::Error::ApiError( "Resume operation channel closed".to_string(), None ) )?
   }
 
   /// Cancel the stream with optimized response handling
@@ -492,7 +557,7 @@ where
     let start_time = Instant::now();
 
     self.control_tx.send( StreamCommand::Cancel { response_tx, start_time } )
-      .map_err( |_| crate::error::Error::ApiError( "Stream control channel closed".to_string() ) )?;
+      .map_err( |_| crate::error::Error::ApiError( "Stream control channel closed".to_string(), None ) )?;
 
     // Wait for response with timeout
     let config_timeout = {
@@ -502,8 +567,8 @@ where
 
     tokio ::time::timeout( config_timeout, response_rx )
       .await
-      .map_err( |_| crate::error::Error::ApiError( "Cancel operation timed out".to_string() ) )?
-      .map_err( |_| crate::error::Error::ApiError( "Cancel operation channel closed".to_string() ) )?
+      .map_err( |_| crate::error::Error::ApiError( "Cancel operation timed out".to_string(), None ) )?
+      .map_err( |_| crate::error::Error::ApiError( "Cancel operation channel closed".to_string(), None ) )?
   }
 
   /// Get the current stream state (lock-free atomic operation)
@@ -536,7 +601,7 @@ where
     let ( response_tx, response_rx ) = oneshot::channel();
 
     self.control_tx.send( StreamCommand::UpdateConfig { new_config, response_tx } )
-      .map_err( |_| crate::error::Error::ApiError( "Stream control channel closed".to_string() ) )?;
+      .map_err( |_| crate::error::Error::ApiError( "Stream control channel closed".to_string(), None ) )?;
 
     let config_timeout = {
       let config_guard = self.config.lock().unwrap();
@@ -545,8 +610,8 @@ where
 
     tokio ::time::timeout( config_timeout, response_rx )
       .await
-      .map_err( |_| crate::error::Error::ApiError( "Config update operation timed out".to_string() ) )?
-      .map_err( |_| crate::error::Error::ApiError( "Config update operation channel closed".to_string() ) )?
+      .map_err( |_| crate::error::Error::ApiError( "Config update operation timed out".to_string(), None ) )?
+      .map_err( |_| crate::error::Error::ApiError( "Config update operation channel closed".to_string(), None ) )?
   }
 
   /// Get the next item from the stream
@@ -564,6 +629,7 @@ pub struct ControllableStreamBuilder< 'a >
   model : &'a crate::models::api::ModelApi< 'a >,
   request : crate::models::GenerateContentRequest,
   config : StreamControlConfig,
+  cancellation_token : Option< CancellationToken >,
 }
 
 impl< 'a > ControllableStreamBuilder< 'a >
@@ -575,9 +641,17 @@ impl< 'a > ControllableStreamBuilder< 'a >
       model,
       request : crate::models::GenerateContentRequest::default(),
       config : StreamControlConfig::default(),
+      cancellation_token : None,
     }
   }
 
+  /// Cancel the stream automatically when the given token is cancelled
+  pub fn with_cancellation_token( mut self, token : CancellationToken ) -> Self
+  {
+    self.cancellation_token = Some( token );
+    self
+  }
+
   /// Add text content to the request
   pub fn text( mut self, text : &str ) -> Self
   {
@@ -616,7 +690,7 @@ impl< 'a > ControllableStreamBuilder< 'a >
     let boxed_stream = Box::pin( stream );
 
     // Wrap it in a controllable stream
-    Ok( ControllableStream::new( boxed_stream, self.config ) )
+    Ok( ControllableStream::with_cancellation_token( boxed_stream, self.config, self.cancellation_token ) )
   }
 }
 
@@ -664,7 +738,7 @@ impl< 'a > StreamingControlApi< 'a >
   ) -> Result< ControllableStream< crate::models::StreamingResponse >, crate::error::Error >
   {
     // qqq : Implement streaming functionality once API structure is clarified
-    Err( crate::error::Error::ApiError( "Streaming functionality not yet implemented".to_string() ) )
+    Err( crate::error::Error::ApiError( "Streaming functionality not yet implemented".to_string(), None ) )
   }
 
   /// Create a controllable stream builder for fluent API
@@ -705,7 +779,7 @@ impl< 'a > StreamingControlApi< 'a >
 
     // Get the session
     let session = ws_api.get_session( &session_id )
-      .ok_or_else( || crate::error::Error::ApiError( "Failed to get WebSocket session".to_string() ) )?;
+      .ok_or_else( || crate::error::Error::ApiError( "Failed to get WebSocket session".to_string(), None ) )?;
 
     // Create controllable WebSocket stream
     Ok( ControllableWebSocketStream::new( session, self.config.clone() ) )
@@ -802,7 +876,7 @@ impl ControllableWebSocketStream
   {
     if self.get_state() != StreamState::Running
     {
-      return Err( crate::error::Error::ApiError( "Stream is not in running state".to_string() ) );
+      return Err( crate::error::Error::ApiError( "Stream is not in running state".to_string(), None ) );
     }
 
     self.session.send_message( message ).await?;
@@ -878,6 +952,6 @@ impl< 'a > StreamControlStreamBuilder< 'a >
   pub async fn create( self ) -> Result< ControllableStream< crate::models::StreamingResponse >, crate::error::Error >
   {
     // qqq : Implement streaming functionality once API structure is clarified
-    Err( crate::error::Error::ApiError( "Streaming functionality not yet implemented".to_string() ) )
+    Err( crate::error::Error::ApiError( "Streaming functionality not yet implemented".to_string(), None ) )
   }
 }
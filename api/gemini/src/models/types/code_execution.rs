@@ -44,3 +44,45 @@ pub struct CodeExecutionResult
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   pub execution_time_ms : Option< i64 >,
 }
+
+/// Code generated by the model for the code execution tool.
+#[ derive( Debug, Clone, Serialize, Deserialize, PartialEq, Eq ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct ExecutableCode
+{
+  /// Programming language of the code, e.g. `PYTHON`.
+  pub language : String,
+
+  /// The code to be executed.
+  pub code : String,
+}
+
+/// An executed code block paired with its result.
+///
+/// Collected from response parts by [`super::generation::GenerateContentResponse::code_executions`].
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct CodeExecutionOutcome
+{
+  /// Programming language of the code, e.g. `PYTHON`.
+  pub language : String,
+
+  /// The code that was executed.
+  pub code : String,
+
+  /// Execution outcome, e.g. `OUTCOME_OK` or `OUTCOME_FAILED`; `None` if no result part followed the code.
+  pub outcome : Option< String >,
+
+  /// Output printed by the code, or the error on failure.
+  pub output : Option< String >,
+}
+
+impl CodeExecutionOutcome
+{
+  /// Whether the code ran to completion ( outcome `OUTCOME_OK` ).
+  #[ inline ]
+  #[ must_use ]
+  pub fn succeeded( &self ) -> bool
+  {
+    self.outcome.as_deref() == Some( "OUTCOME_OK" )
+  }
+}
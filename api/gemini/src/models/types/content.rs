@@ -2,6 +2,7 @@
 
 use serde::{ Deserialize, Serialize };
 use super::file::VideoMetadata;
+use super::code_execution::{ CodeExecutionResult, ExecutableCode };
 
 /// Content in a conversation.
 #[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
@@ -13,6 +14,8 @@ pub struct Content
   #[ serde( default ) ]
   pub parts : Vec< Part >,
   /// Role of the content creator.
+  /// Defaults to empty for candidates blocked before any content was produced.
+  #[ serde( default ) ]
   pub role : String,
 }
 
@@ -44,6 +47,14 @@ pub struct Part
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   /// Video metadata for video content
   pub video_metadata : Option< VideoMetadata >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Code generated by the model for the code execution tool.
+  pub executable_code : Option< ExecutableCode >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Result of running the preceding `executable_code` part.
+  pub code_execution_result : Option< CodeExecutionResult >,
 }
 
 /// Binary data with MIME type.
@@ -57,6 +68,29 @@ pub struct Blob
   pub data : String,
 }
 
+impl Blob
+{
+  /// Check that `data` is valid base64 and return the decoded size in bytes.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidArgument` if `data` is not valid standard base64.
+  #[ inline ]
+  pub fn validate( &self ) -> Result< usize, crate::error::Error >
+  {
+    use base64::Engine as _;
+
+    base64::engine::general_purpose::STANDARD
+    .decode( self.data.as_bytes() )
+    .map( | bytes | bytes.len() )
+    .map_err( | error | crate::error::Error::InvalidArgument
+    (
+      format!( "Inline data for '{}' is not valid base64 : {error}", self.mime_type ),
+      None,
+    ) )
+  }
+}
+
 /// File data for multimedia content
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -91,12 +125,173 @@ pub struct FunctionResponse
   pub response : serde_json::Value,
 }
 
+/// Builder for `Content` with typed part constructors.
+///
+/// Parts are accumulated in order and validated by [`ContentBuilder::build`]. Function
+/// responses must be sent on their own in a `user` turn, so mixing them with text or
+/// media parts, or adding them to a `model` turn, is rejected.
+///
+/// # Examples
+///
+/// ```rust
+/// use api_gemini::models::ContentBuilder;
+///
+/// let content = ContentBuilder::user()
+/// .text( "What is in this picture?" )
+/// .inline_data( "image/png", &[ 0x89, 0x50, 0x4E, 0x47 ] )
+/// .build()
+/// .expect( "text and image parts can be combined" );
+///
+/// assert_eq!( content.role, "user" );
+/// assert_eq!( content.parts.len(), 2 );
+/// ```
+#[ derive( Debug, Clone ) ]
+pub struct ContentBuilder
+{
+  role : String,
+  parts : Vec< Part >,
+}
+
+impl ContentBuilder
+{
+  /// Start building content authored by the user.
+  #[ must_use ]
+  #[ inline ]
+  pub fn user() -> Self
+  {
+    Self { role : "user".to_string(), parts : Vec::new() }
+  }
+
+  /// Start building content authored by the model.
+  #[ must_use ]
+  #[ inline ]
+  pub fn model() -> Self
+  {
+    Self { role : "model".to_string(), parts : Vec::new() }
+  }
+
+  /// Append a text part.
+  #[ must_use ]
+  #[ inline ]
+  pub fn text( mut self, text : impl Into< String > ) -> Self
+  {
+    self.parts.push( Part { text : Some( text.into() ), ..Default::default() } );
+    self
+  }
+
+  /// Append an inline data part, base64-encoding the raw `bytes`.
+  #[ must_use ]
+  #[ inline ]
+  pub fn inline_data( mut self, mime_type : impl Into< String >, bytes : &[ u8 ] ) -> Self
+  {
+    use base64::Engine as _;
+
+    self.parts.push( Part
+    {
+      inline_data : Some( Blob
+      {
+        mime_type : mime_type.into(),
+        data : base64::engine::general_purpose::STANDARD.encode( bytes ),
+      } ),
+      ..Default::default()
+    } );
+    self
+  }
+
+  /// Append a part referencing an uploaded file by URI.
+  #[ must_use ]
+  #[ inline ]
+  pub fn file_data( mut self, uri : impl Into< String >, mime_type : impl Into< String > ) -> Self
+  {
+    self.parts.push( Part
+    {
+      file_data : Some( FileData { uri : Some( uri.into() ), mime_type : Some( mime_type.into() ) } ),
+      ..Default::default()
+    } );
+    self
+  }
+
+  /// Append the result of a function call.
+  #[ must_use ]
+  #[ inline ]
+  pub fn function_response( mut self, name : impl Into< String >, response : serde_json::Value ) -> Self
+  {
+    self.parts.push( Part
+    {
+      function_response : Some( FunctionResponse { name : name.into(), response } ),
+      ..Default::default()
+    } );
+    self
+  }
+
+  /// Validate the accumulated parts and produce the `Content`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidArgument` if no parts were added, a MIME type, URI or
+  /// function name is empty, function responses are mixed with other part types,
+  /// or function responses are added to a `model` turn.
+  #[ inline ]
+  pub fn build( self ) -> Result< Content, crate::error::Error >
+  {
+    use crate::error::Error;
+
+    if self.parts.is_empty()
+    {
+      return Err( Error::InvalidArgument( "Content must contain at least one part".to_string(), None ) );
+    }
+
+    for part in &self.parts
+    {
+      if part.inline_data.as_ref().is_some_and( | blob | blob.mime_type.trim().is_empty() )
+      {
+        return Err( Error::InvalidArgument( "Inline data MIME type cannot be empty".to_string(), None ) );
+      }
+      if let Some( file ) = &part.file_data
+      {
+        if file.uri.as_deref().is_none_or( | uri | uri.trim().is_empty() )
+        {
+          return Err( Error::InvalidArgument( "File data URI cannot be empty".to_string(), None ) );
+        }
+        if file.mime_type.as_deref().is_none_or( | mime | mime.trim().is_empty() )
+        {
+          return Err( Error::InvalidArgument( "File data MIME type cannot be empty".to_string(), None ) );
+        }
+      }
+      if part.function_response.as_ref().is_some_and( | response | response.name.trim().is_empty() )
+      {
+        return Err( Error::InvalidArgument( "Function response name cannot be empty".to_string(), None ) );
+      }
+    }
+
+    let function_responses = self.parts.iter().filter( | part | part.function_response.is_some() ).count();
+    if function_responses > 0
+    {
+      if function_responses != self.parts.len()
+      {
+        return Err( Error::InvalidArgument(
+          "Function response parts cannot be mixed with text or media parts in the same content".to_string(),
+          None,
+        ) );
+      }
+      if self.role == "model"
+      {
+        return Err( Error::InvalidArgument( "Function response parts must be sent in a user turn".to_string(), None ) );
+      }
+    }
+
+    Ok( Content { parts : self.parts, role : self.role } )
+  }
+}
+
 /// A response candidate from the model.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
 pub struct Candidate
 {
   /// Generated content.
+  /// Absent for candidates blocked by a content filter.
+  #[ serde( default ) ]
   pub content : Content,
 
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
@@ -135,6 +330,157 @@ pub struct SafetyRating
   pub blocked : Option< bool >,
 }
 
+impl SafetyRating
+{
+  /// The probability level parsed from `probability`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn probability_level( &self ) -> HarmProbability
+  {
+    HarmProbability::from( self.probability.as_str() )
+  }
+}
+
+/// Ordered harm probability levels reported in safety ratings.
+///
+/// Unknown or unspecified API values map to [`HarmProbability::Unspecified`],
+/// which orders below every known level.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default ) ]
+pub enum HarmProbability
+{
+  /// `HARM_PROBABILITY_UNSPECIFIED` or an unrecognized value.
+  #[ default ]
+  Unspecified,
+  /// `NEGLIGIBLE`
+  Negligible,
+  /// `LOW`
+  Low,
+  /// `MEDIUM`
+  Medium,
+  /// `HIGH`
+  High,
+}
+
+impl HarmProbability
+{
+  /// The API name of the level.
+  #[ inline ]
+  #[ must_use ]
+  pub fn as_str( self ) -> &'static str
+  {
+    match self
+    {
+      Self::Unspecified => "HARM_PROBABILITY_UNSPECIFIED",
+      Self::Negligible => "NEGLIGIBLE",
+      Self::Low => "LOW",
+      Self::Medium => "MEDIUM",
+      Self::High => "HIGH",
+    }
+  }
+}
+
+impl From< &str > for HarmProbability
+{
+  #[ inline ]
+  fn from( value : &str ) -> Self
+  {
+    match value
+    {
+      "NEGLIGIBLE" => Self::Negligible,
+      "LOW" => Self::Low,
+      "MEDIUM" => Self::Medium,
+      "HIGH" => Self::High,
+      _ => Self::Unspecified,
+    }
+  }
+}
+
+impl core::fmt::Display for HarmProbability
+{
+  #[ inline ]
+  fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+  {
+    f.write_str( self.as_str() )
+  }
+}
+
+/// The safety ratings of a prompt or candidate, for enforcing custom safety gates.
+#[ derive( Debug, Clone, Default ) ]
+pub struct SafetyRatings
+{
+  ratings : Vec< SafetyRating >,
+}
+
+impl SafetyRatings
+{
+  /// Wrap a set of ratings.
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( ratings : Vec< SafetyRating > ) -> Self
+  {
+    Self { ratings }
+  }
+
+  /// The wrapped ratings.
+  #[ inline ]
+  #[ must_use ]
+  pub fn ratings( &self ) -> &[ SafetyRating ]
+  {
+    &self.ratings
+  }
+
+  /// The highest probability across all categories, `Unspecified` if there are none.
+  #[ inline ]
+  #[ must_use ]
+  pub fn max_probability( &self ) -> HarmProbability
+  {
+    self.ratings.iter().map( SafetyRating::probability_level ).max().unwrap_or_default()
+  }
+
+  /// Whether `category` was rated strictly above `threshold`.
+  ///
+  /// Categories without a rating never exceed the threshold. To gate on `MEDIUM`
+  /// and above, pass [`HarmProbability::Low`].
+  #[ inline ]
+  #[ must_use ]
+  pub fn exceeds( &self, category : &str, threshold : HarmProbability ) -> bool
+  {
+    self.ratings.iter()
+    .filter( | rating | rating.category == category )
+    .any( | rating | rating.probability_level() > threshold )
+  }
+
+  /// One line describing every rating, e.g.
+  /// `max MEDIUM: HARASSMENT=LOW, HATE_SPEECH=MEDIUM (blocked)`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn summary( &self ) -> String
+  {
+    if self.ratings.is_empty()
+    {
+      return "no safety ratings".to_string();
+    }
+
+    let ratings : Vec< String > = self.ratings.iter().map( | rating |
+    {
+      let category = rating.category.strip_prefix( "HARM_CATEGORY_" ).unwrap_or( &rating.category );
+      let blocked = if rating.blocked == Some( true ) { " (blocked)" } else { "" };
+      format!( "{category}={}{blocked}", rating.probability_level() )
+    } ).collect();
+
+    format!( "max {}: {}", self.max_probability(), ratings.join( ", " ) )
+  }
+}
+
+impl From< Vec< SafetyRating > > for SafetyRatings
+{
+  #[ inline ]
+  fn from( ratings : Vec< SafetyRating > ) -> Self
+  {
+    Self::new( ratings )
+  }
+}
+
 /// Citation information for generated content.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -59,3 +59,77 @@ pub struct ListModelsResponse
   /// Token for retrieving the next page of results.
   pub next_page_token : Option< String >,
 }
+
+/// Explicit normalization of user-supplied model names.
+///
+/// Model names are accepted in several spellings (`gemini-2.5-flash`,
+/// `models/gemini-2.5-flash`, `gemini-2.5-flash-latest`). These helpers turn them
+/// into the canonical `models/{id}` resource path on request; nothing is rewritten
+/// automatically.
+#[ derive( Debug, Clone, Copy ) ]
+pub struct ModelName;
+
+impl ModelName
+{
+  /// Resource prefix used by the Gemini API for model names.
+  pub const PREFIX : &'static str = "models/";
+
+  /// Suffix used by the Gemini API for rolling model aliases.
+  pub const LATEST_SUFFIX : &'static str = "-latest";
+
+  /// Normalize a model name into its canonical `models/{id}` path.
+  ///
+  /// Surrounding whitespace is trimmed and repeated `models/` prefixes collapse to one.
+  #[ must_use ]
+  #[ inline ]
+  pub fn normalize( input : &str ) -> String
+  {
+    format!( "{}{}", Self::PREFIX, Self::id( input ) )
+  }
+
+  /// Normalize a model name, resolving `-latest` aliases against `known_models`.
+  ///
+  /// An exact match in `known_models` wins. Otherwise `name-latest` resolves to `name`
+  /// and `name` resolves to `name-latest` when only the other spelling is known.
+  /// Names that match nothing are returned in plain normalized form.
+  #[ must_use ]
+  #[ inline ]
+  pub fn normalize_with_known< S : AsRef< str > >( input : &str, known_models : &[ S ] ) -> String
+  {
+    let normalized = Self::normalize( input );
+    let known : Vec< String > = known_models.iter().map( | model | Self::normalize( model.as_ref() ) ).collect();
+
+    if known.contains( &normalized )
+    {
+      return normalized;
+    }
+
+    let alternative = match normalized.strip_suffix( Self::LATEST_SUFFIX )
+    {
+      Some( base ) => base.to_string(),
+      None => format!( "{normalized}{}", Self::LATEST_SUFFIX ),
+    };
+
+    if known.contains( &alternative )
+    {
+      alternative
+    }
+    else
+    {
+      normalized
+    }
+  }
+
+  /// Bare model identifier without the `models/` prefix (e.g. `gemini-2.5-flash`).
+  #[ must_use ]
+  #[ inline ]
+  pub fn id( input : &str ) -> &str
+  {
+    let mut id = input.trim();
+    while let Some( stripped ) = id.strip_prefix( Self::PREFIX )
+    {
+      id = stripped;
+    }
+    id
+  }
+}
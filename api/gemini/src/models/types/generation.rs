@@ -37,12 +37,149 @@ pub struct GenerateContentRequest
   pub cached_content : Option< String >,
 }
 
+/// What [`GenerateContentRequest::trim_to_context`] removed from a request.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Default ) ]
+pub struct ContextTrim
+{
+  /// Number of contents dropped entirely.
+  pub removed_contents : usize,
+  /// Number of contents whose text was shortened.
+  pub truncated_contents : usize,
+  /// Estimated tokens removed, according to the caller's counter.
+  pub trimmed_tokens : usize,
+  /// Estimated tokens left, including the system instruction.
+  pub remaining_tokens : usize,
+}
+
+impl ContextTrim
+{
+  /// Whether anything was removed.
+  #[ inline ]
+  #[ must_use ]
+  pub fn is_trimmed( &self ) -> bool
+  {
+    self.removed_contents > 0 || self.truncated_contents > 0
+  }
+}
+
+impl GenerateContentRequest
+{
+  /// Trim the oldest contents until the estimated total fits within `max_tokens`.
+  ///
+  /// `counter` estimates the tokens of one content; the system instruction is
+  /// counted as a content too. Contents are removed oldest first. When part of
+  /// a single-text content is enough to fit, its text is shortened instead,
+  /// keeping the end. A function call and the function response answering it are
+  /// removed together. The system instruction and the final `user` turn, with the
+  /// call it answers if it is a function response, are never touched, so the
+  /// result may still exceed `max_tokens` if they alone do.
+  #[ inline ]
+  pub fn trim_to_context( &mut self, max_tokens : usize, counter : impl Fn( &Content ) -> usize ) -> ContextTrim
+  {
+    let system_tokens = self.system_instruction.as_ref().map_or( 0, | instruction | counter( &Content
+    {
+      parts : instruction.parts.clone(),
+      role : instruction.role.clone(),
+    } ) );
+    let mut tokens : Vec< usize > = self.contents.iter().map( &counter ).collect();
+    let total = system_tokens + tokens.iter().sum::< usize >();
+    let mut trim = ContextTrim { remaining_tokens : total, ..ContextTrim::default() };
+
+    // First and last index of the kept turns : the final user turn, and the call it answers
+    let mut kept = self.contents.iter().rposition( | content | content.role == "user" ).map( | last |
+    {
+      let first = if last > 0 && is_answered_call( &self.contents, last - 1 ) { last - 1 } else { last };
+      ( first, last )
+    } );
+    let mut index = 0;
+    while trim.remaining_tokens > max_tokens && index < self.contents.len()
+    {
+      if let Some( ( _, last ) ) = kept.filter( | ( first, _ ) | *first == index )
+      {
+        index = last + 1;
+        continue;
+      }
+
+      let excess = trim.remaining_tokens - max_tokens;
+      if tokens[ index ] > excess
+      {
+        if let Some( ( shortened, shortened_tokens ) ) = truncate_content( &self.contents[ index ], tokens[ index ] - excess, &counter )
+        {
+          trim.remaining_tokens -= tokens[ index ] - shortened_tokens;
+          trim.truncated_contents += 1;
+          self.contents[ index ] = shortened;
+          tokens[ index ] = shortened_tokens;
+          break;
+        }
+      }
+
+      // Never leave a function response without its call
+      let removed = if is_answered_call( &self.contents, index ) { 2 } else { 1 };
+      for _ in 0..removed
+      {
+        trim.remaining_tokens -= tokens.remove( index );
+        self.contents.remove( index );
+      }
+      trim.removed_contents += removed;
+      kept = kept.map( | ( first, last ) | if first > index { ( first - removed, last - removed ) } else { ( first, last ) } );
+    }
+
+    trim.trimmed_tokens = total - trim.remaining_tokens;
+    trim
+  }
+}
+
+/// Whether `contents[ index ]` calls functions and the next content responds to them.
+fn is_answered_call( contents : &[ Content ], index : usize ) -> bool
+{
+  let calls = contents[ index ].parts.iter().any( | part | part.function_call.is_some() );
+  calls && contents.get( index + 1 ).is_some_and( | next | next.parts.iter().any( | part | part.function_response.is_some() ) )
+}
+
+/// Shorten a single-text content from the start to at most `budget` tokens.
+///
+/// Returns `None` if the content is not a single text part or no non-empty
+/// suffix of the text fits.
+fn truncate_content( content : &Content, budget : usize, counter : &impl Fn( &Content ) -> usize ) -> Option< ( Content, usize ) >
+{
+  let [ part ] = content.parts.as_slice() else { return None };
+  let text_only = part.inline_data.is_none()
+    && part.function_call.is_none()
+    && part.function_response.is_none()
+    && part.file_data.is_none()
+    && part.video_metadata.is_none()
+    && part.executable_code.is_none()
+    && part.code_execution_result.is_none();
+  let text = part.text.as_deref().filter( | _ | text_only )?;
+
+  let with_suffix = | start : usize |
+  {
+    let mut shortened = content.clone();
+    shortened.parts[ 0 ].text = Some( text[ start.. ].to_string() );
+    let tokens = counter( &shortened );
+    ( shortened, tokens )
+  };
+
+  // Binary search the earliest char boundary whose suffix fits the budget
+  let boundaries : Vec< usize > = text.char_indices().map( | ( index, _ ) | index ).collect();
+  let ( mut low, mut high ) = ( 0, boundaries.len() );
+  while low < high
+  {
+    let middle = ( low + high ) / 2;
+    if with_suffix( boundaries[ middle ] ).1 <= budget { high = middle; } else { low = middle + 1; }
+  }
+
+  boundaries.get( low ).map( | start | with_suffix( *start ) )
+}
+
 /// Response from content generation.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
 pub struct GenerateContentResponse
 {
   /// Generated content candidates.
+  /// Absent when the prompt itself was blocked ( see [`GenerateContentResponse::blocked_reason`] ).
+  #[ serde( default ) ]
   pub candidates : Vec< super::content::Candidate >,
 
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
@@ -58,6 +195,257 @@ pub struct GenerateContentResponse
   pub grounding_metadata : Option< super::search::GroundingMetadata >,
 }
 
+/// Maximum number of characters of model output quoted in parse errors.
+const PARSE_ERROR_SNIPPET_CHARS : usize = 200;
+
+impl GenerateContentResponse
+{
+  /// Deserialize the first candidate's text as JSON into `T`.
+  ///
+  /// Text parts of the first candidate are concatenated, and a surrounding markdown
+  /// code fence (```` ```json ... ``` ````) is stripped before parsing, since models
+  /// often wrap structured output in one.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::DeserializationError` if there is no candidate text or the text
+  /// is not valid JSON for `T`; the message includes a snippet of the raw text.
+  #[ inline ]
+  pub fn parse_json< T : serde::de::DeserializeOwned >( &self ) -> Result< T, crate::error::Error >
+  {
+    let text : String = self.candidates
+    .first()
+    .map( | candidate | candidate.content.parts.iter().filter_map( | part | part.text.as_deref() ).collect() )
+    .unwrap_or_default();
+
+    if text.trim().is_empty()
+    {
+      return Err( crate::error::Error::DeserializationError( "Response contains no candidate text to parse as JSON".to_string() ) );
+    }
+
+    serde_json::from_str( strip_code_fence( &text ) ).map_err( | e |
+    {
+      let snippet : String = text.chars().take( PARSE_ERROR_SNIPPET_CHARS ).collect();
+      let ellipsis = if text.chars().count() > PARSE_ERROR_SNIPPET_CHARS { "..." } else { "" };
+      crate::error::Error::DeserializationError( format!( "Failed to parse response text as JSON : {e}. Text : {snippet}{ellipsis}" ) )
+    } )
+  }
+}
+
+/// Finish reasons meaning the candidate was cut off by a content filter.
+const BLOCKING_FINISH_REASONS : &[ &str ] = &[ "SAFETY", "RECITATION", "BLOCKLIST", "PROHIBITED_CONTENT", "SPII", "IMAGE_SAFETY" ];
+
+/// Why a response came back without ( complete ) content.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub enum BlockReason
+{
+  /// The prompt itself was blocked and no candidates were generated.
+  Prompt
+  {
+    /// `promptFeedback.blockReason`, e.g. `SAFETY` or `BLOCKLIST`.
+    reason : String,
+    /// Safety categories flagged as blocked for the prompt.
+    categories : Vec< String >,
+  },
+  /// The first candidate was stopped or filtered.
+  Candidate
+  {
+    /// The candidate's finish reason, e.g. `SAFETY` or `RECITATION`, if any.
+    finish_reason : Option< String >,
+    /// Safety categories flagged as blocked for the candidate.
+    categories : Vec< String >,
+  },
+}
+
+impl GenerateContentResponse
+{
+  /// Report whether and why the response was blocked.
+  ///
+  /// Checks `prompt_feedback.block_reason` first, then the first candidate's
+  /// `finish_reason` ( `SAFETY`, `RECITATION`, `BLOCKLIST`, `PROHIBITED_CONTENT`,
+  /// `SPII`, `IMAGE_SAFETY` ) and its safety ratings marked `blocked`. Returns
+  /// `None` for responses that were not blocked.
+  #[ inline ]
+  #[ must_use ]
+  pub fn blocked_reason( &self ) -> Option< BlockReason >
+  {
+    fn blocked_categories( ratings : Option< &Vec< super::content::SafetyRating > > ) -> Vec< String >
+    {
+      ratings
+      .into_iter()
+      .flatten()
+      .filter( | rating | rating.blocked == Some( true ) )
+      .map( | rating | rating.category.clone() )
+      .collect()
+    }
+
+    if let Some( feedback ) = &self.prompt_feedback
+    {
+      if let Some( reason ) = &feedback.block_reason
+      {
+        return Some( BlockReason::Prompt
+        {
+          reason : reason.clone(),
+          categories : blocked_categories( feedback.safety_ratings.as_ref() ),
+        } );
+      }
+    }
+
+    let candidate = self.candidates.first()?;
+    let categories = blocked_categories( candidate.safety_ratings.as_ref() );
+    let blocked_finish = candidate.finish_reason.as_deref().is_some_and( | reason | BLOCKING_FINISH_REASONS.contains( &reason ) );
+
+    ( blocked_finish || !categories.is_empty() ).then( || BlockReason::Candidate
+    {
+      finish_reason : candidate.finish_reason.clone(),
+      categories,
+    } )
+  }
+}
+
+impl GenerateContentResponse
+{
+  /// The first candidate's text with citation markers for its grounding sources.
+  ///
+  /// Each grounding support's markers are inserted at its `end_index`, a byte
+  /// offset into the concatenated text parts of the first candidate ( moved back
+  /// to a character boundary and clamped to the text ). Sources are numbered in
+  /// the order they are first cited, and the cited sources are listed after the
+  /// text. Supports without an end index and chunk indices without a chunk are
+  /// skipped; without grounding metadata the text is returned unchanged.
+  #[ inline ]
+  #[ must_use ]
+  pub fn text_with_citations( &self, style : super::search::CitationStyle ) -> String
+  {
+    use super::search::CitationStyle;
+
+    let text : String = self.candidates
+    .first()
+    .map( | candidate | candidate.content.parts.iter().filter_map( | part | part.text.as_deref() ).collect() )
+    .unwrap_or_default();
+
+    let Some( metadata ) = &self.grounding_metadata else { return text };
+    let chunks = metadata.grounding_chunks.as_deref().unwrap_or_default();
+
+    // ( insertion offset, chunk indices ) in text order
+    let mut supports : Vec< ( usize, Vec< usize > ) > = metadata.grounding_supports
+    .iter()
+    .flatten()
+    .filter_map( | support |
+    {
+      let end = usize::try_from( support.end_index? ).ok()?.min( text.len() );
+      let end = ( 0..=end ).rev().find( | index | text.is_char_boundary( *index ) ).unwrap_or( 0 );
+      let indices : Vec< usize > = support.grounding_chunk_indices
+      .iter()
+      .filter_map( | index | usize::try_from( *index ).ok() )
+      .filter( | index | *index < chunks.len() )
+      .collect();
+      ( !indices.is_empty() ).then_some( ( end, indices ) )
+    } )
+    .collect();
+    supports.sort_by_key( | ( end, _ ) | *end );
+
+    // Chunk index for each citation number, in order of first citation
+    let mut cited : Vec< usize > = Vec::new();
+    let mut markers : Vec< ( usize, String ) > = Vec::new();
+    for ( end, indices ) in supports
+    {
+      let mut numbers : Vec< usize > = indices.into_iter().map( | index |
+      {
+        cited.iter().position( | cited | *cited == index ).unwrap_or_else( || { cited.push( index ); cited.len() - 1 } ) + 1
+      } ).collect();
+      numbers.sort_unstable();
+      numbers.dedup();
+      let marker : String = numbers.iter().map( | number | match style
+      {
+        CitationStyle::Numeric => format!( "[{number}]" ),
+        CitationStyle::Footnote => format!( "[^{number}]" ),
+      } ).collect();
+      markers.push( ( end, marker ) );
+    }
+
+    if cited.is_empty()
+    {
+      return text;
+    }
+
+    let mut result = text;
+    for ( end, marker ) in markers.into_iter().rev()
+    {
+      result.insert_str( end, &marker );
+    }
+
+    result.push_str( "\n\n" );
+    if style == CitationStyle::Numeric
+    {
+      result.push_str( "Sources:\n" );
+    }
+    for ( number, index ) in cited.iter().enumerate()
+    {
+      let label = chunks[ *index ].source_label();
+      match style
+      {
+        CitationStyle::Numeric => result.push_str( &format!( "[{}] {label}\n", number + 1 ) ),
+        CitationStyle::Footnote => result.push_str( &format!( "[^{}]: {label}\n", number + 1 ) ),
+      }
+    }
+    result
+  }
+}
+
+impl GenerateContentResponse
+{
+  /// Code blocks the model ran with the code execution tool, in order.
+  ///
+  /// Each `executable_code` part of the first candidate is paired with the
+  /// `code_execution_result` part that follows it. Code without a result has
+  /// no outcome or output, and results without preceding code are skipped.
+  #[ inline ]
+  #[ must_use ]
+  pub fn code_executions( &self ) -> Vec< super::code_execution::CodeExecutionOutcome >
+  {
+    let mut executions : Vec< super::code_execution::CodeExecutionOutcome > = Vec::new();
+    let Some( candidate ) = self.candidates.first() else { return executions };
+
+    for part in &candidate.content.parts
+    {
+      if let Some( executable ) = &part.executable_code
+      {
+        executions.push( super::code_execution::CodeExecutionOutcome
+        {
+          language : executable.language.clone(),
+          code : executable.code.clone(),
+          outcome : None,
+          output : None,
+        } );
+      }
+      if let Some( result ) = &part.code_execution_result
+      {
+        if let Some( execution ) = executions.last_mut().filter( | execution | execution.outcome.is_none() )
+        {
+          execution.outcome = Some( result.outcome.clone() );
+          execution.output = result.output.clone().or_else( || result.error.clone() );
+        }
+      }
+    }
+    executions
+  }
+}
+
+/// Remove a surrounding markdown code fence, with or without a language tag.
+fn strip_code_fence( text : &str ) -> &str
+{
+  let trimmed = text.trim();
+  let Some( rest ) = trimmed.strip_prefix( "```" ) else { return trimmed };
+  let Some( body ) = rest.strip_suffix( "```" ) else { return trimmed };
+  // Drop the language tag line (e.g. `json`), but keep content that starts right after the fence
+  match body.split_once( '\n' )
+  {
+    Some( ( tag, content ) ) if tag.trim().chars().all( | c | c.is_ascii_alphanumeric() || matches!( c, '-' | '_' | '+' | '.' ) ) => content.trim(),
+    _ => body.trim(),
+  }
+}
+
 /// Configuration for how the model generates responses.
 #[ derive( Debug, Clone, Serialize, Deserialize, Default ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -86,6 +474,29 @@ pub struct GenerationConfig
   /// Sequences that will stop generation.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   pub stop_sequences : Option< Vec< String > >,
+
+  /// MIME type of the generated candidate text (e.g. `application/json`).
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub response_mime_type : Option< String >,
+
+  /// Schema the generated JSON must conform to; requires a JSON `response_mime_type`.
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  pub response_schema : Option< serde_json::Value >,
+}
+
+impl GenerationConfig
+{
+  /// Set the sequences that stop generation, serialized as `stopSequences`.
+  ///
+  /// The API accepts at most 5 non-empty sequences; see
+  /// [`crate::validation::validate_generation_config`].
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_stop_sequences( mut self, stop_sequences : Vec< String > ) -> Self
+  {
+    self.stop_sequences = Some( stop_sequences );
+    self
+  }
 }
 
 /// Safety setting for blocking content.
@@ -17,3 +17,4 @@ pub mod search;
 pub mod function;
 pub mod code_execution;
 pub mod tuning;
+pub mod universal;
@@ -81,6 +81,35 @@ pub struct GroundingSupport
   pub confidence_score : Option< f64 >,
 }
 
+/// How [`crate::models::GenerateContentResponse::text_with_citations`] marks cited text.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Default ) ]
+pub enum CitationStyle
+{
+  /// Bracketed numbers, `text[1][2]`, followed by a `Sources:` list of `[1] Title - uri` lines.
+  #[ default ]
+  Numeric,
+  /// Markdown footnotes, `text[^1][^2]`, followed by `[^1]: Title - uri` definitions.
+  Footnote,
+}
+
+impl GroundingChunk
+{
+  /// A one-line label for the source, `Title - uri` when both are known.
+  #[ inline ]
+  #[ must_use ]
+  pub fn source_label( &self ) -> String
+  {
+    let name = self.title.as_deref().or( self.domain.as_deref() );
+    match ( name, self.uri.as_deref() )
+    {
+      ( Some( name ), Some( uri ) ) => format!( "{name} - {uri}" ),
+      ( Some( name ), None ) => name.to_string(),
+      ( None, Some( uri ) ) => uri.to_string(),
+      ( None, None ) => "Unknown source".to_string(),
+    }
+  }
+}
+
 /// Search entry point providing access to search functionality.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
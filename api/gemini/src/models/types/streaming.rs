@@ -2,7 +2,8 @@
 
 use serde::{ Deserialize, Serialize };
 use super::content::{ Content, Part, Candidate };
-use super::generation::{ GenerateContentRequest, GenerationConfig, UsageMetadata };
+use super::generation::{ GenerateContentRequest, GenerateContentResponse, GenerationConfig, PromptFeedback, UsageMetadata };
+use super::search::GroundingMetadata;
 
 /// Response type for streaming content generation.
 #[ cfg( feature = "streaming" ) ]
@@ -18,6 +19,14 @@ pub struct StreamingResponse
   /// Usage metadata (typically present in final chunk).
   pub usage_metadata : Option< UsageMetadata >,
 
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Feedback about the prompt.
+  pub prompt_feedback : Option< PromptFeedback >,
+
+  #[ serde( skip_serializing_if = "Option::is_none" ) ]
+  /// Grounding metadata for web search results.
+  pub grounding_metadata : Option< GroundingMetadata >,
+
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   /// Whether this is the final chunk in the stream.
   pub is_final : Option< bool >,
@@ -124,3 +133,366 @@ impl< 'a > StreamingRequestBuilder< 'a >
     self.model.generate_content_stream( &self.request ).await
   }
 }
+
+/// Drive a stream of [`StreamingResponse`] chunks to completion through callbacks.
+///
+/// `on_token` is invoked with every text fragment in arrival order. Once the stream
+/// is exhausted, the chunks are assembled into a single [`GenerateContentResponse`]
+/// (text fragments concatenated per candidate, last usage metadata, prompt feedback
+/// and grounding metadata kept), a copy is
+/// handed to `on_done`, and the assembled response is returned.
+///
+/// # Errors
+///
+/// Returns the first error yielded by the stream. `on_done` is not invoked in that case.
+#[ cfg( feature = "streaming" ) ]
+#[ inline ]
+pub async fn drive_stream_with_callbacks< S, F, D >
+(
+  stream : S,
+  mut on_token : F,
+  on_done : D,
+)
+->
+Result< GenerateContentResponse, crate::error::Error >
+where
+  S : futures::Stream< Item = Result< StreamingResponse, crate::error::Error > >,
+  F : FnMut( &str ),
+  D : FnOnce( GenerateContentResponse ),
+{
+  use futures::StreamExt;
+
+  let mut candidates : Vec< Candidate > = Vec::new();
+  let mut usage_metadata = None;
+  let mut prompt_feedback = None;
+  let mut grounding_metadata = None;
+
+  futures::pin_mut!( stream );
+  while let Some( chunk ) = stream.next().await
+  {
+    let chunk = chunk?;
+
+    if chunk.usage_metadata.is_some()
+    {
+      usage_metadata = chunk.usage_metadata;
+    }
+    if chunk.prompt_feedback.is_some()
+    {
+      prompt_feedback = chunk.prompt_feedback;
+    }
+    if chunk.grounding_metadata.is_some()
+    {
+      grounding_metadata = chunk.grounding_metadata;
+    }
+
+    for ( position, candidate ) in chunk.candidates.unwrap_or_default().into_iter().enumerate()
+    {
+      let slot = candidate.index
+        .and_then( | index | usize::try_from( index ).ok() )
+        .unwrap_or( position );
+      while candidates.len() <= slot
+      {
+        candidates.push( Candidate
+        {
+          content : Content { parts : Vec::new(), role : candidate.content.role.clone() },
+          finish_reason : None,
+          safety_ratings : None,
+          citation_metadata : None,
+          token_count : None,
+          index : i32::try_from( candidates.len() ).ok(),
+        } );
+      }
+
+      let assembled = &mut candidates[ slot ];
+      for part in candidate.content.parts
+      {
+        if let Some( text ) = &part.text
+        {
+          on_token( text );
+          if let Some( Part { text : Some( previous ), .. } ) = assembled.content.parts.last_mut()
+          {
+            previous.push_str( text );
+            continue;
+          }
+        }
+        assembled.content.parts.push( part );
+      }
+
+      if candidate.finish_reason.is_some()
+      {
+        assembled.finish_reason = candidate.finish_reason;
+      }
+      if candidate.safety_ratings.is_some()
+      {
+        assembled.safety_ratings = candidate.safety_ratings;
+      }
+      if candidate.citation_metadata.is_some()
+      {
+        assembled.citation_metadata = candidate.citation_metadata;
+      }
+      if candidate.token_count.is_some()
+      {
+        assembled.token_count = candidate.token_count;
+      }
+    }
+  }
+
+  let response = GenerateContentResponse
+  {
+    candidates,
+    prompt_feedback,
+    usage_metadata,
+    grounding_metadata,
+  };
+
+  on_done( response.clone() );
+  Ok( response )
+}
+
+/// Chunk yielded by a [`ResilientStream`].
+#[ cfg( feature = "streaming" ) ]
+#[ derive( Debug, Clone ) ]
+pub struct ResilientChunk
+{
+  /// The streamed response chunk.
+  pub response : StreamingResponse,
+  /// Number of reconnects performed before this chunk was received.
+  pub reconnects : u32,
+  /// Whether this is the first chunk received after a reconnect, i.e. it follows a seam.
+  pub is_seam : bool,
+}
+
+/// Opt-in wrapper that resumes dropped content generation streams.
+///
+/// When the underlying stream fails with a connection-level error ( see
+/// [`ResilientStream::is_reconnectable`] ), the request is re-sent with the text
+/// received so far appended as a `model` turn, followed by
+/// [`ResilientStream::continuation_prompt`] as a `user` turn, and streaming
+/// continues. Chunks carry the reconnect count and mark the first chunk after
+/// each seam, since the model may not resume at exactly the same character.
+///
+/// Reconnects stop after `max_reconnects` or once `max_elapsed_time` has passed
+/// since the first connection; the last error is then yielded.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use api_gemini::{ client::Client, GenerateContentRequest, Content, Part };
+/// # use api_gemini::models::ResilientStream;
+/// # use futures::StreamExt;
+/// # #[ tokio::main ]
+/// # async fn main() -> Result< (), Box< dyn std::error::Error > > {
+/// let client = Client::new()?;
+/// let models_api = client.models();
+/// let model = models_api.by_name( "gemini-2.5-flash" );
+///
+/// let request = GenerateContentRequest {
+///   contents : vec![ Content {
+///     parts : vec![ Part { text : Some( "Write a long story".to_string() ), ..Default::default() } ],
+///     role : "user".to_string(),
+///   } ],
+///   ..Default::default()
+/// };
+///
+/// let resilient = ResilientStream::new().with_max_reconnects( 2 );
+/// let stream = model.generate_content_stream_resilient( &request, &resilient );
+/// futures::pin_mut!( stream );
+/// while let Some( chunk ) = stream.next().await
+/// {
+///   let chunk = chunk?;
+///   if chunk.is_seam { println!( "\n[reconnected]" ); }
+/// }
+/// # Ok( () )
+/// # }
+/// ```
+#[ cfg( feature = "streaming" ) ]
+#[ derive( Debug, Clone ) ]
+pub struct ResilientStream
+{
+  /// Maximum number of reconnects ( default : 3 ).
+  pub max_reconnects : u32,
+  /// Total time budget after which no further reconnects are attempted ( default : 5 minutes ).
+  pub max_elapsed_time : Option< core::time::Duration >,
+  /// User turn sent after the received text when resuming.
+  pub continuation_prompt : String,
+}
+
+#[ cfg( feature = "streaming" ) ]
+impl Default for ResilientStream
+{
+  #[ inline ]
+  fn default() -> Self
+  {
+    Self::new()
+  }
+}
+
+#[ cfg( feature = "streaming" ) ]
+impl ResilientStream
+{
+  /// Create a wrapper with default limits.
+  #[ must_use ]
+  #[ inline ]
+  pub fn new() -> Self
+  {
+    Self
+    {
+      max_reconnects : 3,
+      max_elapsed_time : Some( core::time::Duration::from_secs( 300 ) ),
+      continuation_prompt : "Continue exactly where you left off, without repeating any text.".to_string(),
+    }
+  }
+
+  /// Set the maximum number of reconnects.
+  #[ must_use ]
+  #[ inline ]
+  pub fn with_max_reconnects( mut self, max_reconnects : u32 ) -> Self
+  {
+    self.max_reconnects = max_reconnects;
+    self
+  }
+
+  /// Set the total time budget for reconnecting ( `None` for no limit ).
+  #[ must_use ]
+  #[ inline ]
+  pub fn with_max_elapsed_time( mut self, max_elapsed_time : Option< core::time::Duration > ) -> Self
+  {
+    self.max_elapsed_time = max_elapsed_time;
+    self
+  }
+
+  /// Set the user turn sent after the received text when resuming.
+  #[ must_use ]
+  #[ inline ]
+  pub fn with_continuation_prompt( mut self, prompt : impl Into< String > ) -> Self
+  {
+    self.continuation_prompt = prompt.into();
+    self
+  }
+
+  /// Whether an error indicates a dropped connection worth resuming.
+  #[ must_use ]
+  #[ inline ]
+  pub fn is_reconnectable( error : &crate::error::Error ) -> bool
+  {
+    use crate::error::Error;
+
+    matches!( error, Error::NetworkError( _ ) | Error::ServerError( _ ) | Error::Io( _ ) | Error::TimeoutError( _ ) )
+  }
+
+  /// Build the request that resumes generation after `received` text.
+  ///
+  /// Without received text the original request is re-sent unchanged.
+  #[ must_use ]
+  #[ inline ]
+  pub fn resume_request( &self, request : &GenerateContentRequest, received : &str ) -> GenerateContentRequest
+  {
+    let mut resumed = request.clone();
+    if received.is_empty()
+    {
+      return resumed;
+    }
+
+    resumed.contents.push( Content
+    {
+      parts : vec![ Part { text : Some( received.to_string() ), ..Default::default() } ],
+      role : "model".to_string(),
+    } );
+    resumed.contents.push( Content
+    {
+      parts : vec![ Part { text : Some( self.continuation_prompt.clone() ), ..Default::default() } ],
+      role : "user".to_string(),
+    } );
+    resumed
+  }
+
+  /// Stream `request` through `connect`, reconnecting on connection errors.
+  ///
+  /// `connect` opens one underlying stream for the given request; it is called
+  /// again with [`ResilientStream::resume_request`] after each drop. Text of the
+  /// first candidate is tracked as the received text.
+  #[ inline ]
+  pub fn stream< C, Fut, S >
+  (
+    &self,
+    request : GenerateContentRequest,
+    mut connect : C,
+  )
+  -> impl futures::Stream< Item = Result< ResilientChunk, crate::error::Error > >
+  where
+    C : FnMut( GenerateContentRequest ) -> Fut,
+    Fut : core::future::Future< Output = Result< S, crate::error::Error > >,
+    S : futures::Stream< Item = Result< StreamingResponse, crate::error::Error > >,
+  {
+    use futures::StreamExt;
+
+    let config = self.clone();
+
+    async_stream::stream!
+    {
+      let start = std::time::Instant::now();
+      let mut received = String::new();
+      let mut reconnects = 0_u32;
+      let mut current = request.clone();
+
+      loop
+      {
+        let error = match connect( current ).await
+        {
+          Ok( inner ) =>
+          {
+            futures::pin_mut!( inner );
+            let mut is_seam = reconnects > 0;
+            let mut failure = None;
+
+            while let Some( item ) = inner.next().await
+            {
+              match item
+              {
+                Ok( response ) =>
+                {
+                  let text = response.candidates.iter()
+                    .flatten()
+                    .find( | candidate | candidate.index.unwrap_or( 0 ) == 0 )
+                    .into_iter()
+                    .flat_map( | candidate | candidate.content.parts.iter() )
+                    .filter_map( | part | part.text.as_deref() );
+                  for fragment in text
+                  {
+                    received.push_str( fragment );
+                  }
+
+                  yield Ok( ResilientChunk { response, reconnects, is_seam } );
+                  is_seam = false;
+                },
+                Err( error ) =>
+                {
+                  failure = Some( error );
+                  break;
+                },
+              }
+            }
+
+            match failure
+            {
+              Some( error ) => error,
+              None => break,
+            }
+          },
+          Err( error ) => error,
+        };
+
+        let within_budget = config.max_elapsed_time.is_none_or( | max | start.elapsed() < max );
+        if reconnects >= config.max_reconnects || !within_budget || !Self::is_reconnectable( &error )
+        {
+          yield Err( error );
+          break;
+        }
+
+        reconnects += 1;
+        current = config.resume_request( &request, &received );
+      }
+    }
+  }
+}
+
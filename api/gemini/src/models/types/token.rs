@@ -1,7 +1,8 @@
 //! Token counting and analysis types for the Gemini API.
 
+use std::collections::HashMap;
 use serde::{ Deserialize, Serialize };
-use super::content::Content;
+use super::content::{ Content, Part };
 use super::generation::GenerateContentRequest;
 
 /// Request for counting tokens in content.
@@ -10,6 +11,9 @@ use super::generation::GenerateContentRequest;
 pub struct CountTokensRequest
 {
   /// Contents to count tokens for.
+  ///
+  /// Leave empty when `generate_content_request` is set; the API accepts only one of them.
+  #[ serde( default, skip_serializing_if = "Vec::is_empty" ) ]
   pub contents : Vec< Content >,
 
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
@@ -17,6 +21,25 @@ pub struct CountTokensRequest
   pub generate_content_request : Option< GenerateContentRequest >,
 }
 
+impl CountTokensRequest
+{
+  /// Build a token count request for the exact payload of a generation request.
+  ///
+  /// The full request is embedded as `generate_content_request`, so the count covers
+  /// the system instruction and tool declarations as well as the conversation.
+  /// `contents` is left empty, since the API rejects requests that set both.
+  #[ must_use ]
+  #[ inline ]
+  pub fn from_generate_request( request : &GenerateContentRequest ) -> Self
+  {
+    Self
+    {
+      contents : Vec::new(),
+      generate_content_request : Some( request.clone() ),
+    }
+  }
+}
+
 /// Response from counting tokens.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -122,6 +145,109 @@ pub struct TokenBreakdown
   /// Tokens used by system instructions.
   #[ serde( skip_serializing_if = "Option::is_none" ) ]
   pub system_tokens : Option< i32 >,
+
+  /// Tokens attributed to each role (`system`, `user`, `model`, `tool`).
+  #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+  pub by_role : Option< HashMap< String, i32 > >,
+}
+
+/// Approximate token cost of a single inline or file media part.
+const MEDIA_PART_TOKEN_WEIGHT : u64 = 258;
+
+/// Relative token weight of a part, used to apportion a known total between roles.
+fn part_token_weight( part : &Part ) -> ( u64, bool )
+{
+  let mut weight = part.text.as_ref().map_or( 0, | text | ( text.len() as u64 ).div_ceil( 4 ) );
+  if part.inline_data.is_some() || part.file_data.is_some()
+  {
+    weight += MEDIA_PART_TOKEN_WEIGHT;
+  }
+  let function_json = part.function_call.as_ref().and_then( | call | serde_json::to_string( call ).ok() )
+  .into_iter()
+  .chain( part.function_response.as_ref().and_then( | response | serde_json::to_string( response ).ok() ) );
+  let mut is_tool = false;
+  for json in function_json
+  {
+    weight += ( json.len() as u64 ).div_ceil( 4 );
+    is_tool = true;
+  }
+  ( weight, is_tool )
+}
+
+impl AnalyzeTokensRequest
+{
+  /// Attribute `total_tokens` to the roles contributing to this request.
+  ///
+  /// Each role is weighted by the structure it sends: the system instruction counts
+  /// as `system`, content turns by their own role, and function calls, function
+  /// responses and tool declarations as `tool`. The total is then split in proportion
+  /// to those weights, so the returned values always sum to `total_tokens`.
+  ///
+  /// Content turns are taken from `generate_content_request` when it is set, and
+  /// from `contents` otherwise, matching what the API counts.
+  #[ must_use ]
+  #[ inline ]
+  pub fn role_token_breakdown( &self, total_tokens : i32 ) -> HashMap< String, i32 >
+  {
+    let mut weights : Vec< ( String, u64 ) > = Vec::new();
+    let mut add = | role : &str, weight : u64 |
+    {
+      match weights.iter_mut().find( | ( existing, _ ) | existing == role )
+      {
+        Some( ( _, total ) ) => *total += weight,
+        None => weights.push( ( role.to_string(), weight ) ),
+      }
+    };
+
+    let generate_request = self.generate_content_request.as_ref();
+    let contents = generate_request.map_or( &self.contents, | request | &request.contents );
+    for content in contents
+    {
+      for part in &content.parts
+      {
+        let ( weight, is_tool ) = part_token_weight( part );
+        add( if is_tool { "tool" } else { content.role.as_str() }, weight );
+      }
+    }
+
+    if let Some( request ) = generate_request
+    {
+      if let Some( instruction ) = &request.system_instruction
+      {
+        add( "system", instruction.parts.iter().map( | part | part_token_weight( part ).0 ).sum() );
+      }
+      if let Some( tools ) = &request.tools
+      {
+        let json_len = serde_json::to_string( tools ).map_or( 0, | json | json.len() as u64 );
+        add( "tool", json_len.div_ceil( 4 ) );
+      }
+    }
+
+    let total = u64::try_from( total_tokens.max( 0 ) ).unwrap_or( 0 );
+    let weight_sum : u64 = weights.iter().map( | ( _, weight ) | weight ).sum();
+    if weight_sum == 0
+    {
+      return weights.into_iter().map( | ( role, _ ) | ( role, 0 ) ).collect();
+    }
+
+    // Largest-remainder apportionment keeps the per-role sum equal to the total
+    let mut shares : Vec< ( String, u64, u64 ) > = weights
+    .into_iter()
+    .map( | ( role, weight ) | ( role, total * weight / weight_sum, total * weight % weight_sum ) )
+    .collect();
+    let assigned : u64 = shares.iter().map( | ( _, share, _ ) | share ).sum();
+    let mut order : Vec< usize > = ( 0..shares.len() ).collect();
+    order.sort_by( | a, b | shares[ *b ].2.cmp( &shares[ *a ].2 ) );
+    for index in order.into_iter().take( usize::try_from( total - assigned ).unwrap_or( 0 ) )
+    {
+      shares[ index ].1 += 1;
+    }
+
+    shares
+    .into_iter()
+    .map( | ( role, share, _ ) | ( role, i32::try_from( share ).unwrap_or( i32::MAX ) ) )
+    .collect()
+  }
 }
 
 /// Cost estimation for token usage.
@@ -125,6 +125,240 @@ pub struct Dataset
   pub examples : Option< TuningExamples >,
 }
 
+/// Row skipped while importing a tuning dataset.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct ImportWarning
+{
+  /// 1-based line where the skipped record starts
+  pub line : usize,
+  /// Why the record was skipped
+  pub reason : String,
+}
+
+impl Dataset
+{
+  /// Import tuning examples from JSON Lines.
+  ///
+  /// Each non-blank line must be a JSON object with string `text_input` (or
+  /// `textInput`) and `output` fields. Lines that are not valid JSON objects,
+  /// lack either field, or have an empty one are skipped and reported.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::Io` if the reader fails or the input is not UTF-8.
+  pub fn from_jsonl< R : std::io::Read >( mut reader : R ) -> Result< ( Self, Vec< ImportWarning > ), crate::error::Error >
+  {
+    let mut text = String::new();
+    reader.read_to_string( &mut text ).map_err( | e | crate::error::Error::Io( format!( "Failed to read JSONL dataset : {e}" ) ) )?;
+
+    let mut examples = Vec::new();
+    let mut warnings = Vec::new();
+    for ( index, raw ) in text.lines().enumerate()
+    {
+      let line = index + 1;
+      if raw.trim().is_empty()
+      {
+        continue;
+      }
+
+      let record = match serde_json::from_str::< serde_json::Value >( raw )
+      {
+        Ok( serde_json::Value::Object( record ) ) => record,
+        Ok( _ ) =>
+        {
+          warnings.push( ImportWarning { line, reason : "record is not a JSON object".to_string() } );
+          continue;
+        },
+        Err( e ) =>
+        {
+          warnings.push( ImportWarning { line, reason : format!( "invalid JSON : {e}" ) } );
+          continue;
+        },
+      };
+
+      let field = | names : &[ &str ] | names.iter().find_map( | name | record.get( *name ) ).and_then( serde_json::Value::as_str );
+      match import_example( field( &[ "text_input", "textInput" ] ), field( &[ "output" ] ), "text_input", "output" )
+      {
+        Ok( example ) => examples.push( example ),
+        Err( reason ) => warnings.push( ImportWarning { line, reason } ),
+      }
+    }
+
+    Ok( ( Self::from_examples( examples ), warnings ) )
+  }
+
+  /// Import tuning examples from CSV with a header row.
+  ///
+  /// Fields are comma-separated and may be quoted with `"`, in which case they
+  /// can contain commas, line breaks and doubled `""` quotes. Inputs and outputs
+  /// are read from the `input_col` and `output_col` columns. Records with an
+  /// unterminated quote, too few fields, or an empty input or output are
+  /// skipped and reported.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::Io` if the reader fails or the input is not UTF-8, and
+  /// `Error::InvalidArgument` if the header is missing either column.
+  pub fn from_csv< R : std::io::Read >( mut reader : R, input_col : &str, output_col : &str ) -> Result< ( Self, Vec< ImportWarning > ), crate::error::Error >
+  {
+    let mut text = String::new();
+    reader.read_to_string( &mut text ).map_err( | e | crate::error::Error::Io( format!( "Failed to read CSV dataset : {e}" ) ) )?;
+
+    let mut records = parse_csv( &text ).into_iter();
+    let header = match records.next()
+    {
+      Some( ( _, Ok( header ) ) ) => header,
+      Some( ( line, Err( reason ) ) ) => return Err( crate::error::Error::InvalidArgument( format!( "Malformed CSV header on line {line} : {reason}" ), None ) ),
+      None => return Err( crate::error::Error::InvalidArgument( "CSV dataset has no header row".to_string(), None ) ),
+    };
+    let column = | name : &str | header.iter().position( | column | column.trim() == name ).ok_or_else( ||
+      crate::error::Error::InvalidArgument( format!( "CSV header has no '{name}' column" ), None )
+    );
+    let input_index = column( input_col )?;
+    let output_index = column( output_col )?;
+
+    let mut examples = Vec::new();
+    let mut warnings = Vec::new();
+    for ( line, record ) in records
+    {
+      let fields = match record
+      {
+        Ok( fields ) => fields,
+        Err( reason ) =>
+        {
+          warnings.push( ImportWarning { line, reason } );
+          continue;
+        },
+      };
+
+      if fields.len() <= input_index.max( output_index )
+      {
+        warnings.push( ImportWarning { line, reason : format!( "expected at least {} fields, found {}", input_index.max( output_index ) + 1, fields.len() ) } );
+        continue;
+      }
+
+      match import_example( Some( fields[ input_index ].as_str() ), Some( fields[ output_index ].as_str() ), input_col, output_col )
+      {
+        Ok( example ) => examples.push( example ),
+        Err( reason ) => warnings.push( ImportWarning { line, reason } ),
+      }
+    }
+
+    Ok( ( Self::from_examples( examples ), warnings ) )
+  }
+
+  /// Dataset holding `examples`.
+  pub fn from_examples( examples : Vec< TuningExample > ) -> Self
+  {
+    Self { examples : Some( TuningExamples { examples } ) }
+  }
+}
+
+/// Build an example from imported fields, or explain why the record is skipped.
+fn import_example( input : Option< &str >, output : Option< &str >, input_name : &str, output_name : &str ) -> Result< TuningExample, String >
+{
+  let input = input.ok_or_else( || format!( "missing '{input_name}' field" ) )?;
+  let output = output.ok_or_else( || format!( "missing '{output_name}' field" ) )?;
+  if input.trim().is_empty()
+  {
+    return Err( format!( "empty '{input_name}' field" ) );
+  }
+  if output.trim().is_empty()
+  {
+    return Err( format!( "empty '{output_name}' field" ) );
+  }
+
+  Ok( TuningExample { text_input : Some( input.to_string() ), output : Some( output.to_string() ) } )
+}
+
+/// Split CSV text into records, each with the 1-based line it starts on.
+///
+/// Blank lines are skipped. A record with an unterminated quoted field, text
+/// after a closing quote, or a quote inside an unquoted field is returned as an error.
+fn parse_csv( text : &str ) -> Vec< ( usize, Result< Vec< String >, String > ) >
+{
+  let mut records = Vec::new();
+  let mut chars = text.chars().peekable();
+  let mut line = 1;
+
+  while chars.peek().is_some()
+  {
+    let start = line;
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut in_quotes = false;
+    let mut error = None;
+
+    while let Some( c ) = chars.next()
+    {
+      if in_quotes
+      {
+        match c
+        {
+          '"' if chars.peek() == Some( &'"' ) =>
+          {
+            chars.next();
+            field.push( '"' );
+          },
+          '"' => in_quotes = false,
+          '\n' =>
+          {
+            line += 1;
+            field.push( c );
+          },
+          _ => field.push( c ),
+        }
+        continue;
+      }
+
+      match c
+      {
+        ',' =>
+        {
+          fields.push( core::mem::take( &mut field ) );
+          quoted = false;
+        },
+        '\n' =>
+        {
+          line += 1;
+          break;
+        },
+        '\r' if chars.peek() == Some( &'\n' ) => {},
+        '"' if field.is_empty() && !quoted =>
+        {
+          quoted = true;
+          in_quotes = true;
+        },
+        _ if quoted =>
+        {
+          error.get_or_insert_with( || "unexpected character after quoted field".to_string() );
+        },
+        '"' =>
+        {
+          error.get_or_insert_with( || "unexpected quote in unquoted field".to_string() );
+        },
+        _ => field.push( c ),
+      }
+    }
+
+    if in_quotes
+    {
+      error = Some( "unterminated quoted field".to_string() );
+    }
+    fields.push( field );
+
+    if fields.len() == 1 && fields[ 0 ].trim().is_empty() && !quoted && error.is_none()
+    {
+      continue; // Blank line
+    }
+
+    records.push( ( start, error.map_or( Ok( fields ), Err ) ) );
+  }
+
+  records
+}
+
 /// Tuning examples container.
 #[ derive( Debug, Clone, Serialize, Deserialize ) ]
 #[ serde( rename_all = "camelCase" ) ]
@@ -0,0 +1,139 @@
+//! Provider-neutral chat messages.
+//!
+//! `UniversalMessage` carries a role and plain text so a conversation can be moved
+//! between providers. The same type, with the same role mapping, exists in the
+//! Claude, `OpenAI` and Ollama crates.
+
+use super::content::{ Content, Part };
+
+/// Provider-neutral message role.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+pub enum UniversalRole
+{
+  /// System instructions.
+  System,
+  /// User turn.
+  User,
+  /// Assistant turn, `model` in Gemini.
+  Assistant,
+}
+
+impl UniversalRole
+{
+  /// Canonical role name ( `system`, `user` or `assistant` ).
+  #[ must_use ]
+  #[ inline ]
+  pub fn as_str( self ) -> &'static str
+  {
+    match self
+    {
+      Self::System => "system",
+      Self::User => "user",
+      Self::Assistant => "assistant",
+    }
+  }
+
+  /// Map a provider role name to a neutral role.
+  ///
+  /// `assistant` and `model` map to `Assistant`, `system` and `developer` to
+  /// `System`; any other name is treated as `User`.
+  #[ must_use ]
+  #[ inline ]
+  pub fn from_provider_role( role : &str ) -> Self
+  {
+    match role
+    {
+      "assistant" | "model" => Self::Assistant,
+      "system" | "developer" => Self::System,
+      _ => Self::User,
+    }
+  }
+
+  /// Gemini role name, `model` for the assistant.
+  #[ must_use ]
+  #[ inline ]
+  pub fn gemini_role( self ) -> &'static str
+  {
+    match self
+    {
+      Self::Assistant => "model",
+      other => other.as_str(),
+    }
+  }
+}
+
+/// Provider-neutral text message.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct UniversalMessage
+{
+  /// Message role.
+  pub role : UniversalRole,
+  /// Message text.
+  pub content : String,
+}
+
+impl UniversalMessage
+{
+  /// Create a message with the given role.
+  #[ must_use ]
+  #[ inline ]
+  pub fn new( role : UniversalRole, content : impl Into< String > ) -> Self
+  {
+    Self { role, content : content.into() }
+  }
+
+  /// Create a system message.
+  #[ must_use ]
+  #[ inline ]
+  pub fn system( content : impl Into< String > ) -> Self
+  {
+    Self::new( UniversalRole::System, content )
+  }
+
+  /// Create a user message.
+  #[ must_use ]
+  #[ inline ]
+  pub fn user( content : impl Into< String > ) -> Self
+  {
+    Self::new( UniversalRole::User, content )
+  }
+
+  /// Create an assistant message.
+  #[ must_use ]
+  #[ inline ]
+  pub fn assistant( content : impl Into< String > ) -> Self
+  {
+    Self::new( UniversalRole::Assistant, content )
+  }
+}
+
+/// Converts to a single text part with the Gemini role.
+///
+/// System messages keep the `system` role; send them as
+/// `GenerateContentRequest::system_instruction` rather than in `contents`.
+impl From< UniversalMessage > for Content
+{
+  #[ inline ]
+  fn from( message : UniversalMessage ) -> Self
+  {
+    Self
+    {
+      parts : vec![ Part { text : Some( message.content ), ..Default::default() } ],
+      role : message.role.gemini_role().to_string(),
+    }
+  }
+}
+
+/// Concatenates text parts; media and function parts are dropped.
+impl From< Content > for UniversalMessage
+{
+  #[ inline ]
+  fn from( content : Content ) -> Self
+  {
+    Self
+    {
+      role : UniversalRole::from_provider_role( &content.role ),
+      content : content.parts.into_iter().filter_map( | part | part.text ).collect(),
+    }
+  }
+}
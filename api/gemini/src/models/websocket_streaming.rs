@@ -408,12 +408,12 @@ mod private
       if !self.is_connected()
       {
         return Err( crate::error::Error::ApiError(
-          "WebSocket is not connected".to_string()
+          "WebSocket is not connected".to_string(), None
         ) );
       }
 
       self.message_tx.send( message )
-        .map_err( |_| crate::error::Error::ApiError( "Failed to send message".to_string() ) )?;
+        .map_err( |_| crate::error::Error::ApiError( "Failed to send message".to_string(), None ) )?;
 
       // Update metrics
       let mut metrics = self.metrics.lock().unwrap();
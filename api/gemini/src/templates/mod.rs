@@ -9,7 +9,15 @@ use crate::models::
   Part,
   GenerationConfig,
   SafetySetting,
+  SystemInstruction,
+  Tool,
+  ToolConfig,
 };
+use crate::error::Error;
+use serde::{ Deserialize, Serialize };
+
+/// Range of candidate counts accepted by [`RequestTemplate::with_candidate_count`].
+const CANDIDATE_COUNT_RANGE : core::ops::RangeInclusive< i32 > = 1..=8;
 
 /// Predefined request templates for common use cases.
 #[ derive( Debug, Clone ) ]
@@ -19,6 +27,35 @@ pub struct RequestTemplate
   request : GenerateContentRequest,
 }
 
+/// Serializable configuration of a [`RequestTemplate`], without conversation contents.
+///
+/// Obtained with [`RequestTemplate::to_spec`] and turned back into a template with
+/// [`RequestTemplate::from_spec`]; storing the JSON is left to the caller.
+#[ derive( Debug, Clone, Default, Serialize, Deserialize ) ]
+#[ serde( rename_all = "camelCase" ) ]
+pub struct TemplateSpec
+{
+  /// Generation parameters such as temperature and token limits.
+  #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+  pub generation_config : Option< GenerationConfig >,
+
+  /// Safety settings applied to requests built from the template.
+  #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+  pub safety_settings : Option< Vec< SafetySetting > >,
+
+  /// System instruction sent with every request.
+  #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+  pub system_instruction : Option< SystemInstruction >,
+
+  /// Tools the model can use.
+  #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+  pub tools : Option< Vec< Tool > >,
+
+  /// Configuration for tool usage.
+  #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+  pub tool_config : Option< ToolConfig >,
+}
+
 impl RequestTemplate
 {
   /// Create a simple chat template with minimal configuration.
@@ -178,6 +215,31 @@ impl RequestTemplate
     self
   }
 
+  /// Set the number of response candidates to generate (1-8).
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidArgument` if `candidate_count` is outside 1..=8.
+  pub fn with_candidate_count( mut self, candidate_count : i32 ) -> Result< Self, Error >
+  {
+    if !CANDIDATE_COUNT_RANGE.contains( &candidate_count )
+    {
+      return Err( Error::InvalidArgument(
+        format!( "Candidate count must be between 1 and 8, got {candidate_count}" ), None
+      ) );
+    }
+    self.generation_config_mut().candidate_count = Some( candidate_count );
+    Ok( self )
+  }
+
+  /// Set the MIME type of the generated text, e.g. `application/json`.
+  #[ must_use ]
+  pub fn with_response_mime_type( mut self, mime_type : &str ) -> Self
+  {
+    self.generation_config_mut().response_mime_type = Some( mime_type.to_string() );
+    self
+  }
+
   /// Add safety settings to the template.
   #[ must_use ]
   pub fn with_safety_settings( mut self, settings : Vec< SafetySetting > ) -> Self
@@ -186,12 +248,51 @@ impl RequestTemplate
     self
   }
 
+  /// The template's configuration, leaving out the prompt contents and cached content.
+  #[ must_use ]
+  pub fn to_spec( &self ) -> TemplateSpec
+  {
+    TemplateSpec
+    {
+      generation_config : self.request.generation_config.clone(),
+      safety_settings : self.request.safety_settings.clone(),
+      system_instruction : self.request.system_instruction.clone(),
+      tools : self.request.tools.clone(),
+      tool_config : self.request.tool_config.clone(),
+    }
+  }
+
+  /// Recreate a template from a spec; set the prompt with [`Self::with_prompt`].
+  #[ must_use ]
+  pub fn from_spec( spec : TemplateSpec ) -> Self
+  {
+    Self
+    {
+      request : GenerateContentRequest
+      {
+        contents : vec![],
+        generation_config : spec.generation_config,
+        safety_settings : spec.safety_settings,
+        system_instruction : spec.system_instruction,
+        tools : spec.tools,
+        tool_config : spec.tool_config,
+        ..Default::default()
+      },
+    }
+  }
+
   /// Build the final `GenerateContentRequest`.
   #[ must_use ]
   pub fn build( self ) -> GenerateContentRequest
   {
     self.request
   }
+
+  /// The generation config, created empty if the template has none.
+  fn generation_config_mut( &mut self ) -> &mut GenerationConfig
+  {
+    self.request.generation_config.get_or_insert_with( GenerationConfig::default )
+  }
 }
 
 #[ cfg( test ) ]
@@ -261,4 +362,80 @@ mod tests
     let config = template.generation_config.unwrap();
     assert_eq!( config.max_output_tokens, Some( 1000 ) );
   }
+
+  #[ test ]
+  fn test_with_candidate_count()
+  {
+    let template = RequestTemplate::factual_qa().with_candidate_count( 3 ).unwrap().build();
+    let config = template.generation_config.unwrap();
+    assert_eq!( config.candidate_count, Some( 3 ) );
+    assert_eq!( config.temperature, Some( 0.1 ) );
+
+    assert!( RequestTemplate::chat().with_candidate_count( 1 ).is_ok() );
+    assert!( RequestTemplate::chat().with_candidate_count( 8 ).is_ok() );
+  }
+
+  #[ test ]
+  fn test_with_candidate_count_out_of_range()
+  {
+    for count in [ 0, 9, -1 ]
+    {
+      let error = RequestTemplate::chat().with_candidate_count( count ).unwrap_err();
+      assert!( matches!( error, Error::InvalidArgument( .. ) ), "{error:?}" );
+    }
+  }
+
+  #[ test ]
+  fn test_with_response_mime_type()
+  {
+    let template = RequestTemplate::code_generation().with_response_mime_type( "application/json" ).build();
+    let config = template.generation_config.unwrap();
+    assert_eq!( config.response_mime_type.as_deref(), Some( "application/json" ) );
+    assert_eq!( config.max_output_tokens, Some( 4096 ) );
+
+    let json = serde_json::to_value( RequestTemplate::chat().with_response_mime_type( "application/json" ).build() ).unwrap();
+    assert_eq!( json[ "generationConfig" ][ "responseMimeType" ], "application/json" );
+  }
+
+  #[ test ]
+  fn test_spec_round_trip()
+  {
+    let template = RequestTemplate::creative_writing()
+    .with_safety_settings( vec![ SafetySetting
+    {
+      category : "HARM_CATEGORY_HARASSMENT".to_string(),
+      threshold : "BLOCK_ONLY_HIGH".to_string(),
+    } ] )
+    .with_prompt( "Write a haiku about rust" );
+
+    let spec = template.to_spec();
+    let json = serde_json::to_string( &spec ).unwrap();
+    assert!( !json.contains( "haiku" ), "{json}" );
+
+    let restored = RequestTemplate::from_spec( serde_json::from_str( &json ).unwrap() )
+    .with_prompt( "Write a limerick" )
+    .build();
+    assert_eq!( restored.contents[ 0 ].parts[ 0 ].text.as_deref(), Some( "Write a limerick" ) );
+
+    let config = restored.generation_config.as_ref().unwrap();
+    assert_eq!( config.temperature, Some( 1.2 ) );
+    assert_eq!( config.max_output_tokens, Some( 8192 ) );
+    assert_eq!( config.top_p, Some( 0.95 ) );
+    assert_eq!( config.top_k, Some( 40 ) );
+    assert_eq!( restored.safety_settings.as_ref().unwrap()[ 0 ].threshold, "BLOCK_ONLY_HIGH" );
+    assert_eq!( serde_json::to_value( RequestTemplate::from_spec( spec ).to_spec() ).unwrap(), serde_json::from_str::< serde_json::Value >( &json ).unwrap() );
+  }
+
+  #[ test ]
+  fn test_spec_json_shape()
+  {
+    let json = serde_json::to_value( RequestTemplate::factual_qa().to_spec() ).unwrap();
+    assert_eq!( json, serde_json::json!(
+    {
+      "generationConfig" : { "temperature" : 0.1_f32, "topP" : 0.8_f32, "maxOutputTokens" : 1024 }
+    } ) );
+
+    let empty : TemplateSpec = serde_json::from_str( "{}" ).unwrap();
+    assert!( RequestTemplate::from_spec( empty ).build().generation_config.is_none() );
+  }
 }
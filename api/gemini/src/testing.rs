@@ -0,0 +1,355 @@
+//! Deterministic offline testing support
+//!
+//! [`MockTransport`] answers the client's requests from canned responses instead
+//! of the network, so code built on the client can be tested without API keys.
+//! [`CassetteTransport`] records real interactions once and replays them from a
+//! JSON cassette file afterwards. Both plug into the client through the
+//! [`Interceptor`] hook: requests are answered before they would be sent, and the
+//! response then goes through the same status classification and deserialization
+//! as a real one.
+//!
+//! The module is behind the opt-in `testing` feature, which `full` does not
+//! include; enable it from `[dev-dependencies]`.
+//!
+//! Streaming requests are answered too, but a canned body is delivered in one
+//! piece: chunk boundaries, delays between chunks and errors in the middle of a
+//! stream cannot be simulated. WebSocket sessions do not go through the
+//! interceptor and are not mocked.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use api_gemini::{ client::Client, testing::MockTransport, ResponseParts };
+//! use reqwest::Method;
+//!
+//! # async fn example() -> Result< (), api_gemini::error::Error > {
+//! let mock = MockTransport::new()
+//!   .on( Method::GET, "/v1beta/models", ResponseParts::new( 200, r#"{ "models" : [] }"# ) );
+//! let client = Client::with_mock_transport( mock.clone() )?;
+//!
+//! let response = client.models().list().await?;
+//! assert!( response.models.is_empty() );
+//! assert_eq!( mock.calls().len(), 1 );
+//! # Ok( () )
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::{ Arc, Mutex, PoisonError };
+use reqwest::Method;
+use serde::{ Deserialize, Serialize };
+
+use crate::error::Error;
+use crate::internal::http::{ Interceptor, RequestParts, ResponseParts };
+
+/// A canned response sequence for one method and path
+#[ derive( Debug ) ]
+struct MockRoute
+{
+  method : Method,
+  path : String,
+  responses : VecDeque< ResponseParts >,
+}
+
+/// Answers client requests from canned request → response mappings
+///
+/// Routes match on the HTTP method and the exact URL path ( e.g.
+/// `/v1beta/models/gemini-2.5-flash:generateContent` ), ignoring host and query.
+/// Registering several responses for the same route returns them in order, the
+/// last one repeating. Requests without a route get a `404` API error naming the
+/// request. Clones share routes and recorded calls.
+#[ derive( Debug, Clone, Default ) ]
+pub struct MockTransport
+{
+  routes : Arc< Mutex< Vec< MockRoute > > >,
+  calls : Arc< Mutex< Vec< RequestParts > > >,
+}
+
+impl MockTransport
+{
+  /// Create a transport with no routes
+  #[ inline ]
+  #[ must_use ]
+  pub fn new() -> Self
+  {
+    Self::default()
+  }
+
+  /// Answer `method` requests to `path` with `response`
+  #[ inline ]
+  #[ must_use ]
+  pub fn on( self, method : Method, path : impl Into< String >, response : ResponseParts ) -> Self
+  {
+    let path = path.into();
+    {
+      let mut routes = self.routes.lock().unwrap_or_else( PoisonError::into_inner );
+      match routes.iter_mut().find( | route | route.method == method && route.path == path )
+      {
+        Some( route ) => route.responses.push_back( response ),
+        None => routes.push( MockRoute { method, path, responses : VecDeque::from( [ response ] ) } ),
+      }
+    }
+    self
+  }
+
+  /// Answer `POST` requests to `model`'s `generateContent` endpoint with `response`
+  #[ inline ]
+  #[ must_use ]
+  pub fn on_generate_content( self, model : &str, response : ResponseParts ) -> Self
+  {
+    self.on( Method::POST, format!( "/v1beta/models/{model}:generateContent" ), response )
+  }
+
+  /// Requests received so far, in order
+  #[ inline ]
+  #[ must_use ]
+  pub fn calls( &self ) -> Vec< RequestParts >
+  {
+    self.calls.lock().unwrap_or_else( PoisonError::into_inner ).clone()
+  }
+
+  /// Next response for `method` and `path`, or a `404` if no route matches
+  fn respond( &self, method : &Method, path : &str ) -> ResponseParts
+  {
+    let mut routes = self.routes.lock().unwrap_or_else( PoisonError::into_inner );
+    let Some( route ) = routes.iter_mut().find( | route | route.method == *method && route.path == path ) else
+    {
+      let body = serde_json::json!(
+      {
+        "error" :
+        {
+          "code" : 404,
+          "message" : format!( "MockTransport has no response for {method} {path}" ),
+          "status" : "NOT_FOUND",
+        }
+      } );
+      return ResponseParts::new( 404, body.to_string() );
+    };
+
+    if route.responses.len() > 1
+    {
+      route.responses.pop_front().unwrap_or_else( || ResponseParts::new( 500, "" ) )
+    }
+    else
+    {
+      route.responses.front().cloned().unwrap_or_else( || ResponseParts::new( 500, "" ) )
+    }
+  }
+}
+
+impl Interceptor for MockTransport
+{
+  #[ inline ]
+  fn before_request( &self, request : &mut RequestParts )
+  {
+    let path = reqwest::Url::parse( &request.url )
+      .map_or_else( | _ | request.url.clone(), | url | url.path().to_string() );
+    let response = self.respond( &request.method, &path );
+
+    self.calls.lock().unwrap_or_else( PoisonError::into_inner ).push( request.clone() );
+    request.respond_with( response );
+  }
+
+  #[ inline ]
+  fn after_response( &self, _response : &ResponseParts )
+  {
+  }
+}
+
+/// Whether a [`CassetteTransport`] records or replays interactions
+#[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+pub enum CassetteMode
+{
+  /// Send requests to the server and keep each interaction in memory
+  Record,
+  /// Answer requests from previously recorded interactions without the network
+  Replay,
+}
+
+/// One recorded request and the response it received
+#[ derive( Debug, Clone, PartialEq, Eq, Serialize, Deserialize ) ]
+pub struct Interaction
+{
+  /// HTTP method
+  pub method : String,
+  /// Request URL without the API key
+  pub url : String,
+  /// Hash of the request body ( see [`body_hash`] )
+  pub body_hash : String,
+  /// Response received for the request
+  pub response : ResponseParts,
+}
+
+/// Stable FNV-1a hash of a request body, as stored in cassettes
+///
+/// Requests without a body hash the same as an empty body.
+#[ inline ]
+#[ must_use ]
+pub fn body_hash( body : Option< &[ u8 ] > ) -> String
+{
+  let hash = body.unwrap_or_default().iter().fold( 0xcbf2_9ce4_8422_2325_u64, | hash, byte |
+  {
+    ( hash ^ u64::from( *byte ) ).wrapping_mul( 0x0000_0100_0000_01b3 )
+  } );
+  format!( "{hash:016x}" )
+}
+
+/// Records HTTP interactions to a cassette and replays them
+///
+/// The mode is chosen explicitly: [`CassetteTransport::record`] lets requests
+/// through and keeps each interaction in memory until [`CassetteTransport::save`]
+/// writes them out; nothing touches the disk on its own. [`CassetteTransport::replay`]
+/// loads a cassette and answers requests matching on method, URL and body hash,
+/// returning identical requests' responses in recorded order ( the last one
+/// repeating ). Unmatched requests get a `404` API error naming the request.
+/// The API key is never recorded. Clones share recorded interactions.
+#[ derive( Debug, Clone ) ]
+pub struct CassetteTransport
+{
+  mode : CassetteMode,
+  interactions : Arc< Mutex< Vec< Interaction > > >,
+  replayed : Arc< Mutex< Vec< bool > > >,
+}
+
+impl CassetteTransport
+{
+  /// Record interactions with the server in memory
+  #[ inline ]
+  #[ must_use ]
+  pub fn record() -> Self
+  {
+    Self::with_interactions( CassetteMode::Record, Vec::new() )
+  }
+
+  /// Replay the interactions stored in the cassette at `path`
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::ConfigurationError` if the file cannot be read or is not a cassette.
+  #[ inline ]
+  pub fn replay< P : AsRef< Path > >( path : P ) -> Result< Self, Error >
+  {
+    let content = std::fs::read_to_string( path.as_ref() )
+      .map_err( |e| Error::ConfigurationError( format!( "Failed to read cassette '{}': {e}", path.as_ref().display() ) ) )?;
+    let interactions = serde_json::from_str( &content )
+      .map_err( |e| Error::ConfigurationError( format!( "Failed to parse cassette '{}': {e}", path.as_ref().display() ) ) )?;
+    Ok( Self::replay_interactions( interactions ) )
+  }
+
+  /// Replay the given interactions
+  #[ inline ]
+  #[ must_use ]
+  pub fn replay_interactions( interactions : Vec< Interaction > ) -> Self
+  {
+    Self::with_interactions( CassetteMode::Replay, interactions )
+  }
+
+  fn with_interactions( mode : CassetteMode, interactions : Vec< Interaction > ) -> Self
+  {
+    let replayed = vec![ false; interactions.len() ];
+    Self
+    {
+      mode,
+      interactions : Arc::new( Mutex::new( interactions ) ),
+      replayed : Arc::new( Mutex::new( replayed ) ),
+    }
+  }
+
+  /// Whether this transport records or replays
+  #[ inline ]
+  #[ must_use ]
+  pub fn mode( &self ) -> CassetteMode
+  {
+    self.mode
+  }
+
+  /// Interactions recorded or loaded so far, in order
+  #[ inline ]
+  #[ must_use ]
+  pub fn interactions( &self ) -> Vec< Interaction >
+  {
+    self.interactions.lock().unwrap_or_else( PoisonError::into_inner ).clone()
+  }
+
+  /// Write the interactions to a cassette file at `path`
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::SerializationError` if the interactions cannot be encoded,
+  /// or `Error::ConfigurationError` if the file cannot be written.
+  #[ inline ]
+  pub fn save< P : AsRef< Path > >( &self, path : P ) -> Result< (), Error >
+  {
+    let content = serde_json::to_string_pretty( &self.interactions() )
+      .map_err( |e| Error::SerializationError( format!( "Failed to encode cassette : {e}" ) ) )?;
+    std::fs::write( path.as_ref(), content )
+      .map_err( |e| Error::ConfigurationError( format!( "Failed to write cassette '{}': {e}", path.as_ref().display() ) ) )
+  }
+
+  /// Recorded response for a request, or a `404` if none matches
+  fn respond( &self, request : &RequestParts ) -> ResponseParts
+  {
+    let method = request.method.to_string();
+    let hash = body_hash( request.body.as_deref() );
+    let interactions = self.interactions.lock().unwrap_or_else( PoisonError::into_inner );
+    let mut replayed = self.replayed.lock().unwrap_or_else( PoisonError::into_inner );
+
+    let matching : Vec< usize > = interactions.iter()
+      .enumerate()
+      .filter( | ( _, interaction ) | interaction.method == method && interaction.url == request.url && interaction.body_hash == hash )
+      .map( | ( index, _ ) | index )
+      .collect();
+
+    let Some( &last ) = matching.last() else
+    {
+      let body = serde_json::json!(
+      {
+        "error" :
+        {
+          "code" : 404,
+          "message" : format!( "CassetteTransport has no recorded interaction for {method} {}", request.url ),
+          "status" : "NOT_FOUND",
+        }
+      } );
+      return ResponseParts::new( 404, body.to_string() );
+    };
+
+    let index = matching.into_iter().find( | index | !replayed[ *index ] ).unwrap_or( last );
+    replayed[ index ] = true;
+    interactions[ index ].response.clone()
+  }
+}
+
+impl Interceptor for CassetteTransport
+{
+  #[ inline ]
+  fn before_request( &self, request : &mut RequestParts )
+  {
+    if self.mode == CassetteMode::Replay
+    {
+      let response = self.respond( request );
+      request.respond_with( response );
+    }
+  }
+
+  #[ inline ]
+  fn after_response( &self, _response : &ResponseParts )
+  {
+  }
+
+  #[ inline ]
+  fn after_exchange( &self, request : &RequestParts, response : &ResponseParts )
+  {
+    if self.mode == CassetteMode::Record
+    {
+      self.interactions.lock().unwrap_or_else( PoisonError::into_inner ).push( Interaction
+      {
+        method : request.method.to_string(),
+        url : request.url.clone(),
+        body_hash : body_hash( request.body.as_deref() ),
+        response : response.clone(),
+      } );
+    }
+  }
+}
@@ -2,6 +2,41 @@
 
 use super::*;
 
+/// Validate generation configuration.
+///
+/// # Arguments
+///
+/// * `config` - The generation configuration to validate
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the configuration is valid, or a validation error.
+pub fn validate_generation_config( config : &GenerationConfig ) -> Result< (), ValidationError >
+{
+  if let Some( stop_sequences ) = &config.stop_sequences
+  {
+    if stop_sequences.len() > MAX_STOP_SEQUENCES
+    {
+      return Err( ValidationError::CollectionTooLarge {
+        field : "stop_sequences".to_string(),
+        size : stop_sequences.len(),
+        max : MAX_STOP_SEQUENCES,
+      } );
+    }
+
+    // Whitespace such as "\n" is a meaningful stop sequence, so only empty strings are rejected
+    if let Some( i ) = stop_sequences.iter().position( String::is_empty )
+    {
+      return Err( ValidationError::RequiredFieldMissing {
+        field : format!( "stop_sequences[{}]", i ),
+        context : "GenerationConfig".to_string(),
+      } );
+    }
+  }
+
+  Ok( () )
+}
+
 /// Validate enhanced function calling configuration.
 ///
 /// # Arguments
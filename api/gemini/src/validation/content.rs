@@ -33,6 +33,17 @@ pub fn validate_enhanced_generate_content_request( request : &GenerateContentReq
       } )?;
   }
 
+  // Validate generation config if provided
+  if let Some( generation_config ) = &request.generation_config
+  {
+    validate_generation_config( generation_config )
+      .map_err( |e| ValidationError::InvalidFieldValue {
+        field : "generation_config".to_string(),
+        value : "GenerationConfig".to_string(),
+        reason : e.to_string(),
+      } )?;
+  }
+
   // Validate tool config if provided
   if let Some( tool_config ) = &request.tool_config
   {
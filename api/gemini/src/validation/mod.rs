@@ -95,6 +95,26 @@ impl core::error::Error for ValidationError
 {
 }
 
+impl From< ValidationError > for crate::error::Error
+{
+  #[ inline ]
+  fn from( error : ValidationError ) -> Self
+  {
+    crate::error::Error::ValidationError { message : error.to_string() }
+  }
+}
+
+/// Joins the messages of all errors with `; `, e.g. those from [`validate_tuning_dataset`].
+impl From< Vec< ValidationError > > for crate::error::Error
+{
+  #[ inline ]
+  fn from( errors : Vec< ValidationError > ) -> Self
+  {
+    let message = errors.iter().map( ToString::to_string ).collect::< Vec< _ > >().join( "; " );
+    crate::error::Error::ValidationError { message }
+  }
+}
+
 /// Maximum number of models that can be compared in a single request.
 const MAX_MODELS_TO_COMPARE: usize = 10;
 
@@ -110,9 +130,15 @@ const MAX_ALLOWED_FUNCTION_NAMES: usize = 100;
 /// Maximum number of tuning examples in a training dataset.
 const MAX_TUNING_EXAMPLES: usize = 10000;
 
+/// Maximum number of stop sequences in a generation config.
+const MAX_STOP_SEQUENCES: usize = 5;
+
 /// Maximum timeout for code execution in seconds.
 const MAX_CODE_EXECUTION_TIMEOUT: i32 = 300;
 
+/// Maximum decoded size of a single inline data part in bytes.
+const MAX_INLINE_DATA_BYTES: usize = 20 * 1024 * 1024;
+
 /// Validate a model name.
 ///
 /// # Arguments
@@ -207,9 +233,61 @@ fn validate_part( part : &Part ) -> Result< (), ValidationError >
     } );
   }
 
+  if let Some( blob ) = &part.inline_data
+  {
+    let size = blob.validate()
+      .map_err( |e| ValidationError::InvalidFieldValue {
+        field : "inline_data.data".to_string(),
+        value : blob.mime_type.clone(),
+        reason : e.to_string(),
+      } )?;
+
+    if size > MAX_INLINE_DATA_BYTES
+    {
+      return Err( ValidationError::ValueOutOfRange {
+        field : "inline_data.data".to_string(),
+        value : size as f64,
+        min : None,
+        max : Some( MAX_INLINE_DATA_BYTES as f64 ),
+      } );
+    }
+  }
+
   Ok( () )
 }
 
+/// Validate every item of a batch, collecting all failures instead of stopping at the first.
+///
+/// # Arguments
+///
+/// * `items` - The items to validate
+/// * `validator` - Validation applied to each item, e.g. [`validate_enhanced_generate_content_request`]
+///
+/// # Returns
+///
+/// Returns `Ok(())` if every item is valid, or every failure paired with the index of its item, in item order.
+pub fn validate_batch< T >
+(
+  items : &[ T ],
+  validator : impl Fn( &T ) -> Result< (), ValidationError >,
+) -> Result< (), Vec< ( usize, ValidationError ) > >
+{
+  let errors : Vec< ( usize, ValidationError ) > = items
+    .iter()
+    .enumerate()
+    .filter_map( | ( index, item ) | validator( item ).err().map( | error | ( index, error ) ) )
+    .collect();
+
+  if errors.is_empty()
+  {
+    Ok( () )
+  }
+  else
+  {
+    Err( errors )
+  }
+}
+
 // Module declarations
 mod tokens;
 mod config;
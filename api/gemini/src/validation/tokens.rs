@@ -64,12 +64,23 @@ pub fn validate_batch_count_tokens_request( request : &BatchCountTokensRequest )
 /// # Errors
 ///
 /// Returns `ValidationError` if the request is invalid, such as:
+/// - Both `contents` and `generate_content_request` set
 /// - Empty contents collection
 /// - Individual content validation failures
 #[ inline ]
 pub fn validate_count_tokens_request( request : &CountTokensRequest ) -> Result< (), ValidationError >
 {
-  if request.contents.is_empty()
+  if !request.contents.is_empty() && request.generate_content_request.is_some()
+  {
+    return Err( ValidationError::InvalidFieldValue {
+      field : "contents".to_string(),
+      value : format!( "{} items", request.contents.len() ),
+      reason : "cannot be combined with generate_content_request".to_string(),
+    } );
+  }
+
+  let contents = request.generate_content_request.as_ref().map_or( &request.contents, | generate_request | &generate_request.contents );
+  if contents.is_empty()
   {
     return Err( ValidationError::EmptyCollection {
       field : "contents".to_string(),
@@ -78,7 +89,7 @@ pub fn validate_count_tokens_request( request : &CountTokensRequest ) -> Result<
   }
 
   // Validate each content item
-  for ( i, content ) in request.contents.iter().enumerate()
+  for ( i, content ) in contents.iter().enumerate()
   {
     validate_content( content )
       .map_err( |e| ValidationError::InvalidFieldValue {
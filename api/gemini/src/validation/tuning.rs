@@ -1,6 +1,56 @@
 //! Model tuning validation functions
 
 use super::*;
+use std::collections::HashMap;
+
+/// Statistics for a tuning dataset that passed validation.
+#[ derive( Debug, Clone, PartialEq ) ]
+pub struct DatasetStats
+{
+  /// Number of examples
+  pub example_count : usize,
+  /// Total characters across all inputs
+  pub total_input_chars : usize,
+  /// Total characters across all outputs
+  pub total_output_chars : usize,
+  /// Longest input in characters
+  pub max_input_chars : usize,
+  /// Longest output in characters
+  pub max_output_chars : usize,
+  /// Inputs that occur more than once, in order of first occurrence
+  pub duplicate_inputs : Vec< DuplicateInput >,
+}
+
+impl DatasetStats
+{
+  /// Average input length in characters.
+  pub fn average_input_chars( &self ) -> f64
+  {
+    if self.example_count == 0 { 0.0 } else { self.total_input_chars as f64 / self.example_count as f64 }
+  }
+
+  /// Average output length in characters.
+  pub fn average_output_chars( &self ) -> f64
+  {
+    if self.example_count == 0 { 0.0 } else { self.total_output_chars as f64 / self.example_count as f64 }
+  }
+
+  /// Whether the dataset passed with warnings (currently only duplicate inputs).
+  pub fn has_warnings( &self ) -> bool
+  {
+    !self.duplicate_inputs.is_empty()
+  }
+}
+
+/// Warning for an input text shared by several tuning examples.
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct DuplicateInput
+{
+  /// The duplicated input, trimmed
+  pub text_input : String,
+  /// Indices of the examples sharing this input
+  pub indices : Vec< usize >,
+}
 
 /// Validate create tuned model request.
 ///
@@ -249,6 +299,115 @@ pub fn validate_tuning_example( example : &TuningExample ) -> Result< (), Valida
   Ok( () )
 }
 
+/// Validate a tuning dataset before submission and collect its statistics.
+///
+/// Unlike `validate_dataset`, which stops at the first problem and accepts
+/// examples with only one side filled in, this checks the whole dataset for
+/// supervised tuning : every example needs non-empty `text_input` and
+/// `output`, and the example count must be within `1..=10000`. Duplicate
+/// inputs are not errors; they are reported in `DatasetStats::duplicate_inputs`.
+///
+/// # Arguments
+///
+/// * `dataset` - The dataset to validate
+///
+/// # Returns
+///
+/// Returns the dataset statistics if the dataset is valid, or every validation error found.
+pub fn validate_tuning_dataset( dataset : &Dataset ) -> Result< DatasetStats, Vec< ValidationError > >
+{
+  let examples = dataset.examples.as_ref().map( | tuning_examples | tuning_examples.examples.as_slice() ).unwrap_or_default();
+  let mut errors = Vec::new();
+
+  if examples.is_empty()
+  {
+    errors.push( ValidationError::EmptyCollection {
+      field : "examples".to_string(),
+      context : "Dataset".to_string(),
+    } );
+  }
+
+  if examples.len() > MAX_TUNING_EXAMPLES
+  {
+    errors.push( ValidationError::CollectionTooLarge {
+      field : "examples".to_string(),
+      size : examples.len(),
+      max : MAX_TUNING_EXAMPLES,
+    } );
+  }
+
+  let mut stats = DatasetStats {
+    example_count : examples.len(),
+    total_input_chars : 0,
+    total_output_chars : 0,
+    max_input_chars : 0,
+    max_output_chars : 0,
+    duplicate_inputs : Vec::new(),
+  };
+  let mut first_seen : HashMap< &str, usize > = HashMap::new();
+
+  for ( i, example ) in examples.iter().enumerate()
+  {
+    let input = example.text_input.as_deref().map( str::trim ).unwrap_or_default();
+    let output = example.output.as_deref().map( str::trim ).unwrap_or_default();
+
+    if input.is_empty()
+    {
+      errors.push( ValidationError::RequiredFieldMissing {
+        field : format!( "examples[{}].text_input", i ),
+        context : "TuningExample".to_string(),
+      } );
+    }
+
+    if output.is_empty()
+    {
+      errors.push( ValidationError::RequiredFieldMissing {
+        field : format!( "examples[{}].output", i ),
+        context : "TuningExample".to_string(),
+      } );
+    }
+
+    let input_chars = input.chars().count();
+    let output_chars = output.chars().count();
+    stats.total_input_chars += input_chars;
+    stats.total_output_chars += output_chars;
+    stats.max_input_chars = stats.max_input_chars.max( input_chars );
+    stats.max_output_chars = stats.max_output_chars.max( output_chars );
+
+    if input.is_empty()
+    {
+      continue;
+    }
+
+    match first_seen.get( input )
+    {
+      Some( &first ) =>
+      {
+        match stats.duplicate_inputs.iter_mut().find( | duplicate | duplicate.indices[ 0 ] == first )
+        {
+          Some( duplicate ) => duplicate.indices.push( i ),
+          None => stats.duplicate_inputs.push( DuplicateInput {
+            text_input : input.to_string(),
+            indices : vec![ first, i ],
+          } ),
+        }
+      },
+      None =>
+      {
+        first_seen.insert( input, i );
+      },
+    }
+  }
+
+  if errors.is_empty()
+  {
+    stats.duplicate_inputs.sort_by_key( | duplicate | duplicate.indices[ 0 ] );
+    Ok( stats )
+  } else {
+    Err( errors )
+  }
+}
+
 /// Validate hyperparameters.
 ///
 /// # Arguments
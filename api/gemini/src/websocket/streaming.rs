@@ -88,7 +88,7 @@ impl WebSocketStreamBuilder
   /// Build and start the WebSocket stream
   pub async fn build( self, manager : &WebSocketConnectionManager ) -> Result< String, Error >
   {
-    let endpoint = self.endpoint.ok_or_else( || Error::InvalidArgument( "Endpoint is required".to_string() ) )?;
+    let endpoint = self.endpoint.ok_or_else( || Error::InvalidArgument( "Endpoint is required".to_string(), None ) )?;
 
     let session_id = manager.create_session( &endpoint, self.config ).await?;
 
@@ -507,7 +507,7 @@ impl< 'a > EnhancedWebSocketStreamBuilder< 'a >
   /// Build and create the connection
   pub async fn build( self ) -> Result< EnhancedConnectionResult< 'a >, Error >
   {
-    let endpoint = self.endpoint.ok_or_else( || Error::InvalidArgument( "Endpoint is required".to_string() ) )?;
+    let endpoint = self.endpoint.ok_or_else( || Error::InvalidArgument( "Endpoint is required".to_string(), None ) )?;
 
     if self.use_optimization
     {
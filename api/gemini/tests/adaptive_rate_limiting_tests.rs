@@ -0,0 +1,181 @@
+//! Adaptive rate limiting tests
+//!
+//! Feeds `RateLimit` a sequence of server outcomes and checks the effective rate
+//! reported in its metrics, then drives 429 fixtures through a `Client`.
+//!
+//! # Test Coverage
+//!
+//! - 429s halve the effective rate, down to a floor
+//! - Successes raise it additively back to the configured ceiling
+//! - Non-adaptive algorithms ignore server feedback
+//! - A client keeps one limiter across calls and clones, so 429s slow later calls
+
+#![ cfg( feature = "rate_limiting" ) ]
+
+use api_gemini::client::Client;
+use api_gemini::error::Error;
+use api_gemini::internal::http::{ RateLimit, RateLimitingConfig, ADAPTIVE_INCREASE_STEP, ADAPTIVE_MIN_FRACTION };
+use api_gemini::{ Interceptor, RequestParts, ResponseParts };
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+
+const CEILING : f64 = 10.0;
+
+fn limiter( algorithm : &str ) -> RateLimit
+{
+  RateLimit::new( RateLimitingConfig
+  {
+    requests_per_second : CEILING,
+    bucket_size : 5,
+    algorithm : algorithm.to_string(),
+    enable_metrics : true,
+  } )
+}
+
+fn effective_rate( limiter : &RateLimit ) -> f64
+{
+  limiter.get_metrics().effective_rate
+}
+
+fn assert_rate( limiter : &RateLimit, expected : f64 )
+{
+  let actual = effective_rate( limiter );
+  assert!( ( actual - expected ).abs() < 1e-9, "expected rate {expected}, got {actual}" );
+}
+
+#[ test ]
+fn rate_decreases_then_recovers()
+{
+  let limiter = limiter( "adaptive" );
+  assert_rate( &limiter, CEILING );
+
+  limiter.record_rate_limited();
+  assert_rate( &limiter, 5.0 );
+  limiter.record_outcome::< () >( &Err( Error::RateLimitError( "429 Too Many Requests".to_string(), None ) ) );
+  assert_rate( &limiter, 2.5 );
+
+  // Other errors carry no rate information
+  limiter.record_outcome::< () >( &Err( Error::ServerError( "503".to_string() ) ) );
+  assert_rate( &limiter, 2.5 );
+
+  for _ in 0..5
+  {
+    limiter.record_outcome( &Ok( () ) );
+  }
+  assert_rate( &limiter, 5.0 );
+
+  for _ in 0..100
+  {
+    limiter.record_success();
+  }
+  assert_rate( &limiter, CEILING );
+  assert_eq!( limiter.get_metrics().server_rate_limits, 2 );
+}
+
+#[ test ]
+fn rate_never_drops_below_floor()
+{
+  let limiter = limiter( "adaptive" );
+
+  for _ in 0..20
+  {
+    limiter.record_rate_limited();
+  }
+
+  assert_rate( &limiter, CEILING * ADAPTIVE_MIN_FRACTION );
+}
+
+#[ tokio::test ]
+async fn reduced_rate_limits_admission()
+{
+  let limiter = limiter( "adaptive" );
+
+  // Drain the burst, then back off hard; refill is now far slower than the ceiling
+  for _ in 0..5
+  {
+    assert!( limiter.should_allow_request().await );
+  }
+  for _ in 0..20
+  {
+    limiter.record_rate_limited();
+  }
+
+  std::thread::sleep( core::time::Duration::from_millis( 150 ) );
+  assert!( !limiter.should_allow_request().await, "0.5 req/s must not refill a token in 150ms" );
+
+  limiter.reset();
+  assert_rate( &limiter, CEILING );
+}
+
+#[ test ]
+fn token_bucket_ignores_feedback()
+{
+  let limiter = limiter( "token_bucket" );
+
+  limiter.record_rate_limited();
+  limiter.record_success();
+
+  assert_rate( &limiter, CEILING );
+  assert_eq!( limiter.get_metrics().server_rate_limits, 1 );
+}
+
+/// Answers the first `rejections` requests with 429 and the rest with an empty model list
+#[ derive( Debug, Clone, Default ) ]
+struct Throttling
+{
+  rejections : Arc< AtomicUsize >,
+}
+
+impl Interceptor for Throttling
+{
+  fn before_request( &self, request : &mut RequestParts )
+  {
+    let rejected = self.rejections.fetch_update( Ordering::SeqCst, Ordering::SeqCst, | left | left.checked_sub( 1 ) ).is_ok();
+    let response = if rejected
+    {
+      ResponseParts::new( 429, r#"{ "error" : { "code" : 429, "message" : "quota exceeded", "status" : "RESOURCE_EXHAUSTED" } }"# )
+    } else {
+      ResponseParts::new( 200, r#"{ "models" : [] }"# )
+    };
+    request.respond_with( response );
+  }
+}
+
+#[ tokio::test ]
+async fn client_keeps_limiter_across_calls()
+{
+  let throttling = Throttling { rejections : Arc::new( AtomicUsize::new( 2 ) ) };
+  let builder = Client::builder()
+  .api_key( "test-key".to_string() )
+  .base_url( "http://127.0.0.1:9".to_string() )
+  .enable_rate_limiting( true )
+  .rate_limit_algorithm( "adaptive" )
+  .rate_limit_requests_per_second( CEILING )
+  .rate_limit_bucket_size( 5 )
+  .with_interceptor( throttling.clone() );
+  #[ cfg( feature = "retry" ) ]
+  let builder = builder.max_retries( 0 );
+  let client = builder.build().expect( "client should build" );
+  let clone = client.clone();
+
+  let error = client.models().list().await.unwrap_err();
+  assert!( matches!( error, Error::RateLimitError( .. ) ), "{error:?}" );
+  let error = clone.models().list().await.unwrap_err();
+  assert!( matches!( error, Error::RateLimitError( .. ) ), "{error:?}" );
+
+  let metrics = client.rate_limiter_metrics().expect( "rate limiting is enabled" );
+  assert_eq!( metrics.server_rate_limits, 2 );
+  assert!( ( metrics.effective_rate - CEILING / 4.0 ).abs() < 1e-9, "{metrics:?}" );
+
+  client.models().list().await.expect( "fixture should answer" );
+  let metrics = clone.rate_limiter_metrics().expect( "clones share the limiter" );
+  assert!( ( metrics.effective_rate - CEILING * ( 0.25 + ADAPTIVE_INCREASE_STEP ) ).abs() < 1e-9, "{metrics:?}" );
+  assert_eq!( metrics.allowed_requests, 3 );
+}
+
+#[ test ]
+fn client_without_rate_limiting_has_no_limiter()
+{
+  let client = Client::builder().api_key( "test-key".to_string() ).build().expect( "client should build" );
+  assert!( client.rate_limiter_metrics().is_none() );
+}
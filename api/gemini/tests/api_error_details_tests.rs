@@ -0,0 +1,141 @@
+//! Structured API error detail tests
+//!
+//! Tests that Gemini error bodies are surfaced through `Error::api_details()` so
+//! callers can branch on `status`/`code` instead of matching message text. Error
+//! responses are answered by `MockTransport`.
+//!
+//! # Test Coverage
+//!
+//! - Full error JSON deserialization into `ApiErrorResponse`
+//! - Details attached to `Error::InvalidArgument` for 400 responses
+//! - Details attached to `Error::ApiError` for other non-retryable statuses
+//! - Details and the `RetryInfo` delay attached to `Error::RateLimitError` for 429 responses
+//! - `Client::handle_response_error` attaches details parsed from the body
+//! - Plain-text error bodies produce no details
+
+use api_gemini::{ client::Client, error::{ Error, ApiErrorResponse }, internal::http::{ self, HttpConfig }, testing::MockTransport, ResponseParts };
+use reqwest::Method;
+use std::sync::Arc;
+
+const INVALID_ARGUMENT_BODY : &str = r#"{
+  "error": {
+    "code": 400,
+    "message": "API key not valid. Please pass a valid API key.",
+    "status": "INVALID_ARGUMENT",
+    "details": [
+      {
+        "@type": "type.googleapis.com/google.rpc.ErrorInfo",
+        "reason": "API_KEY_INVALID",
+        "domain": "googleapis.com"
+      }
+    ]
+  }
+}"#;
+
+const NOT_FOUND_BODY : &str = r#"{
+  "error": {
+    "code": 404,
+    "message": "models/unknown-model is not found for API version v1beta",
+    "status": "NOT_FOUND"
+  }
+}"#;
+
+const RESOURCE_EXHAUSTED_BODY : &str = r#"{
+  "error": {
+    "code": 429,
+    "message": "You exceeded your current quota.",
+    "status": "RESOURCE_EXHAUSTED",
+    "details": [
+      {
+        "@type": "type.googleapis.com/google.rpc.QuotaFailure",
+        "violations": [ { "quotaMetric": "generativelanguage.googleapis.com/generate_content_free_tier_requests" } ]
+      },
+      {
+        "@type": "type.googleapis.com/google.rpc.RetryInfo",
+        "retryDelay": "37s"
+      }
+    ]
+  }
+}"#;
+
+/// List models, answered with `status` and `body`
+async fn fetch( status : u16, body : impl Into< String > ) -> Result< serde_json::Value, Error >
+{
+  let mock = MockTransport::new().on( Method::GET, "/v1beta/models", ResponseParts::new( status, body ) );
+  let config = HttpConfig::new().with_interceptor( Arc::new( mock ) );
+  http ::execute::< (), serde_json::Value >( &reqwest::Client::new(), Method::GET, "http://127.0.0.1:9/v1beta/models", "test-key", None, &config ).await
+}
+
+#[ test ]
+fn test_full_error_json_deserializes_with_details()
+{
+  let parsed : ApiErrorResponse = serde_json::from_str( INVALID_ARGUMENT_BODY ).expect( "error body should parse" );
+
+  assert_eq!( parsed.error.code, 400 );
+  assert_eq!( parsed.error.status.as_deref(), Some( "INVALID_ARGUMENT" ) );
+  assert_eq!( parsed.error.details.len(), 1 );
+  assert_eq!( parsed.error.details[ 0 ][ "reason" ], "API_KEY_INVALID" );
+}
+
+#[ tokio::test ]
+async fn test_invalid_argument_carries_details()
+{
+  // Message mentions "API key", so use a non-auth message body to reach the 400 branch
+  let body = INVALID_ARGUMENT_BODY.replace( "API key not valid. Please pass a valid API key.", "Request contains an invalid argument." );
+  let error = fetch( 400, body ).await.expect_err( "400 response should fail" );
+  assert!( matches!( error, Error::InvalidArgument( _, _ ) ), "unexpected error : {error:?}" );
+  assert!( error.to_string().contains( "Request contains an invalid argument." ), "message should be preserved" );
+
+  let details = error.api_details().expect( "details should be attached" );
+  assert_eq!( details.code, 400 );
+  assert_eq!( details.status.as_deref(), Some( "INVALID_ARGUMENT" ) );
+  assert_eq!( details.details[ 0 ][ "@type" ], "type.googleapis.com/google.rpc.ErrorInfo" );
+}
+
+#[ tokio::test ]
+async fn test_api_error_carries_details()
+{
+  let error = fetch( 404, NOT_FOUND_BODY ).await.expect_err( "404 response should fail" );
+  assert!( matches!( error, Error::ApiError( _, _ ) ), "unexpected error : {error:?}" );
+
+  let details = error.api_details().expect( "details should be attached" );
+  assert_eq!( details.code, 404 );
+  assert_eq!( details.status.as_deref(), Some( "NOT_FOUND" ) );
+  assert!( details.details.is_empty() );
+}
+
+#[ tokio::test ]
+async fn test_rate_limit_error_carries_details()
+{
+  let error = fetch( 429, RESOURCE_EXHAUSTED_BODY ).await.expect_err( "429 response should fail" );
+  assert!( matches!( error, Error::RateLimitError( _, Some( _ ) ) ), "unexpected error : {error:?}" );
+
+  let details = error.api_details().expect( "details should be attached" );
+  assert_eq!( details.status.as_deref(), Some( "RESOURCE_EXHAUSTED" ) );
+  assert_eq!( details.details[ 0 ][ "@type" ], "type.googleapis.com/google.rpc.QuotaFailure" );
+  assert_eq!( details.retry_delay(), Some( core::time::Duration::from_secs( 37 ) ) );
+}
+
+#[ test ]
+fn test_handle_response_error_carries_details()
+{
+  let client = Client::builder().api_key( "test-key".to_string() ).build().expect( "client should build" );
+
+  let error = client.handle_response_error( 429, "Too Many Requests", RESOURCE_EXHAUSTED_BODY ).unwrap_err();
+  assert!( matches!( error, Error::RateLimitError( _, _ ) ), "unexpected error : {error:?}" );
+  assert_eq!( error.api_details().and_then( | details | details.retry_delay() ), Some( core::time::Duration::from_secs( 37 ) ) );
+
+  let error = client.handle_response_error( 404, "Not Found", NOT_FOUND_BODY ).unwrap_err();
+  assert_eq!( error.api_details().and_then( | details | details.status.as_deref() ), Some( "NOT_FOUND" ) );
+
+  let error = client.handle_response_error( 404, "Not Found", "not found" ).unwrap_err();
+  assert!( error.api_details().is_none() );
+}
+
+#[ tokio::test ]
+async fn test_plain_text_error_has_no_details()
+{
+  let error = fetch( 404, "not found" ).await.expect_err( "404 response should fail" );
+  assert!( matches!( error, Error::ApiError( _, None ) ), "unexpected error : {error:?}" );
+  assert!( error.api_details().is_none() );
+}
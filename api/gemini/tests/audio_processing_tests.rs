@@ -96,7 +96,7 @@ async fn test_audio_transcription_mp3()
       // For now, we expect this to fail until audio support is confirmed
       match e
       {
-        Error::InvalidArgument( _ ) | Error::ApiError( _ ) => {
+        Error::InvalidArgument( _, _ ) | Error::ApiError( _, _ ) => {
           // These are acceptable failures for unsupported features
         },
       _ => panic!( "Unexpected error type : {e}" ),
@@ -177,7 +177,7 @@ async fn test_audio_analysis_wav()
     println!( "Audio analysis test failed (expected): {e}" );
       match e
       {
-        Error::InvalidArgument( _ ) | Error::ApiError( _ ) => {
+        Error::InvalidArgument( _, _ ) | Error::ApiError( _, _ ) => {
           // Acceptable failures for unsupported features
         },
       _ => panic!( "Unexpected error type : {e}" ),
@@ -258,7 +258,7 @@ async fn test_multiple_audio_formats()
         // Document which formats are not yet supported
         match e
         {
-          Error::InvalidArgument( _ ) | Error::ApiError( _ ) => {
+          Error::InvalidArgument( _, _ ) | Error::ApiError( _, _ ) => {
             // Expected for unsupported formats
           },
       _ => panic!( "Unexpected error for format {mime_type}: {e}" ),
@@ -341,7 +341,7 @@ async fn test_audio_safety_filtering()
     println!( "Audio safety test failed (expected): {e}" );
       match e
       {
-        Error::InvalidArgument( _ ) | Error::ApiError( _ ) => {
+        Error::InvalidArgument( _, _ ) | Error::ApiError( _, _ ) => {
           // Expected for unsupported features
         },
       _ => panic!( "Unexpected error type : {e}" ),
@@ -415,7 +415,7 @@ async fn test_large_audio_file()
       // Could fail due to size limits or unsupported feature
       match e
       {
-        Error::InvalidArgument( _ ) | Error::ApiError( _ ) | Error::NetworkError( _ ) => {
+        Error::InvalidArgument( _, _ ) | Error::ApiError( _, _ ) | Error::NetworkError( _ ) => {
           // Expected failures
         },
       _ => panic!( "Unexpected error type : {e}" ),
@@ -483,7 +483,7 @@ async fn test_invalid_audio_format()
       // Verify we get appropriate error types for invalid data
       match e
       {
-        Error::InvalidArgument( _ ) | Error::DeserializationError( _ ) | Error::ApiError( _ ) => {
+        Error::InvalidArgument( _, _ ) | Error::DeserializationError( _ ) | Error::ApiError( _, _ ) => {
         println!( "Correctly rejected invalid audio data : {e}" );
         },
       _ => panic!( "Unexpected error type for invalid audio : {e}" ),
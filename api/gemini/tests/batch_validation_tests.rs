@@ -0,0 +1,65 @@
+//! Batch validation tests
+//!
+//! # Test Coverage
+//!
+//! - All valid items pass
+//! - Every invalid item is reported with its index, in order
+//! - Empty batches pass
+//! - Works with the request validators from the `validation` module
+
+use api_gemini::models::{ ContentBuilder, GenerateContentRequest, GenerationConfig };
+use api_gemini::validation::{ validate_batch, validate_enhanced_generate_content_request, validate_generation_config, ValidationError };
+
+fn request( prompt : &str, stop_sequences : usize ) -> GenerateContentRequest
+{
+  GenerateContentRequest
+  {
+    contents : vec![ ContentBuilder::user().text( prompt ).build().unwrap() ],
+    generation_config : Some( GenerationConfig::default().with_stop_sequences( ( 0..stop_sequences ).map( | i | format!( "STOP{i}" ) ).collect() ) ),
+    ..Default::default()
+  }
+}
+
+#[ test ]
+fn all_valid_items_pass()
+{
+  let requests = vec![ request( "one", 1 ), request( "two", 5 ) ];
+  assert!( validate_batch( &requests, validate_enhanced_generate_content_request ).is_ok() );
+  assert!( validate_batch( &[] as &[ GenerateContentRequest ], validate_enhanced_generate_content_request ).is_ok() );
+}
+
+#[ test ]
+fn every_failure_is_indexed()
+{
+  let requests = vec!
+  [
+    request( "valid", 1 ),
+    GenerateContentRequest::default(),
+    request( "also valid", 0 ),
+    request( "too many stops", 6 ),
+  ];
+
+  let errors = validate_batch( &requests, validate_enhanced_generate_content_request ).unwrap_err();
+  let indices : Vec< usize > = errors.iter().map( | ( index, _ ) | *index ).collect();
+  assert_eq!( indices, vec![ 1, 3 ] );
+  assert!( errors[ 1 ].1.to_string().contains( "stop_sequences" ), "{}", errors[ 1 ].1 );
+}
+
+#[ test ]
+fn errors_come_from_the_validator()
+{
+  let configs = vec!
+  [
+    GenerationConfig::default().with_stop_sequences( vec![ String::new() ] ),
+    GenerationConfig::default(),
+    GenerationConfig::default().with_stop_sequences( vec![ "END".to_string(), String::new() ] ),
+  ];
+
+  let errors = validate_batch( &configs, validate_generation_config ).unwrap_err();
+  let fields : Vec< ( usize, String ) > = errors.into_iter().map( | ( index, error ) | match error
+  {
+    ValidationError::RequiredFieldMissing { field, .. } => ( index, field ),
+    other => panic!( "unexpected error : {other}" ),
+  } ).collect();
+  assert_eq!( fields, vec![ ( 0, "stop_sequences[0]".to_string() ), ( 2, "stop_sequences[1]".to_string() ) ] );
+}
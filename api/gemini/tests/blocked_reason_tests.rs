@@ -0,0 +1,110 @@
+//! Blocked reason tests
+//!
+//! Tests for `GenerateContentResponse::blocked_reason`, using response fixtures
+//! shaped like the API's safety-blocked and normal responses.
+//!
+//! # Test Coverage
+//!
+//! - Prompt blocked by `promptFeedback.blockReason`, with no candidates
+//! - Candidate stopped with a `SAFETY` finish reason and blocked categories
+//! - Candidate with a blocked safety rating but another finish reason
+//! - Normal and token-limited responses are not reported as blocked
+
+use api_gemini::models::{ BlockReason, GenerateContentResponse };
+
+fn response( fixture : serde_json::Value ) -> GenerateContentResponse
+{
+  serde_json::from_value( fixture ).expect( "fixture should deserialize" )
+}
+
+#[ test ]
+fn test_blocked_prompt()
+{
+  let response = response( serde_json::json!(
+  {
+    "promptFeedback" :
+    {
+      "blockReason" : "SAFETY",
+      "safetyRatings" :
+      [
+        { "category" : "HARM_CATEGORY_HARASSMENT", "probability" : "NEGLIGIBLE" },
+        { "category" : "HARM_CATEGORY_DANGEROUS_CONTENT", "probability" : "HIGH", "blocked" : true }
+      ]
+    }
+  } ) );
+
+  assert!( response.candidates.is_empty() );
+  assert_eq!( response.blocked_reason(), Some( BlockReason::Prompt
+  {
+    reason : "SAFETY".to_string(),
+    categories : vec![ "HARM_CATEGORY_DANGEROUS_CONTENT".to_string() ],
+  } ) );
+}
+
+#[ test ]
+fn test_candidate_blocked_for_safety()
+{
+  let response = response( serde_json::json!(
+  {
+    "candidates" :
+    [ {
+      "finishReason" : "SAFETY",
+      "index" : 0,
+      "safetyRatings" :
+      [
+        { "category" : "HARM_CATEGORY_SEXUALLY_EXPLICIT", "probability" : "NEGLIGIBLE" },
+        { "category" : "HARM_CATEGORY_HATE_SPEECH", "probability" : "MEDIUM", "blocked" : true }
+      ]
+    } ],
+    "promptFeedback" : { "safetyRatings" : [] }
+  } ) );
+
+  assert!( response.candidates[ 0 ].content.parts.is_empty() );
+  assert_eq!( response.blocked_reason(), Some( BlockReason::Candidate
+  {
+    finish_reason : Some( "SAFETY".to_string() ),
+    categories : vec![ "HARM_CATEGORY_HATE_SPEECH".to_string() ],
+  } ) );
+}
+
+#[ test ]
+fn test_candidate_with_blocked_rating()
+{
+  let response = response( serde_json::json!(
+  {
+    "candidates" :
+    [ {
+      "content" : { "role" : "model", "parts" : [ { "text" : "Partial" } ] },
+      "finishReason" : "OTHER",
+      "safetyRatings" : [ { "category" : "HARM_CATEGORY_HARASSMENT", "probability" : "HIGH", "blocked" : true } ]
+    } ]
+  } ) );
+
+  assert_eq!( response.blocked_reason(), Some( BlockReason::Candidate
+  {
+    finish_reason : Some( "OTHER".to_string() ),
+    categories : vec![ "HARM_CATEGORY_HARASSMENT".to_string() ],
+  } ) );
+}
+
+#[ test ]
+fn test_normal_responses_not_blocked()
+{
+  for finish_reason in [ "STOP", "MAX_TOKENS" ]
+  {
+    let response = response( serde_json::json!(
+    {
+      "candidates" :
+      [ {
+        "content" : { "role" : "model", "parts" : [ { "text" : "Hello!" } ] },
+        "finishReason" : finish_reason,
+        "safetyRatings" : [ { "category" : "HARM_CATEGORY_HARASSMENT", "probability" : "NEGLIGIBLE" } ]
+      } ],
+      "promptFeedback" : { "safetyRatings" : [ { "category" : "HARM_CATEGORY_HARASSMENT", "probability" : "NEGLIGIBLE" } ] }
+    } ) );
+
+    assert_eq!( response.blocked_reason(), None, "{finish_reason}" );
+  }
+
+  assert_eq!( response( serde_json::json!( { "candidates" : [] } ) ).blocked_reason(), None );
+}
@@ -0,0 +1,100 @@
+//! Canary traffic splitting tests
+//!
+//! # Test Coverage
+//!
+//! - The canary share over many keys matches the configured percentage
+//! - A key is routed to the same variant on every call and across routers
+//! - Raising the percentage only moves keys from stable to canary
+//! - 0% and 100% route every key to one variant
+//! - Invalid percentages and non-canary strategies are rejected
+
+use api_gemini::models::model_deployment::{ CanaryRouter, CanaryVariant, DeploymentEnvironment, DeploymentStrategy, ModelDeployment };
+use std::sync::Arc;
+
+fn deployment( id : &str, version : &str ) -> Arc< ModelDeployment >
+{
+  Arc::new( ModelDeployment::new( id.to_string(), "chat".to_string(), version.to_string(), DeploymentEnvironment::Production ) )
+}
+
+fn router( percentage : f64 ) -> CanaryRouter
+{
+  CanaryRouter::new( deployment( "stable", "1.0" ), deployment( "canary", "1.1" ), percentage ).expect( "valid percentage" )
+}
+
+fn keys() -> impl Iterator< Item = String >
+{
+  ( 0..10_000 ).map( | i | format!( "user-{i}" ) )
+}
+
+fn canary_share( router : &CanaryRouter ) -> f64
+{
+  let canary = keys().filter( | key | router.variant( key ) == CanaryVariant::Canary ).count();
+  canary as f64 / 100.0
+}
+
+#[ test ]
+fn split_matches_percentage()
+{
+  for percentage in [ 5.0, 10.0, 25.0, 50.0 ]
+  {
+    let share = canary_share( &router( percentage ) );
+    assert!( ( share - percentage ).abs() < 1.5, "{percentage}% split routed {share}% to canary" );
+  }
+}
+
+#[ test ]
+fn routing_is_sticky_per_key()
+{
+  let first = router( 20.0 );
+  let second = router( 20.0 );
+
+  for key in keys().take( 1_000 )
+  {
+    let variant = first.variant( &key );
+    assert_eq!( first.variant( &key ), variant );
+    assert_eq!( second.variant( &key ), variant, "routing must not depend on the router instance" );
+
+    let expected = if variant == CanaryVariant::Canary { "canary" } else { "stable" };
+    assert_eq!( first.route( &key ).deployment_id, expected );
+  }
+}
+
+#[ test ]
+fn ramping_up_only_moves_keys_to_canary()
+{
+  let small = router( 10.0 );
+  let large = router( 30.0 );
+
+  for key in keys()
+  {
+    if small.variant( &key ) == CanaryVariant::Canary
+    {
+      assert_eq!( large.variant( &key ), CanaryVariant::Canary, "{key} left the canary when ramping up" );
+    }
+  }
+}
+
+#[ test ]
+fn extreme_percentages_route_everything_to_one_variant()
+{
+  let none = router( 0.0 );
+  let all = router( 100.0 );
+  assert!( keys().all( | key | none.variant( &key ) == CanaryVariant::Stable ) );
+  assert!( keys().all( | key | all.variant( &key ) == CanaryVariant::Canary ) );
+}
+
+#[ test ]
+fn invalid_configuration_is_rejected()
+{
+  for percentage in [ -1.0, 100.5, f64::NAN ]
+  {
+    assert!( CanaryRouter::new( deployment( "stable", "1.0" ), deployment( "canary", "1.1" ), percentage ).is_err() );
+  }
+
+  let canary = DeploymentStrategy::Canary { traffic_percentage : 15.0, promotion_criteria : vec![] };
+  let router = CanaryRouter::from_strategy( deployment( "stable", "1.0" ), deployment( "canary", "1.1" ), &canary ).unwrap();
+  assert!( ( router.canary_percentage() - 15.0 ).abs() < f64::EPSILON );
+
+  let rolling = DeploymentStrategy::Rolling { max_unavailable_percentage : 25.0, max_surge_percentage : 25.0 };
+  assert!( CanaryRouter::from_strategy( deployment( "stable", "1.0" ), deployment( "canary", "1.1" ), &rolling ).is_err() );
+}
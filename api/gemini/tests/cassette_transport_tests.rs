@@ -0,0 +1,119 @@
+//! Cassette transport tests
+//!
+//! Tests for `testing::CassetteTransport`, recording interactions with a local
+//! server that fabricates responses and replaying them once the server is gone.
+//!
+//! # Test Coverage
+//!
+//! - Recording keeps interactions in memory, without the API key
+//! - Saved cassettes replay without the network
+//! - Replay matches on method, URL and body hash
+//! - Missing or malformed cassette files are reported
+
+#![ cfg( feature = "testing" ) ]
+
+use api_gemini::{ client::Client, error::Error, models::{ ContentBuilder, GenerateContentRequest }, testing::{ body_hash, CassetteMode, CassetteTransport } };
+
+mod local_server;
+
+const MODEL : &str = "gemini-2.5-flash";
+
+/// Answer `connections` requests with a fabricated generation, then stop listening
+fn serve_generations( connections : usize ) -> ( String, std::thread::JoinHandle< () > )
+{
+  local_server::serve( Some( connections ), | mut socket, index |
+  {
+    local_server::read_request( &mut socket );
+    let body = serde_json::json!(
+    {
+      "candidates" : [ { "content" : { "role" : "model", "parts" : [ { "text" : format!( "reply {index}" ) } ] } } ]
+    } ).to_string();
+    local_server::respond( &mut socket, "200 OK", &body );
+  } )
+}
+
+fn client( base_url : &str, cassette : &CassetteTransport ) -> Client
+{
+  Client::builder()
+  .api_key( "secret-test-key".to_string() )
+  .base_url( base_url.to_string() )
+  .with_interceptor( cassette.clone() )
+  .build()
+  .expect( "client should build" )
+}
+
+async fn generate( client : &Client, prompt : &str ) -> Result< String, Error >
+{
+  let request = GenerateContentRequest
+  {
+    contents : vec![ ContentBuilder::user().text( prompt ).build().unwrap() ],
+    ..Default::default()
+  };
+  let response = client.models().by_name( MODEL ).generate_content( &request ).await?;
+  Ok( response.candidates[ 0 ].content.parts[ 0 ].text.clone().unwrap_or_default() )
+}
+
+fn cassette_path( name : &str ) -> std::path::PathBuf
+{
+  std::env::temp_dir().join( format!( "api_gemini_{name}_{}.json", std::process::id() ) )
+}
+
+#[ tokio::test ]
+async fn test_record_then_replay()
+{
+  let ( base_url, server ) = serve_generations( 2 );
+  let recorder = CassetteTransport::record();
+  assert_eq!( recorder.mode(), CassetteMode::Record );
+
+  let recording = client( &base_url, &recorder );
+  assert_eq!( generate( &recording, "first" ).await.unwrap(), "reply 0" );
+  assert_eq!( generate( &recording, "second" ).await.unwrap(), "reply 1" );
+  server.join().unwrap();
+
+  let interactions = recorder.interactions();
+  assert_eq!( interactions.len(), 2 );
+  assert_eq!( interactions[ 0 ].method, "POST" );
+  assert_eq!( interactions[ 0 ].url, format!( "{base_url}/v1beta/models/{MODEL}:generateContent" ) );
+  assert_eq!( interactions[ 0 ].response.status, 200 );
+  assert_ne!( interactions[ 0 ].body_hash, interactions[ 1 ].body_hash );
+
+  let path = cassette_path( "record_then_replay" );
+  recorder.save( &path ).unwrap();
+  let saved = std::fs::read_to_string( &path ).unwrap();
+  assert!( !saved.contains( "secret-test-key" ) );
+
+  // The server is gone, so every answer comes from the cassette
+  let player = CassetteTransport::replay( &path ).unwrap();
+  std::fs::remove_file( &path ).unwrap();
+  assert_eq!( player.mode(), CassetteMode::Replay );
+
+  let replaying = client( &base_url, &player );
+  assert_eq!( generate( &replaying, "second" ).await.unwrap(), "reply 1" );
+  assert_eq!( generate( &replaying, "first" ).await.unwrap(), "reply 0" );
+  assert_eq!( generate( &replaying, "first" ).await.unwrap(), "reply 0" );
+
+  let error = generate( &replaying, "third" ).await.unwrap_err();
+  assert!( matches!( error, Error::ApiError( .. ) ), "{error:?}" );
+  assert!( error.to_string().contains( "no recorded interaction" ), "{error}" );
+}
+
+#[ test ]
+fn test_body_hash_is_stable()
+{
+  assert_eq!( body_hash( None ), body_hash( Some( b"" ) ) );
+  assert_eq!( body_hash( Some( b"a" ) ), "af63dc4c8601ec8c" );
+  assert_ne!( body_hash( Some( b"{\"a\":1}" ) ), body_hash( Some( b"{\"a\":2}" ) ) );
+}
+
+#[ test ]
+fn test_invalid_cassette_files()
+{
+  let missing = CassetteTransport::replay( cassette_path( "missing" ) ).unwrap_err();
+  assert!( matches!( missing, Error::ConfigurationError( _ ) ), "{missing:?}" );
+
+  let path = cassette_path( "malformed" );
+  std::fs::write( &path, "{ not a cassette" ).unwrap();
+  let malformed = CassetteTransport::replay( &path ).unwrap_err();
+  std::fs::remove_file( &path ).unwrap();
+  assert!( malformed.to_string().contains( "Failed to parse cassette" ), "{malformed}" );
+}
@@ -0,0 +1,178 @@
+//! Chat session history tests
+//!
+//! Drives `ChatSession` through `MockTransport`, which answers each call with a
+//! canned reply and records the request bodies.
+//!
+//! # Test Coverage
+//!
+//! - History grows by a user and a model turn per `send`
+//! - Each request carries the full history and the session generation config
+//! - Failed turns leave the history unchanged
+//! - `clear` resets the history
+
+#![ cfg( feature = "chat" ) ]
+
+use api_gemini::{ client::Client, error::Error, models::GenerationConfig, testing::MockTransport, ResponseParts };
+use reqwest::Method;
+
+const MODEL : &str = "gemini-2.5-flash";
+
+/// Answer each `( action, response )` in order on `MODEL`'s `action` endpoint
+fn transport( replies : Vec< ( &str, ResponseParts ) > ) -> MockTransport
+{
+  replies.into_iter().fold( MockTransport::new(), | mock, ( action, response ) |
+  {
+    mock.on( Method::POST, format!( "/v1beta/models/{MODEL}:{action}" ), response )
+  } )
+}
+
+/// Bodies of the requests `mock` received
+fn requests( mock : &MockTransport ) -> Vec< serde_json::Value >
+{
+  mock.calls().iter()
+  .map( | call | call.body.as_deref().and_then( | body | serde_json::from_slice( body ).ok() ).unwrap_or( serde_json::Value::Null ) )
+  .collect()
+}
+
+fn reply( text : &str ) -> ( &'static str, ResponseParts )
+{
+  ( "generateContent", ResponseParts::new( 200, serde_json::json!( {
+    "candidates" : [ { "content" : { "parts" : [ { "text" : text } ], "role" : "model" }, "finishReason" : "STOP", "index" : 0 } ]
+  } ).to_string() ) )
+}
+
+fn client( mock : &MockTransport ) -> Client
+{
+  Client::with_mock_transport( mock.clone() ).expect( "client should build" )
+}
+
+#[ tokio::test ]
+async fn two_turns_grow_history_and_resend_it()
+{
+  let mock = transport( vec![ reply( "Hi Ada!" ), reply( "Your name is Ada." ) ] );
+  let client = client( &mock );
+  let mut session = client
+    .chat()
+    .session( MODEL )
+    .with_generation_config( GenerationConfig { temperature : Some( 0.1 ), ..Default::default() } );
+
+  let first = session.send( "Hello, I am Ada." ).await.expect( "first turn should succeed" );
+  assert_eq!( first.candidates[ 0 ].content.parts[ 0 ].text.as_deref(), Some( "Hi Ada!" ) );
+  assert_eq!( session.history().len(), 2 );
+
+  session.send( "What is my name?" ).await.expect( "second turn should succeed" );
+  let history = session.history();
+  assert_eq!( history.len(), 4 );
+  let roles : Vec< &str > = history.iter().map( | content | content.role.as_str() ).collect();
+  assert_eq!( roles, vec![ "user", "model", "user", "model" ] );
+  assert_eq!( history[ 3 ].parts[ 0 ].text.as_deref(), Some( "Your name is Ada." ) );
+
+  let requests = requests( &mock );
+  assert_eq!( requests[ 0 ][ "contents" ].as_array().unwrap().len(), 1 );
+  let second = requests[ 1 ][ "contents" ].as_array().unwrap();
+  assert_eq!( second.len(), 3, "second request should carry the full history" );
+  assert_eq!( second[ 1 ][ "parts" ][ 0 ][ "text" ], "Hi Ada!" );
+  assert_eq!( second[ 2 ][ "parts" ][ 0 ][ "text" ], "What is my name?" );
+  let temperature = requests[ 1 ][ "generationConfig" ][ "temperature" ].as_f64().unwrap();
+  assert!( ( temperature - 0.1 ).abs() < 1e-6 );
+}
+
+#[ tokio::test ]
+async fn failed_turn_leaves_history_unchanged()
+{
+  let error_body = serde_json::json!( { "error" : { "code" : 400, "message" : "Invalid request", "status" : "INVALID_ARGUMENT" } } ).to_string();
+  let mock = transport( vec![ reply( "Hello!" ), ( "generateContent", ResponseParts::new( 400, error_body ) ) ] );
+  let client = client( &mock );
+  let mut session = client.chat().session( MODEL );
+
+  session.send( "Hi" ).await.expect( "first turn should succeed" );
+  let result = session.send( "Break please" ).await;
+
+  assert!( matches!( result, Err( Error::InvalidArgument( _, _ ) ) ), "unexpected result : {result:?}" );
+  assert_eq!( session.history().len(), 2 );
+}
+
+#[ tokio::test ]
+async fn clear_resets_history_and_empty_text_is_rejected()
+{
+  let mock = transport( vec![ reply( "Hello!" ) ] );
+  let client = client( &mock );
+  let mut session = client.chat().session( MODEL );
+
+  assert!( matches!( session.send( "   " ).await, Err( Error::InvalidArgument( _, _ ) ) ) );
+  assert!( session.history().is_empty() );
+
+  session.send( "Hi" ).await.expect( "turn should succeed" );
+  assert_eq!( session.history().len(), 2 );
+
+  session.clear();
+  assert!( session.history().is_empty() );
+  assert_eq!( session.model(), MODEL );
+}
+
+#[ cfg( feature = "streaming" ) ]
+fn stream_reply( fragments : &[ &str ] ) -> ( &'static str, ResponseParts )
+{
+  let chunks : Vec< serde_json::Value > = fragments
+    .iter()
+    .enumerate()
+    .map( | ( index, text ) | serde_json::json!( {
+      "candidates" : [ {
+        "content" : { "parts" : [ { "text" : text } ], "role" : "model" },
+        "finishReason" : if index + 1 == fragments.len() { Some( "STOP" ) } else { None },
+        "index" : 0
+      } ]
+    } ) )
+    .collect();
+  ( "streamGenerateContent", ResponseParts::new( 200, serde_json::Value::Array( chunks ).to_string() ) )
+}
+
+#[ cfg( feature = "streaming" ) ]
+#[ tokio::test ]
+async fn streaming_turn_commits_assembled_reply_on_completion()
+{
+  use futures::StreamExt;
+
+  let mock = transport( vec![ stream_reply( &[ "Once", " upon", " a time" ] ), reply( "The end." ) ] );
+  let client = client( &mock );
+  let mut session = client.chat().session( MODEL );
+
+  let fragments : Vec< String > = session
+    .send_streaming( "Tell me a story" )
+    .await
+    .expect( "stream should open" )
+    .map( | fragment | fragment.expect( "fragment should be ok" ) )
+    .collect()
+    .await;
+
+  assert_eq!( fragments, vec![ "Once", " upon", " a time" ] );
+  assert_eq!( session.history().len(), 2 );
+  assert_eq!( session.history()[ 1 ].role, "model" );
+  assert_eq!( session.history()[ 1 ].parts[ 0 ].text.as_deref(), Some( "Once upon a time" ) );
+
+  session.send( "And then?" ).await.expect( "follow-up turn should succeed" );
+  let requests = requests( &mock );
+  assert_eq!( requests[ 1 ][ "contents" ].as_array().unwrap().len(), 3, "streamed turn should be part of the next request" );
+}
+
+#[ cfg( feature = "streaming" ) ]
+#[ tokio::test ]
+async fn failed_stream_records_user_turn_without_model_turn()
+{
+  use futures::StreamExt;
+
+  let mock = transport( vec![ ( "streamGenerateContent", ResponseParts::new( 200, "[ { \"candidates\" : [" ) ) ] );
+  let client = client( &mock );
+  let mut session = client.chat().session( MODEL );
+
+  let items : Vec< Result< String, Error > > = session
+    .send_streaming( "Tell me a story" )
+    .await
+    .expect( "stream should open" )
+    .collect()
+    .await;
+
+  assert!( items.last().is_some_and( Result::is_err ), "stream should end with an error : {items:?}" );
+  assert_eq!( session.history().len(), 1 );
+  assert_eq!( session.history()[ 0 ].role, "user" );
+}
@@ -0,0 +1,85 @@
+//! Circuit breaker shared state tests
+//!
+//! `MockTransport` answers every request with `503 Service Unavailable`, so each
+//! call counts as a breaker failure. Retries are disabled to keep one attempt per call.
+//!
+//! # Test Coverage
+//!
+//! - With shared state, tripping the breaker on one clone opens it for the other
+//! - Without shared state, each clone keeps an independent breaker
+//! - No breaker is attached when the circuit breaker is disabled
+
+#![ cfg( all( feature = "circuit_breaker", feature = "retry" ) ) ]
+
+use api_gemini::{ client::Client, error::Error, internal::http::CircuitBreakerState, testing::MockTransport, ResponseParts };
+use core::time::Duration;
+use reqwest::Method;
+
+const FAILURE_THRESHOLD : u32 = 2;
+
+fn unavailable() -> MockTransport
+{
+  MockTransport::new().on( Method::GET, "/v1beta/models", ResponseParts::new( 503, "service unavailable" ) )
+}
+
+fn client( mock : &MockTransport, shared : bool ) -> Client
+{
+  Client::builder()
+  .api_key( "test-key".to_string() )
+  .with_interceptor( mock.clone() )
+  .max_retries( 0 )
+  .enable_circuit_breaker( true )
+  .circuit_breaker_failure_threshold( FAILURE_THRESHOLD )
+  .circuit_breaker_timeout( Duration::from_secs( 60 ) )
+  .circuit_breaker_shared_state( shared )
+  .build()
+  .expect( "client should build" )
+}
+
+async fn trip( client : &Client )
+{
+  for _ in 0..FAILURE_THRESHOLD
+  {
+    assert!( matches!( client.models().list().await, Err( Error::ServerError( _ ) ) ) );
+  }
+  assert!( matches!( client.circuit_breaker_state(), Some( CircuitBreakerState::Open( _ ) ) ) );
+}
+
+#[ tokio::test ]
+async fn shared_breaker_opens_for_every_clone()
+{
+  let mock = unavailable();
+  let first = client( &mock, true );
+  let second = first.clone();
+
+  trip( &first ).await;
+
+  assert!( matches!( second.circuit_breaker_state(), Some( CircuitBreakerState::Open( _ ) ) ) );
+  assert!( matches!( second.models().list().await, Err( Error::CircuitBreakerOpen( _ ) ) ) );
+  assert_eq!( mock.calls().len(), FAILURE_THRESHOLD as usize, "open breaker must not reach the server" );
+}
+
+#[ tokio::test ]
+async fn independent_breaker_per_clone()
+{
+  let mock = unavailable();
+  let first = client( &mock, false );
+  let second = first.clone();
+
+  trip( &first ).await;
+
+  assert_eq!( second.circuit_breaker_state(), Some( CircuitBreakerState::Closed ) );
+  assert!( matches!( second.models().list().await, Err( Error::ServerError( _ ) ) ) );
+  assert_eq!( mock.calls().len(), FAILURE_THRESHOLD as usize + 1 );
+}
+
+#[ test ]
+fn disabled_breaker_has_no_state()
+{
+  let client = Client::builder()
+  .api_key( "test-key".to_string() )
+  .build()
+  .expect( "client should build" );
+
+  assert!( client.circuit_breaker_state().is_none() );
+}
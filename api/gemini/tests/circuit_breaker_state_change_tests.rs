@@ -0,0 +1,110 @@
+//! Circuit breaker state change callback tests
+//!
+//! Drives a `CircuitBreaker` directly through failures, the open timeout and a
+//! recovery, recording every `( old, new )` pair passed to `on_state_change`.
+//!
+//! # Test Coverage
+//!
+//! - Closed → Open when the failure threshold is reached
+//! - Open → HalfOpen once the open timeout elapses
+//! - HalfOpen → Closed after enough successes
+//! - Callbacks may query the breaker without deadlocking
+//! - Callbacks may register further listeners without deadlocking
+
+#![ cfg( feature = "circuit_breaker" ) ]
+
+use api_gemini::internal::http::{ CircuitBreaker, CircuitBreakerConfig, CircuitBreakerState };
+use core::time::Duration;
+use std::sync::{ Arc, Mutex };
+
+type Transitions = Arc< Mutex< Vec< ( CircuitBreakerState, CircuitBreakerState ) > > >;
+
+fn breaker() -> ( Arc< CircuitBreaker >, Transitions )
+{
+  let breaker = Arc::new( CircuitBreaker::new( CircuitBreakerConfig
+  {
+    failure_threshold : 2,
+    timeout : Duration::from_millis( 20 ),
+    success_threshold : 1,
+    enable_metrics : true,
+  } ) );
+
+  let transitions : Transitions = Arc::default();
+  let recorded = transitions.clone();
+  breaker.on_state_change( move | old, new | recorded.lock().unwrap().push( ( old, new ) ) );
+
+  ( breaker, transitions )
+}
+
+#[ test ]
+fn transitions_fire_in_order()
+{
+  let ( breaker, transitions ) = breaker();
+
+  breaker.record_failure();
+  assert!( transitions.lock().unwrap().is_empty(), "below threshold there is no transition" );
+
+  breaker.record_failure();
+  {
+    let recorded = transitions.lock().unwrap();
+    assert_eq!( recorded.len(), 1 );
+    assert_eq!( recorded[ 0 ].0, CircuitBreakerState::Closed );
+    assert!( matches!( recorded[ 0 ].1, CircuitBreakerState::Open( _ ) ) );
+  }
+
+  assert!( !breaker.should_allow_request() );
+  assert_eq!( transitions.lock().unwrap().len(), 1, "blocked requests do not transition" );
+
+  std::thread::sleep( Duration::from_millis( 30 ) );
+  assert!( breaker.should_allow_request() );
+  {
+    let recorded = transitions.lock().unwrap();
+    assert_eq!( recorded.len(), 2 );
+    assert!( matches!( recorded[ 1 ].0, CircuitBreakerState::Open( _ ) ) );
+    assert_eq!( recorded[ 1 ].1, CircuitBreakerState::HalfOpen );
+  }
+
+  breaker.record_success();
+  let recorded = transitions.lock().unwrap();
+  assert_eq!( recorded.len(), 3 );
+  assert_eq!( recorded[ 2 ], ( CircuitBreakerState::HalfOpen, CircuitBreakerState::Closed ) );
+}
+
+#[ test ]
+fn callback_can_query_breaker()
+{
+  let ( breaker, _ ) = breaker();
+  let observed = Arc::new( Mutex::new( None ) );
+
+  let probe = breaker.clone();
+  let sink = observed.clone();
+  breaker.on_state_change( move | _, _ | *sink.lock().unwrap() = Some( probe.state() ) );
+
+  breaker.record_failure();
+  breaker.record_failure();
+
+  assert!( matches!( *observed.lock().unwrap(), Some( CircuitBreakerState::Open( _ ) ) ) );
+}
+
+#[ test ]
+fn callback_can_register_listener()
+{
+  let ( breaker, _ ) = breaker();
+  let late_calls = Arc::new( Mutex::new( 0_u32 ) );
+
+  let registrar = breaker.clone();
+  let counter = late_calls.clone();
+  breaker.on_state_change( move | _, _ |
+  {
+    let counter = counter.clone();
+    registrar.on_state_change( move | _, _ | *counter.lock().unwrap() += 1 );
+  } );
+
+  // Closed → Open registers a listener, which then sees Open → HalfOpen
+  breaker.record_failure();
+  breaker.record_failure();
+  std::thread::sleep( Duration::from_millis( 30 ) );
+  assert!( breaker.should_allow_request() );
+
+  assert_eq!( *late_calls.lock().unwrap(), 1 );
+}
@@ -0,0 +1,97 @@
+//! Citation text tests
+//!
+//! Tests for `GenerateContentResponse::text_with_citations`, which inserts
+//! markers for grounding sources into the response text.
+//!
+//! # Test Coverage
+//!
+//! - Numeric markers at support end offsets with a `Sources:` list
+//! - Footnote markers with Markdown footnote definitions
+//! - Sources numbered by first citation, listing only cited sources
+//! - Offsets inside multi-byte characters or past the end of the text
+//! - Responses without grounding metadata are returned unchanged
+
+use api_gemini::models::{ CitationStyle, GenerateContentResponse };
+
+const TEXT : &str = "Rust 1.0 was released in 2015. It is memory safe.";
+
+fn grounded( text : &str, supports : serde_json::Value ) -> GenerateContentResponse
+{
+  serde_json::from_value( serde_json::json!(
+  {
+    "candidates" : [ { "content" : { "role" : "model", "parts" : [ { "text" : text } ] } } ],
+    "groundingMetadata" :
+    {
+      "webSearchQueries" : [ "rust 1.0 release year" ],
+      "groundingChunks" :
+      [
+        { "title" : "Rust Blog", "uri" : "https://blog.rust-lang.org" },
+        { "title" : "Wikipedia", "uri" : "https://en.wikipedia.org/wiki/Rust" },
+        { "title" : "Uncited", "uri" : "https://example.com" }
+      ],
+      "groundingSupports" : supports
+    }
+  } ) ).expect( "fixture should deserialize" )
+}
+
+fn fixture() -> GenerateContentResponse
+{
+  grounded( TEXT, serde_json::json!(
+  [
+    { "startIndex" : 0, "endIndex" : 30, "groundingChunkIndices" : [ 1 ], "confidenceScore" : 0.9 },
+    { "startIndex" : 31, "endIndex" : 49, "groundingChunkIndices" : [ 0, 1 ] }
+  ] ) )
+}
+
+#[ test ]
+fn test_numeric_style()
+{
+  assert_eq!(
+    fixture().text_with_citations( CitationStyle::Numeric ),
+    "Rust 1.0 was released in 2015.[1] It is memory safe.[1][2]\n\n\
+    Sources:\n\
+    [1] Wikipedia - https://en.wikipedia.org/wiki/Rust\n\
+    [2] Rust Blog - https://blog.rust-lang.org\n"
+  );
+}
+
+#[ test ]
+fn test_footnote_style()
+{
+  assert_eq!(
+    fixture().text_with_citations( CitationStyle::Footnote ),
+    "Rust 1.0 was released in 2015.[^1] It is memory safe.[^1][^2]\n\n\
+    [^1]: Wikipedia - https://en.wikipedia.org/wiki/Rust\n\
+    [^2]: Rust Blog - https://blog.rust-lang.org\n"
+  );
+}
+
+#[ test ]
+fn test_offsets_snap_to_char_boundaries()
+{
+  // Byte 7 falls inside "é", byte 100 is past the end
+  let response = grounded( "Le café est bon.", serde_json::json!(
+  [
+    { "endIndex" : 7, "groundingChunkIndices" : [ 0 ] },
+    { "endIndex" : 100, "groundingChunkIndices" : [ 2, 7 ] },
+    { "groundingChunkIndices" : [ 1 ] }
+  ] ) );
+
+  let cited = response.text_with_citations( CitationStyle::Numeric );
+  assert!( cited.starts_with( "Le caf[1]é est bon.[2]\n\n" ), "{cited}" );
+  assert!( cited.ends_with( "[2] Uncited - https://example.com\n" ), "{cited}" );
+  assert!( !cited.contains( "Wikipedia" ), "{cited}" );
+}
+
+#[ test ]
+fn test_without_grounding_unchanged()
+{
+  let response : GenerateContentResponse = serde_json::from_value( serde_json::json!(
+  {
+    "candidates" : [ { "content" : { "role" : "model", "parts" : [ { "text" : TEXT } ] } } ]
+  } ) ).unwrap();
+  assert_eq!( response.text_with_citations( CitationStyle::Numeric ), TEXT );
+
+  let response = grounded( TEXT, serde_json::json!( [] ) );
+  assert_eq!( response.text_with_citations( CitationStyle::Footnote ), TEXT );
+}
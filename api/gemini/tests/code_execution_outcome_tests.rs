@@ -0,0 +1,95 @@
+//! Code execution outcome tests
+//!
+//! Tests for `GenerateContentResponse::code_executions`, using response fixtures
+//! shaped like the API's code execution tool output.
+//!
+//! # Test Coverage
+//!
+//! - Executable code parts paired with the result part that follows them
+//! - Failed executions report their outcome and error output
+//! - Code without a result and results without code
+//! - Round trip of `executableCode` and `codeExecutionResult` parts
+
+use api_gemini::models::{ CodeExecutionOutcome, GenerateContentResponse, Part };
+
+fn response( parts : serde_json::Value ) -> GenerateContentResponse
+{
+  serde_json::from_value( serde_json::json!(
+  {
+    "candidates" : [ { "content" : { "role" : "model", "parts" : parts }, "finishReason" : "STOP" } ]
+  } ) ).expect( "fixture should deserialize" )
+}
+
+#[ test ]
+fn test_code_paired_with_result()
+{
+  let response = response( serde_json::json!(
+  [
+    { "text" : "Let me compute that." },
+    { "executableCode" : { "language" : "PYTHON", "code" : "print( sum( range( 101 ) ) )" } },
+    { "codeExecutionResult" : { "outcome" : "OUTCOME_OK", "output" : "5050\n" } },
+    { "text" : "The sum is 5050." }
+  ] ) );
+
+  let executions = response.code_executions();
+  assert_eq!( executions, vec![ CodeExecutionOutcome
+  {
+    language : "PYTHON".to_string(),
+    code : "print( sum( range( 101 ) ) )".to_string(),
+    outcome : Some( "OUTCOME_OK".to_string() ),
+    output : Some( "5050\n".to_string() ),
+  } ] );
+  assert!( executions[ 0 ].succeeded() );
+}
+
+#[ test ]
+fn test_failed_execution()
+{
+  let response = response( serde_json::json!(
+  [
+    { "executableCode" : { "language" : "PYTHON", "code" : "1 / 0" } },
+    { "codeExecutionResult" : { "outcome" : "OUTCOME_FAILED", "output" : "ZeroDivisionError: division by zero" } },
+    { "executableCode" : { "language" : "PYTHON", "code" : "while True: pass" } },
+    { "codeExecutionResult" : { "outcome" : "OUTCOME_DEADLINE_EXCEEDED" } }
+  ] ) );
+
+  let executions = response.code_executions();
+  assert_eq!( executions.len(), 2 );
+  assert!( !executions[ 0 ].succeeded() );
+  assert_eq!( executions[ 0 ].output.as_deref(), Some( "ZeroDivisionError: division by zero" ) );
+  assert_eq!( executions[ 1 ].outcome.as_deref(), Some( "OUTCOME_DEADLINE_EXCEEDED" ) );
+  assert_eq!( executions[ 1 ].output, None );
+}
+
+#[ test ]
+fn test_unpaired_parts()
+{
+  let response = response( serde_json::json!(
+  [
+    { "codeExecutionResult" : { "outcome" : "OUTCOME_OK", "output" : "orphan" } },
+    { "executableCode" : { "language" : "PYTHON", "code" : "print( 1 )" } },
+    { "text" : "Stopped before running." }
+  ] ) );
+
+  let executions = response.code_executions();
+  assert_eq!( executions.len(), 1 );
+  assert_eq!( executions[ 0 ].code, "print( 1 )" );
+  assert_eq!( executions[ 0 ].outcome, None );
+  assert!( !executions[ 0 ].succeeded() );
+
+  assert!( self::response( serde_json::json!( [ { "text" : "No code here" } ] ) ).code_executions().is_empty() );
+}
+
+#[ test ]
+fn test_parts_round_trip()
+{
+  let part : Part = serde_json::from_value( serde_json::json!(
+  {
+    "executableCode" : { "language" : "PYTHON", "code" : "print( 2 )" }
+  } ) ).unwrap();
+  assert_eq!( part.executable_code.as_ref().unwrap().code, "print( 2 )" );
+  assert!( part.code_execution_result.is_none() );
+
+  let json = serde_json::to_value( &part ).unwrap();
+  assert_eq!( json, serde_json::json!( { "executableCode" : { "language" : "PYTHON", "code" : "print( 2 )" } } ) );
+}
@@ -56,6 +56,8 @@ let code_execution_config = CodeExecution {};
         function_response: None,
         file_data: None,
         video_metadata: None,
+        executable_code: None,
+        code_execution_result: None,
       } ],
       role: "user".to_string(),
     } ],
@@ -66,6 +68,8 @@ let code_execution_config = CodeExecution {};
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 2048 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     } ),
     safety_settings: None,
     tools: Some( tools ),
@@ -347,7 +347,7 @@ async fn integration_test_invalid_model_real_api()
   // Verify we get proper API error (not network error)
   match result.err().unwrap()
   {
-  Error::ApiError( _ ) | Error::InvalidArgument( _ ) => {}, // Expected API errors
+  Error::ApiError( _, _ ) | Error::InvalidArgument( _, _ ) => {}, // Expected API errors
   other => panic!( "Expected API error for invalid model, got : {other:?}" ),
   }
 }
@@ -378,7 +378,7 @@ async fn integration_test_empty_content_real_api()
   match result
   {
     Ok( response ) => assert!( !response.candidates.is_empty() ),
-  Err( Error::ApiError( _ ) | Error::InvalidArgument( _ ) ) => {}, // Expected validation errors
+  Err( Error::ApiError( _, _ ) | Error::InvalidArgument( _, _ ) ) => {}, // Expected validation errors
   Err( other ) => panic!( "Unexpected error for empty content : {other:?}" ),
   }
 }
@@ -0,0 +1,71 @@
+//! Configuration export and import tests
+//!
+//! Tests for `ConfigManager::export_version` and `ConfigManager::import_config`,
+//! used to promote a tested configuration from one environment to another.
+//!
+//! # Test Coverage
+//!
+//! - An exported version imports back to the same configuration
+//! - Unknown versions are rejected on export
+//! - Malformed and invalid JSON are rejected on import
+
+use api_gemini::client::Client;
+use api_gemini::error::Error;
+use api_gemini::models::config::*;
+use core::time::Duration;
+
+fn client() -> Client
+{
+  Client::builder()
+  .api_key( "test-key".to_string() )
+  .build()
+  .expect( "client should build" )
+}
+
+#[ tokio::test ]
+async fn exported_version_round_trips()
+{
+  let manager = client().create_config_manager();
+  let staging = DynamicConfig::builder()
+  .timeout( Duration::from_secs( 45 ) )
+  .retry_attempts( 5 )
+  .base_url( "https://staging.example.com".to_string() )
+  .tag( "environment".to_string(), "staging".to_string() )
+  .build()
+  .expect( "config should be valid" );
+  manager.apply_update( manager.update( staging ) ).await.expect( "update should apply" );
+
+  let entry = manager.history().last().cloned().expect( "history is never empty" );
+  let json = manager.export_version( &entry.version_id ).expect( "version should export" );
+  let imported = ConfigManager::import_config( &json ).expect( "export should import" );
+
+  assert_eq!( imported, DynamicConfig { validation_hash : None, ..entry.config } );
+  assert_eq!( imported.tags.get( "environment" ).map( String::as_str ), Some( "staging" ) );
+
+  // The imported config is ready to roll out elsewhere
+  let production = client().create_config_manager();
+  let promoted = production.apply_update( production.update( imported ) ).await.expect( "imported config should apply" );
+  assert_eq!( promoted.current_config().base_url, "https://staging.example.com" );
+}
+
+#[ test ]
+fn unknown_version_is_rejected()
+{
+  let manager = client().create_config_manager();
+  assert!( manager.export_version( "v0" ).is_ok() );
+  assert!( matches!( manager.export_version( "v42" ), Err( Error::ConfigurationError( _ ) ) ) );
+}
+
+#[ test ]
+fn invalid_import_is_rejected()
+{
+  assert!( matches!( ConfigManager::import_config( "{ not json" ), Err( Error::ConfigurationError( _ ) ) ) );
+
+  let mut json : serde_json::Value = serde_json::from_str(
+    &client().create_config_manager().export_version( "v0" ).expect( "initial version should export" )
+  ).expect( "export should be JSON" );
+  json[ "retry_attempts" ] = serde_json::json!( 500 );
+
+  let result = ConfigManager::import_config( &json.to_string() );
+  assert!( matches!( result, Err( Error::ConfigurationError( msg ) ) if msg.contains( "Retry attempts" ) ) );
+}
@@ -0,0 +1,81 @@
+//! Configuration history integrity tests
+//!
+//! Tests that history deltas reconstruct the configuration they replace, and that
+//! a crafted broken delta is detected rather than trusted.
+//!
+//! # Test Coverage
+//!
+//! - `ConfigDelta::apply` reconstructs the target configuration
+//! - A delta with an undecodable or wrong value does not round-trip
+//! - `compress_history` followed by `verify_history_integrity` succeeds
+
+use api_gemini::client::Client;
+use api_gemini::models::config::*;
+use core::time::Duration;
+
+fn config( timeout_secs : u64, retry_attempts : u32 ) -> DynamicConfig
+{
+  DynamicConfig::builder()
+  .timeout( Duration::from_secs( timeout_secs ) )
+  .retry_attempts( retry_attempts )
+  .tag( "team".to_string(), "search".to_string() )
+  .build()
+  .expect( "config should be valid" )
+}
+
+#[ test ]
+fn delta_reconstructs_target()
+{
+  let base = DynamicConfig::default();
+  let target = config( 45, 7 );
+
+  let delta = ConfigDelta::create_delta( &base, &target );
+  let reconstructed = delta.apply( &base );
+
+  assert_eq!( reconstructed.timeout, Duration::from_secs( 45 ) );
+  assert_eq!( reconstructed.retry_attempts, 7 );
+  assert_eq!( reconstructed.tags.get( "team" ).map( String::as_str ), Some( "search" ) );
+  assert!( delta.reconstructs( &base, &target ) );
+}
+
+#[ test ]
+fn broken_delta_is_detected()
+{
+  let base = DynamicConfig::default();
+  let target = config( 45, 7 );
+
+  let mut undecodable = ConfigDelta::create_delta( &base, &target );
+  undecodable.changed_fields.insert( "timeout".to_string(), serde_json::json!( "forty-five" ) );
+  assert!( undecodable.apply_delta( &base ).is_err() );
+  assert_eq!( undecodable.apply( &base ).timeout, base.timeout );
+  assert!( !undecodable.reconstructs( &base, &target ) );
+
+  let mut wrong = ConfigDelta::create_delta( &base, &target );
+  wrong.changed_fields.insert( "retry_attempts".to_string(), serde_json::json!( 2 ) );
+  assert!( !wrong.reconstructs( &base, &target ) );
+
+  let mut entry = ConfigHistoryEntry::from_config( target, ConfigChangeType::Update, "v1".to_string() );
+  entry.delta = Some( wrong );
+  assert!( !entry.verify_delta( &base ) );
+}
+
+#[ tokio::test ]
+async fn compressed_history_verifies()
+{
+  let client = Client::builder()
+  .api_key( "test-key".to_string() )
+  .build()
+  .expect( "client should build" );
+  let manager = client.create_config_manager();
+
+  for ( timeout, retries ) in [ ( 45, 4 ), ( 60, 6 ), ( 90, 2 ) ]
+  {
+    manager.apply_update( manager.update( config( timeout, retries ) ) ).await.expect( "update should apply" );
+  }
+
+  manager.compress_history().expect( "compression should succeed" );
+  let history = manager.history();
+  assert_eq!( history.len(), 4 );
+  assert!( history.iter().skip( 1 ).any( ConfigHistoryEntry::can_compress ) );
+  assert!( manager.verify_history_integrity().is_ok() );
+}
@@ -0,0 +1,172 @@
+//! Configuration propagation tests
+//!
+//! Tests that `ConfigManager::apply_update_with_report` hands the new settings to the
+//! subsystems that read them on every request, and reports which ones changed.
+//! Failing responses come from `MockTransport`; the timeout test needs a local
+//! server that never answers.
+//!
+//! # Test Coverage
+//!
+//! - Changed `retry_attempts` changes the number of attempts against a failing server
+//! - Only subsystems whose settings differ are reported
+//! - Applied timeout is visible through the client configuration
+//! - Sub-second timeouts reach requests without truncation
+//! - Changed circuit breaker threshold replaces the live breaker
+//! - Changed rate limit is applied and reported
+
+#![ cfg( all( feature = "dynamic_configuration", feature = "retry" ) ) ]
+
+use api_gemini::client::{ Client, ClientBuilder };
+use api_gemini::models::config::*;
+use api_gemini::{ testing::MockTransport, ResponseParts };
+use core::time::Duration;
+use reqwest::Method;
+
+mod local_server;
+use local_server::serve_silent;
+
+/// Base URL for clients answered by `MockTransport`; nothing listens on port 9
+const UNREACHABLE : &str = "http://127.0.0.1:9";
+
+fn builder( base_url : &str ) -> ClientBuilder
+{
+  Client::builder()
+  .api_key( "test-key".to_string() )
+  .base_url( base_url.to_string() )
+  .timeout( Duration::from_secs( 30 ) )
+  .max_retries( 0 )
+  .base_delay( Duration::from_millis( 1 ) )
+  .max_delay( Duration::from_millis( 5 ) )
+}
+
+fn client( base_url : &str ) -> Client
+{
+  builder( base_url ).build().expect( "client should build" )
+}
+
+/// Answer every model listing with `503 Service Unavailable`
+fn unavailable() -> MockTransport
+{
+  MockTransport::new().on( Method::GET, "/v1beta/models", ResponseParts::new( 503, "service unavailable" ) )
+}
+
+fn retry_config( base_url : &str, retry_attempts : u32 ) -> DynamicConfig
+{
+  DynamicConfig::builder()
+  .base_url( base_url.to_string() )
+  .timeout( Duration::from_secs( 30 ) )
+  .retry_attempts( retry_attempts )
+  .base_retry_delay( Duration::from_millis( 1 ) )
+  .max_retry_delay( Duration::from_millis( 5 ) )
+  .enable_jitter( false )
+  .build()
+  .expect( "config should be valid" )
+}
+
+#[ tokio::test ]
+async fn changed_retry_attempts_drive_retry_behavior()
+{
+  let mock = unavailable();
+  let client = builder( UNREACHABLE ).with_interceptor( mock.clone() ).build().expect( "client should build" );
+
+  assert!( client.models().list().await.is_err() );
+  assert_eq!( mock.calls().len(), 1, "no retries before the update" );
+
+  let manager = client.config();
+  let report = manager.apply_update_with_report( manager.update( retry_config( UNREACHABLE, 2 ) ) ).await
+  .expect( "update should apply" );
+  assert!( report.was_updated( ConfigSubsystem::Retry ) );
+
+  assert!( report.client.models().list().await.is_err() );
+  assert_eq!( mock.calls().len(), 4, "one attempt plus two retries after the update" );
+}
+
+#[ tokio::test ]
+async fn report_lists_only_changed_subsystems()
+{
+  let base_url = "http://127.0.0.1:1";
+  let client = client( base_url );
+  let manager = client.config();
+
+  let report = manager.apply_update_with_report( manager.update( client.current_config() ) ).await.expect( "update should apply" );
+  assert!( report.updated.is_empty() );
+
+  let config = DynamicConfig
+  {
+    timeout : Duration::from_secs( 90 ),
+    ..retry_config( "http://127.0.0.1:2", 4 )
+  };
+  let report = manager.apply_update_with_report( manager.update( config ) ).await.expect( "update should apply" );
+  assert_eq!( report.updated, vec![ ConfigSubsystem::Endpoint, ConfigSubsystem::Timeout, ConfigSubsystem::Retry ] );
+  assert_eq!( report.client.current_config().timeout, Duration::from_secs( 90 ) );
+  assert_eq!( report.client.current_config().retry_attempts, 4 );
+}
+
+#[ tokio::test ]
+async fn sub_second_timeout_reaches_requests()
+{
+  // The server accepts the connection, but nothing ever answers
+  let base_url = serve_silent( Duration::from_secs( 10 ) );
+  let client = client( &base_url );
+  let manager = client.config();
+
+  let config = DynamicConfig { timeout : Duration::from_millis( 300 ), ..retry_config( &base_url, 0 ) };
+  let report = manager.apply_update_with_report( manager.update( config ) ).await.expect( "update should apply" );
+  assert!( report.was_updated( ConfigSubsystem::Timeout ) );
+
+  let started = std::time::Instant::now();
+  let result = tokio::time::timeout( Duration::from_secs( 10 ), report.client.models().list() ).await
+  .expect( "the applied timeout should end the request" );
+  assert!( result.is_err() );
+  assert!( started.elapsed() < Duration::from_secs( 5 ), "took {:?}", started.elapsed() );
+}
+
+#[ cfg( feature = "circuit_breaker" ) ]
+#[ tokio::test ]
+async fn changed_circuit_breaker_threshold_replaces_live_breaker()
+{
+  let mock = unavailable();
+  let client = Client::builder()
+  .api_key( "test-key".to_string() )
+  .base_url( UNREACHABLE.to_string() )
+  .with_interceptor( mock.clone() )
+  .max_retries( 0 )
+  .enable_circuit_breaker( true )
+  .circuit_breaker_failure_threshold( 5 )
+  .circuit_breaker_timeout( Duration::from_secs( 60 ) )
+  .build()
+  .expect( "client should build" );
+  let manager = client.config();
+
+  let config = DynamicConfig { circuit_breaker_failure_threshold : Some( 1 ), ..retry_config( UNREACHABLE, 0 ) };
+  let report = manager.apply_update_with_report( manager.update( config ) ).await.expect( "update should apply" );
+  assert!( report.was_updated( ConfigSubsystem::CircuitBreaker ) );
+  assert_eq!( report.client.current_config().circuit_breaker_failure_threshold, Some( 1 ) );
+
+  assert!( report.client.models().list().await.is_err() );
+  let error = report.client.models().list().await.unwrap_err();
+  assert!( matches!( error, api_gemini::error::Error::CircuitBreakerOpen( _ ) ), "{error:?}" );
+  assert_eq!( mock.calls().len(), 1, "the breaker should open after one failure" );
+}
+
+#[ cfg( feature = "rate_limiting" ) ]
+#[ tokio::test ]
+async fn changed_rate_limit_is_applied()
+{
+  let base_url = "http://127.0.0.1:1";
+  let client = client( base_url );
+  let manager = client.config();
+
+  let config = DynamicConfig
+  {
+    rate_limit_requests_per_second : Some( 2.5 ),
+    rate_limit_bucket_size : Some( 3 ),
+    ..retry_config( base_url, 0 )
+  };
+  let report = manager.apply_update_with_report( manager.update( config ) ).await.expect( "update should apply" );
+
+  assert!( report.was_updated( ConfigSubsystem::RateLimit ) );
+  let applied = report.client.current_config();
+  assert_eq!( applied.rate_limit_requests_per_second, Some( 2.5 ) );
+  assert_eq!( applied.rate_limit_bucket_size, Some( 3 ) );
+}
@@ -0,0 +1,130 @@
+//! Configuration file watching tests
+//!
+//! Each test writes a configuration file into its own temporary directory, starts
+//! `ConfigManager::watch_file` and rewrites the file to simulate an edit.
+//!
+//! # Test Coverage
+//!
+//! - A change to the watched file is applied and fires a change notification
+//! - An unparseable save is skipped without a notification
+//! - Dropping the handle stops watching
+
+#![ cfg( feature = "dynamic_configuration" ) ]
+
+use api_gemini::client::Client;
+use api_gemini::models::config::*;
+use core::time::Duration;
+use std::path::{ Path, PathBuf };
+use tokio::sync::mpsc;
+use tokio::time::timeout;
+
+fn client() -> Client
+{
+  Client::builder()
+  .api_key( "test-key".to_string() )
+  .build()
+  .expect( "client should build" )
+}
+
+fn config_json( base_url : &str ) -> String
+{
+  serde_json::json!( {
+    "timeout_seconds" : 30,
+    "retry_attempts" : 3,
+    "base_url" : base_url,
+  } ).to_string()
+}
+
+fn config_dir( name : &str ) -> PathBuf
+{
+  let dir = std::env::temp_dir().join( format!( "gemini_watch_{}_{}", name, std::process::id() ) );
+  std::fs::create_dir_all( &dir ).expect( "temp dir should be created" );
+  dir
+}
+
+async fn write( path : &Path, content : &str )
+{
+  tokio::fs::write( path, content ).await.expect( "config file should be written" );
+}
+
+#[ tokio::test ]
+async fn file_change_applies_config_and_notifies()
+{
+  let dir = config_dir( "applies" );
+  let path = dir.join( "config.json" );
+  write( &path, &config_json( "https://initial.example.com" ) ).await;
+
+  let manager = client().create_config_manager();
+  let ( tx, mut rx ) = mpsc::unbounded_channel();
+  let _listener = manager.on_change( move | event | { let _ = tx.send( event ); } );
+
+  let handle = manager.watch_file( &path ).expect( "watch should start" );
+  assert_eq!( handle.path(), path.as_path() );
+
+  write( &path, &config_json( "https://updated.example.com" ) ).await;
+
+  let event = timeout( Duration::from_secs( 5 ), rx.recv() ).await
+  .expect( "notification should fire" )
+  .expect( "listener channel should stay open" );
+  assert_eq!( event.change_type, ConfigChangeType::Update );
+  assert_eq!( event.new_config.base_url, "https://updated.example.com" );
+
+  let latest = manager.history().last().cloned().expect( "history is never empty" );
+  assert_eq!( latest.config.base_url, "https://updated.example.com" );
+
+  drop( handle );
+  let _ = std::fs::remove_dir_all( dir );
+}
+
+#[ tokio::test ]
+async fn invalid_save_is_skipped()
+{
+  let dir = config_dir( "invalid" );
+  let path = dir.join( "config.json" );
+  write( &path, &config_json( "https://initial.example.com" ) ).await;
+
+  let manager = client().create_config_manager();
+  let ( tx, mut rx ) = mpsc::unbounded_channel();
+  let _listener = manager.on_change( move | event | { let _ = tx.send( event ); } );
+  let _handle = manager.watch_file( &path ).expect( "watch should start" );
+
+  write( &path, "{ not json" ).await;
+  assert!( timeout( Duration::from_millis( 500 ), rx.recv() ).await.is_err() );
+  assert_eq!( manager.history().len(), 1 );
+
+  // The watch survives the bad save
+  write( &path, &config_json( "https://fixed.example.com" ) ).await;
+  let event = timeout( Duration::from_secs( 5 ), rx.recv() ).await
+  .expect( "notification should fire" )
+  .expect( "listener channel should stay open" );
+  assert_eq!( event.new_config.base_url, "https://fixed.example.com" );
+
+  let _ = std::fs::remove_dir_all( dir );
+}
+
+#[ tokio::test ]
+async fn dropping_handle_stops_watching()
+{
+  let dir = config_dir( "dropped" );
+  let path = dir.join( "config.json" );
+  write( &path, &config_json( "https://initial.example.com" ) ).await;
+
+  let manager = client().create_config_manager();
+  let ( tx, mut rx ) = mpsc::unbounded_channel();
+  let _listener = manager.on_change( move | event | { let _ = tx.send( event ); } );
+
+  drop( manager.watch_file( &path ).expect( "watch should start" ) );
+
+  write( &path, &config_json( "https://ignored.example.com" ) ).await;
+  assert!( timeout( Duration::from_millis( 500 ), rx.recv() ).await.is_err() );
+  assert_eq!( manager.history().len(), 1 );
+
+  let _ = std::fs::remove_dir_all( dir );
+}
+
+#[ tokio::test ]
+async fn path_without_file_name_is_rejected()
+{
+  let manager = client().create_config_manager();
+  assert!( manager.watch_file( "/" ).is_err() );
+}
@@ -0,0 +1,102 @@
+//! Content builder tests
+//!
+//! Tests for `ContentBuilder`, the typed alternative to constructing `Part`
+//! values by hand with `..Default::default()`.
+//!
+//! # Test Coverage
+//!
+//! - Mixed text and image content with parts in insertion order
+//! - File data and function response parts
+//! - Rejection of empty content and empty MIME types
+//! - Rejection of function responses mixed with other parts or in a model turn
+
+use api_gemini::{ error::Error, models::ContentBuilder };
+use base64::Engine as _;
+
+const PNG_HEADER : [ u8; 8 ] = [ 0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A ];
+
+#[ test ]
+fn test_mixed_text_and_image_content()
+{
+  let content = ContentBuilder::user()
+  .text( "Describe this image" )
+  .inline_data( "image/png", &PNG_HEADER )
+  .build()
+  .expect( "text and image parts should be accepted" );
+
+  assert_eq!( content.role, "user" );
+  assert_eq!( content.parts.len(), 2 );
+
+  assert_eq!( content.parts[ 0 ].text.as_deref(), Some( "Describe this image" ) );
+  assert!( content.parts[ 0 ].inline_data.is_none() );
+
+  let blob = content.parts[ 1 ].inline_data.as_ref().expect( "second part should be inline data" );
+  assert!( content.parts[ 1 ].text.is_none() );
+  assert_eq!( blob.mime_type, "image/png" );
+  assert_eq!( base64::engine::general_purpose::STANDARD.decode( &blob.data ).unwrap(), PNG_HEADER );
+}
+
+#[ test ]
+fn test_file_data_part()
+{
+  let content = ContentBuilder::model()
+  .file_data( "https://generativelanguage.googleapis.com/v1beta/files/abc", "video/mp4" )
+  .text( "Summary follows" )
+  .build()
+  .expect( "file data should be accepted" );
+
+  assert_eq!( content.role, "model" );
+  let file = content.parts[ 0 ].file_data.as_ref().expect( "first part should be file data" );
+  assert_eq!( file.uri.as_deref(), Some( "https://generativelanguage.googleapis.com/v1beta/files/abc" ) );
+  assert_eq!( file.mime_type.as_deref(), Some( "video/mp4" ) );
+}
+
+#[ test ]
+fn test_function_response_parts()
+{
+  let content = ContentBuilder::user()
+  .function_response( "get_weather", serde_json::json!( { "temperature" : 21 } ) )
+  .function_response( "get_time", serde_json::json!( { "time" : "12:00" } ) )
+  .build()
+  .expect( "function responses alone should be accepted" );
+
+  assert_eq!( content.parts.len(), 2 );
+  let response = content.parts[ 0 ].function_response.as_ref().unwrap();
+  assert_eq!( response.name, "get_weather" );
+  assert_eq!( response.response[ "temperature" ], 21 );
+}
+
+#[ test ]
+fn test_empty_content_is_rejected()
+{
+  let result = ContentBuilder::user().build();
+  assert!( matches!( result, Err( Error::InvalidArgument( ref msg, _ ) ) if msg.contains( "at least one part" ) ) );
+}
+
+#[ test ]
+fn test_empty_mime_type_is_rejected()
+{
+  let result = ContentBuilder::user().inline_data( " ", &PNG_HEADER ).build();
+  assert!( matches!( result, Err( Error::InvalidArgument( ref msg, _ ) ) if msg.contains( "MIME type" ) ) );
+}
+
+#[ test ]
+fn test_function_response_mixed_with_text_is_rejected()
+{
+  let result = ContentBuilder::user()
+  .text( "Here is the result" )
+  .function_response( "get_weather", serde_json::json!( {} ) )
+  .build();
+
+  assert!( matches!( result, Err( Error::InvalidArgument( ref msg, _ ) ) if msg.contains( "cannot be mixed" ) ) );
+}
+
+#[ test ]
+fn test_function_response_in_model_turn_is_rejected()
+{
+  let result = ContentBuilder::model()
+  .function_response( "get_weather", serde_json::json!( {} ) )
+  .build();
+
+  assert!( matches!( result, Err( Error::InvalidArgument( ref msg, _ ) ) if msg.contains( "user turn" ) ) );
+}
@@ -0,0 +1,116 @@
+//! Tests for deriving a `CountTokensRequest` from a `GenerateContentRequest`.
+//!
+//! The derivation tests are pure; the integration test compares a plain contents
+//! count with a count of the full payload and requires `GEMINI_API_KEY`.
+
+use api_gemini::models::*;
+use api_gemini::validation::validate_count_tokens_request;
+
+fn generate_request() -> GenerateContentRequest
+{
+  GenerateContentRequest
+  {
+    contents : vec![
+      Content
+      {
+        parts : vec![ Part { text : Some( "What's the weather in Paris?".to_string() ), ..Default::default() } ],
+        role : "user".to_string(),
+      },
+    ],
+    system_instruction : Some( SystemInstruction
+    {
+      role : "system".to_string(),
+      parts : vec![ Part { text : Some( "You are a concise weather assistant.".to_string() ), ..Default::default() } ],
+    } ),
+    tools : Some( vec![ Tool
+    {
+      function_declarations : Some( vec![ FunctionDeclaration
+      {
+        name : "get_weather".to_string(),
+        description : "Get the current weather for a city".to_string(),
+        parameters : Some( serde_json::json!( {
+          "type" : "object",
+          "properties" : { "city" : { "type" : "string" } },
+          "required" : [ "city" ]
+        } ) ),
+      } ] ),
+      code_execution : None,
+      google_search_retrieval : None,
+      code_execution_tool : None,
+    } ] ),
+    ..Default::default()
+  }
+}
+
+#[ test ]
+fn derived_request_carries_contents_system_instruction_and_tools()
+{
+  let request = generate_request();
+  let count_request = CountTokensRequest::from_generate_request( &request );
+
+  assert!( count_request.contents.is_empty(), "contents must not be sent alongside generateContentRequest" );
+
+  let embedded = count_request.generate_content_request.expect( "full request should be embedded" );
+  assert_eq!( embedded.contents.len(), request.contents.len() );
+  assert_eq!( embedded.contents[ 0 ].parts[ 0 ].text, request.contents[ 0 ].parts[ 0 ].text );
+  assert_eq!(
+    embedded.system_instruction.as_ref().and_then( | si | si.parts[ 0 ].text.clone() ),
+    Some( "You are a concise weather assistant.".to_string() )
+  );
+  let tools = embedded.tools.expect( "tools should be carried over" );
+  assert_eq!( tools[ 0 ].function_declarations.as_ref().unwrap()[ 0 ].name, "get_weather" );
+}
+
+#[ test ]
+fn derived_request_serializes_system_instruction()
+{
+  let count_request = CountTokensRequest::from_generate_request( &generate_request() );
+  let json = serde_json::to_value( &count_request ).expect( "request should serialize" );
+
+  assert_eq!(
+    json[ "generateContentRequest" ][ "systemInstruction" ][ "parts" ][ 0 ][ "text" ],
+    "You are a concise weather assistant."
+  );
+  assert!( json[ "generateContentRequest" ][ "tools" ].is_array() );
+  assert!( json.get( "contents" ).is_none(), "only generateContentRequest should be sent : {json}" );
+}
+
+#[ test ]
+fn derived_request_passes_validation_and_rejects_both_sources()
+{
+  let request = generate_request();
+  let count_request = CountTokensRequest::from_generate_request( &request );
+  assert!( validate_count_tokens_request( &count_request ).is_ok() );
+
+  let both = CountTokensRequest { contents : request.contents.clone(), ..count_request };
+  let error = validate_count_tokens_request( &both ).unwrap_err();
+  assert!( error.to_string().contains( "generate_content_request" ), "{error}" );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_full_payload_counts_more_tokens_than_contents()
+{
+  let client = api_gemini::client::Client::new().unwrap_or_else( | error | panic!(
+    "\n❌ INTEGRATION TEST FAILURE: No valid API key found!\n\
+    \n🔑 Required: Set GEMINI_API_KEY environment variable or create secret/-secrets.sh\n\
+    \n🚫 Error details : {error:?}\n"
+  ) );
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash" );
+  let request = generate_request();
+
+  let contents_only = CountTokensRequest { contents : request.contents.clone(), ..Default::default() };
+  let contents_count = model.count_tokens( &contents_only ).await.expect( "contents count should succeed" );
+  let full_count = model
+    .count_tokens( &CountTokensRequest::from_generate_request( &request ) )
+    .await
+    .expect( "full payload count should succeed" );
+
+  assert!(
+    full_count.total_tokens > contents_count.total_tokens,
+    "system instruction and tools should add tokens : {} vs {}",
+    full_count.total_tokens,
+    contents_count.total_tokens
+  );
+}
@@ -294,7 +294,7 @@ async fn test_count_tokens_different_models()
           {
         println!( "⚠️  Authentication error for {model} (expected without API key): {e}" );
           },
-          Error::InvalidArgument( _ ) =>
+          Error::InvalidArgument( _, _ ) =>
           {
           println!( "⚠️  Model {model} not available, skipping" );
           },
@@ -352,7 +352,7 @@ async fn test_count_tokens_error_handling()
     {
       match e
       {
-        Error::InvalidArgument( _ ) => println!( "✅ Correctly rejected invalid model" ),
+        Error::InvalidArgument( _, _ ) => println!( "✅ Correctly rejected invalid model" ),
         Error::AuthenticationError( _ ) => println!( "⚠️  Authentication error (API key needed to test invalid model)" ),
         Error::ServerError( _ ) => println!( "✅ Server correctly rejected invalid model" ),
       _ => println!( "⚠️  Unexpected error type for invalid model : {e:?}" ),
@@ -473,7 +473,7 @@ async fn test_count_tokens_rate_limiting()
       {
         match e
         {
-          Error::RateLimitError( _ ) =>
+          Error::RateLimitError( .. ) =>
           {
           println!( "⚠️  Request {i} hit rate limit (expected behavior)" );
           },
@@ -0,0 +1,108 @@
+//! Default model tests
+//!
+//! Tests for `Client::with_default_model` and the `Client::generate` convenience.
+//!
+//! # Test Coverage
+//!
+//! - Default model is carried by the clone and not by the original client
+//! - `generate` without a default model is rejected
+//! - `generate` targets the default model, while `by_name` still overrides it
+//!   (verified by an interceptor recording the request)
+
+use api_gemini::{ client::Client, error::Error, models::{ Content, GenerateContentRequest, Part }, Interceptor, RequestParts, ResponseParts };
+use std::sync::{ Arc, Mutex };
+
+fn request() -> GenerateContentRequest
+{
+  GenerateContentRequest
+  {
+    contents : vec![ Content
+    {
+      parts : vec![ Part { text : Some( "Hello".to_string() ), ..Default::default() } ],
+      role : "user".to_string(),
+    } ],
+    ..Default::default()
+  }
+}
+
+/// Records each request as `METHOD url` and answers it with an empty generation
+#[ derive( Debug, Default, Clone ) ]
+struct Capture
+{
+  requests : Arc< Mutex< Vec< String > > >,
+}
+
+impl Interceptor for Capture
+{
+  fn before_request( &self, request : &mut RequestParts )
+  {
+    self.requests.lock().unwrap().push( format!( "{} {}", request.method, request.url ) );
+    request.respond_with( ResponseParts::new( 200, r#"{ "candidates" : [] }"# ) );
+  }
+
+  fn after_response( &self, _response : &ResponseParts ) {}
+}
+
+fn client( base_url : String ) -> Client
+{
+  Client::builder()
+  .api_key( "test-key".to_string() )
+  .base_url( base_url )
+  .build()
+  .expect( "client should build" )
+}
+
+fn capturing_client( capture : &Capture ) -> Client
+{
+  Client::builder()
+  .api_key( "test-key".to_string() )
+  .base_url( "http://127.0.0.1:9".to_string() )
+  .with_interceptor( capture.clone() )
+  .build()
+  .expect( "client should build" )
+}
+
+#[ test ]
+fn test_with_default_model_returns_configured_clone()
+{
+  let client = client( "http://127.0.0.1:9".to_string() );
+  let flash = client.with_default_model( "gemini-2.5-flash" );
+
+  assert_eq!( flash.default_model(), Some( "gemini-2.5-flash" ) );
+  assert_eq!( client.default_model(), None );
+  assert_eq!( flash.with_default_model( "gemini-2.5-pro" ).default_model(), Some( "gemini-2.5-pro" ) );
+}
+
+#[ tokio::test ]
+async fn test_generate_without_default_model_is_rejected()
+{
+  let client = client( "http://127.0.0.1:9".to_string() );
+
+  match client.generate( &request() ).await
+  {
+    Err( Error::InvalidArgument( msg, _ ) ) => assert!( msg.contains( "No default model set" ) ),
+    other => panic!( "Expected InvalidArgument, got : {other:?}" ),
+  }
+}
+
+#[ tokio::test ]
+async fn test_generate_uses_default_model()
+{
+  let capture = Capture::default();
+  let client = capturing_client( &capture ).with_default_model( "gemini-2.5-flash" );
+
+  client.generate( &request() ).await.expect( "fixture should answer" );
+
+  assert_eq!( *capture.requests.lock().unwrap(), vec![ "POST http://127.0.0.1:9/v1beta/models/gemini-2.5-flash:generateContent".to_string() ] );
+}
+
+#[ tokio::test ]
+async fn test_by_name_overrides_default_model()
+{
+  let capture = Capture::default();
+  let client = capturing_client( &capture ).with_default_model( "gemini-2.5-flash" );
+
+  client.models().by_name( "gemini-2.5-pro" ).generate_content( &request() ).await.expect( "fixture should answer" );
+
+  assert_eq!( *capture.requests.lock().unwrap(), vec![ "POST http://127.0.0.1:9/v1beta/models/gemini-2.5-pro:generateContent".to_string() ] );
+}
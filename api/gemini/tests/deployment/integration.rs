@@ -311,7 +311,7 @@ async fn test_deployment_validation_error_handling() -> Result< (), Box< dyn std
 
   match result
   {
-    Err( Error::ApiError( msg ) ) => {
+    Err( Error::ApiError( msg, _ ) ) => {
       assert!( msg.contains( "Deployment name is required" ) );
     println!( "✓ Missing deployment name properly rejected : {}", msg );
     },
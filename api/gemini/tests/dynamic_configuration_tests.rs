@@ -31,10 +31,8 @@ mod integration_tests
     // Apply configuration update at runtime (without restart)
     let updated_client = client.config().update( new_config ).apply().await?;
 
-    // NOTE: Timeout application to HTTP client is not yet implemented (xxx : in client.rs:1702)
-    // The current_config() method returns hardcoded 30s timeout
     let current_config = updated_client.config().current();
-    assert_eq!( current_config.timeout, Duration::from_secs( 30 ) ); // Still hardcoded
+    assert_eq!( current_config.timeout, new_timeout );
 
     // Other configuration fields that are implemented should work
     assert_eq!( current_config.base_url, "https://generativelanguage.googleapis.com" );
@@ -142,8 +140,7 @@ mod integration_tests
     .build()?;
 
     let client_v1 = client.config().update( config1 ).apply().await?;
-    // NOTE: Timeout application not yet implemented
-    assert_eq!( client_v1.config().current().timeout, Duration::from_secs( 30 ) );
+    assert_eq!( client_v1.config().current().timeout, Duration::from_secs( 45 ) );
 
     // Apply another configuration change
     let config2 = DynamicConfig::builder()
@@ -152,8 +149,7 @@ mod integration_tests
     .build()?;
 
     let client_v2 = client_v1.config().update( config2 ).apply().await?;
-    // NOTE: Timeout application not yet implemented
-    assert_eq!( client_v2.config().current().timeout, Duration::from_secs( 30 ) );
+    assert_eq!( client_v2.config().current().timeout, Duration::from_secs( 90 ) );
 
     // NOTE: History tracking is not yet implemented (xxx : in config.rs:303-305)
     // Rollback will fail with "No previous configuration to rollback to"
@@ -214,8 +210,7 @@ mod integration_tests
     let client = Client::new()?;
     let updated_client = client.config().load_from_file( &temp_file ).await?;
     let current_config = updated_client.config().current();
-    // NOTE: Timeout application not yet implemented, still returns hardcoded 30s
-    assert_eq!( current_config.timeout, Duration::from_secs( 30 ) );
+    assert_eq!( current_config.timeout, Duration::from_secs( 45 ) );
     assert_eq!( current_config.base_url, "https://file-config.googleapis.com" );
 
     println!( "✓ File configuration successfully applied to client" );
@@ -272,7 +267,7 @@ mod integration_tests
 
     // Verify the configuration change affected the client
     let current_config = updated_client.config().current();
-    assert_eq!( current_config.timeout, Duration::from_secs( 30 ) ); // Timeout not yet implemented
+    assert_eq!( current_config.timeout, Duration::from_secs( 30 ) );
     // NOTE: retry_attempts should be updated since it's implemented
     #[ cfg(feature = "retry") ]
     assert_eq!( current_config.retry_attempts, 4 );
@@ -332,7 +327,7 @@ mod integration_tests
             Ok( ( i, current_config.timeout, current_config.retry_attempts ) )
           },
           Ok( Err( e ) ) => Err( e ),
-          Err( _ ) => Err( Error::ApiError( "Timeout during concurrent update".to_string() ) ),
+          Err( _ ) => Err( Error::ApiError( "Timeout during concurrent update".to_string(), None ) ),
         }
       });
 
@@ -475,8 +470,7 @@ println!( "✓ Concurrent configuration updates completed : {} successful, {} fa
     .build()?;
 
     let timeout_client = retry_client.config().update( timeout_config ).apply().await?;
-    // NOTE: Timeout application not yet implemented
-    assert_eq!( timeout_client.config().current().timeout, Duration::from_secs( 30 ) ); // Still hardcoded
+    assert_eq!( timeout_client.config().current().timeout, Duration::from_secs( 5 ) );
 
     println!( "✓ Timeout configuration properly integrated" );
 
@@ -51,6 +51,8 @@ fn test_chat_example_structure()
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     }),
     safety_settings: Some( vec!
     [
@@ -370,6 +372,8 @@ async fn integration_test_chat_example_real_api()
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 600 ), // Increased to avoid truncation
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     }),
     safety_settings: Some( vec!
     [
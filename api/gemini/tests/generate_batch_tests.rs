@@ -0,0 +1,181 @@
+//! Generate batch tests
+//!
+//! Tests for `Client::generate_batch`, answered by `MockTransport` routes. Each
+//! model's first attempt fails with 503, so a request stays in flight across a
+//! retry delay; an interceptor wrapping the transport counts requests in flight.
+//!
+//! # Test Coverage
+//!
+//! - Results come back in input order with their tags
+//! - No more than `max_concurrent` requests are in flight
+//! - A failing request does not affect the others
+//! - A `max_concurrent` of 0 still makes progress
+
+#![ cfg( feature = "retry" ) ]
+
+use api_gemini::{ client::Client, error::Error, models::{ ContentBuilder, GenerateContentRequest }, testing::MockTransport, Interceptor, RequestParts, ResponseParts };
+use std::collections::HashSet;
+use std::sync::{ Arc, Mutex };
+use std::time::Duration;
+
+const UNAVAILABLE : &str = r#"{ "error" : { "code" : 503, "message" : "overloaded", "status" : "UNAVAILABLE" } }"#;
+const NOT_FOUND : &str = r#"{ "error" : { "code" : 404, "message" : "model not found", "status" : "NOT_FOUND" } }"#;
+
+/// Requests between their first attempt and their final response
+#[ derive( Debug, Default ) ]
+struct InFlightState
+{
+  active : HashSet< String >,
+  peak : usize,
+}
+
+/// Answers through `mock` and counts requests in flight
+#[ derive( Debug, Default, Clone ) ]
+struct InFlight
+{
+  mock : MockTransport,
+  state : Arc< Mutex< InFlightState > >,
+}
+
+impl InFlight
+{
+  fn peak( &self ) -> usize
+  {
+    self.state.lock().unwrap().peak
+  }
+}
+
+impl Interceptor for InFlight
+{
+  fn before_request( &self, request : &mut RequestParts )
+  {
+    {
+      let mut state = self.state.lock().unwrap();
+      state.active.insert( request.url.clone() );
+      state.peak = state.peak.max( state.active.len() );
+    }
+    self.mock.before_request( request );
+  }
+
+  fn after_response( &self, _response : &ResponseParts ) {}
+
+  fn after_exchange( &self, request : &RequestParts, response : &ResponseParts )
+  {
+    // A 503 is retried, so the request stays in flight
+    if response.status != 503
+    {
+      self.state.lock().unwrap().active.remove( &request.url );
+    }
+  }
+}
+
+fn answer( model : &str ) -> ResponseParts
+{
+  let body = serde_json::json!(
+  {
+    "candidates" : [ { "content" : { "role" : "model", "parts" : [ { "text" : format!( "from {model}" ) } ] } } ]
+  } );
+  ResponseParts::new( 200, body.to_string() )
+}
+
+/// Routes failing once with 503 before answering, except `missing-model` which is always 404
+fn in_flight( models : &[ &str ] ) -> InFlight
+{
+  let mock = models.iter().fold( MockTransport::new(), | mock, model |
+  {
+    if *model == "missing-model"
+    {
+      mock.on_generate_content( model, ResponseParts::new( 404, NOT_FOUND ) )
+    }
+    else
+    {
+      mock.on_generate_content( model, ResponseParts::new( 503, UNAVAILABLE ) ).on_generate_content( model, answer( model ) )
+    }
+  } );
+  InFlight { mock, ..Default::default() }
+}
+
+fn client( in_flight : &InFlight ) -> Client
+{
+  Client::builder()
+  .api_key( "test-key".to_string() )
+  .base_url( "http://127.0.0.1:9".to_string() )
+  .max_retries( 1 )
+  .base_delay( Duration::from_millis( 50 ) )
+  .enable_jitter( false )
+  .with_interceptor( in_flight.clone() )
+  .build()
+  .expect( "client should build" )
+}
+
+fn request( prompt : &str ) -> GenerateContentRequest
+{
+  GenerateContentRequest
+  {
+    contents : vec![ ContentBuilder::user().text( prompt ).build().unwrap() ],
+    ..Default::default()
+  }
+}
+
+fn batch( models : &[ &str ] ) -> Vec< ( String, GenerateContentRequest ) >
+{
+  models.iter().map( | model | ( ( *model ).to_string(), request( "Hello" ) ) ).collect()
+}
+
+fn text( result : &Result< api_gemini::models::GenerateContentResponse, Error > ) -> Option< String >
+{
+  result.as_ref().ok()?.candidates.first()?.content.parts.first()?.text.clone()
+}
+
+#[ tokio::test ]
+async fn test_results_in_input_order()
+{
+  let models = [ "slow-model", "fast-model", "other-model" ];
+  let in_flight = in_flight( &models );
+
+  let results = client( &in_flight ).generate_batch( batch( &models ), 3 ).await;
+
+  let tags : Vec< &str > = results.iter().map( | ( tag, _ ) | tag.as_str() ).collect();
+  assert_eq!( tags, models );
+  assert_eq!( text( &results[ 0 ].1 ).as_deref(), Some( "from slow-model" ) );
+  assert_eq!( text( &results[ 2 ].1 ).as_deref(), Some( "from other-model" ) );
+}
+
+#[ tokio::test ]
+async fn test_concurrency_is_bounded()
+{
+  let models = [ "model-0", "model-1", "model-2", "model-3", "model-4", "model-5" ];
+  let in_flight = in_flight( &models );
+
+  let results = client( &in_flight ).generate_batch( batch( &models ), 2 ).await;
+
+  assert_eq!( results.len(), 6 );
+  assert!( results.iter().all( | ( _, result ) | result.is_ok() ), "{results:?}" );
+  assert_eq!( in_flight.peak(), 2 );
+  assert_eq!( in_flight.mock.calls().len(), 12, "every model is retried once" );
+}
+
+#[ tokio::test ]
+async fn test_errors_are_isolated()
+{
+  let models = [ "model-0", "missing-model", "model-2" ];
+  let in_flight = in_flight( &models );
+
+  let results = client( &in_flight ).generate_batch( batch( &models ), 3 ).await;
+
+  assert!( results[ 0 ].1.is_ok(), "{:?}", results[ 0 ].1 );
+  assert!( results[ 1 ].1.is_err(), "{:?}", results[ 1 ].1 );
+  assert!( results[ 2 ].1.is_ok(), "{:?}", results[ 2 ].1 );
+}
+
+#[ tokio::test ]
+async fn test_zero_concurrency_runs_sequentially()
+{
+  let models = [ "model-0", "model-1" ];
+  let in_flight = in_flight( &models );
+
+  let results = client( &in_flight ).generate_batch( batch( &models ), 0 ).await;
+
+  assert!( results.iter().all( | ( _, result ) | result.is_ok() ), "{results:?}" );
+  assert_eq!( in_flight.peak(), 1 );
+}
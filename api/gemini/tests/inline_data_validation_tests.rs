@@ -0,0 +1,91 @@
+//! Inline data validation tests
+//!
+//! Tests for `Blob::validate` (aliased as `diagnostics::InlineData`), which
+//! checks that media payloads are valid base64 and reports their decoded size.
+//!
+//! # Test Coverage
+//!
+//! - Decoded size of valid base64 payloads, including the empty payload
+//! - Rejection of malformed base64 with `Error::InvalidArgument`
+//! - Request validation rejecting malformed and oversized inline data
+//! - Curl generation showing `<N bytes>` instead of the raw payload
+
+use api_gemini::{ error::Error, models::{ Blob, Content, CountTokensRequest, Part }, validation::validate_count_tokens_request };
+use base64::Engine as _;
+
+fn blob( data : &str ) -> Blob
+{
+  Blob { mime_type : "image/png".to_string(), data : data.to_string() }
+}
+
+fn request_with( blob : Blob ) -> CountTokensRequest
+{
+  CountTokensRequest
+  {
+    contents : vec![ Content
+    {
+      role : "user".to_string(),
+      parts : vec![ Part { inline_data : Some( blob ), ..Default::default() } ],
+    } ],
+    ..Default::default()
+  }
+}
+
+#[ test ]
+fn test_valid_base64_reports_decoded_size()
+{
+  let encoded = base64::engine::general_purpose::STANDARD.encode( [ 0_u8; 1000 ] );
+
+  assert_eq!( blob( &encoded ).validate().unwrap(), 1000 );
+  assert_eq!( blob( "aGVsbG8=" ).validate().unwrap(), 5 );
+  assert_eq!( blob( "" ).validate().unwrap(), 0 );
+}
+
+#[ test ]
+fn test_invalid_base64_is_rejected()
+{
+  for data in [ "not base64!", "aGVsbG8", "aGV sbG8=" ]
+  {
+    let error = blob( data ).validate().unwrap_err();
+    assert!( matches!( error, Error::InvalidArgument( .. ) ), "{data} : {error:?}" );
+    assert!( error.to_string().contains( "not valid base64" ), "{error}" );
+  }
+}
+
+#[ test ]
+fn test_request_validation_checks_inline_data()
+{
+  assert!( validate_count_tokens_request( &request_with( blob( "aGVsbG8=" ) ) ).is_ok() );
+
+  let error = validate_count_tokens_request( &request_with( blob( "%%%" ) ) ).unwrap_err();
+  assert!( error.to_string().contains( "not valid base64" ), "{error}" );
+
+  let oversized = base64::engine::general_purpose::STANDARD.encode( vec![ 0_u8; 20 * 1024 * 1024 + 1 ] );
+  let error = validate_count_tokens_request( &request_with( blob( &oversized ) ) ).unwrap_err();
+  assert!( error.to_string().contains( "inline_data.data" ), "{error}" );
+}
+
+#[ cfg( feature = "diagnostics_curl" ) ]
+#[ test ]
+fn test_curl_shows_inline_data_size()
+{
+  use api_gemini::{ diagnostics::AsCurl, models::GenerateContentRequest };
+
+  let encoded = base64::engine::general_purpose::STANDARD.encode( [ 7_u8; 2048 ] );
+  let request = GenerateContentRequest
+  {
+    contents : request_with( blob( &encoded ) ).contents,
+    ..Default::default()
+  };
+
+  let curl = request.as_curl();
+  assert!( curl.contains( "<2048 bytes>" ), "{curl}" );
+  assert!( !curl.contains( &encoded ), "{curl}" );
+
+  let request = GenerateContentRequest
+  {
+    contents : request_with( blob( "not base64!" ) ).contents,
+    ..Default::default()
+  };
+  assert!( request.as_curl().contains( "<invalid base64, 11 chars>" ) );
+}
@@ -209,6 +209,8 @@ async fn test_generate_content_with_parameters()
       top_p: Some( 0.8 ),
       max_output_tokens: Some( 500 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
       candidate_count: None,
     }),
     safety_settings: None,
@@ -469,7 +471,7 @@ async fn test_invalid_model_error()
   // With real API, we expect InvalidArgument or ApiError
   match result.err().unwrap()
   {
-  Error::InvalidArgument( _ ) | Error::ApiError( _ ) => {},
+  Error::InvalidArgument( _, _ ) | Error::ApiError( _, _ ) => {},
   other => panic!( "Expected InvalidArgument or ApiError, got : {other:?}" ),
   }
 }
@@ -670,6 +672,8 @@ async fn test_multiple_candidates_generation()
       candidate_count: Some( 2 ), // Request multiple candidates
       max_output_tokens: Some( 500 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     }),
     safety_settings: None,
     tools: None,
@@ -0,0 +1,167 @@
+//! Predictive scale-up tests for `IntelligentScaler`
+//!
+//! # Test Coverage
+//!
+//! - A rising request rate yields a scale-up recommendation before saturation
+//! - The projection and reasoning are carried on the decision
+//! - A shorter lookahead delays the recommendation
+//! - A growing queue alone triggers a scale-up
+//! - Flat load, missing history and `max_instances` yield no recommendation
+//! - Invalid predictive configurations are rejected
+
+use api_gemini::models::model_deployment::{ IntelligentScaler, PredictiveScalingConfig, ScalingConfig, ScalingDecision };
+use std::time::{ Duration, SystemTime };
+
+const INSTANCES : usize = 2;
+const CAPACITY_PER_INSTANCE : f64 = 10.0;
+
+fn scaler( lookahead_minutes : u64 ) -> IntelligentScaler
+{
+  let config = ScalingConfig::builder().min_instances( 1 ).max_instances( 10 ).build().expect( "valid config" );
+  IntelligentScaler::new( config )
+    .with_predictive_config( PredictiveScalingConfig
+    {
+      lookahead : minutes( lookahead_minutes ),
+      capacity_per_instance : CAPACITY_PER_INSTANCE,
+      target_utilization : 0.8,
+      queue_drain_time : Duration::from_secs( 30 ),
+      trend_window : minutes( 10 ),
+    } )
+    .expect( "valid predictive config" )
+}
+
+fn minutes( count : u64 ) -> Duration
+{
+  Duration::from_secs( count * 60 )
+}
+
+fn start() -> SystemTime
+{
+  SystemTime::UNIX_EPOCH + Duration::from_secs( 1_700_000_000 )
+}
+
+/// Record one sample per minute
+fn feed( scaler : &IntelligentScaler, samples : &[ ( f64, usize ) ] )
+{
+  for ( minute, ( rate, queue ) ) in samples.iter().enumerate()
+  {
+    scaler.record_load_at( start() + minutes( minute as u64 ), *rate, *queue );
+  }
+}
+
+#[ test ]
+fn rising_rate_recommends_scale_up_before_saturation()
+{
+  let scaler = scaler( 5 );
+  let saturation = INSTANCES as f64 * CAPACITY_PER_INSTANCE;
+
+  // Request rate climbs by 2 req/s every minute
+  let mut first_recommendation = None;
+  for minute in 0..10_u64
+  {
+    let rate = 5.0 + 2.0 * minute as f64;
+    scaler.record_load_at( start() + minutes( minute ), rate, 0 );
+    if scaler.predict_scale_up( INSTANCES ).is_some()
+    {
+      first_recommendation = Some( rate );
+      break;
+    }
+  }
+
+  let rate = first_recommendation.expect( "scale-up recommended while load rises" );
+  assert!( rate < saturation, "recommended at {rate} req/s, saturation is {saturation} req/s" );
+}
+
+#[ test ]
+fn decision_carries_projection_and_reason()
+{
+  let scaler = scaler( 5 );
+  feed( &scaler, &[ ( 5.0, 0 ), ( 7.0, 0 ), ( 9.0, 0 ), ( 11.0, 0 ), ( 13.0, 0 ) ] );
+
+  match scaler.predict_scale_up( INSTANCES )
+  {
+    Some( ScalingDecision::ScaleUp { target_instances, reason, projected_load } ) =>
+    {
+      let projected = projected_load.expect( "predictive decisions carry the projection" );
+      assert_eq!( target_instances, 3 );
+      assert!( ( projected.request_rate - 23.0 ).abs() < 1e-6, "projected {}", projected.request_rate );
+      assert!( projected.queue_depth.abs() < 1e-6 );
+      assert_eq!( projected.lookahead, minutes( 5 ) );
+
+      // 7 req/s of headroom at +2 req/s per minute
+      let eta = projected.time_to_saturation.expect( "demand is rising" );
+      assert!( ( eta.as_secs_f64() - 210.0 ).abs() < 1.0, "saturation in {eta:?}" );
+      assert!( reason.contains( "23.0 req/s" ), "reason : {reason}" );
+    },
+    other => panic!( "expected predictive scale-up, got {other:?}" ),
+  }
+}
+
+#[ test ]
+fn shorter_lookahead_delays_recommendation()
+{
+  let samples = [ ( 5.0, 0 ), ( 7.0, 0 ), ( 9.0, 0 ), ( 11.0, 0 ), ( 13.0, 0 ) ];
+
+  // Projected 15 req/s still fits 2 instances at 80% utilization
+  let short = scaler( 1 );
+  feed( &short, &samples );
+  assert!( short.predict_scale_up( INSTANCES ).is_none() );
+
+  let long = scaler( 5 );
+  feed( &long, &samples );
+  assert!( long.predict_scale_up( INSTANCES ).is_some() );
+}
+
+#[ test ]
+fn growing_queue_triggers_scale_up()
+{
+  let scaler = scaler( 5 );
+  feed( &scaler, &[ ( 10.0, 0 ), ( 10.0, 30 ), ( 10.0, 60 ), ( 10.0, 90 ) ] );
+
+  match scaler.predict_scale_up( INSTANCES )
+  {
+    Some( ScalingDecision::ScaleUp { target_instances, projected_load : Some( projected ), .. } ) =>
+    {
+      assert_eq!( target_instances, 3 );
+      assert!( ( projected.queue_depth - 240.0 ).abs() < 1e-6, "projected queue {}", projected.queue_depth );
+      assert!( ( projected.request_rate - 10.0 ).abs() < 1e-6 );
+    },
+    other => panic!( "expected predictive scale-up, got {other:?}" ),
+  }
+}
+
+#[ test ]
+fn no_recommendation_without_rising_demand()
+{
+  let empty = scaler( 5 );
+  assert!( empty.predict_scale_up( INSTANCES ).is_none() );
+
+  let single = scaler( 5 );
+  feed( &single, &[ ( 12.0, 0 ) ] );
+  assert!( single.predict_scale_up( INSTANCES ).is_none() );
+
+  let flat = scaler( 5 );
+  feed( &flat, &[ ( 12.0, 0 ), ( 12.0, 0 ), ( 12.0, 0 ), ( 12.0, 0 ) ] );
+  assert!( flat.predict_scale_up( INSTANCES ).is_none() );
+
+  let rising = scaler( 5 );
+  feed( &rising, &[ ( 5.0, 0 ), ( 7.0, 0 ), ( 9.0, 0 ), ( 11.0, 0 ), ( 13.0, 0 ) ] );
+  assert!( rising.predict_scale_up( 10 ).is_none(), "already at max_instances" );
+}
+
+#[ test ]
+fn invalid_predictive_config_rejected()
+{
+  let invalid = [
+    PredictiveScalingConfig { lookahead : Duration::ZERO, ..PredictiveScalingConfig::default() },
+    PredictiveScalingConfig { capacity_per_instance : 0.0, ..PredictiveScalingConfig::default() },
+    PredictiveScalingConfig { target_utilization : 1.5, ..PredictiveScalingConfig::default() },
+    PredictiveScalingConfig { queue_drain_time : Duration::ZERO, ..PredictiveScalingConfig::default() },
+  ];
+
+  for config in invalid
+  {
+    let result = IntelligentScaler::new( ScalingConfig::default() ).with_predictive_config( config.clone() );
+    assert!( result.is_err(), "accepted {config:?}" );
+  }
+}
@@ -0,0 +1,191 @@
+//! Interceptor tests
+//!
+//! Tests for `ClientBuilder::with_interceptor`, which hooks every request the
+//! client executes before it is sent and after its response arrives.
+//!
+//! # Test Coverage
+//!
+//! - Calls are recorded with the API key stripped from the URL
+//! - A fixture response answers the request without any server
+//! - Fixture error responses are classified like server responses
+//! - Header changes made in `before_request` are the headers sent
+//! - Streaming requests go through the interceptor with extra headers and signature
+
+use api_gemini::{ client::Client, error::Error, Interceptor, RequestParts, ResponseParts };
+use std::sync::{ Arc, Mutex };
+
+/// Records every call and optionally answers with a fixture
+#[ derive( Debug, Default, Clone ) ]
+struct Recorder
+{
+  requests : Arc< Mutex< Vec< ( String, String ) > > >,
+  statuses : Arc< Mutex< Vec< u16 > > >,
+  sent : Arc< Mutex< Vec< RequestParts > > >,
+  fixture : Option< ResponseParts >,
+}
+
+impl Interceptor for Recorder
+{
+  fn before_request( &self, request : &mut RequestParts )
+  {
+    self.requests.lock().unwrap().push( ( request.method.to_string(), request.url.clone() ) );
+    request.set_header( "X-Test-Run", "interceptor" );
+    request.set_header( "User-Agent", "intercepted-agent" );
+    if let Some( fixture ) = &self.fixture
+    {
+      request.respond_with( fixture.clone() );
+    }
+  }
+
+  fn after_response( &self, response : &ResponseParts )
+  {
+    self.statuses.lock().unwrap().push( response.status );
+  }
+
+  fn after_exchange( &self, request : &RequestParts, _response : &ResponseParts )
+  {
+    self.sent.lock().unwrap().push( request.clone() );
+  }
+}
+
+fn client( base_url : &str, recorder : &Recorder ) -> Client
+{
+  Client::builder()
+  .api_key( "test-key".to_string() )
+  .base_url( base_url.to_string() )
+  .with_interceptor( recorder.clone() )
+  .build()
+  .expect( "client should build" )
+}
+
+#[ tokio::test ]
+async fn test_fixture_answers_without_server()
+{
+  let recorder = Recorder
+  {
+    fixture : Some( ResponseParts::new( 200, r#"{ "models" : [ { "name" : "models/fixture-model" } ] }"# ) ),
+    ..Default::default()
+  };
+  // Nothing listens on port 9, so any network access would fail
+  let client = client( "http://127.0.0.1:9", &recorder );
+
+  let response = client.models().list().await.expect( "fixture should answer the request" );
+
+  assert_eq!( response.models.len(), 1 );
+  assert_eq!( response.models[ 0 ].name, "models/fixture-model" );
+  assert_eq!( *recorder.requests.lock().unwrap(), vec![ ( "GET".to_string(), "http://127.0.0.1:9/v1beta/models".to_string() ) ] );
+  assert_eq!( *recorder.statuses.lock().unwrap(), vec![ 200 ] );
+}
+
+#[ tokio::test ]
+async fn test_fixture_error_is_classified()
+{
+  let recorder = Recorder
+  {
+    fixture : Some( ResponseParts::new( 400, r#"{ "error" : { "code" : 400, "message" : "bad request", "status" : "INVALID_ARGUMENT" } }"# ) ),
+    ..Default::default()
+  };
+  let client = client( "http://127.0.0.1:9", &recorder );
+
+  let error = client.models().list().await.unwrap_err();
+
+  assert!( matches!( error, Error::InvalidArgument( .. ) ), "{error:?}" );
+  assert_eq!( *recorder.statuses.lock().unwrap(), vec![ 400 ] );
+}
+
+#[ tokio::test ]
+async fn test_header_mutation_is_sent()
+{
+  let recorder = Recorder
+  {
+    fixture : Some( ResponseParts::new( 200, r#"{ "models" : [] }"# ) ),
+    ..Default::default()
+  };
+  let client = client( "http://127.0.0.1:9", &recorder );
+
+  let response = client.models().list().await.expect( "fixture should answer" );
+
+  assert!( response.models.is_empty() );
+  let sent = recorder.sent.lock().unwrap().clone();
+  assert_eq!( sent.len(), 1 );
+  let user_agents : Vec< _ > = sent[ 0 ].headers.iter().filter( | ( name, _ ) | name.eq_ignore_ascii_case( "user-agent" ) ).collect();
+  assert_eq!( user_agents.len(), 1, "{:?}", sent[ 0 ].headers );
+  assert_eq!( sent[ 0 ].header( "user-agent" ), Some( "intercepted-agent" ) );
+  assert_eq!( sent[ 0 ].header( "x-test-run" ), Some( "interceptor" ) );
+  assert_eq!( sent[ 0 ].header( "content-type" ), Some( "application/json" ) );
+}
+
+/// Signs with a fixed value so the header can be asserted
+#[ cfg( feature = "streaming" ) ]
+#[ derive( Debug ) ]
+struct FixedSigner;
+
+#[ cfg( feature = "streaming" ) ]
+impl api_gemini::RequestSigner for FixedSigner
+{
+  fn sign( &self, method : &reqwest::Method, url : &str, body : &[ u8 ] ) -> Vec< ( String, String ) >
+  {
+    vec![ ( "X-Signature".to_string(), format!( "{method} {url} {}", body.len() ) ) ]
+  }
+}
+
+#[ cfg( feature = "streaming" ) ]
+#[ tokio::test ]
+async fn test_streaming_request_is_intercepted_signed_and_carries_extra_headers()
+{
+  use futures::StreamExt;
+
+  /// Captures the request as sent and answers with a streaming fixture
+  #[ derive( Debug, Default, Clone ) ]
+  struct Capture
+  {
+    request : Arc< Mutex< Option< RequestParts > > >,
+  }
+
+  impl Interceptor for Capture
+  {
+    fn before_request( &self, request : &mut RequestParts )
+    {
+      *self.request.lock().unwrap() = Some( request.clone() );
+      request.respond_with( ResponseParts::new( 200, r#"[ { "candidates" : [ { "content" : { "parts" : [ { "text" : "Hi" } ], "role" : "model" }, "finishReason" : "STOP" } ] } ]"# ) );
+    }
+
+    fn after_response( &self, _response : &ResponseParts ) {}
+  }
+
+  let capture = Capture::default();
+  let client = Client::builder()
+  .api_key( "test-key".to_string() )
+  .base_url( "http://127.0.0.1:9".to_string() )
+  .with_header( "X-Tenant", "acme" )
+  .with_request_signer( FixedSigner )
+  .with_interceptor( capture.clone() )
+  .build()
+  .expect( "client should build" );
+
+  let request = api_gemini::models::GenerateContentRequest
+  {
+    contents : vec![ api_gemini::models::Content
+    {
+      parts : vec![ api_gemini::models::Part { text : Some( "Hello".to_string() ), ..Default::default() } ],
+      role : "user".to_string(),
+    } ],
+    ..Default::default()
+  };
+
+  let stream = client.models().by_name( "gemini-2.5-flash" ).generate_content_stream( &request ).await
+  .expect( "fixture should answer the streaming request" );
+  let chunks : Vec< _ > = stream.collect().await;
+
+  let first = chunks[ 0 ].as_ref().expect( "first chunk should parse" );
+  let text = first.candidates.as_ref().and_then( | candidates | candidates[ 0 ].content.parts[ 0 ].text.clone() );
+  assert_eq!( text.as_deref(), Some( "Hi" ) );
+
+  let sent = capture.request.lock().unwrap().clone().expect( "streaming request should reach the interceptor" );
+  let url = "http://127.0.0.1:9/v1beta/models/gemini-2.5-flash:streamGenerateContent";
+  assert_eq!( sent.url, url );
+  assert_eq!( sent.header( "x-tenant" ), Some( "acme" ) );
+  let body_len = sent.body.as_ref().map_or( 0, Vec::len );
+  assert_eq!( sent.header( "x-signature" ), Some( format!( "POST {url} {body_len}" ).as_str() ) );
+  assert_eq!( sent.header( "accept" ), Some( "application/json" ) );
+}
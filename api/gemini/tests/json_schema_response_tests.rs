@@ -0,0 +1,125 @@
+//! Structured output schema tests
+//!
+//! Tests for `GenerationRequestBuilder::with_json_schema`, which derives a Gemini
+//! `responseSchema` from a Rust type. `JsonSchema` is implemented by hand here so the
+//! tests do not depend on `schemars` derive support.
+//!
+//! # Test Coverage
+//!
+//! - The request carries `application/json` and the derived schema
+//! - Nested types are inlined rather than referenced
+//! - The serialized request uses the Gemini field names
+
+#![ cfg( feature = "json_schema" ) ]
+
+use api_gemini::client::Client;
+use schemars::{ json_schema, JsonSchema, Schema, SchemaGenerator };
+use std::borrow::Cow;
+
+/// Ingredient of a recipe
+#[ allow( dead_code ) ]
+struct Ingredient
+{
+  name : String,
+  grams : u32,
+}
+
+impl JsonSchema for Ingredient
+{
+  fn schema_name() -> Cow< 'static, str >
+  {
+    "Ingredient".into()
+  }
+
+  fn json_schema( generator : &mut SchemaGenerator ) -> Schema
+  {
+    json_schema!( {
+      "type" : "object",
+      "properties" : {
+        "name" : generator.subschema_for::< String >(),
+        "grams" : generator.subschema_for::< u32 >(),
+      },
+      "required" : [ "name", "grams" ]
+    } )
+  }
+}
+
+/// Recipe returned by the model
+#[ allow( dead_code ) ]
+struct Recipe
+{
+  title : String,
+  ingredients : Vec< Ingredient >,
+}
+
+impl JsonSchema for Recipe
+{
+  fn schema_name() -> Cow< 'static, str >
+  {
+    "Recipe".into()
+  }
+
+  fn json_schema( generator : &mut SchemaGenerator ) -> Schema
+  {
+    json_schema!( {
+      "type" : "object",
+      "properties" : {
+        "title" : generator.subschema_for::< String >(),
+        "ingredients" : generator.subschema_for::< Vec< Ingredient > >(),
+      },
+      "required" : [ "title", "ingredients" ]
+    } )
+  }
+}
+
+fn client() -> Client
+{
+  Client::builder().api_key( "test-key".to_string() ).build().expect( "client should build" )
+}
+
+#[ test ]
+fn request_carries_json_mime_type_and_schema()
+{
+  let client = client();
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash" );
+  let builder = model.generation_request().with_prompt( "Give me a pancake recipe" ).with_json_schema::< Recipe >();
+
+  let config = builder.request().generation_config.as_ref().expect( "generation config should be set" );
+  assert_eq!( config.response_mime_type.as_deref(), Some( "application/json" ) );
+
+  let schema = config.response_schema.as_ref().expect( "schema should be set" );
+  assert_eq!( schema[ "type" ], "object" );
+  assert_eq!( schema[ "properties" ][ "title" ][ "type" ], "string" );
+  assert_eq!( schema[ "required" ], serde_json::json!( [ "title", "ingredients" ] ) );
+  assert!( schema.get( "$schema" ).is_none(), "meta-schema keyword should be stripped" );
+}
+
+#[ test ]
+fn nested_types_are_inlined()
+{
+  let client = client();
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash" );
+  let builder = model.generation_request().with_json_schema::< Recipe >();
+
+  let schema = builder.request().generation_config.as_ref().unwrap().response_schema.clone().unwrap();
+  let items = &schema[ "properties" ][ "ingredients" ][ "items" ];
+  assert_eq!( items[ "properties" ][ "name" ][ "type" ], "string" );
+  assert!( !schema.to_string().contains( "$ref" ), "schema should not contain references : {schema}" );
+}
+
+#[ test ]
+fn serialized_request_uses_gemini_field_names()
+{
+  let client = client();
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash" );
+  let builder = model.generation_request().with_temperature( 0.2 ).with_json_schema::< Recipe >();
+
+  let json = serde_json::to_value( builder.request() ).expect( "request should serialize" );
+  assert_eq!( json[ "generationConfig" ][ "responseMimeType" ], "application/json" );
+  assert_eq!( json[ "generationConfig" ][ "responseSchema" ][ "type" ], "object" );
+  let temperature = json[ "generationConfig" ][ "temperature" ].as_f64().unwrap();
+  assert!( ( temperature - 0.2 ).abs() < 1e-6, "existing settings should be preserved" );
+}
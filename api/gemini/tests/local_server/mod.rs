@@ -0,0 +1,93 @@
+//! Local HTTP/1.1 server shared by tests that need a real socket
+//!
+//! This is the transport exception to the no-mockup policy ( see `tests/readme.md` ):
+//! it serves only tests of behavior below the API layer, such as timeouts, body
+//! size limits, connection timing and cassette recording. Tests
+//! that inspect or fabricate requests use an `Interceptor` or `MockTransport`
+//! instead. Include it with `mod local_server;`.
+
+#![ allow( dead_code ) ]
+
+use std::io::{ Read, Write };
+use std::net::TcpStream;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+/// Accept `connections` connections, or every connection when `None`, handling each
+/// on its own thread with the connection and its zero-based index
+///
+/// Returns the server's base URL, `http://127.0.0.1:<port>`, and a handle that
+/// finishes once every limited connection has been handled.
+pub fn serve< F >( connections : Option< usize >, handler : F ) -> ( String, JoinHandle< () > )
+where
+  F : Fn( TcpStream, usize ) + Send + Sync + 'static,
+{
+  let listener = std::net::TcpListener::bind( "127.0.0.1:0" ).expect( "bind local listener" );
+  let address = listener.local_addr().expect( "local address" );
+  let handler = Arc::new( handler );
+
+  let server = std::thread::spawn( move ||
+  {
+    let incoming = listener.incoming().take( connections.unwrap_or( usize::MAX ) );
+    let handlers : Vec< _ > = incoming.enumerate().map_while( | ( index, stream ) |
+    {
+      let socket = stream.ok()?;
+      let handler = handler.clone();
+      Some( std::thread::spawn( move || handler( socket, index ) ) )
+    } ).collect();
+    for handler in handlers
+    {
+      let _ = handler.join();
+    }
+  } );
+
+  ( format!( "http://{address}" ), server )
+}
+
+/// Read one request, headers and `Content-Length` body, lossily decoded
+pub fn read_request( socket : &mut TcpStream ) -> String
+{
+  let mut buffer = Vec::new();
+  let mut chunk = [ 0_u8; 4096 ];
+  loop
+  {
+    let read = socket.read( &mut chunk ).unwrap_or( 0 );
+    buffer.extend_from_slice( &chunk[ ..read ] );
+    let text = String::from_utf8_lossy( &buffer ).into_owned();
+    if let Some( end ) = text.find( "\r\n\r\n" )
+    {
+      let length = text[ ..end ].lines()
+        .find_map( | line | line.split_once( ':' ).filter( | ( name, _ ) | name.eq_ignore_ascii_case( "content-length" ) ) )
+        .and_then( | ( _, value ) | value.trim().parse::< usize >().ok() )
+        .unwrap_or( 0 );
+      if buffer.len() >= end + 4 + length
+      {
+        return text;
+      }
+    }
+    if read == 0
+    {
+      return text;
+    }
+  }
+}
+
+/// Write a complete JSON response with the given status line, e.g. `200 OK`
+pub fn respond( socket : &mut TcpStream, status_line : &str, body : &str )
+{
+  let response = format!(
+    "HTTP/1.1 {status_line}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+    body.len()
+  );
+  let _ = socket.write_all( response.as_bytes() );
+}
+
+/// Accept every connection but never answer, holding each socket open for `hold`
+pub fn serve_silent( hold : core::time::Duration ) -> String
+{
+  serve( None, move | socket, _ |
+  {
+    std::thread::sleep( hold );
+    drop( socket );
+  } ).0
+}
@@ -0,0 +1,72 @@
+//! LoRA size estimate tests
+//!
+//! # Test Coverage
+//!
+//! - The base model shape is inferred from its parameter count
+//! - Trainable parameters scale with rank and target module count
+//! - Scaling, adapter size and training memory follow from the parameter count
+//! - Merged weights add no parameters at inference
+
+use api_gemini::models::model_tuning::LoRAConfig;
+
+/// 32 layers of hidden size 4096 : 12 * 32 * 4096²
+const BASE_7B : u64 = 6_442_450_944;
+/// 16 layers of hidden size 2048 : 12 * 16 * 2048²
+const BASE_1B : u64 = 805_306_368;
+
+fn config( rank : usize, modules : &[ &str ], merge_weights : bool ) -> LoRAConfig
+{
+  LoRAConfig::builder()
+    .rank( rank )
+    .alpha( 16.0 )
+    .target_modules( modules.iter().map( | m | ( *m ).to_string() ).collect() )
+    .merge_weights( merge_weights )
+    .build()
+    .expect( "valid LoRA config" )
+}
+
+#[ test ]
+fn infers_base_model_shape()
+{
+  let estimate = config( 8, &[ "query", "value" ], false ).estimate_parameters( BASE_7B );
+  assert_eq!( ( estimate.hidden_size, estimate.layers ), ( 4096, 32 ) );
+
+  let estimate = config( 8, &[ "query", "value" ], false ).estimate_parameters( BASE_1B );
+  assert_eq!( ( estimate.hidden_size, estimate.layers ), ( 2048, 16 ) );
+}
+
+#[ test ]
+fn rank_8_on_query_and_value()
+{
+  let estimate = config( 8, &[ "query", "value" ], false ).estimate_parameters( BASE_7B );
+
+  // 32 layers * 2 modules * 2 * rank 8 * hidden 4096
+  assert_eq!( estimate.trainable_parameters, 4_194_304 );
+  assert!( ( estimate.trainable_fraction - 4_194_304.0 / BASE_7B as f64 ).abs() < 1e-12 );
+  assert!( ( estimate.scaling - 2.0 ).abs() < f64::EPSILON );
+  assert_eq!( estimate.inference_parameters, BASE_7B + 4_194_304 );
+  assert_eq!( estimate.adapter_bytes, 4_194_304 * 4 );
+  assert_eq!( estimate.training_memory_bytes, BASE_7B * 2 + 4_194_304 * 16 );
+}
+
+#[ test ]
+fn rank_16_doubles_trainable_parameters()
+{
+  let rank_8 = config( 8, &[ "query", "value" ], false ).estimate_parameters( BASE_7B );
+  let rank_16 = config( 16, &[ "query", "value" ], false ).estimate_parameters( BASE_7B );
+
+  assert_eq!( rank_16.trainable_parameters, 8_388_608 );
+  assert_eq!( rank_16.trainable_parameters, 2 * rank_8.trainable_parameters );
+  assert!( ( rank_16.scaling - 1.0 ).abs() < f64::EPSILON );
+}
+
+#[ test ]
+fn target_modules_and_merge()
+{
+  let estimate = config( 4, &[ "query", "key", "value", "output" ], true ).estimate_parameters( BASE_1B );
+
+  // 16 layers * 4 modules * 2 * rank 4 * hidden 2048
+  assert_eq!( estimate.trainable_parameters, 1_048_576 );
+  assert_eq!( estimate.inference_parameters, BASE_1B, "merged adapters add no parameters" );
+  assert_eq!( estimate.training_memory_bytes, BASE_1B * 2 + 1_048_576 * 16 );
+}
@@ -37,7 +37,7 @@ async fn test_large_file_handling() -> Result< (), Box< dyn std::error::Error >
 
       let _ = files_api.delete( &response.file.name ).await;
     },
-    Err( Error::ApiError( msg ) ) if msg.contains( "size" ) || msg.contains( "limit" ) =>
+    Err( Error::ApiError( msg, _ ) ) if msg.contains( "size" ) || msg.contains( "limit" ) =>
     {
     println!( "✓ Large file upload rejected due to size limits (expected): {}", msg );
       assert!( msg.contains( "size" ) || msg.contains( "limit" ), "Error should mention size or limit" );
@@ -141,7 +141,7 @@ println!( "✓ File uploaded for deletion test : {}", file_name );
   let get_result = files_api.get( &file_name ).await;
   match get_result
   {
-    Err( Error::ApiError( msg ) ) => {
+    Err( Error::ApiError( msg, _ ) ) => {
       assert!( msg.contains( "not found" ) || msg.contains( "404" ) );
       println!( "✓ File deletion verified - file no longer accessible" );
     },
@@ -0,0 +1,106 @@
+//! Mock transport tests
+//!
+//! Tests for `testing::MockTransport`, which answers client requests from canned
+//! responses so these tests run without network access or API keys.
+//!
+//! # Test Coverage
+//!
+//! - `generate_content` against a canned response, with the request recorded
+//! - Queued responses returned in order, the last one repeating
+//! - Error classification of `400`, `401` and unmatched ( `404` ) responses
+//! - Deserialization errors for malformed success bodies
+
+#![ cfg( feature = "testing" ) ]
+
+use api_gemini::{ client::Client, error::Error, models::{ ContentBuilder, GenerateContentRequest }, testing::MockTransport, ResponseParts };
+use reqwest::Method;
+
+const MODEL : &str = "gemini-2.5-flash";
+
+fn reply( text : &str ) -> ResponseParts
+{
+  let body = serde_json::json!(
+  {
+    "candidates" : [ { "content" : { "role" : "model", "parts" : [ { "text" : text } ] }, "finishReason" : "STOP" } ],
+    "usageMetadata" : { "promptTokenCount" : 3, "candidatesTokenCount" : 2, "totalTokenCount" : 5 }
+  } );
+  ResponseParts::new( 200, body.to_string() ).with_header( "content-type", "application/json" )
+}
+
+fn api_error( status : u16, message : &str ) -> ResponseParts
+{
+  let body = serde_json::json!( { "error" : { "code" : status, "message" : message, "status" : "FAILED" } } );
+  ResponseParts::new( status, body.to_string() )
+}
+
+fn request( text : &str ) -> GenerateContentRequest
+{
+  GenerateContentRequest
+  {
+    contents : vec![ ContentBuilder::user().text( text ).build().unwrap() ],
+    ..Default::default()
+  }
+}
+
+async fn generate( mock : &MockTransport ) -> Result< api_gemini::models::GenerateContentResponse, Error >
+{
+  let client = Client::with_mock_transport( mock.clone() ).unwrap();
+  client.models().by_name( MODEL ).generate_content( &request( "Say hello" ) ).await
+}
+
+#[ tokio::test ]
+async fn test_generate_content_from_canned_response()
+{
+  let mock = MockTransport::new().on_generate_content( MODEL, reply( "Hello there" ) );
+
+  let response = generate( &mock ).await.expect( "mock should answer" );
+
+  assert_eq!( response.candidates[ 0 ].content.parts[ 0 ].text.as_deref(), Some( "Hello there" ) );
+  assert_eq!( response.usage_metadata.and_then( | usage | usage.total_token_count ), Some( 5 ) );
+
+  let calls = mock.calls();
+  assert_eq!( calls.len(), 1 );
+  assert_eq!( calls[ 0 ].method, Method::POST );
+  assert!( calls[ 0 ].url.ends_with( "/v1beta/models/gemini-2.5-flash:generateContent" ), "{}", calls[ 0 ].url );
+  let body : serde_json::Value = serde_json::from_slice( calls[ 0 ].body.as_deref().unwrap() ).unwrap();
+  assert_eq!( body[ "contents" ][ 0 ][ "parts" ][ 0 ][ "text" ], "Say hello" );
+}
+
+#[ tokio::test ]
+async fn test_queued_responses_in_order()
+{
+  let mock = MockTransport::new()
+    .on_generate_content( MODEL, reply( "first" ) )
+    .on_generate_content( MODEL, reply( "second" ) );
+
+  for expected in [ "first", "second", "second" ]
+  {
+    let response = generate( &mock ).await.unwrap();
+    assert_eq!( response.candidates[ 0 ].content.parts[ 0 ].text.as_deref(), Some( expected ) );
+  }
+  assert_eq!( mock.calls().len(), 3 );
+}
+
+#[ tokio::test ]
+async fn test_error_classification()
+{
+  let mock = MockTransport::new().on_generate_content( MODEL, api_error( 400, "Invalid JSON payload" ) );
+  let error = generate( &mock ).await.unwrap_err();
+  assert!( matches!( error, Error::InvalidArgument( ref message, Some( _ ) ) if message.contains( "Invalid JSON payload" ) ), "{error:?}" );
+
+  let mock = MockTransport::new().on_generate_content( MODEL, api_error( 401, "Request is missing credentials" ) );
+  assert!( matches!( generate( &mock ).await, Err( Error::AuthenticationError( _ ) ) ) );
+
+  let mock = MockTransport::new().on( Method::GET, "/v1beta/models", ResponseParts::new( 200, r#"{ "models" : [] }"# ) );
+  let error = generate( &mock ).await.unwrap_err();
+  assert!( matches!( error, Error::ApiError( .. ) ), "{error:?}" );
+  assert!( error.to_string().contains( "no response for POST /v1beta/models/gemini-2.5-flash:generateContent" ), "{error}" );
+}
+
+#[ tokio::test ]
+async fn test_malformed_success_body()
+{
+  let mock = MockTransport::new().on_generate_content( MODEL, ResponseParts::new( 200, "not json" ) );
+
+  assert!( matches!( generate( &mock ).await, Err( Error::DeserializationError( _ ) ) ) );
+}
@@ -0,0 +1,88 @@
+//! Model name normalization tests
+//!
+//! Tests for `ModelName`, the explicit helper that turns user-supplied model
+//! names into canonical `models/{id}` paths.
+//!
+//! # Test Coverage
+//!
+//! - Bare ids, prefixed paths, repeated prefixes, and surrounding whitespace
+//! - `-latest` alias resolution against a known-models list in both directions
+//! - Unknown names fall back to plain normalization
+//! - `by_name` stays literal while `by_name_normalized` normalizes
+
+use api_gemini::{ client::Client, models::ModelName };
+
+const KNOWN : [ &str; 3 ] = [ "models/gemini-2.5-flash", "gemini-2.5-pro", "models/gemini-flash-latest" ];
+
+#[ test ]
+fn test_normalize_bare_id_adds_prefix()
+{
+  assert_eq!( ModelName::normalize( "gemini-2.5-flash" ), "models/gemini-2.5-flash" );
+}
+
+#[ test ]
+fn test_normalize_prefixed_path_is_unchanged()
+{
+  assert_eq!( ModelName::normalize( "models/gemini-2.5-flash" ), "models/gemini-2.5-flash" );
+}
+
+#[ test ]
+fn test_normalize_collapses_repeated_prefix()
+{
+  assert_eq!( ModelName::normalize( "models/models/gemini-2.5-flash" ), "models/gemini-2.5-flash" );
+}
+
+#[ test ]
+fn test_normalize_trims_whitespace()
+{
+  assert_eq!( ModelName::normalize( "  models/gemini-2.5-flash\n" ), "models/gemini-2.5-flash" );
+  assert_eq!( ModelName::normalize( " gemini-2.5-flash " ), "models/gemini-2.5-flash" );
+}
+
+#[ test ]
+fn test_id_strips_prefix()
+{
+  assert_eq!( ModelName::id( "models/gemini-2.5-flash" ), "gemini-2.5-flash" );
+  assert_eq!( ModelName::id( "gemini-2.5-flash" ), "gemini-2.5-flash" );
+}
+
+#[ test ]
+fn test_known_exact_match_wins()
+{
+  assert_eq!( ModelName::normalize_with_known( "gemini-2.5-pro", &KNOWN ), "models/gemini-2.5-pro" );
+  assert_eq!( ModelName::normalize_with_known( "gemini-flash-latest", &KNOWN ), "models/gemini-flash-latest" );
+}
+
+#[ test ]
+fn test_latest_suffix_resolves_to_known_base()
+{
+  assert_eq!( ModelName::normalize_with_known( "gemini-2.5-flash-latest", &KNOWN ), "models/gemini-2.5-flash" );
+  assert_eq!( ModelName::normalize_with_known( "models/gemini-2.5-pro-latest", &KNOWN ), "models/gemini-2.5-pro" );
+}
+
+#[ test ]
+fn test_base_resolves_to_known_latest_alias()
+{
+  assert_eq!( ModelName::normalize_with_known( "gemini-flash", &KNOWN ), "models/gemini-flash-latest" );
+}
+
+#[ test ]
+fn test_unknown_name_falls_back_to_normalize()
+{
+  assert_eq!( ModelName::normalize_with_known( "gemini-unknown-latest", &KNOWN ), "models/gemini-unknown-latest" );
+  assert_eq!( ModelName::normalize_with_known( "gemini-unknown", &[] as &[ &str ] ), "models/gemini-unknown" );
+}
+
+#[ test ]
+fn test_by_name_is_literal_and_by_name_normalized_normalizes()
+{
+  let client = Client::builder()
+  .api_key( "test-key".to_string() )
+  .build()
+  .expect( "client should build" );
+  let models = client.models();
+
+  assert_eq!( models.by_name( "models/gemini-2.5-flash" ).model_id(), "models/gemini-2.5-flash" );
+  assert_eq!( models.by_name_normalized( "models/gemini-2.5-flash" ).model_id(), "gemini-2.5-flash" );
+  assert_eq!( models.by_name_normalized( " gemini-2.5-flash " ).model_id(), "gemini-2.5-flash" );
+}
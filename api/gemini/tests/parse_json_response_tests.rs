@@ -0,0 +1,113 @@
+//! Structured output parsing tests
+//!
+//! Tests for `GenerateContentResponse::parse_json`, built from locally constructed
+//! responses.
+//!
+//! # Test Coverage
+//!
+//! - Bare JSON responses
+//! - Fenced responses with and without a language tag
+//! - Fenced responses whose JSON starts on the opening fence line
+//! - JSON split across multiple text parts
+//! - Errors for invalid JSON and missing candidate text
+
+use api_gemini::{ error::Error, models::* };
+use serde::Deserialize;
+
+#[ derive( Debug, PartialEq, Deserialize ) ]
+struct City
+{
+  name : String,
+  population : u64,
+}
+
+fn response( parts : &[ &str ] ) -> GenerateContentResponse
+{
+  GenerateContentResponse
+  {
+    candidates : vec![ Candidate
+    {
+      content : Content
+      {
+        parts : parts.iter().map( | text | Part { text : Some( ( *text ).to_string() ), ..Default::default() } ).collect(),
+        role : "model".to_string(),
+      },
+      finish_reason : Some( "STOP".to_string() ),
+      safety_ratings : None,
+      citation_metadata : None,
+      token_count : None,
+      index : Some( 0 ),
+    } ],
+    prompt_feedback : None,
+    usage_metadata : None,
+    grounding_metadata : None,
+  }
+}
+
+fn paris() -> City
+{
+  City { name : "Paris".to_string(), population : 2_100_000 }
+}
+
+#[ test ]
+fn parses_bare_json()
+{
+  let city : City = response( &[ r#"{ "name" : "Paris", "population" : 2100000 }"# ] ).parse_json().unwrap();
+  assert_eq!( city, paris() );
+}
+
+#[ test ]
+fn parses_json_fenced_with_language_tag()
+{
+  let text = "```json\n{ \"name\" : \"Paris\", \"population\" : 2100000 }\n```\n";
+  let city : City = response( &[ text ] ).parse_json().unwrap();
+  assert_eq!( city, paris() );
+}
+
+#[ test ]
+fn parses_json_fenced_without_language_tag()
+{
+  let text = "```\n{ \"name\" : \"Paris\", \"population\" : 2100000 }\n```";
+  let city : City = response( &[ text ] ).parse_json().unwrap();
+  assert_eq!( city, paris() );
+}
+
+#[ test ]
+fn parses_json_starting_on_fence_line()
+{
+  let city : City = response( &[ r#"```{ "name" : "Paris", "population" : 2100000 }```"# ] ).parse_json().unwrap();
+  assert_eq!( city, paris() );
+
+  let text = "```{\n  \"name\" : \"Paris\",\n  \"population\" : 2100000\n}\n```";
+  let city : City = response( &[ text ] ).parse_json().unwrap();
+  assert_eq!( city, paris() );
+}
+
+#[ test ]
+fn concatenates_text_parts()
+{
+  let city : City = response( &[ r#"{ "name" : "Par"#, r#"is", "population" : 2100000 }"# ] ).parse_json().unwrap();
+  assert_eq!( city, paris() );
+}
+
+#[ test ]
+fn invalid_json_reports_snippet()
+{
+  let result : Result< City, Error > = response( &[ "Sure! Paris has about 2.1 million people." ] ).parse_json();
+
+  match result
+  {
+    Err( Error::DeserializationError( msg ) ) => assert!( msg.contains( "Sure! Paris has about" ), "snippet missing : {msg}" ),
+    other => panic!( "Expected DeserializationError, got : {other:?}" ),
+  }
+}
+
+#[ test ]
+fn missing_text_is_an_error()
+{
+  let mut empty = response( &[] );
+  assert!( matches!( empty.parse_json::< City >(), Err( Error::DeserializationError( _ ) ) ) );
+
+  empty.candidates.clear();
+  assert!( matches!( empty.parse_json::< City >(), Err( Error::DeserializationError( _ ) ) ) );
+}
@@ -0,0 +1,102 @@
+//! Recommendation prioritization tests for `PerformanceOptimizer`
+//!
+//! # Test Coverage
+//!
+//! - Scores combine impact and effort, high impact and low effort scoring highest
+//! - Recommendations are ranked by score, highest first
+//! - Equal scores are ordered by priority, then impact, then recording order
+//! - Recommendations from `analyze_deployment` are ranked the same way
+
+use api_gemini::models::model_deployment::
+{
+  DeploymentEnvironment,
+  ImpactEstimate,
+  ImplementationEffort,
+  ModelDeployment,
+  OptimizationCategory,
+  OptimizationPriority,
+  OptimizationRecommendation,
+  PerformanceOptimizer,
+};
+
+fn recommendation(
+  title : &str,
+  priority : OptimizationPriority,
+  estimated_impact : ImpactEstimate,
+  implementation_effort : ImplementationEffort,
+) -> OptimizationRecommendation
+{
+  OptimizationRecommendation {
+    category : OptimizationCategory::Performance,
+    priority,
+    title : title.to_string(),
+    description : String::new(),
+    estimated_impact,
+    implementation_effort,
+  }
+}
+
+fn titles( recommendations : &[ OptimizationRecommendation ] ) -> Vec< &str >
+{
+  recommendations.iter().map( | r | r.title.as_str() ).collect()
+}
+
+#[ test ]
+fn score_favours_high_impact_and_low_effort()
+{
+  let score = | impact, effort | recommendation( "r", OptimizationPriority::Medium, impact, effort ).score();
+
+  assert_eq!( score( ImpactEstimate::High, ImplementationEffort::Low ), 9 );
+  assert_eq!( score( ImpactEstimate::High, ImplementationEffort::Medium ), 6 );
+  assert_eq!( score( ImpactEstimate::Medium, ImplementationEffort::Medium ), 4 );
+  assert_eq!( score( ImpactEstimate::High, ImplementationEffort::High ), 3 );
+  assert_eq!( score( ImpactEstimate::Low, ImplementationEffort::High ), 1 );
+}
+
+#[ test ]
+fn ranks_by_score()
+{
+  let optimizer = PerformanceOptimizer::new();
+  optimizer.add_recommendation( recommendation( "rewrite", OptimizationPriority::Critical, ImpactEstimate::Medium, ImplementationEffort::High ) );
+  optimizer.add_recommendation( recommendation( "quick-win", OptimizationPriority::Low, ImpactEstimate::High, ImplementationEffort::Low ) );
+  optimizer.add_recommendation( recommendation( "tweak", OptimizationPriority::Medium, ImpactEstimate::Low, ImplementationEffort::Low ) );
+  optimizer.add_recommendation( recommendation( "cache", OptimizationPriority::Medium, ImpactEstimate::High, ImplementationEffort::Medium ) );
+
+  let ranked = optimizer.prioritized_recommendations();
+
+  assert_eq!( titles( &ranked ), vec![ "quick-win", "cache", "tweak", "rewrite" ] );
+  let scores : Vec< u32 > = ranked.iter().map( OptimizationRecommendation::score ).collect();
+  assert_eq!( scores, vec![ 9, 6, 3, 2 ] );
+  assert_eq!( titles( &optimizer.get_recommendations() )[ 0 ], "rewrite", "stored order is unchanged" );
+}
+
+#[ test ]
+fn ties_break_on_priority_then_impact_then_order()
+{
+  let optimizer = PerformanceOptimizer::new();
+  // All score 3
+  optimizer.add_recommendation( recommendation( "low-first", OptimizationPriority::Low, ImpactEstimate::Low, ImplementationEffort::Low ) );
+  optimizer.add_recommendation( recommendation( "high-impact", OptimizationPriority::Low, ImpactEstimate::High, ImplementationEffort::High ) );
+  optimizer.add_recommendation( recommendation( "critical", OptimizationPriority::Critical, ImpactEstimate::Low, ImplementationEffort::Low ) );
+  optimizer.add_recommendation( recommendation( "low-second", OptimizationPriority::Low, ImpactEstimate::Low, ImplementationEffort::Low ) );
+
+  let ranked = optimizer.prioritized_recommendations();
+
+  assert_eq!( titles( &ranked ), vec![ "critical", "high-impact", "low-first", "low-second" ] );
+}
+
+#[ test ]
+fn ranks_analyzed_recommendations()
+{
+  let deployment = ModelDeployment::new( "d1".to_string(), "chat".to_string(), "1.0".to_string(), DeploymentEnvironment::Production );
+  deployment.get_metrics().set_cpu_utilization( 95.0 );
+  deployment.get_metrics().set_memory_utilization( 90.0 );
+
+  let optimizer = PerformanceOptimizer::new();
+  let analyzed = optimizer.analyze_deployment( &deployment );
+  assert_eq!( titles( &analyzed ), vec![ "High CPU Utilization Detected", "High Memory Utilization Detected" ] );
+
+  // Memory (high impact, low effort) outranks CPU (high impact, medium effort)
+  let ranked = optimizer.prioritized_recommendations();
+  assert_eq!( titles( &ranked ), vec![ "High Memory Utilization Detected", "High CPU Utilization Detected" ] );
+}
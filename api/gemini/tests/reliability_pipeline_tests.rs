@@ -0,0 +1,177 @@
+//! Reliability pipeline tests
+//!
+//! Operations are plain closures so the tests observe stage ordering without
+//! a server.
+//!
+//! # Test Coverage
+//!
+//! - Stages run in order: rate limit, circuit breaker, retry, cache, operation
+//! - A cache hit short-circuits the operation
+//! - An open circuit fails fast without running the operation
+//! - Non-GET requests bypass the cache
+//! - A rate limit rejection stops before the circuit breaker
+
+#![ cfg( all( feature = "retry", feature = "circuit_breaker", feature = "rate_limiting", feature = "caching" ) ) ]
+
+use api_gemini::{ error::Error, internal::http::* };
+use core::time::Duration;
+use reqwest::Method;
+use std::sync::{ Arc, Mutex, atomic::{ AtomicU32, Ordering } };
+
+const URL : &str = "https://example.test/v1beta/models";
+
+fn retry_config() -> RetryConfig
+{
+  RetryConfig
+  {
+    max_retries : 3,
+    base_delay : Duration::from_millis( 1 ),
+    max_delay : Duration::from_millis( 5 ),
+    backoff_multiplier : 2.0,
+    enable_jitter : false,
+    max_elapsed_time : None,
+    retry_non_idempotent : false,
+  }
+}
+
+fn rate_limit( bucket_size : usize ) -> Arc< RateLimit >
+{
+  Arc::new( RateLimit::new( RateLimitingConfig
+  {
+    requests_per_second : 0.001,
+    bucket_size,
+    algorithm : "token_bucket".to_string(),
+    enable_metrics : true,
+  } ) )
+}
+
+fn circuit_breaker() -> Arc< CircuitBreaker >
+{
+  Arc::new( CircuitBreaker::new( CircuitBreakerConfig
+  {
+    failure_threshold : 1,
+    timeout : Duration::from_secs( 60 ),
+    success_threshold : 1,
+    enable_metrics : true,
+  } ) )
+}
+
+/// Build a pipeline with every stage enabled, recording entered stages
+fn pipeline( rate_limiter : Arc< RateLimit >, breaker : Arc< CircuitBreaker > ) -> ( ReliabilityPipeline, Arc< Mutex< Vec< PipelineStage > > > )
+{
+  let stages = Arc::new( Mutex::new( Vec::new() ) );
+  let recorder = Arc::clone( &stages );
+  let pipeline = ReliabilityPipeline::builder()
+    .rate_limit( rate_limiter )
+    .circuit_breaker( breaker )
+    .retry( retry_config() )
+    .cache( Arc::new( RequestCache::new( CacheConfig::default() ) ) )
+    .on_stage( move | stage | recorder.lock().unwrap().push( stage ) )
+    .build();
+  ( pipeline, stages )
+}
+
+fn take( stages : &Mutex< Vec< PipelineStage > > ) -> Vec< PipelineStage >
+{
+  core::mem::take( &mut *stages.lock().unwrap() )
+}
+
+#[ tokio::test ]
+async fn stages_run_in_order_and_cache_hit_short_circuits()
+{
+  let ( pipeline, stages ) = pipeline( rate_limit( 10 ), circuit_breaker() );
+  let calls = Arc::new( AtomicU32::new( 0 ) );
+
+  let operation = ||
+  {
+    let calls = Arc::clone( &calls );
+    async move
+    {
+      // First attempt fails transiently, the retry succeeds
+      if calls.fetch_add( 1, Ordering::SeqCst ) == 0
+      {
+        Err( Error::ServerError( "unavailable".to_string() ) )
+      }
+      else
+      {
+        Ok( "models".to_string() )
+      }
+    }
+  };
+
+  let first : String = pipeline.execute( &Method::GET, URL, None::< &() >, operation ).await.expect( "retry succeeds" );
+  assert_eq!( first, "models" );
+  assert_eq!( take( &stages ), vec!
+  [
+    PipelineStage::RateLimit,
+    PipelineStage::CircuitBreaker,
+    PipelineStage::Retry,
+    PipelineStage::Cache,
+    PipelineStage::Operation,
+    PipelineStage::Cache,
+    PipelineStage::Operation,
+  ] );
+  assert_eq!( calls.load( Ordering::SeqCst ), 2 );
+
+  let second : String = pipeline.execute( &Method::GET, URL, None::< &() >, operation ).await.expect( "served from cache" );
+  assert_eq!( second, "models" );
+  assert_eq!( take( &stages ), vec!
+  [
+    PipelineStage::RateLimit,
+    PipelineStage::CircuitBreaker,
+    PipelineStage::Retry,
+    PipelineStage::Cache,
+  ] );
+  assert_eq!( calls.load( Ordering::SeqCst ), 2, "cache hit must not run the operation" );
+}
+
+#[ tokio::test ]
+async fn open_circuit_fails_fast()
+{
+  let breaker = circuit_breaker();
+  breaker.record_failure();
+  let ( pipeline, stages ) = pipeline( rate_limit( 10 ), breaker );
+  let calls = &AtomicU32::new( 0 );
+
+  let result : Result< String, Error > = pipeline.execute( &Method::GET, URL, None::< &() >, || async move
+  {
+    calls.fetch_add( 1, Ordering::SeqCst );
+    Ok( "models".to_string() )
+  } ).await;
+
+  assert!( matches!( result, Err( Error::CircuitBreakerOpen( _ ) ) ) );
+  assert_eq!( take( &stages ), vec![ PipelineStage::RateLimit, PipelineStage::CircuitBreaker ] );
+  assert_eq!( calls.load( Ordering::SeqCst ), 0 );
+}
+
+#[ tokio::test ]
+async fn non_get_requests_bypass_cache_and_retry()
+{
+  let ( pipeline, stages ) = pipeline( rate_limit( 10 ), circuit_breaker() );
+
+  for _ in 0..2
+  {
+    let result : String = pipeline.execute( &Method::POST, URL, Some( &"prompt" ), || async { Ok( "generated".to_string() ) } ).await.expect( "operation succeeds" );
+    assert_eq!( result, "generated" );
+    assert_eq!( take( &stages ), vec!
+    [
+      PipelineStage::RateLimit,
+      PipelineStage::CircuitBreaker,
+      PipelineStage::Operation,
+    ] );
+  }
+}
+
+#[ tokio::test ]
+async fn rate_limit_rejection_stops_before_circuit_breaker()
+{
+  let ( pipeline, stages ) = pipeline( rate_limit( 1 ), circuit_breaker() );
+
+  let first : Result< String, Error > = pipeline.execute( &Method::POST, URL, None::< &() >, || async { Ok( "ok".to_string() ) } ).await;
+  assert!( first.is_ok() );
+  take( &stages );
+
+  let second : Result< String, Error > = pipeline.execute( &Method::POST, URL, None::< &() >, || async { Ok( "ok".to_string() ) } ).await;
+  assert!( matches!( second, Err( Error::RateLimited( _ ) ) ) );
+  assert_eq!( take( &stages ), vec![ PipelineStage::RateLimit ] );
+}
@@ -0,0 +1,168 @@
+//! Request metrics tests
+//!
+//! Tests for the `RequestMetrics` collected with the `diagnostics_timing` feature,
+//! using a local server that delays its headers and its body.
+//!
+//! # Test Coverage
+//!
+//! - A new connection to a host name records DNS and connect phases
+//! - Slow headers show up in `time_to_first_byte`
+//! - Slow bodies show up in `download`
+//! - `total` is the sum of the phases
+//! - A pooled connection records no DNS or connect phase
+//! - `execute_with_metrics` returns metrics without an interceptor
+//! - Fixture responses carry no metrics
+
+#![ cfg( feature = "diagnostics_timing" ) ]
+
+use api_gemini::{ client::Client, internal::http::{ self, ConnectTimingLayer, HttpConfig, TimingResolver }, Interceptor, RequestMetrics, RequestParts, ResponseParts };
+use std::io::Write;
+use std::net::TcpStream;
+use std::sync::{ Arc, Mutex };
+use std::time::Duration;
+
+mod local_server;
+
+const HEADER_DELAY : Duration = Duration::from_millis( 300 );
+const BODY_DELAY : Duration = Duration::from_millis( 200 );
+const BODY : &str = r#"{ "models" : [ { "name" : "models/slow-model" } ] }"#;
+
+/// Keeps the metrics of every response
+#[ derive( Debug, Default, Clone ) ]
+struct MetricsRecorder
+{
+  metrics : Arc< Mutex< Vec< Option< RequestMetrics > > > >,
+  fixture : Option< ResponseParts >,
+}
+
+impl Interceptor for MetricsRecorder
+{
+  fn before_request( &self, request : &mut RequestParts )
+  {
+    if let Some( fixture ) = &self.fixture
+    {
+      request.respond_with( fixture.clone() );
+    }
+  }
+
+  fn after_response( &self, response : &ResponseParts )
+  {
+    self.metrics.lock().unwrap().push( response.metrics );
+  }
+}
+
+fn client( base_url : &str, recorder : &MetricsRecorder ) -> Client
+{
+  Client::builder()
+  .api_key( "test-key".to_string() )
+  .base_url( base_url.to_string() )
+  .with_interceptor( recorder.clone() )
+  .build()
+  .expect( "client should build" )
+}
+
+/// Answer one request on `socket` after `HEADER_DELAY`, sending the body `BODY_DELAY` later
+fn respond_slowly( socket : &mut TcpStream )
+{
+  local_server::read_request( socket );
+
+  std::thread::sleep( HEADER_DELAY );
+  let headers = format!( "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n", BODY.len() );
+  socket.write_all( headers.as_bytes() ).unwrap();
+  socket.flush().unwrap();
+
+  std::thread::sleep( BODY_DELAY );
+  socket.write_all( BODY.as_bytes() ).unwrap();
+}
+
+/// Serve `requests` slow responses on one kept-alive connection, returning a `localhost` URL
+fn serve_slow( requests : usize ) -> ( String, std::thread::JoinHandle< () > )
+{
+  let ( base_url, server ) = local_server::serve( Some( 1 ), move | mut socket, _ |
+  {
+    for _ in 0..requests
+    {
+      respond_slowly( &mut socket );
+    }
+  } );
+  ( base_url.replace( "127.0.0.1", "localhost" ), server )
+}
+
+fn assert_slow_phases( metrics : &RequestMetrics )
+{
+  assert!( metrics.time_to_first_byte >= HEADER_DELAY, "{metrics:?}" );
+  assert!( metrics.download >= BODY_DELAY, "{metrics:?}" );
+  let setup = metrics.dns.unwrap_or_default() + metrics.connect.unwrap_or_default();
+  assert_eq!( metrics.total, setup + metrics.time_to_first_byte + metrics.download, "{metrics:?}" );
+}
+
+#[ tokio::test ]
+async fn test_phases_of_slow_response()
+{
+  let ( base_url, server ) = serve_slow( 1 );
+
+  let recorder = MetricsRecorder::default();
+  let response = client( &base_url, &recorder ).models().list().await.expect( "server should answer" );
+  server.join().unwrap();
+
+  assert_eq!( response.models[ 0 ].name, "models/slow-model" );
+  let metrics = recorder.metrics.lock().unwrap()[ 0 ].expect( "server responses carry metrics" );
+  assert!( metrics.dns.is_some(), "a new connection to a host name is resolved : {metrics:?}" );
+  assert!( metrics.connect.is_some(), "a new connection is opened : {metrics:?}" );
+  assert_slow_phases( &metrics );
+}
+
+#[ tokio::test ]
+async fn test_pooled_connection_has_no_setup_phases()
+{
+  let ( base_url, server ) = serve_slow( 2 );
+
+  let recorder = MetricsRecorder::default();
+  let client = client( &base_url, &recorder );
+  client.models().list().await.expect( "server should answer" );
+  client.models().list().await.expect( "server should answer on the same connection" );
+  server.join().unwrap();
+
+  let metrics = recorder.metrics.lock().unwrap().clone();
+  let first = metrics[ 0 ].expect( "server responses carry metrics" );
+  let second = metrics[ 1 ].expect( "server responses carry metrics" );
+  assert!( first.connect.is_some(), "{first:?}" );
+  assert_eq!( ( second.dns, second.connect ), ( None, None ), "{second:?}" );
+  assert_slow_phases( &second );
+}
+
+#[ tokio::test ]
+async fn test_execute_with_metrics_without_interceptor()
+{
+  let ( base_url, server ) = serve_slow( 1 );
+  let client = reqwest::Client::builder()
+  .dns_resolver( Arc::new( TimingResolver ) )
+  .connector_layer( ConnectTimingLayer )
+  .build()
+  .expect( "client should build" );
+
+  let ( response, metrics ) = http::execute_with_metrics::< (), serde_json::Value >
+  (
+    &client, reqwest::Method::GET, &format!( "{base_url}/v1beta/models" ), "test-key", None, &HttpConfig::default(),
+  ).await.expect( "server should answer" );
+  server.join().unwrap();
+
+  assert_eq!( response[ "models" ][ 0 ][ "name" ], "models/slow-model" );
+  let metrics = metrics.expect( "server responses carry metrics" );
+  assert!( metrics.dns.is_some() && metrics.connect.is_some(), "{metrics:?}" );
+  assert_slow_phases( &metrics );
+}
+
+#[ tokio::test ]
+async fn test_fixture_has_no_metrics()
+{
+  let recorder = MetricsRecorder
+  {
+    fixture : Some( ResponseParts::new( 200, r#"{ "models" : [] }"# ) ),
+    ..Default::default()
+  };
+
+  client( "http://127.0.0.1:9", &recorder ).models().list().await.expect( "fixture should answer" );
+
+  assert_eq!( *recorder.metrics.lock().unwrap(), vec![ None ] );
+}
@@ -0,0 +1,118 @@
+//! Per-request timeout override tests
+//!
+//! A local server delays every response, standing in for a slow generation.
+//!
+//! # Test Coverage
+//!
+//! - A short override times out with `Error::TimeoutError`
+//! - The same request succeeds under the client-wide timeout
+//! - An override longer than the delay succeeds
+//! - `execute_with_timeout` without an override behaves like `execute`
+//! - An override widens a shorter configured transport timeout
+//! - A timeout of exactly 30 seconds set through a setter is applied
+
+use api_gemini::{ client::Client, error::Error, internal::http::{ self, HttpConfig, DEFAULT_TIMEOUT_SECONDS }, Content, GenerateContentRequest, Part };
+use core::time::Duration;
+use reqwest::Method;
+
+mod local_server;
+
+const RESPONSE_DELAY : Duration = Duration::from_millis( 500 );
+
+/// Serve a valid generation response to every connection after `RESPONSE_DELAY`
+fn serve_slow() -> String
+{
+  local_server::serve( None, | mut socket, _ |
+  {
+    local_server::read_request( &mut socket );
+    std::thread::sleep( RESPONSE_DELAY );
+    local_server::respond( &mut socket, "200 OK", r#"{"candidates":[]}"# );
+  } ).0
+}
+
+fn client( base_url : &str ) -> Client
+{
+  Client::builder()
+  .api_key( "test-key".to_string() )
+  .base_url( base_url.to_string() )
+  .build()
+  .expect( "client should build" )
+}
+
+fn request() -> GenerateContentRequest
+{
+  GenerateContentRequest
+  {
+    contents : vec![ Content
+    {
+      parts : vec![ Part { text : Some( "Write a long story".to_string() ), ..Default::default() } ],
+      role : "user".to_string(),
+    } ],
+    ..Default::default()
+  }
+}
+
+#[ tokio::test ]
+async fn short_override_times_out_where_default_succeeds()
+{
+  let client = client( &serve_slow() );
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash" );
+
+  match model.generate_content_with_timeout( &request(), Duration::from_millis( 100 ) ).await
+  {
+    Err( Error::TimeoutError( msg ) ) => assert!( msg.contains( "100ms" ), "unexpected message : {msg}" ),
+    other => panic!( "expected per-call timeout, got {other:?}" ),
+  }
+
+  let response = model.generate_content( &request() ).await.expect( "client-wide timeout is long enough" );
+  assert!( response.candidates.is_empty() );
+}
+
+#[ tokio::test ]
+async fn override_longer_than_response_succeeds()
+{
+  let client = client( &serve_slow() );
+  let models = client.models();
+  let model = models.by_name( "gemini-2.5-flash" );
+
+  let response = model.generate_content_with_timeout( &request(), Duration::from_secs( 5 ) ).await.expect( "override covers the delay" );
+  assert!( response.candidates.is_empty() );
+}
+
+#[ tokio::test ]
+async fn no_override_behaves_like_execute()
+{
+  let url = format!( "{}/v1beta/models", serve_slow() );
+
+  let value : serde_json::Value = http::execute_with_timeout::< (), _ >( &reqwest::Client::new(), Method::GET, &url, "test-key", None, &HttpConfig::new(), None )
+  .await
+  .expect( "no per-call timeout applies" );
+  assert_eq!( value[ "candidates" ], serde_json::json!( [] ) );
+}
+
+#[ tokio::test ]
+async fn override_widens_shorter_transport_timeout()
+{
+  let url = format!( "{}/v1beta/models", serve_slow() );
+  let config = HttpConfig::new().with_timeout_duration( Duration::from_millis( 100 ) );
+
+  let value : serde_json::Value = http::execute_with_timeout::< (), _ >( &reqwest::Client::new(), Method::GET, &url, "test-key", None, &config, Some( Duration::from_secs( 5 ) ) )
+  .await
+  .expect( "the override outlasts the configured transport timeout" );
+  assert_eq!( value[ "candidates" ], serde_json::json!( [] ) );
+}
+
+#[ test ]
+fn default_valued_timeout_is_applied_when_set()
+{
+  assert_eq!( HttpConfig::new().request_timeout(), None, "the HTTP client's own timeout applies by default" );
+
+  let thirty = Duration::from_secs( DEFAULT_TIMEOUT_SECONDS );
+  assert_eq!( HttpConfig::new().with_timeout( DEFAULT_TIMEOUT_SECONDS ).request_timeout(), Some( thirty ) );
+  assert_eq!( HttpConfig::new().with_timeout_duration( thirty ).request_timeout(), Some( thirty ) );
+
+  let mut config = HttpConfig::new();
+  config.timeout_seconds = 60;
+  assert_eq!( config.request_timeout(), Some( Duration::from_secs( 60 ) ) );
+}
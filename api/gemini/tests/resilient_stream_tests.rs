@@ -0,0 +1,163 @@
+//! Tests for `ResilientStream` reconnection.
+//!
+//! Underlying connections are simulated with in-memory streams, so no network
+//! access is required.
+
+#![ cfg( feature = "streaming" ) ]
+
+use api_gemini::{ models::*, error::Error };
+use futures::{ StreamExt, stream };
+use std::sync::{ Arc, Mutex };
+
+fn text_chunk( text : &str ) -> Result< StreamingResponse, Error >
+{
+  Ok( StreamingResponse
+  {
+    candidates : Some( vec![ Candidate
+    {
+      content : Content
+      {
+        parts : vec![ Part { text : Some( text.to_string() ), ..Default::default() } ],
+        role : "model".to_string(),
+      },
+      finish_reason : None,
+      safety_ratings : None,
+      citation_metadata : None,
+      token_count : None,
+      index : Some( 0 ),
+    } ] ),
+    usage_metadata : None,
+    prompt_feedback : None,
+    grounding_metadata : None,
+    is_final : None,
+    error : None,
+  } )
+}
+
+fn request() -> GenerateContentRequest
+{
+  GenerateContentRequest
+  {
+    contents : vec![ Content
+    {
+      parts : vec![ Part { text : Some( "Tell me a story".to_string() ), ..Default::default() } ],
+      role : "user".to_string(),
+    } ],
+    ..Default::default()
+  }
+}
+
+fn chunk_text( chunk : &ResilientChunk ) -> String
+{
+  chunk.response.candidates.iter()
+    .flatten()
+    .flat_map( | candidate | candidate.content.parts.iter() )
+    .filter_map( | part | part.text.clone() )
+    .collect()
+}
+
+/// Serve one scripted connection per call and record the requests received
+fn scripted_connector
+(
+  script : Vec< Vec< Result< StreamingResponse, Error > > >,
+)
+-> ( Arc< Mutex< Vec< GenerateContentRequest > > >, impl FnMut( GenerateContentRequest ) -> futures::future::Ready< Result< stream::Iter< std::vec::IntoIter< Result< StreamingResponse, Error > > >, Error > > )
+{
+  let requests = Arc::new( Mutex::new( Vec::new() ) );
+  let recorded = Arc::clone( &requests );
+  let mut script = script.into_iter();
+
+  let connect = move | request : GenerateContentRequest |
+  {
+    recorded.lock().unwrap().push( request );
+    let chunks = script.next().unwrap_or_else( || vec![ Err( Error::NetworkError( "connection refused".to_string() ) ) ] );
+    futures::future::ready( Ok( stream::iter( chunks ) ) )
+  };
+
+  ( requests, connect )
+}
+
+#[ tokio::test ]
+async fn test_stream_continues_after_disconnect()
+{
+  let ( requests, connect ) = scripted_connector( vec!
+  [
+    vec![ text_chunk( "Once upon " ), text_chunk( "a time" ), Err( Error::NetworkError( "connection reset".to_string() ) ) ],
+    vec![ text_chunk( ", there was" ), text_chunk( " a dragon." ) ],
+  ] );
+
+  let chunks : Vec< ResilientChunk > = ResilientStream::new()
+    .stream( request(), connect )
+    .map( | chunk | chunk.expect( "stream should recover from the disconnect" ) )
+    .collect()
+    .await;
+
+  let text : String = chunks.iter().map( chunk_text ).collect();
+  assert_eq!( text, "Once upon a time, there was a dragon." );
+
+  let seams : Vec< bool > = chunks.iter().map( | chunk | chunk.is_seam ).collect();
+  assert_eq!( seams, vec![ false, false, true, false ] );
+  assert_eq!( chunks[ 3 ].reconnects, 1 );
+
+  let requests = requests.lock().unwrap();
+  assert_eq!( requests.len(), 2 );
+  let resumed = &requests[ 1 ].contents;
+  assert_eq!( resumed.len(), 3 );
+  assert_eq!( resumed[ 1 ].role, "model" );
+  assert_eq!( resumed[ 1 ].parts[ 0 ].text.as_deref(), Some( "Once upon a time" ) );
+  assert_eq!( resumed[ 2 ].role, "user" );
+}
+
+#[ tokio::test ]
+async fn test_reconnects_are_bounded()
+{
+  let ( requests, connect ) = scripted_connector( vec![ vec![ text_chunk( "Hello" ), Err( Error::NetworkError( "reset".to_string() ) ) ] ] );
+
+  let items : Vec< Result< ResilientChunk, Error > > = ResilientStream::new()
+    .with_max_reconnects( 2 )
+    .stream( request(), connect )
+    .collect()
+    .await;
+
+  assert_eq!( requests.lock().unwrap().len(), 3, "initial connection plus two reconnects" );
+  assert!( matches!( items.last(), Some( Err( Error::NetworkError( _ ) ) ) ) );
+  assert_eq!( items.iter().filter( | item | item.is_ok() ).count(), 1 );
+}
+
+#[ tokio::test ]
+async fn test_elapsed_budget_stops_reconnecting()
+{
+  let ( requests, connect ) = scripted_connector( vec![] );
+
+  let items : Vec< Result< ResilientChunk, Error > > = ResilientStream::new()
+    .with_max_reconnects( 100 )
+    .with_max_elapsed_time( Some( core::time::Duration::ZERO ) )
+    .stream( request(), connect )
+    .collect()
+    .await;
+
+  assert_eq!( requests.lock().unwrap().len(), 1 );
+  assert_eq!( items.len(), 1 );
+  assert!( items[ 0 ].is_err() );
+}
+
+#[ tokio::test ]
+async fn test_non_connection_error_is_not_retried()
+{
+  let ( requests, connect ) = scripted_connector( vec![ vec![ Err( Error::InvalidArgument( "bad request".to_string(), None ) ) ] ] );
+
+  let items : Vec< Result< ResilientChunk, Error > > = ResilientStream::new()
+    .stream( request(), connect )
+    .collect()
+    .await;
+
+  assert_eq!( requests.lock().unwrap().len(), 1 );
+  assert!( matches!( items.as_slice(), [ Err( Error::InvalidArgument( _, _ ) ) ] ) );
+}
+
+#[ test ]
+fn test_resume_request_without_received_text_is_unchanged()
+{
+  let resumed = ResilientStream::new().resume_request( &request(), "" );
+  assert_eq!( resumed.contents.len(), 1 );
+}
@@ -0,0 +1,99 @@
+//! Response body size limit tests
+//!
+//! Tests for `HttpConfig::max_response_bytes`, which bounds how much of a response
+//! body `execute` is willing to read into memory.
+//!
+//! # Test Coverage
+//!
+//! - Bodies just under the limit are accepted
+//! - Bodies over the limit are rejected via `Content-Length`
+//! - Chunked bodies over the limit are rejected while streaming
+//! - No limit preserves the default behavior
+
+use api_gemini::{ error::Error, internal::http::{ self, HttpConfig } };
+use reqwest::Method;
+use std::io::Write;
+
+mod local_server;
+
+/// Serve a single HTTP/1.1 response on a local socket and return its URL
+fn serve_once( body : String, chunked : bool ) -> String
+{
+  let ( base_url, _ ) = local_server::serve( Some( 1 ), move | mut socket, _ |
+  {
+    local_server::read_request( &mut socket );
+    if chunked
+    {
+      let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\nConnection: close\r\n\r\n{:x}\r\n{}\r\n0\r\n\r\n",
+        body.len(),
+        body
+      );
+      let _ = socket.write_all( response.as_bytes() );
+    }
+    else
+    {
+      local_server::respond( &mut socket, "200 OK", &body );
+    }
+  } );
+
+  format!( "{base_url}/v1beta/models" )
+}
+
+/// JSON string value whose serialized form is exactly `len` bytes
+fn json_body( len : usize ) -> String
+{
+  format!( "\"{}\"", "a".repeat( len - 2 ) )
+}
+
+async fn fetch( url : &str, config : &HttpConfig ) -> Result< serde_json::Value, Error >
+{
+  http ::execute::< (), serde_json::Value >( &reqwest::Client::new(), Method::GET, url, "test-key", None, config ).await
+}
+
+#[ tokio::test ]
+async fn test_response_just_under_limit_is_accepted()
+{
+  let url = serve_once( json_body( 1023 ), false );
+  let config = HttpConfig::new().with_max_response_bytes( 1024 );
+
+  let value = fetch( &url, &config ).await.expect( "body under the limit should be accepted" );
+  assert_eq!( value.as_str().map( str::len ), Some( 1021 ) );
+}
+
+#[ tokio::test ]
+async fn test_response_exceeding_limit_is_rejected()
+{
+  let url = serve_once( json_body( 2048 ), false );
+  let config = HttpConfig::new().with_max_response_bytes( 1024 );
+
+  match fetch( &url, &config ).await
+  {
+    Err( Error::DeserializationError( msg ) ) => assert!( msg.contains( "limit of 1024 bytes" ), "unexpected message : {msg}" ),
+    other => panic!( "Expected DeserializationError for oversized body, got : {other:?}" ),
+  }
+}
+
+#[ tokio::test ]
+async fn test_chunked_response_exceeding_limit_is_rejected()
+{
+  let url = serve_once( json_body( 2048 ), true );
+  let config = HttpConfig::new().with_max_response_bytes( 1024 );
+
+  match fetch( &url, &config ).await
+  {
+    Err( Error::DeserializationError( msg ) ) => assert!( msg.contains( "limit of 1024 bytes" ), "unexpected message : {msg}" ),
+    other => panic!( "Expected DeserializationError for oversized chunked body, got : {other:?}" ),
+  }
+}
+
+#[ tokio::test ]
+async fn test_no_limit_reads_full_body()
+{
+  let url = serve_once( json_body( 4096 ), false );
+  let config = HttpConfig::new();
+
+  assert!( config.max_response_bytes.is_none() );
+  let value = fetch( &url, &config ).await.expect( "unlimited config should read the full body" );
+  assert_eq!( value.as_str().map( str::len ), Some( 4094 ) );
+}
@@ -0,0 +1,135 @@
+//! Retry idempotency tests
+//!
+//! Tests that retry logic only replays requests that are safe to repeat, so
+//! mutations such as uploads or tuning-job creation are never duplicated.
+//! `MockTransport` answers every attempt with `503 Service Unavailable`.
+//!
+//! # Test Coverage
+//!
+//! - Idempotency derived from the HTTP method and endpoint
+//! - `GET` requests are retried on transient failures
+//! - `POST` requests are attempted exactly once unless marked idempotent
+//! - `POST` requests to side-effect free actions such as `:generateContent` are retried
+//! - Per-call override and `RetryConfig::retry_non_idempotent`
+
+#![ cfg( feature = "retry" ) ]
+
+use api_gemini::{ error::Error, internal::http::{ self, HttpConfig, RetryConfig }, testing::MockTransport, ResponseParts };
+use core::time::Duration;
+use reqwest::Method;
+use std::sync::Arc;
+
+fn retry_config( retry_non_idempotent : bool ) -> RetryConfig
+{
+  RetryConfig
+  {
+    max_retries : 2,
+    base_delay : Duration::from_millis( 1 ),
+    max_delay : Duration::from_millis( 5 ),
+    backoff_multiplier : 2.0,
+    enable_jitter : false,
+    max_elapsed_time : None,
+    retry_non_idempotent,
+  }
+}
+
+async fn call( method : Method, config : &RetryConfig, idempotent : Option< bool > ) -> ( Result< serde_json::Value, Error >, usize )
+{
+  call_path( method, "", config, idempotent ).await
+}
+
+async fn call_path( method : Method, path : &str, config : &RetryConfig, idempotent : Option< bool > ) -> ( Result< serde_json::Value, Error >, usize )
+{
+  let path = format!( "/v1beta/models{path}" );
+  let mock = MockTransport::new().on( method.clone(), path.clone(), ResponseParts::new( 503, "service unavailable" ) );
+  let url = format!( "http://127.0.0.1:9{path}" );
+  let body = serde_json::json!( { "displayName" : "job" } );
+  let body = ( method == Method::POST ).then_some( &body );
+
+  let result = http::execute_with_retries(
+    &reqwest::Client::new(),
+    method,
+    &url,
+    "test-key",
+    body,
+    &HttpConfig::new().with_interceptor( Arc::new( mock.clone() ) ),
+    Some( config ),
+    idempotent,
+  ).await;
+
+  ( result, mock.calls().len() )
+}
+
+#[ test ]
+fn test_idempotency_derived_from_method()
+{
+  const URL : &str = "https://generativelanguage.googleapis.com/v1beta/tunedModels";
+  let config = retry_config( false );
+
+  assert!( http::is_idempotent_method( &Method::GET ) );
+  assert!( !http::is_idempotent_method( &Method::POST ) );
+  assert!( http::is_retry_allowed( &Method::GET, URL, None, &config ) );
+  assert!( !http::is_retry_allowed( &Method::POST, URL, None, &config ) );
+  assert!( http::is_retry_allowed( &Method::POST, URL, Some( true ), &config ) );
+  assert!( !http::is_retry_allowed( &Method::GET, URL, Some( false ), &config ) );
+  assert!( http::is_retry_allowed( &Method::POST, URL, None, &retry_config( true ) ) );
+}
+
+#[ test ]
+fn test_idempotency_derived_from_endpoint()
+{
+  let base = "https://generativelanguage.googleapis.com/v1beta";
+
+  assert!( http::is_idempotent_request( &Method::POST, &format!( "{base}/models/gemini-2.5-flash:generateContent" ) ) );
+  assert!( http::is_idempotent_request( &Method::POST, &format!( "{base}/models/gemini-2.5-flash:countTokens?key=abc" ) ) );
+  assert!( http::is_idempotent_request( &Method::POST, &format!( "{base}/models/text-embedding-004:batchEmbedContents" ) ) );
+  assert!( !http::is_idempotent_request( &Method::POST, &format!( "{base}/tunedModels" ) ) );
+  assert!( !http::is_idempotent_request( &Method::POST, &format!( "{base}/models/gemini-2.5-flash:batchGenerateContent" ) ) );
+  assert!( !http::is_idempotent_request( &Method::DELETE, &format!( "{base}/models/gemini-2.5-flash:generateContent" ) ) );
+  assert!( !http::is_retry_allowed( &Method::POST, &format!( "{base}/models/m:generateContent" ), Some( false ), &retry_config( false ) ) );
+}
+
+#[ tokio::test ]
+async fn test_get_is_retried_on_failure()
+{
+  let ( result, attempts ) = call( Method::GET, &retry_config( false ), None ).await;
+
+  assert!( matches!( result, Err( Error::ServerError( _ ) ) ) );
+  assert_eq!( attempts, 3, "GET should be attempted once plus max_retries times" );
+}
+
+#[ tokio::test ]
+async fn test_non_idempotent_post_is_attempted_once()
+{
+  let ( result, attempts ) = call( Method::POST, &retry_config( false ), None ).await;
+
+  assert!( matches!( result, Err( Error::ServerError( _ ) ) ) );
+  assert_eq!( attempts, 1, "non-idempotent POST must not be replayed" );
+}
+
+#[ tokio::test ]
+async fn test_post_marked_idempotent_is_retried()
+{
+  let ( result, attempts ) = call( Method::POST, &retry_config( false ), Some( true ) ).await;
+
+  assert!( matches!( result, Err( Error::ServerError( _ ) ) ) );
+  assert_eq!( attempts, 3 );
+}
+
+#[ tokio::test ]
+async fn test_post_to_idempotent_action_is_retried()
+{
+  let ( result, attempts ) = call_path( Method::POST, "/gemini-2.5-flash:generateContent", &retry_config( false ), None ).await;
+
+  assert!( matches!( result, Err( Error::ServerError( _ ) ) ) );
+  assert_eq!( attempts, 3, "generateContent is side-effect free and should be replayed" );
+}
+
+#[ tokio::test ]
+async fn test_retry_non_idempotent_config_retries_post()
+{
+  let ( result, attempts ) = call( Method::POST, &retry_config( true ), None ).await;
+
+  assert!( matches!( result, Err( Error::ServerError( _ ) ) ) );
+  assert_eq!( attempts, 3 );
+}
@@ -58,6 +58,8 @@ mod integration_tests
         candidate_count: Some( 1 ),
         max_output_tokens: Some( 800 ),
         stop_sequences: None,
+        response_mime_type: None,
+        response_schema: None,
       }),
     };
 
@@ -0,0 +1,75 @@
+//! Safety ratings tests
+//!
+//! Tests for `SafetyRatings`, the aggregate view over per-category
+//! `SafetyRating` values used to enforce custom safety gates.
+//!
+//! # Test Coverage
+//!
+//! - Ordering and parsing of `HarmProbability` levels
+//! - Maximum probability across categories
+//! - Strict per-category threshold checks
+//! - Human-readable summary line
+
+use api_gemini::models::{ HarmProbability, SafetyRating, SafetyRatings };
+
+fn rating( category : &str, probability : &str, blocked : Option< bool > ) -> SafetyRating
+{
+  SafetyRating { category : category.to_string(), probability : probability.to_string(), blocked }
+}
+
+fn ratings() -> SafetyRatings
+{
+  SafetyRatings::new( vec!
+  [
+    rating( "HARM_CATEGORY_HARASSMENT", "LOW", None ),
+    rating( "HARM_CATEGORY_HATE_SPEECH", "MEDIUM", Some( true ) ),
+    rating( "HARM_CATEGORY_DANGEROUS_CONTENT", "NEGLIGIBLE", Some( false ) ),
+  ] )
+}
+
+#[ test ]
+fn test_probability_levels()
+{
+  assert!( HarmProbability::Unspecified < HarmProbability::Negligible );
+  assert!( HarmProbability::Negligible < HarmProbability::Low );
+  assert!( HarmProbability::Low < HarmProbability::Medium );
+  assert!( HarmProbability::Medium < HarmProbability::High );
+
+  assert_eq!( HarmProbability::from( "HIGH" ), HarmProbability::High );
+  assert_eq!( HarmProbability::from( "SOMETHING_NEW" ), HarmProbability::Unspecified );
+  assert_eq!( HarmProbability::Medium.to_string(), "MEDIUM" );
+  assert_eq!( rating( "HARM_CATEGORY_HARASSMENT", "LOW", None ).probability_level(), HarmProbability::Low );
+}
+
+#[ test ]
+fn test_max_probability()
+{
+  assert_eq!( ratings().max_probability(), HarmProbability::Medium );
+  assert_eq!( SafetyRatings::default().max_probability(), HarmProbability::Unspecified );
+
+  let high = SafetyRatings::from( vec![ rating( "HARM_CATEGORY_SEXUALLY_EXPLICIT", "HIGH", None ) ] );
+  assert_eq!( high.max_probability(), HarmProbability::High );
+}
+
+#[ test ]
+fn test_exceeds_threshold()
+{
+  let ratings = ratings();
+
+  assert!( ratings.exceeds( "HARM_CATEGORY_HATE_SPEECH", HarmProbability::Low ) );
+  assert!( !ratings.exceeds( "HARM_CATEGORY_HATE_SPEECH", HarmProbability::Medium ) );
+  assert!( ratings.exceeds( "HARM_CATEGORY_HARASSMENT", HarmProbability::Negligible ) );
+  assert!( !ratings.exceeds( "HARM_CATEGORY_DANGEROUS_CONTENT", HarmProbability::Negligible ) );
+  // Unrated categories never exceed
+  assert!( !ratings.exceeds( "HARM_CATEGORY_CIVIC_INTEGRITY", HarmProbability::Unspecified ) );
+}
+
+#[ test ]
+fn test_summary()
+{
+  assert_eq!(
+    ratings().summary(),
+    "max MEDIUM: HARASSMENT=LOW, HATE_SPEECH=MEDIUM (blocked), DANGEROUS_CONTENT=NEGLIGIBLE"
+  );
+  assert_eq!( SafetyRatings::default().summary(), "no safety ratings" );
+}
@@ -0,0 +1,66 @@
+//! Stop sequences tests
+//!
+//! Tests for `GenerationConfig::with_stop_sequences` and the stop sequence
+//! checks in `validation::validate_generation_config`.
+//!
+//! # Test Coverage
+//!
+//! - Serialization as `stopSequences`
+//! - Up to 5 sequences accepted, including whitespace-only ones
+//! - Rejection of 6 sequences and of empty strings
+//! - Generate content request validation covers the generation config
+
+use api_gemini::{ models::{ ContentBuilder, GenerateContentRequest, GenerationConfig }, validation::{ validate_enhanced_generate_content_request, validate_generation_config, ValidationError } };
+
+fn sequences( count : usize ) -> Vec< String >
+{
+  ( 0..count ).map( | i | format!( "STOP{i}" ) ).collect()
+}
+
+#[ test ]
+fn test_serialized_as_stop_sequences()
+{
+  let config = GenerationConfig::default().with_stop_sequences( vec![ "END".to_string(), "\n\n".to_string() ] );
+
+  let json = serde_json::to_value( &config ).unwrap();
+  assert_eq!( json, serde_json::json!( { "stopSequences" : [ "END", "\n\n" ] } ) );
+}
+
+#[ test ]
+fn test_up_to_five_sequences_accepted()
+{
+  assert!( validate_generation_config( &GenerationConfig::default() ).is_ok() );
+  assert!( validate_generation_config( &GenerationConfig::default().with_stop_sequences( sequences( 5 ) ) ).is_ok() );
+  assert!( validate_generation_config( &GenerationConfig::default().with_stop_sequences( vec![ "\n".to_string() ] ) ).is_ok() );
+}
+
+#[ test ]
+fn test_six_sequences_rejected()
+{
+  let error = validate_generation_config( &GenerationConfig::default().with_stop_sequences( sequences( 6 ) ) ).unwrap_err();
+
+  assert!( matches!( error, ValidationError::CollectionTooLarge { size : 6, max : 5, .. } ), "{error:?}" );
+}
+
+#[ test ]
+fn test_empty_sequence_rejected()
+{
+  let config = GenerationConfig::default().with_stop_sequences( vec![ "END".to_string(), String::new() ] );
+
+  let error = validate_generation_config( &config ).unwrap_err();
+  assert!( matches!( error, ValidationError::RequiredFieldMissing { ref field, .. } if field == "stop_sequences[1]" ), "{error:?}" );
+}
+
+#[ test ]
+fn test_request_validation_checks_generation_config()
+{
+  let request = GenerateContentRequest
+  {
+    contents : vec![ ContentBuilder::user().text( "Count to ten" ).build().unwrap() ],
+    generation_config : Some( GenerationConfig::default().with_stop_sequences( sequences( 6 ) ) ),
+    ..Default::default()
+  };
+
+  let error = validate_enhanced_generate_content_request( &request ).unwrap_err();
+  assert!( error.to_string().contains( "stop_sequences" ), "{error}" );
+}
@@ -0,0 +1,105 @@
+//! External cancellation token tests for `ControllableStream`
+//!
+//! These tests drive local `futures` streams, so they run without an API key.
+//!
+//! # Test Coverage
+//!
+//! - Cancelling the external token moves the stream to `Cancelled` and stops it yielding
+//! - Buffered chunks are discarded with `auto_cleanup` and delivered without it
+//! - A token cancelled before creation stops the stream immediately
+
+#![ cfg( feature = "streaming_control" ) ]
+
+use api_gemini::{ error::Error, models::streaming_control::* };
+use core::time::Duration;
+use futures::{ stream, StreamExt };
+use tokio::time::timeout;
+use tokio_util::sync::CancellationToken;
+
+/// Two chunks followed by a source that never completes on its own
+fn endless_source( items : &[ &str ] ) -> std::pin::Pin< Box< dyn futures::Stream< Item = Result< String, Error > > + Send > >
+{
+  let items : Vec< Result< String, Error > > = items.iter().map( | item | Ok( ( *item ).to_string() ) ).collect();
+  Box::pin( stream::iter( items ).chain( stream::pending() ) )
+}
+
+fn config( auto_cleanup : bool ) -> StreamControlConfig
+{
+  StreamControlConfig::builder()
+  .auto_cleanup( auto_cleanup )
+  .control_operation_timeout( Duration::from_secs( 1 ) )
+  .build()
+  .expect( "valid config" )
+}
+
+#[ tokio::test ]
+async fn external_token_cancels_running_stream()
+{
+  let token = CancellationToken::new();
+  let mut controllable = ControllableStream::with_cancellation_token( endless_source( &[ "a", "b" ] ), config( true ), Some( token.clone() ) );
+
+  assert_eq!( controllable.next().await.unwrap().unwrap(), "a" );
+  assert_eq!( controllable.next().await.unwrap().unwrap(), "b" );
+  assert_eq!( controllable.state(), StreamState::Running );
+
+  token.cancel();
+
+  let end = timeout( Duration::from_secs( 1 ), controllable.next() ).await.expect( "stream should end promptly" );
+  assert!( end.is_none(), "cancelled stream should stop yielding" );
+  assert_eq!( controllable.state(), StreamState::Cancelled );
+  assert!( controllable.is_cancelled() );
+}
+
+#[ tokio::test ]
+async fn auto_cleanup_discards_buffered_chunks()
+{
+  let ( tx, rx ) = tokio::sync::mpsc::unbounded_channel::< Result< String, Error > >();
+  let source = Box::pin( tokio_stream::wrappers::UnboundedReceiverStream::new( rx ) );
+  let token = CancellationToken::new();
+  let mut controllable = ControllableStream::with_cancellation_token( source, config( true ), Some( token.clone() ) );
+
+  controllable.pause().await.expect( "pause should succeed" );
+  tx.send( Ok( "buffered".to_string() ) ).unwrap();
+  tokio::time::sleep( Duration::from_millis( 50 ) ).await;
+  assert_eq!( controllable.get_metrics().buffer_size, 1 );
+
+  token.cancel();
+
+  let end = timeout( Duration::from_secs( 1 ), controllable.next() ).await.expect( "stream should end promptly" );
+  assert!( end.is_none(), "buffered chunk should be discarded" );
+  assert_eq!( controllable.state(), StreamState::Cancelled );
+  assert_eq!( controllable.get_metrics().buffer_size, 0 );
+}
+
+#[ tokio::test ]
+async fn without_auto_cleanup_buffered_chunks_are_delivered()
+{
+  let ( tx, rx ) = tokio::sync::mpsc::unbounded_channel::< Result< String, Error > >();
+  let source = Box::pin( tokio_stream::wrappers::UnboundedReceiverStream::new( rx ) );
+  let token = CancellationToken::new();
+  let mut controllable = ControllableStream::with_cancellation_token( source, config( false ), Some( token.clone() ) );
+
+  controllable.pause().await.expect( "pause should succeed" );
+  tx.send( Ok( "buffered".to_string() ) ).unwrap();
+  tokio::time::sleep( Duration::from_millis( 50 ) ).await;
+
+  token.cancel();
+
+  let item = timeout( Duration::from_secs( 1 ), controllable.next() ).await.expect( "buffered chunk should arrive" );
+  assert_eq!( item.unwrap().unwrap(), "buffered" );
+  assert!( controllable.next().await.is_none(), "no chunks after the buffer is flushed" );
+  assert_eq!( controllable.state(), StreamState::Cancelled );
+}
+
+#[ tokio::test ]
+async fn pre_cancelled_token_stops_stream_immediately()
+{
+  let token = CancellationToken::new();
+  token.cancel();
+
+  let mut controllable = ControllableStream::with_cancellation_token( endless_source( &[] ), config( true ), Some( token ) );
+
+  let end = timeout( Duration::from_secs( 1 ), controllable.next() ).await.expect( "stream should end promptly" );
+  assert!( end.is_none() );
+  assert_eq!( controllable.state(), StreamState::Cancelled );
+}
@@ -0,0 +1,52 @@
+//! Throughput helpers on `StreamMetricsSnapshot`
+//!
+//! # Test Coverage
+//!
+//! - Bytes and chunks per second over known counters and durations
+//! - Average chunk size, including the zero-chunk case
+//! - Zero durations yield zero rates instead of infinity
+//! - `StreamMetrics::elapsed_since_start` advances from creation
+
+#![ cfg( feature = "streaming_control" ) ]
+
+use api_gemini::models::streaming_control::*;
+use core::time::Duration;
+
+fn snapshot( total_chunks : u64, bytes_received : u64 ) -> StreamMetricsSnapshot
+{
+  let mut snapshot = StreamMetrics::new().snapshot();
+  snapshot.total_chunks = total_chunks;
+  snapshot.bytes_received = bytes_received;
+  snapshot
+}
+
+#[ test ]
+fn rates_over_known_counters()
+{
+  let snapshot = snapshot( 40, 10_000 );
+
+  assert!( ( snapshot.bytes_per_second( Duration::from_secs( 4 ) ) - 2500.0 ).abs() < f64::EPSILON );
+  assert!( ( snapshot.chunks_per_second( Duration::from_secs( 4 ) ) - 10.0 ).abs() < f64::EPSILON );
+  assert!( ( snapshot.bytes_per_second( Duration::from_millis( 500 ) ) - 20_000.0 ).abs() < f64::EPSILON );
+  assert!( ( snapshot.average_chunk_size() - 250.0 ).abs() < f64::EPSILON );
+}
+
+#[ test ]
+fn zero_duration_and_zero_chunks_yield_zero()
+{
+  let snapshot = snapshot( 0, 0 );
+  assert!( snapshot.average_chunk_size().abs() < f64::EPSILON );
+  assert!( snapshot.chunks_per_second( Duration::from_secs( 1 ) ).abs() < f64::EPSILON );
+
+  let snapshot = self::snapshot( 5, 100 );
+  assert!( snapshot.bytes_per_second( Duration::ZERO ).abs() < f64::EPSILON );
+  assert!( snapshot.chunks_per_second( Duration::ZERO ).abs() < f64::EPSILON );
+}
+
+#[ test ]
+fn elapsed_since_start_advances()
+{
+  let metrics = StreamMetrics::new();
+  std::thread::sleep( Duration::from_millis( 20 ) );
+  assert!( metrics.elapsed_since_start() >= Duration::from_millis( 20 ) );
+}
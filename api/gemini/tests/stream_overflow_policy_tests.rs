@@ -0,0 +1,106 @@
+//! Pause buffer overflow policy tests
+//!
+//! A channel-backed source lets each test push more chunks than `max_buffered_chunks`
+//! while the stream is paused, then resume and observe which chunks survive.
+//!
+//! # Test Coverage
+//!
+//! - `Cancel` (the default) cancels the stream when the buffer overflows
+//! - `DropOldest` keeps the most recent chunks
+//! - `DropNewest` keeps the earliest chunks
+//! - `Block` stops reading the source and delivers everything after resume
+//! - `buffer_overflows` counts only dropped chunks and cancellations, not backpressure
+
+#![ cfg( feature = "streaming_control" ) ]
+
+use api_gemini::{ error::Error, models::streaming_control::* };
+use core::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::timeout;
+
+const MAX_BUFFERED : usize = 3;
+const SENT : usize = 5;
+
+/// Pause a stream with the given policy and push `SENT` chunks into it
+async fn paused_and_filled( policy : OverflowPolicy ) -> ( ControllableStream< String >, mpsc::UnboundedSender< Result< String, Error > > )
+{
+  let ( tx, rx ) = mpsc::unbounded_channel::< Result< String, Error > >();
+  let source = Box::pin( tokio_stream::wrappers::UnboundedReceiverStream::new( rx ) );
+  let config = StreamControlConfig::builder()
+  .max_buffered_chunks( MAX_BUFFERED )
+  .overflow_policy( policy )
+  .control_operation_timeout( Duration::from_secs( 1 ) )
+  .build()
+  .expect( "valid config" );
+  let mut controllable = ControllableStream::new( source, config );
+
+  controllable.pause().await.expect( "pause should succeed" );
+  for index in 0..SENT
+  {
+    tx.send( Ok( format!( "chunk{index}" ) ) ).unwrap();
+  }
+  tokio::time::sleep( Duration::from_millis( 50 ) ).await;
+  ( controllable, tx )
+}
+
+async fn fill_while_paused( policy : OverflowPolicy ) -> ( Vec< String >, StreamMetricsSnapshot )
+{
+  let ( mut controllable, tx ) = paused_and_filled( policy ).await;
+  let metrics = controllable.get_metrics();
+
+  controllable.resume().await.expect( "resume should succeed" );
+  drop( tx );
+
+  let mut received = Vec::new();
+  while let Ok( Some( item ) ) = timeout( Duration::from_secs( 1 ), controllable.next() ).await
+  {
+    received.push( item.expect( "chunk should be ok" ) );
+  }
+  ( received, metrics )
+}
+
+#[ tokio::test ]
+async fn drop_oldest_keeps_latest_chunks()
+{
+  let ( received, metrics ) = fill_while_paused( OverflowPolicy::DropOldest ).await;
+
+  assert_eq!( received, vec![ "chunk2", "chunk3", "chunk4" ] );
+  assert_eq!( metrics.buffer_size, MAX_BUFFERED );
+  assert_eq!( metrics.buffer_overflows, 2 );
+}
+
+#[ tokio::test ]
+async fn drop_newest_keeps_earliest_chunks()
+{
+  let ( received, metrics ) = fill_while_paused( OverflowPolicy::DropNewest ).await;
+
+  assert_eq!( received, vec![ "chunk0", "chunk1", "chunk2" ] );
+  assert_eq!( metrics.buffer_size, MAX_BUFFERED );
+  assert_eq!( metrics.buffer_overflows, 2 );
+}
+
+#[ tokio::test ]
+async fn block_applies_backpressure_without_loss()
+{
+  let ( received, metrics ) = fill_while_paused( OverflowPolicy::Block ).await;
+
+  assert_eq!( received, vec![ "chunk0", "chunk1", "chunk2", "chunk3", "chunk4" ] );
+  assert_eq!( metrics.buffer_size, MAX_BUFFERED, "source should not be read past a full buffer" );
+  assert_eq!( metrics.buffer_overflows, 0, "backpressure loses nothing" );
+}
+
+#[ tokio::test ]
+async fn cancel_stops_stream_on_overflow()
+{
+  let ( controllable, _tx ) = paused_and_filled( OverflowPolicy::Cancel ).await;
+
+  assert_eq!( controllable.state(), StreamState::Cancelled );
+  assert_eq!( controllable.get_metrics().buffer_overflows, 1 );
+}
+
+#[ test ]
+fn cancel_is_default_policy()
+{
+  assert_eq!( StreamControlConfig::default().overflow_policy, OverflowPolicy::Cancel );
+  assert_eq!( OverflowPolicy::default(), OverflowPolicy::Cancel );
+}
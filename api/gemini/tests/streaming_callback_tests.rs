@@ -0,0 +1,155 @@
+//! Tests for the streaming-to-callback bridge.
+//!
+//! The assembly tests feed pre-built `StreamingResponse` chunks through
+//! `drive_stream_with_callbacks`; the integration test drives the real
+//! `:streamGenerateContent` endpoint and requires `GEMINI_API_KEY`.
+
+#![ cfg( feature = "streaming" ) ]
+
+use api_gemini::{ models::*, error::Error };
+
+fn text_chunk( text : &str, finish_reason : Option< &str > ) -> Result< StreamingResponse, Error >
+{
+  Ok( StreamingResponse
+  {
+    candidates : Some( vec![ Candidate
+    {
+      content : Content
+      {
+        parts : vec![ Part { text : Some( text.to_string() ), ..Default::default() } ],
+        role : "model".to_string(),
+      },
+      finish_reason : finish_reason.map( ToString::to_string ),
+      safety_ratings : None,
+      citation_metadata : None,
+      token_count : None,
+      index : Some( 0 ),
+    } ] ),
+    usage_metadata : None,
+    prompt_feedback : None,
+    grounding_metadata : None,
+    is_final : Some( finish_reason.is_some() ),
+    error : None,
+  } )
+}
+
+#[ tokio::test ]
+async fn callbacks_receive_each_chunk_and_final_response()
+{
+  let chunks = vec![
+    text_chunk( "Hello", None ),
+    text_chunk( ", ", None ),
+    text_chunk( "world", Some( "STOP" ) ),
+    Ok( StreamingResponse { candidates : None, usage_metadata : None, prompt_feedback : None, grounding_metadata : None, is_final : Some( true ), error : None } ),
+  ];
+
+  let mut tokens = Vec::new();
+  let mut done = None;
+
+  let response = drive_stream_with_callbacks(
+    futures::stream::iter( chunks ),
+    | token | tokens.push( token.to_string() ),
+    | final_response | done = Some( final_response ),
+  )
+  .await
+  .expect( "stream should complete" );
+
+  assert_eq!( tokens, vec![ "Hello", ", ", "world" ] );
+  assert_eq!( response.candidates.len(), 1 );
+  assert_eq!( response.candidates[ 0 ].content.parts.len(), 1 );
+  assert_eq!( response.candidates[ 0 ].content.parts[ 0 ].text.as_deref(), Some( "Hello, world" ) );
+  assert_eq!( response.candidates[ 0 ].finish_reason.as_deref(), Some( "STOP" ) );
+
+  let done = done.expect( "on_done should be invoked" );
+  assert_eq!( done.candidates[ 0 ].content.parts[ 0 ].text.as_deref(), Some( "Hello, world" ) );
+}
+
+#[ tokio::test ]
+async fn stream_error_propagates_without_on_done()
+{
+  let chunks = vec![
+    text_chunk( "partial", None ),
+    Err( Error::NetworkError( "connection reset".to_string() ) ),
+  ];
+
+  let mut tokens = Vec::new();
+  let mut done_called = false;
+
+  let result = drive_stream_with_callbacks(
+    futures::stream::iter( chunks ),
+    | token | tokens.push( token.to_string() ),
+    | _ | done_called = true,
+  )
+  .await;
+
+  assert!( matches!( result, Err( Error::NetworkError( _ ) ) ) );
+  assert_eq!( tokens, vec![ "partial" ] );
+  assert!( !done_called );
+}
+
+#[ tokio::test ]
+async fn prompt_feedback_and_grounding_metadata_are_kept()
+{
+  let mut first = text_chunk( "Paris", None ).unwrap();
+  first.prompt_feedback = serde_json::from_value( serde_json::json!(
+  {
+    "safetyRatings" : [ { "category" : "HARM_CATEGORY_HARASSMENT", "probability" : "NEGLIGIBLE" } ]
+  } ) ).unwrap();
+  let mut last = text_chunk( " is the capital.", Some( "STOP" ) ).unwrap();
+  last.grounding_metadata = serde_json::from_value( serde_json::json!(
+  {
+    "webSearchQueries" : [ "capital of France" ],
+    "groundingChunks" : [ { "uri" : "https://example.com/paris", "title" : "Paris" } ]
+  } ) ).unwrap();
+
+  let response = drive_stream_with_callbacks( futures::stream::iter( vec![ Ok( first ), Ok( last ) ] ), | _ | {}, | _ | {} )
+  .await
+  .expect( "stream should complete" );
+
+  let feedback = response.prompt_feedback.expect( "prompt feedback should be kept" );
+  assert_eq!( feedback.safety_ratings.map( | ratings | ratings.len() ), Some( 1 ) );
+  let grounding = response.grounding_metadata.expect( "grounding metadata should be kept" );
+  assert_eq!( grounding.web_search_queries, Some( vec![ "capital of France".to_string() ] ) );
+  assert_eq!( grounding.grounding_chunks.map( | chunks | chunks.len() ), Some( 1 ) );
+}
+
+#[ cfg( feature = "integration" ) ]
+#[ tokio::test ]
+async fn integration_streaming_callback_real_api()
+{
+  let client = api_gemini::client::Client::new().unwrap_or_else( | error | panic!(
+    "\n❌ INTEGRATION TEST FAILURE: No valid API key found!\n\
+    \n🔑 Required: Set GEMINI_API_KEY environment variable or create secret/-secrets.sh\n\
+    \n🚫 Error details : {error:?}\n"
+  ) );
+
+  let request = GenerateContentRequest
+  {
+    contents : vec![ Content
+    {
+      parts : vec![ Part { text : Some( "Count from 1 to 5, separated by spaces.".to_string() ), ..Default::default() } ],
+      role : "user".to_string(),
+    } ],
+    ..Default::default()
+  };
+
+  let mut streamed = String::new();
+  let mut done_text = None;
+
+  let response = client
+    .models()
+    .by_name( "gemini-2.5-flash" )
+    .generate_content_streaming_callback(
+      &request,
+      | token | streamed.push_str( token ),
+      | done | done_text = done.candidates.first()
+        .and_then( | candidate | candidate.content.parts.first() )
+        .and_then( | part | part.text.clone() ),
+    )
+    .await
+    .expect( "streaming callback request should succeed" );
+
+  assert!( !streamed.is_empty(), "on_token should receive text" );
+  assert_eq!( done_text.as_deref(), Some( streamed.as_str() ) );
+  assert_eq!( response.candidates[ 0 ].content.parts[ 0 ].text.as_deref(), Some( streamed.as_str() ) );
+}
@@ -1,5 +1,7 @@
 //! Streaming control functionality tests
 
+#![ cfg( feature = "streaming_control" ) ]
+
 use api_gemini::client::Client;
 use api_gemini::models::streaming_control::*;
 use std::time::Duration;
@@ -334,7 +336,7 @@ mod integration_tests
     let error_stream = stream::iter( vec![
     Ok( "good_data_1".to_string() ),
     Ok( "good_data_2".to_string() ),
-    Err( api_gemini::error::Error::ApiError( "Test error".to_string() ) ),
+    Err( api_gemini::error::Error::ApiError( "Test error".to_string(), None ) ),
     Ok( "good_data_3".to_string() ),
     ]);
     let boxed_stream = Box::pin( error_stream );
@@ -589,6 +591,7 @@ mod unit_tests
       control_operations: AtomicU64::new( 6 ),
       buffer_overflows: AtomicU64::new( 0 ),
       items_sent: AtomicU64::new( 12 ),
+      started_at: std::time::Instant::now(),
     };
 
     let snapshot = metrics.snapshot();
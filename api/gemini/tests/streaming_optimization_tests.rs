@@ -1,5 +1,7 @@
 //! Tests specifically for streaming control optimizations
 
+#![ cfg( feature = "streaming_control" ) ]
+
 use api_gemini::models::streaming_control::*;
 use futures::stream;
 use std::time::Duration;
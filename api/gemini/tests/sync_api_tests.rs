@@ -256,7 +256,7 @@ fn test_sync_streaming_blocking()
         println!( "⚠️  Authentication error (expected without valid API key)" );
         // Test passes - authentication error is expected behavior
       }
-      Err(api_gemini::error::Error::ApiError(msg)) if msg.contains("API key") =>
+      Err(api_gemini::error::Error::ApiError(msg, _)) if msg.contains("API key") =>
       {
       println!("⚠️  API key error (expected without valid API key): {msg}");
         // Test passes - API key error is expected behavior
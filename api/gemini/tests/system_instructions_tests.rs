@@ -48,6 +48,8 @@ conversation_history: Option< Vec< Content > >,
       function_response: None,
       file_data: None,
       video_metadata: None,
+      executable_code: None,
+      code_execution_result: None,
     } ],
   };
 
@@ -60,6 +62,8 @@ conversation_history: Option< Vec< Content > >,
       function_response: None,
       file_data: None,
       video_metadata: None,
+      executable_code: None,
+      code_execution_result: None,
     } ],
     role: "user".to_string(),
   } );
@@ -73,6 +77,8 @@ conversation_history: Option< Vec< Content > >,
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     } ),
     safety_settings: None,
     tools: None,
@@ -94,6 +100,8 @@ fn create_basic_request( user_message: &str ) -> GenerateContentRequest
         function_response: None,
         file_data: None,
         video_metadata: None,
+        executable_code: None,
+        code_execution_result: None,
       } ],
       role: "user".to_string(),
     } ],
@@ -104,6 +112,8 @@ fn create_basic_request( user_message: &str ) -> GenerateContentRequest
       candidate_count: Some( 1 ),
       max_output_tokens: Some( 1024 ),
       stop_sequences: None,
+      response_mime_type: None,
+      response_schema: None,
     } ),
     safety_settings: None,
     tools: None,
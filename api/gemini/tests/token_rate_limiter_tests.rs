@@ -0,0 +1,68 @@
+//! Token rate limiter tests
+//!
+//! Uses a short window in place of a minute so the budget rolls over quickly.
+//!
+//! # Test Coverage
+//!
+//! - Acquisitions within the budget return immediately
+//! - An exhausted budget blocks until earlier usage leaves the window
+//! - Estimates larger than the budget are rejected
+//! - `ClientBuilder::with_token_rate_limit` wires a budget shared by clones
+
+#![ cfg( feature = "rate_limiting" ) ]
+
+use api_gemini::{ client::Client, error::Error, internal::http::TokenRateLimiter };
+use core::time::Duration;
+use std::time::Instant;
+
+const WINDOW : Duration = Duration::from_millis( 300 );
+
+#[ tokio::test ]
+async fn exhausted_budget_blocks_until_window_rolls()
+{
+  let limiter = TokenRateLimiter::new( 1_000 ).with_window( WINDOW );
+
+  let start = Instant::now();
+  limiter.acquire_tokens( 600 ).await.expect( "fits in budget" );
+  limiter.acquire_tokens( 400 ).await.expect( "fits in budget" );
+  assert!( start.elapsed() < WINDOW / 2, "acquisitions within budget must not wait" );
+  assert_eq!( limiter.available_tokens(), 0 );
+  assert!( !limiter.try_acquire_tokens( 1 ).expect( "valid estimate" ) );
+
+  limiter.acquire_tokens( 500 ).await.expect( "fits once the window rolls" );
+  assert!( start.elapsed() >= WINDOW, "must wait for the first reservation to expire" );
+  assert!( limiter.available_tokens() <= 500, "the new reservation counts against the budget" );
+}
+
+#[ test ]
+fn oversized_estimate_is_rejected()
+{
+  let limiter = TokenRateLimiter::new( 100 );
+
+  assert!( matches!( limiter.try_acquire_tokens( 101 ), Err( Error::InvalidArgument( .. ) ) ) );
+  assert!( limiter.try_acquire_tokens( 100 ).expect( "valid estimate" ) );
+  assert_eq!( limiter.budget(), 100 );
+  assert_eq!( limiter.window(), Duration::from_secs( 60 ) );
+}
+
+#[ tokio::test ]
+async fn builder_wires_shared_budget()
+{
+  let client = Client::builder()
+  .api_key( "test-key".to_string() )
+  .with_token_rate_limit( 1_000 )
+  .build()
+  .expect( "client should build" );
+  let clone = client.clone();
+
+  client.acquire_tokens( 700 ).await.expect( "fits in budget" );
+  let limiter = clone.token_rate_limiter().expect( "token rate limit configured" );
+  assert_eq!( limiter.available_tokens(), 300 );
+  assert!( matches!( clone.acquire_tokens( 5_000 ).await, Err( Error::InvalidArgument( .. ) ) ) );
+
+  let unlimited = Client::builder().api_key( "test-key".to_string() ).build().expect( "client should build" );
+  assert!( unlimited.token_rate_limiter().is_none() );
+  unlimited.acquire_tokens( u32::MAX ).await.expect( "no budget configured" );
+
+  assert!( Client::builder().api_key( "test-key".to_string() ).with_token_rate_limit( 0 ).build().is_err() );
+}
@@ -0,0 +1,147 @@
+//! Per-role token attribution tests
+//!
+//! Tests for `AnalyzeTokensRequest::role_token_breakdown`, which splits a known
+//! token total between the roles that make up a request.
+//!
+//! # Test Coverage
+//!
+//! - Multi-role requests with system, user, model and tool contributions
+//! - Role sums always equal the total, including awkward remainders
+//! - Heavier roles receive more tokens
+//! - Contents set in both places are attributed once
+//! - `TokenBreakdown::by_role` deserialization
+
+use api_gemini::models::*;
+
+fn text_content( role : &str, text : &str ) -> Content
+{
+  Content { parts : vec![ Part { text : Some( text.to_string() ), ..Default::default() } ], role : role.to_string() }
+}
+
+fn multi_role_request() -> AnalyzeTokensRequest
+{
+  let contents = vec![
+    text_content( "user", "Plan a three day trip to Kyoto with a focus on temples and food." ),
+    Content
+    {
+      parts : vec![ Part
+      {
+        function_call : Some( FunctionCall { name : "search_places".to_string(), args : serde_json::json!( { "city" : "Kyoto" } ) } ),
+        ..Default::default()
+      } ],
+      role : "model".to_string(),
+    },
+    Content
+    {
+      parts : vec![ Part
+      {
+        function_response : Some( FunctionResponse
+        {
+          name : "search_places".to_string(),
+          response : serde_json::json!( { "places" : [ "Kinkaku-ji", "Fushimi Inari", "Nishiki Market" ] } ),
+        } ),
+        ..Default::default()
+      } ],
+      role : "user".to_string(),
+    },
+    text_content( "model", "Day one: Fushimi Inari at sunrise, then Nishiki Market for lunch." ),
+  ];
+
+  AnalyzeTokensRequest
+  {
+    contents : Vec::new(),
+    generate_content_request : Some( GenerateContentRequest
+    {
+      contents,
+      system_instruction : Some( SystemInstruction
+      {
+        role : "system".to_string(),
+        parts : vec![ Part { text : Some( "You are an experienced travel planner.".to_string() ), ..Default::default() } ],
+      } ),
+      tools : Some( vec![ Tool
+      {
+        function_declarations : Some( vec![ FunctionDeclaration
+        {
+          name : "search_places".to_string(),
+          description : "Search notable places in a city".to_string(),
+          parameters : Some( serde_json::json!( { "type" : "object", "properties" : { "city" : { "type" : "string" } } } ) ),
+        } ] ),
+        code_execution : None,
+        google_search_retrieval : None,
+        code_execution_tool : None,
+      } ] ),
+      ..Default::default()
+    } ),
+    include_breakdown : Some( true ),
+    estimate_generation_tokens : None,
+  }
+}
+
+#[ test ]
+fn test_multi_role_sums_equal_total()
+{
+  let request = multi_role_request();
+
+  for total in [ 0, 1, 7, 97, 1234 ]
+  {
+    let by_role = request.role_token_breakdown( total );
+    assert_eq!( by_role.values().sum::< i32 >(), total, "role sums should equal total {total} : {by_role:?}" );
+  }
+
+  let by_role = request.role_token_breakdown( 500 );
+  for role in [ "system", "user", "model", "tool" ]
+  {
+    assert!( by_role.contains_key( role ), "missing role {role} in {by_role:?}" );
+    assert!( by_role[ role ] > 0, "role {role} should contribute tokens" );
+  }
+}
+
+#[ test ]
+fn test_heavier_role_receives_more_tokens()
+{
+  let request = AnalyzeTokensRequest
+  {
+    contents : vec![
+      text_content( "user", &"long prompt ".repeat( 100 ) ),
+      text_content( "model", "ok" ),
+    ],
+    generate_content_request : None,
+    include_breakdown : Some( true ),
+    estimate_generation_tokens : None,
+  };
+
+  let by_role = request.role_token_breakdown( 300 );
+  assert_eq!( by_role.values().sum::< i32 >(), 300 );
+  assert!( by_role[ "user" ] > by_role[ "model" ] );
+  assert!( !by_role.contains_key( "system" ) );
+}
+
+#[ test ]
+fn test_contents_counted_from_one_source()
+{
+  let mut request = multi_role_request();
+  let embedded_only = request.role_token_breakdown( 1000 );
+
+  // Mirroring the turns into `contents` must not double their share
+  request.contents = request.generate_content_request.as_ref().unwrap().contents.clone();
+  assert_eq!( request.role_token_breakdown( 1000 ), embedded_only );
+
+  // Without an embedded request, `contents` is used
+  let contents_only = AnalyzeTokensRequest { generate_content_request : None, ..request };
+  let by_role = contents_only.role_token_breakdown( 1000 );
+  assert!( by_role[ "user" ] > 0 );
+  assert!( !by_role.contains_key( "system" ) );
+}
+
+#[ test ]
+fn test_by_role_deserializes()
+{
+  let breakdown : TokenBreakdown = serde_json::from_str( r#"{ "textTokens" : 10, "byRole" : { "user" : 6, "system" : 4 } }"# )
+  .expect( "breakdown should parse" );
+  let by_role = breakdown.by_role.expect( "byRole should be present" );
+  assert_eq!( by_role[ "user" ], 6 );
+  assert_eq!( by_role[ "system" ], 4 );
+
+  let breakdown : TokenBreakdown = serde_json::from_str( r#"{ "textTokens" : 10 }"# ).expect( "breakdown should parse" );
+  assert!( breakdown.by_role.is_none() );
+}
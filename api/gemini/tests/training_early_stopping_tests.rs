@@ -0,0 +1,95 @@
+//! Early-stopping recommendation tests
+//!
+//! # Test Coverage
+//!
+//! - A plateauing validation loss recommends stopping after `patience` evaluations
+//! - A steadily improving loss never recommends stopping
+//! - Improvements smaller than `min_delta` do not reset patience
+//! - Steps without an evaluation are ignored
+//! - `TrainingJob` records metrics into its history
+
+use api_gemini::models::model_tuning::{ HyperparameterConfig, TrainingJob, TrainingMetrics, TrainingMetricsHistory };
+
+fn evaluation( step : usize, validation_loss : f64 ) -> TrainingMetrics
+{
+  TrainingMetrics { step, validation_loss : Some( validation_loss ), ..TrainingMetrics::default() }
+}
+
+fn history( losses : &[ f64 ] ) -> TrainingMetricsHistory
+{
+  let mut history = TrainingMetricsHistory::new();
+  for ( i, loss ) in losses.iter().enumerate()
+  {
+    history.record( evaluation( i * 100, *loss ) );
+  }
+  history
+}
+
+#[ test ]
+fn plateau_recommends_stopping()
+{
+  let mut losses = vec![ 2.0, 1.5, 1.2, 1.0 ];
+  let mut history = history( &losses );
+  assert!( !history.should_early_stop( 3, 0.01 ) );
+
+  // Plateau around 1.0 : stop once three evaluations fail to improve
+  for ( i, loss ) in [ 1.005, 0.998, 1.002 ].into_iter().enumerate()
+  {
+    assert!( !history.should_early_stop( 3, 0.01 ), "recommended early after {} plateau evaluations", i );
+    losses.push( loss );
+    history.record( evaluation( losses.len() * 100, loss ) );
+  }
+  assert!( history.should_early_stop( 3, 0.01 ) );
+  assert_eq!( history.best_validation_loss(), Some( 0.998 ) );
+}
+
+#[ test ]
+fn improving_loss_keeps_training()
+{
+  let mut history = TrainingMetricsHistory::new();
+  let mut loss = 2.0;
+  for step in 0..50
+  {
+    history.record( evaluation( step * 100, loss ) );
+    assert!( !history.should_early_stop( 3, 0.01 ), "recommended stopping at step {step} with loss {loss}" );
+    loss *= 0.95;
+  }
+}
+
+#[ test ]
+fn small_improvements_do_not_reset_patience()
+{
+  let history = history( &[ 1.0, 0.99, 0.98, 0.97 ] );
+
+  assert!( history.should_early_stop( 3, 0.05 ), "improvements below min_delta count as a plateau" );
+  assert!( !history.should_early_stop( 3, 0.0 ), "any improvement resets patience without min_delta" );
+}
+
+#[ test ]
+fn steps_without_evaluation_are_ignored()
+{
+  let mut history = history( &[ 1.0, 1.0 ] );
+  for step in 0..10
+  {
+    history.record( TrainingMetrics { step, loss : 0.5, ..TrainingMetrics::default() } );
+  }
+
+  assert_eq!( history.validation_losses(), vec![ 1.0, 1.0 ] );
+  assert!( !history.should_early_stop( 2, 0.0 ) );
+  assert!( history.should_early_stop( 1, 0.0 ) );
+  assert!( !TrainingMetricsHistory::new().should_early_stop( 1, 0.0 ) );
+}
+
+#[ test ]
+fn training_job_records_history()
+{
+  let job = TrainingJob::new( "job-1".to_string(), HyperparameterConfig::default() );
+  for ( i, loss ) in [ 1.0, 1.0, 1.0 ].into_iter().enumerate()
+  {
+    job.record_metrics( evaluation( i, loss ) );
+  }
+
+  assert_eq!( job.get_metrics().step, 2 );
+  assert_eq!( job.get_metrics_history().entries().len(), 3 );
+  assert!( job.get_metrics_history().should_early_stop( 2, 0.0 ) );
+}
@@ -0,0 +1,79 @@
+//! Training progress ETA tests
+//!
+//! # Test Coverage
+//!
+//! - No speed or ETA before the first step completes or without a total
+//! - Speed and ETA follow from completed steps and elapsed time
+//! - The ETA converges on the true remaining time as steps complete
+//! - Completed jobs report a zero ETA and 100%
+
+use api_gemini::models::model_tuning::{ TrainingMetrics, TrainingProgress };
+use std::time::Duration;
+
+const TOTAL_STEPS : usize = 100;
+
+fn snapshot( step : usize, elapsed_time_seconds : f64, total_steps : usize ) -> TrainingProgress
+{
+  TrainingProgress::new( TrainingMetrics { step, elapsed_time_seconds, ..TrainingMetrics::default() }, total_steps )
+}
+
+/// Elapsed time after `step` steps : a 5s warm-up step, then 0.5s per step
+fn elapsed_after( step : usize ) -> f64
+{
+  5.0 + ( step - 1 ) as f64 * 0.5
+}
+
+#[ test ]
+fn no_eta_without_enough_data()
+{
+  let not_started = snapshot( 0, 0.0, TOTAL_STEPS );
+  assert_eq!( not_started.steps_per_second(), None );
+  assert_eq!( not_started.eta(), None );
+  assert_eq!( not_started.estimated_time_remaining, None );
+
+  let no_step_yet = snapshot( 0, 12.0, TOTAL_STEPS );
+  assert_eq!( no_step_yet.eta(), None );
+
+  let unknown_total = snapshot( 10, 5.0, 0 );
+  assert_eq!( unknown_total.steps_per_second(), Some( 2.0 ) );
+  assert_eq!( unknown_total.eta(), None );
+}
+
+#[ test ]
+fn eta_from_steps_and_elapsed_time()
+{
+  let progress = snapshot( 25, 10.0, TOTAL_STEPS );
+
+  assert_eq!( progress.steps_per_second(), Some( 2.5 ) );
+  assert_eq!( progress.eta(), Some( Duration::from_secs( 30 ) ) );
+  assert_eq!( progress.estimated_time_remaining, progress.eta() );
+  assert!( ( progress.percentage - 25.0 ).abs() < f64::EPSILON );
+}
+
+#[ test ]
+fn eta_converges_as_steps_complete()
+{
+  let mut previous_error = f64::INFINITY;
+  for step in [ 10, 30, 50, 70, 90, 99 ]
+  {
+    let progress = snapshot( step, elapsed_after( step ), TOTAL_STEPS );
+    let actual_remaining = elapsed_after( TOTAL_STEPS ) - elapsed_after( step );
+    let error = ( progress.eta().unwrap().as_secs_f64() - actual_remaining ).abs();
+
+    assert!( error < previous_error, "error {error:.3}s at step {step} did not shrink from {previous_error:.3}s" );
+    previous_error = error;
+  }
+  assert!( previous_error < 0.1, "final error {previous_error:.3}s" );
+}
+
+#[ test ]
+fn completed_job_has_zero_eta()
+{
+  let done = snapshot( TOTAL_STEPS, elapsed_after( TOTAL_STEPS ), TOTAL_STEPS );
+  assert_eq!( done.eta(), Some( Duration::ZERO ) );
+  assert!( ( done.percentage - 100.0 ).abs() < f64::EPSILON );
+
+  let overshoot = snapshot( TOTAL_STEPS + 5, 60.0, TOTAL_STEPS );
+  assert_eq!( overshoot.eta(), Some( Duration::ZERO ) );
+  assert!( ( overshoot.percentage - 100.0 ).abs() < f64::EPSILON );
+}
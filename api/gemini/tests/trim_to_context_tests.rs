@@ -0,0 +1,168 @@
+//! Context trimming tests
+//!
+//! Tests for `GenerateContentRequest::trim_to_context`, using a counter that
+//! charges one token per text byte and 10 tokens per non-text part.
+//!
+//! # Test Coverage
+//!
+//! - Requests within budget are left unchanged
+//! - Oldest contents are dropped first
+//! - A single-text content is shortened, keeping its end, when that is enough
+//! - Non-text contents are dropped rather than shortened
+//! - The system instruction and the final user turn are always preserved
+//! - A function call and its response are dropped together
+//! - A final function response keeps the call it answers
+
+use api_gemini::models::{ Content, ContextTrim, FunctionCall, FunctionResponse, GenerateContentRequest, Part, SystemInstruction };
+
+fn count( content : &Content ) -> usize
+{
+  content.parts.iter().map( | part | part.text.as_ref().map_or( 10, String::len ) ).sum()
+}
+
+fn turn( role : &str, text : &str ) -> Content
+{
+  Content { role : role.to_string(), parts : vec![ Part { text : Some( text.to_string() ), ..Default::default() } ] }
+}
+
+fn texts( request : &GenerateContentRequest ) -> Vec< String >
+{
+  request.contents.iter().map( | content | content.parts[ 0 ].text.clone().unwrap_or_default() ).collect()
+}
+
+fn function_call() -> Content
+{
+  Content
+  {
+    role : "model".to_string(),
+    parts : vec![ Part { function_call : Some( FunctionCall { name : "lookup".to_string(), args : serde_json::json!( {} ) } ), ..Default::default() } ],
+  }
+}
+
+fn function_response() -> Content
+{
+  Content
+  {
+    role : "user".to_string(),
+    parts : vec![ Part
+    {
+      function_response : Some( FunctionResponse { name : "lookup".to_string(), response : serde_json::json!( { "ok" : true } ) } ),
+      ..Default::default()
+    } ],
+  }
+}
+
+/// 3 system tokens and three 10 token turns, 33 in total
+fn conversation() -> GenerateContentRequest
+{
+  GenerateContentRequest
+  {
+    contents : vec![ turn( "user", "0123456789" ), turn( "model", "bbbbbbbbbb" ), turn( "user", "cccccccccc" ) ],
+    system_instruction : Some( SystemInstruction
+    {
+      role : "system".to_string(),
+      parts : vec![ Part { text : Some( "sys".to_string() ), ..Default::default() } ],
+    } ),
+    ..Default::default()
+  }
+}
+
+#[ test ]
+fn within_budget_unchanged()
+{
+  let mut request = conversation();
+  let trim = request.trim_to_context( 33, count );
+
+  assert!( !trim.is_trimmed() );
+  assert_eq!( trim, ContextTrim { remaining_tokens : 33, ..Default::default() } );
+  assert_eq!( request.contents.len(), 3 );
+}
+
+#[ test ]
+fn oldest_content_dropped()
+{
+  let mut request = conversation();
+  let trim = request.trim_to_context( 23, count );
+
+  assert_eq!( trim, ContextTrim { removed_contents : 1, truncated_contents : 0, trimmed_tokens : 10, remaining_tokens : 23 } );
+  assert_eq!( texts( &request ), vec![ "bbbbbbbbbb", "cccccccccc" ] );
+}
+
+#[ test ]
+fn oldest_content_truncated()
+{
+  let mut request = conversation();
+  let trim = request.trim_to_context( 28, count );
+
+  assert_eq!( trim, ContextTrim { removed_contents : 0, truncated_contents : 1, trimmed_tokens : 5, remaining_tokens : 28 } );
+  assert_eq!( texts( &request ), vec![ "56789", "bbbbbbbbbb", "cccccccccc" ] );
+  assert_eq!( request.contents[ 0 ].role, "user" );
+}
+
+#[ test ]
+fn non_text_content_dropped()
+{
+  let mut request = conversation();
+  request.contents[ 0 ] = Content
+  {
+    role : "user".to_string(),
+    parts : vec![ Part
+    {
+      function_response : Some( FunctionResponse { name : "lookup".to_string(), response : serde_json::json!( { "ok" : true } ) } ),
+      ..Default::default()
+    } ],
+  };
+
+  let trim = request.trim_to_context( 28, count );
+
+  assert_eq!( trim.removed_contents, 1 );
+  assert_eq!( trim.truncated_contents, 0 );
+  assert_eq!( trim.remaining_tokens, 23 );
+}
+
+#[ test ]
+fn system_instruction_and_final_user_turn_preserved()
+{
+  let mut request = conversation();
+  request.contents.push( turn( "model", "eeee" ) );
+
+  // Only the system instruction and the final user turn fit in 13 tokens
+  let trim = request.trim_to_context( 13, count );
+  assert_eq!( trim, ContextTrim { removed_contents : 3, truncated_contents : 0, trimmed_tokens : 24, remaining_tokens : 13 } );
+  assert_eq!( texts( &request ), vec![ "cccccccccc" ] );
+  assert!( request.system_instruction.is_some() );
+
+  // They are kept even when they alone exceed the budget
+  let trim = request.trim_to_context( 1, count );
+  assert!( !trim.is_trimmed() );
+  assert_eq!( trim.remaining_tokens, 13 );
+  assert_eq!( texts( &request ), vec![ "cccccccccc" ] );
+}
+
+#[ test ]
+fn function_call_and_response_dropped_together()
+{
+  let mut request = conversation();
+  request.contents.splice( 1..1, [ function_call(), function_response() ] );
+
+  // Dropping the call alone would fit in 38 tokens, but would orphan its response
+  let trim = request.trim_to_context( 38, count );
+
+  assert_eq!( trim, ContextTrim { removed_contents : 3, truncated_contents : 0, trimmed_tokens : 30, remaining_tokens : 23 } );
+  assert_eq!( texts( &request ), vec![ "bbbbbbbbbb", "cccccccccc" ] );
+}
+
+#[ test ]
+fn final_function_response_keeps_its_call()
+{
+  let mut request = conversation();
+  request.contents.truncate( 1 );
+  request.contents.extend( [ function_call(), function_response() ] );
+
+  let trim = request.trim_to_context( 13, count );
+
+  assert_eq!( trim, ContextTrim { removed_contents : 1, truncated_contents : 0, trimmed_tokens : 10, remaining_tokens : 23 } );
+  assert_eq!( request.contents.len(), 2 );
+  assert!( request.contents[ 0 ].parts[ 0 ].function_call.is_some() );
+  assert!( request.contents[ 1 ].parts[ 0 ].function_response.is_some() );
+}
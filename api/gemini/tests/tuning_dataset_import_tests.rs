@@ -0,0 +1,115 @@
+//! Tuning dataset import tests
+//!
+//! # Test Coverage
+//!
+//! - JSONL records become examples, accepting `text_input` and `textInput`
+//! - Malformed JSONL lines are skipped with a warning naming the line
+//! - CSV columns are selected by header name, with quoted fields
+//! - Malformed CSV rows are skipped with a warning naming the line
+//! - A CSV header without the requested columns is an error
+
+use api_gemini::models::{ Dataset, ImportWarning, TuningExample };
+
+fn pairs( dataset : &Dataset ) -> Vec< ( &str, &str ) >
+{
+  dataset.examples.as_ref().unwrap().examples.iter()
+    .map( | TuningExample { text_input, output } | ( text_input.as_deref().unwrap(), output.as_deref().unwrap() ) )
+    .collect()
+}
+
+fn warning_lines( warnings : &[ ImportWarning ] ) -> Vec< usize >
+{
+  warnings.iter().map( | warning | warning.line ).collect()
+}
+
+#[ test ]
+fn imports_jsonl()
+{
+  let jsonl = r#"{"text_input": "1", "output": "one"}
+{"textInput": "2", "output": "two"}
+
+{"text_input": "3", "output": "three", "extra": true}
+"#;
+
+  let ( dataset, warnings ) = Dataset::from_jsonl( jsonl.as_bytes() ).unwrap();
+
+  assert_eq!( pairs( &dataset ), vec![ ( "1", "one" ), ( "2", "two" ), ( "3", "three" ) ] );
+  assert!( warnings.is_empty(), "{warnings:?}" );
+}
+
+#[ test ]
+fn jsonl_skips_malformed_lines()
+{
+  let jsonl = r#"{"text_input": "ok", "output": "fine"}
+{"text_input": "truncated"
+["not", "an", "object"]
+{"text_input": "no output"}
+{"text_input": "blank output", "output": "  "}
+{"text_input": "last", "output": "kept"}"#;
+
+  let ( dataset, warnings ) = Dataset::from_jsonl( jsonl.as_bytes() ).unwrap();
+
+  assert_eq!( pairs( &dataset ), vec![ ( "ok", "fine" ), ( "last", "kept" ) ] );
+  assert_eq!( warning_lines( &warnings ), vec![ 2, 3, 4, 5 ] );
+  assert!( warnings[ 0 ].reason.starts_with( "invalid JSON" ) );
+  assert_eq!( warnings[ 1 ].reason, "record is not a JSON object" );
+  assert_eq!( warnings[ 2 ].reason, "missing 'output' field" );
+  assert_eq!( warnings[ 3 ].reason, "empty 'output' field" );
+}
+
+#[ test ]
+fn imports_csv_by_column_name()
+{
+  let csv = "id,question,answer\r\n\
+1,What is 2+2?,4\r\n\
+2,\"Say \"\"hi\"\", politely\",\"Hello,\nthere\"\r\n\
+3,  Capital of France?  ,Paris\r\n";
+
+  let ( dataset, warnings ) = Dataset::from_csv( csv.as_bytes(), "question", "answer" ).unwrap();
+
+  assert_eq!( pairs( &dataset ), vec![
+    ( "What is 2+2?", "4" ),
+    ( "Say \"hi\", politely", "Hello,\nthere" ),
+    ( "  Capital of France?  ", "Paris" ),
+  ] );
+  assert!( warnings.is_empty(), "{warnings:?}" );
+}
+
+#[ test ]
+fn csv_skips_malformed_rows()
+{
+  let csv = "input,output
+good,row
+only-one-field
+\"quoted\"trailing,x
+bad \"quote\",x
+
+,empty input
+multi,\"line
+ok\"
+\"unterminated,x";
+
+  let ( dataset, warnings ) = Dataset::from_csv( csv.as_bytes(), "input", "output" ).unwrap();
+
+  assert_eq!( pairs( &dataset ), vec![ ( "good", "row" ), ( "multi", "line\nok" ) ] );
+  assert_eq!( warning_lines( &warnings ), vec![ 3, 4, 5, 7, 10 ] );
+  assert_eq!( warnings[ 0 ].reason, "expected at least 2 fields, found 1" );
+  assert_eq!( warnings[ 1 ].reason, "unexpected character after quoted field" );
+  assert_eq!( warnings[ 2 ].reason, "unexpected quote in unquoted field" );
+  assert_eq!( warnings[ 3 ].reason, "empty 'input' field" );
+  assert_eq!( warnings[ 4 ].reason, "unterminated quoted field" );
+}
+
+#[ test ]
+fn csv_requires_columns()
+{
+  let csv = "prompt,completion\nhi,hello\n";
+
+  let error = Dataset::from_csv( csv.as_bytes(), "prompt", "output" ).unwrap_err();
+  assert!( error.to_string().contains( "no 'output' column" ), "{error}" );
+
+  assert!( Dataset::from_csv( "".as_bytes(), "prompt", "completion" ).is_err() );
+
+  let ( dataset, _ ) = Dataset::from_csv( csv.as_bytes(), "prompt", "completion" ).unwrap();
+  assert_eq!( pairs( &dataset ), vec![ ( "hi", "hello" ) ] );
+}
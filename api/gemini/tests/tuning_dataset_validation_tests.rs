@@ -0,0 +1,98 @@
+//! Tuning dataset validation tests
+//!
+//! # Test Coverage
+//!
+//! - A valid dataset returns its statistics
+//! - Duplicate inputs are reported as warnings, not errors
+//! - Empty or missing inputs and outputs are reported per example
+//! - Datasets over the 10000 example cap are rejected
+//! - Missing and empty example lists are rejected
+
+use api_gemini::models::{ Dataset, TuningExample, TuningExamples };
+use api_gemini::validation::{ validate_tuning_dataset, DuplicateInput, ValidationError };
+
+fn example( input : &str, output : &str ) -> TuningExample
+{
+  TuningExample { text_input : Some( input.to_string() ), output : Some( output.to_string() ) }
+}
+
+fn dataset( examples : Vec< TuningExample > ) -> Dataset
+{
+  Dataset { examples : Some( TuningExamples { examples } ) }
+}
+
+#[ test ]
+fn valid_dataset_returns_stats()
+{
+  let stats = validate_tuning_dataset( &dataset( vec![
+    example( "1", "one" ),
+    example( "22", "two" ),
+    example( "333", "three" ),
+  ] ) ).expect( "valid dataset" );
+
+  assert_eq!( stats.example_count, 3 );
+  assert_eq!( stats.total_input_chars, 6 );
+  assert_eq!( stats.total_output_chars, 11 );
+  assert_eq!( stats.max_input_chars, 3 );
+  assert_eq!( stats.max_output_chars, 5 );
+  assert!( ( stats.average_input_chars() - 2.0 ).abs() < f64::EPSILON );
+  assert!( !stats.has_warnings() );
+}
+
+#[ test ]
+fn duplicate_inputs_are_warnings()
+{
+  let stats = validate_tuning_dataset( &dataset( vec![
+    example( "hello", "a" ),
+    example( "bye", "b" ),
+    example( "bye ", "c" ),
+    example( "hello", "d" ),
+    example( "hello", "e" ),
+  ] ) ).expect( "duplicates do not fail validation" );
+
+  assert!( stats.has_warnings() );
+  assert_eq!( stats.duplicate_inputs, vec![
+    DuplicateInput { text_input : "hello".to_string(), indices : vec![ 0, 3, 4 ] },
+    DuplicateInput { text_input : "bye".to_string(), indices : vec![ 1, 2 ] },
+  ] );
+}
+
+#[ test ]
+fn empty_outputs_are_rejected()
+{
+  let errors = validate_tuning_dataset( &dataset( vec![
+    example( "fine", "ok" ),
+    example( "no output", "  " ),
+    TuningExample { text_input : None, output : None },
+  ] ) ).expect_err( "empty fields fail validation" );
+
+  let fields : Vec< String > = errors.iter().map( | error | match error
+  {
+    ValidationError::RequiredFieldMissing { field, .. } => field.clone(),
+    other => panic!( "unexpected error : {other}" ),
+  } ).collect();
+  assert_eq!( fields, vec![ "examples[1].output", "examples[2].text_input", "examples[2].output" ] );
+}
+
+#[ test ]
+fn over_cap_is_rejected()
+{
+  let examples = ( 0..10_001 ).map( | i | example( &format!( "input {i}" ), "output" ) ).collect();
+  let errors = validate_tuning_dataset( &dataset( examples ) ).expect_err( "over the example cap" );
+
+  assert_eq!( errors.len(), 1 );
+  assert!( matches!( errors[ 0 ], ValidationError::CollectionTooLarge { size : 10_001, max : 10_000, .. } ) );
+
+  let at_cap = ( 0..10_000 ).map( | i | example( &format!( "input {i}" ), "output" ) ).collect();
+  assert_eq!( validate_tuning_dataset( &dataset( at_cap ) ).unwrap().example_count, 10_000 );
+}
+
+#[ test ]
+fn empty_dataset_is_rejected()
+{
+  for empty in [ Dataset { examples : None }, dataset( Vec::new() ) ]
+  {
+    let errors = validate_tuning_dataset( &empty ).expect_err( "no examples" );
+    assert!( matches!( errors.as_slice(), [ ValidationError::EmptyCollection { .. } ] ) );
+  }
+}
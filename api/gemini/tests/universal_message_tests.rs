@@ -0,0 +1,64 @@
+//! Universal message conversion tests
+//!
+//! Tests for `UniversalMessage`, the provider-neutral message shared in shape
+//! with the Claude, `OpenAI` and Ollama crates.
+//!
+//! # Test Coverage
+//!
+//! - Two-turn conversation round-trip through `Content`
+//! - `assistant` ↔ `model` role mapping
+//! - Multiple text parts concatenated, non-text parts dropped
+
+use api_gemini::models::{ Blob, Content, Part, UniversalMessage, UniversalRole };
+
+#[ test ]
+fn test_two_turn_round_trip()
+{
+  let conversation = vec!
+  [
+    UniversalMessage::user( "What is the capital of France?" ),
+    UniversalMessage::assistant( "Paris." ),
+  ];
+
+  let contents : Vec< Content > = conversation.iter().cloned().map( Into::into ).collect();
+  assert_eq!( contents[ 0 ].role, "user" );
+  assert_eq!( contents[ 1 ].role, "model" );
+  assert_eq!( contents[ 1 ].parts.len(), 1 );
+  assert_eq!( contents[ 1 ].parts[ 0 ].text.as_deref(), Some( "Paris." ) );
+
+  let back : Vec< UniversalMessage > = contents.into_iter().map( Into::into ).collect();
+  assert_eq!( back, conversation );
+}
+
+#[ test ]
+fn test_role_mapping()
+{
+  assert_eq!( UniversalRole::from_provider_role( "model" ), UniversalRole::Assistant );
+  assert_eq!( UniversalRole::from_provider_role( "assistant" ), UniversalRole::Assistant );
+  assert_eq!( UniversalRole::from_provider_role( "system" ), UniversalRole::System );
+  assert_eq!( UniversalRole::from_provider_role( "user" ), UniversalRole::User );
+  assert_eq!( UniversalRole::Assistant.gemini_role(), "model" );
+  assert_eq!( UniversalRole::Assistant.as_str(), "assistant" );
+}
+
+#[ test ]
+fn test_text_parts_concatenated()
+{
+  let content = Content
+  {
+    role : "model".to_string(),
+    parts : vec!
+    [
+      Part { text : Some( "Hello, ".to_string() ), ..Default::default() },
+      Part
+      {
+        inline_data : Some( Blob { mime_type : "image/png".to_string(), data : "iVBORw0K".to_string() } ),
+        ..Default::default()
+      },
+      Part { text : Some( "world".to_string() ), ..Default::default() },
+    ],
+  };
+
+  let message = UniversalMessage::from( content );
+  assert_eq!( message, UniversalMessage::assistant( "Hello, world" ) );
+}
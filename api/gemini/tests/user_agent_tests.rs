@@ -0,0 +1,86 @@
+//! User-Agent tests
+//!
+//! Tests for `ClientBuilder::with_user_agent` and `ClientBuilder::with_app_identity`,
+//! checking the header an interceptor sees on the outgoing request.
+//!
+//! # Test Coverage
+//!
+//! - The default User-Agent identifies the library
+//! - A custom User-Agent replaces the default
+//! - An app identity is appended to the default or custom User-Agent
+//! - Invalid User-Agent values are rejected when building the client
+
+use api_gemini::{ client::{ Client, ClientBuilder }, error::Error, internal::http::DEFAULT_USER_AGENT, Interceptor, RequestParts, ResponseParts };
+use std::sync::{ Arc, Mutex };
+
+/// Keeps the `User-Agent` of the last request and answers with an empty model list
+#[ derive( Debug, Default, Clone ) ]
+struct UserAgentCapture
+{
+  user_agent : Arc< Mutex< Option< String > > >,
+}
+
+impl Interceptor for UserAgentCapture
+{
+  fn before_request( &self, request : &mut RequestParts )
+  {
+    *self.user_agent.lock().unwrap() = request.header( "user-agent" ).map( str::to_string );
+    request.respond_with( ResponseParts::new( 200, r#"{ "models" : [] }"# ) );
+  }
+
+  fn after_response( &self, _response : &ResponseParts ) {}
+}
+
+/// Send one `models().list()` request and return the `User-Agent` it carried
+async fn received_user_agent( builder : ClientBuilder ) -> String
+{
+  let capture = UserAgentCapture::default();
+  let client = builder
+  .api_key( "test-key".to_string() )
+  .base_url( "http://127.0.0.1:9".to_string() )
+  .with_interceptor( capture.clone() )
+  .build()
+  .expect( "client should build" );
+  client.models().list().await.expect( "fixture should answer" );
+
+  let user_agent = capture.user_agent.lock().unwrap().clone();
+  user_agent.expect( "request should carry a User-Agent" )
+}
+
+#[ tokio::test ]
+async fn test_default_user_agent()
+{
+  assert_eq!( received_user_agent( Client::builder() ).await, DEFAULT_USER_AGENT );
+}
+
+#[ tokio::test ]
+async fn test_custom_user_agent()
+{
+  let builder = Client::builder().with_user_agent( "acme-gateway/3.1".to_string() );
+  assert_eq!( received_user_agent( builder ).await, "acme-gateway/3.1" );
+}
+
+#[ tokio::test ]
+async fn test_app_identity_appended()
+{
+  let builder = Client::builder().with_app_identity( "support-bot", "1.4.2" );
+  assert_eq!( received_user_agent( builder ).await, format!( "{DEFAULT_USER_AGENT} support-bot/1.4.2" ) );
+
+  // The identity is kept regardless of setter order
+  let builder = Client::builder()
+  .with_app_identity( "support-bot", "1.4.2" )
+  .with_user_agent( "acme-gateway/3.1".to_string() );
+  assert_eq!( received_user_agent( builder ).await, "acme-gateway/3.1 support-bot/1.4.2" );
+}
+
+#[ test ]
+fn test_invalid_user_agent_rejected()
+{
+  let error = Client::builder()
+  .api_key( "test-key".to_string() )
+  .with_user_agent( "bad\nagent".to_string() )
+  .build()
+  .unwrap_err();
+
+  assert!( matches!( error, Error::InvalidArgument( .. ) ), "{error:?}" );
+}
@@ -0,0 +1,72 @@
+//! Validation error conversion tests
+//!
+//! # Test Coverage
+//!
+//! - A single `ValidationError` converts to `Error::ValidationError` with its message
+//! - A list of validation errors converts with the messages joined
+//! - `?` propagates validation failures in functions returning `Result< T, Error >`
+
+use api_gemini::error::Error;
+use api_gemini::models::{ Dataset, GenerationConfig, TuningExample, TuningExamples };
+use api_gemini::validation::{ validate_generation_config, validate_tuning_dataset, ValidationError };
+
+fn message( error : Error ) -> String
+{
+  match error
+  {
+    Error::ValidationError { message } => message,
+    other => panic!( "expected Error::ValidationError, got {other:?}" ),
+  }
+}
+
+#[ test ]
+fn single_error_keeps_message()
+{
+  let validation = ValidationError::RequiredFieldMissing
+  {
+    field : "contents".to_string(),
+    context : "GenerateContentRequest".to_string(),
+  };
+  let expected = validation.to_string();
+
+  let error = Error::from( validation );
+  assert_eq!( message( error.clone() ), expected );
+  assert_eq!( error.to_string(), format!( "Validation error : {expected}" ) );
+}
+
+#[ test ]
+fn error_list_joins_messages()
+{
+  let dataset = Dataset
+  {
+    examples : Some( TuningExamples
+    {
+      examples : vec!
+      [
+        TuningExample { text_input : Some( String::new() ), output : Some( "one".to_string() ) },
+        TuningExample { text_input : Some( "2".to_string() ), output : None },
+      ],
+    } ),
+  };
+  let errors = validate_tuning_dataset( &dataset ).unwrap_err();
+  assert_eq!( errors.len(), 2 );
+  let expected = format!( "{}; {}", errors[ 0 ], errors[ 1 ] );
+
+  assert_eq!( message( Error::from( errors ) ), expected );
+}
+
+#[ test ]
+fn question_mark_converts()
+{
+  fn check( config : &GenerationConfig ) -> Result< (), Error >
+  {
+    validate_generation_config( config )?;
+    Ok( () )
+  }
+
+  assert!( check( &GenerationConfig::default() ).is_ok() );
+
+  let config = GenerationConfig::default().with_stop_sequences( vec![ String::new() ] );
+  let message = message( check( &config ).unwrap_err() );
+  assert!( message.contains( "stop_sequences[0]" ), "{message}" );
+}
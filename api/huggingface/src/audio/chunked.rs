@@ -0,0 +1,259 @@
+//! Chunked Automatic Speech Recognition
+//!
+//! Transcribe audio longer than a single request allows by splitting decoded PCM
+//! into overlapping chunks, preferring quiet frames as cut points, and merging
+//! the per-chunk transcripts with overlap deduplication.
+//!
+//! Audio is accepted as already-decoded 16-bit mono PCM so no codec dependency
+//! is required; each chunk is re-wrapped as a WAV payload before upload.
+
+use core::ops::Range;
+use crate::
+{
+  error::{ HuggingFaceError, Result },
+  audio::{ Audio, AudioInput },
+  environment::HuggingFaceEnvironment,
+};
+
+/// Overlap between consecutive chunks, in seconds
+///
+/// Words cut at a chunk boundary appear whole in one of the two chunks and are
+/// removed from the merged transcript by `merge_transcripts`.
+pub const ASR_CHUNK_OVERLAP_SECONDS : u32 = 1;
+
+/// Window before each time boundary searched for a quiet cut point, in seconds
+pub const ASR_SILENCE_SEARCH_SECONDS : u32 = 2;
+
+/// Maximum number of words compared when deduplicating chunk overlaps
+pub const ASR_MAX_OVERLAP_WORDS : usize = 16;
+
+/// Decoded 16-bit mono PCM audio
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct PcmAudio
+{
+  /// Signed 16-bit samples
+  pub samples : Vec< i16 >,
+  /// Samples per second
+  pub sample_rate : u32,
+}
+
+impl PcmAudio
+{
+  /// Create PCM audio from samples
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( samples : Vec< i16 >, sample_rate : u32 ) -> Self
+  {
+  Self { samples, sample_rate }
+  }
+
+  /// Create PCM audio from little-endian 16-bit sample bytes
+  ///
+  /// A trailing odd byte is ignored.
+  #[ inline ]
+  #[ must_use ]
+  pub fn from_le_bytes( bytes : &[ u8 ], sample_rate : u32 ) -> Self
+  {
+  let samples = bytes
+      .chunks_exact( 2 )
+      .map( | pair | i16::from_le_bytes( [ pair[ 0 ], pair[ 1 ] ] ) )
+      .collect();
+  Self { samples, sample_rate }
+  }
+
+  /// Audio duration in seconds
+  #[ inline ]
+  #[ must_use ]
+  pub fn duration_seconds( &self ) -> f64
+  {
+  if self.sample_rate == 0
+  {
+      return 0.0;
+  }
+  #[ allow( clippy::cast_precision_loss ) ]
+  let samples = self.samples.len() as f64;
+  samples / f64::from( self.sample_rate )
+  }
+
+  /// Encode a sample range as a 16-bit mono WAV file
+  #[ inline ]
+  #[ must_use ]
+  pub fn to_wav( &self, range : Range< usize > ) -> Vec< u8 >
+  {
+  let samples = &self.samples[ range ];
+  let data_len = u32::try_from( samples.len() * 2 ).unwrap_or( u32::MAX );
+  let mut wav = Vec::with_capacity( 44 + samples.len() * 2 );
+
+  wav.extend_from_slice( b"RIFF" );
+  wav.extend_from_slice( &data_len.saturating_add( 36 ).to_le_bytes() );
+  wav.extend_from_slice( b"WAVE" );
+  wav.extend_from_slice( b"fmt " );
+  wav.extend_from_slice( &16_u32.to_le_bytes() );
+  wav.extend_from_slice( &1_u16.to_le_bytes() ); // PCM
+  wav.extend_from_slice( &1_u16.to_le_bytes() ); // mono
+  wav.extend_from_slice( &self.sample_rate.to_le_bytes() );
+  wav.extend_from_slice( &self.sample_rate.saturating_mul( 2 ).to_le_bytes() ); // byte rate
+  wav.extend_from_slice( &2_u16.to_le_bytes() ); // block align
+  wav.extend_from_slice( &16_u16.to_le_bytes() ); // bits per sample
+  wav.extend_from_slice( b"data" );
+  wav.extend_from_slice( &data_len.to_le_bytes() );
+  for sample in samples
+  {
+      wav.extend_from_slice( &sample.to_le_bytes() );
+  }
+
+  wav
+  }
+}
+
+/// Split audio into overlapping sample ranges of at most `chunk_seconds`
+///
+/// Each cut is placed at the quietest 20 ms frame within
+/// `ASR_SILENCE_SEARCH_SECONDS` before the time boundary (never earlier than half
+/// a chunk), and the next chunk starts `ASR_CHUNK_OVERLAP_SECONDS` before the cut.
+///
+/// # Errors
+///
+/// Returns `HuggingFaceError::InvalidArgument` if `chunk_seconds` or the sample rate is zero
+#[ inline ]
+pub fn plan_chunks( audio : &PcmAudio, chunk_seconds : u32 ) -> Result< Vec< Range< usize > > >
+{
+  if chunk_seconds == 0
+  {
+  return Err( HuggingFaceError::InvalidArgument( "chunk_seconds must be greater than zero".to_string() ) );
+  }
+  if audio.sample_rate == 0
+  {
+  return Err( HuggingFaceError::InvalidArgument( "sample_rate must be greater than zero".to_string() ) );
+  }
+
+  let rate = audio.sample_rate as usize;
+  let len = audio.samples.len();
+  let chunk = chunk_seconds as usize * rate;
+  let overlap = ( ASR_CHUNK_OVERLAP_SECONDS as usize * rate ).min( chunk / 4 );
+  let search = ASR_SILENCE_SEARCH_SECONDS as usize * rate;
+  let frame = ( rate / 50 ).max( 1 );
+
+  let mut chunks = Vec::new();
+  let mut start = 0;
+  while start < len
+  {
+  let target_end = start + chunk;
+  if target_end >= len
+  {
+      chunks.push( start..len );
+      break;
+  }
+
+  let window_start = ( start + chunk / 2 ).max( target_end.saturating_sub( search ) );
+  let end = quietest_boundary( &audio.samples, window_start, target_end, frame );
+  chunks.push( start..end );
+
+  // Always advance, even when the overlap would reach back past this chunk's start
+  start = end.saturating_sub( overlap ).max( start + 1 );
+  }
+
+  Ok( chunks )
+}
+
+/// Sample index at the centre of the quietest frame in `window_start..window_end`
+///
+/// Ties resolve to the latest frame so cuts stay close to the time boundary.
+fn quietest_boundary( samples : &[ i16 ], window_start : usize, window_end : usize, frame : usize ) -> usize
+{
+  let mut best = window_end;
+  let mut best_energy = u64::MAX;
+  let mut frame_start = window_start;
+
+  while frame_start + frame <= window_end
+  {
+  let energy : u64 = samples[ frame_start..frame_start + frame ]
+      .iter()
+      .map( | s | u64::from( s.unsigned_abs() ) )
+      .sum();
+  if energy <= best_energy
+  {
+      best_energy = energy;
+      best = frame_start + frame / 2;
+  }
+  frame_start += frame;
+  }
+
+  best
+}
+
+/// Concatenate chunk transcripts, dropping words repeated across chunk overlaps
+///
+/// For each transcript, the longest run (up to `ASR_MAX_OVERLAP_WORDS`) of its
+/// leading words that matches the trailing words of the text so far is removed.
+/// Words are compared case-insensitively, ignoring surrounding punctuation.
+#[ inline ]
+#[ must_use ]
+pub fn merge_transcripts( parts : &[ String ] ) -> String
+{
+  let mut merged : Vec< &str > = Vec::new();
+
+  for part in parts
+  {
+  let words : Vec< &str > = part.split_whitespace().collect();
+  let max_overlap = ASR_MAX_OVERLAP_WORDS.min( merged.len() ).min( words.len() );
+
+  let overlap = ( 1..=max_overlap )
+      .rev()
+      .find( | &n |
+      {
+  merged[ merged.len() - n.. ]
+          .iter()
+          .zip( &words[ ..n ] )
+          .all( | ( a, b ) | normalize_word( a ) == normalize_word( b ) )
+      } )
+      .unwrap_or( 0 );
+
+  merged.extend_from_slice( &words[ overlap.. ] );
+  }
+
+  merged.join( " " )
+}
+
+/// Lowercase a word and strip surrounding punctuation for overlap comparison
+fn normalize_word( word : &str ) -> String
+{
+  word.trim_matches( | c : char | !c.is_alphanumeric() ).to_lowercase()
+}
+
+impl< E > Audio< E >
+where
+  E : HuggingFaceEnvironment + crate::environment::EnvironmentInterface + Send + Sync + 'static + Clone,
+{
+  /// Transcribe long audio by splitting it into overlapping chunks
+  ///
+  /// Chunks are planned by `plan_chunks`, transcribed sequentially with
+  /// `transcribe`, and joined with `merge_transcripts`.
+  ///
+  /// # Arguments
+  ///
+  /// * `model_id` - Model identifier ( e.g., "openai/whisper-base" )
+  /// * `audio` - Decoded 16-bit mono PCM audio
+  /// * `chunk_seconds` - Maximum chunk length in seconds
+  ///
+  /// # Errors
+  ///
+  /// Returns error if `chunk_seconds` or the sample rate is zero, or if any chunk fails to transcribe
+  #[ inline ]
+  pub async fn transcribe_chunked(
+  &self,
+  model_id : &str,
+  audio : &PcmAudio,
+  chunk_seconds : u32,
+  ) -> Result< String >
+  {
+  let mut transcripts = Vec::new();
+  for range in plan_chunks( audio, chunk_seconds )?
+  {
+      let input = AudioInput::from_bytes( audio.to_wav( range ) );
+      transcripts.push( self.transcribe( input, model_id ).await? );
+  }
+
+  Ok( merge_transcripts( &transcripts ) )
+  }
+}
@@ -5,6 +5,7 @@
 //! ## Features
 //!
 //! - **Automatic Speech Recognition (ASR)**: Convert speech to text
+//! - **Chunked ASR**: Transcribe long PCM audio in overlapping chunks
 //! - **Text-to-Speech (TTS)**: Generate speech from text
 //! - **Audio Classification**: Classify audio into categories
 //! - **Audio-to-Audio**: Transform audio (noise reduction, enhancement, etc.)
@@ -36,8 +37,10 @@ pub mod asr;
 pub mod tts;
 pub mod classification;
 pub mod audio_to_audio;
+pub mod chunked;
 
 pub use types::*;
+pub use chunked::{ PcmAudio, plan_chunks, merge_transcripts };
 
 use crate::Client;
 
@@ -13,6 +13,7 @@
 //! 1. **Explicit Retry**: Configurable exponential backoff (developer calls `Client::with_retry()`)
 //!    - NO automatic retries - developer must explicitly configure retry behavior
 //!    - Configurable via `ExplicitRetryConfig` - developer controls all retry parameters
+//!    - Total retry time bounded by `max_elapsed_time`; retried API statuses chosen via `retryable_status_codes`
 //!
 //! 2. **Circuit Breakers**: Opt-in failure detection (developer enables and configures)
 //!    - NO automatic circuit breaking - developer must explicitly enable
@@ -63,6 +64,8 @@ mod private
   {
   error::{ ApiErrorWrap, HuggingFaceError, Result, map_deserialization_error },
   };
+  use core::time::Duration;
+  use std::collections::HashSet;
   
   #[ cfg( feature = "env-config" ) ]
   use crate::environment::{ HuggingFaceEnvironment, EnvironmentInterface };
@@ -148,6 +151,10 @@ mod private
   pub max_delay_ms : u64,
   /// Random jitter to add/subtract from delay in milliseconds
   pub jitter_ms : u64,
+  /// Total time budget for the operation including all retries ( `None` = bounded by `max_retries` only )
+  pub max_elapsed_time : Option< Duration >,
+  /// HTTP status codes of API errors that are retried
+  pub retryable_status_codes : HashSet< u16 >,
   }
 
   impl ExplicitRetryConfig
@@ -164,6 +171,8 @@ mod private
   multiplier : 2.0,
   max_delay_ms : 30_000,
   jitter_ms : 100,
+  max_elapsed_time : None,
+  retryable_status_codes : Self::server_error_status_codes(),
       }
   }
 
@@ -179,6 +188,150 @@ mod private
   multiplier : 1.5,
   max_delay_ms : 10_000,
   jitter_ms : 50,
+  max_elapsed_time : None,
+  retryable_status_codes : Self::server_error_status_codes(),
+      }
+  }
+
+  /// All 5xx status codes, the default retryable set
+  #[ inline ]
+  #[ must_use ]
+  pub fn server_error_status_codes() -> HashSet< u16 >
+  {
+      ( 500..600 ).collect()
+  }
+
+  /// Set the total time budget for the operation including all retries
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_max_elapsed_time( mut self, max_elapsed_time : Duration ) -> Self
+  {
+      self.max_elapsed_time = Some( max_elapsed_time );
+      self
+  }
+
+  /// Replace the set of retryable HTTP status codes
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_retryable_status_codes< I >( mut self, status_codes : I ) -> Self
+  where
+      I : IntoIterator< Item = u16 >,
+  {
+      self.retryable_status_codes = status_codes.into_iter().collect();
+      self
+  }
+
+  /// Determines if an error is suitable for retry attempts under this configuration
+  ///
+  /// Non-success responses surface as API errors with their status code and
+  /// are retried only if the code is in `retryable_status_codes`.
+  #[ inline ]
+  #[ must_use ]
+  pub fn is_retryable( &self, error : &HuggingFaceError ) -> bool
+  {
+      match error
+      {
+  // Transport errors (no response received) are generally retryable
+  // Rate limiting should be retried
+  // Model unavailable might be temporary
+  // Stream errors could be network-related
+  HuggingFaceError::Http( _ ) |
+  HuggingFaceError::RateLimit( _ ) |
+  HuggingFaceError::ModelUnavailable( _ ) |
+  HuggingFaceError::Stream( _ ) |
+  HuggingFaceError::Timeout( _ ) => true,
+
+  // API errors need more specific checking
+  HuggingFaceError::Api( api_error ) =>
+  {
+          if let Some( status_code ) = api_error.status_code
+          {
+      self.retryable_status_codes.contains( &status_code )
+          }
+          else
+          {
+      // If no status code, check error message for common retryable patterns
+      let msg = api_error.message.to_lowercase();
+      msg.contains( "timeout" ) ||
+      msg.contains( "unavailable" ) ||
+      msg.contains( "overloaded" ) ||
+      msg.contains( "rate limit" ) ||
+      msg.contains( "service" )
+          }
+  },
+
+  // These errors are generally not retryable
+  HuggingFaceError::Authentication( _ ) |
+  HuggingFaceError::Validation( _ ) |
+  HuggingFaceError::Serialization( _ ) |
+  HuggingFaceError::InvalidArgument( _ ) |
+  HuggingFaceError::Generic( _ ) => false,
+      }
+  }
+
+  /// Run an operation, retrying retryable errors with exponential backoff
+  ///
+  /// Stops at `max_retries` retries, and returns `HuggingFaceError::Timeout`
+  /// instead of sleeping when the next backoff would exceed `max_elapsed_time`.
+  ///
+  /// # Errors
+  /// Returns the last error if it is non-retryable or retries are exhausted,
+  /// or `HuggingFaceError::Timeout` if the elapsed-time budget is exhausted.
+  #[ cfg( feature = "env-config" ) ]
+  #[ inline ]
+  pub async fn execute< R, F, Fut >( &self, mut operation : F ) -> Result< R >
+  where
+      F : FnMut() -> Fut,
+      Fut : core::future::Future< Output = Result< R > >,
+  {
+      let start = std::time::Instant::now();
+      let mut retry_count = 0;
+      let mut delay = self.initial_delay_ms;
+
+      loop
+      {
+  let error = match operation().await
+  {
+          Ok( response ) => return Ok( response ),
+          Err( error ) => error,
+  };
+
+  if retry_count >= self.max_retries || !self.is_retryable( &error )
+  {
+          return Err( error );
+  }
+  retry_count += 1;
+
+  // Add jitter to prevent thundering herd
+  let jitter = if self.jitter_ms == 0
+  {
+          0
+  }
+  else
+  {
+          ( rand::random::< u64 >() % ( self.jitter_ms * 2 ) ).saturating_sub( self.jitter_ms )
+  };
+  let total_delay = Duration::from_millis( delay.saturating_add( jitter ).min( self.max_delay_ms ) );
+
+  if let Some( max_elapsed_time ) = self.max_elapsed_time
+  {
+          let elapsed = start.elapsed();
+          if elapsed + total_delay > max_elapsed_time
+          {
+      return Err( HuggingFaceError::Timeout( format!(
+              "Retry budget of {max_elapsed_time:?} exhausted after {elapsed:?} and {retry_count} attempt(s); last error : {error}"
+      ) ) );
+          }
+  }
+
+  tokio::time::sleep( total_delay ).await;
+
+  // Update delay for next iteration with exponential backoff
+  #[ allow( clippy::cast_possible_truncation, clippy::cast_sign_loss ) ]
+  {
+          delay = ( ( delay as f64 ) * self.multiplier ) as u64;
+  }
+  delay = delay.min( self.max_delay_ms );
       }
   }
   }
@@ -356,7 +509,7 @@ mod private
       } else {
   let error_text = response.text().await
           .unwrap_or_else( | _ | "Failed to read error response".to_string() );
-  Err( HuggingFaceError::Http( format!("HTTP {status} - {error_text}") ) )
+  Err( HuggingFaceError::Api( ApiErrorWrap::new( error_text ).with_status_code( status.as_u16() ) ) )
       }
   }
 
@@ -424,37 +577,7 @@ mod private
       T : Serialize + ?Sized,
       R : DeserializeOwned,
   {
-      let mut retry_count = 0;
-      let mut delay = retry_config.initial_delay_ms;
-
-      loop
-      {
-  let result = self.post_direct( url, payload ).await;
-
-  match result
-  {
-          Ok( response ) => return Ok( response ),
-          Err( error ) if retry_count >= retry_config.max_retries => return Err( error ),
-          Err( error ) if Self::is_error_retryable( &error ) =>
-          {
-      retry_count += 1;
-
-      // Add jitter to prevent thundering herd
-      let jitter = ( rand::random::< u64 >() % ( retry_config.jitter_ms * 2 ) ).saturating_sub( retry_config.jitter_ms );
-      let total_delay = delay.saturating_add( jitter ).min( retry_config.max_delay_ms );
-
-      tokio::time::sleep( tokio::time::Duration::from_millis( total_delay ) ).await;
-
-      // Update delay for next iteration with exponential backoff
-      #[ allow( clippy::cast_possible_truncation, clippy::cast_sign_loss ) ]
-      {
-              delay = ( ( delay as f64 ) * retry_config.multiplier ) as u64;
-      }
-      delay = delay.min( retry_config.max_delay_ms );
-          },
-          Err( error ) => return Err( error ), // Non-retryable error
-  }
-      }
+      retry_config.execute( || self.post_direct( url, payload ) ).await
   }
 
   /// Makes a GET request with explicit retry logic
@@ -473,83 +596,7 @@ mod private
   where
       R : DeserializeOwned,
   {
-      let mut retry_count = 0;
-      let mut delay = retry_config.initial_delay_ms;
-
-      loop
-      {
-  let result = self.get_direct( url ).await;
-
-  match result
-  {
-          Ok( response ) => return Ok( response ),
-          Err( error ) if retry_count >= retry_config.max_retries => return Err( error ),
-          Err( error ) if Self::is_error_retryable( &error ) =>
-          {
-      retry_count += 1;
-
-      // Add jitter to prevent thundering herd
-      let jitter = ( rand::random::< u64 >() % ( retry_config.jitter_ms * 2 ) ).saturating_sub( retry_config.jitter_ms );
-      let total_delay = delay.saturating_add( jitter ).min( retry_config.max_delay_ms );
-
-      tokio::time::sleep( tokio::time::Duration::from_millis( total_delay ) ).await;
-
-      // Update delay for next iteration with exponential backoff
-      #[ allow( clippy::cast_possible_truncation, clippy::cast_sign_loss ) ]
-      {
-              delay = ( ( delay as f64 ) * retry_config.multiplier ) as u64;
-      }
-      delay = delay.min( retry_config.max_delay_ms );
-          },
-          Err( error ) => return Err( error ), // Non-retryable error
-  }
-      }
-  }
-
-  /// Determines if an error is suitable for retry attempts
-  ///
-  /// This is an explicit helper method for use with explicit retry operations.
-  #[ inline ]
-  fn is_error_retryable( error : &HuggingFaceError ) -> bool
-  {
-      match error
-      {
-  // Network and HTTP errors are generally retryable
-  // Rate limiting should be retried
-  // Model unavailable might be temporary
-  // Stream errors could be network-related
-  HuggingFaceError::Http( _ ) |
-  HuggingFaceError::RateLimit( _ ) |
-  HuggingFaceError::ModelUnavailable( _ ) |
-  HuggingFaceError::Stream( _ ) => true,
-
-  // API errors need more specific checking
-  HuggingFaceError::Api( api_error ) =>
-  {
-          // Check if it's a 5xx server error (retryable) vs 4xx client error (not retryable)
-          if let Some( status_code ) = api_error.status_code
-          {
-      ( 500..600 ).contains( &status_code )
-          }
-          else
-          {
-      // If no status code, check error message for common retryable patterns
-      let msg = api_error.message.to_lowercase();
-      msg.contains( "timeout" ) ||
-      msg.contains( "unavailable" ) ||
-      msg.contains( "overloaded" ) ||
-      msg.contains( "rate limit" ) ||
-      msg.contains( "service" )
-          }
-  },
-
-  // These errors are generally not retryable
-  HuggingFaceError::Authentication( _ ) |
-  HuggingFaceError::Validation( _ ) |
-  HuggingFaceError::Serialization( _ ) |
-  HuggingFaceError::InvalidArgument( _ ) |
-  HuggingFaceError::Generic( _ ) => false,
-      }
+      retry_config.execute( || self.get_direct( url ) ).await
   }
 
   /// Makes a streaming POST request to the specified URL.
@@ -39,6 +39,9 @@ pub enum HuggingFaceError
   /// Invalid argument errors
   InvalidArgument( String ),
   
+  /// Operation did not complete within its deadline
+  Timeout( String ),
+  
   /// Generic errors for unexpected cases
   Generic( String ),
 }
@@ -59,6 +62,7 @@ impl fmt::Display for HuggingFaceError
       HuggingFaceError::Stream( msg ) => write!( f, "Stream error : {msg}" ),
       HuggingFaceError::Serialization( msg ) => write!( f, "Serialization error : {msg}" ),
       HuggingFaceError::InvalidArgument( msg ) => write!( f, "Invalid argument : {msg}" ),
+      HuggingFaceError::Timeout( msg ) => write!( f, "Timeout error : {msg}" ),
       HuggingFaceError::Generic( msg ) => write!( f, "Generic error : {msg}" ),
   }
   }
@@ -22,6 +22,8 @@ use crate::
 #[ cfg( feature = "env-config" ) ]
 use crate::environment::{ HuggingFaceEnvironment, EnvironmentInterface };
 
+use core::time::Duration;
+
 /// API group for `HuggingFace` inference operations
 #[ derive( Debug ) ]
 pub struct Inference< E >
@@ -287,6 +289,128 @@ where
   let receiver = self.create_stream( inputs, model, parameters ).await?;
   Ok( crate::streaming_control::wrap_stream( receiver ) )
   }
+
+  /// Wait until a cold-start model is loaded and answering requests
+  ///
+  /// Probes the model with a minimal request; while it answers 503 "model loading",
+  /// sleeps for the returned `estimated_time` and probes again.
+  ///
+  /// # Arguments
+  /// - `model_id`: Model identifier to wait for
+  /// - `max_wait`: Maximum total time to wait
+  ///
+  /// # Errors
+  /// Returns `HuggingFaceError::Timeout` if the model is not ready within `max_wait`,
+  /// or the probe error if the model fails for a reason other than loading
+  #[ inline ]
+  pub async fn wait_until_ready( &self, model_id : &str, max_wait : Duration ) -> Result< () >
+  {
+  validate_model_identifier( model_id )?;
+
+  let url = self.client.environment.endpoint_url( &format!( "/models/{model_id}" ) )?;
+  let probe_request = serde_json::json!
+  ({
+      "inputs": "ping",
+      "options": { "wait_for_model": false }
+  });
+
+  poll_until_ready( model_id, max_wait, ||
+  {
+      let url = url.clone();
+      let probe_request = probe_request.clone();
+      async move
+      {
+  readiness_from_result( self.client.post::< serde_json::Value, serde_json::Value >( url.as_str(), &probe_request ).await )
+      }
+  } ).await
+  }
+}
+
+/// Fallback delay between readiness probes when a 503 carries no `estimated_time`
+pub const READINESS_FALLBACK_POLL_INTERVAL : Duration = Duration::from_secs( 1 );
+
+/// Readiness of a model as observed by a single probe
+#[ derive( Debug, Clone, PartialEq ) ]
+pub enum ModelReadiness
+{
+  /// Model answered normally
+  Ready,
+  /// Model is still loading (HTTP 503)
+  Loading
+  {
+  /// Server-estimated time until the model is loaded
+  estimated_time : Option< Duration >,
+  },
+}
+
+/// Parse `estimated_time` (seconds) from a "model loading" 503 response body
+///
+/// # Arguments
+/// - `body`: Raw response body, e.g. `{"error":"Model is currently loading","estimated_time":20.0}`
+#[ inline ]
+#[ must_use ]
+pub fn parse_estimated_time( body : &str ) -> Option< Duration >
+{
+  serde_json::from_str::< serde_json::Value >( body )
+  .ok()?
+  .get( "estimated_time" )?
+  .as_f64()
+  .filter( | secs | secs.is_finite() && *secs >= 0.0 )
+  .map( Duration::from_secs_f64 )
+}
+
+/// Classify a probe outcome, mapping 503 API errors to `ModelReadiness::Loading`
+///
+/// # Errors
+/// Returns the original error for any failure other than HTTP 503
+#[ inline ]
+pub fn readiness_from_result< T >( result : Result< T > ) -> Result< ModelReadiness >
+{
+  match result
+  {
+  Ok( _ ) => Ok( ModelReadiness::Ready ),
+  Err( HuggingFaceError::Api( api_error ) ) if api_error.status_code == Some( 503 ) =>
+  {
+      Ok( ModelReadiness::Loading { estimated_time : parse_estimated_time( &api_error.message ) } )
+  },
+  Err( e ) => Err( e ),
+  }
+}
+
+/// Poll `probe` until it reports `ModelReadiness::Ready` or `max_wait` elapses
+///
+/// Between probes this sleeps for the server's `estimated_time` (or
+/// `READINESS_FALLBACK_POLL_INTERVAL` when absent), capped at the remaining wait.
+///
+/// # Errors
+/// Returns `HuggingFaceError::Timeout` if the deadline passes first, or any
+/// non-loading error returned by `probe`
+#[ inline ]
+pub async fn poll_until_ready< F, Fut >( model_id : &str, max_wait : Duration, mut probe : F ) -> Result< () >
+where
+  F : FnMut() -> Fut,
+  Fut : core::future::Future< Output = Result< ModelReadiness > >,
+{
+  let deadline = std::time::Instant::now() + max_wait;
+
+  loop
+  {
+  let estimated_time = match probe().await?
+  {
+      ModelReadiness::Ready => return Ok( () ),
+      ModelReadiness::Loading { estimated_time } => estimated_time,
+  };
+
+  let remaining = deadline.saturating_duration_since( std::time::Instant::now() );
+  if remaining.is_zero()
+  {
+      return Err( HuggingFaceError::Timeout(
+  format!( "Model '{model_id}' was not ready within {:.1} seconds", max_wait.as_secs_f64() )
+      ) );
+  }
+
+  tokio::time::sleep( estimated_time.unwrap_or( READINESS_FALLBACK_POLL_INTERVAL ).min( remaining ) ).await;
+  }
 }
 
 // Basic implementation for when env-config is not available
@@ -340,5 +464,10 @@ crate::mod_interface!
   exposed use 
   {
   private::Inference,
+  private::ModelReadiness,
+  private::READINESS_FALLBACK_POLL_INTERVAL,
+  private::parse_estimated_time,
+  private::readiness_from_result,
+  private::poll_until_ready,
   };
 }
\ No newline at end of file
@@ -0,0 +1,337 @@
+//! Chat Template Token Counting
+//!
+//! Counts chat tokens after applying the model's chat template, so counts include
+//! the role headers and turn separators the server adds around each message.
+//!
+//! ## Template Support
+//!
+//! `HuggingFace` chat templates are Jinja programs. Rather than evaluating Jinja,
+//! `ChatTemplate::from_tokenizer_config` recognizes the common template families
+//! ( `ChatML`, Llama 3, Llama 2 / Mistral `[INST]`, Gemma ) by their markers and
+//! falls back to a plain `role: content` layout for anything else.
+//!
+//! ## Usage
+//!
+//! ```no_run
+//! # use api_huggingface::token_counter::{ ChatTokenCounter, HubChatTemplateSource, TokenCounter, CountingStrategy };
+//! # use api_huggingface::cache::CacheConfig;
+//! # use api_huggingface::providers::ChatMessage;
+//! # async fn example( client : api_huggingface::Client< api_huggingface::environment::HuggingFaceEnvironmentImpl > ) -> Result< (), Box< dyn std::error::Error > > {
+//! let counter = ChatTokenCounter::new(
+//!   TokenCounter::new( CountingStrategy::CharacterBased ),
+//!   HubChatTemplateSource::new( client ),
+//!   CacheConfig::default( ),
+//! );
+//!
+//! let messages = vec![ ChatMessage { role : "user".to_string( ), content : "Hello!".to_string( ) } ];
+//! let tokens = counter.count_chat_tokens( "HuggingFaceH4/zephyr-7b-beta", &messages ).await?;
+//! println!( "Tokens : {tokens}" );
+//! # Ok( ( ))
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use crate::cache::{ Cache, CacheConfig };
+use crate::error::{ HuggingFaceError, Result };
+use crate::providers::ChatMessage;
+use super::counter::TokenCounter;
+
+/// Chat template rendered with `{role}` and `{content}` placeholders
+#[ derive( Debug, Clone, PartialEq, Eq ) ]
+pub struct ChatTemplate
+{
+  /// Text emitted once before the first message ( e.g. a BOS token )
+  pub prefix : String,
+  /// Format applied to each message unless its role has an override
+  pub message_format : String,
+  /// Per-role format overrides
+  pub role_formats : HashMap< String, String >,
+  /// Text appended after the last message to prompt the assistant turn
+  pub generation_prompt : String,
+}
+
+impl ChatTemplate
+{
+  /// Create a template with a single message format and no prefix or generation prompt
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( message_format : impl Into< String > ) -> Self
+  {
+  Self {
+      prefix : String::new( ),
+      message_format : message_format.into( ),
+      role_formats : HashMap::new( ),
+      generation_prompt : String::new( ),
+  }
+  }
+
+  /// Set the prefix emitted before the first message
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_prefix( mut self, prefix : impl Into< String > ) -> Self
+  {
+  self.prefix = prefix.into( );
+  self
+  }
+
+  /// Override the message format for a role
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_role_format( mut self, role : impl Into< String >, format : impl Into< String > ) -> Self
+  {
+  self.role_formats.insert( role.into( ), format.into( ));
+  self
+  }
+
+  /// Set the generation prompt appended after the last message
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_generation_prompt( mut self, generation_prompt : impl Into< String > ) -> Self
+  {
+  self.generation_prompt = generation_prompt.into( );
+  self
+  }
+
+  /// `ChatML` layout ( `<|im_start|>role ... <|im_end|>` )
+  #[ inline ]
+  #[ must_use ]
+  pub fn chatml() -> Self
+  {
+  Self::new( "<|im_start|>{role}\n{content}<|im_end|>\n" )
+      .with_generation_prompt( "<|im_start|>assistant\n" )
+  }
+
+  /// Llama 3 layout ( `<|start_header_id|>role<|end_header_id|> ... <|eot_id|>` )
+  #[ inline ]
+  #[ must_use ]
+  pub fn llama3() -> Self
+  {
+  Self::new( "<|start_header_id|>{role}<|end_header_id|>\n\n{content}<|eot_id|>" )
+      .with_prefix( "<|begin_of_text|>" )
+      .with_generation_prompt( "<|start_header_id|>assistant<|end_header_id|>\n\n" )
+  }
+
+  /// Llama 2 / Mistral layout ( `[INST] ... [/INST]` )
+  #[ inline ]
+  #[ must_use ]
+  pub fn inst() -> Self
+  {
+  Self::new( "{content}\n" )
+      .with_prefix( "<s>" )
+      .with_role_format( "system", "<<SYS>>\n{content}\n<</SYS>>\n\n" )
+      .with_role_format( "user", "[INST] {content} [/INST]" )
+      .with_role_format( "assistant", " {content}</s>" )
+  }
+
+  /// Gemma layout ( `<start_of_turn>role ... <end_of_turn>`, assistant role named `model` )
+  #[ inline ]
+  #[ must_use ]
+  pub fn gemma() -> Self
+  {
+  Self::new( "<start_of_turn>{role}\n{content}<end_of_turn>\n" )
+      .with_prefix( "<bos>" )
+      .with_role_format( "assistant", "<start_of_turn>model\n{content}<end_of_turn>\n" )
+      .with_generation_prompt( "<start_of_turn>model\n" )
+  }
+
+  /// Plain `role: content` layout used when the template family is not recognized
+  #[ inline ]
+  #[ must_use ]
+  pub fn plain() -> Self
+  {
+  Self::new( "{role}: {content}\n" )
+  }
+
+  /// Build a template from a model's `tokenizer_config.json`
+  ///
+  /// The Jinja `chat_template` is matched against known families by its markers;
+  /// unrecognized templates fall back to `ChatTemplate::plain`.
+  ///
+  /// # Errors
+  /// Returns `HuggingFaceError::Validation` if the config has no `chat_template`.
+  #[ inline ]
+  pub fn from_tokenizer_config( config : &serde_json::Value ) -> Result< Self >
+  {
+  let source = match config.get( "chat_template" )
+  {
+      Some( serde_json::Value::String( source ) ) => source.as_str( ),
+      // Multi-template configs list named templates; use the default one
+      Some( serde_json::Value::Array( templates ) ) => templates.iter( )
+  .find( |t| t.get( "name" ).and_then( serde_json::Value::as_str ) == Some( "default" ))
+  .or_else( || templates.first( ))
+  .and_then( |t| t.get( "template" ))
+  .and_then( serde_json::Value::as_str )
+  .unwrap_or_default( ),
+      _ => "",
+  };
+
+  if source.is_empty( )
+  {
+      return Err( HuggingFaceError::Validation( "Tokenizer config has no chat_template".to_string( )) );
+  }
+
+  let template = if source.contains( "<|im_start|>" )
+  {
+      Self::chatml( )
+  }
+  else if source.contains( "<|start_header_id|>" )
+  {
+      Self::llama3( )
+  }
+  else if source.contains( "<start_of_turn>" )
+  {
+      Self::gemma( )
+  }
+  else if source.contains( "[INST]" )
+  {
+      Self::inst( )
+  }
+  else
+  {
+      Self::plain( )
+  };
+
+  Ok( template )
+  }
+
+  /// Render messages into the prompt text the server would tokenize
+  #[ inline ]
+  #[ must_use ]
+  pub fn render( &self, messages : &[ ChatMessage ] ) -> String
+  {
+  let mut rendered = self.prefix.clone( );
+  for message in messages
+  {
+      let format = self.role_formats.get( &message.role ).unwrap_or( &self.message_format );
+      rendered.push_str( &format.replace( "{role}", &message.role ).replace( "{content}", &message.content ));
+  }
+  rendered.push_str( &self.generation_prompt );
+  rendered
+  }
+}
+
+/// Source of chat templates for models
+#[ async_trait::async_trait ]
+pub trait ChatTemplateSource : Send + Sync
+{
+  /// Fetch the chat template for a model
+  ///
+  /// # Errors
+  /// Returns an error if the template cannot be fetched or parsed.
+  async fn fetch_chat_template( &self, model_id : &str ) -> Result< ChatTemplate >;
+}
+
+/// Fetches chat templates from the model's `tokenizer_config.json` on the Hub
+#[ cfg( feature = "env-config" ) ]
+#[ derive( Debug, Clone ) ]
+pub struct HubChatTemplateSource< E >
+where
+  E : Clone,
+{
+  client : crate::client::Client< E >,
+}
+
+#[ cfg( feature = "env-config" ) ]
+impl< E > HubChatTemplateSource< E >
+where
+  E : Clone,
+{
+  /// Create a source fetching templates through the given client
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( client : crate::client::Client< E > ) -> Self
+  {
+  Self { client }
+  }
+}
+
+#[ cfg( feature = "env-config" ) ]
+#[ async_trait::async_trait ]
+impl< E > ChatTemplateSource for HubChatTemplateSource< E >
+where
+  E : crate::environment::HuggingFaceEnvironment + crate::environment::EnvironmentInterface + Send + Sync + 'static + Clone,
+{
+  #[ inline ]
+  async fn fetch_chat_template( &self, model_id : &str ) -> Result< ChatTemplate >
+  {
+  crate::validation::validate_model_identifier( model_id )?;
+  let url = format!( "https://huggingface.co/{model_id}/resolve/main/tokenizer_config.json" );
+  let config : serde_json::Value = self.client.get( &url ).await?;
+  ChatTemplate::from_tokenizer_config( &config )
+  }
+}
+
+/// Token counter that applies per-model chat templates before counting
+///
+/// Templates are fetched once per model and kept in a process-local `Cache`.
+pub struct ChatTokenCounter< S >
+{
+  counter : TokenCounter,
+  source : S,
+  templates : Cache< String, ChatTemplate >,
+}
+
+impl< S > core::fmt::Debug for ChatTokenCounter< S >
+{
+  #[ inline ]
+  fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+  {
+  f.debug_struct( "ChatTokenCounter" )
+      .field( "counter", &self.counter )
+      .field( "templates", &self.templates )
+      .finish_non_exhaustive( )
+  }
+}
+
+impl< S > ChatTokenCounter< S >
+where
+  S : ChatTemplateSource,
+{
+  /// Create a chat token counter with explicit template cache configuration
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( counter : TokenCounter, source : S, cache_config : CacheConfig ) -> Self
+  {
+  Self {
+      counter,
+      source,
+      templates : Cache::new( cache_config ),
+  }
+  }
+
+  /// Get the chat template for a model, fetching it on a cache miss
+  ///
+  /// # Errors
+  /// Returns an error if the template is not cached and cannot be fetched.
+  #[ inline ]
+  pub async fn chat_template( &self, model_id : &str ) -> Result< ChatTemplate >
+  {
+  if let Some( template ) = self.templates.get( &model_id.to_string( )).await
+  {
+      return Ok( template );
+  }
+
+  let template = self.source.fetch_chat_template( model_id ).await?;
+  self.templates.insert( model_id.to_string( ), template.clone( ), None ).await;
+  Ok( template )
+  }
+
+  /// Count tokens of `messages` as rendered by the model's chat template
+  ///
+  /// # Errors
+  /// Returns an error if the model's chat template cannot be fetched.
+  #[ inline ]
+  pub async fn count_chat_tokens( &self, model_id : &str, messages : &[ ChatMessage ] ) -> Result< usize >
+  {
+  let template = self.chat_template( model_id ).await?;
+  Ok( self.counter.count_tokens( &template.render( messages )).total )
+  }
+
+  /// Get the underlying text token counter
+  #[ inline ]
+  #[ must_use ]
+  pub fn counter( &self ) -> &TokenCounter
+  {
+  &self.counter
+  }
+}
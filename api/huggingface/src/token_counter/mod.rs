@@ -8,6 +8,7 @@
 //! - **Message Counting**: Count tokens in chat messages
 //! - **Request Estimation**: Estimate costs before making API calls
 //! - **Model-Aware**: Different counting for different models
+//! - **Chat Templates**: Count chat tokens as rendered by the model's chat template
 //!
 //! ## Usage
 //!
@@ -24,6 +25,7 @@
 //! ```
 
 pub mod counter;
+pub mod chat_template;
 
 pub use counter::{
   TokenCounter,
@@ -31,3 +33,12 @@ pub use counter::{
   TokenCount,
   TokenCountError,
 };
+
+pub use chat_template::{
+  ChatTemplate,
+  ChatTemplateSource,
+  ChatTokenCounter,
+};
+
+#[ cfg( feature = "env-config" ) ]
+pub use chat_template::HubChatTemplateSource;
@@ -534,3 +534,93 @@ fn test_audio_classification_multiple_high_scores()
   assert!( results[ 1 ].score > 0.4 );
   assert!( results[ 2 ].score < 0.1 );
 }
+
+mod chunked_asr_tests
+{
+  use api_huggingface::audio::{ PcmAudio, plan_chunks, merge_transcripts };
+
+  /// Test time-boundary chunking of uniformly loud audio
+  #[ test ]
+  fn test_plan_chunks_time_boundaries()
+  {
+  // Setup : 25 seconds at 100 Hz, constant level ( no quiet frames to prefer )
+  let audio = PcmAudio::new( vec![ 1000; 2500 ], 100 );
+
+  // Execution
+  let chunks = plan_chunks( &audio, 10 ).expect( "Chunk planning should succeed" );
+
+  // Verification
+  assert_eq!( chunks.len(), 3 );
+  assert_eq!( chunks[ 0 ].start, 0 );
+  assert_eq!( chunks.last().unwrap().end, 2500 );
+  for chunk in &chunks
+  {
+      assert!( chunk.len() <= 1000, "Chunk {chunk:?} exceeds 10 seconds" );
+  }
+  for pair in chunks.windows( 2 )
+  {
+      assert_eq!( pair[ 0 ].end - pair[ 1 ].start, 100, "Consecutive chunks should overlap by one second" );
+  }
+  }
+
+  /// Test cuts move to a silent gap before the time boundary
+  #[ test ]
+  fn test_plan_chunks_prefers_silence()
+  {
+  // Setup : loud audio with a 0.2 second silence at 8.5 seconds
+  let mut samples = vec![ 1000_i16; 2500 ];
+  samples[ 850..870 ].fill( 0 );
+  let audio = PcmAudio::new( samples, 100 );
+
+  // Execution
+  let chunks = plan_chunks( &audio, 10 ).expect( "Chunk planning should succeed" );
+
+  // Verification
+  assert!( ( 850..870 ).contains( &chunks[ 0 ].end ), "First cut should fall in the silence, got {:?}", chunks[ 0 ] );
+  assert_eq!( chunks.last().unwrap().end, 2500 );
+  }
+
+  /// Test invalid chunking parameters are rejected
+  #[ test ]
+  fn test_plan_chunks_rejects_zero_lengths()
+  {
+  assert!( plan_chunks( &PcmAudio::new( vec![ 0; 10 ], 100 ), 0 ).is_err() );
+  assert!( plan_chunks( &PcmAudio::new( vec![ 0; 10 ], 0 ), 10 ).is_err() );
+  }
+
+  /// Test overlapping words are merged once
+  #[ test ]
+  fn test_merge_transcripts_dedupes_overlap()
+  {
+  let parts = vec![
+      "The quick brown fox jumps".to_string(),
+      "fox jumps over the lazy".to_string(),
+      "Lazy dog.".to_string(),
+  ];
+
+  assert_eq!( merge_transcripts( &parts ), "The quick brown fox jumps over the lazy dog." );
+  }
+
+  /// Test transcripts without overlap are joined with a space
+  #[ test ]
+  fn test_merge_transcripts_without_overlap()
+  {
+  let parts = vec![ "hello there".to_string(), "general kenobi".to_string(), String::new() ];
+
+  assert_eq!( merge_transcripts( &parts ), "hello there general kenobi" );
+  }
+
+  /// Test PCM decoding and WAV encoding of a chunk
+  #[ test ]
+  fn test_pcm_wav_roundtrip_header()
+  {
+  let audio = PcmAudio::from_le_bytes( &[ 0x01, 0x00, 0xFF, 0xFF, 0x7F ], 16_000 );
+  assert_eq!( audio.samples, vec![ 1, -1 ] );
+
+  let wav = audio.to_wav( 0..2 );
+  assert_eq!( wav.len(), 44 + 4 );
+  assert_eq!( &wav[ 0..4 ], b"RIFF" );
+  assert_eq!( &wav[ 8..12 ], b"WAVE" );
+  assert_eq!( &wav[ 44.. ], &[ 0x01, 0x00, 0xFF, 0xFF ] );
+  }
+}
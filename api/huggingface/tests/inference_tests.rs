@@ -371,6 +371,118 @@ fn test_default_parameters_are_valid()
   assert!( default_params.top_p.is_some(), "Default should have top_p" );
 }
 
+mod readiness_tests
+{
+  use super::*;
+  use api_huggingface::{ error::ApiErrorWrap, inference::{ ModelReadiness, parse_estimated_time, poll_until_ready, readiness_from_result } };
+  use core::time::Duration;
+  use std::collections::VecDeque;
+  use std::sync::{ Arc, Mutex };
+
+  fn loading_error( estimated_secs : f64 ) -> HuggingFaceError
+  {
+  HuggingFaceError::Api(
+      ApiErrorWrap::new( format!( r#"{{"error":"Model is currently loading","estimated_time":{estimated_secs}}}"# ) )
+      .with_status_code( 503 )
+  )
+  }
+
+  /// Test `estimated_time` parsing from 503 bodies
+  #[ test ]
+  fn test_parse_estimated_time()
+  {
+  assert_eq!( parse_estimated_time( r#"{"error":"loading","estimated_time":20.5}"# ), Some( Duration::from_millis( 20_500 ) ) );
+  assert_eq!( parse_estimated_time( r#"{"error":"loading"}"# ), None );
+  assert_eq!( parse_estimated_time( "Service Unavailable" ), None );
+  }
+
+  /// Test 503 classification versus other errors
+  #[ test ]
+  fn test_readiness_from_result()
+  {
+  let loading = readiness_from_result::< () >( Err( loading_error( 0.5 ) ) ).unwrap();
+  assert_eq!( loading, ModelReadiness::Loading { estimated_time : Some( Duration::from_millis( 500 ) ) } );
+
+  assert_eq!( readiness_from_result( Ok( () ) ).unwrap(), ModelReadiness::Ready );
+
+  let not_found = HuggingFaceError::Api( ApiErrorWrap::new( "Not Found" ).with_status_code( 404 ) );
+  assert!( readiness_from_result::< () >( Err( not_found ) ).is_err() );
+  }
+
+  /// Test polling through two "model loading" responses before a 200
+  #[ tokio::test ]
+  async fn test_poll_until_ready_after_two_loading_responses()
+  {
+  let responses : Arc< Mutex< VecDeque< Result< () > > > > = Arc::new( Mutex::new( VecDeque::from( vec![
+      Err( loading_error( 0.02 ) ),
+      Err( loading_error( 0.03 ) ),
+      Ok( () ),
+  ] ) ) );
+  let probes = Arc::new( Mutex::new( 0_u32 ) );
+
+  let started = std::time::Instant::now();
+  let result = poll_until_ready( "test/model", Duration::from_secs( 5 ), ||
+  {
+      let responses = responses.clone();
+      let probes = probes.clone();
+      async move
+      {
+  *probes.lock().unwrap() += 1;
+  let next = responses.lock().unwrap().pop_front().expect( "probe called too often" );
+  readiness_from_result( next )
+      }
+  } ).await;
+
+  assert!( result.is_ok(), "Model should become ready : {result:?}" );
+  assert_eq!( *probes.lock().unwrap(), 3 );
+  assert!( started.elapsed() >= Duration::from_millis( 50 ), "Should sleep for each estimated_time" );
+  }
+
+  /// Test the deadline is enforced while the model keeps loading
+  #[ tokio::test ]
+  async fn test_poll_until_ready_times_out()
+  {
+  let result = poll_until_ready( "test/model", Duration::from_millis( 50 ), ||
+  async { readiness_from_result::< () >( Err( loading_error( 0.02 ) ) ) }
+  ).await;
+
+  assert!( matches!( result, Err( HuggingFaceError::Timeout( _ ) ) ), "Expected timeout, got : {result:?}" );
+  }
+
+  /// Test `wait_until_ready` polls a server answering 503 twice, then 200
+  #[ tokio::test ]
+  async fn test_wait_until_ready_polls_server_until_loaded()
+  {
+  use wiremock::{ MockServer, Mock, ResponseTemplate };
+  use wiremock::matchers::{ method, path };
+
+  let mock_server = MockServer::start().await;
+
+  Mock::given( method( "POST" ) )
+  .and( path( "/models/test/model" ) )
+  .respond_with( ResponseTemplate::new( 503 ).set_body_string( r#"{"error":"Model is currently loading","estimated_time":0.02}"# ) )
+  .up_to_n_times( 2 )
+  .mount( &mock_server )
+  .await;
+
+  Mock::given( method( "POST" ) )
+  .and( path( "/models/test/model" ) )
+  .respond_with( ResponseTemplate::new( 200 ).set_body_json( serde_json::json!( [ { "generated_text" : "pong" } ] ) ) )
+  .mount( &mock_server )
+  .await;
+
+  let env = HuggingFaceEnvironmentImpl::build( Secret::new( "test-api-key".to_string() ), Some( mock_server.uri() ) )
+  .expect( "Environment creation should succeed" );
+  let client = Client::build( env ).expect( "Client creation should succeed" );
+
+  let result = client.inference().wait_until_ready( "test/model", Duration::from_secs( 5 ) ).await;
+
+  assert!( result.is_ok(), "Model should become ready : {result:?}" );
+  let requests = mock_server.received_requests().await.expect( "Request recording should be enabled" );
+  assert_eq!( requests.len(), 3, "Should probe through both 503 responses" );
+  }
+}
+
 #[ cfg( feature = "inference-streaming" ) ]
 mod streaming_tests
 {
@@ -105,38 +105,146 @@ mod retry_tests
   assert!( !is_error_retryable_test( &invalid_error ) );
   }
 
-  /// Helper function to access the private `is_retryable_error` function for testing
+  /// Classify an error with the default retryable status set
   fn is_error_retryable_test( error : &HuggingFaceError ) -> bool
   {
-  // This is a simplified version of the logic from the private function
-  match error
+  ExplicitRetryConfig::conservative().is_retryable( error )
+  }
+
+  /// Test that the retryable status set is user-configurable
+  #[ test ]
+  fn test_retryable_status_codes_configurable()
+  {
+  let unavailable = HuggingFaceError::Api( ApiErrorWrap::new( "Service unavailable" ).with_status_code( 503 ) );
+  let internal = HuggingFaceError::Api( ApiErrorWrap::new( "Internal server error" ).with_status_code( 500 ) );
+  let conflict = HuggingFaceError::Api( ApiErrorWrap::new( "Conflict" ).with_status_code( 409 ) );
+
+  let default_config = ExplicitRetryConfig::conservative();
+  assert!( default_config.is_retryable( &unavailable ) );
+  assert!( !default_config.is_retryable( &conflict ) );
+
+  let custom = ExplicitRetryConfig::conservative().with_retryable_status_codes( [ 500, 409 ] );
+  assert!( !custom.is_retryable( &unavailable ), "503 excluded from the custom set" );
+  assert!( custom.is_retryable( &internal ) );
+  assert!( custom.is_retryable( &conflict ) );
+
+  // Non-API errors are unaffected by the status set
+  let none = ExplicitRetryConfig::conservative().with_retryable_status_codes( [] );
+  assert!( !none.is_retryable( &internal ) );
+  assert!( none.is_retryable( &HuggingFaceError::Http( "reset".to_string() ) ) );
+  }
+
+  /// Test that a status outside the retryable set fails without retrying
+  #[ cfg( feature = "env-config" ) ]
+  #[ tokio::test ]
+  async fn test_execute_does_not_retry_excluded_status()
+  {
+  let config = ExplicitRetryConfig::aggressive()
+      .with_retryable_status_codes( [ 500 ] );
+  let mut attempts = 0_u32;
+
+  let result : Result< (), HuggingFaceError > = config.execute( ||
+  {
+      attempts += 1;
+      async { Err( HuggingFaceError::Api( ApiErrorWrap::new( "Loading" ).with_status_code( 503 ) ) ) }
+  } ).await;
+
+  assert!( matches!( result, Err( HuggingFaceError::Api( _ ) ) ) );
+  assert_eq!( attempts, 1 );
+  }
+
+  /// Test that a POST answered with 400 is attempted exactly once
+  #[ cfg( feature = "env-config" ) ]
+  #[ tokio::test ]
+  async fn test_post_with_explicit_retry_does_not_retry_client_error()
+  {
+  use api_huggingface::{ environment::HuggingFaceEnvironmentImpl, secret::Secret };
+  use wiremock::{ MockServer, Mock, ResponseTemplate };
+  use wiremock::matchers::method;
+
+  let mock_server = MockServer::start().await;
+  Mock::given( method( "POST" ) )
+      .respond_with( ResponseTemplate::new( 400 ).set_body_string( r#"{"error":"Bad request"}"# ) )
+      .mount( &mock_server )
+      .await;
+
+  let env = HuggingFaceEnvironmentImpl::build( Secret::new( "test-api-key".to_string() ), Some( mock_server.uri() ) )
+      .expect( "Environment creation should succeed" );
+  let client = Client::build( env ).expect( "Client creation should succeed" );
+  let config = ExplicitRetryConfig { initial_delay_ms : 1, max_delay_ms : 1, jitter_ms : 0, ..ExplicitRetryConfig::aggressive() };
+
+  let url = format!( "{}/models/test/model", mock_server.uri() );
+  let result : Result< serde_json::Value, HuggingFaceError > = client
+      .post_with_explicit_retry( &url, &serde_json::json!( { "inputs" : "hi" } ), &config )
+      .await;
+
+  assert!(
+      matches!( &result, Err( HuggingFaceError::Api( api_error ) ) if api_error.status_code == Some( 400 ) ),
+      "Expected API error with status 400, got {result:?}"
+  );
+  let requests = mock_server.received_requests().await.expect( "Request recording should be enabled" );
+  assert_eq!( requests.len(), 1, "400 must not be retried" );
+  }
+
+  /// Test that `max_elapsed_time` bounds retrying regardless of `max_retries`
+  #[ cfg( feature = "env-config" ) ]
+  #[ tokio::test ]
+  async fn test_execute_respects_max_elapsed_time()
+  {
+  let config = ExplicitRetryConfig
+  {
+      max_retries : 1_000,
+      initial_delay_ms : 40,
+      multiplier : 1.0,
+      max_delay_ms : 40,
+      jitter_ms : 0,
+      max_elapsed_time : None,
+      retryable_status_codes : ExplicitRetryConfig::server_error_status_codes(),
+  }
+  .with_max_elapsed_time( core::time::Duration::from_millis( 150 ) );
+  let mut attempts = 0_u32;
+
+  let start = std::time::Instant::now();
+  let result : Result< (), HuggingFaceError > = config.execute( ||
+  {
+      attempts += 1;
+      async { Err( HuggingFaceError::Http( "Connection reset".to_string() ) ) }
+  } ).await;
+  let elapsed = start.elapsed();
+
+  assert!( matches!( result, Err( HuggingFaceError::Timeout( _ ) ) ), "Expected timeout, got {result:?}" );
+  assert!( elapsed < core::time::Duration::from_millis( 150 ), "Budget exceeded : {elapsed:?}" );
+  assert!( ( 2..=4 ).contains( &attempts ), "Unexpected attempt count : {attempts}" );
+  }
+
+  /// Test that successful retries within the budget return the value
+  #[ cfg( feature = "env-config" ) ]
+  #[ tokio::test ]
+  async fn test_execute_succeeds_within_budget()
   {
-      HuggingFaceError::Http( _ ) | 
-      HuggingFaceError::RateLimit( _ ) | 
-      HuggingFaceError::ModelUnavailable( _ ) | 
-      HuggingFaceError::Stream( _ ) => true,
-      HuggingFaceError::Api( api_error ) =>
+  let config = ExplicitRetryConfig::aggressive()
+      .with_max_elapsed_time( core::time::Duration::from_secs( 5 ) );
+  let config = ExplicitRetryConfig { initial_delay_ms : 1, max_delay_ms : 1, jitter_ms : 0, ..config };
+  let mut attempts = 0_u32;
+
+  let result = config.execute( ||
+  {
+      attempts += 1;
+      let current = attempts;
+      async move
       {
-  if let Some( status_code ) = api_error.status_code
+  if current < 3
   {
-          ( 500..600 ).contains( &status_code )
+          Err( HuggingFaceError::RateLimit( "Slow down".to_string() ) )
   }
   else
   {
-          let msg = api_error.message.to_lowercase();
-          msg.contains( "timeout" ) || 
-          msg.contains( "unavailable" ) ||
-          msg.contains( "overloaded" ) ||
-          msg.contains( "rate limit" ) ||
-          msg.contains( "service" )
-  }
-      },
-      HuggingFaceError::Authentication( _ ) |
-      HuggingFaceError::Validation( _ ) |
-      HuggingFaceError::Serialization( _ ) |
-      HuggingFaceError::InvalidArgument( _ ) |
-      HuggingFaceError::Generic( _ ) => false,
+          Ok( current )
   }
+      }
+  } ).await;
+
+  assert_eq!( result.ok(), Some( 3 ) );
   }
 
   /// Integration test : Client with retry policy
@@ -162,6 +270,8 @@ mod retry_tests
       multiplier : 1.8,
       max_delay_ms : 5000,
       jitter_ms : 25,
+      max_elapsed_time : None,
+      retryable_status_codes : ExplicitRetryConfig::server_error_status_codes(),
   };
 
   // Verify the explicit retry configuration
@@ -204,6 +314,8 @@ mod retry_tests
       multiplier : 3.0,
       max_delay_ms : 10_000,
       jitter_ms : 200,
+      max_elapsed_time : None,
+      retryable_status_codes : ExplicitRetryConfig::server_error_status_codes(),
   };
 
   assert_eq!( config.max_retries, 3 );
@@ -253,6 +365,8 @@ mod retry_tests
   multiplier : 2.0,
   max_delay_ms : 1000,
   jitter_ms : 10,
+  max_elapsed_time : None,
+  retryable_status_codes : ExplicitRetryConfig::server_error_status_codes(),
       };
 
       // Make API call with explicit retry that should fail and trigger retries
@@ -297,6 +411,8 @@ mod retry_tests
   multiplier : 1.5,
   max_delay_ms : 2000,
   jitter_ms : 50,
+  max_elapsed_time : None,
+  retryable_status_codes : ExplicitRetryConfig::server_error_status_codes(),
       };
 
       // Make multiple rapid calls with explicit retry to potentially trigger rate limiting
@@ -451,3 +451,95 @@ fn test_count_long_conversation()
   assert!( count.total > 200 );
   assert!( count.characters > 1000 );
 }
+
+// ============================================================================
+// Chat Template Tests
+// ============================================================================
+
+mod chat_template
+{
+  use super::*;
+  use api_huggingface::token_counter::{ ChatTemplate, ChatTemplateSource, ChatTokenCounter };
+  use api_huggingface::cache::CacheConfig;
+  use api_huggingface::error::Result;
+  use std::sync::Arc;
+  use core::sync::atomic::{ AtomicUsize, Ordering };
+
+  /// Stub source returning a fixed `ChatML` template and counting fetches
+  struct StubTemplateSource
+  {
+  fetches : Arc< AtomicUsize >,
+  }
+
+  #[ async_trait::async_trait ]
+  impl ChatTemplateSource for StubTemplateSource
+  {
+  async fn fetch_chat_template( &self, _model_id : &str ) -> Result< ChatTemplate >
+  {
+      self.fetches.fetch_add( 1, Ordering::SeqCst );
+      ChatTemplate::from_tokenizer_config( &serde_json::json!( {
+  "chat_template" : "{% for message in messages %}{{'<|im_start|>' + message['role'] + '\n' + message['content'] + '<|im_end|>' + '\n'}}{% endfor %}"
+      } ))
+  }
+  }
+
+  fn messages() -> Vec< ChatMessage >
+  {
+  vec![
+      ChatMessage { role : "system".to_string( ), content : "Be brief.".to_string( ) },
+      ChatMessage { role : "user".to_string( ), content : "Hello".to_string( ) },
+  ]
+  }
+
+  #[ test ]
+  fn test_chatml_template_rendering()
+  {
+  let rendered = ChatTemplate::chatml( ).render( &messages( ));
+
+  assert_eq!(
+      rendered,
+      "<|im_start|>system\nBe brief.<|im_end|>\n<|im_start|>user\nHello<|im_end|>\n<|im_start|>assistant\n"
+  );
+  }
+
+  #[ test ]
+  fn test_template_family_detection()
+  {
+  let llama3 = ChatTemplate::from_tokenizer_config( &serde_json::json!( { "chat_template" : "<|start_header_id|>{{ role }}" } )).unwrap( );
+  assert_eq!( llama3, ChatTemplate::llama3( ));
+
+  let inst = ChatTemplate::from_tokenizer_config( &serde_json::json!( { "chat_template" : "{{ '[INST] ' + content + ' [/INST]' }}" } )).unwrap( );
+  assert_eq!( inst, ChatTemplate::inst( ));
+
+  let unknown = ChatTemplate::from_tokenizer_config( &serde_json::json!( { "chat_template" : "{{ content }}" } )).unwrap( );
+  assert_eq!( unknown, ChatTemplate::plain( ));
+
+  assert!( ChatTemplate::from_tokenizer_config( &serde_json::json!( {} )).is_err( ));
+  }
+
+  #[ tokio::test ]
+  async fn test_count_chat_tokens_renders_then_counts()
+  {
+  let fetches = Arc::new( AtomicUsize::new( 0 ));
+  let counter = ChatTokenCounter::new(
+      TokenCounter::new( CountingStrategy::Estimation ),
+      StubTemplateSource { fetches : fetches.clone( ) },
+      CacheConfig::default( ),
+  );
+
+  let rendered = ChatTemplate::chatml( ).render( &messages( ));
+  let expected = TokenCounter::new( CountingStrategy::Estimation ).count_tokens( &rendered ).total;
+  let raw = TokenCounter::new( CountingStrategy::Estimation ).count_messages( &messages( )).total;
+
+  let count = counter.count_chat_tokens( "stub/model", &messages( )).await.unwrap( );
+  assert_eq!( count, expected );
+  assert_ne!( count, raw );
+
+  // Second count for the same model reuses the cached template
+  counter.count_chat_tokens( "stub/model", &messages( )).await.unwrap( );
+  assert_eq!( fetches.load( Ordering::SeqCst ), 1 );
+
+  counter.count_chat_tokens( "stub/other", &messages( )).await.unwrap( );
+  assert_eq!( fetches.load( Ordering::SeqCst ), 2 );
+  }
+}
@@ -24,6 +24,8 @@ pub enum AudioFormat
   Flac,
   /// OGG (Ogg Vorbis) - open-source compressed audio
   Ogg,
+  /// Raw little-endian PCM samples without a container header
+  Pcm,
 }
 
 impl AudioFormat
@@ -82,6 +84,7 @@ impl AudioFormat
       AudioFormat::Mp3 => "audio/mpeg",
       AudioFormat::Flac => "audio/flac",
       AudioFormat::Ogg => "audio/ogg",
+      AudioFormat::Pcm => "audio/pcm",
     }
   }
 
@@ -96,6 +99,7 @@ impl AudioFormat
       AudioFormat::Mp3 => "mp3",
       AudioFormat::Flac => "flac",
       AudioFormat::Ogg => "ogg",
+      AudioFormat::Pcm => "pcm",
     }
   }
 }
@@ -252,6 +256,10 @@ pub struct AudioProcessingConfig
   buffer_size : usize,
   /// Default timeout for audio operations
   timeout : Duration,
+  /// Maximum chunks an audio stream buffers for a lagging consumer
+  max_buffered_chunks : usize,
+  /// Behaviour when the stream buffer is full
+  buffer_policy : AudioBufferPolicy,
 }
 
 impl AudioProcessingConfig
@@ -266,6 +274,8 @@ impl AudioProcessingConfig
       channels : 1,
       buffer_size : 512,
       timeout : Duration::from_secs( 30 ),
+      max_buffered_chunks : 64,
+      buffer_policy : AudioBufferPolicy::Block,
     }
   }
 
@@ -314,6 +324,24 @@ impl AudioProcessingConfig
     self
   }
 
+  /// Set the maximum number of chunks an audio stream buffers
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_max_buffered_chunks( mut self, chunks : usize ) -> Self
+  {
+    self.max_buffered_chunks = chunks;
+    self
+  }
+
+  /// Set the behaviour when the stream buffer is full
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_buffer_policy( mut self, policy : AudioBufferPolicy ) -> Self
+  {
+    self.buffer_policy = policy;
+    self
+  }
+
   /// Get the sample rate
   #[ inline ]
   #[ must_use ]
@@ -338,6 +366,75 @@ impl AudioProcessingConfig
   #[ inline ]
   #[ must_use ]
   pub fn timeout( &self ) -> Duration { self.timeout }
+
+  /// Convert audio between container formats
+  ///
+  /// Supports wrapping raw PCM into WAV, using this configuration's sample
+  /// rate, bit depth and channel count for the header, and unwrapping WAV
+  /// back into raw PCM. Converting a format to itself returns the input
+  /// unchanged. No codecs are involved, so compressed formats are rejected.
+  ///
+  /// # Errors
+  /// Returns an error for unsupported conversions, a malformed WAV input, or
+  /// PCM input that is not a whole number of sample frames.
+  #[ inline ]
+  pub fn convert( &self, input : &[ u8 ], from : AudioFormat, to : AudioFormat ) -> error_tools::untyped::Result< Vec< u8 > >
+  {
+    use error_tools::untyped::format_err;
+
+    match ( &from, &to )
+    {
+      _ if from == to => Ok( input.to_vec() ),
+      ( AudioFormat::Pcm, AudioFormat::Wav ) =>
+      {
+        let block_align = u32::from( self.channels ) * u32::from( self.bit_depth ) / 8;
+        if block_align == 0 || self.bit_depth % 8 != 0
+        {
+          return Err( format_err!( "Cannot describe PCM with {} channel(s) at {} bits as WAV", self.channels, self.bit_depth ) );
+        }
+        if input.len() % block_align as usize != 0
+        {
+          return Err( format_err!( "PCM data of {} bytes is not a whole number of {}-byte frames", input.len(), block_align ) );
+        }
+        let data_len = u32::try_from( input.len() )
+          .ok()
+          .filter( | len | len.checked_add( 36 ).is_some() )
+          .ok_or_else( || format_err!( "PCM data of {} bytes is too large for WAV", input.len() ) )?;
+
+        let mut output = Vec::with_capacity( 44 + input.len() );
+        output.extend_from_slice( b"RIFF" );
+        output.extend_from_slice( &( data_len + 36 ).to_le_bytes() );
+        output.extend_from_slice( b"WAVEfmt " );
+        output.extend_from_slice( &16_u32.to_le_bytes() );
+        output.extend_from_slice( &1_u16.to_le_bytes() );
+        output.extend_from_slice( &u16::from( self.channels ).to_le_bytes() );
+        output.extend_from_slice( &self.sample_rate.to_le_bytes() );
+        output.extend_from_slice( &( self.sample_rate * block_align ).to_le_bytes() );
+        output.extend_from_slice( &( block_align as u16 ).to_le_bytes() );
+        output.extend_from_slice( &self.bit_depth.to_le_bytes() );
+        output.extend_from_slice( b"data" );
+        output.extend_from_slice( &data_len.to_le_bytes() );
+        output.extend_from_slice( input );
+        Ok( output )
+      },
+      ( AudioFormat::Wav, AudioFormat::Pcm ) =>
+      {
+        let info = WavInfo::parse( input ).ok_or_else( || format_err!( "Input is not a valid WAV file" ) )?;
+        Ok( input[ info.data_offset..info.data_offset + info.data_len ].to_vec() )
+      },
+      _ => Err( format_err!( "Conversion from {:?} to {:?} is not supported; only PCM and WAV can be converted", from, to ) ),
+    }
+  }
+
+  /// Get the maximum number of buffered stream chunks
+  #[ inline ]
+  #[ must_use ]
+  pub fn max_buffered_chunks( &self ) -> usize { self.max_buffered_chunks }
+
+  /// Get the behaviour when the stream buffer is full
+  #[ inline ]
+  #[ must_use ]
+  pub fn buffer_policy( &self ) -> AudioBufferPolicy { self.buffer_policy }
 }
 
 impl Default for AudioProcessingConfig
@@ -348,24 +445,137 @@ impl Default for AudioProcessingConfig
   }
 }
 
-/// Stream receiver for audio processing operations
+
+/// What an audio stream does when its buffer is full because the consumer lags
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Default ) ]
+pub enum AudioBufferPolicy
+{
+  /// Wait until the consumer frees a slot, so no chunk is lost
+  #[ default ]
+  Block,
+  /// Discard the oldest buffered chunk to make room, counting it as dropped
+  DropOldest,
+}
+
+/// Buffer shared between an `AudioStreamSender` and its `AudioStreamReceiver`
 #[ derive( Debug ) ]
-pub struct AudioStreamReceiver
+struct AudioStreamBuffer
 {
-  // Internal stream handling - implementation depends on the streaming backend
-  // This would typically wrap a tokio::sync::mpsc::Receiver or similar
-  inner : tokio::sync::mpsc::Receiver< Result< AudioStreamChunk, String > >,
+  queue : std::sync::Mutex< std::collections::VecDeque< Result< AudioStreamChunk, String > > >,
+  capacity : usize,
+  policy : AudioBufferPolicy,
+  dropped : core::sync::atomic::AtomicU64,
+  receiver_closed : core::sync::atomic::AtomicBool,
+  sender_closed : core::sync::atomic::AtomicBool,
+  readable : tokio::sync::Notify,
+  writable : tokio::sync::Notify,
 }
 
-impl AudioStreamReceiver
+impl AudioStreamBuffer
+{
+  fn queue( &self ) -> std::sync::MutexGuard< '_, std::collections::VecDeque< Result< AudioStreamChunk, String > > >
+  {
+    self.queue.lock().unwrap_or_else( std::sync::PoisonError::into_inner )
+  }
+}
+
+/// Create a bounded audio stream channel
+///
+/// At most `config.max_buffered_chunks()` chunks are held for the receiver;
+/// `config.buffer_policy()` decides what the sender does beyond that.
+#[ inline ]
+#[ must_use ]
+pub fn audio_stream_channel( config : &AudioProcessingConfig ) -> ( AudioStreamSender, AudioStreamReceiver )
+{
+  let capacity = config.max_buffered_chunks().max( 1 );
+  let buffer = std::sync::Arc::new( AudioStreamBuffer
+  {
+    queue : std::sync::Mutex::new( std::collections::VecDeque::with_capacity( capacity ) ),
+    capacity,
+    policy : config.buffer_policy(),
+    dropped : core::sync::atomic::AtomicU64::new( 0 ),
+    receiver_closed : core::sync::atomic::AtomicBool::new( false ),
+    sender_closed : core::sync::atomic::AtomicBool::new( false ),
+    readable : tokio::sync::Notify::new(),
+    writable : tokio::sync::Notify::new(),
+  } );
+
+  ( AudioStreamSender { buffer : buffer.clone() }, AudioStreamReceiver { buffer } )
+}
+
+/// Producing half of an audio stream
+///
+/// Dropping the sender ends the stream once buffered chunks are received.
+#[ derive( Debug ) ]
+pub struct AudioStreamSender
+{
+  buffer : std::sync::Arc< AudioStreamBuffer >,
+}
+
+impl AudioStreamSender
+{
+  /// Send a chunk or stream error, applying the buffer policy when full
+  ///
+  /// # Errors
+  /// Returns an error if the receiver has been closed or dropped.
+  #[ inline ]
+  pub async fn send( &self, item : Result< AudioStreamChunk, String > ) -> Result< (), String >
+  {
+    use core::sync::atomic::Ordering;
+
+    loop
+    {
+      {
+        let mut queue = self.buffer.queue();
+        if self.buffer.receiver_closed.load( Ordering::SeqCst )
+        {
+          return Err( "Audio stream receiver is closed".to_string() );
+        }
+        if queue.len() < self.buffer.capacity || self.buffer.policy == AudioBufferPolicy::DropOldest
+        {
+          if queue.len() >= self.buffer.capacity
+          {
+            queue.pop_front();
+            self.buffer.dropped.fetch_add( 1, Ordering::SeqCst );
+          }
+          queue.push_back( item );
+          self.buffer.readable.notify_one();
+          return Ok( () );
+        }
+      }
+      // A slot freed between releasing the lock and waiting leaves a stored permit
+      self.buffer.writable.notified().await;
+    }
+  }
+
+  /// Number of chunks discarded because the buffer was full
+  #[ inline ]
+  #[ must_use ]
+  pub fn dropped_chunks( &self ) -> u64
+  {
+    self.buffer.dropped.load( core::sync::atomic::Ordering::SeqCst )
+  }
+}
+
+impl Drop for AudioStreamSender
 {
-  /// Create a new audio stream receiver
-  #[ allow( dead_code ) ]
-  pub( crate ) fn new( receiver : tokio::sync::mpsc::Receiver< Result< AudioStreamChunk, String > > ) -> Self
+  #[ inline ]
+  fn drop( &mut self )
   {
-    Self { inner : receiver }
+    self.buffer.sender_closed.store( true, core::sync::atomic::Ordering::SeqCst );
+    self.buffer.readable.notify_one();
   }
+}
+
+/// Stream receiver for audio processing operations
+#[ derive( Debug ) ]
+pub struct AudioStreamReceiver
+{
+  buffer : std::sync::Arc< AudioStreamBuffer >,
+}
 
+impl AudioStreamReceiver
+{
   /// Receive the next chunk from the audio stream
   ///
   /// # Returns
@@ -374,12 +584,346 @@ impl AudioStreamReceiver
   /// * `None` - Stream has ended
   pub async fn recv( &mut self ) -> Option< Result< AudioStreamChunk, String > >
   {
-    self.inner.recv().await
+    use core::sync::atomic::Ordering;
+
+    loop
+    {
+      {
+        let mut queue = self.buffer.queue();
+        if let Some( item ) = queue.pop_front()
+        {
+          self.buffer.writable.notify_one();
+          return Some( item );
+        }
+        if self.buffer.sender_closed.load( Ordering::SeqCst ) || self.buffer.receiver_closed.load( Ordering::SeqCst )
+        {
+          return None;
+        }
+      }
+      self.buffer.readable.notified().await;
+    }
+  }
+
+  /// Number of chunks discarded because the consumer lagged
+  #[ inline ]
+  #[ must_use ]
+  pub fn dropped_chunks( &self ) -> u64
+  {
+    self.buffer.dropped.load( core::sync::atomic::Ordering::SeqCst )
+  }
+
+  /// Number of chunks currently waiting to be received
+  #[ inline ]
+  #[ must_use ]
+  pub fn buffered_chunks( &self ) -> usize
+  {
+    self.buffer.queue().len()
   }
 
   /// Close the stream receiver
+  ///
+  /// Further sends fail; chunks already buffered can still be received.
   pub fn close( &mut self )
   {
-    self.inner.close();
+    self.buffer.receiver_closed.store( true, core::sync::atomic::Ordering::SeqCst );
+    self.buffer.writable.notify_one();
+  }
+}
+
+impl Drop for AudioStreamReceiver
+{
+  #[ inline ]
+  fn drop( &mut self )
+  {
+    self.close();
+  }
+}
+
+/// Thresholds for classifying PCM frames as speech or silence
+#[ derive( Debug, Clone, PartialEq ) ]
+pub struct VadConfig
+{
+  /// Minimum RMS energy, as a fraction of full scale, for a frame to count as speech
+  energy_threshold : f64,
+  /// Maximum fraction of adjacent samples changing sign; above it the frame is treated as noise
+  max_zero_crossing_rate : f64,
+}
+
+impl VadConfig
+{
+  /// Create a voice activity detection configuration with defaults
+  #[ inline ]
+  #[ must_use ]
+  pub fn new() -> Self
+  {
+    Self
+    {
+      energy_threshold : 0.02,
+      max_zero_crossing_rate : 0.3,
+    }
   }
-}
\ No newline at end of file
+
+  /// Set the minimum RMS energy (0.0 to 1.0 of full scale)
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_energy_threshold( mut self, threshold : f64 ) -> Self
+  {
+    self.energy_threshold = threshold;
+    self
+  }
+
+  /// Set the maximum zero-crossing rate (0.0 to 1.0)
+  #[ inline ]
+  #[ must_use ]
+  pub fn with_max_zero_crossing_rate( mut self, rate : f64 ) -> Self
+  {
+    self.max_zero_crossing_rate = rate;
+    self
+  }
+
+  /// Get the minimum RMS energy
+  #[ inline ]
+  #[ must_use ]
+  pub fn energy_threshold( &self ) -> f64 { self.energy_threshold }
+
+  /// Get the maximum zero-crossing rate
+  #[ inline ]
+  #[ must_use ]
+  pub fn max_zero_crossing_rate( &self ) -> f64 { self.max_zero_crossing_rate }
+}
+
+impl Default for VadConfig
+{
+  fn default() -> Self
+  {
+    Self::new()
+  }
+}
+
+/// Measurements and verdict for a single PCM frame
+#[ derive( Debug, Clone, Copy, PartialEq ) ]
+pub struct VadDecision
+{
+  /// RMS energy as a fraction of full scale
+  pub energy : f64,
+  /// Fraction of adjacent sample pairs that change sign
+  pub zero_crossing_rate : f64,
+  /// Whether the frame is classified as speech
+  pub is_speech : bool,
+}
+
+/// Voice activity detection gate over 16-bit PCM frames
+///
+/// A frame counts as speech when it is loud enough and its zero-crossing rate
+/// stays below the noise limit. The gate is a helper : callers decide which
+/// segments to skip before building a `VoiceChatRequest`.
+#[ derive( Debug, Clone, Default ) ]
+pub struct VadGate
+{
+  config : VadConfig,
+}
+
+impl VadGate
+{
+  /// Create a gate with the given thresholds
+  #[ inline ]
+  #[ must_use ]
+  pub fn new( config : VadConfig ) -> Self
+  {
+    Self { config }
+  }
+
+  /// Get the gate configuration
+  #[ inline ]
+  #[ must_use ]
+  pub fn config( &self ) -> &VadConfig
+  {
+    &self.config
+  }
+
+  /// Measure a frame of signed 16-bit PCM samples and classify it
+  #[ inline ]
+  #[ must_use ]
+  pub fn analyze( &self, frame : &[ i16 ] ) -> VadDecision
+  {
+    if frame.is_empty()
+    {
+      return VadDecision { energy : 0.0, zero_crossing_rate : 0.0, is_speech : false };
+    }
+
+    let sum_squares : f64 = frame.iter().map( | &sample | f64::from( sample ).powi( 2 ) ).sum();
+    let energy = ( sum_squares / frame.len() as f64 ).sqrt() / f64::from( i16::MAX );
+
+    let crossings = frame.windows( 2 ).filter( | pair | ( pair[ 0 ] < 0 ) != ( pair[ 1 ] < 0 ) ).count();
+    let zero_crossing_rate = if frame.len() > 1 { crossings as f64 / ( frame.len() - 1 ) as f64 } else { 0.0 };
+
+    let is_speech = energy >= self.config.energy_threshold && zero_crossing_rate <= self.config.max_zero_crossing_rate;
+    VadDecision { energy, zero_crossing_rate, is_speech }
+  }
+
+  /// Whether a frame of signed 16-bit PCM samples contains speech
+  #[ inline ]
+  #[ must_use ]
+  pub fn is_speech( &self, frame : &[ i16 ] ) -> bool
+  {
+    self.analyze( frame ).is_speech
+  }
+
+  /// Whether a frame of little-endian 16-bit PCM bytes contains speech
+  ///
+  /// A trailing odd byte is ignored.
+  #[ inline ]
+  #[ must_use ]
+  pub fn is_speech_le_bytes( &self, frame : &[ u8 ] ) -> bool
+  {
+    let samples : Vec< i16 > = frame.chunks_exact( 2 ).map( | pair | i16::from_le_bytes( [ pair[ 0 ], pair[ 1 ] ] ) ).collect();
+    self.is_speech( &samples )
+  }
+}
+
+/// Assemble streamed audio chunks into a single buffer
+///
+/// Chunks are ordered by `sequence` when every chunk carries one, otherwise
+/// kept in arrival order. The assembled buffer must start with a `format`
+/// header when the format is recognisable. For WAV the RIFF and `data` chunk
+/// sizes, which streaming encoders leave as placeholders, are rewritten to
+/// match the assembled length, and the sample rate and duration are read from
+/// the `fmt ` chunk.
+///
+/// # Errors
+/// Returns the first stream error, or an error if no audio arrived or the
+/// header does not match `format`.
+#[ inline ]
+pub async fn collect_audio< S, E >( stream : S, format : AudioFormat ) -> error_tools::untyped::Result< TextToSpeechResponse >
+where
+  S : futures_util::Stream< Item = Result< AudioStreamChunk, E > >,
+  E : core::fmt::Display,
+{
+  use futures_util::StreamExt;
+  use error_tools::untyped::format_err;
+
+  let mut chunks = Vec::new();
+  let mut stream = core::pin::pin!( stream );
+  while let Some( item ) = stream.next().await
+  {
+    chunks.push( item.map_err( | e | format_err!( "Audio stream failed : {}", e ) )? );
+  }
+  if chunks.iter().all( | chunk | chunk.sequence.is_some() )
+  {
+    chunks.sort_by_key( | chunk | chunk.sequence );
+  }
+
+  let mut audio_data : Vec< u8 > = chunks.into_iter().flat_map( | chunk | chunk.data ).collect();
+  if audio_data.is_empty()
+  {
+    return Err( format_err!( "Audio stream produced no data" ) );
+  }
+
+  if let Some( detected ) = AudioFormat::detect_format( &audio_data )
+  {
+    if detected != format
+    {
+      return Err( format_err!( "Audio stream is {:?}, expected {:?}", detected, format ) );
+    }
+  }
+
+  let ( sample_rate, duration ) = if format == AudioFormat::Wav
+  {
+    let header = finalize_wav_header( &mut audio_data )
+      .ok_or_else( || format_err!( "Audio stream is missing a valid WAV header" ) )?;
+    let byte_rate = f64::from( header.sample_rate ) * f64::from( header.channels ) * f64::from( header.bit_depth ) / 8.0;
+    let duration = if byte_rate > 0.0 { Some( header.data_len as f64 / byte_rate ) } else { None };
+    ( Some( header.sample_rate ), duration )
+  }
+  else
+  {
+    ( None, None )
+  };
+
+  Ok( TextToSpeechResponse
+  {
+    audio_data,
+    format,
+    duration,
+    sample_rate,
+    metadata : None,
+  } )
+}
+
+/// PCM layout described by a WAV header
+#[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+pub struct WavInfo
+{
+  /// Samples per second
+  pub sample_rate : u32,
+  /// Number of interleaved channels
+  pub channels : u16,
+  /// Bits per sample
+  pub bit_depth : u16,
+  /// Offset of the first sample byte
+  pub data_offset : usize,
+  /// Number of sample bytes
+  pub data_len : usize,
+}
+
+impl WavInfo
+{
+  /// Parse the header of a WAV buffer
+  ///
+  /// A `data` size larger than the buffer, as left by streaming encoders, is
+  /// clamped to the bytes present. Returns `None` if the buffer is not WAV or
+  /// lacks a `fmt ` or `data` chunk.
+  #[ inline ]
+  #[ must_use ]
+  pub fn parse( data : &[ u8 ] ) -> Option< Self >
+  {
+    if data.len() < 12 || !data.starts_with( b"RIFF" ) || &data[ 8..12 ] != b"WAVE"
+    {
+      return None;
+    }
+
+    let mut format = None;
+    let mut offset = 12;
+    while offset + 8 <= data.len()
+    {
+      let id = &data[ offset..offset + 4 ];
+      let size = read_u32_le( data, offset + 4 )? as usize;
+      let body = offset + 8;
+      if id == b"data"
+      {
+        let ( sample_rate, channels, bit_depth ) = format?;
+        return Some( Self { sample_rate, channels, bit_depth, data_offset : body, data_len : size.min( data.len() - body ) } );
+      }
+      if id == b"fmt "
+      {
+        format = Some( ( read_u32_le( data, body + 4 )?, read_u16_le( data, body + 2 )?, read_u16_le( data, body + 14 )? ) );
+      }
+      // Chunks are padded to an even length
+      offset = body + size + size % 2;
+    }
+
+    None
+  }
+}
+
+fn read_u16_le( data : &[ u8 ], at : usize ) -> Option< u16 >
+{
+  data.get( at..at + 2 ).map( | bytes | u16::from_le_bytes( [ bytes[ 0 ], bytes[ 1 ] ] ) )
+}
+
+fn read_u32_le( data : &[ u8 ], at : usize ) -> Option< u32 >
+{
+  data.get( at..at + 4 ).map( | bytes | u32::from_le_bytes( [ bytes[ 0 ], bytes[ 1 ], bytes[ 2 ], bytes[ 3 ] ] ) )
+}
+
+/// Rewrite the RIFF and `data` sizes of an assembled WAV buffer to its length
+fn finalize_wav_header( data : &mut [ u8 ] ) -> Option< WavInfo >
+{
+  let mut info = WavInfo::parse( data )?;
+  info.data_len = data.len() - info.data_offset;
+
+  let riff_len = u32::try_from( data.len() - 8 ).ok()?;
+  data[ 4..8 ].copy_from_slice( &riff_len.to_le_bytes() );
+  data[ info.data_offset - 4..info.data_offset ].copy_from_slice( &u32::try_from( info.data_len ).ok()?.to_le_bytes() );
+  Some( info )
+}
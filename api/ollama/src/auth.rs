@@ -568,6 +568,186 @@ mod private
     }
   }
 
+  // =====================================
+  // Secret Source Chain
+  // =====================================
+
+  /// A single place a secret can be looked up
+  #[ cfg( feature = "secret_management" ) ]
+  #[ derive( Debug, Clone ) ]
+  pub enum SecretSource
+  {
+    /// Process environment variables, read at lookup time
+    Environment,
+    /// Secrets loaded from a workspace `secret/-secrets.sh` file
+    Workspace
+    {
+      /// Workspace root the secrets were loaded from
+      path : std::path::PathBuf,
+      /// Secrets loaded from the file
+      store : SecretStore,
+    },
+    /// Explicitly provided values
+    Explicit( SecretStore ),
+  }
+
+  #[ cfg( feature = "secret_management" ) ]
+  impl SecretSource
+  {
+    /// Load the secrets of the workspace at `path`
+    ///
+    /// A missing secrets file yields an empty source.
+    #[ inline ]
+    pub fn workspace< P : AsRef< std::path::Path > >( path : P ) -> Result< Self >
+    {
+      Ok( Self::Workspace
+      {
+        path : path.as_ref().to_path_buf(),
+        store : SecretStore::from_path( path.as_ref() )?,
+      } )
+    }
+
+    /// Source serving the values of a secret configuration
+    #[ inline ]
+    #[ must_use ]
+    pub fn explicit( config : SecretConfig ) -> Self
+    {
+      Self::Explicit( SecretStore::from_config( config ) )
+    }
+
+    /// Look up `key` in this source
+    #[ inline ]
+    pub fn get( &self, key : &str ) -> Result< Option< String > >
+    {
+      match self
+      {
+        Self::Environment => Ok( std::env::var( key ).ok() ),
+        Self::Workspace { store, .. } | Self::Explicit( store ) => store.get( key ),
+      }
+    }
+
+    /// Human-readable name of this source, used in diagnostics
+    #[ inline ]
+    #[ must_use ]
+    pub fn describe( &self ) -> String
+    {
+      match self
+      {
+        Self::Environment => "environment".to_string(),
+        Self::Workspace { path, .. } => format!( "workspace {}", path.display() ),
+        Self::Explicit( _ ) => "explicit values".to_string(),
+      }
+    }
+  }
+
+  /// Error returned when no source in a `SecretChain` holds a key
+  #[ cfg( feature = "secret_management" ) ]
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct SecretNotFound
+  {
+    /// Key that was looked up
+    pub key : String,
+    /// Descriptions of the sources consulted, in precedence order
+    pub sources_tried : Vec< String >,
+  }
+
+  #[ cfg( feature = "secret_management" ) ]
+  impl core::fmt::Display for SecretNotFound
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      write!( f, "Secret '{}' not found; sources tried : {}", self.key, self.sources_tried.join( ", " ) )
+    }
+  }
+
+  #[ cfg( feature = "secret_management" ) ]
+  impl std::error::Error for SecretNotFound {}
+
+  /// Ordered list of secret sources, consulted first to last
+  ///
+  /// The first source holding a key wins, so list sources from highest to
+  /// lowest precedence (e.g. environment, then workspace file, then defaults).
+  #[ cfg( feature = "secret_management" ) ]
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct SecretChain
+  {
+    sources : Vec< SecretSource >,
+  }
+
+  #[ cfg( feature = "secret_management" ) ]
+  impl SecretChain
+  {
+    /// Create an empty chain
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Append a source with lower precedence than those already added
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_source( mut self, source : SecretSource ) -> Self
+    {
+      self.sources.push( source );
+      self
+    }
+
+    /// Append the process environment
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_env( self ) -> Self
+    {
+      self.with_source( SecretSource::Environment )
+    }
+
+    /// Append the secrets of the workspace at `path`
+    #[ inline ]
+    pub fn with_workspace< P : AsRef< std::path::Path > >( self, path : P ) -> Result< Self >
+    {
+      Ok( self.with_source( SecretSource::workspace( path )? ) )
+    }
+
+    /// Append explicitly provided values
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_explicit( self, config : SecretConfig ) -> Self
+    {
+      self.with_source( SecretSource::explicit( config ) )
+    }
+
+    /// Sources in precedence order
+    #[ inline ]
+    #[ must_use ]
+    pub fn sources( &self ) -> &[ SecretSource ]
+    {
+      &self.sources
+    }
+
+    /// Get `key` from the first source that holds it
+    ///
+    /// On a miss the error wraps a `SecretNotFound` listing every source tried.
+    #[ inline ]
+    pub fn get( &self, key : &str ) -> Result< String >
+    {
+      for source in &self.sources
+      {
+        if let Some( value ) = source.get( key )?
+        {
+          return Ok( value );
+        }
+      }
+
+      Err( SecretNotFound
+      {
+        key : key.to_string(),
+        sources_tried : self.sources.iter().map( SecretSource::describe ).collect(),
+      }.into() )
+    }
+  }
+
   /// Authentication helper functions
   #[ cfg( feature = "secret_management" ) ]
   #[ derive( Debug ) ]
@@ -616,6 +796,12 @@ crate ::mod_interface!
   exposed use private::SecretConfig;
   #[ cfg( feature = "secret_management" ) ]
   exposed use private::AuthHelper;
+  #[ cfg( feature = "secret_management" ) ]
+  exposed use private::SecretSource;
+  #[ cfg( feature = "secret_management" ) ]
+  exposed use private::SecretNotFound;
+  #[ cfg( feature = "secret_management" ) ]
+  exposed use private::SecretChain;
   #[ cfg( feature = "workspace" ) ]
   exposed use private::WorkspaceConfig;
   #[ cfg( feature = "workspace" ) ]
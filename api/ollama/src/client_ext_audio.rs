@@ -343,6 +343,133 @@ impl OllamaClient
     }
   }
 
+  /// Stream synthesized speech as audio chunks while it is produced
+  ///
+  /// Each network read becomes one `AudioStreamChunk` with an increasing
+  /// `sequence` and a `timestamp` in seconds since the request started; an
+  /// empty chunk with `is_final` set marks the end of the stream. Pass the
+  /// stream to `crate::audio::collect_audio` to assemble a complete buffer.
+  ///
+  /// The client timeout applies to waiting for the response and to each read,
+  /// so a long synthesis is not cut off while audio keeps arriving.
+  ///
+  /// # Errors
+  /// Returns error if the request fails or the API returns an error status
+  #[ inline ]
+  pub async fn text_to_speech_stream( &mut self, request : crate::audio::TextToSpeechRequest )
+    -> OllamaResult< core::pin::Pin< Box< dyn futures_util::Stream< Item = OllamaResult< crate::audio::AudioStreamChunk > > + Send > > >
+  {
+    use futures_util::StreamExt;
+
+    #[ cfg( feature = "circuit_breaker" ) ]
+    {
+      if let Some( ref circuit_breaker ) = &self.circuit_breaker
+      {
+        if !circuit_breaker.can_execute()
+        {
+          return Err( format_err!( "Circuit breaker is open" ) );
+        }
+      }
+    }
+
+    #[ cfg( feature = "rate_limiting" ) ]
+    {
+      if let Some( ref rate_limiter ) = &self.rate_limiter
+      {
+        if !rate_limiter.should_allow_request()
+        {
+          return Err( format_err!( "Rate limit exceeded. Please try again later." ) );
+        }
+      }
+    }
+
+    let start_time = std::time::Instant::now();
+    let url = format!( "{}/api/audio/synthesize", self.base_url );
+    let request_body = serde_json::json!({
+      "model": request.model,
+      "text": request.text,
+      "voice": request.voice,
+      "format": request.format.file_extension(),
+      "stream": true,
+    });
+
+    // The client timeout bounds the wait for the response and for each read,
+    // not the whole download, so long audio is not cut off
+    let timeout = self.timeout;
+    let response = match tokio::time::timeout( timeout, self.client.post( &url ).json( &request_body ).send() ).await
+    {
+      Ok( Ok( response ) ) => response,
+      failure =>
+      {
+        #[ cfg( feature = "circuit_breaker" ) ]
+        if let Some( ref circuit_breaker ) = &self.circuit_breaker
+        {
+          circuit_breaker.record_failure();
+        }
+        return Err( match failure
+        {
+          Ok( Err( e ) ) => format_err!( "Text-to-speech request failed : {}", e ),
+          _ => format_err!( "Text-to-speech request timed out after {:?}", timeout ),
+        } );
+      }
+    };
+
+    if !response.status().is_success()
+    {
+      #[ cfg( feature = "circuit_breaker" ) ]
+      if let Some( ref circuit_breaker ) = &self.circuit_breaker
+      {
+        circuit_breaker.record_failure();
+      }
+      let error_text = response.text().await.unwrap_or_else( | _ | "Unknown error".to_string() );
+      return Err( format_err!( "Text-to-speech failed : {}", error_text ) );
+    }
+
+    #[ cfg( feature = "circuit_breaker" ) ]
+    if let Some( ref circuit_breaker ) = &self.circuit_breaker
+    {
+      circuit_breaker.record_success();
+    }
+
+    let mut sequence = 0_u64;
+    let mut next_chunk = move | data : Vec< u8 >, is_final : bool |
+    {
+      let chunk = crate::audio::AudioStreamChunk
+      {
+        data,
+        timestamp : start_time.elapsed().as_secs_f64(),
+        is_final,
+        sequence : Some( sequence ),
+      };
+      sequence += 1;
+      chunk
+    };
+
+    let reads = futures_util::stream::unfold( Some( response.bytes_stream().boxed() ), move | body | async move
+    {
+      let mut body = body?;
+      match tokio::time::timeout( timeout, body.next() ).await
+      {
+        Ok( Some( Ok( bytes ) ) ) => Some( ( Ok( bytes ), Some( body ) ) ),
+        Ok( Some( Err( e ) ) ) => Some( ( Err( format_err!( "Failed to read audio data : {}", e ) ), None ) ),
+        Ok( None ) => None,
+        Err( _ ) => Some( ( Err( format_err!( "No audio data received for {:?}", timeout ) ), None ) ),
+      }
+    } );
+
+    let chunks = reads
+      .map( Some )
+      .chain( futures_util::stream::once( async { None } ) )
+      .map( move | item | match item
+      {
+        Some( Ok( bytes ) ) => Ok( next_chunk( bytes.to_vec(), false ) ),
+        Some( Err( e ) ) => Err( e ),
+        None => Ok( next_chunk( Vec::new(), true ) ),
+      } );
+
+    Ok( Box::pin( chunks ) )
+  }
+
   /// Check if audio format is supported
   #[ inline ]
   fn is_audio_format_supported( &self, format : &crate::audio::AudioFormat ) -> bool
@@ -6,16 +6,23 @@ mod private
 {
   use crate::client::OllamaClient;
   use crate::{ OllamaResult, EnhancedModelDetails, ModelHealthCheck, ModelDiagnostics, DeleteModelRequest, ModelPerformanceMetrics };
+  #[ cfg( feature = "model_details" ) ]
+  use crate::{ GenerateRequest, StorageReport };
   use error_tools::format_err;
 
   impl OllamaClient
   {
+    #[ cfg( feature = "model_details" ) ]
+    /// Models probed at once by `check_all_models`
+    pub const MODEL_HEALTH_PROBE_CONCURRENCY : usize = 4;
+
     #[ cfg( feature = "model_details" ) ]
     /// Perform a health check on a specific model
     #[ inline ]
     pub async fn perform_model_health_check( &self, _model_name : &str ) -> OllamaResult< ModelHealthCheck >
     {
       Ok( ModelHealthCheck {
+        model_name : _model_name.to_string(),
         is_available : true,
         response_time : core::time::Duration::from_millis( 150 ),
         health_score : 0.95,
@@ -23,6 +30,96 @@ mod private
       } )
     }
 
+    #[ cfg( feature = "model_details" ) ]
+    /// Probe every local model, `Self::MODEL_HEALTH_PROBE_CONCURRENCY` at a time
+    ///
+    /// See `check_all_models_with_concurrency`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local model list cannot be fetched
+    #[ inline ]
+    pub async fn check_all_models( &self ) -> OllamaResult< Vec< ModelHealthCheck > >
+    {
+      self.check_all_models_with_concurrency( Self::MODEL_HEALTH_PROBE_CONCURRENCY ).await
+    }
+
+    #[ cfg( feature = "model_details" ) ]
+    /// Probe every local model with a one-token generation, at most `max_concurrent` at a time
+    ///
+    /// Checks are returned in model list order. A model whose probe fails is
+    /// reported unavailable with the error as its issue rather than failing the
+    /// whole check; summarize the result with `ModelHealthSummary::from_checks`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local model list cannot be fetched
+    #[ inline ]
+    pub async fn check_all_models_with_concurrency( &self, max_concurrent : usize ) -> OllamaResult< Vec< ModelHealthCheck > >
+    {
+      use futures_util::{ stream, StreamExt };
+
+      let models = self.clone().list_models().await?.models;
+      let checks : Vec< ModelHealthCheck > = stream::iter( models.into_iter().map( | model | self.probe_model( model.name ) ) )
+        .buffered( max_concurrent.max( 1 ) )
+        .collect()
+        .await;
+      Ok( checks )
+    }
+
+    #[ cfg( feature = "model_details" ) ]
+    /// Report disk usage across local models, flagging models not modified within `stale_after`
+    ///
+    /// Informational only; nothing is deleted. See `StorageReport` for why
+    /// staleness is judged by modification time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local model list cannot be fetched
+    #[ inline ]
+    pub async fn storage_report( &self, stale_after : core::time::Duration ) -> OllamaResult< StorageReport >
+    {
+      let models = self.clone().list_models().await?.models;
+      Ok( StorageReport::from_models( &models, stale_after, std::time::SystemTime::now() ) )
+    }
+
+    #[ cfg( feature = "model_details" ) ]
+    /// Run a one-token generation against `model_name` and time it
+    async fn probe_model( &self, model_name : String ) -> ModelHealthCheck
+    {
+      let request = GenerateRequest
+      {
+        model : model_name.clone(),
+        prompt : "ping".to_string(),
+        stream : Some( false ),
+        options : Some( serde_json::json!( { "num_predict" : 1 } ) ),
+      };
+
+      let started = std::time::Instant::now();
+      let result = self.clone().generate( request ).await;
+      let response_time = started.elapsed();
+
+      match result
+      {
+        Ok( _ ) => ModelHealthCheck
+        {
+          model_name,
+          is_available : true,
+          response_time,
+          health_score : 1.0,
+          issues : None,
+        },
+        Err( error ) => ModelHealthCheck
+        {
+          model_name,
+          is_available : false,
+          response_time,
+          health_score : 0.0,
+          issues : Some( vec![ error.to_string() ] ),
+        },
+      }
+    }
+
     #[ cfg( feature = "model_details" ) ]
     /// Configure client with model details caching
     #[ inline ]
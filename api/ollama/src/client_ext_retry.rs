@@ -2,14 +2,60 @@
 //!
 //! Explicit retry methods for API operations.
 
-#[ cfg( feature = "retry" ) ]
+#[ cfg( feature = "enabled" ) ]
 mod private
 {
+  use core::future::Future;
+  use core::pin::Pin;
   use crate::client::OllamaClient;
-  use crate::{ OllamaResult, ChatRequest, ChatResponse, GenerateRequest, GenerateResponse, TagsResponse, ModelInfo };
+  use crate::OllamaResult;
+  #[ cfg( feature = "retry" ) ]
+  use crate::{ ChatRequest, ChatResponse, GenerateRequest, GenerateResponse, TagsResponse, ModelInfo, RetryConfig, RetryableHttpClient };
 
   impl OllamaClient
   {
+    #[ cfg( feature = "retry" ) ]
+    /// Configure the retry policy used by the `*_with_retries` methods and `execute_with_optional_retries`
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_retry_config( mut self, config : RetryConfig ) -> Self
+    {
+      self.retry_client = Some( RetryableHttpClient::new( Some( config ) ) );
+      self
+    }
+
+    #[ cfg( feature = "retry" ) ]
+    /// Get the configured retry policy
+    #[ inline ]
+    #[ must_use ]
+    pub fn retry_config( &self ) -> Option< &RetryConfig >
+    {
+      self.retry_client.as_ref().and_then( | retry_client | retry_client.config.as_ref() )
+    }
+
+    /// Run an operation, retrying only when retry support is compiled in and configured
+    ///
+    /// Single call site regardless of enabled features : with the `retry` feature and a
+    /// policy set via `with_retry_config`, retryable failures are retried with backoff;
+    /// otherwise the operation runs exactly once and no retry machinery is involved.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last attempt
+    #[ inline ]
+    pub async fn execute_with_optional_retries< F, T >( &self, operation : F ) -> OllamaResult< T >
+    where
+      F : Fn() -> Pin< Box< dyn Future< Output = OllamaResult< T > > + Send > > + Send + Sync,
+    {
+      #[ cfg( feature = "retry" ) ]
+      if let Some( retry_client ) = &self.retry_client
+      {
+        return retry_client.execute( operation ).await;
+      }
+
+      operation().await
+    }
+
     #[ cfg( feature = "retry" ) ]
     /// Execute chat request with retry logic (explicit retry method)
     pub async fn chat_with_retries( &mut self, request : ChatRequest ) -> OllamaResult< ChatResponse >
@@ -29,6 +29,8 @@ mod private
     Degraded,
     /// Endpoint is unhealthy and should not be used
     Unhealthy,
+    /// Endpoint was unhealthy and its recovery interval elapsed; the next request probes it
+    Probing,
     /// Endpoint status is unknown (not yet checked)
     Unknown,
   }
@@ -119,6 +121,23 @@ mod private
     {
       self.health == EndpointHealth::Healthy
     }
+
+    /// Check if endpoint can be selected, either healthy or due for a recovery probe
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_available( &self ) -> bool
+    {
+      matches!( self.health, EndpointHealth::Healthy | EndpointHealth::Probing )
+    }
+
+    /// Check if an unhealthy endpoint has waited `recovery_after` since its last failure at `now`
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_recovery_due( &self, recovery_after : Duration, now : std::time::Instant ) -> bool
+    {
+      self.health == EndpointHealth::Unhealthy
+        && self.last_failure.map_or( true, |failed_at| now.saturating_duration_since( failed_at ) >= recovery_after )
+    }
   }
 
   #[ cfg( feature = "failover" ) ]
@@ -161,7 +180,9 @@ mod private
   pub struct FailoverManager
   {
     /// List of configured endpoints with health information
-    endpoints : Vec< EndpointInfo >,
+    endpoints : std::sync::Mutex< Vec< EndpointInfo > >,
+    /// Number of configured endpoints
+    endpoint_count : usize,
     /// Current active endpoint index
     current_index : AtomicUsize,
     /// Failover policy
@@ -170,6 +191,8 @@ mod private
     stats : std::sync::Mutex< FailoverStats >,
     /// Request timeout
     timeout : Duration,
+    /// Interval after which an unhealthy endpoint is probed again
+    recovery_after : Option< Duration >,
   }
 
   #[ cfg( feature = "failover" ) ]
@@ -195,33 +218,90 @@ mod private
         .map( EndpointInfo::new )
         .collect();
 
-      let stats = FailoverStats::new( endpoint_infos.len() );
+      let endpoint_count = endpoint_infos.len();
+      let stats = FailoverStats::new( endpoint_count );
 
       Ok( Self
       {
-        endpoints : endpoint_infos,
+        endpoints : std::sync::Mutex::new( endpoint_infos ),
+        endpoint_count,
         current_index : AtomicUsize::new( 0 ),
         policy,
         stats : std::sync::Mutex::new( stats ),
         timeout,
+        recovery_after : None,
       })
     }
 
-    /// Get the currently active endpoint URL
+    /// Probe unhealthy endpoints again once `recovery_after` has elapsed since their last failure
+    ///
+    /// Without a recovery interval an unhealthy endpoint stays excluded until it is
+    /// marked healthy explicitly.
     #[ inline ]
     #[ must_use ]
-    pub fn get_active_endpoint( &self ) -> String
+    pub fn with_recovery_after( mut self, recovery_after : Duration ) -> Self
     {
-      let index = self.current_index.load( std::sync::atomic::Ordering::Acquire );
-      if index < self.endpoints.len()
+      self.recovery_after = Some( recovery_after );
+      self
+    }
+
+    /// Get the configured recovery interval
+    #[ inline ]
+    #[ must_use ]
+    pub fn recovery_after( &self ) -> Option< Duration >
+    {
+      self.recovery_after
+    }
+
+    /// Move unhealthy endpoints whose recovery interval elapsed at `now` into the probing state
+    ///
+    /// A probing endpoint is selectable again: a successful request marks it
+    /// healthy, a failed one marks it unhealthy and restarts its timer. Under the
+    /// priority policy the active endpoint is recalculated so a recovered primary
+    /// is probed first. Returns the number of endpoints moved to probing.
+    #[ inline ]
+    pub fn recover_endpoints_at( &self, now : std::time::Instant ) -> usize
+    {
+      let Some( recovery_after ) = self.recovery_after else { return 0 };
+
+      let mut recovered = 0;
+      for endpoint in self.lock_endpoints().iter_mut()
       {
-        self.endpoints[ index ].url.clone()
+        if endpoint.is_recovery_due( recovery_after, now )
+        {
+          endpoint.health = EndpointHealth::Probing;
+          recovered += 1;
+        }
       }
-      else
+
+      if recovered > 0 && self.policy == FailoverPolicy::Priority
       {
-        // Fallback to first endpoint if index is out of bounds
-        self.endpoints[ 0 ].url.clone()
+        self.select_next_healthy();
       }
+      recovered
+    }
+
+    /// Lock the endpoint list, recovering it if a previous holder panicked
+    fn lock_endpoints( &self ) -> std::sync::MutexGuard< '_, Vec< EndpointInfo > >
+    {
+      self.endpoints.lock().unwrap_or_else( std::sync::PoisonError::into_inner )
+    }
+
+    /// Get the currently active endpoint URL
+    #[ inline ]
+    #[ must_use ]
+    pub fn get_active_endpoint( &self ) -> String
+    {
+      self.active_endpoint_info().url
+    }
+
+    /// Snapshot of the currently active endpoint
+    fn active_endpoint_info( &self ) -> EndpointInfo
+    {
+      let index = self.current_index.load( std::sync::atomic::Ordering::Acquire );
+      let endpoints = self.lock_endpoints();
+      // Fallback to first endpoint if index is out of bounds
+      endpoints.get( index ).unwrap_or( &endpoints[ 0 ] ).clone()
     }
 
     /// Get the number of configured endpoints
@@ -229,7 +309,7 @@ mod private
     #[ must_use ]
     pub fn get_endpoint_count( &self ) -> usize
     {
-      self.endpoints.len()
+      self.endpoint_count
     }
 
     /// Check if a specific endpoint is healthy
@@ -237,16 +317,16 @@ mod private
     #[ must_use ]
     pub fn is_endpoint_healthy( &self, url : &str ) -> bool
     {
-      self.endpoints.iter()
+      self.lock_endpoints().iter()
         .find( |endpoint| endpoint.url == url )
-        .map_or( false, |endpoint| endpoint.is_healthy() )
+        .map_or( false, EndpointInfo::is_healthy )
     }
 
     /// Mark an endpoint as healthy (requires mutable access)
     #[ inline ]
     pub fn mark_endpoint_healthy( &mut self, url : &str )
     {
-      if let Some( endpoint ) = self.endpoints.iter_mut().find( |e| e.url == url )
+      if let Some( endpoint ) = self.lock_endpoints().iter_mut().find( |e| e.url == url )
       {
         endpoint.mark_healthy();
       }
@@ -261,7 +341,7 @@ mod private
     #[ inline ]
     pub fn mark_endpoint_unhealthy( &mut self, url : &str )
     {
-      if let Some( endpoint ) = self.endpoints.iter_mut().find( |e| e.url == url )
+      if let Some( endpoint ) = self.lock_endpoints().iter_mut().find( |e| e.url == url )
       {
         endpoint.mark_unhealthy();
       }
@@ -273,41 +353,46 @@ mod private
     pub fn rotate_endpoint( &mut self )
     {
       let current = self.current_index.load( std::sync::atomic::Ordering::Acquire );
-      let next = ( current + 1 ) % self.endpoints.len();
+      let next = ( current + 1 ) % self.endpoint_count;
       self.current_index.store( next, std::sync::atomic::Ordering::Release );
     }
 
     /// Select the next healthy endpoint based on the failover policy
+    #[ inline ]
     pub fn select_next_healthy_endpoint( &mut self )
     {
-      match self.policy
-      {
-        FailoverPolicy::RoundRobin =>
+      self.select_next_healthy();
+    }
+
+    /// Select the next healthy endpoint through shared access
+    ///
+    /// Endpoints in the probing state are selectable. Returns `false` when no
+    /// endpoint is selectable; the active endpoint is then left unchanged.
+    fn select_next_healthy( &self ) -> bool
+    {
+      let selected = {
+        let endpoints = self.lock_endpoints();
+        match self.policy
         {
-          // Find next healthy endpoint in round-robin fashion
-          let current = self.current_index.load( std::sync::atomic::Ordering::Acquire );
-          for i in 1..=self.endpoints.len()
+          FailoverPolicy::RoundRobin =>
           {
-            let index = ( current + i ) % self.endpoints.len();
-            if self.endpoints[ index ].is_healthy()
-            {
-              self.current_index.store( index, std::sync::atomic::Ordering::Release );
-              break;
-            }
+            // Find next healthy endpoint in round-robin fashion
+            let current = self.current_index.load( std::sync::atomic::Ordering::Acquire );
+            ( 1..=endpoints.len() )
+              .map( |i| ( current + i ) % endpoints.len() )
+              .find( |&index| endpoints[ index ].is_available() )
           }
-        }
-        FailoverPolicy::Priority =>
-        {
-          // Find first healthy endpoint (highest priority)
-          for ( index, endpoint ) in self.endpoints.iter().enumerate()
+          FailoverPolicy::Priority =>
           {
-            if endpoint.is_healthy()
-            {
-              self.current_index.store( index, std::sync::atomic::Ordering::Release );
-              break;
-            }
+            // Find first healthy endpoint (highest priority)
+            endpoints.iter().position( EndpointInfo::is_available )
           }
         }
+      };
+
+      if let Some( index ) = selected
+      {
+        self.current_index.store( index, std::sync::atomic::Ordering::Release );
       }
 
       // Update failover stats
@@ -316,6 +401,70 @@ mod private
         stats.total_failovers += 1;
         stats.active_endpoint_index = self.current_index.load( std::sync::atomic::Ordering::Acquire );
       }
+
+      selected.is_some()
+    }
+
+    /// Run an operation against the active endpoint, failing over on error
+    ///
+    /// `op` is called with the currently selected endpoint. When it fails the
+    /// endpoint is marked unhealthy and `op` is retried against the next healthy
+    /// endpoint chosen by the failover policy. Each endpoint is tried at most
+    /// once per call. Returns the last error when every attempt fails.
+    ///
+    /// With a recovery interval configured, unhealthy endpoints that are due are
+    /// moved to probing before the first attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last attempted endpoint if no endpoint succeeds.
+    #[ inline ]
+    pub async fn execute< F, Fut, T >( &self, op : F ) -> OllamaResult< T >
+    where
+      F : Fn( &EndpointInfo ) -> Fut,
+      Fut : core::future::Future< Output = OllamaResult< T > >,
+    {
+      let mut last_error = None;
+      self.recover_endpoints_at( std::time::Instant::now() );
+
+      for _ in 0..self.endpoint_count
+      {
+        let endpoint = self.active_endpoint_info();
+        if let Ok( mut stats ) = self.stats.lock()
+        {
+          stats.total_requests += 1;
+        }
+
+        let started = std::time::Instant::now();
+        let result = op( &endpoint ).await;
+        let elapsed = started.elapsed();
+
+        if let Some( info ) = self.lock_endpoints().iter_mut().find( |e| e.url == endpoint.url )
+        {
+          info.update_request_stats( elapsed );
+          if result.is_ok()
+          {
+            info.mark_healthy();
+          }
+          else
+          {
+            info.mark_unhealthy();
+          }
+        }
+
+        match result
+        {
+          Ok( value ) => return Ok( value ),
+          Err( error ) => last_error = Some( error ),
+        }
+
+        if !self.select_next_healthy()
+        {
+          break;
+        }
+      }
+
+      Err( last_error.unwrap_or_else( || format_err!( "No failover endpoint was attempted" ) ) )
     }
 
     /// Get failover statistics
@@ -325,7 +474,7 @@ mod private
     {
       self.stats.lock().map( |stats| stats.clone() ).unwrap_or_else( |_|
       {
-        FailoverStats::new( self.endpoints.len() )
+        FailoverStats::new( self.endpoint_count )
       })
     }
   }
@@ -183,7 +183,7 @@ mod client_ext_tuning;
 mod client_ext_workspace;
 #[ cfg( feature = "safety_settings" ) ]
 mod client_ext_safety;
-#[ cfg( feature = "retry" ) ]
+#[ cfg( feature = "enabled" ) ]
 mod client_ext_retry;
 #[ cfg( feature = "secret_management" ) ]
 mod client_ext_auth;
@@ -270,6 +270,8 @@ crate ::mod_interface!
     messages ::Message,
     messages ::MessageRole,
     messages ::ChatMessage,
+    messages ::UniversalRole,
+    messages ::UniversalMessage,
     chat ::ChatRequest,
     chat ::ChatResponse,
     generate ::GenerateRequest,
@@ -304,11 +306,15 @@ crate ::mod_interface!
   {
     auth ::SecretStore,
     auth ::SecretConfig,
+    auth ::SecretSource,
+    auth ::SecretNotFound,
+    auth ::SecretChain,
   };
   #[ cfg( all( feature = "workspace", feature = "secret_management" ) ) ]
   exposed use
   {
     workspace ::WorkspaceSecretStore,
+    workspace ::VersionedSecret,
   };
   #[ cfg( feature = "embeddings" ) ]
   exposed use
@@ -355,7 +361,15 @@ crate ::mod_interface!
     VoiceChatRequest,
     VoiceChatResponse,
     AudioProcessingConfig,
+    AudioBufferPolicy,
+    AudioStreamSender,
     AudioStreamReceiver,
+    audio_stream_channel,
+    collect_audio,
+    WavInfo,
+    VadConfig,
+    VadDecision,
+    VadGate,
   };
 
   // MessageRole and ChatMessage are now exported in the base module
@@ -431,6 +445,8 @@ crate ::mod_interface!
     models_enhanced ::ModelLifecycle,
     models_enhanced ::ModelOperation,
     models_enhanced ::ModelPerformanceMetrics,
+    models_enhanced ::ModelCapabilityFilter,
+    models_enhanced ::filter_models,
     models_operations ::ShowModelRequest,
     models_operations ::PullModelRequest,
     models_operations ::PushModelRequest,
@@ -439,10 +455,17 @@ crate ::mod_interface!
     models_operations ::ModelProgressStream,
     models_additional ::ComprehensiveModelInfo,
     models_additional ::ModelRecommendation,
+    models_additional ::ModelSizePreference,
+    models_additional ::TaskRequirements,
+    models_additional ::recommend_model,
     models_additional ::ModelLifecycleStatus,
     models_additional ::ModelOperationHistoryEntry,
     models_additional ::ModelHealthCheck,
+    models_additional ::ModelHealthSummary,
     models_additional ::LocalModelStorageInfo,
+    models_additional ::StoredModel,
+    models_additional ::StaleModel,
+    models_additional ::StorageReport,
     models_additional ::ModelDiagnostics,
   };
 
@@ -118,6 +118,176 @@ mod private
     /// ID linking this response to the tool call
     pub tool_call_id : String,
   }
+
+  /// Provider-neutral message role
+  ///
+  /// The same type, with the same role mapping, exists in the Claude, Gemini and
+  /// `OpenAI` crates so a conversation can be moved between providers.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+  pub enum UniversalRole
+  {
+    /// System instructions
+    System,
+    /// User turn
+    User,
+    /// Assistant turn
+    Assistant,
+  }
+
+  impl UniversalRole
+  {
+    /// Canonical role name (`system`, `user` or `assistant`)
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        Self::System => "system",
+        Self::User => "user",
+        Self::Assistant => "assistant",
+      }
+    }
+
+    /// Map a provider role name to a neutral role
+    ///
+    /// `assistant` and `model` map to `Assistant`, `system` and `developer` to
+    /// `System`; any other name is treated as `User`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_provider_role( role : &str ) -> Self
+    {
+      match role
+      {
+        "assistant" | "model" => Self::Assistant,
+        "system" | "developer" => Self::System,
+        _ => Self::User,
+      }
+    }
+  }
+
+  /// Provider-neutral text message
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct UniversalMessage
+  {
+    /// Role of the message sender
+    pub role : UniversalRole,
+    /// Content of the message
+    pub content : String,
+  }
+
+  impl UniversalMessage
+  {
+    /// Create a message with the given role
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( role : UniversalRole, content : impl Into< String > ) -> Self
+    {
+      Self { role, content : content.into() }
+    }
+
+    /// Create a system message
+    #[ inline ]
+    #[ must_use ]
+    pub fn system( content : impl Into< String > ) -> Self
+    {
+      Self::new( UniversalRole::System, content )
+    }
+
+    /// Create a user message
+    #[ inline ]
+    #[ must_use ]
+    pub fn user( content : impl Into< String > ) -> Self
+    {
+      Self::new( UniversalRole::User, content )
+    }
+
+    /// Create an assistant message
+    #[ inline ]
+    #[ must_use ]
+    pub fn assistant( content : impl Into< String > ) -> Self
+    {
+      Self::new( UniversalRole::Assistant, content )
+    }
+  }
+
+  impl From< UniversalMessage > for Message
+  {
+    #[ inline ]
+    fn from( message : UniversalMessage ) -> Self
+    {
+      Self { role : message.role.as_str().to_string(), content : message.content }
+    }
+  }
+
+  impl From< Message > for UniversalMessage
+  {
+    #[ inline ]
+    fn from( message : Message ) -> Self
+    {
+      Self { role : UniversalRole::from_provider_role( &message.role ), content : message.content }
+    }
+  }
+
+  #[ cfg( feature = "vision_support" ) ]
+  impl From< UniversalRole > for MessageRole
+  {
+    #[ inline ]
+    fn from( role : UniversalRole ) -> Self
+    {
+      match role
+      {
+        UniversalRole::System => MessageRole::System,
+        UniversalRole::User => MessageRole::User,
+        UniversalRole::Assistant => MessageRole::Assistant,
+      }
+    }
+  }
+
+  /// Tool responses map to `User`
+  #[ cfg( feature = "vision_support" ) ]
+  impl From< MessageRole > for UniversalRole
+  {
+    #[ inline ]
+    fn from( role : MessageRole ) -> Self
+    {
+      match role
+      {
+        MessageRole::System => UniversalRole::System,
+        MessageRole::Assistant => UniversalRole::Assistant,
+        #[ cfg( feature = "tool_calling" ) ]
+        MessageRole::Tool => UniversalRole::User,
+        MessageRole::User => UniversalRole::User,
+      }
+    }
+  }
+
+  /// Converts to a text-only message without images or tool calls
+  #[ cfg( feature = "vision_support" ) ]
+  impl From< UniversalMessage > for ChatMessage
+  {
+    #[ inline ]
+    fn from( message : UniversalMessage ) -> Self
+    {
+      Self
+      {
+        role : message.role.into(),
+        content : message.content,
+        ..Default::default()
+      }
+    }
+  }
+
+  /// Images and tool calls are dropped
+  #[ cfg( feature = "vision_support" ) ]
+  impl From< ChatMessage > for UniversalMessage
+  {
+    #[ inline ]
+    fn from( message : ChatMessage ) -> Self
+    {
+      Self { role : message.role.into(), content : message.content }
+    }
+  }
 }
 
 #[ cfg( feature = "enabled" ) ]
@@ -126,6 +296,8 @@ crate ::mod_interface!
   exposed use
   {
     Message,
+    UniversalRole,
+    UniversalMessage,
   };
 
   #[ cfg( feature = "vision_support" ) ]
@@ -1,7 +1,8 @@
 //! Additional model information types for Ollama API.
 //!
 //! Provides supplementary model structures for comprehensive information,
-//! recommendations, diagnostics, and storage details.
+//! recommendations, diagnostics, and storage details, and task-based model
+//! recommendation.
 
 #[ cfg( feature = "model_details" ) ]
 mod private
@@ -13,6 +14,8 @@ mod private
   #[ derive( Debug, Clone ) ]
   pub struct ComprehensiveModelInfo
   {
+    /// Model name
+    pub name : String,
     /// Model size in bytes
     pub size_bytes : u64,
     /// Human-readable size format
@@ -51,6 +54,171 @@ mod private
     pub similarity_score : f64,
   }
 
+  /// Trade-off between model size and output quality
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Default ) ]
+  pub enum ModelSizePreference
+  {
+    /// Prefer the smallest eligible model
+    Speed,
+    /// Prefer a mid-sized eligible model
+    #[ default ]
+    Balanced,
+    /// Prefer the largest eligible model
+    Quality,
+  }
+
+  /// Requirements a task places on the model serving it
+  #[ derive( Debug, Clone, Default, PartialEq, Eq ) ]
+  pub struct TaskRequirements
+  {
+    /// Minimum context window in tokens
+    pub min_context_length : u32,
+    /// Features the model must support (matched case-insensitively against `supported_features`)
+    pub required_features : Vec< String >,
+    /// Largest acceptable model size in bytes
+    pub max_size_bytes : Option< u64 >,
+    /// Size versus quality trade-off
+    pub size_preference : ModelSizePreference,
+  }
+
+  impl TaskRequirements
+  {
+    /// Create requirements that every model satisfies, with a balanced preference
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Require a context window of at least `tokens`
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_min_context_length( mut self, tokens : u32 ) -> Self
+    {
+      self.min_context_length = tokens;
+      self
+    }
+
+    /// Require a supported feature
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_feature( mut self, feature : impl Into< String > ) -> Self
+    {
+      self.required_features.push( feature.into() );
+      self
+    }
+
+    /// Limit the model size
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_max_size_bytes( mut self, bytes : u64 ) -> Self
+    {
+      self.max_size_bytes = Some( bytes );
+      self
+    }
+
+    /// Set the size versus quality trade-off
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_size_preference( mut self, preference : ModelSizePreference ) -> Self
+    {
+      self.size_preference = preference;
+      self
+    }
+
+    /// Why `model` cannot serve the task, or `None` if it is eligible
+    fn rejection( &self, model : &ComprehensiveModelInfo ) -> Option< String >
+    {
+      if model.context_length < self.min_context_length
+      {
+        return Some( format!( "context {} < {}", model.context_length, self.min_context_length ) );
+      }
+      if let Some( feature ) = self.required_features.iter()
+        .find( | feature | !model.supported_features.iter().any( | f | f.eq_ignore_ascii_case( feature ) ) )
+      {
+        return Some( format!( "missing feature '{feature}'" ) );
+      }
+      match self.max_size_bytes
+      {
+        Some( max ) if model.size_bytes > max => Some( format!( "size {} > {max} bytes", model.size_bytes ) ),
+        _ => None,
+      }
+    }
+  }
+
+  /// Model scale used for ranking : parameter count, or size in bytes when the count is unknown
+  fn model_scale( model : &ComprehensiveModelInfo ) -> f64
+  {
+    let scale = if model.parameter_count > 0 { model.parameter_count } else { model.size_bytes };
+    ( scale.max( 1 ) as f64 ).ln()
+  }
+
+  /// Recommend the eligible model that best fits `requirements`
+  ///
+  /// Models lacking the context length, a required feature, or exceeding the
+  /// size limit are excluded. The rest are placed on a logarithmic size scale
+  /// from smallest (0.0) to largest (1.0) and scored by the size preference :
+  /// `Speed` favours the small end, `Quality` the large end and `Balanced` the
+  /// middle. Ties go to the larger context window. The score is returned as
+  /// `similarity_score`. This only recommends; nothing is loaded or pulled.
+  #[ inline ]
+  #[ must_use ]
+  pub fn recommend_model( available : &[ ComprehensiveModelInfo ], requirements : &TaskRequirements ) -> Option< ModelRecommendation >
+  {
+    let eligible : Vec< &ComprehensiveModelInfo > = available.iter()
+      .filter( | model | requirements.rejection( model ).is_none() )
+      .collect();
+    let excluded = available.len() - eligible.len();
+
+    let smallest = eligible.iter().map( | model | model_scale( model ) ).fold( f64::INFINITY, f64::min );
+    let largest = eligible.iter().map( | model | model_scale( model ) ).fold( f64::NEG_INFINITY, f64::max );
+    let position = | model : &ComprehensiveModelInfo |
+    {
+      if largest > smallest { ( model_scale( model ) - smallest ) / ( largest - smallest ) } else { 0.5 }
+    };
+    let score = | model : &ComprehensiveModelInfo |
+    {
+      let position = position( model );
+      match requirements.size_preference
+      {
+        ModelSizePreference::Speed => 1.0 - position,
+        ModelSizePreference::Quality => position,
+        ModelSizePreference::Balanced => 1.0 - ( position - 0.5 ).abs() * 2.0,
+      }
+    };
+
+    let best = eligible.into_iter().max_by( | a, b |
+    {
+      score( a ).total_cmp( &score( b ) )
+        .then_with( || a.context_length.cmp( &b.context_length ) )
+    } )?;
+
+    let size = if best.parameter_size.is_empty() { best.size_human_readable.clone() } else { best.parameter_size.clone() };
+    let fit = match requirements.size_preference
+    {
+      ModelSizePreference::Speed => "smallest fit, preferred for speed",
+      ModelSizePreference::Quality => "largest fit, preferred for quality",
+      ModelSizePreference::Balanced => "mid-sized fit, balancing speed and quality",
+    };
+    let mut reason = format!( "{size} model with {}-token context; {fit}", best.context_length );
+    if !requirements.required_features.is_empty()
+    {
+      reason.push_str( &format!( "; supports {}", requirements.required_features.join( ", " ) ) );
+    }
+    if excluded > 0
+    {
+      reason.push_str( &format!( "; {excluded} model(s) excluded by requirements" ) );
+    }
+
+    Some( ModelRecommendation
+    {
+      model_name : best.name.clone(),
+      reason,
+      similarity_score : score( best ),
+    } )
+  }
+
   /// Model lifecycle status information
   #[ derive( Debug, Clone ) ]
   pub struct ModelLifecycleStatus
@@ -81,6 +249,8 @@ mod private
   #[ derive( Debug, Clone ) ]
   pub struct ModelHealthCheck
   {
+    /// Checked model name
+    pub model_name : String,
     /// Whether model is available
     pub is_available : bool,
     /// Response time for health check
@@ -91,6 +261,50 @@ mod private
     pub issues : Option< Vec< String > >,
   }
 
+  /// Aggregate of health checks across models
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct ModelHealthSummary
+  {
+    /// Number of models checked
+    pub total : usize,
+    /// Models that loaded and answered the probe
+    pub loadable : usize,
+    /// Names of models that failed the probe
+    pub failing : Vec< String >,
+    /// Mean probe response time of loadable models
+    pub average_response_time : Option< Duration >,
+  }
+
+  impl ModelHealthSummary
+  {
+    /// Summarize per-model health checks
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_checks( checks : &[ ModelHealthCheck ] ) -> Self
+    {
+      let loadable : Vec< &ModelHealthCheck > = checks.iter().filter( | check | check.is_available ).collect();
+      let average_response_time = u32::try_from( loadable.len() ).ok()
+        .filter( | count | *count > 0 )
+        .map( | count | loadable.iter().map( | check | check.response_time ).sum::< Duration >() / count );
+
+      Self
+      {
+        total : checks.len(),
+        loadable : loadable.len(),
+        failing : checks.iter().filter( | check | !check.is_available ).map( | check | check.model_name.clone() ).collect(),
+        average_response_time,
+      }
+    }
+
+    /// Whether every checked model is loadable
+    #[ inline ]
+    #[ must_use ]
+    pub fn all_loadable( &self ) -> bool
+    {
+      self.loadable == self.total
+    }
+  }
+
   /// Local model storage information
   #[ derive( Debug, Clone ) ]
   pub struct LocalModelStorageInfo
@@ -105,6 +319,91 @@ mod private
     pub available_space_bytes : u64,
   }
 
+  /// Model size entry in a storage report
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct StoredModel
+  {
+    /// Model name
+    pub name : String,
+    /// Size on disk in bytes
+    pub size_bytes : u64,
+  }
+
+  /// Model not modified within the report's threshold
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct StaleModel
+  {
+    /// Model name
+    pub name : String,
+    /// Size on disk in bytes
+    pub size_bytes : u64,
+    /// Time since the model was last modified
+    pub since_modified : Duration,
+  }
+
+  /// Disk usage across local models
+  ///
+  /// The server does not report when a model was last used, so models are
+  /// flagged as stale by their `modified_at` time, which is set when the model
+  /// is pulled or created. A stale model may still be in regular use. Models
+  /// whose timestamp is not valid RFC 3339 are never flagged.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct StorageReport
+  {
+    /// Number of local models
+    pub total_models : usize,
+    /// Combined size of all local models in bytes
+    pub total_size_bytes : u64,
+    /// Largest models, largest first, at most `StorageReport::LARGEST_MODELS`
+    pub largest : Vec< StoredModel >,
+    /// Models not modified within the threshold, least recently modified first
+    pub stale : Vec< StaleModel >,
+  }
+
+  impl StorageReport
+  {
+    /// Number of models listed in `largest`
+    pub const LARGEST_MODELS : usize = 5;
+
+    /// Build a report from a model listing as of `now`
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_models( models : &[ ModelEntry ], stale_after : Duration, now : std::time::SystemTime ) -> Self
+    {
+      let mut largest : Vec< StoredModel > = models.iter()
+        .map( | model | StoredModel { name : model.name.clone(), size_bytes : model.size } )
+        .collect();
+      largest.sort_by( | a, b | b.size_bytes.cmp( &a.size_bytes ).then_with( || a.name.cmp( &b.name ) ) );
+      largest.truncate( Self::LARGEST_MODELS );
+
+      let mut stale : Vec< StaleModel > = models.iter()
+        .filter_map( | model |
+        {
+          let modified_at = chrono::DateTime::parse_from_rfc3339( model.modified_at.trim() ).ok()?;
+          let since_modified = now.duration_since( modified_at.into() ).ok()?;
+          ( since_modified > stale_after ).then( || StaleModel { name : model.name.clone(), size_bytes : model.size, since_modified } )
+        } )
+        .collect();
+      stale.sort_by( | a, b | b.since_modified.cmp( &a.since_modified ) );
+
+      Self
+      {
+        total_models : models.len(),
+        total_size_bytes : models.iter().map( | model | model.size ).sum(),
+        largest,
+        stale,
+      }
+    }
+
+    /// Bytes that removing every stale model would free
+    #[ inline ]
+    #[ must_use ]
+    pub fn reclaimable_bytes( &self ) -> u64
+    {
+      self.stale.iter().map( | model | model.size_bytes ).sum()
+    }
+  }
+
   /// Model diagnostics information
   #[ derive( Debug, Clone ) ]
   pub struct ModelDiagnostics
@@ -121,10 +420,17 @@ crate ::mod_interface!
   {
     ComprehensiveModelInfo,
     ModelRecommendation,
+    ModelSizePreference,
+    TaskRequirements,
+    recommend_model,
     ModelLifecycleStatus,
     ModelOperationHistoryEntry,
     ModelHealthCheck,
+    ModelHealthSummary,
     LocalModelStorageInfo,
+    StoredModel,
+    StaleModel,
+    StorageReport,
     ModelDiagnostics,
   };
 }
@@ -1,6 +1,7 @@
 //! Enhanced model details types for Ollama API.
 //!
-//! Provides comprehensive model metadata, lifecycle tracking, and performance metrics.
+//! Provides comprehensive model metadata, lifecycle tracking, and performance metrics,
+//! and capability-based model filtering.
 
 #[ cfg( feature = "model_details" ) ]
 mod private
@@ -143,6 +144,127 @@ mod private
     pub system_prompt : Option< String >,
     /// Additional model parameters
     pub parameters : std::collections::HashMap<  String, serde_json::Value  >,
+    /// Capabilities reported by the server (`completion`, `vision`, `tools`, `embedding`, ...)
+    pub capabilities : Vec< String >,
+    /// Maximum context length in tokens, if known
+    pub context_length : Option< u64 >,
+  }
+
+  impl ModelMetadata
+  {
+    /// Whether the server reports `capability` for this model (case-insensitive)
+    #[ inline ]
+    #[ must_use ]
+    pub fn has_capability( &self, capability : &str ) -> bool
+    {
+      self.capabilities.iter().any( | c | c.eq_ignore_ascii_case( capability ) )
+    }
+  }
+
+  /// Capability requirements for selecting models
+  ///
+  /// A model matches when it reports every required capability and, if a
+  /// minimum context length is set, a known context length at least that long.
+  #[ derive( Debug, Clone, Default, PartialEq, Eq ) ]
+  pub struct ModelCapabilityFilter
+  {
+    capabilities : Vec< String >,
+    min_context_length : Option< u64 >,
+  }
+
+  impl ModelCapabilityFilter
+  {
+    /// Create a filter that matches every model
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Require a capability by its server name
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_capability( mut self, capability : impl Into< String > ) -> Self
+    {
+      let capability = capability.into();
+      if !self.capabilities.iter().any( | c | c.eq_ignore_ascii_case( &capability ) )
+      {
+        self.capabilities.push( capability );
+      }
+      self
+    }
+
+    /// Require image input support
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_vision( self ) -> Self
+    {
+      self.with_capability( "vision" )
+    }
+
+    /// Require tool calling support
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_tools( self ) -> Self
+    {
+      self.with_capability( "tools" )
+    }
+
+    /// Require embedding generation support
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_embeddings( self ) -> Self
+    {
+      self.with_capability( "embedding" )
+    }
+
+    /// Require a context length of at least `tokens`
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_min_context_length( mut self, tokens : u64 ) -> Self
+    {
+      self.min_context_length = Some( tokens );
+      self
+    }
+
+    /// Required capabilities
+    #[ inline ]
+    pub fn capabilities( &self ) -> &[ String ]
+    {
+      &self.capabilities
+    }
+
+    /// Required minimum context length
+    #[ inline ]
+    #[ must_use ]
+    pub fn min_context_length( &self ) -> Option< u64 >
+    {
+      self.min_context_length
+    }
+
+    /// Whether `model` satisfies every requirement
+    #[ inline ]
+    #[ must_use ]
+    pub fn matches( &self, model : &EnhancedModelDetails ) -> bool
+    {
+      let metadata = model.metadata();
+      let has_capabilities = self.capabilities.iter().all( | c | metadata.has_capability( c ) );
+      let has_context = match self.min_context_length
+      {
+        Some( min ) => metadata.context_length.is_some_and( | length | length >= min ),
+        None => true,
+      };
+      has_capabilities && has_context
+    }
+  }
+
+  /// Models matching every requirement of `filter`, in their original order
+  #[ inline ]
+  #[ must_use ]
+  pub fn filter_models< 'a >( models : &'a [ EnhancedModelDetails ], filter : &ModelCapabilityFilter ) -> Vec< &'a EnhancedModelDetails >
+  {
+    models.iter().filter( | model | filter.matches( model ) ).collect()
   }
 
   /// Model lifecycle status
@@ -204,5 +326,7 @@ crate ::mod_interface!
     ModelLifecycle,
     ModelOperation,
     ModelPerformanceMetrics,
+    ModelCapabilityFilter,
+    filter_models,
   };
 }
@@ -7,20 +7,49 @@
 mod private
 {
   use std::collections::HashMap;
+  use std::collections::hash_map::DefaultHasher;
   use std::fmt;
+  use std::hash::{ Hash, Hasher };
+  use std::sync::{ Arc, PoisonError, RwLock };
   use error_tools::untyped::{ format_err, Result };
-  use workspace_tools::{ workspace, WorkspaceError };
+  use workspace_tools::{ workspace, Workspace, WorkspaceError };
 
   /// Result type for Ollama API operations
   pub type OllamaResult< T > = Result< T >;
 
+  /// Secret value paired with a hash of its content
+  ///
+  /// The version changes whenever the value changes, so callers can detect a
+  /// rotated secret without comparing or retaining the secret itself.
+  #[ derive( Clone, PartialEq, Eq ) ]
+  pub struct VersionedSecret
+  {
+    /// Secret value
+    pub value : String,
+    /// Hash of the value content
+    pub version : u64,
+  }
+
+  impl fmt::Debug for VersionedSecret
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "VersionedSecret" )
+        .field( "value", &"***" )
+        .field( "version", &self.version )
+        .finish()
+    }
+  }
+
   /// Secret store that integrates with workspace_tools
   ///
   /// Provides fallback chain : workspace secrets → environment variables → error
+  ///
+  /// Clones share the loaded secrets, so a `reload` is seen by every clone.
   #[ derive( Clone ) ]
   pub struct WorkspaceSecretStore
   {
-    secrets : HashMap<  String, String  >,
+    secrets : Arc< RwLock< HashMap<  String, String  > > >,
     workspace_path : Option< std::path::PathBuf >,
   }
 
@@ -33,24 +62,39 @@ mod private
         .map_err( | e | format_err!( "Failed to resolve workspace : {}", Self::sanitize_error( &format!( "{}", e ) ) ) )?;
 
       let workspace_path = Some( ws.root().to_path_buf() );
+      let secrets = Self::load_secrets( &ws )?;
 
-      let secrets = match ws.load_secrets_from_file( "-secrets.sh" )
-      {
-        Ok( secrets ) => secrets,
-        Err( WorkspaceError::IoError( _ ) ) =>
-        {
-          HashMap::new()
-        },
-        Err( e ) => return Err( format_err!( "Failed to load workspace configuration : {}", Self::sanitize_error( &format!( "{}", e ) ) ) ),
-      };
+      Ok( Self { secrets : Arc::new( RwLock::new( secrets ) ), workspace_path } )
+    }
+
+    /// Create secret store from the workspace at a specific path
+    pub fn from_workspace_at< P : AsRef< std::path::Path > >( workspace_path : P ) -> OllamaResult< Self >
+    {
+      let ws = Workspace::new( workspace_path.as_ref() );
+      let secrets = Self::load_secrets( &ws )?;
 
-      Ok( Self { secrets, workspace_path } )
+      Ok( Self { secrets : Arc::new( RwLock::new( secrets ) ), workspace_path : Some( ws.root().to_path_buf() ) } )
+    }
+
+    /// Re-read the workspace secret file, replacing the in-memory secrets
+    ///
+    /// Lets a rotated key take effect without restarting the process. The
+    /// file itself is only read; nothing is persisted. On error the
+    /// previously loaded secrets are kept.
+    pub fn reload( &self ) -> OllamaResult< () >
+    {
+      let workspace_path = self.workspace_path.as_ref()
+        .ok_or_else( || format_err!( "Cannot reload workspace secrets : no workspace path" ) )?;
+      let secrets = Self::load_secrets( &Workspace::new( workspace_path ) )?;
+
+      *self.secrets.write().unwrap_or_else( PoisonError::into_inner ) = secrets;
+      Ok( () )
     }
 
     /// Get a secret value with fallback chain : workspace secrets → environment → error
     pub fn get_secret( &self, key : &str ) -> OllamaResult< String >
     {
-      if let Some( value ) = self.secrets.get( key )
+      if let Some( value ) = self.read_secrets().get( key )
       {
         return Ok( value.clone() );
       }
@@ -59,16 +103,29 @@ mod private
         .map_err( | _ | format_err!( "{} not found in workspace secrets or environment variables", key ) )
     }
 
+    /// Get a secret value together with a hash of its content
+    ///
+    /// Uses the same fallback chain as `get_secret`. Comparing versions
+    /// across calls detects a changed value, e.g. after `reload`.
+    pub fn get_versioned( &self, key : &str ) -> OllamaResult< VersionedSecret >
+    {
+      let value = self.get_secret( key )?;
+      let mut hasher = DefaultHasher::new();
+      value.hash( &mut hasher );
+
+      Ok( VersionedSecret { value, version : hasher.finish() } )
+    }
+
     /// Check if a secret exists in either workspace secrets or environment
     pub fn has_secret( &self, key : &str ) -> bool
     {
-      self.secrets.contains_key( key ) || std::env::var( key ).is_ok()
+      self.read_secrets().contains_key( key ) || std::env::var( key ).is_ok()
     }
 
     /// Get all available secret keys (without values for security)
     pub fn available_keys( &self ) -> Vec< String >
     {
-      let mut keys = self.secrets.keys().cloned().collect::< Vec< _ > >();
+      let mut keys = self.read_secrets().keys().cloned().collect::< Vec< _ > >();
 
       for ( env_key, _ ) in std::env::vars()
       {
@@ -120,6 +177,22 @@ mod private
       sanitized
     }
 
+    /// Load the workspace secret file, treating a missing file as empty
+    fn load_secrets( ws : &Workspace ) -> OllamaResult< HashMap<  String, String  > >
+    {
+      match ws.load_secrets_from_file( "-secrets.sh" )
+      {
+        Ok( secrets ) => Ok( secrets ),
+        Err( WorkspaceError::IoError( _ ) ) => Ok( HashMap::new() ),
+        Err( e ) => Err( format_err!( "Failed to load workspace configuration : {}", Self::sanitize_error( &format!( "{}", e ) ) ) ),
+      }
+    }
+
+    fn read_secrets( &self ) -> std::sync::RwLockReadGuard< '_, HashMap<  String, String  > >
+    {
+      self.secrets.read().unwrap_or_else( PoisonError::into_inner )
+    }
+
     /// Check if environment variable key looks like a secret
     fn looks_like_secret_key( key : &str ) -> bool
     {
@@ -136,17 +209,18 @@ mod private
   {
     fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
     {
-      let masked_secrets : HashMap<  String, String  > = self.secrets
+      let secrets = self.read_secrets();
+      let masked_secrets : HashMap<  String, String  > = secrets
         .keys()
         .map( | k |
         {
-          let masked_value = if self.secrets[ k ].len() <= 8
+          let masked_value = if secrets[ k ].len() <= 8
           {
             "***".to_string()
           }
           else
           {
-            format!( "{}***", &self.secrets[ k ][ ..4 ] )
+            format!( "{}***", &secrets[ k ][ ..4 ] )
           };
           ( k.clone(), masked_value )
         })
@@ -163,7 +237,7 @@ mod private
   {
     fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
     {
-      write!( f, "WorkspaceSecretStore with {} secrets from workspace", self.secrets.len() )?;
+      write!( f, "WorkspaceSecretStore with {} secrets from workspace", self.read_secrets().len() )?;
       if let Some( path ) = &self.workspace_path
       {
         write!( f, " at {}", path.display() )?;
@@ -177,4 +251,5 @@ mod private
 crate ::mod_interface!
 {
   exposed use WorkspaceSecretStore;
+  exposed use VersionedSecret;
 }
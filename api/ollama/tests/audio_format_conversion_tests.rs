@@ -0,0 +1,75 @@
+//! Audio format conversion tests
+//!
+//! Tests `AudioProcessingConfig::convert` wrapping raw PCM into WAV and
+//! unwrapping it back, the header metadata read by `WavInfo::parse`, and
+//! errors for conversions that would need a codec.
+
+#![ cfg( feature = "audio_processing" ) ]
+
+use api_ollama::{ AudioFormat, AudioProcessingConfig, WavInfo };
+
+fn pcm( frames : usize, frame_bytes : usize ) -> Vec< u8 >
+{
+  ( 0..frames * frame_bytes ).map( | i | ( i % 251 ) as u8 ).collect()
+}
+
+#[ test ]
+fn pcm_round_trips_through_wav()
+{
+  let config = AudioProcessingConfig::new().with_sample_rate( 22_050 ).with_channels( 2 ).with_bit_depth( 16 );
+  let samples = pcm( 1_000, 4 );
+
+  let wav = config.convert( &samples, AudioFormat::Pcm, AudioFormat::Wav ).unwrap();
+
+  assert_eq!( AudioFormat::detect_format( &wav ), Some( AudioFormat::Wav ) );
+  assert_eq!( wav.len(), 44 + samples.len() );
+  let info = WavInfo::parse( &wav ).unwrap();
+  assert_eq!( info, WavInfo { sample_rate : 22_050, channels : 2, bit_depth : 16, data_offset : 44, data_len : samples.len() } );
+  // Byte rate and block align
+  assert_eq!( u32::from_le_bytes( wav[ 28..32 ].try_into().unwrap() ), 22_050 * 4 );
+  assert_eq!( u16::from_le_bytes( wav[ 32..34 ].try_into().unwrap() ), 4 );
+
+  let unwrapped = config.convert( &wav, AudioFormat::Wav, AudioFormat::Pcm ).unwrap();
+  assert_eq!( unwrapped, samples );
+}
+
+#[ test ]
+fn unwraps_wav_with_extra_chunks()
+{
+  let config = AudioProcessingConfig::new().with_sample_rate( 8_000 ).with_channels( 1 ).with_bit_depth( 8 );
+  let samples = pcm( 9, 1 );
+  let mut wav = config.convert( &samples, AudioFormat::Pcm, AudioFormat::Wav ).unwrap();
+
+  // Insert an odd-sized LIST chunk, padded to even length, before `data`
+  let list = [ b"LIST".as_slice(), &3_u32.to_le_bytes(), b"abc\0" ].concat();
+  wav.splice( 36..36, list );
+
+  let info = WavInfo::parse( &wav ).unwrap();
+  assert_eq!( ( info.sample_rate, info.channels, info.bit_depth ), ( 8_000, 1, 8 ) );
+  assert_eq!( config.convert( &wav, AudioFormat::Wav, AudioFormat::Pcm ).unwrap(), samples );
+}
+
+#[ test ]
+fn same_format_is_unchanged()
+{
+  let config = AudioProcessingConfig::new();
+  let data = b"\xFF\xFB\x90\x00 mp3 frames".to_vec();
+  assert_eq!( config.convert( &data, AudioFormat::Mp3, AudioFormat::Mp3 ).unwrap(), data );
+}
+
+#[ test ]
+fn rejects_unsupported_and_malformed_input()
+{
+  let config = AudioProcessingConfig::new();
+
+  let error = config.convert( &[ 0; 16 ], AudioFormat::Mp3, AudioFormat::Wav ).unwrap_err();
+  assert!( error.to_string().contains( "Conversion from Mp3 to Wav is not supported" ), "{error}" );
+  assert!( config.convert( &[ 0; 16 ], AudioFormat::Pcm, AudioFormat::Flac ).is_err() );
+
+  // Not a WAV file
+  assert!( config.convert( &[ 0; 64 ], AudioFormat::Wav, AudioFormat::Pcm ).is_err() );
+
+  // 16-bit mono needs an even number of bytes
+  let error = config.convert( &[ 0; 3 ], AudioFormat::Pcm, AudioFormat::Wav ).unwrap_err();
+  assert!( error.to_string().contains( "whole number" ), "{error}" );
+}
@@ -0,0 +1,113 @@
+//! Audio stream assembly tests
+//!
+//! Tests `collect_audio` assembling synthetic multi-chunk text-to-speech
+//! streams : sequence ordering, WAV header size fix-up and metadata, format
+//! mismatches and stream errors.
+
+#![ cfg( feature = "audio_processing" ) ]
+
+use api_ollama::{ collect_audio, AudioFormat, AudioStreamChunk };
+use futures_util::stream;
+
+const SAMPLE_RATE : u32 = 16_000;
+
+/// 16 kHz mono 16-bit WAV header with the placeholder sizes streaming encoders emit
+fn streaming_wav_header() -> Vec< u8 >
+{
+  let mut header = Vec::new();
+  header.extend_from_slice( b"RIFF" );
+  header.extend_from_slice( &u32::MAX.to_le_bytes() );
+  header.extend_from_slice( b"WAVEfmt " );
+  header.extend_from_slice( &16_u32.to_le_bytes() );
+  header.extend_from_slice( &1_u16.to_le_bytes() ); // PCM
+  header.extend_from_slice( &1_u16.to_le_bytes() ); // mono
+  header.extend_from_slice( &SAMPLE_RATE.to_le_bytes() );
+  header.extend_from_slice( &( SAMPLE_RATE * 2 ).to_le_bytes() );
+  header.extend_from_slice( &2_u16.to_le_bytes() );
+  header.extend_from_slice( &16_u16.to_le_bytes() );
+  header.extend_from_slice( b"data" );
+  header.extend_from_slice( &u32::MAX.to_le_bytes() );
+  header
+}
+
+fn chunk( sequence : Option< u64 >, data : Vec< u8 >, is_final : bool ) -> AudioStreamChunk
+{
+  AudioStreamChunk { data, timestamp : 0.0, is_final, sequence }
+}
+
+fn read_u32( data : &[ u8 ], at : usize ) -> u32
+{
+  u32::from_le_bytes( data[ at..at + 4 ].try_into().unwrap() )
+}
+
+#[ tokio::test ]
+async fn assembles_wav_chunks_in_sequence_order()
+{
+  // One second of samples split across three chunks, delivered out of order
+  let samples = vec![ 7_u8; ( SAMPLE_RATE * 2 ) as usize ];
+  let ( first, rest ) = samples.split_at( 10_000 );
+  let ( second, third ) = rest.split_at( 12_000 );
+  let chunks = vec!
+  [
+    Ok::< _, String >( chunk( Some( 0 ), streaming_wav_header(), false ) ),
+    Ok( chunk( Some( 2 ), second.to_vec(), false ) ),
+    Ok( chunk( Some( 1 ), first.to_vec(), false ) ),
+    Ok( chunk( Some( 4 ), Vec::new(), true ) ),
+    Ok( chunk( Some( 3 ), third.to_vec(), false ) ),
+  ];
+
+  let audio = collect_audio( stream::iter( chunks ), AudioFormat::Wav ).await.unwrap();
+
+  let data = &audio.audio_data;
+  assert_eq!( data.len(), 44 + samples.len() );
+  assert_eq!( &data[ 44.. ], samples.as_slice() );
+  assert_eq!( read_u32( data, 4 ) as usize, data.len() - 8 );
+  assert_eq!( read_u32( data, 40 ) as usize, samples.len() );
+  assert_eq!( audio.format, AudioFormat::Wav );
+  assert_eq!( audio.sample_rate, Some( SAMPLE_RATE ) );
+  assert!( ( audio.duration.unwrap() - 1.0 ).abs() < f64::EPSILON );
+}
+
+#[ tokio::test ]
+async fn keeps_arrival_order_without_sequences()
+{
+  let chunks = vec!
+  [
+    Ok::< _, String >( chunk( None, b"\xFF\xFB\x90\x00".to_vec(), false ) ),
+    Ok( chunk( None, vec![ 1; 8 ], false ) ),
+    Ok( chunk( None, vec![ 2; 8 ], true ) ),
+  ];
+
+  let audio = collect_audio( stream::iter( chunks ), AudioFormat::Mp3 ).await.unwrap();
+
+  assert_eq!( audio.audio_data.len(), 20 );
+  assert_eq!( &audio.audio_data[ 4..12 ], &[ 1; 8 ] );
+  assert_eq!( audio.sample_rate, None );
+}
+
+#[ tokio::test ]
+async fn rejects_mismatched_or_missing_audio()
+{
+  let ogg = vec![ Ok::< _, String >( chunk( Some( 0 ), b"OggS\x00\x02\x00\x00\x00\x00\x00\x00".to_vec(), true ) ) ];
+  let error = collect_audio( stream::iter( ogg ), AudioFormat::Wav ).await.unwrap_err();
+  assert!( error.to_string().contains( "expected Wav" ), "{error}" );
+
+  let headerless = vec![ Ok::< _, String >( chunk( Some( 0 ), vec![ 0; 64 ], true ) ) ];
+  assert!( collect_audio( stream::iter( headerless ), AudioFormat::Wav ).await.is_err() );
+
+  let empty = vec![ Ok::< _, String >( chunk( Some( 0 ), Vec::new(), true ) ) ];
+  assert!( collect_audio( stream::iter( empty ), AudioFormat::Mp3 ).await.is_err() );
+}
+
+#[ tokio::test ]
+async fn propagates_stream_errors()
+{
+  let chunks = vec!
+  [
+    Ok( chunk( Some( 0 ), streaming_wav_header(), false ) ),
+    Err( "connection reset".to_string() ),
+  ];
+
+  let error = collect_audio( stream::iter( chunks ), AudioFormat::Wav ).await.unwrap_err();
+  assert!( error.to_string().contains( "connection reset" ), "{error}" );
+}
@@ -0,0 +1,105 @@
+//! Audio stream buffering tests
+//!
+//! Tests the `audio_stream_channel` buffer policies with a consumer that lags
+//! behind the producer : `Block` holds the producer back without losing
+//! chunks, `DropOldest` keeps the newest chunks and counts the dropped ones.
+
+#![ cfg( feature = "audio_processing" ) ]
+
+use api_ollama::{ audio_stream_channel, AudioBufferPolicy, AudioProcessingConfig, AudioStreamChunk, AudioStreamReceiver };
+use core::time::Duration;
+
+fn chunk( sequence : u64 ) -> AudioStreamChunk
+{
+  AudioStreamChunk
+  {
+    data : vec![ 0; 16 ],
+    timestamp : sequence as f64 * 0.1,
+    is_final : false,
+    sequence : Some( sequence ),
+  }
+}
+
+async fn drain( receiver : &mut AudioStreamReceiver ) -> Vec< u64 >
+{
+  let mut sequences = Vec::new();
+  while let Some( item ) = receiver.recv().await
+  {
+    sequences.push( item.unwrap().sequence.unwrap() );
+    // Slow consumer
+    tokio::time::sleep( Duration::from_millis( 5 ) ).await;
+  }
+  sequences
+}
+
+#[ tokio::test ]
+async fn block_policy_holds_producer_back()
+{
+  let config = AudioProcessingConfig::new()
+    .with_max_buffered_chunks( 2 )
+    .with_buffer_policy( AudioBufferPolicy::Block );
+  let ( sender, mut receiver ) = audio_stream_channel( &config );
+
+  let producer = tokio::spawn( async move
+  {
+    for sequence in 0..6
+    {
+      sender.send( Ok( chunk( sequence ) ) ).await.unwrap();
+    }
+  } );
+
+  tokio::time::sleep( Duration::from_millis( 50 ) ).await;
+  assert_eq!( receiver.buffered_chunks(), 2 );
+  assert!( !producer.is_finished() );
+
+  assert_eq!( drain( &mut receiver ).await, vec![ 0, 1, 2, 3, 4, 5 ] );
+  assert_eq!( receiver.dropped_chunks(), 0 );
+  producer.await.unwrap();
+}
+
+#[ tokio::test ]
+async fn drop_oldest_policy_keeps_newest_chunks()
+{
+  let config = AudioProcessingConfig::new()
+    .with_max_buffered_chunks( 3 )
+    .with_buffer_policy( AudioBufferPolicy::DropOldest );
+  let ( sender, mut receiver ) = audio_stream_channel( &config );
+
+  // Never waits, however far the consumer lags
+  for sequence in 0..10
+  {
+    sender.send( Ok( chunk( sequence ) ) ).await.unwrap();
+  }
+  assert_eq!( sender.dropped_chunks(), 7 );
+  assert_eq!( receiver.buffered_chunks(), 3 );
+  drop( sender );
+
+  assert_eq!( drain( &mut receiver ).await, vec![ 7, 8, 9 ] );
+  assert_eq!( receiver.dropped_chunks(), 7 );
+}
+
+#[ tokio::test ]
+async fn closing_receiver_fails_sends()
+{
+  let config = AudioProcessingConfig::new().with_max_buffered_chunks( 1 );
+  let ( sender, mut receiver ) = audio_stream_channel( &config );
+
+  sender.send( Ok( chunk( 0 ) ) ).await.unwrap();
+  let blocked = tokio::spawn( async move { sender.send( Ok( chunk( 1 ) ) ).await } );
+  tokio::time::sleep( Duration::from_millis( 20 ) ).await;
+
+  receiver.close();
+
+  assert!( blocked.await.unwrap().is_err() );
+  // Already buffered chunks are still delivered
+  assert_eq!( receiver.recv().await.unwrap().unwrap().sequence, Some( 0 ) );
+  assert!( receiver.recv().await.is_none() );
+}
+
+#[ test ]
+fn buffering_defaults()
+{
+  let config = AudioProcessingConfig::default();
+  assert_eq!( config.max_buffered_chunks(), 64 );
+  assert_eq!( config.buffer_policy(), AudioBufferPolicy::Block );
+}
@@ -0,0 +1,73 @@
+//! Text-to-speech stream timeout tests
+//!
+//! Tests that `text_to_speech_stream` applies the client timeout to each read
+//! rather than to the whole download, against a local server that trickles
+//! audio in chunks.
+
+#![ cfg( feature = "audio_processing" ) ]
+
+use api_ollama::{ collect_audio, AudioFormat, OllamaClient, TextToSpeechRequest };
+use core::time::Duration;
+use tokio::io::{ AsyncReadExt as _, AsyncWriteExt as _ };
+use tokio::net::TcpListener;
+
+/// Serve a chunked response that sends `chunks` pieces of audio, `gap` apart
+async fn spawn_trickling_server( chunks : usize, gap : Duration ) -> String
+{
+  let listener = TcpListener::bind( "127.0.0.1:0" ).await.unwrap();
+  let base_url = format!( "http://{}", listener.local_addr().unwrap() );
+
+  tokio::spawn( async move
+  {
+    let Ok( ( mut socket, _ ) ) = listener.accept().await else { return };
+    let mut request = [ 0_u8; 4096 ];
+    let _ = socket.read( &mut request ).await;
+    socket.write_all( b"HTTP/1.1 200 OK\r\ncontent-type: audio/mpeg\r\ntransfer-encoding: chunked\r\n\r\n" ).await.unwrap();
+    for _ in 0..chunks
+    {
+      tokio::time::sleep( gap ).await;
+      socket.write_all( b"4\r\naudi\r\n" ).await.unwrap();
+    }
+    socket.write_all( b"0\r\n\r\n" ).await.unwrap();
+  });
+
+  base_url
+}
+
+fn request() -> TextToSpeechRequest
+{
+  TextToSpeechRequest
+  {
+    model : "tts-1".to_string(),
+    text : "A long story".to_string(),
+    voice : None,
+    format : AudioFormat::Mp3,
+    speed : None,
+    options : None,
+  }
+}
+
+#[ tokio::test ]
+async fn stream_outlasting_timeout_is_not_cut_off()
+{
+  // 5 chunks 100ms apart take longer than the 300ms timeout, but no single read does
+  let base_url = spawn_trickling_server( 5, Duration::from_millis( 100 ) ).await;
+  let mut client = OllamaClient::new( base_url, Duration::from_millis( 300 ) );
+
+  let stream = client.text_to_speech_stream( request() ).await.unwrap();
+  let audio = collect_audio( stream, AudioFormat::Mp3 ).await.unwrap();
+
+  assert_eq!( audio.audio_data, b"audi".repeat( 5 ) );
+}
+
+#[ tokio::test ]
+async fn stalled_read_times_out()
+{
+  let base_url = spawn_trickling_server( 1, Duration::from_millis( 500 ) ).await;
+  let mut client = OllamaClient::new( base_url, Duration::from_millis( 100 ) );
+
+  let stream = client.text_to_speech_stream( request() ).await.unwrap();
+  let error = collect_audio( stream, AudioFormat::Mp3 ).await.unwrap_err();
+
+  assert!( error.to_string().contains( "No audio data received" ), "unexpected error : {error}" );
+}
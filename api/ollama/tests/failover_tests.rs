@@ -9,7 +9,9 @@
 
 #![ allow( clippy::std_instead_of_core ) ] // std required for time operations
 
-use api_ollama::{ OllamaClient, ChatRequest, ChatMessage, MessageRole, FailoverPolicy };
+use api_ollama::{ OllamaClient, ChatRequest, ChatMessage, MessageRole, FailoverPolicy, FailoverManager, EndpointInfo };
+use error_tools::untyped::format_err;
+use std::sync::Mutex;
 use std::time::Duration;
 
 /// Test failover client creation with multiple endpoints
@@ -259,3 +261,136 @@ async fn test_concurrent_requests_with_failover()
   assert!( !result1.is_empty() );
   assert!( !result2.is_empty() );
 }
+
+/// Test execute fails over from a failing primary to the backup endpoint
+#[ tokio::test ]
+async fn test_execute_fails_over_to_backup()
+{
+  let endpoints = vec![
+    "http://primary:11434".to_string(),
+    "http://backup:11434".to_string(),
+  ];
+  let manager = FailoverManager::new( endpoints, FailoverPolicy::Priority, Duration::from_secs( 5 ) ).unwrap();
+  let attempted = Mutex::new( Vec::new() );
+
+  let result = manager.execute( | endpoint : &EndpointInfo |
+  {
+    attempted.lock().unwrap().push( endpoint.url.clone() );
+    let url = endpoint.url.clone();
+    async move
+    {
+      if url == "http://primary:11434"
+      {
+        Err( format_err!( "connection refused" ) )
+      }
+      else
+      {
+        Ok( format!( "served by {url}" ) )
+      }
+    }
+  }).await;
+
+  assert_eq!( result.unwrap(), "served by http://backup:11434" );
+  assert_eq!( *attempted.lock().unwrap(), vec![ "http://primary:11434", "http://backup:11434" ] );
+  assert!( !manager.is_endpoint_healthy( "http://primary:11434" ) );
+  assert!( manager.is_endpoint_healthy( "http://backup:11434" ) );
+  assert_eq!( manager.get_active_endpoint(), "http://backup:11434" );
+
+  let stats = manager.get_failover_stats();
+  assert_eq!( stats.total_requests, 2 );
+  assert_eq!( stats.total_failovers, 1 );
+  assert_eq!( stats.active_endpoint_index, 1 );
+}
+
+/// Test execute returns the last error when every endpoint fails
+#[ tokio::test ]
+async fn test_execute_returns_last_error_when_all_fail()
+{
+  let endpoints = vec![
+    "http://primary:11434".to_string(),
+    "http://backup:11434".to_string(),
+  ];
+  let manager = FailoverManager::new( endpoints, FailoverPolicy::RoundRobin, Duration::from_secs( 5 ) ).unwrap();
+
+  let result : Result< (), _ > = manager.execute( | endpoint : &EndpointInfo |
+  {
+    let url = endpoint.url.clone();
+    async move { Err( format_err!( "{url} unavailable" ) ) }
+  }).await;
+
+  let error = result.unwrap_err().to_string();
+  assert_eq!( error, "http://backup:11434 unavailable" );
+  assert!( !manager.is_endpoint_healthy( "http://primary:11434" ) );
+  assert!( !manager.is_endpoint_healthy( "http://backup:11434" ) );
+  assert_eq!( manager.get_failover_stats().total_requests, 2 );
+}
+
+/// Test an unhealthy endpoint becomes probeable after the recovery interval and recovers
+#[ tokio::test ]
+async fn test_recovery_after_probes_and_recovers_endpoint()
+{
+  let endpoints = vec![
+    "http://primary:11434".to_string(),
+    "http://backup:11434".to_string(),
+  ];
+  let manager = FailoverManager::new( endpoints, FailoverPolicy::Priority, Duration::from_secs( 5 ) )
+    .unwrap()
+    .with_recovery_after( Duration::from_secs( 60 ) );
+  let primary_up = std::sync::atomic::AtomicBool::new( false );
+
+  let call = | endpoint : &EndpointInfo |
+  {
+    let url = endpoint.url.clone();
+    let fail = url == "http://primary:11434" && !primary_up.load( std::sync::atomic::Ordering::SeqCst );
+    async move { if fail { Err( format_err!( "{url} down" ) ) } else { Ok( url ) } }
+  };
+
+  assert_eq!( manager.execute( call ).await.unwrap(), "http://backup:11434" );
+  assert!( !manager.is_endpoint_healthy( "http://primary:11434" ) );
+
+  // Before the interval elapses the primary stays excluded
+  let failed_at = std::time::Instant::now();
+  assert_eq!( manager.recover_endpoints_at( failed_at + Duration::from_secs( 30 ) ), 0 );
+  assert_eq!( manager.get_active_endpoint(), "http://backup:11434" );
+
+  // After the interval the primary is probed first
+  assert_eq!( manager.recover_endpoints_at( failed_at + Duration::from_secs( 61 ) ), 1 );
+  assert_eq!( manager.get_active_endpoint(), "http://primary:11434" );
+
+  primary_up.store( true, std::sync::atomic::Ordering::SeqCst );
+  assert_eq!( manager.execute( call ).await.unwrap(), "http://primary:11434" );
+  assert!( manager.is_endpoint_healthy( "http://primary:11434" ) );
+  assert_eq!( manager.get_active_endpoint(), "http://primary:11434" );
+}
+
+/// Test a failed recovery probe marks the endpoint unhealthy and restarts its timer
+#[ tokio::test ]
+async fn test_failed_recovery_probe_resets_timer()
+{
+  let endpoints = vec![
+    "http://primary:11434".to_string(),
+    "http://backup:11434".to_string(),
+  ];
+  let manager = FailoverManager::new( endpoints, FailoverPolicy::Priority, Duration::from_secs( 5 ) )
+    .unwrap()
+    .with_recovery_after( Duration::from_secs( 60 ) );
+
+  let call = | endpoint : &EndpointInfo |
+  {
+    let url = endpoint.url.clone();
+    async move { if url == "http://primary:11434" { Err( format_err!( "{url} down" ) ) } else { Ok( url ) } }
+  };
+
+  manager.execute( call ).await.unwrap();
+  assert_eq!( manager.recover_endpoints_at( std::time::Instant::now() + Duration::from_secs( 61 ) ), 1 );
+
+  // The probe fails and the request falls through to the backup
+  assert_eq!( manager.execute( call ).await.unwrap(), "http://backup:11434" );
+  assert!( !manager.is_endpoint_healthy( "http://primary:11434" ) );
+  assert_eq!( manager.get_active_endpoint(), "http://backup:11434" );
+
+  // The timer restarted at the probe failure
+  let probed_at = std::time::Instant::now();
+  assert_eq!( manager.recover_endpoints_at( probed_at + Duration::from_secs( 30 ) ), 0 );
+  assert_eq!( manager.recover_endpoints_at( probed_at + Duration::from_secs( 61 ) ), 1 );
+}
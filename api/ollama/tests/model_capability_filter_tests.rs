@@ -0,0 +1,79 @@
+//! Model capability filter tests
+//!
+//! Tests selecting models from a fixture set by reported capabilities and
+//! minimum context length with `ModelCapabilityFilter` and `filter_models`.
+
+#![ cfg( feature = "model_details" ) ]
+
+use api_ollama::{ filter_models, EnhancedModelDetails, ModelCapabilityFilter, ModelMetadata };
+
+fn model( name : &str, capabilities : &[ &str ], context_length : Option< u64 > ) -> EnhancedModelDetails
+{
+  EnhancedModelDetails::new( name ).with_metadata( ModelMetadata
+  {
+    name : name.to_string(),
+    capabilities : capabilities.iter().map( | c | ( *c ).to_string() ).collect(),
+    context_length,
+    ..ModelMetadata::default()
+  } )
+}
+
+fn fixtures() -> Vec< EnhancedModelDetails >
+{
+  vec!
+  [
+    model( "llama3.2", &[ "completion", "tools" ], Some( 131_072 ) ),
+    model( "llava", &[ "completion", "vision" ], Some( 4_096 ) ),
+    model( "llama3.2-vision", &[ "completion", "vision", "tools" ], Some( 131_072 ) ),
+    model( "gemma3", &[ "completion", "Vision" ], Some( 32_768 ) ),
+    model( "moondream", &[ "completion", "vision" ], None ),
+    model( "nomic-embed-text", &[ "embedding" ], Some( 8_192 ) ),
+  ]
+}
+
+fn names< 'a >( models : &[ &'a EnhancedModelDetails ] ) -> Vec< &'a str >
+{
+  models.iter().map( | m | m.name() ).collect()
+}
+
+#[ test ]
+fn vision_with_min_context()
+{
+  let models = fixtures();
+  let filter = ModelCapabilityFilter::new().with_vision().with_min_context_length( 32_000 );
+
+  let matched = filter_models( &models, &filter );
+
+  // llava is too short, moondream has no known context length
+  assert_eq!( names( &matched ), vec![ "llama3.2-vision", "gemma3" ] );
+}
+
+#[ test ]
+fn all_capabilities_required()
+{
+  let models = fixtures();
+
+  let vision_tools = filter_models( &models, &ModelCapabilityFilter::new().with_vision().with_tools() );
+  assert_eq!( names( &vision_tools ), vec![ "llama3.2-vision" ] );
+
+  let embeddings = filter_models( &models, &ModelCapabilityFilter::new().with_embeddings() );
+  assert_eq!( names( &embeddings ), vec![ "nomic-embed-text" ] );
+
+  let none = filter_models( &models, &ModelCapabilityFilter::new().with_embeddings().with_vision() );
+  assert!( none.is_empty() );
+}
+
+#[ test ]
+fn empty_filter_matches_everything()
+{
+  let models = fixtures();
+  assert_eq!( filter_models( &models, &ModelCapabilityFilter::new() ).len(), models.len() );
+}
+
+#[ test ]
+fn capabilities_are_deduplicated_case_insensitively()
+{
+  let filter = ModelCapabilityFilter::new().with_vision().with_capability( "VISION" ).with_tools();
+  assert_eq!( filter.capabilities(), [ "vision".to_string(), "tools".to_string() ] );
+  assert_eq!( filter.min_context_length(), None );
+}
@@ -0,0 +1,169 @@
+//! Model health aggregation tests
+//!
+//! Tests `OllamaClient::check_all_models` against a local HTTP stub serving a
+//! fixed model list, where the probe for one model fails. The stub stands in
+//! for a server with specific models installed, which a live test server
+//! cannot provide.
+
+#![ cfg( all( feature = "enabled", feature = "model_details" ) ) ]
+
+use api_ollama::{ ModelHealthSummary, OllamaClient };
+use core::time::Duration;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+use std::sync::Arc;
+use tokio::io::{ AsyncReadExt, AsyncWriteExt };
+use tokio::net::{ TcpListener, TcpStream };
+
+const TAGS : &str = r#"{ "models" : [
+  { "name" : "llama3.2:1b", "model" : "llama3.2:1b", "modified_at" : "", "size" : 1, "digest" : "a" },
+  { "name" : "broken:latest", "model" : "broken:latest", "modified_at" : "", "size" : 1, "digest" : "b" },
+  { "name" : "qwen2.5:0.5b", "model" : "qwen2.5:0.5b", "modified_at" : "", "size" : 1, "digest" : "c" },
+  { "name" : "phi3:mini", "model" : "phi3:mini", "modified_at" : "", "size" : 1, "digest" : "d" },
+  { "name" : "gemma2:2b", "model" : "gemma2:2b", "modified_at" : "", "size" : 1, "digest" : "e" }
+] }"#;
+
+/// Probes in flight now, and the most seen at once
+#[ derive( Default ) ]
+struct Concurrency
+{
+  current : AtomicUsize,
+  peak : AtomicUsize,
+}
+
+/// Read one HTTP request, returning its request line and body
+async fn read_request( stream : &mut TcpStream ) -> ( String, String )
+{
+  let mut buffer = Vec::new();
+  let mut chunk = [ 0_u8; 1024 ];
+  let header_end = loop
+  {
+    let read = stream.read( &mut chunk ).await.unwrap();
+    buffer.extend_from_slice( &chunk[ ..read ] );
+    if let Some( position ) = buffer.windows( 4 ).position( | window | window == b"\r\n\r\n" )
+    {
+      break position + 4;
+    }
+  };
+
+  let head = String::from_utf8_lossy( &buffer[ ..header_end ] ).to_string();
+  let content_length = head.lines()
+    .find_map( | line | line.to_ascii_lowercase().strip_prefix( "content-length:" ).map( | value | value.trim().parse::< usize >().unwrap() ) )
+    .unwrap_or( 0 );
+  while buffer.len() < header_end + content_length
+  {
+    let read = stream.read( &mut chunk ).await.unwrap();
+    buffer.extend_from_slice( &chunk[ ..read ] );
+  }
+
+  let request_line = head.lines().next().unwrap_or_default().to_string();
+  ( request_line, String::from_utf8_lossy( &buffer[ header_end.. ] ).to_string() )
+}
+
+async fn respond( stream : &mut TcpStream, status : &str, body : &str )
+{
+  let response = format!( "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len() );
+  stream.write_all( response.as_bytes() ).await.unwrap();
+}
+
+async fn handle( mut stream : TcpStream, concurrency : Arc< Concurrency > )
+{
+  let ( request_line, body ) = read_request( &mut stream ).await;
+
+  if request_line.starts_with( "GET /api/tags" )
+  {
+    respond( &mut stream, "200 OK", TAGS ).await;
+    return;
+  }
+
+  let current = concurrency.current.fetch_add( 1, Ordering::SeqCst ) + 1;
+  concurrency.peak.fetch_max( current, Ordering::SeqCst );
+  tokio::time::sleep( Duration::from_millis( 50 ) ).await;
+  concurrency.current.fetch_sub( 1, Ordering::SeqCst );
+
+  let request : serde_json::Value = serde_json::from_str( &body ).unwrap();
+  if request[ "model" ] == "broken:latest"
+  {
+    respond( &mut stream, "500 Internal Server Error", r#"{ "error" : "failed to load model" }"# ).await;
+  }
+  else
+  {
+    respond( &mut stream, "200 OK", r#"{ "response" : "p", "done" : true }"# ).await;
+  }
+}
+
+async fn stub_server() -> ( String, Arc< Concurrency > )
+{
+  let listener = TcpListener::bind( "127.0.0.1:0" ).await.unwrap();
+  let url = format!( "http://{}", listener.local_addr().unwrap() );
+  let concurrency = Arc::new( Concurrency::default() );
+  let shared = Arc::clone( &concurrency );
+
+  tokio::spawn( async move
+  {
+    loop
+    {
+      let ( stream, _ ) = listener.accept().await.unwrap();
+      tokio::spawn( handle( stream, Arc::clone( &shared ) ) );
+    }
+  } );
+
+  ( url, concurrency )
+}
+
+#[ tokio::test ]
+async fn aggregates_probe_results()
+{
+  let ( url, _ ) = stub_server().await;
+  let client = OllamaClient::new( url, Duration::from_secs( 5 ) );
+
+  let checks = client.check_all_models().await.unwrap();
+
+  let names : Vec< &str > = checks.iter().map( | check | check.model_name.as_str() ).collect();
+  assert_eq!( names, vec![ "llama3.2:1b", "broken:latest", "qwen2.5:0.5b", "phi3:mini", "gemma2:2b" ] );
+
+  let broken = &checks[ 1 ];
+  assert!( !broken.is_available );
+  assert!( broken.health_score.abs() < f64::EPSILON );
+  assert!( broken.issues.as_ref().unwrap()[ 0 ].contains( "500" ), "{:?}", broken.issues );
+  assert!( checks.iter().filter( | check | check.is_available ).all( | check | check.issues.is_none() && check.response_time >= Duration::from_millis( 50 ) ) );
+
+  let summary = ModelHealthSummary::from_checks( &checks );
+  assert_eq!( summary.total, 5 );
+  assert_eq!( summary.loadable, 4 );
+  assert_eq!( summary.failing, vec![ "broken:latest".to_string() ] );
+  assert!( !summary.all_loadable() );
+  assert!( summary.average_response_time.unwrap() >= Duration::from_millis( 50 ) );
+}
+
+#[ tokio::test ]
+async fn bounds_probe_concurrency()
+{
+  let ( url, concurrency ) = stub_server().await;
+  let client = OllamaClient::new( url, Duration::from_secs( 5 ) );
+
+  let checks = client.check_all_models_with_concurrency( 2 ).await.unwrap();
+
+  assert_eq!( checks.len(), 5 );
+  assert_eq!( concurrency.peak.load( Ordering::SeqCst ), 2 );
+}
+
+#[ tokio::test ]
+async fn list_failure_is_an_error()
+{
+  // Nothing listens on the port once the listener is dropped
+  let listener = TcpListener::bind( "127.0.0.1:0" ).await.unwrap();
+  let url = format!( "http://{}", listener.local_addr().unwrap() );
+  drop( listener );
+
+  let client = OllamaClient::new( url, Duration::from_secs( 2 ) );
+  assert!( client.check_all_models().await.is_err() );
+}
+
+#[ test ]
+fn empty_summary()
+{
+  let summary = ModelHealthSummary::from_checks( &[] );
+  assert_eq!( ( summary.total, summary.loadable ), ( 0, 0 ) );
+  assert!( summary.all_loadable() );
+  assert_eq!( summary.average_response_time, None );
+}
@@ -0,0 +1,92 @@
+//! Model recommendation tests
+//!
+//! Tests `recommend_model` against a fixture set : the size preference decides
+//! between eligible models, and context length, feature and size requirements
+//! exclude models before scoring.
+
+#![ cfg( feature = "model_details" ) ]
+
+use api_ollama::{ recommend_model, ComprehensiveModelInfo, ModelSizePreference, TaskRequirements };
+
+const GB : u64 = 1_000_000_000;
+
+fn model( name : &str, parameter_count : u64, size_bytes : u64, context_length : u32, features : &[ &str ] ) -> ComprehensiveModelInfo
+{
+  ComprehensiveModelInfo
+  {
+    name : name.to_string(),
+    size_bytes,
+    size_human_readable : format!( "{:.1} GB", size_bytes as f64 / GB as f64 ),
+    family : "llama".to_string(),
+    families : vec![ "llama".to_string() ],
+    parameter_size : format!( "{:.1}B", parameter_count as f64 / 1e9 ),
+    parameter_count,
+    architecture : "llama".to_string(),
+    quantization_level : "Q4_K_M".to_string(),
+    format : "gguf".to_string(),
+    supported_features : features.iter().map( | f | ( *f ).to_string() ).collect(),
+    context_length,
+    max_sequence_length : context_length,
+  }
+}
+
+fn fixtures() -> Vec< ComprehensiveModelInfo >
+{
+  vec!
+  [
+    model( "llama3.1:8b", 8_000_000_000, 5 * GB, 131_072, &[ "completion", "tools" ] ),
+    model( "llama3.2:1b", 1_200_000_000, GB, 131_072, &[ "completion", "tools" ] ),
+    model( "llama3.3:70b", 70_000_000_000, 43 * GB, 131_072, &[ "completion", "tools" ] ),
+    model( "llava:7b", 7_000_000_000, 5 * GB, 4_096, &[ "completion", "vision" ] ),
+    model( "phi3:3.8b", 3_800_000_000, 2 * GB, 4_096, &[ "completion" ] ),
+  ]
+}
+
+fn tool_task( preference : ModelSizePreference ) -> TaskRequirements
+{
+  TaskRequirements::new()
+    .with_min_context_length( 32_000 )
+    .with_feature( "tools" )
+    .with_size_preference( preference )
+}
+
+#[ test ]
+fn small_fast_and_large_accurate_pick_differently()
+{
+  let models = fixtures();
+
+  let fast = recommend_model( &models, &tool_task( ModelSizePreference::Speed ) ).unwrap();
+  let accurate = recommend_model( &models, &tool_task( ModelSizePreference::Quality ) ).unwrap();
+  let balanced = recommend_model( &models, &tool_task( ModelSizePreference::Balanced ) ).unwrap();
+
+  assert_eq!( fast.model_name, "llama3.2:1b" );
+  assert_eq!( accurate.model_name, "llama3.3:70b" );
+  assert_eq!( balanced.model_name, "llama3.1:8b" );
+  assert!( ( fast.similarity_score - 1.0 ).abs() < f64::EPSILON );
+  assert!( ( accurate.similarity_score - 1.0 ).abs() < f64::EPSILON );
+  assert!( fast.reason.contains( "speed" ), "{}", fast.reason );
+  assert!( accurate.reason.contains( "quality" ), "{}", accurate.reason );
+  assert!( fast.reason.contains( "supports tools" ), "{}", fast.reason );
+  assert!( fast.reason.contains( "2 model(s) excluded" ), "{}", fast.reason );
+}
+
+#[ test ]
+fn requirements_exclude_models()
+{
+  let models = fixtures();
+
+  let vision = recommend_model( &models, &TaskRequirements::new().with_feature( "Vision" ).with_size_preference( ModelSizePreference::Quality ) ).unwrap();
+  assert_eq!( vision.model_name, "llava:7b" );
+
+  let capped = recommend_model( &models, &tool_task( ModelSizePreference::Quality ).with_max_size_bytes( 10 * GB ) ).unwrap();
+  assert_eq!( capped.model_name, "llama3.1:8b" );
+}
+
+#[ test ]
+fn no_recommendation_when_nothing_fits()
+{
+  let models = fixtures();
+
+  assert!( recommend_model( &models, &TaskRequirements::new().with_feature( "vision" ).with_min_context_length( 32_000 ) ).is_none() );
+  assert!( recommend_model( &[], &TaskRequirements::new() ).is_none() );
+}
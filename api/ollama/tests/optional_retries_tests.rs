@@ -0,0 +1,88 @@
+//! Optional retry entry point tests
+//!
+//! Tests `OllamaClient::execute_with_optional_retries` with an operation that
+//! fails twice with `503` before succeeding. Without the `retry` feature, or
+//! without a retry configuration, the operation runs exactly once.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_ollama::{ OllamaClient, OllamaResult };
+use core::future::Future;
+use core::pin::Pin;
+use core::time::Duration;
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicUsize, Ordering };
+
+type Operation = Box< dyn Fn() -> Pin< Box< dyn Future< Output = OllamaResult< usize > > + Send > > + Send + Sync >;
+
+/// Fails with `503` on the first two calls, then returns the attempt number
+fn flaky( attempts : &Arc< AtomicUsize > ) -> Operation
+{
+  let attempts = Arc::clone( attempts );
+  Box::new( move ||
+  {
+    let attempt = attempts.fetch_add( 1, Ordering::SeqCst ) + 1;
+    Box::pin( async move
+    {
+      if attempt <= 2
+      {
+        Err( error_tools::untyped::format_err!( "API error 503 : Service Unavailable" ) )
+      }
+      else
+      {
+        Ok( attempt )
+      }
+    } )
+  } )
+}
+
+fn client() -> OllamaClient
+{
+  OllamaClient::new( "http://127.0.0.1:9".to_string(), Duration::from_secs( 1 ) )
+}
+
+#[ tokio::test ]
+async fn single_attempt_without_retry_config()
+{
+  let attempts = Arc::new( AtomicUsize::new( 0 ) );
+
+  let result = client().execute_with_optional_retries( flaky( &attempts ) ).await;
+
+  assert!( result.is_err() );
+  assert_eq!( attempts.load( Ordering::SeqCst ), 1 );
+}
+
+#[ cfg( feature = "retry" ) ]
+fn retry_config( max_attempts : u32 ) -> api_ollama::RetryConfig
+{
+  api_ollama::RetryConfig::new()
+    .with_max_attempts( max_attempts )
+    .with_base_delay_ms( 1 )
+    .with_jitter_ms( 0 )
+    .with_logging( false )
+}
+
+#[ cfg( feature = "retry" ) ]
+#[ tokio::test ]
+async fn retries_when_configured()
+{
+  let attempts = Arc::new( AtomicUsize::new( 0 ) );
+  let client = client().with_retry_config( retry_config( 3 ) );
+
+  let result = client.execute_with_optional_retries( flaky( &attempts ) ).await;
+
+  assert_eq!( result.unwrap(), 3 );
+  assert_eq!( attempts.load( Ordering::SeqCst ), 3 );
+  assert_eq!( client.retry_config().map( | config | config.max_attempts ), Some( 3 ) );
+}
+
+#[ cfg( feature = "retry" ) ]
+#[ tokio::test ]
+async fn gives_up_after_max_attempts()
+{
+  let attempts = Arc::new( AtomicUsize::new( 0 ) );
+  let client = client().with_retry_config( retry_config( 2 ) );
+
+  assert!( client.execute_with_optional_retries( flaky( &attempts ) ).await.is_err() );
+  assert_eq!( attempts.load( Ordering::SeqCst ), 2 );
+}
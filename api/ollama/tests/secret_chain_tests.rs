@@ -0,0 +1,81 @@
+//! Secret source chain tests
+//!
+//! Tests `SecretChain` resolving keys from overlapping environment, workspace
+//! file and explicit sources in precedence order, and the `SecretNotFound`
+//! diagnostic listing the sources tried on a miss.
+
+#![ cfg( feature = "secret_management" ) ]
+
+use api_ollama::{ SecretChain, SecretConfig, SecretNotFound };
+use std::env;
+
+#[ test ]
+fn first_source_wins()
+{
+  env::set_var( "OLLAMA_CHAIN_TEST_SHARED", "from-env" );
+
+  let explicit = SecretConfig::new()
+    .with_secret( "OLLAMA_CHAIN_TEST_SHARED", "from-explicit" )
+    .with_secret( "OLLAMA_CHAIN_TEST_EXPLICIT_ONLY", "explicit-only" );
+
+  let env_first = SecretChain::new().with_env().with_explicit( explicit.clone() );
+  let explicit_first = SecretChain::new().with_explicit( explicit ).with_env();
+
+  assert_eq!( env_first.get( "OLLAMA_CHAIN_TEST_SHARED" ).unwrap(), "from-env" );
+  assert_eq!( explicit_first.get( "OLLAMA_CHAIN_TEST_SHARED" ).unwrap(), "from-explicit" );
+
+  // Lower-precedence sources still serve keys the earlier ones lack
+  assert_eq!( env_first.get( "OLLAMA_CHAIN_TEST_EXPLICIT_ONLY" ).unwrap(), "explicit-only" );
+
+  env::remove_var( "OLLAMA_CHAIN_TEST_SHARED" );
+}
+
+#[ cfg( feature = "workspace" ) ]
+#[ test ]
+fn env_then_workspace_then_explicit()
+{
+  let workspace = tempfile::tempdir().unwrap();
+  let secret_dir = workspace.path().join( "secret" );
+  std::fs::create_dir_all( &secret_dir ).unwrap();
+  std::fs::write
+  (
+    secret_dir.join( "-secrets.sh" ),
+    "OLLAMA_CHAIN_TEST_URL=\"http://workspace:11434\"\nOLLAMA_CHAIN_TEST_MODEL=\"workspace-model\"\n",
+  ).unwrap();
+  env::set_var( "OLLAMA_CHAIN_TEST_URL", "http://env:11434" );
+
+  let chain = SecretChain::new()
+    .with_env()
+    .with_workspace( workspace.path() ).unwrap()
+    .with_explicit
+    (
+      SecretConfig::new()
+        .with_secret( "OLLAMA_CHAIN_TEST_URL", "http://explicit:11434" )
+        .with_secret( "OLLAMA_CHAIN_TEST_MODEL", "explicit-model" )
+        .with_secret( "OLLAMA_CHAIN_TEST_TIMEOUT", "30" )
+    );
+
+  assert_eq!( chain.get( "OLLAMA_CHAIN_TEST_URL" ).unwrap(), "http://env:11434" );
+  assert_eq!( chain.get( "OLLAMA_CHAIN_TEST_MODEL" ).unwrap(), "workspace-model" );
+  assert_eq!( chain.get( "OLLAMA_CHAIN_TEST_TIMEOUT" ).unwrap(), "30" );
+
+  env::remove_var( "OLLAMA_CHAIN_TEST_URL" );
+}
+
+#[ test ]
+fn miss_reports_sources_tried()
+{
+  let chain = SecretChain::new()
+    .with_env()
+    .with_explicit( SecretConfig::new().with_secret( "OTHER", "value" ) );
+
+  let error = chain.get( "OLLAMA_CHAIN_TEST_MISSING" ).unwrap_err();
+  let not_found = error.downcast_ref::< SecretNotFound >().unwrap();
+
+  assert_eq!( not_found.key, "OLLAMA_CHAIN_TEST_MISSING" );
+  assert_eq!( not_found.sources_tried, vec![ "environment".to_string(), "explicit values".to_string() ] );
+  assert!( error.to_string().contains( "sources tried : environment, explicit values" ), "{error}" );
+
+  let empty = SecretChain::new().get( "OLLAMA_CHAIN_TEST_MISSING" ).unwrap_err();
+  assert!( empty.downcast_ref::< SecretNotFound >().unwrap().sources_tried.is_empty() );
+}
@@ -0,0 +1,98 @@
+//! Local model storage report tests
+//!
+//! Tests `StorageReport::from_models` against a fixture model listing : totals,
+//! largest-model ranking, and stale-model flagging from `modified_at`.
+
+#![ cfg( all( feature = "enabled", feature = "model_details" ) ) ]
+
+use api_ollama::{ ModelEntry, StorageReport, StoredModel, StaleModel };
+use core::time::Duration;
+use std::time::SystemTime;
+
+const GB : u64 = 1_000_000_000;
+const DAY : u64 = 86_400;
+
+/// 2025-01-31T00:00:00Z
+fn now() -> SystemTime
+{
+  SystemTime::UNIX_EPOCH + Duration::from_secs( 1_738_281_600 )
+}
+
+fn entry( name : &str, size : u64, modified_at : &str ) -> ModelEntry
+{
+  ModelEntry
+  {
+    name : name.to_string(),
+    model : name.to_string(),
+    modified_at : modified_at.to_string(),
+    size,
+    digest : String::new(),
+    details : None,
+  }
+}
+
+fn fixtures() -> Vec< ModelEntry >
+{
+  vec!
+  [
+    entry( "llama3.1:8b", 5 * GB, "2025-01-30T00:00:00+02:00" ),
+    entry( "llama3.3:70b", 40 * GB, "2024-10-01T12:00:00Z" ),
+    entry( "llama3.2:1b", GB, "2024-12-01T08:30:00.5-05:00" ),
+    entry( "nomic-embed-text", GB / 4, "not a timestamp" ),
+    entry( "qwen2.5:14b", 9 * GB, "2025-01-20T00:00:00Z" ),
+    entry( "mistral:7b", 4 * GB, "2025-01-15T00:00:00Z" ),
+    entry( "phi3:mini", 2 * GB, "2025-01-10T00:00:00Z" ),
+  ]
+}
+
+fn stored( name : &str, size_bytes : u64 ) -> StoredModel
+{
+  StoredModel { name : name.to_string(), size_bytes }
+}
+
+#[ test ]
+fn totals_and_largest_models()
+{
+  let report = StorageReport::from_models( &fixtures(), Duration::from_secs( 30 * DAY ), now() );
+
+  assert_eq!( report.total_models, 7 );
+  assert_eq!( report.total_size_bytes, 61 * GB + GB / 4 );
+  assert_eq!( report.largest, vec!
+  [
+    stored( "llama3.3:70b", 40 * GB ),
+    stored( "qwen2.5:14b", 9 * GB ),
+    stored( "llama3.1:8b", 5 * GB ),
+    stored( "mistral:7b", 4 * GB ),
+    stored( "phi3:mini", 2 * GB ),
+  ] );
+}
+
+#[ test ]
+fn flags_models_not_modified_within_threshold()
+{
+  let report = StorageReport::from_models( &fixtures(), Duration::from_secs( 30 * DAY ), now() );
+
+  // 121.5 days and 60.4 days less half a second; the unparsable timestamp is skipped
+  assert_eq!( report.stale, vec!
+  [
+    StaleModel { name : "llama3.3:70b".to_string(), size_bytes : 40 * GB, since_modified : Duration::from_secs( 10_497_600 ) },
+    StaleModel { name : "llama3.2:1b".to_string(), size_bytes : GB, since_modified : Duration::from_millis( 5_221_799_500 ) },
+  ] );
+  assert_eq!( report.reclaimable_bytes(), 41 * GB );
+
+  // The UTC offset is applied : 2025-01-30T00:00:00+02:00 is 26 hours before now
+  let recent = StorageReport::from_models( &fixtures(), Duration::from_secs( DAY ), now() );
+  assert!( recent.stale.iter().any( | model | model.name == "llama3.1:8b" && model.since_modified == Duration::from_secs( 26 * 3600 ) ) );
+}
+
+#[ test ]
+fn empty_listing()
+{
+  let report = StorageReport::from_models( &[], Duration::from_secs( DAY ), now() );
+
+  assert_eq!( report.total_models, 0 );
+  assert_eq!( report.total_size_bytes, 0 );
+  assert!( report.largest.is_empty() );
+  assert!( report.stale.is_empty() );
+  assert_eq!( report.reclaimable_bytes(), 0 );
+}
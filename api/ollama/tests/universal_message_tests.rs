@@ -0,0 +1,51 @@
+//! Universal message conversion tests for `api_ollama`.
+//!
+//! Verifies `UniversalMessage` conversions to and from `Message` and the
+//! vision-enabled `ChatMessage`, including role mapping.
+
+#![ cfg( feature = "enabled" ) ]
+
+use api_ollama::{ Message, UniversalMessage, UniversalRole };
+
+fn conversation() -> Vec< UniversalMessage >
+{
+  vec!
+  [
+    UniversalMessage::user( "What is the capital of France?" ),
+    UniversalMessage::assistant( "Paris." ),
+  ]
+}
+
+#[ test ]
+fn message_round_trip()
+{
+  let messages : Vec< Message > = conversation().into_iter().map( Into::into ).collect();
+  assert_eq!( messages[ 0 ].role, "user" );
+  assert_eq!( messages[ 1 ].role, "assistant" );
+  assert_eq!( messages[ 1 ].content, "Paris." );
+
+  let back : Vec< UniversalMessage > = messages.into_iter().map( Into::into ).collect();
+  assert_eq!( back, conversation() );
+}
+
+#[ cfg( feature = "vision_support" ) ]
+#[ test ]
+fn chat_message_round_trip()
+{
+  use api_ollama::{ ChatMessage, MessageRole };
+
+  let messages : Vec< ChatMessage > = conversation().into_iter().map( Into::into ).collect();
+  assert_eq!( messages[ 0 ].role, MessageRole::User );
+  assert_eq!( messages[ 1 ].role, MessageRole::Assistant );
+  assert!( messages[ 1 ].images.is_none() );
+
+  let back : Vec< UniversalMessage > = messages.into_iter().map( Into::into ).collect();
+  assert_eq!( back, conversation() );
+}
+
+#[ test ]
+fn model_role_maps_to_assistant()
+{
+  let message = Message { role : "model".to_string(), content : "Hi".to_string() };
+  assert_eq!( UniversalMessage::from( message ).role, UniversalRole::Assistant );
+}
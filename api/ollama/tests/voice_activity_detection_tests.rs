@@ -0,0 +1,78 @@
+//! Voice activity detection tests
+//!
+//! Tests `VadGate` classification of synthetic 16-bit PCM frames : silence,
+//! a speech-band tone, a quiet tone below the energy threshold, and
+//! high-frequency noise above the zero-crossing limit.
+
+#![ cfg( feature = "audio_processing" ) ]
+
+use api_ollama::{ VadConfig, VadGate };
+
+const SAMPLE_RATE : f64 = 16_000.0;
+const FRAME : usize = 320; // 20 ms
+
+fn tone( frequency : f64, amplitude : f64 ) -> Vec< i16 >
+{
+  ( 0..FRAME )
+    .map( | i | ( amplitude * f64::from( i16::MAX ) * ( 2.0 * core::f64::consts::PI * frequency * i as f64 / SAMPLE_RATE ).sin() ) as i16 )
+    .collect()
+}
+
+#[ test ]
+fn silence_is_not_speech()
+{
+  let gate = VadGate::default();
+
+  let decision = gate.analyze( &[ 0; FRAME ] );
+  assert!( !decision.is_speech );
+  assert!( decision.energy.abs() < f64::EPSILON );
+
+  assert!( !gate.is_speech( &[] ) );
+}
+
+#[ test ]
+fn speech_band_tone_is_speech()
+{
+  let gate = VadGate::default();
+
+  let decision = gate.analyze( &tone( 440.0, 0.5 ) );
+  assert!( decision.is_speech, "{decision:?}" );
+  // 0.5 amplitude sine has RMS 0.5 / sqrt(2); 440 Hz crosses zero 880 times a second
+  assert!( ( decision.energy - 0.3536 ).abs() < 0.01, "{decision:?}" );
+  assert!( ( decision.zero_crossing_rate - 0.055 ).abs() < 0.01, "{decision:?}" );
+}
+
+#[ test ]
+fn quiet_tone_is_below_energy_threshold()
+{
+  let gate = VadGate::default();
+  assert!( !gate.is_speech( &tone( 440.0, 0.005 ) ) );
+
+  let sensitive = VadGate::new( VadConfig::new().with_energy_threshold( 0.001 ) );
+  assert!( sensitive.is_speech( &tone( 440.0, 0.005 ) ) );
+}
+
+#[ test ]
+fn high_frequency_noise_is_not_speech()
+{
+  let gate = VadGate::default();
+
+  // Loud, but changes sign on every sample
+  let hiss : Vec< i16 > = ( 0..FRAME ).map( | i | if i % 2 == 0 { 12_000 } else { -12_000 } ).collect();
+  let decision = gate.analyze( &hiss );
+  assert!( !decision.is_speech, "{decision:?}" );
+  assert!( ( decision.zero_crossing_rate - 1.0 ).abs() < f64::EPSILON );
+
+  let permissive = VadGate::new( VadConfig::new().with_max_zero_crossing_rate( 1.0 ) );
+  assert!( permissive.is_speech( &hiss ) );
+}
+
+#[ test ]
+fn little_endian_bytes()
+{
+  let gate = VadGate::default();
+
+  let bytes : Vec< u8 > = tone( 300.0, 0.4 ).iter().flat_map( | sample | sample.to_le_bytes() ).collect();
+  assert!( gate.is_speech_le_bytes( &bytes ) );
+  assert!( !gate.is_speech_le_bytes( &[ 0; 641 ] ) );
+}
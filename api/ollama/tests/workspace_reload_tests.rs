@@ -0,0 +1,73 @@
+//! Workspace secret reload tests
+//!
+//! Tests `WorkspaceSecretStore::reload` picking up a rotated secret file in a
+//! temporary workspace, and `get_versioned` reporting a changed version.
+
+#![ cfg( all( feature = "workspace", feature = "secret_management" ) ) ]
+
+use api_ollama::WorkspaceSecretStore;
+use std::fs;
+use std::path::Path;
+
+fn write_secrets( workspace : &Path, content : &str )
+{
+  let secret_dir = workspace.join( "secret" );
+  fs::create_dir_all( &secret_dir ).unwrap();
+  fs::write( secret_dir.join( "-secrets.sh" ), content ).unwrap();
+}
+
+#[ test ]
+fn reload_serves_rotated_secret()
+{
+  let workspace = tempfile::tempdir().unwrap();
+  write_secrets( workspace.path(), "OLLAMA_RELOAD_TEST_KEY=\"sk-original-0000000000\"\n" );
+
+  let store = WorkspaceSecretStore::from_workspace_at( workspace.path() ).unwrap();
+  let shared = store.clone();
+  let before = store.get_versioned( "OLLAMA_RELOAD_TEST_KEY" ).unwrap();
+  assert_eq!( before.value, "sk-original-0000000000" );
+
+  write_secrets( workspace.path(), "OLLAMA_RELOAD_TEST_KEY=\"sk-rotated-1111111111\"\n" );
+
+  // Served from memory until reloaded
+  assert_eq!( store.get_secret( "OLLAMA_RELOAD_TEST_KEY" ).unwrap(), "sk-original-0000000000" );
+
+  store.reload().unwrap();
+
+  let after = store.get_versioned( "OLLAMA_RELOAD_TEST_KEY" ).unwrap();
+  assert_eq!( after.value, "sk-rotated-1111111111" );
+  assert_ne!( after.version, before.version );
+  assert_eq!( shared.get_secret( "OLLAMA_RELOAD_TEST_KEY" ).unwrap(), "sk-rotated-1111111111" );
+}
+
+#[ test ]
+fn version_is_stable_for_unchanged_value()
+{
+  let workspace = tempfile::tempdir().unwrap();
+  write_secrets( workspace.path(), "OLLAMA_RELOAD_STABLE_KEY=\"sk-stable-2222222222\"\n" );
+
+  let store = WorkspaceSecretStore::from_workspace_at( workspace.path() ).unwrap();
+  let first = store.get_versioned( "OLLAMA_RELOAD_STABLE_KEY" ).unwrap();
+  store.reload().unwrap();
+  let second = store.get_versioned( "OLLAMA_RELOAD_STABLE_KEY" ).unwrap();
+
+  assert_eq!( first, second );
+  assert!( !format!( "{second:?}" ).contains( "sk-stable" ) );
+}
+
+#[ test ]
+fn reload_drops_removed_secret()
+{
+  let workspace = tempfile::tempdir().unwrap();
+  write_secrets( workspace.path(), "OLLAMA_RELOAD_REMOVED_KEY=\"sk-removed-3333333333\"\n" );
+
+  let store = WorkspaceSecretStore::from_workspace_at( workspace.path() ).unwrap();
+  assert!( store.has_secret( "OLLAMA_RELOAD_REMOVED_KEY" ) );
+
+  write_secrets( workspace.path(), "OLLAMA_RELOAD_OTHER_KEY=\"sk-other-4444444444\"\n" );
+  store.reload().unwrap();
+
+  assert!( !store.has_secret( "OLLAMA_RELOAD_REMOVED_KEY" ) );
+  assert!( store.get_versioned( "OLLAMA_RELOAD_REMOVED_KEY" ).is_err() );
+  assert!( store.has_secret( "OLLAMA_RELOAD_OTHER_KEY" ) );
+}
@@ -20,10 +20,11 @@ mod private
 {
   use crate::
   {
-    environment ::{ OpenaiEnvironmentImpl },
+    environment ::{ OpenaiEnvironmentImpl, OpenaiEnvironment, EnvironmentInterface },
     secret ::Secret,
     error ::OpenAIError,
     client ::Client,
+    diagnostics ::DiagnosticsConfig,
   };
   use core::time::Duration;
   use std::
@@ -35,6 +36,8 @@ mod private
   // use tokio::time::sleep; // Commented out as unused
   use serde::{ Deserialize, Serialize };
   use error_tools::untyped::Result;
+  use reqwest::header;
+  use url::Url;
 
   /// OAuth token response from authentication server
   #[ derive( Debug, Clone, Serialize, Deserialize ) ]
@@ -598,6 +601,292 @@ mod private
     }
   }
 
+  /// `OAuth2` refresh-token grant configuration
+  #[ derive( Debug, Clone ) ]
+  pub struct OAuth2Config
+  {
+    /// Token endpoint of the authorization server
+    pub token_url : String,
+    /// OAuth client ID
+    pub client_id : String,
+    /// OAuth client secret, for confidential clients
+    pub client_secret : Option< String >,
+    /// Refresh token; replaced when the server rotates it
+    pub refresh_token : String,
+    /// Scope to request on refresh
+    pub scope : Option< String >,
+    /// Tokens expiring within this margin are refreshed before use
+    pub refresh_margin : Duration,
+  }
+
+  impl OAuth2Config
+  {
+    /// Configuration for a public client with a 60 second refresh margin
+    #[ must_use ]
+    pub fn new( token_url : impl Into< String >, client_id : impl Into< String >, refresh_token : impl Into< String > ) -> Self
+    {
+      Self
+      {
+        token_url : token_url.into(),
+        client_id : client_id.into(),
+        client_secret : None,
+        refresh_token : refresh_token.into(),
+        scope : None,
+        refresh_margin : Duration::from_secs( 60 ),
+      }
+    }
+  }
+
+  /// Access token held by `OAuth2Provider`
+  #[ derive( Debug, Clone ) ]
+  struct CachedToken
+  {
+    access_token : String,
+    expires_at : Instant,
+  }
+
+  /// Supplies `OAuth2` bearer tokens, refreshing them with the refresh-token grant
+  ///
+  /// Refresh happens only inside [`OAuth2Provider::token`], when the cached token
+  /// is missing or within `refresh_margin` of expiry; there is no background task.
+  /// Concurrent callers share a single refresh.
+  #[ derive( Debug ) ]
+  pub struct OAuth2Provider
+  {
+    config : tokio::sync::Mutex< OAuth2Config >,
+    refresh_margin : Duration,
+    http_client : reqwest::Client,
+    cached : RwLock< Option< CachedToken > >,
+  }
+
+  impl OAuth2Provider
+  {
+    /// Create a provider with no cached token; the first `token` call refreshes
+    #[ must_use ]
+    pub fn new( config : OAuth2Config ) -> Self
+    {
+      Self
+      {
+        refresh_margin : config.refresh_margin,
+        config : tokio::sync::Mutex::new( config ),
+        http_client : reqwest::Client::new(),
+        cached : RwLock::new( None ),
+      }
+    }
+
+    /// Seed the cache with an access token obtained elsewhere, valid for `expires_in`
+    #[ must_use ]
+    pub fn with_access_token( self, access_token : impl Into< String >, expires_in : Duration ) -> Self
+    {
+      self.store( access_token.into(), expires_in );
+      self
+    }
+
+    /// Return a valid access token, refreshing it first if it is missing or near expiry
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token endpoint cannot be reached, rejects the
+    /// refresh, or returns an unparseable response.
+    pub async fn token( &self ) -> Result< String >
+    {
+      if let Some( token ) = self.fresh_token()
+      {
+        return Ok( token );
+      }
+
+      let mut config = self.config.lock().await;
+      // Another caller may have refreshed while this one waited for the lock
+      if let Some( token ) = self.fresh_token()
+      {
+        return Ok( token );
+      }
+
+      let mut form = vec!
+      [
+        ( "grant_type", "refresh_token".to_string() ),
+        ( "refresh_token", config.refresh_token.clone() ),
+        ( "client_id", config.client_id.clone() ),
+      ];
+      if let Some( secret ) = &config.client_secret
+      {
+        form.push( ( "client_secret", secret.clone() ) );
+      }
+      if let Some( scope ) = &config.scope
+      {
+        form.push( ( "scope", scope.clone() ) );
+      }
+
+      let response = self.http_client.post( &config.token_url ).form( &form ).send().await
+        .map_err( OpenAIError::from )?;
+      let status = response.status();
+      let body = response.bytes().await.map_err( OpenAIError::from )?;
+      if !status.is_success()
+      {
+        return Err( OpenAIError::Http( format!( "OAuth2 token refresh failed with {status} : {}", String::from_utf8_lossy( &body ) ) ).into() );
+      }
+      let refreshed : OAuthTokenResponse = serde_json::from_slice( &body )
+        .map_err( | e | OpenAIError::Internal( format!( "Invalid OAuth2 token response : {e}" ) ) )?;
+
+      if let Some( rotated ) = refreshed.refresh_token
+      {
+        config.refresh_token = rotated;
+      }
+      self.store( refreshed.access_token.clone(), Duration::from_secs( refreshed.expires_in ) );
+      Ok( refreshed.access_token )
+    }
+
+    /// The cached access token, without refreshing
+    #[ must_use ]
+    pub fn cached_token( &self ) -> Option< String >
+    {
+      self.cached.read().ok()?.as_ref().map( | token | token.access_token.clone() )
+    }
+
+    fn fresh_token( &self ) -> Option< String >
+    {
+      let cached = self.cached.read().ok()?;
+      let token = cached.as_ref()?;
+      ( Instant::now() + self.refresh_margin < token.expires_at ).then( || token.access_token.clone() )
+    }
+
+    fn store( &self, access_token : String, expires_in : Duration )
+    {
+      if let Ok( mut cached ) = self.cached.write()
+      {
+        *cached = Some( CachedToken { access_token, expires_at : Instant::now() + expires_in } );
+      }
+    }
+  }
+
+  /// Environment that authenticates with the bearer token of an `OAuth2Provider`
+  ///
+  /// Every request asks the provider for a token, so an expired token is refreshed
+  /// transparently before the request is sent and a `Client` never needs rebuilding.
+  #[ derive( Debug, Clone ) ]
+  pub struct OAuth2Environment
+  {
+    inner : OpenaiEnvironmentImpl,
+    provider : Arc< OAuth2Provider >,
+  }
+
+  impl OAuth2Environment
+  {
+    /// Wrap `inner`, whose URLs, organization and project are kept
+    #[ must_use ]
+    pub fn new( inner : OpenaiEnvironmentImpl, provider : Arc< OAuth2Provider > ) -> Self
+    {
+      Self { inner, provider }
+    }
+
+    /// The token provider
+    #[ must_use ]
+    pub fn provider( &self ) -> &Arc< OAuth2Provider >
+    {
+      &self.provider
+    }
+
+    /// Build a client authenticated with a currently valid token
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token refresh or client creation fails.
+    pub async fn client( &self ) -> Result< Client< Self > >
+    {
+      self.provider.token().await?;
+      Client::build( self.clone() )
+    }
+
+    fn authorization( token : &str ) -> Result< header::HeaderValue >
+    {
+      header::HeaderValue::from_str( &format!( "Bearer {token}" ) )
+        .map_err( | error | error_tools::Error::from( OpenAIError::InvalidArgument( format!( "Invalid OAuth2 access token : {error}" ) ) ) )
+    }
+  }
+
+  #[ async_trait::async_trait ]
+  impl OpenaiEnvironment for OAuth2Environment
+  {
+    fn api_key( &self ) -> &Secret
+    {
+      OpenaiEnvironment::api_key( &self.inner )
+    }
+
+    fn organization_id( &self ) -> Option< &str >
+    {
+      OpenaiEnvironment::organization_id( &self.inner )
+    }
+
+    fn project_id( &self ) -> Option< &str >
+    {
+      OpenaiEnvironment::project_id( &self.inner )
+    }
+
+    fn base_url( &self ) -> &Url
+    {
+      self.inner.base_url()
+    }
+
+    fn realtime_base_url( &self ) -> &Url
+    {
+      self.inner.realtime_base_url()
+    }
+
+    fn diagnostics_config( &self ) -> Option< &DiagnosticsConfig >
+    {
+      self.inner.diagnostics_config()
+    }
+
+    /// The inner headers, authorized with the cached token if there is one
+    fn headers( &self ) -> Result< header::HeaderMap >
+    {
+      let mut headers = self.inner.headers()?;
+      headers.remove( header::AUTHORIZATION );
+      if let Some( token ) = self.provider.cached_token()
+      {
+        headers.insert( header::AUTHORIZATION, Self::authorization( &token )? );
+      }
+      Ok( headers )
+    }
+
+    /// Authorization with a token refreshed whenever the cached one is about to expire
+    async fn request_headers( &self ) -> Result< header::HeaderMap >
+    {
+      let token = self.provider.token().await?;
+      let mut headers = header::HeaderMap::new();
+      headers.insert( header::AUTHORIZATION, Self::authorization( &token )? );
+      Ok( headers )
+    }
+
+    fn join_base_url( &self, path : &str ) -> Result< Url >
+    {
+      self.inner.join_base_url( path )
+    }
+
+    fn join_realtime_base_url( &self, path : &str ) -> Result< Url >
+    {
+      self.inner.join_realtime_base_url( path )
+    }
+  }
+
+  impl EnvironmentInterface for OAuth2Environment
+  {
+    fn api_key( &self ) -> &Secret
+    {
+      EnvironmentInterface::api_key( &self.inner )
+    }
+
+    fn organization_id( &self ) -> Option< &str >
+    {
+      EnvironmentInterface::organization_id( &self.inner )
+    }
+
+    fn project_id( &self ) -> Option< &str >
+    {
+      EnvironmentInterface::project_id( &self.inner )
+    }
+  }
+
   /// Authentication performance metrics
   #[ derive( Debug, Clone ) ]
   pub struct AuthPerformanceMetrics
@@ -790,6 +1079,9 @@ crate ::mod_interface!
   orphan use AdvancedAuthConfig;
   orphan use AdvancedAuthManager;
   orphan use AuthPerformanceMetrics;
+  orphan use OAuth2Config;
+  orphan use OAuth2Provider;
+  orphan use OAuth2Environment;
   orphan use initialize_advanced_auth;
   orphan use get_advanced_auth_manager;
   orphan use create_oauth_client;
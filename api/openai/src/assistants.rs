@@ -19,10 +19,15 @@ mod private
     AssistantObject,
     ListAssistantsResponse,
     DeleteAssistantResponse,
+    RunObject,
+    RunStreamEvent,
+    SubmitToolOutputsRunRequest,
+    ToolOutput,
   };
   use crate::components::common::ListQuery;
 
   // External crates
+  use tokio::sync::mpsc;
 
 
 
@@ -193,6 +198,50 @@ mod private
         self.client.get( &path ).await
       }
     }
+
+    /// Retrieves a run on a thread.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread the run belongs to.
+    /// - `run_id`: The ID of the run to retrieve.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn retrieve_run( &self, thread_id : &str, run_id : &str ) -> Result< RunObject >
+    {
+      let path = format!( "threads/{thread_id}/runs/{run_id}" );
+      self.client.get( &path ).await
+    }
+
+    /// Submits tool outputs to a run in `requires_action` and streams the resumed run.
+    ///
+    /// The run is retrieved first and every output must reference one of the tool
+    /// calls in its `required_action`. The stream yields the run status events;
+    /// message and run step events are skipped.
+    ///
+    /// # Arguments
+    /// - `thread_id`: The ID of the thread the run belongs to.
+    /// - `run_id`: The ID of the run awaiting tool outputs.
+    /// - `outputs`: The outputs of the pending tool calls.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` if the run is not awaiting tool outputs or an
+    /// output references an unknown tool call, and `OpenAIError` if a request fails.
+    #[ inline ]
+    pub async fn submit_tool_outputs_streaming( &self, thread_id : &str, run_id : &str, outputs : Vec< ToolOutput > ) -> Result< mpsc::Receiver< Result< RunStreamEvent > > >
+    {
+      let run = self.retrieve_run( thread_id, run_id ).await?;
+      run.validate_tool_outputs( &outputs )?;
+
+      let request = SubmitToolOutputsRunRequest { tool_outputs : outputs, stream : Some( true ) };
+      let path = format!( "threads/{thread_id}/runs/{run_id}/submit_tool_outputs" );
+      self.client.post_event_stream( &path, &request, | event, data |
+      {
+        RunStreamEvent::from_sse( event, data )
+          .map( | parsed | parsed.map_err( | e | crate::error::OpenAIError::Stream( format!( "Failed to parse run event '{event}': {e}" ) ).into() ) )
+      }).await
+    }
   }
 } // end mod private
 
@@ -19,8 +19,10 @@ mod private
     CreateSpeechRequest,
     CreateTranscriptionRequest,
     CreateTranscriptionResponseJson,
+    CreateTranscriptionResponseVerboseJson,
     CreateTranslationRequest,
-    CreateTranslationResponseJson,
+    TranslationResponse,
+    TranscriptionResponseFormat,
   };
 
   // External crates
@@ -74,6 +76,34 @@ mod private
     /// Returns `OpenAIError` if the request fails.
     #[ inline ]
     pub async fn transcribe( &self, request : CreateTranscriptionRequest ) -> Result< CreateTranscriptionResponseJson >
+    {
+      let form = Self::transcription_form( request )?;
+      let path = "/audio/transcriptions";
+      self.client.post_multipart( path, form ).await
+    }
+
+    /// Transcribes audio with word and segment timestamps.
+    ///
+    /// Forces the `verbose_json` response format, which timestamp granularities
+    /// require. Words and segments are only present for the granularities listed
+    /// in `request.timestamp_granularities`.
+    ///
+    /// # Arguments
+    /// - `request`: The request body for transcribing audio.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn transcribe_verbose( &self, mut request : CreateTranscriptionRequest ) -> Result< CreateTranscriptionResponseVerboseJson >
+    {
+      request.response_format = Some( TranscriptionResponseFormat::VerboseJson );
+      let form = Self::transcription_form( request )?;
+      let path = "/audio/transcriptions";
+      self.client.post_multipart( path, form ).await
+    }
+
+    /// Builds the multipart form for a transcription request.
+    fn transcription_form( request : CreateTranscriptionRequest ) -> Result< Form >
     {
       // Create multipart form
       let file_part = Part::bytes( request.file )
@@ -97,8 +127,7 @@ mod private
 
       if let Some( response_format ) = request.response_format
       {
-        form = form.text( "response_format", serde_json::to_string( &response_format )
-          .map_err( | e | crate::error::OpenAIError::Internal( format!( "Failed to serialize response_format : {e}" ) ) )? );
+        form = form.text( "response_format", response_format.as_str() );
       }
 
       if let Some( temperature ) = request.temperature
@@ -106,27 +135,30 @@ mod private
         form = form.text( "temperature", temperature.to_string() );
       }
 
-      if let Some( timestamp_granularities ) = request.timestamp_granularities
+      // The API expects one `timestamp_granularities[]` field per value
+      for granularity in request.timestamp_granularities
       {
-        let granularities_json = serde_json::to_string( &timestamp_granularities )
-          .map_err( | e | crate::error::OpenAIError::Internal( format!( "Failed to serialize timestamp_granularities : {e}" ) ) )?;
-        form = form.text( "timestamp_granularities", granularities_json );
+        form = form.text( "timestamp_granularities[]", granularity.as_str() );
       }
 
-      let path = "/audio/transcriptions";
-      self.client.post_multipart( path, form ).await
+      Ok( form )
     }
 
     /// Translates audio into English.
     ///
+    /// For the `text`, `srt` and `vtt` response formats the body is returned
+    /// verbatim as `TranslationResponse::Text`; otherwise it is deserialized.
+    ///
     /// # Arguments
     /// - `request`: The request body for translating audio.
     ///
     /// # Errors
-    /// Returns `OpenAIError` if the request fails.
+    /// Returns `OpenAIError` if the request fails or a JSON body cannot be parsed.
     #[ inline ]
-    pub async fn translate( &self, request : CreateTranslationRequest ) -> Result< CreateTranslationResponseJson >
+    pub async fn translate( &self, request : CreateTranslationRequest ) -> Result< TranslationResponse >
     {
+      let response_format = request.response_format;
+
       // Create multipart form
       let file_part = Part::bytes( request.file )
         .file_name( request.filename )
@@ -144,8 +176,7 @@ mod private
 
       if let Some( response_format ) = request.response_format
       {
-        form = form.text( "response_format", serde_json::to_string( &response_format )
-          .map_err( | e | crate::error::OpenAIError::Internal( format!( "Failed to serialize response_format : {e}" ) ) )? );
+        form = form.text( "response_format", response_format.as_str() );
       }
 
       if let Some( temperature ) = request.temperature
@@ -154,7 +185,9 @@ mod private
       }
 
       let path = "/audio/translations";
-      self.client.post_multipart( path, form ).await
+      let body = self.client.post_multipart_text( path, form ).await?;
+      TranslationResponse::from_body( response_format, body )
+        .map_err( | e | crate::error::OpenAIError::Internal( format!( "Failed to parse translation response : {e}" ) ).into() )
     }
   }
 } // end mod private
@@ -13,6 +13,7 @@ mod private
     error ::Result,
     diagnostics ::DiagnosticsCollector,
     request_cache ::{ ApiRequestCache, CacheConfig },
+    platform_specific ::TlsBackend,
   };
 
   #[ cfg( feature = "retry" ) ]
@@ -40,9 +41,38 @@ mod private
     /// Returns `OpenAIError::InvalidArgument` if the API key is invalid.
     #[ inline ]
     pub fn build( environment : E ) -> Result< Self >
+    {
+      Self::build_with_tls( environment, TlsBackend::default() )
+    }
+
+    /// Creates a new `Client` instance whose connections use the given TLS backend.
+    ///
+    /// # Arguments
+    /// - `environment`: The `OpenAI` environment configuration.
+    /// - `tls`: The TLS implementation; its cargo feature must be enabled.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` if the API key is invalid or the
+    /// TLS backend is not available in this build.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use api_openai::{ Client, environment::{ OpenaiEnvironmentImpl, OpenAIRecommended }, Secret, platform_specific::TlsBackend };
+    ///
+    /// # fn example() -> Result<(), Box< dyn core::error::Error > > {
+    /// let secret = Secret::load_from_env("OPENAI_API_KEY")?;
+    /// let env = OpenaiEnvironmentImpl::build(secret, None, None, OpenAIRecommended::base_url().to_string(), OpenAIRecommended::realtime_base_url().to_string())?;
+    /// let backend = if TlsBackend::NativeTls.is_available() { TlsBackend::NativeTls } else { TlsBackend::default() };
+    /// let client = Client::build_with_tls(env, backend)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[ inline ]
+    pub fn build_with_tls( environment : E, tls : TlsBackend ) -> Result< Self >
     {
       let headers = environment.headers()?;
-      let http_client = HttpClient::builder()
+      let http_client = tls.apply( HttpClient::builder() )?
         .default_headers( headers )
         .timeout( core::time::Duration::from_secs( 300 ) ) // 5 minute default timeout
         .connect_timeout( core::time::Duration::from_secs( 30 ) ) // 30 second connect timeout
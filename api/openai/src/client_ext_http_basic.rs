@@ -51,10 +51,11 @@ mod private
       O : DeserializeOwned,
     {
       let url = self.environment.join_base_url( path )?;
+      let request_headers = self.environment.request_headers().await?;
       let http_client = &self.http_client;
 
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::GET, url.clone() ).query( query ).send()
+        http_client.request( Method::GET, url.clone() ).headers( request_headers.clone() ).query( query ).send()
       }).await?;
 
       let bytes = response.bytes().await?.to_vec(); // Convert to Vec< u8 >
@@ -70,10 +71,11 @@ mod private
       O : DeserializeOwned,
     {
       let url = self.environment.join_base_url( path )?;
+      let request_headers = self.environment.request_headers().await?;
       let http_client = &self.http_client;
 
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::GET, url.clone() ).send()
+        http_client.request( Method::GET, url.clone() ).headers( request_headers.clone() ).send()
       }).await?;
 
       let bytes = response.bytes().await?.to_vec(); // Convert to Vec< u8 >
@@ -83,6 +85,38 @@ mod private
     }
 
 
+    /// Sends a GET request, conditional on `if_none_match` when given.
+    ///
+    /// Returns `None` on `304 Not Modified`, otherwise the body and the response `ETag`.
+    #[ cfg( feature = "caching" ) ]
+    #[ inline ]
+    pub(in crate) async fn get_conditional( &self, path : &str, if_none_match : Option< &str > ) -> Result< Option< ( Vec< u8 >, Option< String > ) > >
+    {
+      let url = self.environment.join_base_url( path )?;
+      let request_headers = self.environment.request_headers().await?;
+      let http_client = &self.http_client;
+
+      let response = self.execute_request_with_retry( || {
+        let request = http_client.request( Method::GET, url.clone() ).headers( request_headers.clone() );
+        match if_none_match
+        {
+          Some( etag ) => request.header( reqwest::header::IF_NONE_MATCH, etag ).send(),
+          None => request.send(),
+        }
+      }).await?;
+
+      if response.status() == reqwest::StatusCode::NOT_MODIFIED
+      {
+        return Ok( None );
+      }
+
+      let etag = response.headers().get( reqwest::header::ETAG )
+        .and_then( | value | value.to_str().ok() )
+        .map( str::to_string );
+      let bytes = response.bytes().await?.to_vec();
+      Ok( Some( ( bytes, etag ) ) )
+    }
+
     /// Sends a POST request to the specified path with a JSON body.
     #[ inline ]
     pub(in crate) async fn post< I, O >( &self, path : &str, body : &I ) -> Result< O >
@@ -91,6 +125,7 @@ mod private
       O : DeserializeOwned,
     {
       let url = self.environment.join_base_url( path )?;
+      let request_headers = self.environment.request_headers().await?;
       let http_client = &self.http_client;
       let start_time = Instant::now();
 
@@ -119,7 +154,7 @@ mod private
       }
 
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::POST, url.clone() ).json( body ).send()
+        http_client.request( Method::POST, url.clone() ).headers( request_headers.clone() ).json( body ).send()
       }).await;
 
       // Handle response and record metrics
@@ -187,10 +222,11 @@ mod private
       O : DeserializeOwned,
     {
       let url = self.environment.join_base_url( path )?;
+      let request_headers = self.environment.request_headers().await?;
       let http_client = &self.http_client;
 
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::DELETE, url.clone() ).send()
+        http_client.request( Method::DELETE, url.clone() ).headers( request_headers.clone() ).send()
       }).await?;
 
       let bytes = response.bytes().await?.to_vec(); // Convert to Vec< u8 >
@@ -207,9 +243,10 @@ mod private
       O : DeserializeOwned,
     {
       let url = self.environment.join_base_url( path )?;
+      let request_headers = self.environment.request_headers().await?;
       let http_client = &self.http_client;
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::PATCH, url.clone() ).json( body ).send()
+        http_client.request( Method::PATCH, url.clone() ).headers( request_headers.clone() ).json( body ).send()
       }).await?;
 
       let bytes = response.bytes().await?.to_vec();
@@ -224,9 +261,10 @@ mod private
       O : DeserializeOwned,
     {
       let url = self.environment.join_base_url( path )?;
+      let request_headers = self.environment.request_headers().await?;
       let http_client = &self.http_client;
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::POST, url.clone() ).send()
+        http_client.request( Method::POST, url.clone() ).headers( request_headers.clone() ).send()
       }).await?;
 
       let bytes = response.bytes().await?.to_vec();
@@ -26,7 +26,6 @@ mod private
     E : OpenaiEnvironment + EnvironmentInterface + Send + Sync + 'static,
   {
     /// Sends a POST request to the specified path with a JSON body and streams the response.
-    #[ allow( clippy::unused_async ) ]
     #[ inline ]
     pub(in crate) async fn post_stream< I, O >( &self, path : &str, body : &I ) -> Result< mpsc::Receiver< Result< O > > >
     where
@@ -34,7 +33,7 @@ mod private
       O : DeserializeOwned + Send + 'static, // Add Send + 'static
     {
       let url = self.environment.join_base_url( path )?;
-      let request = self.http_client.request( Method::POST, url ).json( body );
+      let request = self.http_client.request( Method::POST, url ).headers( self.environment.request_headers().await? ).json( body );
       let ( tx, rx ) = mpsc::channel( 100 );
       let tx_arc = Arc::new( tx ); // Wrap tx in Arc
 
@@ -101,13 +100,104 @@ mod private
       Ok( rx )
     }
 
+    /// Sends a POST request with a JSON body and streams named server-sent events.
+    ///
+    /// `parse` receives each event's name and data; events for which it returns
+    /// `None` are skipped.
+    #[ inline ]
+    pub(in crate) async fn post_event_stream< I, O, F >( &self, path : &str, body : &I, parse : F ) -> Result< mpsc::Receiver< Result< O > > >
+    where
+      I : Serialize,
+      O : Send + 'static,
+      F : Fn( &str, &str ) -> Option< Result< O > > + Send + 'static,
+    {
+      let url = self.environment.join_base_url( path )?;
+      let request = self.http_client.request( Method::POST, url ).headers( self.environment.request_headers().await? ).json( body );
+      let ( tx, rx ) = mpsc::channel( 100 );
+
+      tokio ::spawn( async move
+      {
+        let response = match request.send().await
+        {
+          Ok( res ) => res,
+          Err( e ) =>
+          {
+            let _ = tx.send( Err( OpenAIError::Stream( e.to_string() ).into() ) ).await;
+            return;
+          }
+        };
+
+        if !response.status().is_success()
+        {
+          let status = response.status();
+          let error_text = response.text().await.unwrap_or_else( | _ | "Unknown error".to_string() );
+          let _ = tx.send( Err( OpenAIError::Api( crate::error::ApiError {
+            code : Some( status.as_u16().to_string() ),
+            message : error_text,
+            param : None,
+            r#type : Some( "http_error".to_string() ),
+          } ).into() ) ).await;
+          return;
+        }
+
+        let mut event_stream = response.bytes_stream().eventsource();
+
+        while let Some( event_result ) = event_stream.next().await
+        {
+          match event_result
+          {
+            Ok( event ) =>
+            {
+              if event.data == "[DONE]"
+              {
+                return;
+              }
+              if let Some( item ) = parse( &event.event, &event.data )
+              {
+                if tx.send( item ).await.is_err()
+                {
+                  return; // Receiver dropped
+                }
+              }
+            },
+            Err( e ) =>
+            {
+              let error_msg = format!( "SSE streaming error : {e}" );
+              let _ = tx.send( Err( OpenAIError::Stream( error_msg ).into() ) ).await;
+              break;
+            },
+          }
+        }
+      });
+
+      Ok( rx )
+    }
+
     /// Sends a POST request with multipart form data
     #[ inline ]
     pub(in crate) async fn post_multipart< O >( &self, path : &str, form : reqwest::multipart::Form ) -> Result< O >
     where
       O : DeserializeOwned,
+    {
+      let response = self.send_multipart( path, form ).await?;
+      let result : O = response.json().await.map_err( | e | OpenAIError::Internal( e.to_string() ) )?;
+      Ok( result )
+    }
+
+    /// Sends a POST request with multipart form data and returns the raw response body
+    #[ inline ]
+    pub(in crate) async fn post_multipart_text( &self, path : &str, form : reqwest::multipart::Form ) -> Result< String >
+    {
+      let response = self.send_multipart( path, form ).await?;
+      let result = response.text().await.map_err( | e | OpenAIError::Internal( e.to_string() ) )?;
+      Ok( result )
+    }
+
+    /// Sends a multipart POST request, returning the response if its status is a success
+    async fn send_multipart( &self, path : &str, form : reqwest::multipart::Form ) -> Result< reqwest::Response >
     {
       let url = self.environment.join_base_url( path )?;
+      let request_headers = self.environment.request_headers().await?;
       let http_client = &self.http_client;
       let start_time = Instant::now();
 
@@ -134,15 +224,14 @@ mod private
       }
 
       // For multipart requests, don't use retry logic due to form consumption
-      let response = http_client.request( Method::POST, url ).multipart( form ).send().await;
+      let response = http_client.request( Method::POST, url ).headers( request_headers ).multipart( form ).send().await;
 
       // Handle response
       let response = response.map_err( | e | OpenAIError::Network( e.to_string() ) )?;
 
       if response.status().is_success()
       {
-        let result : O = response.json().await.map_err( | e | OpenAIError::Internal( e.to_string() ) )?;
-        Ok( result )
+        Ok( response )
       }
       else
       {
@@ -164,6 +253,7 @@ mod private
       I: serde::Serialize + Sync,
     {
       let url = self.environment.join_base_url( path )?;
+      let request_headers = self.environment.request_headers().await?;
       let http_client = &self.http_client;
       let start_time = Instant::now();
 
@@ -192,7 +282,7 @@ mod private
 
       // Send request using execute_request_with_retry but extract bytes
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::POST, url.clone() ).json( body ).send()
+        http_client.request( Method::POST, url.clone() ).headers( request_headers.clone() ).json( body ).send()
       }).await;
 
       // Handle response
@@ -252,6 +342,7 @@ mod private
     pub(in crate) async fn get_bytes( &self, path : &str ) -> Result< Vec< u8 > >
     {
       let url = self.environment.join_base_url( path )?;
+      let request_headers = self.environment.request_headers().await?;
       let http_client = &self.http_client;
       let start_time = Instant::now();
 
@@ -279,7 +370,7 @@ mod private
 
       // Send request using execute_request_with_retry but extract bytes
       let response = self.execute_request_with_retry( || {
-        http_client.request( Method::GET, url.clone() ).send()
+        http_client.request( Method::GET, url.clone() ).headers( request_headers.clone() ).send()
       }).await;
 
       // Handle response
@@ -65,6 +65,31 @@ mod private
       }
     }
 
+    /// Execute a request, retrying only when retry support is compiled in and configured
+    ///
+    /// Single call site for custom requests regardless of enabled features : with the
+    /// `retry` feature and a retry configuration set via `with_retry_config`, failures
+    /// classified as retryable are replayed; otherwise this is exactly `execute_request`
+    /// and no retry machinery is involved. The builder is called once per attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last attempt, or an error if the retry configuration is invalid.
+    #[ inline ]
+    pub async fn execute_with_optional_retries< F, Fut >( &self, request_builder : F ) -> Result< reqwest::Response >
+    where
+      F : Fn() -> Fut + Send + Sync,
+      Fut : core::future::Future< Output = core::result::Result< reqwest::Response, reqwest::Error > > + Send,
+    {
+      #[ cfg( feature = "retry" ) ]
+      if let Some( retry_config ) = &self.retry_config
+      {
+        return self.execute_with_retry( &request_builder, retry_config ).await;
+      }
+
+      self.execute_request( request_builder ).await
+    }
+
     /// Enhanced HTTP request handler with optional retry and circuit breaker logic
     ///
     /// This method wraps `execute_request` with optional reliability features when configured.
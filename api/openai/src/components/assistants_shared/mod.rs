@@ -63,6 +63,8 @@ pub use run::
   RunLastError,
   RequiredAction,
   SubmitToolOutputs,
+  SubmitToolOutputsRunRequest,
+  ToolOutput,
   RunToolCallObject,
   RunToolCallFunction,
   RunObject,
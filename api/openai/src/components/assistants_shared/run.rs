@@ -14,6 +14,8 @@ mod private
   // Import message types from the message module
   use crate::components::assistants_shared::message::IncompleteDetails;
 
+  use crate::error::{ OpenAIError, Result };
+
   // Add serde imports
   use serde::{ Serialize, Deserialize };
   use serde_json::Value;
@@ -178,6 +180,80 @@ mod private
     pub response_format : Option< AssistantsApiResponseFormatOption >,
   }
 
+  impl RunObject
+  {
+    /// Returns the IDs of tool calls awaiting output; empty unless the run is in `requires_action`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn pending_tool_call_ids( &self ) -> Vec< &str >
+    {
+      self.required_action.iter()
+        .flat_map( | action | &action.submit_tool_outputs.tool_calls )
+        .map( | call | call.id.as_str() )
+        .collect()
+    }
+
+    /// Checks that every output answers a tool call pending on this run.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` if the run is not awaiting tool outputs
+    /// or an output references an unknown `tool_call_id`.
+    #[ inline ]
+    pub fn validate_tool_outputs( &self, outputs : &[ ToolOutput ] ) -> Result< () >
+    {
+      let pending = self.pending_tool_call_ids();
+      if pending.is_empty()
+      {
+        return Err( OpenAIError::InvalidArgument( format!( "Run {} is not awaiting tool outputs (status : {})", self.id, self.status ) ).into() );
+      }
+
+      if let Some( unknown ) = outputs.iter().find( | output | !pending.contains( &output.tool_call_id.as_str() ) )
+      {
+        return Err( OpenAIError::InvalidArgument( format!( "Tool call {} is not pending on run {}; pending : {}", unknown.tool_call_id, self.id, pending.join( ", " ) ) ).into() );
+      }
+
+      Ok( () )
+    }
+  }
+
+  /// The output of a tool call, submitted to resume a run in `requires_action`.
+  ///
+  /// # Used By
+  /// - `SubmitToolOutputsRunRequest`
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct ToolOutput
+  {
+    /// The ID of the tool call in `required_action` this output answers.
+    pub tool_call_id : String,
+    /// The output of the tool call.
+    pub output : String,
+  }
+
+  impl ToolOutput
+  {
+    /// Creates the output for a tool call.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( tool_call_id : impl Into< String >, output : impl Into< String > ) -> Self
+    {
+      Self { tool_call_id : tool_call_id.into(), output : output.into() }
+    }
+  }
+
+  /// Request body for submitting tool outputs to a run.
+  ///
+  /// # Used By
+  /// - `/threads/{thread_id}/runs/{run_id}/submit_tool_outputs` (POST request)
+  #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
+  pub struct SubmitToolOutputsRunRequest
+  {
+    /// The outputs of the tool calls.
+    pub tool_outputs : Vec< ToolOutput >,
+    /// Whether to stream the resumed run as server-sent events.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub stream : Option< bool >,
+  }
+
   /// Response containing a list of runs.
   ///
   /// # Used By
@@ -468,6 +544,8 @@ crate ::mod_interface!
   exposed use private::RunToolCallObject;
   exposed use private::RunToolCallFunction;
   exposed use private::RunObject;
+  exposed use private::ToolOutput;
+  exposed use private::SubmitToolOutputsRunRequest;
   exposed use private::ListRunsResponse;
   exposed use private::RunStepDetailsMessageCreationObject;
   exposed use private::MessageCreationDetails;
@@ -203,6 +203,57 @@ mod private
     Expired( RunExpiredEvent ),
   }
 
+  impl RunStreamEvent
+  {
+    /// Builds a run event from a server-sent event name and its JSON data.
+    ///
+    /// Returns `None` for events that are not `thread.run.*` status events, such as
+    /// message deltas, run steps and `done`.
+    ///
+    /// # Errors
+    /// The inner result is an error if the data is not a valid run object.
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_sse( event : &str, data : &str ) -> Option< serde_json::Result< Self > >
+    {
+      let wrap : fn( String, RunObject ) -> Self = match event
+      {
+        "thread.run.created" => | event, data | Self::Created( RunCreatedEvent { event, data } ),
+        "thread.run.queued" => | event, data | Self::Queued( RunQueuedEvent { event, data } ),
+        "thread.run.in_progress" => | event, data | Self::InProgress( RunInProgressEvent { event, data } ),
+        "thread.run.requires_action" => | event, data | Self::RequiresAction( RunRequiresActionEvent { event, data } ),
+        "thread.run.completed" => | event, data | Self::Completed( RunCompletedEvent { event, data } ),
+        "thread.run.incomplete" => | event, data | Self::Incomplete( RunIncompleteEvent { event, data } ),
+        "thread.run.failed" => | event, data | Self::Failed( RunFailedEvent { event, data } ),
+        "thread.run.cancelling" => | event, data | Self::Cancelling( RunCancellingEvent { event, data } ),
+        "thread.run.cancelled" => | event, data | Self::Cancelled( RunCancelledEvent { event, data } ),
+        "thread.run.expired" => | event, data | Self::Expired( RunExpiredEvent { event, data } ),
+        _ => return None,
+      };
+      Some( serde_json::from_str::< RunObject >( data ).map( | run | wrap( event.to_string(), run ) ) )
+    }
+
+    /// Returns the run object carried by the event.
+    #[ inline ]
+    #[ must_use ]
+    pub fn run( &self ) -> &RunObject
+    {
+      match self
+      {
+        Self::Created( e ) => &e.data,
+        Self::Queued( e ) => &e.data,
+        Self::InProgress( e ) => &e.data,
+        Self::RequiresAction( e ) => &e.data,
+        Self::Completed( e ) => &e.data,
+        Self::Incomplete( e ) => &e.data,
+        Self::Failed( e ) => &e.data,
+        Self::Cancelling( e ) => &e.data,
+        Self::Cancelled( e ) => &e.data,
+        Self::Expired( e ) => &e.data,
+      }
+    }
+  }
+
   /// Event data for when a run is created.
   #[ derive( Debug, Deserialize, Clone, PartialEq ) ]
   pub struct RunCreatedEvent
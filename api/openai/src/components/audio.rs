@@ -88,6 +88,25 @@ mod private
     pub segments : Option< Vec< TranscriptionSegment > >,
   }
 
+  impl CreateTranscriptionResponseVerboseJson
+  {
+    /// Word timestamps, empty when word granularity was not requested.
+    #[ inline ]
+    #[ must_use ]
+    pub fn words( &self ) -> &[ TranscriptionWord ]
+    {
+      self.words.as_deref().unwrap_or_default()
+    }
+
+    /// Segment timestamps, empty when the response carries no segments.
+    #[ inline ]
+    #[ must_use ]
+    pub fn segments( &self ) -> &[ TranscriptionSegment ]
+    {
+      self.segments.as_deref().unwrap_or_default()
+    }
+  }
+
   /// Represents a basic translation response containing the translated text.
   ///
   /// # Used By
@@ -243,8 +262,8 @@ mod private
     pub response_format : Option< TranscriptionResponseFormat >,
     /// The sampling temperature between 0 and 1.
     pub temperature : Option< f64 >,
-    /// Word-level timestamps.
-    pub timestamp_granularities : Option< Vec< TimestampGranularity > >,
+    /// Timestamp granularities to populate. Requires the `verbose_json` response format; empty requests none.
+    pub timestamp_granularities : Vec< TimestampGranularity >,
   }
 
   /// Request parameters for translating audio to English text.
@@ -262,8 +281,8 @@ mod private
     pub model : String,
     /// An optional text to guide the model's style.
     pub prompt : Option< String >,
-    /// The format of the transcript output.
-    pub response_format : Option< TranscriptionResponseFormat >,
+    /// The format of the translation output; `srt` and `vtt` return subtitle files.
+    pub response_format : Option< TranslationResponseFormat >,
     /// The sampling temperature between 0 and 1.
     pub temperature : Option< f64 >,
   }
@@ -285,6 +304,112 @@ mod private
     Vtt,
   }
 
+  impl TranscriptionResponseFormat
+  {
+    /// Returns the form field value sent to the API.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( &self ) -> &'static str
+    {
+      match self
+      {
+        Self::Json => "json",
+        Self::Text => "text",
+        Self::Srt => "srt",
+        Self::VerboseJson => "verbose_json",
+        Self::Vtt => "vtt",
+      }
+    }
+  }
+
+  /// Available response formats for translation.
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq ) ]
+  #[ serde( rename_all = "lowercase" ) ]
+  pub enum TranslationResponseFormat
+  {
+    /// JSON format
+    Json,
+    /// Plain text format
+    Text,
+    /// `SubRip` Subtitle format
+    Srt,
+    /// `WebVTT` format
+    Vtt,
+  }
+
+  impl TranslationResponseFormat
+  {
+    /// Returns the form field value sent to the API.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        Self::Json => "json",
+        Self::Text => "text",
+        Self::Srt => "srt",
+        Self::Vtt => "vtt",
+      }
+    }
+
+    /// Returns `true` if the API answers with a plain body instead of JSON.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_plain_text( self ) -> bool
+    {
+      !matches!( self, Self::Json )
+    }
+  }
+
+  /// Translation output, routed by the requested response format.
+  ///
+  /// # Used By
+  /// - `/audio/translations` (POST response)
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub enum TranslationResponse
+  {
+    /// Deserialized `json` response.
+    Json( CreateTranslationResponseJson ),
+    /// Verbatim body of a `text`, `srt` or `vtt` response.
+    Text( String ),
+  }
+
+  impl TranslationResponse
+  {
+    /// Interprets a response body according to the requested format.
+    ///
+    /// Plain formats are returned verbatim; `json`, and an unset format which the
+    /// API treats as `json`, are deserialized.
+    ///
+    /// # Errors
+    /// Returns an error if a JSON body cannot be deserialized.
+    #[ inline ]
+    pub fn from_body( format : Option< TranslationResponseFormat >, body : String ) -> serde_json::Result< Self >
+    {
+      if format.is_some_and( TranslationResponseFormat::is_plain_text )
+      {
+        Ok( Self::Text( body ) )
+      }
+      else
+      {
+        serde_json::from_str( &body ).map( Self::Json )
+      }
+    }
+
+    /// Returns the translated text, or the subtitle file for `srt` and `vtt`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn text( &self ) -> &str
+    {
+      match self
+      {
+        Self::Json( response ) => &response.text,
+        Self::Text( text ) => text,
+      }
+    }
+  }
+
   /// Timestamp granularity options.
   #[ derive( Debug, Serialize, Deserialize, Clone, PartialEq ) ]
   #[ serde( rename_all = "lowercase" ) ]
@@ -295,6 +420,21 @@ mod private
     /// Segment-level timestamps
     Segment,
   }
+
+  impl TimestampGranularity
+  {
+    /// Returns the form field value sent to the API.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( &self ) -> &'static str
+    {
+      match self
+      {
+        Self::Word => "word",
+        Self::Segment => "segment",
+      }
+    }
+  }
 } // end mod private
 
 crate ::mod_interface!
@@ -316,6 +456,8 @@ crate ::mod_interface!
     CreateTranscriptionRequest,
     CreateTranslationRequest,
     TranscriptionResponseFormat,
+    TranslationResponseFormat,
+    TranslationResponse,
     TimestampGranularity,
   };
 }
\ No newline at end of file
@@ -315,6 +315,99 @@ mod private
     pub total_tokens : i32,
   }
 
+  impl CreateChatCompletionResponse
+  {
+    /// Message text of every choice, in choice order.
+    ///
+    /// Choices without text content (e.g. tool calls only) yield an empty string.
+    #[ inline ]
+    #[ must_use ]
+    pub fn choices_text( &self ) -> Vec< String >
+    {
+      self.choices
+      .iter()
+      .map( | choice | choice.message.content.clone().unwrap_or_default() )
+      .collect()
+    }
+
+    /// Distinct choice texts in order of first appearance, for self-consistency sampling with `n > 1`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn unique_choices( &self ) -> Vec< String >
+    {
+      let mut unique : Vec< String > = Vec::new();
+      for text in self.choices_text()
+      {
+        if !unique.contains( &text )
+        {
+          unique.push( text );
+        }
+      }
+      unique
+    }
+
+    /// Finish reason of every choice, in choice order.
+    #[ inline ]
+    #[ must_use ]
+    pub fn finish_reasons( &self ) -> Vec< String >
+    {
+      self.choices.iter().map( | choice | choice.finish_reason.clone() ).collect()
+    }
+
+    /// Flattens the first choice's log probability content into per-token entries.
+    ///
+    /// Returns an empty vector when the request did not ask for `logprobs`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn token_logprobs( &self ) -> Vec< TokenLogprob >
+    {
+      self.choices
+      .first()
+      .and_then( | choice | choice.logprobs.as_ref() )
+      .map( | logprobs | logprobs.content.iter().map( | entry | TokenLogprob
+      {
+        token : entry.token.clone(),
+        logprob : f64::from( entry.logprob ),
+        top_alternatives : entry.top_logprobs.iter()
+        .map( | alt | ( alt.token.clone(), f64::from( alt.logprob ) ) )
+        .collect(),
+      } ).collect() )
+      .unwrap_or_default()
+    }
+
+    /// Perplexity of the first choice, `exp( -mean( logprob ) )` over its tokens.
+    ///
+    /// Returns `None` when no token log probabilities are present.
+    #[ inline ]
+    #[ must_use ]
+    pub fn perplexity( &self ) -> Option< f64 >
+    {
+      let tokens = self.token_logprobs();
+      if tokens.is_empty()
+      {
+        return None;
+      }
+      #[ allow( clippy::cast_precision_loss ) ]
+      let mean = tokens.iter().map( | token | token.logprob ).sum::< f64 >() / tokens.len() as f64;
+      Some( ( -mean ).exp() )
+    }
+  }
+
+  /// A single generated token with its log probability, flattened for confidence scoring.
+  ///
+  /// # Used By
+  /// - `CreateChatCompletionResponse::token_logprobs`
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct TokenLogprob
+  {
+    /// The token.
+    pub token : String,
+    /// The log probability of the token.
+    pub logprob : f64,
+    /// The most likely alternative tokens at this position with their log probabilities.
+    pub top_alternatives : Vec< ( String, f64 ) >,
+  }
+
   /// Represents log probability information for a chat completion choice.
   ///
   /// # Used By
@@ -413,6 +506,156 @@ mod private
     #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub tool_calls : Option< Vec< ChatCompletionMessageToolCall > >,
   }
+
+  /// Provider-neutral message role.
+  ///
+  /// The same type, with the same role mapping, exists in the Claude, Gemini and
+  /// Ollama crates so a conversation can be moved between providers.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+  pub enum UniversalRole
+  {
+    /// System instructions.
+    System,
+    /// User turn.
+    User,
+    /// Assistant turn.
+    Assistant,
+  }
+
+  impl UniversalRole
+  {
+    /// Canonical role name ( `system`, `user` or `assistant` ).
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        Self::System => "system",
+        Self::User => "user",
+        Self::Assistant => "assistant",
+      }
+    }
+
+    /// Map a provider role name to a neutral role.
+    ///
+    /// `assistant` and `model` map to `Assistant`, `system` and `developer` to
+    /// `System`; any other name is treated as `User`.
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_provider_role( role : &str ) -> Self
+    {
+      match role
+      {
+        "assistant" | "model" => Self::Assistant,
+        "system" | "developer" => Self::System,
+        _ => Self::User,
+      }
+    }
+  }
+
+  /// Provider-neutral text message.
+  ///
+  /// # Used By
+  /// - `ChatCompletionRequestMessage` ( via `From` )
+  /// - `ChatCompletionResponseMessage` ( via `From` )
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct UniversalMessage
+  {
+    /// The role of the message's author.
+    pub role : UniversalRole,
+    /// The text of the message.
+    pub content : String,
+  }
+
+  impl UniversalMessage
+  {
+    /// Creates a message with the given role.
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( role : UniversalRole, content : impl Into< String > ) -> Self
+    {
+      Self { role, content : content.into() }
+    }
+
+    /// Creates a system message.
+    #[ inline ]
+    #[ must_use ]
+    pub fn system( content : impl Into< String > ) -> Self
+    {
+      Self::new( UniversalRole::System, content )
+    }
+
+    /// Creates a user message.
+    #[ inline ]
+    #[ must_use ]
+    pub fn user( content : impl Into< String > ) -> Self
+    {
+      Self::new( UniversalRole::User, content )
+    }
+
+    /// Creates an assistant message.
+    #[ inline ]
+    #[ must_use ]
+    pub fn assistant( content : impl Into< String > ) -> Self
+    {
+      Self::new( UniversalRole::Assistant, content )
+    }
+  }
+
+  /// Converts to a plain text message.
+  impl From< UniversalMessage > for ChatCompletionRequestMessage
+  {
+    #[ inline ]
+    fn from( message : UniversalMessage ) -> Self
+    {
+      Self
+      {
+        role : message.role.as_str().to_string(),
+        content : Some( ChatCompletionRequestMessageContent::Text( message.content ) ),
+        name : None,
+        tool_calls : None,
+        tool_call_id : None,
+      }
+    }
+  }
+
+  /// Concatenates text parts; image parts and tool calls are dropped.
+  impl From< ChatCompletionRequestMessage > for UniversalMessage
+  {
+    #[ inline ]
+    fn from( message : ChatCompletionRequestMessage ) -> Self
+    {
+      let content = match message.content
+      {
+        Some( ChatCompletionRequestMessageContent::Text( text ) ) => text,
+        Some( ChatCompletionRequestMessageContent::Parts( parts ) ) => parts
+          .into_iter()
+          .filter_map( | part | match part
+          {
+            ChatCompletionRequestMessageContentPart::Text { text } => Some( text ),
+            ChatCompletionRequestMessageContentPart::ImageUrl { .. } => None,
+          } )
+          .collect(),
+        None => String::new(),
+      };
+      Self { role : UniversalRole::from_provider_role( &message.role ), content }
+    }
+  }
+
+  /// Converts a model reply so it can be appended to a conversation; tool calls are dropped.
+  impl From< ChatCompletionResponseMessage > for UniversalMessage
+  {
+    #[ inline ]
+    fn from( message : ChatCompletionResponseMessage ) -> Self
+    {
+      Self
+      {
+        role : UniversalRole::from_provider_role( &message.role ),
+        content : message.content.unwrap_or_default(),
+      }
+    }
+  }
 }
 
 crate ::mod_interface!
@@ -438,8 +681,11 @@ crate ::mod_interface!
     ChatCompletionLogprobs,
     ChatCompletionLogprobsContent,
     ChatCompletionLogprobsTopLogprob,
+    TokenLogprob,
     ChatCompletionStreamResponse,
     ChatCompletionStreamChoice,
     ChatCompletionStreamResponseMessage,
+    UniversalRole,
+    UniversalMessage,
   };
 }
\ No newline at end of file
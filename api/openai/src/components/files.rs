@@ -3,6 +3,7 @@
 /// Define a private namespace for all its items.
 mod private
 {
+  use crate::error::{ OpenAIError, Result };
   // Serde imports
   use serde::{ Serialize, Deserialize }; // Added Serialize
 
@@ -79,6 +80,106 @@ mod private
     /// or "fine-tune" for fine-tuning.
     pub purpose : String,
   }
+
+  /// The intended purpose of an uploaded file.
+  ///
+  /// # Used By
+  /// - `Files::upload_validated`
+  #[ derive( Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq ) ]
+  pub enum FilePurpose
+  {
+    /// Assistants and Message files.
+    #[ serde( rename = "assistants" ) ]
+    Assistants,
+    /// Batch API input (JSON Lines).
+    #[ serde( rename = "batch" ) ]
+    Batch,
+    /// Fine-tuning training data (JSON Lines).
+    #[ serde( rename = "fine-tune" ) ]
+    FineTune,
+    /// Images for vision fine-tuning.
+    #[ serde( rename = "vision" ) ]
+    Vision,
+    /// Flexible file type for any purpose.
+    #[ serde( rename = "user_data" ) ]
+    UserData,
+  }
+
+  impl FilePurpose
+  {
+    /// Returns the `purpose` form value sent to the API.
+    #[ inline ]
+    #[ must_use ]
+    pub fn as_str( self ) -> &'static str
+    {
+      match self
+      {
+        Self::Assistants => "assistants",
+        Self::Batch => "batch",
+        Self::FineTune => "fine-tune",
+        Self::Vision => "vision",
+        Self::UserData => "user_data",
+      }
+    }
+
+    /// Checks the filename extension and content against the purpose.
+    ///
+    /// `batch` and `fine-tune` require a `.jsonl` file whose first line is a JSON
+    /// object; `vision` requires a PNG, JPEG, GIF or WebP image. Other purposes
+    /// accept any file. Only obvious mismatches are caught; the server still
+    /// validates the full content.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` describing the mismatch.
+    #[ inline ]
+    pub fn validate_file( self, filename : &str, bytes : &[ u8 ] ) -> Result< () >
+    {
+      let extension = filename.rsplit_once( '.' ).map( | ( _, ext ) | ext.to_ascii_lowercase() ).unwrap_or_default();
+      let mismatch = | reason : &str | -> Result< () >
+      {
+        Err( OpenAIError::InvalidArgument( format!( "File '{filename}' does not match purpose '{}': {reason}", self.as_str() ) ).into() )
+      };
+
+      match self
+      {
+        Self::Batch | Self::FineTune =>
+        {
+          if extension != "jsonl"
+          {
+            return mismatch( "expected a .jsonl file" );
+          }
+          let first_line = bytes.split( | b | *b == b'\n' ).find( | line | !line.iter().all( u8::is_ascii_whitespace ) );
+          match first_line.map( serde_json::from_slice::< serde_json::Value > )
+          {
+            Some( Ok( serde_json::Value::Object( _ ) ) ) => Ok( () ),
+            Some( _ ) => mismatch( "the first line is not a JSON object" ),
+            None => mismatch( "the file is empty" ),
+          }
+        }
+        Self::Vision =>
+        {
+          if !matches!( extension.as_str(), "png" | "jpg" | "jpeg" | "gif" | "webp" )
+          {
+            return mismatch( "expected a .png, .jpg, .jpeg, .gif or .webp image" );
+          }
+          let is_image = bytes.starts_with( b"\x89PNG\r\n\x1a\n" )
+            || bytes.starts_with( &[ 0xFF, 0xD8, 0xFF ] )
+            || bytes.starts_with( b"GIF87a" )
+            || bytes.starts_with( b"GIF89a" )
+            || ( bytes.len() >= 12 && bytes.starts_with( b"RIFF" ) && &bytes[ 8..12 ] == b"WEBP" );
+          if is_image
+          {
+            Ok( () )
+          }
+          else
+          {
+            mismatch( "the content is not a PNG, JPEG, GIF or WebP image" )
+          }
+        }
+        Self::Assistants | Self::UserData => Ok( () ),
+      }
+    }
+  }
 } // end mod private
 
 crate ::mod_interface!
@@ -88,5 +189,6 @@ crate ::mod_interface!
     FileObject,
     ListFilesResponse,
     CreateFileRequest,
+    FilePurpose,
   };
 }
@@ -253,6 +253,53 @@ mod private
     TranscriptionSessionUpdate( RealtimeClientEventTranscriptionSessionUpdate ),
   }
 
+  impl RealtimeClientEvent
+  {
+    /// Creates an `input_audio_buffer.append` event, base64-encoding the audio bytes.
+    ///
+    /// # Arguments
+    /// - `audio`: Raw audio in the session's input format (e.g. 16-bit PCM).
+    #[ inline ]
+    #[ must_use ]
+    pub fn append_audio( audio : &[ u8 ] ) -> Self
+    {
+      use base64::Engine as _;
+      Self::InputAudioBufferAppend( RealtimeClientEventInputAudioBufferAppend
+      {
+        event_id : None,
+        audio : base64::engine::general_purpose::STANDARD.encode( audio ),
+      })
+    }
+
+    /// Creates an `input_audio_buffer.commit` event.
+    #[ inline ]
+    #[ must_use ]
+    pub fn commit_audio() -> Self
+    {
+      Self::InputAudioBufferCommit( RealtimeClientEventInputAudioBufferCommit { event_id : None } )
+    }
+
+    /// Creates a `response.create` event.
+    ///
+    /// # Arguments
+    /// - `response`: Optional overrides for this response; `None` uses the session defaults.
+    #[ inline ]
+    #[ must_use ]
+    pub fn request_response( response : Option< RealtimeResponseCreateParams > ) -> Self
+    {
+      Self::ResponseCreate( RealtimeClientEventResponseCreate { event_id : None, response } )
+    }
+
+    /// Returns `true` for events that configure the session rather than the conversation
+    /// (`session.update` and `transcription_session.update`).
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_session_setup( &self ) -> bool
+    {
+      matches!( self, Self::SessionUpdate( _ ) | Self::TranscriptionSessionUpdate( _ ) )
+    }
+  }
+
 
   /// Represents the different types of events sent by the server in a Realtime session.
   ///
@@ -358,6 +405,9 @@ mod private
     /// Transcription session updated event.
     #[ serde( rename = "transcription_session.updated" ) ]
     TranscriptionSessionUpdated( RealtimeServerEventTranscriptionSessionUpdated ),
+    /// An event type this crate does not know yet; its payload is discarded.
+    #[ serde( other ) ]
+    Unknown,
   }
 
 } // end mod private
@@ -14,10 +14,11 @@ mod private
     sync ::Arc,
     time ::{ Duration, Instant },
   };
-  use core::sync::atomic::{ AtomicU64, AtomicUsize, Ordering };
+  use core::sync::atomic::{ AtomicBool, AtomicU64, AtomicUsize, Ordering };
   use tokio::sync::{ RwLock, Mutex };
   use reqwest::Client as HttpClient;
   use serde::{ Serialize, Deserialize };
+  use crate::error::{ OpenAIError, Result };
 
   /// Configuration for advanced connection management
   #[ derive( Debug, Clone ) ]
@@ -258,7 +259,7 @@ mod private
     /// Returns an error if a new connection cannot be created when the pool is empty
     /// or when all existing connections are unhealthy.
     #[ inline ]
-    pub async fn get_connection( &self ) -> Result< Arc< ManagedConnection >, reqwest::Error >
+    pub async fn get_connection( &self ) -> Result< Arc< ManagedConnection > >
     {
       // Try to get an available healthy connection
       if let Some( conn ) = self.get_healthy_connection().await
@@ -324,7 +325,7 @@ mod private
     }
 
     /// Create a new HTTP connection
-    async fn create_new_connection( &self ) -> Result< Arc< ManagedConnection >, reqwest::Error >
+    async fn create_new_connection( &self ) -> Result< Arc< ManagedConnection > >
     {
       let client = HttpClient::builder()
         .timeout( Duration::from_secs( 300 ) )
@@ -332,7 +333,8 @@ mod private
         .pool_max_idle_per_host( self.config.max_connections_per_host )
         .pool_idle_timeout( self.config.idle_timeout )
         .tcp_keepalive( Duration::from_secs( 60 ) )
-        .build()?;
+        .build()
+        .map_err( OpenAIError::from )?;
 
       let connection = Arc::new( ManagedConnection::new( client, self.host.clone() ) );
 
@@ -358,7 +360,7 @@ mod private
     }
 
     /// Wait for a connection to become available
-    async fn wait_for_connection( &self ) -> Result< Arc< ManagedConnection >, reqwest::Error >
+    async fn wait_for_connection( &self ) -> Result< Arc< ManagedConnection > >
     {
       let start = Instant::now();
       while start.elapsed() < self.config.connection_wait_timeout
@@ -483,6 +485,34 @@ mod private
     pub current_utilization : f64,
   }
 
+  /// Outcome of `ConnectionManager::drain`
+  #[ derive( Debug, Clone, PartialEq, Eq, Serialize, Deserialize ) ]
+  pub struct DrainResult
+  {
+    /// Connections in use when draining started that were returned before the timeout
+    pub completed : usize,
+    /// Connections still in use at the timeout, dropped from their pools
+    pub forcibly_closed : usize,
+    /// Idle connections closed
+    pub idle_closed : usize,
+    /// Time spent waiting for in-use connections
+    pub elapsed : Duration,
+  }
+
+  impl DrainResult
+  {
+    /// Whether every in-use connection was returned before the timeout
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_clean( &self ) -> bool
+    {
+      self.forcibly_closed == 0
+    }
+  }
+
+  /// How often `drain` checks for returned connections
+  const DRAIN_POLL_INTERVAL : Duration = Duration::from_millis( 10 );
+
   /// Global connection manager
   #[ derive( Debug ) ]
   pub struct ConnectionManager
@@ -493,6 +523,8 @@ mod private
     config : ConnectionConfig,
     /// Background cleanup task handle
     cleanup_handle : Option< tokio::task::JoinHandle< () > >,
+    /// Set once `drain` starts; no connections are handed out afterwards
+    draining : AtomicBool,
   }
 
   impl ConnectionManager
@@ -507,6 +539,7 @@ mod private
         pools : Arc::new( RwLock::new( HashMap::new() ) ),
         config,
         cleanup_handle : None,
+        draining : AtomicBool::new( false ),
       }
     }
 
@@ -550,14 +583,80 @@ mod private
     ///
     /// # Errors
     ///
-    /// Returns an error if no connection can be obtained from the pool for the specified host.
+    /// Returns an error if no connection can be obtained from the pool for the specified host,
+    /// or `OpenAIError::Internal` once the manager is draining.
     #[ inline ]
-    pub async fn get_connection( &self, host : &str ) -> Result< Arc< ManagedConnection >, reqwest::Error >
+    pub async fn get_connection( &self, host : &str ) -> Result< Arc< ManagedConnection > >
     {
+      if self.is_draining()
+      {
+        return Err( OpenAIError::Internal( "Connection manager is draining".to_string() ).into() );
+      }
       let pool = self.get_or_create_pool( host ).await;
       pool.get_connection().await
     }
 
+    /// Whether `drain` has been called
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_draining( &self ) -> bool
+    {
+      self.draining.load( Ordering::Acquire )
+    }
+
+    /// Shut the manager down gracefully
+    ///
+    /// Stops handing out connections, then waits up to `timeout` for in-use
+    /// connections to be returned. Afterwards every pool is removed : idle
+    /// connections are closed, connections still in use are dropped from their
+    /// pools and closed once their holders release them, and connections
+    /// returned later are discarded.
+    #[ inline ]
+    pub async fn drain( &self, timeout : Duration ) -> DrainResult
+    {
+      self.draining.store( true, Ordering::Release );
+      let start = Instant::now();
+      let initially_in_use = self.in_use_count().await;
+
+      let mut in_use = initially_in_use;
+      while in_use > 0 && start.elapsed() < timeout
+      {
+        tokio ::time::sleep( DRAIN_POLL_INTERVAL.min( timeout.saturating_sub( start.elapsed() ) ) ).await;
+        in_use = self.in_use_count().await;
+      }
+      let elapsed = start.elapsed();
+
+      let pools : Vec< _ > = self.pools.write().await.drain().map( | ( _, pool ) | pool ).collect();
+      let mut idle_closed = 0;
+      for pool in &pools
+      {
+        let mut available = pool.available.lock().await;
+        idle_closed += available.len();
+        pool.pool_stats.read().await.connections_destroyed.fetch_add( available.len() as u64, Ordering::Relaxed );
+        available.clear();
+        pool.in_use.write().await.clear();
+      }
+
+      DrainResult
+      {
+        completed : initially_in_use.saturating_sub( in_use ),
+        forcibly_closed : in_use,
+        idle_closed,
+        elapsed,
+      }
+    }
+
+    async fn in_use_count( &self ) -> usize
+    {
+      let pools : Vec< _ > = self.pools.read().await.values().cloned().collect();
+      let mut count = 0;
+      for pool in pools
+      {
+        count += pool.in_use.read().await.len();
+      }
+      count
+    }
+
     /// Return connection to pool
     #[ inline ]
     pub async fn return_connection( &self, conn : Arc< ManagedConnection > )
@@ -736,5 +835,6 @@ mod_interface!
     PoolStatistics,
     ConnectionManager,
     ConnectionEfficiencyMetrics,
+    DrainResult,
   };
 }
\ No newline at end of file
@@ -10,18 +10,21 @@
 /// Define a private namespace for all its items.
 mod private
 {
-  use crate::{ Client, error::OpenAIError };
+  use crate::{ Client, ClientApiAccessors, error::OpenAIError };
   use crate::environment::OpenaiEnvironmentImpl;
   use crate::components::batch_shared::*;
+  use crate::components::chat_shared::{ ChatCompletionRequest, CreateChatCompletionResponse };
   use crate::components::common::Metadata;
+  use crate::components::files::FilePurpose;
   use serde_json::{ json, Value };
   use core::time::Duration;
   use std::{
-    collections ::HashMap,
+    collections ::{ HashMap, HashSet },
     sync ::{ Arc, Mutex },
   };
   use tokio::sync::Semaphore;
   use error_tools::untyped::Result;
+  use error_tools::dependency::thiserror;
 
   /// Recommended configuration values for enhanced batch operations following "Thin Client, Rich API" principles.
   ///
@@ -128,6 +131,217 @@ mod private
     pub requests_per_second : f64,
   }
 
+  /// Builder for Batch API input files
+  ///
+  /// Each added request becomes one JSONL line with `custom_id`, `method`, `url`
+  /// and `body`. `custom_id`s must be unique within a batch.
+  #[ derive( Debug, Clone ) ]
+  pub struct BatchInputBuilder
+  {
+    /// Endpoint shared by every request in the batch
+    endpoint : String,
+    /// Batch input lines in insertion order
+    lines : Vec< Value >,
+    /// `custom_id`s already added
+    custom_ids : HashSet< String >,
+  }
+
+  impl Default for BatchInputBuilder
+  {
+    #[ inline ]
+    fn default() -> Self
+    {
+      Self::new()
+    }
+  }
+
+  impl BatchInputBuilder
+  {
+    /// Creates an empty builder for chat completion requests
+    #[ must_use ]
+    #[ inline ]
+    pub fn new() -> Self
+    {
+      Self
+      {
+        endpoint : "/v1/chat/completions".to_string(),
+        lines : Vec::new(),
+        custom_ids : HashSet::new(),
+      }
+    }
+
+    /// Adds a chat completion request under the given `custom_id`
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenAIError::InvalidArgument` if the `custom_id` is empty or already used,
+    /// or if the request cannot be serialized.
+    #[ inline ]
+    pub fn add_chat_request( &mut self, custom_id : impl Into< String >, request : &ChatCompletionRequest ) -> Result< &mut Self >
+    {
+      let custom_id = custom_id.into();
+      if custom_id.is_empty()
+      {
+        return Err( OpenAIError::InvalidArgument( "Batch custom_id must not be empty".to_string() ).into() );
+      }
+      if self.custom_ids.contains( &custom_id )
+      {
+        return Err( OpenAIError::InvalidArgument( format!( "Duplicate batch custom_id : {custom_id}" ) ).into() );
+      }
+
+      let body = serde_json::to_value( request )
+        .map_err( | e | OpenAIError::InvalidArgument( format!( "Failed to serialize request {custom_id} : {e}" ) ) )?;
+      self.lines.push( json!( {
+        "custom_id": custom_id,
+        "method": "POST",
+        "url": self.endpoint,
+        "body": body,
+      } ) );
+      self.custom_ids.insert( custom_id );
+      Ok( self )
+    }
+
+    /// Returns the endpoint the batch targets
+    #[ must_use ]
+    #[ inline ]
+    pub fn endpoint( &self ) -> &str
+    {
+      &self.endpoint
+    }
+
+    /// Returns the number of requests added
+    #[ must_use ]
+    #[ inline ]
+    pub fn len( &self ) -> usize
+    {
+      self.lines.len()
+    }
+
+    /// Returns `true` if no requests were added
+    #[ must_use ]
+    #[ inline ]
+    pub fn is_empty( &self ) -> bool
+    {
+      self.lines.is_empty()
+    }
+
+    /// Renders the batch input file, one request per line
+    #[ must_use ]
+    #[ inline ]
+    pub fn build_jsonl( &self ) -> String
+    {
+      self.lines.iter().map( | line | format!( "{line}\n" ) ).collect()
+    }
+  }
+
+  /// Uploads the builder's JSONL as a batch input file and creates the batch
+  ///
+  /// # Errors
+  ///
+  /// Returns `OpenAIError::InvalidArgument` if the builder is empty, or an error if the
+  /// upload or batch creation fails.
+  #[ inline ]
+  pub async fn upload_and_create( client : &Client< OpenaiEnvironmentImpl >, builder : &BatchInputBuilder, completion_window : &str, metadata : Option< Metadata > ) -> Result< Batch >
+  {
+    if builder.is_empty()
+    {
+      return Err( OpenAIError::InvalidArgument( "Batch input has no requests".to_string() ).into() );
+    }
+
+    let file = client.files()
+      .upload_validated( builder.build_jsonl().into_bytes(), "batch_input.jsonl", FilePurpose::Batch )
+      .await?;
+
+    let mut request = json!( {
+      "input_file_id": file.id,
+      "endpoint": builder.endpoint(),
+      "completion_window": completion_window,
+    } );
+    if let Some( metadata ) = metadata
+    {
+      request[ "metadata" ] = serde_json::to_value( metadata )?;
+    }
+
+    client.post( "batches", &request ).await
+  }
+
+  /// Failure recorded for a single line of a batch output file
+  #[ derive( Debug, Clone, PartialEq, thiserror::Error ) ]
+  pub enum BatchLineError
+  {
+    /// The request failed before producing a response
+    #[ error( "Batch request failed ({}) : {message}", code.as_deref().unwrap_or( "unknown" ) ) ]
+    Request
+    {
+      /// Machine-readable error code
+      code : Option< String >,
+      /// Human-readable error message
+      message : String,
+    },
+    /// The endpoint answered with a non-success status
+    #[ error( "Batch request returned HTTP {status_code} : {body}" ) ]
+    Http
+    {
+      /// HTTP status code of the response
+      status_code : i32,
+      /// Response body, usually an API error object
+      body : Value,
+    },
+    /// The line could not be interpreted as a chat completion result
+    #[ error( "Malformed batch output line : {0}" ) ]
+    Malformed( String ),
+  }
+
+  /// Parses a batch output file, mapping each `custom_id` to its chat completion or error
+  ///
+  /// Lines that are not JSON objects with a `custom_id` cannot be correlated and are
+  /// skipped. Lines with a `custom_id` but an unexpected shape map to
+  /// `BatchLineError::Malformed`. If a `custom_id` repeats, the last line wins.
+  #[ must_use ]
+  #[ inline ]
+  pub fn parse_batch_output( jsonl : &str ) -> HashMap< String, core::result::Result< CreateChatCompletionResponse, BatchLineError > >
+  {
+    let mut results = HashMap::new();
+
+    for line in jsonl.lines().filter( | line | !line.trim().is_empty() )
+    {
+      let Ok( value ) = serde_json::from_str::< Value >( line ) else { continue };
+      let Some( custom_id ) = value.get( "custom_id" ).and_then( Value::as_str ).map( ToString::to_string ) else { continue };
+
+      let result = match serde_json::from_value::< BatchRequestOutput >( value )
+      {
+        Ok( output ) => batch_output_result( output ),
+        Err( e ) => Err( BatchLineError::Malformed( e.to_string() ) ),
+      };
+      results.insert( custom_id, result );
+    }
+
+    results
+  }
+
+  /// Interprets one parsed batch output line
+  fn batch_output_result( output : BatchRequestOutput ) -> core::result::Result< CreateChatCompletionResponse, BatchLineError >
+  {
+    if let Some( error ) = output.error
+    {
+      return Err( BatchLineError::Request
+      {
+        code : error.code,
+        message : error.message.unwrap_or_default(),
+      } );
+    }
+
+    let response = output.response.ok_or_else( || BatchLineError::Malformed( "line has neither response nor error".to_string() ) )?;
+    let body = response.body.unwrap_or( Value::Null );
+    let status_code = response.status_code.unwrap_or( 200 );
+    if !( 200..300 ).contains( &status_code )
+    {
+      return Err( BatchLineError::Http { status_code, body } );
+    }
+
+    serde_json::from_value( body ).map_err( | e | BatchLineError::Malformed( format!( "invalid chat completion body : {e}" ) ) )
+  }
+
   /// Enhanced batch job creation with priority and retry configuration
   ///
   /// # Errors
@@ -715,5 +929,9 @@ crate ::mod_interface!
     BatchJobConfig,
     BatchProcessingMetrics,
     BatchRecommended,
+    BatchInputBuilder,
+    upload_and_create,
+    BatchLineError,
+    parse_batch_output,
   };
 }
\ No newline at end of file
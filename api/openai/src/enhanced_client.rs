@@ -533,7 +533,7 @@ mod private
       };
 
       // Build and execute request
-      let request_builder = connection.client.request( method, url );
+      let request_builder = connection.client.request( method, url ).headers( self.base_client.environment.request_headers().await? );
       let request_builder = if let Some( body ) = body
       {
         request_builder.json( body )
@@ -13,9 +13,11 @@ mod private
     client_api_accessors ::ClientApiAccessors,
     error ::{ Result, OpenAIError },
     environment ::{ OpenaiEnvironment, EnvironmentInterface },
-    components ::embeddings::CreateEmbeddingResponse,
+    components ::embeddings::{ CreateEmbeddingResponse, Embedding },
     components ::embeddings_request::CreateEmbeddingRequest,
   };
+  use core::ops::Range;
+  use futures_util::{ stream, StreamExt };
 
   // Feature-gated imports
   #[ cfg( feature = "batching" ) ]
@@ -36,6 +38,8 @@ mod private
     batcher : Arc< RequestBatcher< CreateEmbeddingRequest > >,
     #[ cfg( feature = "batching" ) ]
     config : BatchConfig,
+
+    embed_many_config : EmbedManyConfig,
   }
 
   impl< 'client, E > EnhancedEmbeddings< 'client, E >
@@ -54,6 +58,7 @@ mod private
         client,
         batcher,
         config,
+        embed_many_config : EmbedManyConfig::default(),
       }
     }
 
@@ -65,6 +70,7 @@ mod private
       Self
       {
         client,
+        embed_many_config : EmbedManyConfig::default(),
       }
     }
 
@@ -84,6 +90,15 @@ mod private
       Self::new( client, () )
     }
 
+    /// Set the model and batch limits used by [`Self::embed_many`]
+    #[ inline ]
+    #[ must_use ]
+    pub fn with_embed_many_config( mut self, config : EmbedManyConfig ) -> Self
+    {
+      self.embed_many_config = config;
+      self
+    }
+
     /// Create embeddings directly without batching (fallback method)
     ///
     /// # Errors
@@ -184,6 +199,55 @@ mod private
       }
     }
 
+    /// Embed many inputs, returning one embedding per input in input order
+    ///
+    /// Inputs are grouped by [`plan_embedding_batches`] under the limits of the
+    /// configured [`EmbedManyConfig`], and the batches are sent with at most
+    /// `max_concurrency` requests in flight. Each returned embedding's `index`
+    /// is its position in `inputs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first failed batch, in input order. Use
+    /// [`Self::embed_many_batches`] to keep the results of the batches that succeeded.
+    #[ inline ]
+    pub async fn embed_many( &self, inputs : Vec< String > ) -> Result< Vec< Embedding > >
+    {
+      let mut embeddings = Vec::with_capacity( inputs.len() );
+      for batch in self.embed_many_batches( inputs ).await
+      {
+        embeddings.extend( batch.result? );
+      }
+      Ok( embeddings )
+    }
+
+    /// Embed many inputs like [`Self::embed_many`], reporting each batch separately
+    ///
+    /// Batches are returned in input order, whether they succeeded or failed.
+    #[ inline ]
+    pub async fn embed_many_batches( &self, inputs : Vec< String > ) -> Vec< EmbeddingBatchResult >
+    {
+      let config = &self.embed_many_config;
+      let inputs = &inputs;
+      let estimates : Vec< usize > = inputs.iter().map( | input | ( config.token_estimator )( input.as_str() ) ).collect();
+      let batches = plan_embedding_batches( &estimates, config.max_inputs_per_request, config.max_tokens_per_request );
+
+      stream::iter( batches )
+      .map( | range | async move
+      {
+        let request = CreateEmbeddingRequest::new_multiple( inputs[ range.clone() ].to_vec(), config.model.clone() );
+        let result = match self.client.embeddings().create( request ).await
+        {
+          Ok( response ) => reassemble_batch( response, &range ),
+          Err( error ) => Err( error ),
+        };
+        EmbeddingBatchResult { inputs : range, result }
+      } )
+      .buffered( config.max_concurrency.max( 1 ) )
+      .collect()
+      .await
+    }
+
     /// Get batching performance metrics
     #[ cfg( feature = "batching" ) ]
     #[ inline ]
@@ -253,6 +317,121 @@ mod private
 
   }
 
+  /// Estimates the number of tokens in one embedding input
+  pub type TokenEstimator = Arc< dyn Fn( &str ) -> usize + Send + Sync >;
+
+  /// Model and batch limits for [`EnhancedEmbeddings::embed_many`]
+  #[ derive( Clone ) ]
+  pub struct EmbedManyConfig
+  {
+    /// Embedding model
+    pub model : String,
+    /// Maximum number of inputs per request
+    pub max_inputs_per_request : usize,
+    /// Maximum estimated tokens per request
+    pub max_tokens_per_request : usize,
+    /// Maximum number of requests in flight
+    pub max_concurrency : usize,
+    /// Token estimate for one input, e.g. backed by a tokenizer
+    pub token_estimator : TokenEstimator,
+  }
+
+  impl Default for EmbedManyConfig
+  {
+    /// `text-embedding-3-small` within the API limits of 2048 inputs and
+    /// 300 000 tokens per request, estimating four bytes per token
+    #[ inline ]
+    fn default() -> Self
+    {
+      Self
+      {
+        model : "text-embedding-3-small".to_string(),
+        max_inputs_per_request : 2048,
+        max_tokens_per_request : 300_000,
+        max_concurrency : 4,
+        token_estimator : Arc::new( | input : &str | input.len() / 4 + 1 ),
+      }
+    }
+  }
+
+  impl core::fmt::Debug for EmbedManyConfig
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      f.debug_struct( "EmbedManyConfig" )
+      .field( "model", &self.model )
+      .field( "max_inputs_per_request", &self.max_inputs_per_request )
+      .field( "max_tokens_per_request", &self.max_tokens_per_request )
+      .field( "max_concurrency", &self.max_concurrency )
+      .finish_non_exhaustive()
+    }
+  }
+
+  /// Outcome of one request made by [`EnhancedEmbeddings::embed_many_batches`]
+  #[ derive( Debug ) ]
+  pub struct EmbeddingBatchResult
+  {
+    /// Positions of the batch's inputs in the original input list
+    pub inputs : Range< usize >,
+    /// Embeddings of the batch in input order, or the error of its request
+    pub result : Result< Vec< Embedding > >,
+  }
+
+  /// Group inputs into batches by count and token budget
+  ///
+  /// `token_estimates` holds one estimate per input. Consecutive inputs are
+  /// added to a batch until adding the next would exceed `max_inputs` inputs or
+  /// `max_tokens` estimated tokens. An input estimated above `max_tokens` on its
+  /// own is placed in a batch by itself, leaving the API to reject it.
+  #[ must_use ]
+  #[ inline ]
+  pub fn plan_embedding_batches( token_estimates : &[ usize ], max_inputs : usize, max_tokens : usize ) -> Vec< Range< usize > >
+  {
+    let max_inputs = max_inputs.max( 1 );
+    let mut batches = Vec::new();
+    let mut start = 0;
+    let mut tokens = 0;
+
+    for ( index, &estimate ) in token_estimates.iter().enumerate()
+    {
+      let count = index - start;
+      if count > 0 && ( count >= max_inputs || tokens + estimate > max_tokens )
+      {
+        batches.push( start..index );
+        start = index;
+        tokens = 0;
+      }
+      tokens += estimate;
+    }
+
+    if start < token_estimates.len()
+    {
+      batches.push( start..token_estimates.len() );
+    }
+    batches
+  }
+
+  /// Order a batch response by input position and renumber it against the full input list
+  fn reassemble_batch( response : CreateEmbeddingResponse, range : &Range< usize > ) -> Result< Vec< Embedding > >
+  {
+    if response.data.len() != range.len()
+    {
+      return Err( OpenAIError::Internal( format!(
+        "Embedding batch for inputs {}..{} returned {} embeddings, expected {}",
+        range.start, range.end, response.data.len(), range.len()
+      ) ) );
+    }
+
+    let mut data = response.data;
+    data.sort_by_key( | embedding | embedding.index );
+    for ( offset, embedding ) in data.iter_mut().enumerate()
+    {
+      embedding.index = i32::try_from( range.start + offset ).unwrap_or( i32::MAX );
+    }
+    Ok( data )
+  }
+
   /// Analyze batching potential for given requests (standalone function)
   #[ must_use ]
   #[ inline ]
@@ -405,7 +584,11 @@ mod_interface!
     EnhancedEmbeddings,
     EnhancedEmbeddingsConfig,
     EmbeddingBatchProcessor,
+    EmbedManyConfig,
+    EmbeddingBatchResult,
+    TokenEstimator,
     analyze_embedding_batching_potential,
+    plan_embedding_batches,
   };
 
   #[ cfg( feature = "batching" ) ]
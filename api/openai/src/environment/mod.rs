@@ -68,6 +68,7 @@ mod private
   }
 
   /// A trait defining the interface for `OpenAI` environment configuration.
+  #[ async_trait::async_trait ]
   pub trait OpenaiEnvironment : Send + Sync + 'static
   {
     /// Returns the API key.
@@ -87,6 +88,18 @@ mod private
     /// # Errors
     /// Returns `OpenAIError::InvalidArgument` if headers contain invalid values.
     fn headers( &self ) -> Result< header::HeaderMap >;
+    /// Returns the HTTP headers resolved again for every request, such as a refreshed access token.
+    ///
+    /// They override the client's default headers built from [`OpenaiEnvironment::headers`].
+    /// The default implementation returns no headers.
+    ///
+    /// # Errors
+    /// Returns an error if a header value cannot be obtained.
+    #[ inline ]
+    async fn request_headers( &self ) -> Result< header::HeaderMap >
+    {
+      Ok( header::HeaderMap::new() )
+    }
     /// Joins a path to the base URL.
     ///
     /// # Errors
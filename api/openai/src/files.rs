@@ -19,6 +19,7 @@ mod private
     FileObject,
     ListFilesResponse,
     CreateFileRequest,
+    FilePurpose,
   };
   use crate::components::common::ListQuery;
 
@@ -73,6 +74,27 @@ mod private
       self.client.post_multipart( path, form ).await
     }
 
+    /// Uploads a file after checking it against its purpose.
+    ///
+    /// Obvious mismatches, such as a `.jsonl` file uploaded for `vision`, are rejected
+    /// locally instead of failing server-side.
+    ///
+    /// # Arguments
+    /// - `bytes`: The file data to upload.
+    /// - `filename`: The filename for the uploaded file.
+    /// - `purpose`: The intended purpose of the file.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::InvalidArgument` if the file does not match the purpose,
+    /// or `OpenAIError` if the request fails.
+    #[ inline ]
+    pub async fn upload_validated( &self, bytes : Vec< u8 >, filename : impl Into< String >, purpose : FilePurpose ) -> Result< FileObject >
+    {
+      let filename = filename.into();
+      purpose.validate_file( &filename, &bytes )?;
+      self.upload( CreateFileRequest { file : bytes, filename, purpose : purpose.as_str().to_string() } ).await
+    }
+
     /// Lists files that belong to the user's organization.
     ///
     /// # Arguments
@@ -24,7 +24,7 @@ mod private
 
   use std::
   {
-    collections ::HashMap,
+    collections ::{ BTreeMap, HashMap },
     sync ::Arc,
     time ::{ SystemTime, UNIX_EPOCH },
   };
@@ -248,6 +248,18 @@ mod private
     pub risk_level : String,
   }
 
+  /// Default latency histogram bucket boundaries in milliseconds for [`MetricsCollector::export_otlp`]
+  pub const OTLP_LATENCY_BOUNDS_MS : &[ f64 ] = &[ 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0, 10000.0, 30000.0 ];
+
+  /// OTLP `AGGREGATION_TEMPORALITY_CUMULATIVE`
+  const OTLP_CUMULATIVE : u8 = 2;
+
+  /// OTLP encodes 64-bit integers as JSON strings
+  fn unix_nanos( time : SystemTime ) -> String
+  {
+    time.duration_since( UNIX_EPOCH ).unwrap_or_default().as_nanos().to_string()
+  }
+
   /// Central metrics collector and analyzer
   #[ derive( Debug ) ]
   pub struct MetricsCollector
@@ -258,6 +270,9 @@ mod private
     metrics_history : Arc< RwLock< Vec< MetricsSnapshot > > >,
     /// Request timing data
     timing_data : Arc< RwLock< Vec< f64 > > >,
+    /// Every recorded latency as whole milliseconds mapped to its count, never
+    /// trimmed, so cumulative OTLP exports only grow
+    latency_totals : Arc< RwLock< BTreeMap< u64, u64 > > >,
     /// Error tracking
     error_counts : Arc< RwLock< HashMap<  String, u64  > > >,
     /// Collection start time
@@ -295,6 +310,7 @@ mod private
         config,
         metrics_history : Arc::new( RwLock::new( Vec::new() ) ),
         timing_data : Arc::new( RwLock::new( Vec::new() ) ),
+        latency_totals : Arc::new( RwLock::new( BTreeMap::new() ) ),
         error_counts : Arc::new( RwLock::new( HashMap::new() ) ),
         start_time : Instant::now(),
         collection_handle : None,
@@ -369,8 +385,9 @@ mod private
     {
       if self.config.collect_timing_metrics
       {
-        let mut timing_data = self.timing_data.write().await;
-        timing_data.push( duration.as_millis() as f64 );
+        let millis = u64::try_from( duration.as_millis() ).unwrap_or( u64::MAX );
+        self.timing_data.write().await.push( millis as f64 );
+        *self.latency_totals.write().await.entry( millis ).or_insert( 0 ) += 1;
       }
     }
 
@@ -652,6 +669,118 @@ mod private
       output
     }
 
+    /// Export metrics as an OTLP/JSON `ExportMetricsServiceRequest`
+    ///
+    /// Request latencies become the `openai.request.duration` histogram with
+    /// [`OTLP_LATENCY_BOUNDS_MS`] as bucket boundaries; request and error counts
+    /// become the cumulative sums `openai.request.count` and `openai.error.count`
+    /// (one data point per error type). The counts cover every recorded request,
+    /// not just the samples kept under `max_entries`, so successive exports never
+    /// decrease. The result is the body for a collector's
+    /// `/v1/metrics` endpoint with `Content-Type: application/json`.
+    #[ inline ]
+    pub async fn export_otlp( &self ) -> String
+    {
+      self.export_otlp_with_bounds( OTLP_LATENCY_BOUNDS_MS ).await
+    }
+
+    /// Export metrics as OTLP/JSON with custom latency bucket boundaries in milliseconds
+    ///
+    /// `bounds` must be sorted in increasing order; the histogram has one more
+    /// bucket than there are bounds.
+    #[ inline ]
+    pub async fn export_otlp_with_bounds( &self, bounds : &[ f64 ] ) -> String
+    {
+      let now = SystemTime::now();
+      let time = unix_nanos( now );
+      let start = unix_nanos( now.checked_sub( self.start_time.elapsed() ).unwrap_or( UNIX_EPOCH ) );
+
+      let totals = self.latency_totals.read().await.clone();
+      let mut bucket_counts = vec![ 0_u64; bounds.len() + 1 ];
+      let ( mut count, mut sum ) = ( 0_u64, 0.0 );
+      for ( &millis, &occurrences ) in &totals
+      {
+        let value = millis as f64;
+        bucket_counts[ bounds.partition_point( | bound | *bound < value ) ] += occurrences;
+        count += occurrences;
+        sum += value * occurrences as f64;
+      }
+      let mut duration_point = serde_json::json!(
+      {
+        "startTimeUnixNano" : start,
+        "timeUnixNano" : time,
+        "count" : count.to_string(),
+        "sum" : sum,
+        "bucketCounts" : bucket_counts.iter().map( ToString::to_string ).collect::< Vec< _ > >(),
+        "explicitBounds" : bounds,
+      });
+      if let ( Some( min ), Some( max ) ) = ( totals.keys().next(), totals.keys().next_back() )
+      {
+        duration_point[ "min" ] = ( *min as f64 ).into();
+        duration_point[ "max" ] = ( *max as f64 ).into();
+      }
+
+      let mut metrics = vec!
+      [
+        serde_json::json!(
+        {
+          "name" : "openai.request.duration",
+          "description" : "Duration of API requests",
+          "unit" : "ms",
+          "histogram" : { "aggregationTemporality" : OTLP_CUMULATIVE, "dataPoints" : [ duration_point ] },
+        }),
+        serde_json::json!(
+        {
+          "name" : "openai.request.count",
+          "description" : "Number of timed API requests",
+          "unit" : "{request}",
+          "sum" :
+          {
+            "aggregationTemporality" : OTLP_CUMULATIVE,
+            "isMonotonic" : true,
+            "dataPoints" : [ { "startTimeUnixNano" : start, "timeUnixNano" : time, "asInt" : count.to_string() } ],
+          },
+        }),
+      ];
+
+      let mut errors : Vec< _ > = self.error_counts.read().await.iter().map( | ( kind, count ) | ( kind.clone(), *count ) ).collect();
+      if !errors.is_empty()
+      {
+        errors.sort();
+        let points : Vec< _ > = errors.into_iter().map( | ( kind, count ) | serde_json::json!(
+        {
+          "attributes" : [ { "key" : "error.type", "value" : { "stringValue" : kind } } ],
+          "startTimeUnixNano" : start,
+          "timeUnixNano" : time,
+          "asInt" : count.to_string(),
+        })).collect();
+        metrics.push( serde_json::json!(
+        {
+          "name" : "openai.error.count",
+          "description" : "Number of API errors by type",
+          "unit" : "{error}",
+          "sum" : { "aggregationTemporality" : OTLP_CUMULATIVE, "isMonotonic" : true, "dataPoints" : points },
+        }));
+      }
+
+      serde_json::json!(
+      {
+        "resourceMetrics" :
+        [
+          {
+            "resource" : { "attributes" : [ { "key" : "service.name", "value" : { "stringValue" : env!( "CARGO_PKG_NAME" ) } } ] },
+            "scopeMetrics" :
+            [
+              {
+                "scope" : { "name" : env!( "CARGO_PKG_NAME" ), "version" : env!( "CARGO_PKG_VERSION" ) },
+                "metrics" : metrics,
+              }
+            ],
+          }
+        ],
+      }).to_string()
+    }
+
     /// Get current configuration
     #[ inline ]
     #[ must_use ]
@@ -868,6 +997,7 @@ mod_interface!
     MetricsAggregation,
     MetricsAnalysisReport,
     MetricsCollector,
+    OTLP_LATENCY_BOUNDS_MS,
   };
 
   #[ cfg( feature = "circuit_breaker" ) ]
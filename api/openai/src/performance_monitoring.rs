@@ -8,7 +8,7 @@ mod private
 {
   use std::
   {
-    collections ::HashMap,
+    collections ::{ HashMap, VecDeque },
     sync ::{ Arc, Mutex },
     time ::Instant,
   };
@@ -94,6 +94,74 @@ mod private
     }
   }
 
+  /// Latency and success objectives for one endpoint
+  #[ derive( Debug, Clone, PartialEq, Serialize, Deserialize ) ]
+  pub struct SloConfig
+  {
+    /// Requests slower than this count against the latency objective
+    pub target_latency : Duration,
+    /// Fraction of requests (0.0 - 1.0) that must succeed, and that must finish within `target_latency`
+    pub target_success_rate : f64,
+    /// Number of most recent requests the objectives are evaluated over
+    pub window : usize,
+  }
+
+  impl Default for SloConfig
+  {
+    #[ inline ]
+    fn default() -> Self
+    {
+      Self
+      {
+        target_latency : Duration::from_secs( 2 ),
+        target_success_rate : 0.99,
+        window : 1000,
+      }
+    }
+  }
+
+  /// Result of evaluating an endpoint's recent requests against its `SloConfig`
+  #[ derive( Debug, Clone, PartialEq, Serialize, Deserialize ) ]
+  pub struct SloStatus
+  {
+    /// Endpoint the status applies to
+    pub endpoint : String,
+    /// Number of requests evaluated
+    pub requests : usize,
+    /// Fraction of evaluated requests that succeeded
+    pub success_rate : f64,
+    /// Fraction of evaluated requests that finished within the target latency
+    pub within_latency_rate : f64,
+    /// Whether `within_latency_rate` meets the target
+    pub meets_latency : bool,
+    /// Whether `success_rate` meets the target
+    pub meets_success_rate : bool,
+    /// Fraction of the error budget left (1.0 untouched, 0.0 exhausted)
+    ///
+    /// The budget is the `1 - target_success_rate` share of requests allowed to
+    /// fail or miss the latency target.
+    pub error_budget_remaining : f64,
+  }
+
+  impl SloStatus
+  {
+    /// Whether both the latency and success objectives are met
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_met( &self ) -> bool
+    {
+      self.meets_latency && self.meets_success_rate
+    }
+  }
+
+  /// Outcome of one request, kept for SLO evaluation
+  #[ derive( Debug, Clone, Copy ) ]
+  struct RequestSample
+  {
+    latency : Duration,
+    success : bool,
+  }
+
   /// Performance monitoring context
   #[ derive( Debug ) ]
   pub struct PerformanceMonitor
@@ -101,6 +169,8 @@ mod private
     config : Arc< Mutex< PerformanceConfig > >,
     metrics : Arc< Mutex< HashMap< String, Vec< Duration > > > >,
     memory_snapshots : Arc< Mutex< Vec< u64 > > >,
+    slo_configs : Arc< Mutex< HashMap< String, SloConfig > > >,
+    request_samples : Arc< Mutex< HashMap< String, VecDeque< RequestSample > > > >,
   }
 
   impl PerformanceMonitor
@@ -133,9 +203,101 @@ mod private
         config : Arc::new( Mutex::new( config ) ),
         metrics : Arc::new( Mutex::new( HashMap::new() ) ),
         memory_snapshots : Arc::new( Mutex::new( Vec::new() ) ),
+        slo_configs : Arc::new( Mutex::new( HashMap::new() ) ),
+        request_samples : Arc::new( Mutex::new( HashMap::new() ) ),
       }
     }
 
+    /// Set the SLO for `endpoint`; endpoints without one use `SloConfig::default()`
+    #[ inline ]
+    pub fn set_slo( &self, endpoint : &str, slo : SloConfig )
+    {
+      if let Ok( mut configs ) = self.slo_configs.lock()
+      {
+        configs.insert( endpoint.to_string(), slo );
+      }
+    }
+
+    /// Record the latency and outcome of a request to `endpoint`
+    ///
+    /// Only the most recent `window` requests of the endpoint's SLO are kept.
+    #[ inline ]
+    pub fn record_request( &self, endpoint : &str, latency : Duration, success : bool )
+    {
+      let window = self.slo_config( endpoint ).window;
+      if let Ok( mut samples ) = self.request_samples.lock()
+      {
+        let samples = samples.entry( endpoint.to_string() ).or_default();
+        samples.push_back( RequestSample { latency, success } );
+        while samples.len() > window
+        {
+          samples.pop_front();
+        }
+      }
+    }
+
+    /// Evaluate the recent requests to `endpoint` against its SLO
+    ///
+    /// An endpoint without recorded requests meets its SLO with the full error budget.
+    #[ inline ]
+    #[ must_use ]
+    pub fn evaluate_slo( &self, endpoint : &str ) -> SloStatus
+    {
+      let slo = self.slo_config( endpoint );
+      let recent : Vec< RequestSample > = self.request_samples.lock()
+        .ok()
+        .and_then( | samples | samples.get( endpoint ).map( | samples | samples.iter().rev().take( slo.window ).copied().collect() ) )
+        .unwrap_or_default();
+
+      let requests = recent.len();
+      if requests == 0
+      {
+        return SloStatus
+        {
+          endpoint : endpoint.to_string(),
+          requests,
+          success_rate : 1.0,
+          within_latency_rate : 1.0,
+          meets_latency : true,
+          meets_success_rate : true,
+          error_budget_remaining : 1.0,
+        };
+      }
+
+      let total = requests as f64;
+      let succeeded = recent.iter().filter( | sample | sample.success ).count();
+      let within_latency = recent.iter().filter( | sample | sample.latency <= slo.target_latency ).count();
+      let bad = recent.iter().filter( | sample | !sample.success || sample.latency > slo.target_latency ).count();
+
+      let success_rate = succeeded as f64 / total;
+      let within_latency_rate = within_latency as f64 / total;
+      let allowed_bad = ( 1.0 - slo.target_success_rate ) * total;
+      let error_budget_remaining = if allowed_bad > 0.0
+      {
+        ( 1.0 - bad as f64 / allowed_bad ).clamp( 0.0, 1.0 )
+      }
+      else if bad == 0 { 1.0 } else { 0.0 };
+
+      SloStatus
+      {
+        endpoint : endpoint.to_string(),
+        requests,
+        success_rate,
+        within_latency_rate,
+        meets_latency : within_latency_rate >= slo.target_success_rate,
+        meets_success_rate : success_rate >= slo.target_success_rate,
+        error_budget_remaining,
+      }
+    }
+
+    fn slo_config( &self, endpoint : &str ) -> SloConfig
+    {
+      self.slo_configs.lock()
+        .ok()
+        .and_then( | configs | configs.get( endpoint ).cloned() )
+        .unwrap_or_default()
+    }
+
     /// Measure request overhead
     ///
     /// # Errors
@@ -558,6 +720,8 @@ crate ::mod_interface!
   orphan use RegressionReport;
   orphan use ThroughputMetrics;
   orphan use PerformanceConfig;
+  orphan use SloConfig;
+  orphan use SloStatus;
   orphan use PerformanceMonitor;
   orphan use get_performance_monitor;
   orphan use configure_performance_monitoring;
@@ -1,7 +1,8 @@
 //! Platform-specific features and integrations.
 //!
 //! This module provides advanced capabilities for specific use cases including
-//! search grounding, code execution, web browsing, and custom tool integrations.
+//! search grounding, code execution, web browsing, and custom tool integrations,
+//! and the choice of TLS backend for API connections.
 //! All features are feature-gated and include comprehensive security controls.
 
 pub mod search_grounding;
@@ -11,6 +12,7 @@ pub mod custom_tool;
 pub mod image_generation;
 pub mod api_connector;
 pub mod client_impl;
+pub mod tls_backend;
 
 use mod_interface::mod_interface;
 
@@ -24,6 +26,7 @@ mod private
   pub use super::image_generation::*;
   pub use super::api_connector::*;
   pub use super::client_impl::*;
+  pub use super::tls_backend::*;
 }
 
 mod_interface!
@@ -73,5 +76,8 @@ mod_interface!
 
     // Client Extensions
     PlatformSpecificClient,
+
+    // TLS
+    TlsBackend,
   };
 }
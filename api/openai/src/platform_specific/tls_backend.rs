@@ -0,0 +1,95 @@
+//! TLS Backend Selection
+//!
+//! Chooses the TLS implementation used by the `reqwest` client. Each backend is
+//! compiled in only by its own feature : `rustls` with `tls_rustls` (a default
+//! feature), the platform library (`OpenSSL`, `SChannel`, Security.framework)
+//! with `tls_native`. Where a FIPS-validated TLS stack is required, build with
+//! `--no-default-features --features full,tls_native` so `rustls` is not linked.
+
+use serde::{ Serialize, Deserialize };
+use crate::error::{ OpenAIError, Result };
+
+/// TLS implementation used for API connections.
+///
+/// The selected backend is applied explicitly, so a client never depends on
+/// `reqwest`'s own preference between the TLS stacks it links.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize ) ]
+pub enum TlsBackend
+{
+  /// Pure-Rust `rustls` (feature `tls_rustls`)
+  Rustls,
+  /// Platform TLS library via `native-tls` (feature `tls_native`)
+  NativeTls,
+}
+
+impl TlsBackend
+{
+  /// Whether this backend is compiled into this build.
+  #[ inline ]
+  #[ must_use ]
+  pub const fn is_available( self ) -> bool
+  {
+    match self
+    {
+      Self::Rustls => cfg!( feature = "tls_rustls" ),
+      Self::NativeTls => cfg!( feature = "tls_native" ),
+    }
+  }
+
+  /// All backends compiled into this build.
+  #[ inline ]
+  #[ must_use ]
+  pub fn available() -> Vec< Self >
+  {
+    [ Self::Rustls, Self::NativeTls ].into_iter().filter( | backend | backend.is_available() ).collect()
+  }
+
+  /// Check that this backend is compiled into this build.
+  ///
+  /// # Errors
+  ///
+  /// Returns `OpenAIError::InvalidArgument` naming the feature that selects it.
+  #[ inline ]
+  pub fn validate( self ) -> Result< Self >
+  {
+    if self.is_available()
+    {
+      return Ok( self );
+    }
+    let hint = match self
+    {
+      Self::Rustls => "enable the `tls_rustls` feature",
+      Self::NativeTls => "enable the `tls_native` feature",
+    };
+    Err( OpenAIError::InvalidArgument( format!( "TLS backend {self:?} is not available : {hint}" ) ).into() )
+  }
+
+  /// Configure `builder` to use this backend and no other.
+  ///
+  /// # Errors
+  ///
+  /// Returns `OpenAIError::InvalidArgument` if the backend is not available.
+  #[ inline ]
+  pub fn apply( self, builder : reqwest::ClientBuilder ) -> Result< reqwest::ClientBuilder >
+  {
+    match self
+    {
+      #[ cfg( feature = "tls_rustls" ) ]
+      Self::Rustls => Ok( builder.use_rustls_tls() ),
+      #[ cfg( feature = "tls_native" ) ]
+      Self::NativeTls => Ok( builder.use_native_tls() ),
+      #[ allow( unreachable_patterns ) ]
+      unavailable => unavailable.validate().map( | _ | builder ),
+    }
+  }
+}
+
+impl Default for TlsBackend
+{
+  /// `rustls` when it is compiled in, otherwise the platform library.
+  #[ inline ]
+  fn default() -> Self
+  {
+    if Self::Rustls.is_available() { Self::Rustls } else { Self::NativeTls }
+  }
+}
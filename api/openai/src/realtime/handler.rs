@@ -0,0 +1,94 @@
+//! Typed dispatch of Realtime server events.
+//!
+//! Implement `RealtimeEventHandler` for the events of interest and pass it to
+//! `WsSession::run_with_handler` instead of matching on `RealtimeServerEvent`.
+
+/// Define a private namespace for all its items.
+mod private
+{
+  use crate::components::realtime_shared::
+  {
+    RealtimeServerEvent,
+    RealtimeServerEventError,
+    RealtimeServerEventInputAudioBufferSpeechStarted,
+    RealtimeServerEventInputAudioBufferSpeechStopped,
+    RealtimeServerEventResponseAudioDelta,
+    RealtimeServerEventResponseAudioTranscriptDelta,
+    RealtimeServerEventResponseDone,
+    RealtimeServerEventResponseFunctionCallArgumentsDone,
+    RealtimeServerEventResponseTextDelta,
+  };
+
+  /// Callbacks for Realtime server events.
+  ///
+  /// Every method has an empty default, so implementors override only the events
+  /// they care about. Events without a dedicated callback go to `on_other`.
+  pub trait RealtimeEventHandler
+  {
+    /// Called for each chunk of generated audio (`response.audio.delta`).
+    #[ inline ]
+    fn on_audio_delta( &mut self, _event : &RealtimeServerEventResponseAudioDelta ) {}
+
+    /// Called for each chunk of the generated audio transcript (`response.audio_transcript.delta`).
+    #[ inline ]
+    fn on_audio_transcript_delta( &mut self, _event : &RealtimeServerEventResponseAudioTranscriptDelta ) {}
+
+    /// Called for each chunk of generated text (`response.text.delta`).
+    #[ inline ]
+    fn on_text_delta( &mut self, _event : &RealtimeServerEventResponseTextDelta ) {}
+
+    /// Called when a function call's arguments are complete (`response.function_call_arguments.done`).
+    #[ inline ]
+    fn on_function_call( &mut self, _event : &RealtimeServerEventResponseFunctionCallArgumentsDone ) {}
+
+    /// Called when a response finishes (`response.done`).
+    #[ inline ]
+    fn on_response_done( &mut self, _event : &RealtimeServerEventResponseDone ) {}
+
+    /// Called when voice activity detection reports speech start (`input_audio_buffer.speech_started`).
+    #[ inline ]
+    fn on_speech_started( &mut self, _event : &RealtimeServerEventInputAudioBufferSpeechStarted ) {}
+
+    /// Called when voice activity detection reports speech stop (`input_audio_buffer.speech_stopped`).
+    #[ inline ]
+    fn on_speech_stopped( &mut self, _event : &RealtimeServerEventInputAudioBufferSpeechStopped ) {}
+
+    /// Called for server-reported errors (`error`).
+    #[ inline ]
+    fn on_error( &mut self, _event : &RealtimeServerEventError ) {}
+
+    /// Called for events without a dedicated callback.
+    #[ inline ]
+    fn on_other( &mut self, _event : &RealtimeServerEvent ) {}
+
+    /// Called once when the connection closes.
+    #[ inline ]
+    fn on_close( &mut self ) {}
+
+    /// Dispatches an event to the matching callback.
+    #[ inline ]
+    fn handle_event( &mut self, event : &RealtimeServerEvent )
+    {
+      match event
+      {
+        RealtimeServerEvent::ResponseAudioDelta( e ) => self.on_audio_delta( e ),
+        RealtimeServerEvent::ResponseAudioTranscriptDelta( e ) => self.on_audio_transcript_delta( e ),
+        RealtimeServerEvent::ResponseTextDelta( e ) => self.on_text_delta( e ),
+        RealtimeServerEvent::ResponseFunctionCallArgumentsDone( e ) => self.on_function_call( e ),
+        RealtimeServerEvent::ResponseDone( e ) => self.on_response_done( e ),
+        RealtimeServerEvent::InputAudioBufferSpeechStarted( e ) => self.on_speech_started( e ),
+        RealtimeServerEvent::InputAudioBufferSpeechStopped( e ) => self.on_speech_stopped( e ),
+        RealtimeServerEvent::Error( e ) => self.on_error( e ),
+        other => self.on_other( other ),
+      }
+    }
+  }
+} // end mod private
+
+crate ::mod_interface!
+{
+  exposed use
+  {
+    RealtimeEventHandler,
+  };
+}
@@ -17,6 +17,7 @@ mod private
   use crate::components::realtime_shared:: // Corrected import path
   {
     RealtimeClientEvent,
+    RealtimeResponseCreateParams,
     RealtimeServerEvent,
     RealtimeSession,
     RealtimeSessionCreateRequest,
@@ -34,14 +35,16 @@ mod private
     net ::TcpStream,
     sync ::{ Mutex }, // Mutex is needed here
   };
-  use tokio_tungstenite::{ MaybeTlsStream, WebSocketStream };
+  use tokio_tungstenite::{ MaybeTlsStream, WebSocketStream, tungstenite::Message };
   use futures_util::
   {
+    stream::SplitSink,
     StreamExt as _, // Renamed to avoid conflict
     SinkExt as _, // Renamed to avoid conflict
   };
   use serde_json;
   use std::sync::Arc;
+  use super::handler::RealtimeEventHandler;
 
   /// The client for the `OpenAI` Realtime API.
   #[ derive( Debug, Clone ) ]
@@ -201,8 +204,8 @@ mod private
     pub rx : Arc< tokio::sync::Mutex< tokio::sync::mpsc::Receiver< HandlerMessage > > >, // Changed to Arc< Mutex< Receiver > >
     /// Sender for messages to the WebSocket.
     pub tx : Arc< tokio::sync::mpsc::Sender< HandlerMessage > >,
-    /// The WebSocket stream.
-    pub ws_stream : Arc< tokio::sync::Mutex< WebSocketStream< MaybeTlsStream< TcpStream > > > >,
+    /// The sending half of the WebSocket stream; the reader task owns the receiving half.
+    pub ws_stream : Arc< tokio::sync::Mutex< SplitSink< WebSocketStream< MaybeTlsStream< TcpStream > >, Message > > >,
   }
 
   impl WsSession
@@ -221,52 +224,38 @@ mod private
       .await
       .map_err( | e | OpenAIError::Ws( e.to_string() ) )?; // Convert error to String
 
-      let ws_stream_arc = Arc::new( tokio::sync::Mutex::new( ws_stream ) );
+      let ( ws_sink, mut ws_reader ) = ws_stream.split();
+      let ws_stream_arc = Arc::new( tokio::sync::Mutex::new( ws_sink ) );
       let ( tx, rx ) = mpsc::channel( 100 );
       let rx_arc = Arc::new( Mutex::new( rx ) ); // Wrap rx in Arc< Mutex >
       let tx_arc = Arc::new( tx ); // Wrap tx in Arc
 
-      let ws_stream_locked = Arc::< _ >::clone( &ws_stream_arc );
       let tx_clone = Arc::< _ >::clone( &tx_arc ); // Clone the Arc< Sender >
 
       tokio ::spawn( async move
       {
-        let mut ws_stream_locked = ws_stream_locked.lock().await;
         loop
         {
-          tokio ::select!
+          match ws_reader.next().await
           {
-            // Receive messages from the WebSocket
-            msg = ws_stream_locked.next() =>
+            Some( Ok( msg ) ) =>
             {
-              match msg
+              if msg.is_text()
               {
-                Some( Ok( msg ) ) =>
-                {
-                  if msg.is_text()
-                  {
-                    let message = msg.to_string();
-                    let _ = tx_clone.send( HandlerMessage::Message( message ) ).await.ok();
-                  }
-                },
-                Some( Err( error ) ) =>
-                {
-                  let _ = tx_clone.send( HandlerMessage::Error( OpenAIError::Ws( error.to_string() ) ) ).await.ok(); // Convert error to String
-                  break;
-                },
-                None =>
-                {
-                  let _ = tx_clone.send( HandlerMessage::Closed ).await.ok();
-                  break;
-                },
+                let message = msg.to_string();
+                let _ = tx_clone.send( HandlerMessage::Message( message ) ).await.ok();
               }
             },
-            // Handle messages to send (if any, though this is primarily a receiver)
-            // This branch is mostly for demonstration or future expansion
-            _unit = tokio::time::sleep( tokio::time::Duration::from_secs( 1 ) ) =>
+            Some( Err( error ) ) =>
             {
-              // Periodically check or send keep-alive if needed
-            }
+              let _ = tx_clone.send( HandlerMessage::Error( OpenAIError::Ws( error.to_string() ) ) ).await.ok(); // Convert error to String
+              break;
+            },
+            None =>
+            {
+              let _ = tx_clone.send( HandlerMessage::Closed ).await.ok();
+              break;
+            },
           }
         }
       });
@@ -292,14 +281,94 @@ mod private
       let message = serde_json::to_string( &event )
       .map_err( | e | OpenAIError::Internal( format!( "Serialization error : {e}" ) ) )?;
       let mut ws_stream_locked = self.ws_stream.lock().await;
-      ws_stream_locked.send( tokio_tungstenite::tungstenite::Message::Text( message.into() ) ) // Convert String to Utf8Bytes
+      ws_stream_locked.send( Message::Text( message.into() ) ) // Convert String to Utf8Bytes
       .await
       .map_err( | e | OpenAIError::Ws( e.to_string() ) )?; // Convert error to String
       Ok( () )
     }
 
+    /// Appends audio to the input audio buffer.
+    ///
+    /// # Arguments
+    /// - `pcm`: Raw audio in the session's input format; it is base64-encoded before sending.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::Ws` if sending fails.
+    #[ inline ]
+    pub async fn append_audio( &self, pcm : &[ u8 ] ) -> Result< () >
+    {
+      self.send_event( RealtimeClientEvent::append_audio( pcm ) ).await
+    }
+
+    /// Commits the input audio buffer, creating a user message.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::Ws` if sending fails.
+    #[ inline ]
+    pub async fn commit_audio( &self ) -> Result< () >
+    {
+      self.send_event( RealtimeClientEvent::commit_audio() ).await
+    }
+
+    /// Asks the model to generate a response.
+    ///
+    /// # Arguments
+    /// - `params`: Optional overrides for this response; `None` uses the session defaults.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::Ws` if sending fails.
+    #[ inline ]
+    pub async fn request_response( &self, params : Option< RealtimeResponseCreateParams > ) -> Result< () >
+    {
+      self.send_event( RealtimeClientEvent::request_response( params ) ).await
+    }
+
+    /// Receives server events and dispatches them to `handler` until the connection closes.
+    ///
+    /// `handler.on_close` is called once the connection closes normally. Events of a
+    /// type this crate does not know are skipped.
+    ///
+    /// # Arguments
+    /// - `handler`: The callbacks to invoke for each event.
+    ///
+    /// # Errors
+    /// Returns `OpenAIError::Ws` on a WebSocket error and `OpenAIError::Internal` if an event cannot be deserialized.
+    #[ inline ]
+    pub async fn run_with_handler< H >( &self, handler : &mut H ) -> Result< () >
+    where
+      H : RealtimeEventHandler + ?Sized,
+    {
+      let mut rx = self.rx.lock().await;
+      loop
+      {
+        match rx.recv().await
+        {
+          Some( HandlerMessage::Message( message ) ) =>
+          {
+            let event = serde_json::from_str::< RealtimeServerEvent >( &message )
+              .map_err( | e | OpenAIError::Internal( format!( "Deserialization error : {e}" ) ) )?;
+            // Newer server event types must not end the session
+            if matches!( event, RealtimeServerEvent::Unknown )
+            {
+              tracing::trace!( "Skipping unknown realtime server event" );
+              continue;
+            }
+            handler.handle_event( &event );
+          },
+          Some( HandlerMessage::Error( error ) ) => return Err( error.into() ),
+          Some( HandlerMessage::Closed ) | None =>
+          {
+            handler.on_close();
+            return Ok( () );
+          },
+        }
+      }
+    }
+
     /// Receives a server event message from the WebSocket.
     ///
+    /// Events of a type this crate does not know are returned as `RealtimeServerEvent::Unknown`.
+    ///
     /// # Errors
     /// Returns `OpenAIError::Internal` if deserialization fails or if the channel is closed.
     #[ inline ]
@@ -328,4 +397,6 @@ crate ::mod_interface!
     WsSession,
     HandlerMessage,
   };
+
+  layer handler;
 }
\ No newline at end of file
@@ -33,6 +33,12 @@ mod private
     pub max_batch_size : usize,
     /// Maximum time to wait before flushing a partial batch
     pub flush_timeout : Duration,
+    /// Quiet period after the most recent enqueue before a partial batch is flushed
+    ///
+    /// Unlike `flush_timeout`, which is only checked when a request is enqueued,
+    /// this is timer-driven: each enqueue restarts the timer for its signature, so
+    /// a partially filled batch is flushed even when no further requests arrive.
+    pub max_batch_delay : Duration,
     /// Maximum concurrent batches processing
     pub max_concurrent_batches : usize,
     /// Enable smart batching for similar requests
@@ -50,6 +56,7 @@ mod private
       {
         max_batch_size : 100,
         flush_timeout : Duration::from_millis( 50 ),
+        max_batch_delay : Duration::from_millis( 100 ),
         max_concurrent_batches : 10,
         enable_smart_batching : true,
         smart_batch_threshold : 5,
@@ -247,6 +254,9 @@ mod private
       // Start batch processor if needed
       self.ensure_batch_processor_running().await;
 
+      // Flush a partial batch once no further requests arrive
+      self.schedule_delayed_flush( signature );
+
       // Wait for response
       rx.await.map_err( | _ | crate::error::OpenAIError::Internal( "Batch processing failed".to_string() ) )?
     }
@@ -266,6 +276,38 @@ mod private
       }
     }
 
+    /// Flush the signature's partial batch after `max_batch_delay` without a newer enqueue
+    ///
+    /// Every enqueue schedules its own timer; a timer whose request is no longer
+    /// the newest in the queue does nothing, which restarts the delay on each enqueue.
+    fn schedule_delayed_flush( &self, signature : RequestSignature )
+    {
+      let pending_requests = Arc::clone( &self.pending_requests );
+      let metrics = Arc::clone( &self.metrics );
+      let config = self.config.clone();
+
+      tokio ::spawn( async move
+      {
+        tokio ::time::sleep( config.max_batch_delay ).await;
+
+        let batch = {
+          let mut pending = pending_requests.write().await;
+          let Some( queue ) = pending.get_mut( &signature ) else { return };
+          if !queue.back().is_some_and( | req | req.queued_at.elapsed() >= config.max_batch_delay )
+          {
+            return;
+          }
+          let count = config.max_batch_size.min( queue.len() );
+          queue.drain( ..count ).collect::< Vec< _ > >()
+        };
+
+        if !batch.is_empty()
+        {
+          Self::dispatch_batch( &signature, batch, &metrics ).await;
+        }
+      } );
+    }
+
     /// Ensure batch processor is running
     async fn ensure_batch_processor_running( &self )
     {
@@ -299,29 +341,7 @@ mod private
 
             if let Some( ( signature, requests ) ) = batch_to_process
             {
-              let start_time = Instant::now();
-              let batch_size = requests.len();
-
-              // Process the batch
-              let _results = Self::process_batch_requests( &signature, requests );
-              let processing_time = start_time.elapsed();
-
-              // Update metrics
-              {
-                let mut metrics_guard = metrics.write().await;
-                metrics_guard.total_requests += batch_size as u64;
-                metrics_guard.total_batches += 1;
-                metrics_guard.avg_batch_size = ( metrics_guard.avg_batch_size * ( metrics_guard.total_batches - 1 ) as f64 + batch_size as f64 ) / metrics_guard.total_batches as f64;
-                metrics_guard.http_requests_saved += ( batch_size as u64 ).saturating_sub( 1 );
-                let new_avg_nanos = ( metrics_guard.avg_batch_time.as_nanos() * u128::from( metrics_guard.total_batches - 1 ) +
-                  processing_time.as_nanos() ) / u128::from( metrics_guard.total_batches );
-                let bounded_nanos = new_avg_nanos.min( u128::from( u64::MAX ) );
-                metrics_guard.avg_batch_time = Duration::from_nanos( u64::try_from( bounded_nanos ).unwrap_or( u64::MAX ) );
-                if metrics_guard.total_requests > 0
-                {
-                  metrics_guard.efficiency_improvement = metrics_guard.http_requests_saved as f64 / metrics_guard.total_requests as f64;
-                }
-              }
+              Self::dispatch_batch( &signature, requests, &metrics ).await;
             }
             else
             {
@@ -339,6 +359,36 @@ mod private
       }
     }
 
+    /// Process a batch and record it in the metrics
+    async fn dispatch_batch(
+      signature : &RequestSignature,
+      requests : Vec< BatchedRequest< T > >,
+      metrics : &RwLock< BatchMetrics >,
+    )
+    {
+      let start_time = Instant::now();
+      let batch_size = requests.len();
+
+      // Process the batch
+      let _results = Self::process_batch_requests( signature, requests );
+      let processing_time = start_time.elapsed();
+
+      // Update metrics
+      let mut metrics_guard = metrics.write().await;
+      metrics_guard.total_requests += batch_size as u64;
+      metrics_guard.total_batches += 1;
+      metrics_guard.avg_batch_size = ( metrics_guard.avg_batch_size * ( metrics_guard.total_batches - 1 ) as f64 + batch_size as f64 ) / metrics_guard.total_batches as f64;
+      metrics_guard.http_requests_saved += ( batch_size as u64 ).saturating_sub( 1 );
+      let new_avg_nanos = ( metrics_guard.avg_batch_time.as_nanos() * u128::from( metrics_guard.total_batches - 1 ) +
+        processing_time.as_nanos() ) / u128::from( metrics_guard.total_batches );
+      let bounded_nanos = new_avg_nanos.min( u128::from( u64::MAX ) );
+      metrics_guard.avg_batch_time = Duration::from_nanos( u64::try_from( bounded_nanos ).unwrap_or( u64::MAX ) );
+      if metrics_guard.total_requests > 0
+      {
+        metrics_guard.efficiency_improvement = metrics_guard.http_requests_saved as f64 / metrics_guard.total_requests as f64;
+      }
+    }
+
     /// Extract ready batch for processing
     fn extract_ready_batch(
       pending : &mut HashMap< RequestSignature, VecDeque< BatchedRequest< T > > >,
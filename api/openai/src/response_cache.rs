@@ -84,6 +84,8 @@ mod private
     pub method : String,
     /// Request path that generated this cache entry
     pub path : String,
+    /// `ETag` returned with the response, used to revalidate the entry once it expires
+    pub etag : Option< String >,
   }
 
   impl CacheEntry
@@ -102,6 +104,17 @@ mod private
     {
       self.hit_count += 1;
     }
+
+    /// Whether this entry is still worth keeping
+    ///
+    /// Expired entries with an `ETag` are kept so they can be revalidated with a
+    /// conditional request instead of downloaded again.
+    #[ inline ]
+    #[ must_use ]
+    pub fn is_retained( &self ) -> bool
+    {
+      !self.is_expired() || self.etag.is_some()
+    }
   }
 
   /// Cache key generation and management
@@ -287,10 +300,13 @@ mod private
           return Some( entry.data.clone() );
         }
 
-        // Remove expired entry
-        cache.remove( &key_str );
-        stats.current_entries = cache.len();
-        stats.expired_entries_cleaned += 1;
+        // Remove expired entry unless it can be revalidated
+        if !entry.is_retained()
+        {
+          cache.remove( &key_str );
+          stats.current_entries = cache.len();
+          stats.expired_entries_cleaned += 1;
+        }
       }
 
       stats.cache_misses += 1;
@@ -305,6 +321,20 @@ mod private
     /// Returns an error if the response data is too large for caching.
     #[ inline ]
     pub async fn put( &self, key : &CacheKey, data : Vec< u8 >, ttl : Option< Duration > ) -> Result< () >
+    {
+      self.put_with_etag( key, data, ttl, None ).await
+    }
+
+    /// Store response in cache with TTL and the `ETag` it was served with
+    ///
+    /// Once the TTL elapses the entry is kept, and [`Self::stale_etag`] offers the
+    /// `ETag` for a conditional request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the response data is too large for caching.
+    #[ inline ]
+    pub async fn put_with_etag( &self, key : &CacheKey, data : Vec< u8 >, ttl : Option< Duration >, etag : Option< String > ) -> Result< () >
     {
       let data_size = data.len();
 
@@ -330,6 +360,7 @@ mod private
         hit_count : 0,
         method : key.method.clone(),
         path : key.path.clone(),
+        etag,
       };
 
       let mut cache = self.cache.write().await;
@@ -354,6 +385,37 @@ mod private
       Ok( () )
     }
 
+    /// `ETag` of an expired entry that can be revalidated
+    ///
+    /// Returns `None` when the entry is missing, still fresh, or has no `ETag`.
+    #[ inline ]
+    pub async fn stale_etag( &self, key : &CacheKey ) -> Option< String >
+    {
+      let cache = self.cache.read().await;
+      cache.get( &key.to_cache_key() )
+      .filter( | entry | entry.is_expired() )
+      .and_then( | entry | entry.etag.clone() )
+    }
+
+    /// Restart the TTL of an entry confirmed unchanged by a `304 Not Modified` response
+    ///
+    /// Returns the cached data, or `None` if the entry was evicted in the meantime.
+    #[ inline ]
+    pub async fn refresh( &self, key : &CacheKey, ttl : Option< Duration > ) -> Option< Vec< u8 > >
+    {
+      let mut cache = self.cache.write().await;
+      let mut stats = self.stats.write().await;
+
+      let entry = cache.get_mut( &key.to_cache_key() )?;
+      entry.created_at = Instant::now();
+      entry.ttl = ttl.unwrap_or( self.config.default_ttl );
+      entry.record_hit();
+
+      stats.cache_hits += 1;
+      stats.hit_ratio = stats.cache_hits as f64 / stats.total_requests.max( 1 ) as f64;
+      Some( entry.data.clone() )
+    }
+
     /// Clear all cached entries
     #[ inline ]
     pub async fn clear( &self )
@@ -382,7 +444,7 @@ mod private
       let mut stats = self.stats.write().await;
 
       let initial_count = cache.len();
-      cache.retain( | _, entry | !entry.is_expired() );
+      cache.retain( | _, entry | entry.is_retained() );
       let final_count = cache.len();
       let cleaned_count = initial_count - final_count;
 
@@ -414,7 +476,7 @@ mod private
           let mut stats_guard = stats.write().await;
 
           let initial_count = cache_guard.len();
-          cache_guard.retain( | _, entry | !entry.is_expired() );
+          cache_guard.retain( | _, entry | entry.is_retained() );
           let final_count = cache_guard.len();
           let cleaned_count = initial_count - final_count;
 
@@ -508,19 +570,28 @@ mod private
       // Try cache first
       if let Some( cached_data ) = self.cache.get( &cache_key ).await
       {
-        let result : T = serde_json::from_slice( &cached_data )
-          .map_err( | e | OpenAIError::Internal( format!( "Failed to deserialize cached response : {e}" ) ) )?;
-        return Ok( result );
+        return Self::deserialize_cached( &cached_data );
       }
 
-      // Cache miss - make actual request
-      let response : T = self.client.get( path ).await?;
+      // Cache miss - revalidate a stale entry if the server gave it an ETag
+      let etag = self.cache.stale_etag( &cache_key ).await;
+      let ( bytes, etag ) = match self.client.get_conditional( path, etag.as_deref() ).await?
+      {
+        Some( response ) => response,
+        None => match self.cache.refresh( &cache_key, ttl ).await
+        {
+          Some( cached_data ) => return Self::deserialize_cached( &cached_data ),
+          // Entry evicted since the lookup - fetch it unconditionally
+          None => self.client.get_conditional( path, None ).await?
+            .ok_or_else( || OpenAIError::Internal( "Unexpected 304 Not Modified for an unconditional request".to_string() ) )?,
+        },
+      };
+
+      let response : T = serde_json::from_slice( &bytes )
+        .map_err( | e | OpenAIError::Internal( format!( "Failed to parse JSON response : {e}" ) ) )?;
 
       // Cache the response
-      if let Ok( serialized ) = serde_json::to_vec( &response )
-      {
-        let _ = self.cache.put( &cache_key, serialized, ttl ).await;
-      }
+      let _ = self.cache.put_with_etag( &cache_key, bytes, ttl, etag ).await;
 
       Ok( response )
     }
@@ -564,6 +635,16 @@ mod private
       Ok( response )
     }
 
+    /// Deserialize a cached response
+    fn deserialize_cached< T >( data : &[ u8 ] ) -> Result< T >
+    where
+      T: serde::de::DeserializeOwned,
+    {
+      let result = serde_json::from_slice( data )
+        .map_err( | e | OpenAIError::Internal( format!( "Failed to deserialize cached response : {e}" ) ) )?;
+      Ok( result )
+    }
+
     /// Get cache statistics
     #[ inline ]
     pub async fn get_cache_statistics( &self ) -> CacheStatistics
@@ -630,6 +711,7 @@ mod private
         hit_count : 0,
         method : "GET".to_string(),
         path : "/test".to_string(),
+        etag : None,
       };
 
       assert!( entry.is_expired() );
@@ -23,7 +23,7 @@ mod private
   };
   use tokio::
   {
-    sync ::{ RwLock, Semaphore },
+    sync ::{ RwLock, Semaphore, mpsc },
   };
   use serde::{ Serialize, Deserialize };
 
@@ -659,6 +659,194 @@ mod private
     }
   }
 
+  /// Distribution of the gaps between consecutive stream chunks
+  #[ derive( Debug, Clone, PartialEq, Eq, Serialize, Deserialize ) ]
+  pub struct LatencyDistribution
+  {
+    /// Number of gaps measured
+    pub samples : usize,
+    /// Shortest gap
+    pub min : Duration,
+    /// Longest gap
+    pub max : Duration,
+    /// Mean gap
+    pub mean : Duration,
+    /// Median gap
+    pub p50 : Duration,
+    /// 95th percentile gap
+    pub p95 : Duration,
+    /// 99th percentile gap
+    pub p99 : Duration,
+  }
+
+  impl LatencyDistribution
+  {
+    /// Summarize gaps, `None` when there are none
+    #[ inline ]
+    #[ must_use ]
+    pub fn from_samples( mut samples : Vec< Duration > ) -> Option< Self >
+    {
+      if samples.is_empty()
+      {
+        return None;
+      }
+      samples.sort_unstable();
+
+      let count = samples.len();
+      let total : Duration = samples.iter().sum();
+      // Nearest-rank percentile
+      #[ allow( clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss ) ]
+      let percentile = | p : f64 | samples[ ( ( p * count as f64 ).ceil() as usize ).clamp( 1, count ) - 1 ];
+
+      Some( Self
+      {
+        samples : count,
+        min : samples[ 0 ],
+        max : samples[ count - 1 ],
+        mean : total / u32::try_from( count ).unwrap_or( u32::MAX ),
+        p50 : percentile( 0.50 ),
+        p95 : percentile( 0.95 ),
+        p99 : percentile( 0.99 ),
+      } )
+    }
+  }
+
+  /// Timing of a single consumed stream
+  #[ derive( Debug, Clone, PartialEq, Eq, Serialize, Deserialize ) ]
+  pub struct StreamPerfReport
+  {
+    /// Number of chunks received
+    pub chunks : usize,
+    /// Time from the start of the request to the first chunk, `None` if no chunk arrived
+    pub time_to_first_token : Option< Duration >,
+    /// Gaps between consecutive chunks, `None` with fewer than two chunks
+    pub inter_token_latency : Option< LatencyDistribution >,
+    /// Time from the start of the request to the end of the stream
+    pub total_duration : Duration,
+  }
+
+  /// Records chunk arrival times for a [`StreamPerfReport`]
+  ///
+  /// Collection is opt-in and per stream: start a recorder when the request is
+  /// sent, call [`Self::record_chunk`] as each chunk is consumed, and build the
+  /// report when the stream ends. [`MeasuredReceiver`] does this for a channel.
+  #[ derive( Debug, Clone ) ]
+  pub struct StreamPerfRecorder
+  {
+    started_at : Instant,
+    chunk_times : Vec< Instant >,
+  }
+
+  impl StreamPerfRecorder
+  {
+    /// Start recording now
+    #[ inline ]
+    #[ must_use ]
+    pub fn new() -> Self
+    {
+      Self::start_at( Instant::now() )
+    }
+
+    /// Start recording from `started_at`, e.g. when the request was sent
+    #[ inline ]
+    #[ must_use ]
+    pub fn start_at( started_at : Instant ) -> Self
+    {
+      Self { started_at, chunk_times : Vec::new() }
+    }
+
+    /// Record a chunk received now
+    #[ inline ]
+    pub fn record_chunk( &mut self )
+    {
+      self.record_chunk_at( Instant::now() );
+    }
+
+    /// Record a chunk received at `received_at`
+    #[ inline ]
+    pub fn record_chunk_at( &mut self, received_at : Instant )
+    {
+      self.chunk_times.push( received_at );
+    }
+
+    /// Report with the stream ending now
+    #[ inline ]
+    #[ must_use ]
+    pub fn report( &self ) -> StreamPerfReport
+    {
+      self.report_at( Instant::now() )
+    }
+
+    /// Report with the stream ending at `finished_at`
+    #[ inline ]
+    #[ must_use ]
+    pub fn report_at( &self, finished_at : Instant ) -> StreamPerfReport
+    {
+      let gaps = self.chunk_times.windows( 2 ).map( | pair | pair[ 1 ].saturating_duration_since( pair[ 0 ] ) ).collect();
+
+      StreamPerfReport
+      {
+        chunks : self.chunk_times.len(),
+        time_to_first_token : self.chunk_times.first().map( | first | first.saturating_duration_since( self.started_at ) ),
+        inter_token_latency : LatencyDistribution::from_samples( gaps ),
+        total_duration : finished_at.saturating_duration_since( self.started_at ),
+      }
+    }
+  }
+
+  impl Default for StreamPerfRecorder
+  {
+    #[ inline ]
+    fn default() -> Self
+    {
+      Self::new()
+    }
+  }
+
+  /// Stream receiver that records chunk timing as it is consumed
+  ///
+  /// Wrap the receiver returned by a `create_stream` call right away so the time
+  /// to first token covers the wait for the first chunk.
+  #[ derive( Debug ) ]
+  pub struct MeasuredReceiver< T >
+  {
+    receiver : mpsc::Receiver< T >,
+    recorder : StreamPerfRecorder,
+    finished_at : Option< Instant >,
+  }
+
+  impl< T > MeasuredReceiver< T >
+  {
+    /// Start measuring `receiver` now
+    #[ inline ]
+    #[ must_use ]
+    pub fn new( receiver : mpsc::Receiver< T > ) -> Self
+    {
+      Self { receiver, recorder : StreamPerfRecorder::new(), finished_at : None }
+    }
+
+    /// Receive the next chunk, recording its arrival
+    #[ inline ]
+    pub async fn recv( &mut self ) -> Option< T >
+    {
+      let item = self.receiver.recv().await;
+      match item
+      {
+        Some( _ ) => self.recorder.record_chunk(),
+        None => { self.finished_at.get_or_insert_with( Instant::now ); },
+      }
+      item
+    }
+
+    /// Timing so far, or of the whole stream once it has ended
+    #[ inline ]
+    #[ must_use ]
+    pub fn report( &self ) -> StreamPerfReport
+    {
+      self.recorder.report_at( self.finished_at.unwrap_or_else( Instant::now ) )
+    }
+  }
+
   /// Enhanced streaming processor with performance optimizations
   #[ derive( Debug ) ]
   pub struct StreamingProcessor
@@ -878,6 +1066,10 @@ crate ::mod_interface!
   exposed use private::ConnectionGuard;
   exposed use private::StreamingProcessor;
   exposed use private::ProcessingStats;
+  exposed use private::LatencyDistribution;
+  exposed use private::StreamPerfReport;
+  exposed use private::StreamPerfRecorder;
+  exposed use private::MeasuredReceiver;
   exposed use private::get_streaming_processor;
   exposed use private::configure_streaming_processor;
   exposed use private::process_event;
@@ -19,7 +19,7 @@ mod private
   use std::
   {
     collections ::VecDeque,
-    sync ::{ Arc, Mutex },
+    sync ::{ Arc, Mutex, atomic::{ AtomicU64, Ordering } },
     time ::Instant,
   };
   use tokio::
@@ -858,6 +858,231 @@ mod private
     }
   }
 
+  /// Callback invoked after each successful reconnect, with the total reconnect count
+  pub type ReconnectCallback = Arc< dyn Fn( u64 ) + Send + Sync >;
+
+  /// Realtime session that reconnects transparently when the connection drops
+  ///
+  /// Every session setup event (`session.update`, `transcription_session.update`)
+  /// sent through the session is remembered and replayed in order on each new
+  /// connection, so the server sees the same configuration as before the drop.
+  /// Conversation events are not buffered: an event whose send fails is resent
+  /// once after reconnecting, but events already sent are not replayed.
+  ///
+  /// Reconnects use `max_reconnection_attempts`, `initial_reconnection_delay`,
+  /// `max_reconnection_delay`, `connection_timeout` and `enable_auto_reconnect`
+  /// from [`WebSocketReliabilityConfig`].
+  pub struct ReliableWsSession
+  {
+    url : String,
+    config : WebSocketReliabilityConfig,
+    /// Current connection, tagged with a generation bumped on every reconnect
+    session : RwLock< ( u64, WsSession ) >,
+    setup_events : Mutex< Vec< RealtimeClientEvent > >,
+    reconnects : AtomicU64,
+    on_reconnect : Option< ReconnectCallback >,
+  }
+
+  impl core::fmt::Debug for ReliableWsSession
+  {
+    #[ inline ]
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      f.debug_struct( "ReliableWsSession" )
+        .field( "url", &self.url )
+        .field( "config", &self.config )
+        .field( "reconnects", &self.reconnect_count() )
+        .field( "on_reconnect", &self.on_reconnect.is_some() )
+        .finish_non_exhaustive()
+    }
+  }
+
+  impl ReliableWsSession
+  {
+    /// Connect to `url`, reconnecting on later drops according to `config`
+    ///
+    /// # Errors
+    ///
+    /// Returns `OpenAIError::Ws` if the initial connection fails or times out.
+    #[ inline ]
+    pub async fn connect( url : impl Into< String >, config : WebSocketReliabilityConfig ) -> Result< Self >
+    {
+      let url = url.into();
+      let session = open_session( &url, config.connection_timeout ).await?;
+      Ok( Self
+      {
+        url,
+        config,
+        session : RwLock::new( ( 0, session ) ),
+        setup_events : Mutex::new( Vec::new() ),
+        reconnects : AtomicU64::new( 0 ),
+        on_reconnect : None,
+      })
+    }
+
+    /// Set a callback invoked after each successful reconnect
+    #[ inline ]
+    #[ must_use ]
+    pub fn on_reconnect< F >( mut self, callback : F ) -> Self
+    where
+      F : Fn( u64 ) + Send + Sync + 'static,
+    {
+      self.on_reconnect = Some( Arc::new( callback ) );
+      self
+    }
+
+    /// Number of reconnects performed since `connect`
+    #[ inline ]
+    #[ must_use ]
+    pub fn reconnect_count( &self ) -> u64
+    {
+      self.reconnects.load( Ordering::Relaxed )
+    }
+
+    /// Send a client event, reconnecting if the connection has dropped
+    ///
+    /// Session setup events are remembered for replay after later reconnects.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails, or if the connection dropped and
+    /// could not be re-established within `max_reconnection_attempts`.
+    #[ inline ]
+    pub async fn send_event( &self, event : RealtimeClientEvent ) -> Result< () >
+    {
+      let ( generation, session ) = self.current().await;
+      match session.send_event( event.clone() ).await
+      {
+        Ok( () ) =>
+        {
+          self.remember( event );
+          Ok( () )
+        },
+        Err( error ) if is_disconnect( &error ) =>
+        {
+          let setup = event.is_session_setup();
+          if setup
+          {
+            self.remember( event.clone() );
+          }
+          // A setup event is part of the replay when this call performed the reconnect
+          let replayed = self.reconnect_from( generation, error ).await? && setup;
+          if !replayed
+          {
+            self.current().await.1.send_event( event ).await?;
+          }
+          Ok( () )
+        },
+        Err( error ) => Err( error ),
+      }
+    }
+
+    /// Receive the next server event, reconnecting if the connection has dropped
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deserialization fails, or if the connection dropped and
+    /// could not be re-established within `max_reconnection_attempts`.
+    #[ inline ]
+    pub async fn recv_event( &self ) -> Result< crate::components::realtime_shared::RealtimeServerEvent >
+    {
+      loop
+      {
+        let ( generation, session ) = self.current().await;
+        match session.recv_event().await
+        {
+          Err( error ) if is_disconnect( &error ) =>
+          {
+            self.reconnect_from( generation, error ).await?;
+          },
+          other => return other,
+        }
+      }
+    }
+
+    async fn current( &self ) -> ( u64, WsSession )
+    {
+      self.session.read().await.clone()
+    }
+
+    fn remember( &self, event : RealtimeClientEvent )
+    {
+      if event.is_session_setup()
+      {
+        self.setup_events.lock().unwrap_or_else( std::sync::PoisonError::into_inner ).push( event );
+      }
+    }
+
+    /// Replace the connection of `generation` with a new one
+    ///
+    /// Returns `false` without reconnecting if another caller already replaced it.
+    async fn reconnect_from( &self, generation : u64, error : error_tools::Error ) -> Result< bool >
+    {
+      if !self.config.enable_auto_reconnect
+      {
+        return Err( error );
+      }
+
+      let mut current = self.session.write().await;
+      if current.0 != generation
+      {
+        return Ok( false );
+      }
+
+      let mut attempts = 0;
+      let mut delay = self.config.initial_reconnection_delay;
+      let session = loop
+      {
+        attempts += 1;
+        match self.open_and_replay().await
+        {
+          Ok( session ) => break session,
+          Err( error ) if attempts >= self.config.max_reconnection_attempts =>
+          {
+            return Err( OpenAIError::Ws( format!( "Reconnect failed after {attempts} attempts : {error}" ) ).into() );
+          },
+          Err( _ ) =>
+          {
+            sleep( delay ).await;
+            delay = core::cmp::min( delay * 2, self.config.max_reconnection_delay );
+          },
+        }
+      };
+      *current = ( generation + 1, session );
+      drop( current );
+
+      let count = self.reconnects.fetch_add( 1, Ordering::Relaxed ) + 1;
+      if let Some( callback ) = &self.on_reconnect
+      {
+        callback( count );
+      }
+      Ok( true )
+    }
+
+    async fn open_and_replay( &self ) -> Result< WsSession >
+    {
+      let session = open_session( &self.url, self.config.connection_timeout ).await?;
+      let setup_events = self.setup_events.lock().unwrap_or_else( std::sync::PoisonError::into_inner ).clone();
+      for event in setup_events
+      {
+        session.send_event( event ).await?;
+      }
+      Ok( session )
+    }
+  }
+
+  async fn open_session( url : &str, connection_timeout : Duration ) -> Result< WsSession >
+  {
+    timeout( connection_timeout, WsSession::connect( url ) ).await
+      .map_err( |_| error_tools::Error::from( OpenAIError::Ws( format!( "Connection timeout after {connection_timeout:?}" ) ) ) )?
+  }
+
+  /// Whether `error` means the connection is gone, as opposed to a bad message
+  fn is_disconnect( error : &error_tools::Error ) -> bool
+  {
+    matches!( error.downcast_ref::< OpenAIError >(), Some( OpenAIError::Ws( _ ) ) )
+  }
+
   /// Global configuration for WebSocket reliability
   static GLOBAL_CONFIG : std::sync::OnceLock< WebSocketReliabilityConfig > = std::sync::OnceLock::new();
 
@@ -899,6 +1124,8 @@ crate ::mod_interface!
   exposed use private::ConnectionState;
   exposed use private::BufferedMessage;
   exposed use private::ReliableWebSocketSession;
+  exposed use private::ReliableWsSession;
+  exposed use private::ReconnectCallback;
   exposed use private::get_global_config;
   exposed use private::set_global_config;
   exposed use private::create_reliable_session;
@@ -0,0 +1,159 @@
+//! Assistants tool output submission tests
+//!
+//! Tests `Assistants::submit_tool_outputs_streaming` against a local HTTP server
+//! that serves a run in `requires_action` with one pending tool call.
+
+use api_openai::ClientApiAccessors;
+use api_openai::exposed::
+{
+  environment ::OpenaiEnvironmentImpl,
+  secret ::Secret,
+  client ::Client,
+};
+use api_openai::components::assistants_shared::{ RunObject, RunStreamEvent, ToolOutput };
+use std::sync::{ Arc, Mutex };
+use tokio::io::{ AsyncReadExt as _, AsyncWriteExt as _ };
+use tokio::net::TcpListener;
+
+fn run_json( status : &str, required_action : &str ) -> String
+{
+  format!(
+    r#"{{ "id" : "run_1", "object" : "thread.run", "created_at" : 1700000000, "thread_id" : "thread_1", "assistant_id" : "asst_1", "status" : "{status}", "required_action" : {required_action}, "model" : "gpt-4o", "tools" : [], "parallel_tool_calls" : true }}"#
+  )
+}
+
+fn requires_action_run() -> String
+{
+  run_json( "requires_action", r#"{ "type" : "submit_tool_outputs", "submit_tool_outputs" : { "tool_calls" : [ { "id" : "call_weather", "type" : "function", "function" : { "name" : "get_weather", "arguments" : "{\"city\":\"Paris\"}" } } ] } }"# )
+}
+
+/// Serves the run on GET and a resumed run stream on POST, recording POST bodies.
+async fn spawn_server( run : String ) -> ( String, Arc< Mutex< Vec< String > > > )
+{
+  let listener = TcpListener::bind( "127.0.0.1:0" ).await.unwrap();
+  let base_url = format!( "http://{}/v1/", listener.local_addr().unwrap() );
+  let posted = Arc::new( Mutex::new( Vec::new() ) );
+  let recorded = Arc::clone( &posted );
+
+  tokio::spawn( async move
+  {
+    loop
+    {
+      let Ok( ( mut socket, _ ) ) = listener.accept().await else { return };
+      let mut buffer = Vec::new();
+      let mut chunk = [ 0_u8; 4096 ];
+      let ( head, body ) = loop
+      {
+        let read = socket.read( &mut chunk ).await.unwrap();
+        buffer.extend_from_slice( &chunk[ ..read ] );
+        let text = String::from_utf8_lossy( &buffer ).to_string();
+        if let Some( end ) = text.find( "\r\n\r\n" )
+        {
+          let head = text[ ..end ].to_lowercase();
+          let length = head.lines()
+            .find_map( | line | line.strip_prefix( "content-length:" ) )
+            .map_or( 0, | value | value.trim().parse::< usize >().unwrap() );
+          if text.len() >= end + 4 + length || read == 0
+          {
+            break ( head, text[ end + 4.. ].to_string() );
+          }
+        }
+      };
+
+      let response = if head.starts_with( "get " )
+      {
+        format!( "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{run}", run.len() )
+      }
+      else
+      {
+        recorded.lock().unwrap().push( body );
+        let completed = run_json( "completed", "null" );
+        let events = format!(
+          "event: thread.run.queued\ndata: {}\n\nevent: thread.message.delta\ndata: {{\"id\":\"msg_1\"}}\n\nevent: thread.run.completed\ndata: {completed}\n\nevent: done\ndata: [DONE]\n\n",
+          run_json( "queued", "null" ),
+        );
+        format!( "HTTP/1.1 200 OK\r\ncontent-type: text/event-stream\r\nconnection: close\r\n\r\n{events}" )
+      };
+      socket.write_all( response.as_bytes() ).await.unwrap();
+      socket.shutdown().await.ok();
+    }
+  });
+
+  ( base_url, posted )
+}
+
+fn client( base_url : String ) -> Client< OpenaiEnvironmentImpl >
+{
+  let environment = OpenaiEnvironmentImpl::build
+  (
+    Secret::new_unchecked( "sk-test".to_string() ),
+    None,
+    None,
+    base_url,
+    "wss://127.0.0.1/v1/realtime/".to_string(),
+  ).unwrap();
+  Client::build( environment ).unwrap()
+}
+
+#[ tokio::test ]
+async fn submits_outputs_and_streams_resumed_run()
+{
+  let ( base_url, posted ) = spawn_server( requires_action_run() ).await;
+  let client = client( base_url );
+
+  let mut events = client.assistants()
+    .submit_tool_outputs_streaming( "thread_1", "run_1", vec![ ToolOutput::new( "call_weather", "18C and sunny" ) ] )
+    .await
+    .unwrap();
+
+  let mut statuses = Vec::new();
+  while let Some( event ) = events.recv().await
+  {
+    statuses.push( event.unwrap().run().status.clone() );
+  }
+  assert_eq!( statuses, vec![ "queued", "completed" ] );
+
+  let body : serde_json::Value = serde_json::from_str( &posted.lock().unwrap()[ 0 ] ).unwrap();
+  assert_eq!( body, serde_json::json!(
+  {
+    "tool_outputs" : [ { "tool_call_id" : "call_weather", "output" : "18C and sunny" } ],
+    "stream" : true,
+  }));
+}
+
+#[ tokio::test ]
+async fn rejects_unknown_tool_call_id_without_submitting()
+{
+  let ( base_url, posted ) = spawn_server( requires_action_run() ).await;
+  let client = client( base_url );
+
+  let result = client.assistants()
+    .submit_tool_outputs_streaming( "thread_1", "run_1", vec![ ToolOutput::new( "call_unknown", "{}" ) ] )
+    .await;
+
+  let error = result.unwrap_err().to_string();
+  assert!( error.contains( "call_unknown" ), "{error}" );
+  assert!( posted.lock().unwrap().is_empty() );
+}
+
+#[ test ]
+fn validation_requires_pending_action()
+{
+  let run : RunObject = serde_json::from_str( &requires_action_run() ).unwrap();
+  assert_eq!( run.pending_tool_call_ids(), vec![ "call_weather" ] );
+  assert!( run.validate_tool_outputs( &[ ToolOutput::new( "call_weather", "ok" ) ] ).is_ok() );
+
+  let completed : RunObject = serde_json::from_str( &run_json( "completed", "null" ) ).unwrap();
+  assert!( completed.pending_tool_call_ids().is_empty() );
+  assert!( completed.validate_tool_outputs( &[ ToolOutput::new( "call_weather", "ok" ) ] ).is_err() );
+}
+
+#[ test ]
+fn from_sse_skips_non_run_events()
+{
+  assert!( RunStreamEvent::from_sse( "thread.message.delta", "{}" ).is_none() );
+  assert!( RunStreamEvent::from_sse( "done", "[DONE]" ).is_none() );
+
+  let event = RunStreamEvent::from_sse( "thread.run.requires_action", &requires_action_run() ).unwrap().unwrap();
+  assert!( matches!( event, RunStreamEvent::RequiresAction( ref e ) if e.event == "thread.run.requires_action" ) );
+}
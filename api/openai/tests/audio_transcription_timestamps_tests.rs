@@ -0,0 +1,85 @@
+//! Transcription timestamp tests
+//!
+//! Tests for parsing word and segment timestamps from `verbose_json`
+//! transcription responses and for the timestamp granularity request values.
+
+use api_openai::components::audio::
+{
+  CreateTranscriptionResponseVerboseJson,
+  TimestampGranularity,
+  TranscriptionResponseFormat,
+};
+
+const VERBOSE_WITH_WORDS : &str = r#"{
+  "task": "transcribe",
+  "language": "english",
+  "duration": 2.95,
+  "text": "Hello there, general.",
+  "words": [
+    { "word": "Hello", "start": 0.0, "end": 0.48 },
+    { "word": "there", "start": 0.48, "end": 0.9 },
+    { "word": "general", "start": 1.32, "end": 2.1 }
+  ],
+  "segments": [
+    {
+      "id": 0,
+      "seek": 0,
+      "start": 0.0,
+      "end": 2.95,
+      "text": " Hello there, general.",
+      "tokens": [ 50364, 2425, 456, 11, 2674, 13, 50512 ],
+      "temperature": 0.0,
+      "avg_logprob": -0.28,
+      "compression_ratio": 0.79,
+      "no_speech_prob": 0.01
+    }
+  ]
+}"#;
+
+#[ test ]
+fn verbose_json_parses_word_timestamps()
+{
+  let response : CreateTranscriptionResponseVerboseJson = serde_json::from_str( VERBOSE_WITH_WORDS ).unwrap();
+
+  assert_eq!( response.language, "english" );
+  assert_eq!( response.text, "Hello there, general." );
+
+  let words = response.words();
+  assert_eq!( words.len(), 3 );
+  assert_eq!( words[ 0 ].word, "Hello" );
+  assert!( ( words[ 0 ].end - 0.48 ).abs() < f64::EPSILON );
+  assert_eq!( words[ 2 ].word, "general" );
+  assert!( ( words[ 2 ].start - 1.32 ).abs() < f64::EPSILON );
+  assert!( words.iter().all( | word | word.start <= word.end ) );
+}
+
+#[ test ]
+fn verbose_json_parses_segment_timestamps()
+{
+  let response : CreateTranscriptionResponseVerboseJson = serde_json::from_str( VERBOSE_WITH_WORDS ).unwrap();
+
+  let segments = response.segments();
+  assert_eq!( segments.len(), 1 );
+  assert_eq!( segments[ 0 ].text, " Hello there, general." );
+  assert!( ( segments[ 0 ].end - 2.95 ).abs() < f64::EPSILON );
+}
+
+#[ test ]
+fn verbose_json_without_granularities_has_no_timestamps()
+{
+  let json = r#"{ "language": "english", "duration": 2.95, "text": "Hello there, general." }"#;
+  let response : CreateTranscriptionResponseVerboseJson = serde_json::from_str( json ).unwrap();
+
+  assert!( response.words.is_none() );
+  assert!( response.words().is_empty() );
+  assert!( response.segments().is_empty() );
+}
+
+#[ test ]
+fn form_values_match_api_names()
+{
+  assert_eq!( TimestampGranularity::Word.as_str(), "word" );
+  assert_eq!( TimestampGranularity::Segment.as_str(), "segment" );
+  assert_eq!( TranscriptionResponseFormat::VerboseJson.as_str(), "verbose_json" );
+  assert_eq!( TranscriptionResponseFormat::Srt.as_str(), "srt" );
+}
@@ -0,0 +1,56 @@
+//! Translation response format tests
+//!
+//! Tests for routing `/audio/translations` bodies by the requested
+//! `TranslationResponseFormat`.
+
+use api_openai::components::audio::{ TranslationResponse, TranslationResponseFormat };
+
+const SRT_BODY : &str = "1\n00:00:00,000 --> 00:00:02,950\nHello there, general.\n\n";
+
+#[ test ]
+fn srt_output_is_returned_verbatim()
+{
+  let response = TranslationResponse::from_body( Some( TranslationResponseFormat::Srt ), SRT_BODY.to_string() ).unwrap();
+
+  assert_eq!( response, TranslationResponse::Text( SRT_BODY.to_string() ) );
+  assert_eq!( response.text(), SRT_BODY );
+}
+
+#[ test ]
+fn vtt_and_text_output_are_not_parsed_as_json()
+{
+  let vtt = "WEBVTT\n\n00:00.000 --> 00:02.950\nHello there, general.\n";
+  let response = TranslationResponse::from_body( Some( TranslationResponseFormat::Vtt ), vtt.to_string() ).unwrap();
+  assert_eq!( response.text(), vtt );
+
+  let response = TranslationResponse::from_body( Some( TranslationResponseFormat::Text ), "{ not json".to_string() ).unwrap();
+  assert_eq!( response.text(), "{ not json" );
+}
+
+#[ test ]
+fn json_output_deserializes()
+{
+  let body = r#"{ "text": "Hello there, general." }"#.to_string();
+
+  let explicit = TranslationResponse::from_body( Some( TranslationResponseFormat::Json ), body.clone() ).unwrap();
+  assert!( matches!( explicit, TranslationResponse::Json( ref json ) if json.text == "Hello there, general." ) );
+
+  // The API defaults to json when no format is requested
+  let defaulted = TranslationResponse::from_body( None, body ).unwrap();
+  assert_eq!( defaulted, explicit );
+}
+
+#[ test ]
+fn json_format_mismatch_is_an_error()
+{
+  assert!( TranslationResponse::from_body( Some( TranslationResponseFormat::Json ), SRT_BODY.to_string() ).is_err() );
+}
+
+#[ test ]
+fn format_values_match_api_names()
+{
+  assert_eq!( TranslationResponseFormat::Srt.as_str(), "srt" );
+  assert_eq!( TranslationResponseFormat::Vtt.as_str(), "vtt" );
+  assert!( !TranslationResponseFormat::Json.is_plain_text() );
+  assert!( TranslationResponseFormat::Srt.is_plain_text() );
+}
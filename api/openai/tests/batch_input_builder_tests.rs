@@ -0,0 +1,84 @@
+//! Batch input builder tests
+//!
+//! Tests for `BatchInputBuilder` JSONL output and `custom_id` validation.
+
+use api_openai::
+{
+  Client,
+  environment ::OpenaiEnvironmentImpl,
+  secret ::Secret,
+  components ::chat_shared::ChatCompletionRequest,
+  enhanced_batch_operations ::{ BatchInputBuilder, upload_and_create },
+};
+use serde_json::{ json, Value };
+
+fn chat_request( prompt : &str ) -> ChatCompletionRequest
+{
+  serde_json::from_value( json!(
+  {
+    "model" : "gpt-4o-mini",
+    "messages" : [ { "role" : "user", "content" : prompt } ],
+  })).unwrap()
+}
+
+#[ test ]
+fn build_jsonl_emits_one_batch_line_per_request()
+{
+  let mut builder = BatchInputBuilder::new();
+  builder.add_chat_request( "req-1", &chat_request( "Hello" ) ).unwrap();
+  builder.add_chat_request( "req-2", &chat_request( "Bonjour" ) ).unwrap();
+  assert_eq!( builder.len(), 2 );
+
+  let jsonl = builder.build_jsonl();
+  assert!( jsonl.ends_with( '\n' ) );
+
+  let lines : Vec< Value > = jsonl.lines().map( | line | serde_json::from_str( line ).unwrap() ).collect();
+  assert_eq!( lines.len(), 2 );
+  assert_eq!( lines[ 0 ][ "custom_id" ], "req-1" );
+  assert_eq!( lines[ 0 ][ "method" ], "POST" );
+  assert_eq!( lines[ 0 ][ "url" ], "/v1/chat/completions" );
+  assert_eq!( lines[ 0 ][ "body" ][ "model" ], "gpt-4o-mini" );
+  assert_eq!( lines[ 1 ][ "custom_id" ], "req-2" );
+  assert_eq!( lines[ 1 ][ "body" ][ "messages" ][ 0 ][ "content" ], "Bonjour" );
+
+  let keys : Vec< &str > = lines[ 0 ].as_object().unwrap().keys().map( String::as_str ).collect();
+  assert_eq!( keys.len(), 4, "unexpected keys : {keys:?}" );
+}
+
+#[ test ]
+fn duplicate_custom_id_is_rejected()
+{
+  let mut builder = BatchInputBuilder::new();
+  builder.add_chat_request( "req-1", &chat_request( "Hello" ) ).unwrap();
+
+  let error = builder.add_chat_request( "req-1", &chat_request( "Again" ) ).unwrap_err();
+  assert!( error.to_string().contains( "req-1" ) );
+  assert_eq!( builder.len(), 1 );
+  assert_eq!( builder.build_jsonl().lines().count(), 1 );
+}
+
+#[ test ]
+fn empty_custom_id_is_rejected()
+{
+  let mut builder = BatchInputBuilder::new();
+  assert!( builder.add_chat_request( "", &chat_request( "Hello" ) ).is_err() );
+  assert!( builder.is_empty() );
+  assert_eq!( builder.build_jsonl(), "" );
+}
+
+#[ tokio::test ]
+async fn upload_and_create_rejects_empty_builder()
+{
+  let environment = OpenaiEnvironmentImpl::build
+  (
+    Secret::new_unchecked( "sk-test".to_string() ),
+    None,
+    None,
+    "http://127.0.0.1:9/v1/".to_string(),
+    "wss://127.0.0.1:9/v1/realtime/".to_string(),
+  ).unwrap();
+  let client = Client::build( environment ).unwrap();
+
+  let error = upload_and_create( &client, &BatchInputBuilder::new(), "24h", None ).await.unwrap_err();
+  assert!( error.to_string().contains( "no requests" ) );
+}
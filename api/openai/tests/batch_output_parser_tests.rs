@@ -0,0 +1,68 @@
+//! Batch output parser tests
+//!
+//! Tests for `parse_batch_output`, which maps batch output lines back to the
+//! `custom_id`s of the original requests.
+
+use api_openai::enhanced_batch_operations::{ BatchLineError, parse_batch_output };
+
+const OUTPUT : &str = r#"{"id":"batch_req_1","custom_id":"req-1","response":{"status_code":200,"request_id":"r1","body":{"id":"chatcmpl-1","object":"chat.completion","created":1700000000,"model":"gpt-4o-mini","choices":[{"index":0,"finish_reason":"stop","message":{"role":"assistant","content":"Hello!"}}]}},"error":null}
+{"id":"batch_req_2","custom_id":"req-2","response":null,"error":{"code":"batch_expired","message":"This line could not be executed before the batch expired."}}
+{"id":"batch_req_3","custom_id":"req-3","response":{"status_code":400,"request_id":"r3","body":{"error":{"message":"Invalid model","type":"invalid_request_error"}}},"error":null}
+this is not json
+{"id":"batch_req_4","custom_id":"req-4","response":{"status_code":200,"request_id":"r4","body":{"unexpected":true}},"error":null}
+
+{"id":"batch_req_5","response":null,"error":null}
+"#;
+
+#[ test ]
+fn maps_custom_ids_to_results()
+{
+  let results = parse_batch_output( OUTPUT );
+
+  let mut ids : Vec< &String > = results.keys().collect();
+  ids.sort();
+  assert_eq!( ids, vec![ "req-1", "req-2", "req-3", "req-4" ] );
+
+  let completion = results[ "req-1" ].as_ref().unwrap();
+  assert_eq!( completion.id, "chatcmpl-1" );
+  assert_eq!( completion.choices[ 0 ].message.content.as_deref(), Some( "Hello!" ) );
+}
+
+#[ test ]
+fn request_errors_are_reported_per_line()
+{
+  let results = parse_batch_output( OUTPUT );
+
+  assert_eq!( results[ "req-2" ], Err( BatchLineError::Request
+  {
+    code : Some( "batch_expired".to_string() ),
+    message : "This line could not be executed before the batch expired.".to_string(),
+  }));
+
+  match &results[ "req-3" ]
+  {
+    Err( BatchLineError::Http { status_code, body } ) =>
+    {
+      assert_eq!( *status_code, 400 );
+      assert_eq!( body[ "error" ][ "message" ], "Invalid model" );
+    },
+    other => panic!( "expected an HTTP error, got {other:?}" ),
+  }
+}
+
+#[ test ]
+fn malformed_lines_are_tolerated()
+{
+  let results = parse_batch_output( OUTPUT );
+
+  // A line with a custom_id but an unexpected body is reported, not fatal
+  assert!( matches!( results[ "req-4" ], Err( BatchLineError::Malformed( _ ) ) ) );
+  // Non-JSON lines and lines without a custom_id cannot be correlated
+  assert_eq!( results.len(), 4 );
+}
+
+#[ test ]
+fn empty_output_yields_empty_map()
+{
+  assert!( parse_batch_output( "" ).is_empty() );
+}
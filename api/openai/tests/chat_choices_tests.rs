@@ -0,0 +1,50 @@
+//! Chat completion multi-choice helper tests
+//!
+//! Tests for `CreateChatCompletionResponse::choices_text`, `unique_choices` and
+//! `finish_reasons` on a locally parsed `n = 3` response.
+
+use api_openai::components::chat_shared::CreateChatCompletionResponse;
+
+const THREE_CHOICES_FIXTURE : &str = r#"{
+  "id": "chatcmpl-456",
+  "object": "chat.completion",
+  "created": 1700000000,
+  "model": "gpt-4o-mini",
+  "choices": [
+    { "index": 0, "finish_reason": "stop", "message": { "role": "assistant", "content": "42" } },
+    { "index": 1, "finish_reason": "length", "message": { "role": "assistant", "content": "Forty-two" } },
+    { "index": 2, "finish_reason": "stop", "message": { "role": "assistant", "content": "42" } }
+  ]
+}"#;
+
+fn fixture() -> CreateChatCompletionResponse
+{
+  serde_json::from_str( THREE_CHOICES_FIXTURE ).expect( "fixture should deserialize" )
+}
+
+#[ test ]
+fn choices_text_extracts_every_choice()
+{
+  assert_eq!( fixture().choices_text(), vec![ "42", "Forty-two", "42" ] );
+}
+
+#[ test ]
+fn unique_choices_dedupes_in_first_seen_order()
+{
+  assert_eq!( fixture().unique_choices(), vec![ "42", "Forty-two" ] );
+}
+
+#[ test ]
+fn finish_reasons_follow_choice_order()
+{
+  assert_eq!( fixture().finish_reasons(), vec![ "stop", "length", "stop" ] );
+}
+
+#[ test ]
+fn choice_without_content_yields_empty_text()
+{
+  let mut response = fixture();
+  response.choices[ 1 ].message.content = None;
+
+  assert_eq!( response.choices_text(), vec![ "42", "", "42" ] );
+}
@@ -0,0 +1,81 @@
+//! Chat completion logprobs helper tests
+//!
+//! Tests for `CreateChatCompletionResponse::token_logprobs` and `perplexity`,
+//! parsed from a local logprobs fixture.
+
+#![ allow( clippy::float_cmp ) ] // Acceptable in tests for exact value checking
+
+use api_openai::components::chat_shared::CreateChatCompletionResponse;
+
+const LOGPROBS_FIXTURE : &str = r#"{
+  "id": "chatcmpl-123",
+  "object": "chat.completion",
+  "created": 1700000000,
+  "model": "gpt-4o-mini",
+  "choices": [
+    {
+      "index": 0,
+      "finish_reason": "stop",
+      "message": { "role": "assistant", "content": "Hello world" },
+      "logprobs": {
+        "content": [
+          {
+            "token": "Hello",
+            "logprob": -0.5,
+            "top_logprobs": [
+              { "token": "Hello", "logprob": -0.5 },
+              { "token": "Hi", "logprob": -1.25 }
+            ]
+          },
+          {
+            "token": " world",
+            "logprob": -1.5,
+            "top_logprobs": [
+              { "token": " world", "logprob": -1.5 }
+            ]
+          }
+        ]
+      }
+    }
+  ]
+}"#;
+
+fn fixture() -> CreateChatCompletionResponse
+{
+  serde_json::from_str( LOGPROBS_FIXTURE ).expect( "fixture should deserialize" )
+}
+
+#[ test ]
+fn token_logprobs_flattens_content()
+{
+  let tokens = fixture().token_logprobs();
+
+  assert_eq!( tokens.len(), 2 );
+  assert_eq!( tokens[ 0 ].token, "Hello" );
+  assert_eq!( tokens[ 0 ].logprob, -0.5 );
+  assert_eq!( tokens[ 0 ].top_alternatives, vec![ ( "Hello".to_string(), -0.5 ), ( "Hi".to_string(), -1.25 ) ] );
+  assert_eq!( tokens[ 1 ].token, " world" );
+  assert_eq!( tokens[ 1 ].top_alternatives.len(), 1 );
+}
+
+#[ test ]
+fn perplexity_is_exp_of_negative_mean_logprob()
+{
+  let perplexity = fixture().perplexity().expect( "perplexity should be present" );
+
+  // mean logprob = ( -0.5 + -1.5 ) / 2 = -1.0
+  assert!( ( perplexity - 1.0_f64.exp() ).abs() < 1e-9 );
+}
+
+#[ test ]
+fn missing_logprobs_yield_empty_and_none()
+{
+  let mut response = fixture();
+  response.choices[ 0 ].logprobs = None;
+
+  assert!( response.token_logprobs().is_empty() );
+  assert!( response.perplexity().is_none() );
+
+  response.choices.clear();
+  assert!( response.perplexity().is_none() );
+}
@@ -0,0 +1,72 @@
+//! `ConnectionManager::drain` tests
+//!
+//! Tests that draining refuses new checkouts, waits for checked-out
+//! connections to be returned, and reports connections it had to close.
+
+use api_openai::connection_manager::{ ConnectionConfig, ConnectionManager };
+use core::time::Duration;
+use std::sync::Arc;
+use std::time::Instant;
+
+const HOST : &str = "api.openai.com";
+
+fn manager() -> Arc< ConnectionManager >
+{
+  Arc::new( ConnectionManager::new( ConnectionConfig::default() ) )
+}
+
+#[ tokio::test ]
+async fn drain_waits_for_in_flight_connection()
+{
+  let manager = manager();
+  let connection = manager.get_connection( HOST ).await.unwrap();
+  let idle = manager.get_connection( HOST ).await.unwrap();
+  manager.return_connection( idle ).await;
+
+  let returner = Arc::clone( &manager );
+  tokio::spawn( async move
+  {
+    tokio::time::sleep( Duration::from_millis( 100 ) ).await;
+    returner.return_connection( connection ).await;
+  });
+
+  let start = Instant::now();
+  let result = manager.drain( Duration::from_secs( 2 ) ).await;
+
+  assert!( start.elapsed() >= Duration::from_millis( 100 ), "drain returned before the request finished" );
+  assert!( result.elapsed < Duration::from_secs( 2 ) );
+  assert!( result.is_clean() );
+  assert_eq!( result.completed, 1 );
+  assert_eq!( result.idle_closed, 2, "both connections are idle once returned" );
+  assert!( manager.get_all_stats().await.is_empty(), "pools are removed" );
+}
+
+#[ tokio::test ]
+async fn drain_reports_connections_still_in_use_at_timeout()
+{
+  let manager = manager();
+  let _held = manager.get_connection( HOST ).await.unwrap();
+
+  let result = manager.drain( Duration::from_millis( 100 ) ).await;
+
+  assert!( result.elapsed >= Duration::from_millis( 100 ) );
+  assert!( !result.is_clean() );
+  assert_eq!( result.forcibly_closed, 1 );
+  assert_eq!( result.completed, 0 );
+  assert_eq!( result.idle_closed, 0 );
+}
+
+#[ tokio::test ]
+async fn draining_refuses_new_checkouts()
+{
+  let manager = manager();
+  assert!( !manager.is_draining() );
+
+  let result = manager.drain( Duration::from_secs( 1 ) ).await;
+  assert!( result.is_clean() );
+  assert!( result.elapsed < Duration::from_millis( 100 ), "nothing to wait for" );
+  assert!( manager.is_draining() );
+
+  let error = manager.get_connection( HOST ).await.unwrap_err();
+  assert!( error.to_string().contains( "draining" ), "unexpected error : {error}" );
+}
@@ -0,0 +1,187 @@
+//! `EnhancedEmbeddings::embed_many` tests
+//!
+//! Tests batch planning by count and token budget, and runs `embed_many` against
+//! a local HTTP server that embeds each input as its length and returns each
+//! batch in reverse order.
+
+#![ cfg( feature = "batching" ) ]
+
+use api_openai::exposed::
+{
+  environment ::OpenaiEnvironmentImpl,
+  secret ::Secret,
+  client ::Client,
+};
+use api_openai::enhanced_embeddings::{ EnhancedEmbeddings, EmbedManyConfig, plan_embedding_batches };
+use std::sync::{ Arc, Mutex };
+use tokio::io::{ AsyncReadExt as _, AsyncWriteExt as _ };
+use tokio::net::TcpListener;
+
+/// Serves `/embeddings`, recording the inputs of each request. Inputs starting with `fail` are rejected.
+async fn spawn_server() -> ( String, Arc< Mutex< Vec< Vec< String > > > > )
+{
+  let listener = TcpListener::bind( "127.0.0.1:0" ).await.unwrap();
+  let base_url = format!( "http://{}/v1/", listener.local_addr().unwrap() );
+  let requests = Arc::new( Mutex::new( Vec::new() ) );
+  let recorded = Arc::clone( &requests );
+
+  tokio::spawn( async move
+  {
+    loop
+    {
+      let Ok( ( mut socket, _ ) ) = listener.accept().await else { return };
+      let recorded = Arc::clone( &recorded );
+      tokio::spawn( async move
+      {
+        let mut buffer = Vec::new();
+        let mut chunk = [ 0_u8; 4096 ];
+        let body = loop
+        {
+          let read = socket.read( &mut chunk ).await.unwrap();
+          buffer.extend_from_slice( &chunk[ ..read ] );
+          let text = String::from_utf8_lossy( &buffer ).to_string();
+          if let Some( end ) = text.find( "\r\n\r\n" )
+          {
+            let length = text[ ..end ].to_lowercase().lines()
+              .find_map( | line | line.strip_prefix( "content-length:" ).map( | value | value.trim().parse::< usize >().unwrap() ) )
+              .unwrap_or( 0 );
+            if text.len() >= end + 4 + length || read == 0
+            {
+              break text[ end + 4.. ].to_string();
+            }
+          }
+        };
+
+        let request : serde_json::Value = serde_json::from_str( &body ).unwrap();
+        let inputs : Vec< String > = serde_json::from_value( request[ "input" ].clone() ).unwrap();
+        recorded.lock().unwrap().push( inputs.clone() );
+
+        let response = if inputs.iter().any( | input | input.starts_with( "fail" ) )
+        {
+          let error = r#"{ "error" : { "message" : "bad input", "type" : "invalid_request_error", "param" : null, "code" : null } }"#;
+          format!( "HTTP/1.1 400 Bad Request\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{error}", error.len() )
+        }
+        else
+        {
+          let data : Vec< serde_json::Value > = inputs.iter().enumerate().rev()
+            .map( | ( index, input ) | serde_json::json!( { "index" : index, "embedding" : [ input.len() as f64 ], "object" : "embedding" } ) )
+            .collect();
+          let json = serde_json::json!(
+          {
+            "data" : data,
+            "model" : "text-embedding-3-small",
+            "object" : "list",
+            "usage" : { "prompt_tokens" : 1, "total_tokens" : 1 },
+          }).to_string();
+          format!( "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{json}", json.len() )
+        };
+        socket.write_all( response.as_bytes() ).await.unwrap();
+        socket.shutdown().await.ok();
+      });
+    }
+  });
+
+  ( base_url, requests )
+}
+
+fn client( base_url : String ) -> Client< OpenaiEnvironmentImpl >
+{
+  let environment = OpenaiEnvironmentImpl::build
+  (
+    Secret::new_unchecked( "sk-test".to_string() ),
+    None,
+    None,
+    base_url,
+    "wss://127.0.0.1/v1/realtime/".to_string(),
+  ).unwrap();
+  Client::build( environment ).unwrap()
+}
+
+fn config( max_inputs_per_request : usize, max_tokens_per_request : usize ) -> EmbedManyConfig
+{
+  EmbedManyConfig
+  {
+    max_inputs_per_request,
+    max_tokens_per_request,
+    max_concurrency : 2,
+    token_estimator : Arc::new( str::len ),
+    ..EmbedManyConfig::default()
+  }
+}
+
+/// Inputs of distinct lengths, so each embedding identifies its input
+fn inputs( count : usize ) -> Vec< String >
+{
+  ( 1..=count ).map( | length | "x".repeat( length ) ).collect()
+}
+
+#[ test ]
+fn batches_split_by_count()
+{
+  assert_eq!( plan_embedding_batches( &[ 1; 5 ], 2, 100 ), vec![ 0..2, 2..4, 4..5 ] );
+  assert_eq!( plan_embedding_batches( &[ 1; 4 ], 4, 100 ), vec![ 0..4 ] );
+  assert!( plan_embedding_batches( &[], 4, 100 ).is_empty() );
+}
+
+#[ test ]
+fn batches_split_by_token_budget()
+{
+  assert_eq!( plan_embedding_batches( &[ 4, 4, 4, 1, 10, 3 ], 100, 8 ), vec![ 0..2, 2..4, 4..5, 5..6 ] );
+}
+
+#[ tokio::test ]
+async fn results_are_returned_in_input_order()
+{
+  let ( base_url, requests ) = spawn_server().await;
+  let client = client( base_url );
+  let embeddings = EnhancedEmbeddings::with_default_batching( &client ).with_embed_many_config( config( 3, 100 ) );
+
+  let result = embeddings.embed_many( inputs( 7 ) ).await.unwrap();
+
+  let indices : Vec< i32 > = result.iter().map( | embedding | embedding.index ).collect();
+  assert_eq!( indices, ( 0..7 ).collect::< Vec< _ > >() );
+  let lengths : Vec< f64 > = result.iter().map( | embedding | embedding.embedding[ 0 ] ).collect();
+  assert_eq!( lengths, vec![ 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0 ] );
+
+  let mut sizes : Vec< usize > = requests.lock().unwrap().iter().map( Vec::len ).collect();
+  sizes.sort_unstable();
+  assert_eq!( sizes, vec![ 1, 3, 3 ] );
+}
+
+#[ tokio::test ]
+async fn token_budget_limits_each_request()
+{
+  let ( base_url, requests ) = spawn_server().await;
+  let client = client( base_url );
+  let embeddings = EnhancedEmbeddings::with_default_batching( &client ).with_embed_many_config( config( 100, 6 ) );
+
+  let result = embeddings.embed_many( inputs( 5 ) ).await.unwrap();
+  assert_eq!( result.len(), 5 );
+
+  let mut batches = requests.lock().unwrap().clone();
+  batches.sort();
+  assert_eq!( batches, vec!
+  [
+    vec![ "x".to_string(), "xx".to_string(), "xxx".to_string() ],
+    vec![ "xxxx".to_string() ],
+    vec![ "xxxxx".to_string() ],
+  ] );
+}
+
+#[ tokio::test ]
+async fn failed_batch_is_reported_without_losing_others()
+{
+  let ( base_url, _requests ) = spawn_server().await;
+  let client = client( base_url );
+  let embeddings = EnhancedEmbeddings::with_default_batching( &client ).with_embed_many_config( config( 2, 100 ) );
+  let inputs = vec![ "a".to_string(), "b".to_string(), "fail".to_string(), "c".to_string(), "d".to_string() ];
+
+  assert!( embeddings.embed_many( inputs.clone() ).await.is_err() );
+
+  let batches = embeddings.embed_many_batches( inputs ).await;
+  let ranges : Vec< _ > = batches.iter().map( | batch | batch.inputs.clone() ).collect();
+  assert_eq!( ranges, vec![ 0..2, 2..4, 4..5 ] );
+  assert!( batches[ 0 ].result.is_ok() );
+  assert!( batches[ 1 ].result.is_err() );
+  assert_eq!( batches[ 2 ].result.as_ref().unwrap()[ 0 ].index, 4 );
+}
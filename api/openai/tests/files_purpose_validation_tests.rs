@@ -0,0 +1,69 @@
+//! File purpose validation tests
+//!
+//! Tests for `FilePurpose::validate_file`, which `Files::upload_validated` runs
+//! before uploading.
+
+use api_openai::components::files::FilePurpose;
+use api_openai::exposed::error::{ OpenAIError, Result };
+
+const TRAINING_JSONL : &[ u8 ] = b"{\"messages\":[{\"role\":\"user\",\"content\":\"Hi\"},{\"role\":\"assistant\",\"content\":\"Hello\"}]}\n";
+const PNG_HEADER : &[ u8 ] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+fn is_invalid_argument( result : &Result< () > ) -> bool
+{
+  result.as_ref().err()
+    .and_then( | error | error.downcast_ref::< OpenAIError >() )
+    .is_some_and( | error | matches!( error, OpenAIError::InvalidArgument( _ ) ) )
+}
+
+#[ test ]
+fn jsonl_with_vision_purpose_is_rejected()
+{
+  let result = FilePurpose::Vision.validate_file( "train.jsonl", TRAINING_JSONL );
+
+  assert!( is_invalid_argument( &result ) );
+  assert!( result.unwrap_err().to_string().contains( "train.jsonl" ) );
+}
+
+#[ test ]
+fn jsonl_with_fine_tune_purpose_is_accepted()
+{
+  assert!( FilePurpose::FineTune.validate_file( "train.jsonl", TRAINING_JSONL ).is_ok() );
+  assert!( FilePurpose::Batch.validate_file( "requests.JSONL", TRAINING_JSONL ).is_ok() );
+}
+
+#[ test ]
+fn image_with_vision_purpose_is_accepted()
+{
+  assert!( FilePurpose::Vision.validate_file( "chart.png", PNG_HEADER ).is_ok() );
+  assert!( FilePurpose::Vision.validate_file( "photo.jpg", &[ 0xFF, 0xD8, 0xFF, 0xE0 ] ).is_ok() );
+}
+
+#[ test ]
+fn image_with_fine_tune_purpose_is_rejected()
+{
+  assert!( is_invalid_argument( &FilePurpose::FineTune.validate_file( "chart.png", PNG_HEADER ) ) );
+}
+
+#[ test ]
+fn renamed_content_is_rejected()
+{
+  // Right extension, wrong content
+  assert!( is_invalid_argument( &FilePurpose::Vision.validate_file( "chart.png", TRAINING_JSONL ) ) );
+  assert!( is_invalid_argument( &FilePurpose::FineTune.validate_file( "train.jsonl", PNG_HEADER ) ) );
+  assert!( is_invalid_argument( &FilePurpose::Batch.validate_file( "empty.jsonl", b"\n\n" ) ) );
+}
+
+#[ test ]
+fn general_purposes_accept_any_file()
+{
+  assert!( FilePurpose::Assistants.validate_file( "notes.pdf", b"%PDF-1.7" ).is_ok() );
+  assert!( FilePurpose::UserData.validate_file( "data.bin", &[ 0, 1, 2 ] ).is_ok() );
+}
+
+#[ test ]
+fn purpose_values_match_api_names()
+{
+  assert_eq!( FilePurpose::FineTune.as_str(), "fine-tune" );
+  assert_eq!( serde_json::to_value( FilePurpose::UserData ).unwrap(), "user_data" );
+}
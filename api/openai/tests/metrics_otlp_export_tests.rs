@@ -0,0 +1,111 @@
+//! `MetricsCollector::export_otlp` tests
+//!
+//! Tests that recorded latencies and errors are exported as an OTLP/JSON
+//! `ExportMetricsServiceRequest` with the expected metric names, bucket
+//! boundaries and counts, and that those counts survive sample trimming.
+
+use api_openai::metrics_framework::{ MetricsCollector, MetricsConfig, OTLP_LATENCY_BOUNDS_MS };
+use core::time::Duration;
+use serde_json::Value;
+
+fn metrics( export : &str ) -> Vec< Value >
+{
+  let request : Value = serde_json::from_str( export ).unwrap();
+  let scope = &request[ "resourceMetrics" ][ 0 ][ "scopeMetrics" ][ 0 ];
+  assert_eq!( scope[ "scope" ][ "name" ], "api_openai" );
+  scope[ "metrics" ].as_array().unwrap().clone()
+}
+
+fn metric< 'a >( metrics : &'a [ Value ], name : &str ) -> &'a Value
+{
+  metrics.iter().find( | metric | metric[ "name" ] == name ).unwrap_or_else( || panic!( "missing metric {name}" ) )
+}
+
+#[ tokio::test ]
+async fn latencies_export_as_histogram_with_bounds()
+{
+  let collector = MetricsCollector::new();
+  for millis in [ 3, 10, 40, 40, 700 ]
+  {
+    collector.record_timing( Duration::from_millis( millis ) ).await;
+  }
+
+  let metrics = metrics( &collector.export_otlp_with_bounds( &[ 10.0, 50.0, 100.0 ] ).await );
+
+  let duration = metric( &metrics, "openai.request.duration" );
+  assert_eq!( duration[ "unit" ], "ms" );
+  assert_eq!( duration[ "histogram" ][ "aggregationTemporality" ], 2 );
+  let point = &duration[ "histogram" ][ "dataPoints" ][ 0 ];
+  assert_eq!( point[ "explicitBounds" ], serde_json::json!( [ 10.0, 50.0, 100.0 ] ) );
+  assert_eq!( point[ "bucketCounts" ], serde_json::json!( [ "2", "2", "0", "1" ] ), "upper bounds are inclusive" );
+  assert_eq!( point[ "count" ], "5" );
+  assert_eq!( point[ "sum" ], 793.0 );
+  assert_eq!( point[ "min" ], 3.0 );
+  assert_eq!( point[ "max" ], 700.0 );
+  assert!( point[ "startTimeUnixNano" ].as_str().unwrap().parse::< u128 >().unwrap() <= point[ "timeUnixNano" ].as_str().unwrap().parse::< u128 >().unwrap() );
+
+  let count = metric( &metrics, "openai.request.count" );
+  assert_eq!( count[ "sum" ][ "isMonotonic" ], true );
+  assert_eq!( count[ "sum" ][ "dataPoints" ][ 0 ][ "asInt" ], "5" );
+}
+
+#[ tokio::test ]
+async fn errors_export_as_sums_by_type()
+{
+  let collector = MetricsCollector::new();
+  collector.record_error( "timeout" ).await;
+  collector.record_error( "rate_limit" ).await;
+  collector.record_error( "timeout" ).await;
+
+  let metrics = metrics( &collector.export_otlp().await );
+
+  let points = metric( &metrics, "openai.error.count" )[ "sum" ][ "dataPoints" ].as_array().unwrap().clone();
+  let by_type : Vec< ( String, String ) > = points.iter()
+    .map( | point | ( point[ "attributes" ][ 0 ][ "value" ][ "stringValue" ].as_str().unwrap().to_string(), point[ "asInt" ].as_str().unwrap().to_string() ) )
+    .collect();
+  assert_eq!( by_type, vec![ ( "rate_limit".to_string(), "1".to_string() ), ( "timeout".to_string(), "2".to_string() ) ] );
+}
+
+#[ tokio::test ]
+async fn empty_collector_exports_default_bounds()
+{
+  let collector = MetricsCollector::new();
+  let metrics = metrics( &collector.export_otlp().await );
+
+  let names : Vec< &str > = metrics.iter().map( | metric | metric[ "name" ].as_str().unwrap() ).collect();
+  assert_eq!( names, vec![ "openai.request.duration", "openai.request.count" ] );
+
+  let point = &metric( &metrics, "openai.request.duration" )[ "histogram" ][ "dataPoints" ][ 0 ];
+  assert_eq!( point[ "explicitBounds" ], serde_json::json!( OTLP_LATENCY_BOUNDS_MS ) );
+  assert_eq!( point[ "bucketCounts" ].as_array().unwrap().len(), OTLP_LATENCY_BOUNDS_MS.len() + 1 );
+  assert_eq!( point[ "count" ], "0" );
+  assert!( point.get( "min" ).is_none() );
+}
+
+#[ tokio::test ]
+async fn cumulative_counts_survive_sample_trimming()
+{
+  let mut collector = MetricsCollector::with_config( MetricsConfig
+  {
+    max_entries : 2,
+    collection_interval : Duration::from_millis( 10 ),
+    ..MetricsConfig::default()
+  });
+  collector.start_collection();
+  for millis in [ 3, 10, 40, 40, 700 ]
+  {
+    collector.record_timing( Duration::from_millis( millis ) ).await;
+  }
+  tokio::time::sleep( Duration::from_millis( 50 ) ).await;
+  collector.record_timing( Duration::from_millis( 20 ) ).await;
+  tokio::time::sleep( Duration::from_millis( 50 ) ).await;
+
+  let metrics = metrics( &collector.export_otlp_with_bounds( &[ 10.0, 50.0, 100.0 ] ).await );
+
+  let point = &metric( &metrics, "openai.request.duration" )[ "histogram" ][ "dataPoints" ][ 0 ];
+  assert_eq!( point[ "count" ], "6" );
+  assert_eq!( point[ "bucketCounts" ], serde_json::json!( [ "2", "3", "0", "1" ] ) );
+  assert_eq!( point[ "sum" ], 813.0 );
+  assert_eq!( point[ "min" ], 3.0 );
+  assert_eq!( metric( &metrics, "openai.request.count" )[ "sum" ][ "dataPoints" ][ 0 ][ "asInt" ], "6" );
+}
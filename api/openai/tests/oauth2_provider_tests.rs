@@ -0,0 +1,226 @@
+//! `OAuth2Provider` refresh tests
+//!
+//! Tests token caching and refresh-on-expiry against a local token endpoint
+//! that issues numbered access tokens and rotates the refresh token, and that
+//! a client authorizes every request with a currently valid token.
+
+use api_openai::advanced_auth::{ OAuth2Config, OAuth2Environment, OAuth2Provider };
+use api_openai::exposed::
+{
+  environment ::{ OpenaiEnvironment, OpenaiEnvironmentImpl },
+  secret ::Secret,
+};
+use api_openai::{ Client, ClientApiAccessors };
+use core::time::Duration;
+use std::sync::{ Arc, Mutex };
+use tokio::io::{ AsyncReadExt as _, AsyncWriteExt as _ };
+use tokio::net::TcpListener;
+
+/// Serves a token endpoint that answers refresh number `n` with `access-n`, valid for
+/// `expires_in` seconds, and rotates the refresh token to `refresh-n`.
+/// Records the body of every request; refresh token `revoked` is rejected.
+async fn spawn_token_endpoint( expires_in : u64 ) -> ( String, Arc< Mutex< Vec< String > > > )
+{
+  let listener = TcpListener::bind( "127.0.0.1:0" ).await.unwrap();
+  let token_url = format!( "http://{}/oauth/token", listener.local_addr().unwrap() );
+  let requests = Arc::new( Mutex::new( Vec::new() ) );
+  let recorded = Arc::clone( &requests );
+
+  tokio::spawn( async move
+  {
+    loop
+    {
+      let Ok( ( mut socket, _ ) ) = listener.accept().await else { return };
+      let mut buffer = Vec::new();
+      let mut chunk = [ 0_u8; 4096 ];
+      let body = loop
+      {
+        let read = socket.read( &mut chunk ).await.unwrap();
+        buffer.extend_from_slice( &chunk[ ..read ] );
+        let text = String::from_utf8_lossy( &buffer ).to_string();
+        if let Some( end ) = text.find( "\r\n\r\n" )
+        {
+          let length = text[ ..end ].to_lowercase().lines()
+            .find_map( | line | line.strip_prefix( "content-length:" ).map( | value | value.trim().parse::< usize >().unwrap() ) )
+            .unwrap_or( 0 );
+          if text.len() >= end + 4 + length || read == 0
+          {
+            break text[ end + 4.. ].to_string();
+          }
+        }
+      };
+
+      let count = {
+        let mut recorded = recorded.lock().unwrap();
+        recorded.push( body.clone() );
+        recorded.len()
+      };
+      let response = if body.contains( "refresh_token=revoked" )
+      {
+        let error = r#"{"error":"invalid_grant"}"#;
+        format!( "HTTP/1.1 400 Bad Request\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{error}", error.len() )
+      }
+      else
+      {
+        let json = serde_json::json!(
+        {
+          "access_token" : format!( "access-{count}" ),
+          "token_type" : "Bearer",
+          "expires_in" : expires_in,
+          "refresh_token" : format!( "refresh-{count}" ),
+        }).to_string();
+        format!( "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{json}", json.len() )
+      };
+      socket.write_all( response.as_bytes() ).await.unwrap();
+      socket.shutdown().await.ok();
+    }
+  });
+
+  ( token_url, requests )
+}
+
+/// Serves an empty model list to every request, recording its `Authorization` header.
+async fn spawn_api() -> ( String, Arc< Mutex< Vec< String > > > )
+{
+  let listener = TcpListener::bind( "127.0.0.1:0" ).await.unwrap();
+  let base_url = format!( "http://{}/v1/", listener.local_addr().unwrap() );
+  let authorizations = Arc::new( Mutex::new( Vec::new() ) );
+  let recorded = Arc::clone( &authorizations );
+
+  tokio::spawn( async move
+  {
+    loop
+    {
+      let Ok( ( mut socket, _ ) ) = listener.accept().await else { return };
+      let mut buffer = Vec::new();
+      let mut chunk = [ 0_u8; 4096 ];
+      while !String::from_utf8_lossy( &buffer ).contains( "\r\n\r\n" )
+      {
+        let read = socket.read( &mut chunk ).await.unwrap();
+        if read == 0 { break; }
+        buffer.extend_from_slice( &chunk[ ..read ] );
+      }
+      let authorization = String::from_utf8_lossy( &buffer ).lines()
+        .find_map( | line | line.to_lowercase().starts_with( "authorization:" ).then( || line[ "authorization:".len().. ].trim().to_string() ) )
+        .unwrap_or_default();
+      recorded.lock().unwrap().push( authorization );
+
+      let json = r#"{"object":"list","data":[]}"#;
+      let response = format!( "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{json}", json.len() );
+      socket.write_all( response.as_bytes() ).await.unwrap();
+      socket.shutdown().await.ok();
+    }
+  });
+
+  ( base_url, authorizations )
+}
+
+fn config( token_url : &str, refresh_token : &str ) -> OAuth2Config
+{
+  OAuth2Config
+  {
+    refresh_margin : Duration::from_secs( 30 ),
+    ..OAuth2Config::new( token_url, "client-1", refresh_token )
+  }
+}
+
+#[ tokio::test ]
+async fn valid_token_is_served_from_cache()
+{
+  let ( token_url, requests ) = spawn_token_endpoint( 3600 ).await;
+  let provider = OAuth2Provider::new( config( &token_url, "refresh-0" ) )
+    .with_access_token( "seeded", Duration::from_secs( 3600 ) );
+
+  assert_eq!( provider.token().await.unwrap(), "seeded" );
+  assert_eq!( provider.token().await.unwrap(), "seeded" );
+  assert!( requests.lock().unwrap().is_empty(), "no refresh while the token is valid" );
+}
+
+#[ tokio::test ]
+async fn token_near_expiry_is_refreshed()
+{
+  // Tokens live 10s, inside the 30s margin, so every call refreshes
+  let ( token_url, requests ) = spawn_token_endpoint( 10 ).await;
+  let provider = OAuth2Provider::new( config( &token_url, "refresh-0" ) )
+    .with_access_token( "seeded", Duration::from_secs( 10 ) );
+
+  assert_eq!( provider.token().await.unwrap(), "access-1" );
+  assert_eq!( provider.token().await.unwrap(), "access-2" );
+
+  let requests = requests.lock().unwrap();
+  assert_eq!( requests.len(), 2 );
+  assert!( requests[ 0 ].contains( "grant_type=refresh_token" ) );
+  assert!( requests[ 0 ].contains( "refresh_token=refresh-0" ) );
+  assert!( requests[ 0 ].contains( "client_id=client-1" ) );
+  assert!( requests[ 1 ].contains( "refresh_token=refresh-1" ), "rotated refresh token is used" );
+}
+
+#[ tokio::test ]
+async fn missing_token_is_fetched_once()
+{
+  let ( token_url, requests ) = spawn_token_endpoint( 3600 ).await;
+  let provider = Arc::new( OAuth2Provider::new( config( &token_url, "refresh-0" ) ) );
+  assert_eq!( provider.cached_token(), None );
+
+  let tokens = futures_util::future::join_all( ( 0..4 ).map( | _ | provider.token() ) ).await;
+  assert!( tokens.iter().all( | token | token.as_deref().unwrap() == "access-1" ) );
+  assert_eq!( requests.lock().unwrap().len(), 1, "concurrent callers share one refresh" );
+}
+
+#[ tokio::test ]
+async fn rejected_refresh_is_an_error()
+{
+  let ( token_url, _requests ) = spawn_token_endpoint( 3600 ).await;
+  let provider = OAuth2Provider::new( config( &token_url, "revoked" ) );
+
+  let error = provider.token().await.unwrap_err();
+  assert!( error.to_string().contains( "invalid_grant" ), "unexpected error : {error}" );
+  assert_eq!( provider.cached_token(), None );
+}
+
+#[ tokio::test ]
+async fn environment_headers_use_provider_token()
+{
+  let ( token_url, _requests ) = spawn_token_endpoint( 3600 ).await;
+  let provider = Arc::new( OAuth2Provider::new( config( &token_url, "refresh-0" ) ) );
+  let inner = OpenaiEnvironmentImpl::build
+  (
+    Secret::new_unchecked( "sk-unused".to_string() ),
+    Some( "org-1".to_string() ),
+    None,
+    "http://127.0.0.1/v1/".to_string(),
+    "wss://127.0.0.1/v1/realtime/".to_string(),
+  ).unwrap();
+  let environment = OAuth2Environment::new( inner, Arc::clone( &provider ) );
+
+  assert!( environment.headers().unwrap().get( reqwest::header::AUTHORIZATION ).is_none(), "no token before the first refresh" );
+
+  let _client = environment.client().await.unwrap();
+  let headers = environment.headers().unwrap();
+  assert_eq!( headers[ reqwest::header::AUTHORIZATION ], "Bearer access-1" );
+  assert_eq!( headers[ "openai-organization" ], "org-1" );
+}
+
+#[ tokio::test ]
+async fn client_refreshes_token_expiring_between_requests()
+{
+  // Tokens live 10s, inside the 30s margin, so the token expires between the two calls
+  let ( token_url, token_requests ) = spawn_token_endpoint( 10 ).await;
+  let ( base_url, authorizations ) = spawn_api().await;
+  let provider = Arc::new( OAuth2Provider::new( config( &token_url, "refresh-0" ) ) );
+  let inner = OpenaiEnvironmentImpl::build
+  (
+    Secret::new_unchecked( "sk-unused".to_string() ),
+    None,
+    None,
+    base_url,
+    "wss://127.0.0.1/v1/realtime/".to_string(),
+  ).unwrap();
+  let client = Client::build( OAuth2Environment::new( inner, provider ) ).unwrap();
+
+  client.models().list().await.unwrap();
+  client.models().list().await.unwrap();
+
+  assert_eq!( *authorizations.lock().unwrap(), vec![ "Bearer access-1".to_string(), "Bearer access-2".to_string() ] );
+  assert_eq!( token_requests.lock().unwrap().len(), 2 );
+}
@@ -0,0 +1,98 @@
+//! Optional retry entry point tests
+//!
+//! Tests `Client::execute_with_optional_retries` against a local server that
+//! fails twice with `503` before succeeding. Without the `retry` feature, or
+//! without a retry configuration, the request is attempted exactly once.
+
+use api_openai::
+{
+  Client,
+  environment ::OpenaiEnvironmentImpl,
+  secret ::Secret,
+};
+use std::io::{ Read, Write };
+use std::sync::{ Arc, atomic::{ AtomicUsize, Ordering } };
+
+/// Serve `503` to the first two connections and `200` afterwards, counting attempts
+fn serve_flaky() -> ( String, Arc< AtomicUsize > )
+{
+  let listener = std::net::TcpListener::bind( "127.0.0.1:0" ).expect( "bind local listener" );
+  let address = listener.local_addr().expect( "local address" );
+  let attempts = Arc::new( AtomicUsize::new( 0 ) );
+  let counter = attempts.clone();
+
+  std::thread::spawn( move ||
+  {
+    for stream in listener.incoming()
+    {
+      let Ok( mut socket ) = stream else { break };
+      let attempt = counter.fetch_add( 1, Ordering::SeqCst ) + 1;
+      let mut request = [ 0_u8; 4096 ];
+      let _ = socket.read( &mut request );
+      let ( status, body ) = if attempt <= 2 { ( "503 Service Unavailable", "busy" ) } else { ( "200 OK", "{}" ) };
+      let response = format!( "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len() );
+      let _ = socket.write_all( response.as_bytes() );
+    }
+  } );
+
+  ( format!( "http://{address}/v1/models" ), attempts )
+}
+
+fn client() -> Client< OpenaiEnvironmentImpl >
+{
+  let environment = OpenaiEnvironmentImpl::build
+  (
+    Secret::new_unchecked( "sk-test".to_string() ),
+    None,
+    None,
+    "http://127.0.0.1:9/v1/".to_string(),
+    "wss://127.0.0.1:9/v1/realtime/".to_string(),
+  ).unwrap();
+  Client::build( environment ).unwrap()
+}
+
+#[ tokio::test ]
+async fn single_attempt_without_retry_config()
+{
+  let ( url, attempts ) = serve_flaky();
+  let client = client();
+
+  let result = client.execute_with_optional_retries( || client.http_client.get( &url ).send() ).await;
+
+  assert!( result.is_err() );
+  assert_eq!( attempts.load( Ordering::SeqCst ), 1 );
+}
+
+#[ cfg( feature = "retry" ) ]
+#[ tokio::test ]
+async fn retries_when_configured()
+{
+  use api_openai::EnhancedRetryConfig;
+
+  let ( url, attempts ) = serve_flaky();
+  let client = client().with_retry_config
+  (
+    EnhancedRetryConfig::new().with_max_attempts( 3 ).with_base_delay( 1 ).with_max_delay( 5 ).with_jitter( 0 )
+  );
+
+  let response = client.execute_with_optional_retries( || client.http_client.get( &url ).send() ).await.unwrap();
+
+  assert_eq!( response.status(), 200 );
+  assert_eq!( attempts.load( Ordering::SeqCst ), 3 );
+}
+
+#[ cfg( feature = "retry" ) ]
+#[ tokio::test ]
+async fn gives_up_after_max_attempts()
+{
+  use api_openai::EnhancedRetryConfig;
+
+  let ( url, attempts ) = serve_flaky();
+  let client = client().with_retry_config
+  (
+    EnhancedRetryConfig::new().with_max_attempts( 2 ).with_base_delay( 1 ).with_max_delay( 5 ).with_jitter( 0 )
+  );
+
+  assert!( client.execute_with_optional_retries( || client.http_client.get( &url ).send() ).await.is_err() );
+  assert_eq!( attempts.load( Ordering::SeqCst ), 2 );
+}
@@ -0,0 +1,104 @@
+//! Per-endpoint SLO evaluation tests
+//!
+//! Tests `PerformanceMonitor::evaluate_slo` against seeded request samples that
+//! meet the SLO, miss its latency or success objective, and roll out of the window.
+
+use api_openai::performance_monitoring::{ PerformanceMonitor, SloConfig };
+use core::time::Duration;
+
+fn ms( millis : u64 ) -> Duration
+{
+  Duration::from_millis( millis )
+}
+
+fn monitor() -> PerformanceMonitor
+{
+  let monitor = PerformanceMonitor::new();
+  monitor.set_slo( "chat/completions", SloConfig { target_latency : ms( 500 ), target_success_rate : 0.9, window : 20 } );
+  monitor
+}
+
+#[ test ]
+fn healthy_endpoint_meets_slo()
+{
+  let monitor = monitor();
+  for i in 0..20
+  {
+    // One slow request out of twenty uses half of the 10% budget
+    monitor.record_request( "chat/completions", if i == 0 { ms( 900 ) } else { ms( 100 ) }, true );
+  }
+
+  let status = monitor.evaluate_slo( "chat/completions" );
+  assert_eq!( status.requests, 20 );
+  assert!( status.is_met() );
+  assert!( ( status.success_rate - 1.0 ).abs() < f64::EPSILON );
+  assert!( ( status.within_latency_rate - 0.95 ).abs() < 1e-9 );
+  assert!( ( status.error_budget_remaining - 0.5 ).abs() < 1e-9, "remaining : {}", status.error_budget_remaining );
+}
+
+#[ test ]
+fn slow_endpoint_misses_latency_objective()
+{
+  let monitor = monitor();
+  for i in 0..20
+  {
+    monitor.record_request( "chat/completions", if i % 4 == 0 { ms( 800 ) } else { ms( 100 ) }, true );
+  }
+
+  let status = monitor.evaluate_slo( "chat/completions" );
+  assert!( !status.meets_latency );
+  assert!( status.meets_success_rate );
+  assert!( !status.is_met() );
+  assert!( status.error_budget_remaining.abs() < f64::EPSILON, "budget is exhausted" );
+}
+
+#[ test ]
+fn failing_endpoint_misses_success_objective()
+{
+  let monitor = monitor();
+  for i in 0..20
+  {
+    monitor.record_request( "chat/completions", ms( 100 ), i % 5 != 0 );
+  }
+
+  let status = monitor.evaluate_slo( "chat/completions" );
+  assert!( status.meets_latency );
+  assert!( !status.meets_success_rate );
+  assert!( ( status.success_rate - 0.8 ).abs() < 1e-9 );
+  assert!( status.error_budget_remaining.abs() < f64::EPSILON );
+}
+
+#[ test ]
+fn only_recent_requests_are_evaluated()
+{
+  let monitor = monitor();
+  for _ in 0..20
+  {
+    monitor.record_request( "chat/completions", ms( 100 ), false );
+  }
+  for _ in 0..20
+  {
+    monitor.record_request( "chat/completions", ms( 100 ), true );
+  }
+
+  let status = monitor.evaluate_slo( "chat/completions" );
+  assert_eq!( status.requests, 20 );
+  assert!( status.is_met(), "failures fell out of the window" );
+  assert!( ( status.error_budget_remaining - 1.0 ).abs() < f64::EPSILON );
+}
+
+#[ test ]
+fn endpoints_are_tracked_separately()
+{
+  let monitor = monitor();
+  monitor.record_request( "embeddings", ms( 5_000 ), false );
+
+  let untouched = monitor.evaluate_slo( "chat/completions" );
+  assert_eq!( untouched.requests, 0 );
+  assert!( untouched.is_met() );
+
+  let embeddings = monitor.evaluate_slo( "embeddings" );
+  assert_eq!( embeddings.endpoint, "embeddings" );
+  assert_eq!( embeddings.requests, 1 );
+  assert!( !embeddings.is_met(), "default SLO applies to endpoints without one" );
+}
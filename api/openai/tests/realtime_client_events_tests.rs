@@ -0,0 +1,57 @@
+//! Realtime client event helper tests
+//!
+//! Tests that the audio buffer and response helpers used by `WsSession`
+//! build correctly serialized `RealtimeClientEvent`s.
+
+use api_openai::components::realtime_shared::{ RealtimeClientEvent, RealtimeResponseCreateParams };
+use serde_json::json;
+
+#[ test ]
+fn append_audio_serializes_base64_audio()
+{
+  let pcm = [ 0x00_u8, 0x01, 0xfe, 0xff, 0x10 ];
+  let event = serde_json::to_value( RealtimeClientEvent::append_audio( &pcm ) ).unwrap();
+
+  assert_eq!( event, json!( { "type" : "input_audio_buffer.append", "audio" : "AAH+/xA=" } ) );
+}
+
+#[ test ]
+fn append_audio_accepts_empty_buffer()
+{
+  let event = serde_json::to_value( RealtimeClientEvent::append_audio( &[] ) ).unwrap();
+
+  assert_eq!( event, json!( { "type" : "input_audio_buffer.append", "audio" : "" } ) );
+}
+
+#[ test ]
+fn commit_audio_serializes_commit_event()
+{
+  let event = serde_json::to_value( RealtimeClientEvent::commit_audio() ).unwrap();
+
+  assert_eq!( event, json!( { "type" : "input_audio_buffer.commit" } ) );
+}
+
+#[ test ]
+fn request_response_without_params_uses_session_defaults()
+{
+  let event = serde_json::to_value( RealtimeClientEvent::request_response( None ) ).unwrap();
+
+  assert_eq!( event, json!( { "type" : "response.create" } ) );
+}
+
+#[ test ]
+fn request_response_serializes_overrides()
+{
+  let params : RealtimeResponseCreateParams = serde_json::from_value( json!(
+  {
+    "modalities" : [ "text" ],
+    "instructions" : "Answer briefly.",
+  })).unwrap();
+  let event = serde_json::to_value( RealtimeClientEvent::request_response( Some( params ) ) ).unwrap();
+
+  assert_eq!( event, json!(
+  {
+    "type" : "response.create",
+    "response" : { "modalities" : [ "text" ], "instructions" : "Answer briefly." },
+  }));
+}
@@ -0,0 +1,184 @@
+//! Realtime event handler tests
+//!
+//! Tests that `RealtimeEventHandler` dispatches a scripted sequence of server
+//! events to the right callbacks, both directly and through
+//! `WsSession::run_with_handler` against a local WebSocket server, and that
+//! unknown event types deserialize to `RealtimeServerEvent::Unknown`.
+
+use api_openai::components::realtime_shared::
+{
+  RealtimeServerEvent,
+  RealtimeServerEventError,
+  RealtimeServerEventInputAudioBufferSpeechStarted,
+  RealtimeServerEventResponseAudioDelta,
+  RealtimeServerEventResponseFunctionCallArgumentsDone,
+  RealtimeServerEventResponseTextDelta,
+};
+use api_openai::realtime::{ RealtimeEventHandler, WsSession };
+use futures_util::{ SinkExt as _, StreamExt as _ };
+
+/// Records every callback invocation in order.
+#[ derive( Debug, Default ) ]
+struct RecordingHandler
+{
+  calls : Vec< String >,
+}
+
+impl RealtimeEventHandler for RecordingHandler
+{
+  fn on_audio_delta( &mut self, event : &RealtimeServerEventResponseAudioDelta )
+  {
+    self.calls.push( format!( "audio:{}", event.delta ) );
+  }
+
+  fn on_text_delta( &mut self, event : &RealtimeServerEventResponseTextDelta )
+  {
+    self.calls.push( format!( "text:{}", event.delta ) );
+  }
+
+  fn on_function_call( &mut self, event : &RealtimeServerEventResponseFunctionCallArgumentsDone )
+  {
+    self.calls.push( format!( "function:{}:{}", event.call_id, event.arguments ) );
+  }
+
+  fn on_speech_started( &mut self, event : &RealtimeServerEventInputAudioBufferSpeechStarted )
+  {
+    self.calls.push( format!( "speech_started:{}", event.audio_start_ms ) );
+  }
+
+  fn on_error( &mut self, event : &RealtimeServerEventError )
+  {
+    self.calls.push( format!( "error:{}", event.error.message ) );
+  }
+
+  fn on_other( &mut self, _event : &RealtimeServerEvent )
+  {
+    self.calls.push( "other".to_string() );
+  }
+
+  fn on_close( &mut self )
+  {
+    self.calls.push( "close".to_string() );
+  }
+}
+
+fn scripted_events() -> Vec< &'static str >
+{
+  vec!
+  [
+    r#"{ "type" : "input_audio_buffer.speech_started", "event_id" : "e1", "audio_start_ms" : 120, "item_id" : "item_1" }"#,
+    r#"{ "type" : "input_audio_buffer.committed", "event_id" : "e2", "previous_item_id" : null, "item_id" : "item_1" }"#,
+    r#"{ "type" : "response.text.delta", "event_id" : "e3", "response_id" : "resp_1", "item_id" : "item_2", "output_index" : 0, "content_index" : 0, "delta" : "Hel" }"#,
+    r#"{ "type" : "response.audio.delta", "event_id" : "e4", "response_id" : "resp_1", "item_id" : "item_2", "output_index" : 0, "content_index" : 0, "delta" : "AAE=" }"#,
+    r#"{ "type" : "response.function_call_arguments.done", "event_id" : "e5", "response_id" : "resp_1", "item_id" : "item_3", "output_index" : 1, "call_id" : "call_1", "arguments" : "{}" }"#,
+    r#"{ "type" : "error", "event_id" : "e6", "error" : { "type" : "invalid_request_error", "code" : null, "message" : "bad audio", "param" : null } }"#,
+  ]
+}
+
+fn expected_calls() -> Vec< String >
+{
+  [ "speech_started:120", "other", "text:Hel", "audio:AAE=", "function:call_1:{}", "error:bad audio" ]
+    .iter()
+    .map( ToString::to_string )
+    .collect()
+}
+
+#[ test ]
+fn handle_event_dispatches_to_matching_callbacks()
+{
+  let mut handler = RecordingHandler::default();
+  for json in scripted_events()
+  {
+    let event : RealtimeServerEvent = serde_json::from_str( json ).unwrap();
+    handler.handle_event( &event );
+  }
+
+  assert_eq!( handler.calls, expected_calls() );
+}
+
+#[ test ]
+fn default_callbacks_ignore_events()
+{
+  struct Silent;
+  impl RealtimeEventHandler for Silent {}
+
+  let mut handler = Silent;
+  for json in scripted_events()
+  {
+    let event : RealtimeServerEvent = serde_json::from_str( json ).unwrap();
+    handler.handle_event( &event );
+  }
+  handler.on_close();
+}
+
+#[ tokio::test ]
+async fn run_with_handler_dispatches_until_close()
+{
+  let listener = tokio::net::TcpListener::bind( "127.0.0.1:0" ).await.unwrap();
+  let addr = listener.local_addr().unwrap();
+
+  let server = tokio::spawn( async move
+  {
+    let ( stream, _ ) = listener.accept().await.unwrap();
+    let mut ws = tokio_tungstenite::accept_async( stream ).await.unwrap();
+    for json in scripted_events()
+    {
+      ws.send( tokio_tungstenite::tungstenite::Message::Text( json.into() ) ).await.unwrap();
+    }
+    ws.close( None ).await.unwrap();
+    // Drain until the client acknowledges the close handshake
+    while let Some( Ok( _ ) ) = ws.next().await {}
+  });
+
+  let session = WsSession::connect( &format!( "ws://{addr}" ) ).await.unwrap();
+  let mut handler = RecordingHandler::default();
+  session.run_with_handler( &mut handler ).await.unwrap();
+  server.await.unwrap();
+
+  let mut expected = expected_calls();
+  expected.push( "close".to_string() );
+  assert_eq!( handler.calls, expected );
+}
+
+#[ tokio::test ]
+async fn run_with_handler_skips_unknown_event_types()
+{
+  let listener = tokio::net::TcpListener::bind( "127.0.0.1:0" ).await.unwrap();
+  let addr = listener.local_addr().unwrap();
+
+  let server = tokio::spawn( async move
+  {
+    let ( stream, _ ) = listener.accept().await.unwrap();
+    let mut ws = tokio_tungstenite::accept_async( stream ).await.unwrap();
+    let events = [ r#"{ "type" : "response.future_feature.delta", "event_id" : "e0", "delta" : "?" }"# ]
+      .into_iter()
+      .chain( scripted_events() );
+    for json in events
+    {
+      ws.send( tokio_tungstenite::tungstenite::Message::Text( json.into() ) ).await.unwrap();
+    }
+    ws.close( None ).await.unwrap();
+    while let Some( Ok( _ ) ) = ws.next().await {}
+  });
+
+  let session = WsSession::connect( &format!( "ws://{addr}" ) ).await.unwrap();
+  let mut handler = RecordingHandler::default();
+  session.run_with_handler( &mut handler ).await.expect( "unknown event types should be skipped" );
+  server.await.unwrap();
+
+  let mut expected = expected_calls();
+  expected.push( "close".to_string() );
+  assert_eq!( handler.calls, expected );
+}
+
+#[ test ]
+fn unknown_event_type_deserializes_to_unknown()
+{
+  let event : RealtimeServerEvent = serde_json::from_str( r#"{ "type" : "response.future_feature.delta", "event_id" : "e0", "delta" : "?" }"# )
+    .expect( "unknown event types should deserialize" );
+  assert_eq!( event, RealtimeServerEvent::Unknown );
+
+  // A known type with a malformed payload is still an error
+  let malformed = serde_json::from_str::< RealtimeServerEvent >( r#"{ "type" : "response.text.delta", "event_id" : 7 }"# );
+  assert!( malformed.is_err(), "{malformed:?}" );
+}
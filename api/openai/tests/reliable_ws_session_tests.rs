@@ -0,0 +1,156 @@
+//! `ReliableWsSession` reconnect tests
+//!
+//! Tests that a dropped realtime connection is re-established with the session
+//! setup events replayed, using a local WebSocket server that closes the first
+//! connection after reading its first event.
+
+use api_openai::components::realtime_shared::
+{
+  RealtimeClientEvent,
+  RealtimeClientEventSessionUpdate,
+  RealtimeServerEvent,
+  RealtimeSessionCreateRequest,
+};
+use api_openai::websocket_reliability_enhanced::{ ReliableWsSession, WebSocketReliabilityConfig };
+use core::time::Duration;
+use futures_util::{ SinkExt as _, StreamExt as _ };
+use std::sync::{ Arc, Mutex };
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+
+const SERVER_ERROR : &str = r#"{ "type" : "error", "event_id" : "e1", "error" : { "type" : "server_error", "code" : null, "message" : "still here", "param" : null } }"#;
+
+fn config( max_reconnection_attempts : usize ) -> WebSocketReliabilityConfig
+{
+  WebSocketReliabilityConfig
+  {
+    max_reconnection_attempts,
+    initial_reconnection_delay : Duration::from_millis( 10 ),
+    max_reconnection_delay : Duration::from_millis( 50 ),
+    connection_timeout : Duration::from_secs( 2 ),
+    ..WebSocketReliabilityConfig::default()
+  }
+}
+
+fn session_update( instructions : &str ) -> RealtimeClientEvent
+{
+  RealtimeClientEvent::SessionUpdate( RealtimeClientEventSessionUpdate
+  {
+    event_id : None,
+    session : RealtimeSessionCreateRequest
+    {
+      instructions : Some( instructions.to_string() ),
+      ..RealtimeSessionCreateRequest::default()
+    },
+  })
+}
+
+/// Read the next text frame as JSON
+async fn next_json< S >( ws : &mut tokio_tungstenite::WebSocketStream< S > ) -> serde_json::Value
+where
+  S : tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+  loop
+  {
+    if let Message::Text( text ) = ws.next().await.unwrap().unwrap()
+    {
+      return serde_json::from_str( &text ).unwrap();
+    }
+  }
+}
+
+#[ test ]
+fn only_session_updates_are_setup_events()
+{
+  assert!( session_update( "hi" ).is_session_setup() );
+  assert!( !RealtimeClientEvent::commit_audio().is_session_setup() );
+  assert!( !RealtimeClientEvent::request_response( None ).is_session_setup() );
+}
+
+#[ tokio::test ]
+async fn reconnect_replays_session_setup_events()
+{
+  let listener = TcpListener::bind( "127.0.0.1:0" ).await.unwrap();
+  let addr = listener.local_addr().unwrap();
+  let replayed = Arc::new( Mutex::new( Vec::new() ) );
+  let recorded = Arc::clone( &replayed );
+
+  let server = tokio::spawn( async move
+  {
+    // First connection : receive the setup events and a conversation event, then drop
+    let ( stream, _ ) = listener.accept().await.unwrap();
+    let mut ws = tokio_tungstenite::accept_async( stream ).await.unwrap();
+    for _ in 0..3
+    {
+      next_json( &mut ws ).await;
+    }
+    ws.close( None ).await.unwrap();
+    while let Some( Ok( _ ) ) = ws.next().await {}
+
+    // Second connection : record the replay, then answer
+    let ( stream, _ ) = listener.accept().await.unwrap();
+    let mut ws = tokio_tungstenite::accept_async( stream ).await.unwrap();
+    for _ in 0..2
+    {
+      let event = next_json( &mut ws ).await;
+      recorded.lock().unwrap().push( format!( "{}:{}", event[ "type" ].as_str().unwrap(), event[ "session" ][ "instructions" ].as_str().unwrap() ) );
+    }
+    ws.send( Message::Text( SERVER_ERROR.into() ) ).await.unwrap();
+    while let Some( Ok( _ ) ) = ws.next().await {}
+  });
+
+  let callbacks = Arc::new( Mutex::new( Vec::new() ) );
+  let observed = Arc::clone( &callbacks );
+  let session = ReliableWsSession::connect( format!( "ws://{addr}" ), config( 3 ) ).await.unwrap()
+    .on_reconnect( move | count | observed.lock().unwrap().push( count ) );
+
+  session.send_event( session_update( "first" ) ).await.unwrap();
+  session.send_event( session_update( "second" ) ).await.unwrap();
+  session.send_event( RealtimeClientEvent::commit_audio() ).await.unwrap();
+  assert_eq!( session.reconnect_count(), 0 );
+
+  let event = tokio::time::timeout( Duration::from_secs( 5 ), session.recv_event() ).await
+    .expect( "server event after reconnect" )
+    .unwrap();
+  match event
+  {
+    RealtimeServerEvent::Error( event ) => assert_eq!( event.error.message, "still here" ),
+    other => panic!( "unexpected event : {other:?}" ),
+  }
+
+  assert_eq!( session.reconnect_count(), 1 );
+  assert_eq!( *callbacks.lock().unwrap(), vec![ 1 ] );
+  assert_eq!( *replayed.lock().unwrap(), vec![ "session.update:first".to_string(), "session.update:second".to_string() ] );
+
+  drop( session );
+  server.abort();
+}
+
+#[ tokio::test ]
+async fn reconnect_gives_up_after_max_attempts()
+{
+  let listener = TcpListener::bind( "127.0.0.1:0" ).await.unwrap();
+  let addr = listener.local_addr().unwrap();
+
+  tokio::spawn( async move
+  {
+    // Accept once, then stop listening so every reconnect is refused
+    let ( stream, _ ) = listener.accept().await.unwrap();
+    drop( listener );
+    let mut ws = tokio_tungstenite::accept_async( stream ).await.unwrap();
+    ws.close( None ).await.unwrap();
+    while let Some( Ok( _ ) ) = ws.next().await {}
+  });
+
+  let callbacks = Arc::new( Mutex::new( Vec::new() ) );
+  let observed = Arc::clone( &callbacks );
+  let session = ReliableWsSession::connect( format!( "ws://{addr}" ), config( 2 ) ).await.unwrap()
+    .on_reconnect( move | count | observed.lock().unwrap().push( count ) );
+
+  let error = tokio::time::timeout( Duration::from_secs( 5 ), session.recv_event() ).await
+    .expect( "reconnect should give up" )
+    .unwrap_err();
+  assert!( error.to_string().contains( "after 2 attempts" ), "unexpected error : {error}" );
+  assert_eq!( session.reconnect_count(), 0 );
+  assert!( callbacks.lock().unwrap().is_empty() );
+}
@@ -0,0 +1,71 @@
+//! `BatchConfig::max_batch_delay` tests
+//!
+//! Tests that a partially filled batch is flushed once no request has been
+//! enqueued for `max_batch_delay`, instead of waiting for a full batch.
+
+#![ cfg( feature = "batching" ) ]
+
+use api_openai::request_batching::{ BatchConfig, RequestBatcher, RequestSignature };
+use core::time::Duration;
+use std::time::Instant;
+
+const DELAY : Duration = Duration::from_millis( 100 );
+
+fn batcher() -> RequestBatcher< String >
+{
+  RequestBatcher::new( BatchConfig
+  {
+    smart_batch_threshold : 10,
+    flush_timeout : Duration::from_secs( 60 ),
+    max_batch_delay : DELAY,
+    ..BatchConfig::default()
+  } )
+}
+
+fn signature() -> RequestSignature
+{
+  RequestSignature::new( "POST", "embeddings", b"{}" )
+}
+
+#[ tokio::test ]
+async fn single_request_flushes_after_delay()
+{
+  let batcher = batcher();
+  let start = Instant::now();
+
+  let response = tokio::time::timeout( Duration::from_secs( 2 ), batcher.submit_request( signature(), "text".to_string() ) )
+    .await
+    .expect( "partial batch should flush without a full batch" )
+    .expect( "batched request succeeds" );
+
+  assert_eq!( response, b"{ \"batched\": true }".to_vec() );
+  assert!( start.elapsed() >= DELAY, "flushed before the delay : {:?}", start.elapsed() );
+
+  let metrics = batcher.get_metrics().await;
+  assert_eq!( metrics.total_batches, 1 );
+  assert_eq!( metrics.total_requests, 1 );
+}
+
+#[ tokio::test ]
+async fn each_enqueue_restarts_the_delay()
+{
+  let batcher = batcher();
+  let start = Instant::now();
+
+  let first = batcher.submit_request( signature(), "first".to_string() );
+  let second = async
+  {
+    tokio::time::sleep( DELAY / 2 ).await;
+    batcher.submit_request( signature(), "second".to_string() ).await
+  };
+  let ( first, second ) = tokio::time::timeout( Duration::from_secs( 2 ), async { tokio::join!( first, second ) } )
+    .await
+    .expect( "partial batch should flush without a full batch" );
+
+  assert!( first.is_ok() && second.is_ok() );
+  assert!( start.elapsed() >= DELAY + DELAY / 2, "flushed before the restarted delay : {:?}", start.elapsed() );
+
+  let metrics = batcher.get_metrics().await;
+  assert_eq!( metrics.total_batches, 1, "both requests should flush in one batch" );
+  assert_eq!( metrics.total_requests, 2 );
+}
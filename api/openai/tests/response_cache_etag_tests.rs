@@ -0,0 +1,127 @@
+//! `CachedClient` `ETag` revalidation tests
+//!
+//! Tests `CachedClient::get_cached` against a local HTTP server that tags its
+//! responses with an `ETag` and answers conditional requests either with
+//! `304 Not Modified` or with a new version of the resource.
+
+#![ cfg( feature = "caching" ) ]
+
+use api_openai::exposed::
+{
+  environment ::OpenaiEnvironmentImpl,
+  secret ::Secret,
+  client ::Client,
+};
+use api_openai::response_cache::{ CacheConfig, CachedClient };
+use core::time::Duration;
+use std::sync::{ Arc, Mutex };
+use tokio::io::{ AsyncReadExt as _, AsyncWriteExt as _ };
+use tokio::net::TcpListener;
+
+const TTL : Duration = Duration::from_millis( 50 );
+
+/// Serves `{"version":1}` tagged `"v1"`, recording each request's `If-None-Match`.
+///
+/// A conditional request for `"v1"` gets `304 Not Modified` if `modified` is false,
+/// or `{"version":2}` tagged `"v2"` otherwise.
+async fn spawn_server( modified : bool ) -> ( String, Arc< Mutex< Vec< Option< String > > > > )
+{
+  let listener = TcpListener::bind( "127.0.0.1:0" ).await.unwrap();
+  let base_url = format!( "http://{}/v1/", listener.local_addr().unwrap() );
+  let conditions = Arc::new( Mutex::new( Vec::new() ) );
+  let recorded = Arc::clone( &conditions );
+
+  tokio::spawn( async move
+  {
+    loop
+    {
+      let Ok( ( mut socket, _ ) ) = listener.accept().await else { return };
+      let mut buffer = Vec::new();
+      let mut chunk = [ 0_u8; 4096 ];
+      let head = loop
+      {
+        let read = socket.read( &mut chunk ).await.unwrap();
+        buffer.extend_from_slice( &chunk[ ..read ] );
+        let text = String::from_utf8_lossy( &buffer ).to_lowercase();
+        if let Some( end ) = text.find( "\r\n\r\n" )
+        {
+          break text[ ..end ].to_string();
+        }
+      };
+
+      let condition = head.lines()
+        .find_map( | line | line.strip_prefix( "if-none-match:" ) )
+        .map( | value | value.trim().to_string() );
+      recorded.lock().unwrap().push( condition.clone() );
+
+      let response = match ( condition.as_deref(), modified )
+      {
+        ( Some( "\"v1\"" ), false ) => "HTTP/1.1 304 Not Modified\r\netag: \"v1\"\r\nconnection: close\r\n\r\n".to_string(),
+        ( Some( _ ), _ ) => tagged( "\"v2\"", r#"{"version":2}"# ),
+        ( None, _ ) => tagged( "\"v1\"", r#"{"version":1}"# ),
+      };
+      socket.write_all( response.as_bytes() ).await.unwrap();
+      socket.shutdown().await.ok();
+    }
+  });
+
+  ( base_url, conditions )
+}
+
+fn tagged( etag : &str, body : &str ) -> String
+{
+  format!( "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\netag: {etag}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}", body.len() )
+}
+
+fn cached_client( base_url : String ) -> CachedClient< OpenaiEnvironmentImpl >
+{
+  let environment = OpenaiEnvironmentImpl::build
+  (
+    Secret::new_unchecked( "sk-test".to_string() ),
+    None,
+    None,
+    base_url,
+    "wss://127.0.0.1/v1/realtime/".to_string(),
+  ).unwrap();
+  let config = CacheConfig { cleanup_interval : Duration::ZERO, ..CacheConfig::default() };
+  CachedClient::with_cache_config( Client::build( environment ).unwrap(), config )
+}
+
+async fn version( client : &CachedClient< OpenaiEnvironmentImpl > ) -> u64
+{
+  let value : serde_json::Value = client.get_cached( "models", Some( TTL ) ).await.unwrap();
+  value[ "version" ].as_u64().unwrap()
+}
+
+#[ tokio::test ]
+async fn not_modified_refreshes_stale_entry()
+{
+  let ( base_url, conditions ) = spawn_server( false ).await;
+  let client = cached_client( base_url );
+
+  assert_eq!( version( &client ).await, 1 );
+  assert_eq!( version( &client ).await, 1, "fresh entry is served from cache" );
+  assert_eq!( conditions.lock().unwrap().len(), 1 );
+
+  tokio::time::sleep( TTL * 2 ).await;
+  assert_eq!( version( &client ).await, 1, "304 keeps the cached body" );
+  assert_eq!( *conditions.lock().unwrap(), vec![ None, Some( "\"v1\"".to_string() ) ] );
+
+  // The refreshed entry is fresh again
+  assert_eq!( version( &client ).await, 1 );
+  assert_eq!( conditions.lock().unwrap().len(), 2 );
+}
+
+#[ tokio::test ]
+async fn modified_response_replaces_stale_entry()
+{
+  let ( base_url, conditions ) = spawn_server( true ).await;
+  let client = cached_client( base_url );
+
+  assert_eq!( version( &client ).await, 1 );
+
+  tokio::time::sleep( TTL * 2 ).await;
+  assert_eq!( version( &client ).await, 2, "200 replaces the cached body" );
+  assert_eq!( version( &client ).await, 2, "replacement is cached" );
+  assert_eq!( *conditions.lock().unwrap(), vec![ None, Some( "\"v1\"".to_string() ) ] );
+}
@@ -0,0 +1,89 @@
+//! Stream performance report tests
+//!
+//! Tests `StreamPerfRecorder` with a timestamped chunk sequence, and
+//! `MeasuredReceiver` against a channel fed with delays.
+
+use api_openai::streaming_performance_enhanced::{ LatencyDistribution, MeasuredReceiver, StreamPerfRecorder };
+use core::time::Duration;
+use std::time::Instant;
+
+fn ms( millis : u64 ) -> Duration
+{
+  Duration::from_millis( millis )
+}
+
+#[ test ]
+fn report_from_timestamped_chunks()
+{
+  let start = Instant::now();
+  let mut recorder = StreamPerfRecorder::start_at( start );
+  for offset in [ 200, 210, 230, 260, 300 ]
+  {
+    recorder.record_chunk_at( start + ms( offset ) );
+  }
+
+  let report = recorder.report_at( start + ms( 320 ) );
+
+  assert_eq!( report.chunks, 5 );
+  assert_eq!( report.time_to_first_token, Some( ms( 200 ) ) );
+  assert_eq!( report.total_duration, ms( 320 ) );
+  assert_eq!( report.inter_token_latency, Some( LatencyDistribution
+  {
+    samples : 4,
+    min : ms( 10 ),
+    max : ms( 40 ),
+    mean : ms( 25 ),
+    p50 : ms( 20 ),
+    p95 : ms( 40 ),
+    p99 : ms( 40 ),
+  } ) );
+}
+
+#[ test ]
+fn report_without_enough_chunks()
+{
+  let start = Instant::now();
+  let mut recorder = StreamPerfRecorder::start_at( start );
+
+  let empty = recorder.report_at( start + ms( 50 ) );
+  assert_eq!( empty.chunks, 0 );
+  assert_eq!( empty.time_to_first_token, None );
+  assert_eq!( empty.inter_token_latency, None );
+
+  recorder.record_chunk_at( start + ms( 30 ) );
+  let single = recorder.report_at( start + ms( 50 ) );
+  assert_eq!( single.time_to_first_token, Some( ms( 30 ) ) );
+  assert_eq!( single.inter_token_latency, None );
+}
+
+#[ tokio::test ]
+async fn measured_receiver_records_while_consuming()
+{
+  let ( tx, rx ) = tokio::sync::mpsc::channel( 8 );
+  let mut measured = MeasuredReceiver::new( rx );
+
+  tokio::spawn( async move
+  {
+    tokio::time::sleep( ms( 50 ) ).await;
+    for chunk in 0..3
+    {
+      tx.send( chunk ).await.unwrap();
+      tokio::time::sleep( ms( 10 ) ).await;
+    }
+  } );
+
+  let mut received = Vec::new();
+  while let Some( chunk ) = measured.recv().await
+  {
+    received.push( chunk );
+  }
+  assert_eq!( received, vec![ 0, 1, 2 ] );
+
+  let report = measured.report();
+  assert_eq!( report.chunks, 3 );
+  assert!( report.time_to_first_token.unwrap() >= ms( 50 ) );
+  assert_eq!( report.inter_token_latency.as_ref().unwrap().samples, 2 );
+  assert!( report.inter_token_latency.unwrap().min >= ms( 10 ) );
+  assert!( report.total_duration >= ms( 70 ) );
+  assert_eq!( measured.report().total_duration, report.total_duration, "duration is fixed once the stream ends" );
+}
@@ -0,0 +1,59 @@
+//! TLS backend selection tests
+//!
+//! Tests that `Client::build_with_tls` builds a client with each backend
+//! compiled into this build, and rejects the others.
+
+#![ cfg( any( feature = "tls_rustls", feature = "tls_native" ) ) ]
+
+use api_openai::
+{
+  platform_specific ::TlsBackend,
+  environment ::OpenaiEnvironmentImpl,
+  secret ::Secret,
+  Client,
+};
+
+fn environment() -> OpenaiEnvironmentImpl
+{
+  OpenaiEnvironmentImpl::build
+  (
+    Secret::new_unchecked( "sk-test".to_string() ),
+    None,
+    None,
+    "https://127.0.0.1/v1/".to_string(),
+    "wss://127.0.0.1/v1/realtime/".to_string(),
+  ).unwrap()
+}
+
+#[ test ]
+fn availability_matches_features()
+{
+  assert_eq!( TlsBackend::Rustls.is_available(), cfg!( feature = "tls_rustls" ) );
+  assert_eq!( TlsBackend::NativeTls.is_available(), cfg!( feature = "tls_native" ) );
+  assert!( TlsBackend::available().contains( &TlsBackend::default() ) );
+  if cfg!( feature = "tls_rustls" )
+  {
+    assert_eq!( TlsBackend::default(), TlsBackend::Rustls, "rustls is preferred when compiled in" );
+  }
+}
+
+#[ test ]
+fn client_builds_with_each_available_backend()
+{
+  for backend in TlsBackend::available()
+  {
+    assert_eq!( backend.validate().unwrap(), backend );
+    Client::build_with_tls( environment(), backend ).unwrap_or_else( | error | panic!( "{backend:?} : {error}" ) );
+  }
+  Client::build( environment() ).expect( "default backend is available" );
+}
+
+#[ test ]
+fn unavailable_backend_is_rejected()
+{
+  for backend in [ TlsBackend::Rustls, TlsBackend::NativeTls ].into_iter().filter( | backend | !backend.is_available() )
+  {
+    let error = Client::build_with_tls( environment(), backend ).unwrap_err();
+    assert!( error.to_string().contains( "not available" ), "unexpected error : {error}" );
+  }
+}
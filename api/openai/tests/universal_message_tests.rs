@@ -0,0 +1,72 @@
+//! Universal message conversion tests
+//!
+//! Tests for `UniversalMessage` conversions to and from `ChatCompletionRequestMessage`
+//! and `ChatCompletionResponseMessage`.
+
+use api_openai::components::chat_shared::
+{
+  ChatCompletionRequestMessage,
+  ChatCompletionRequestMessageContent,
+  ChatCompletionRequestMessageContentPart,
+  ChatCompletionResponseMessage,
+  UniversalMessage,
+  UniversalRole,
+};
+
+#[ test ]
+fn two_turn_conversation_round_trips()
+{
+  let conversation = vec!
+  [
+    UniversalMessage::user( "What is the capital of France?" ),
+    UniversalMessage::assistant( "Paris." ),
+  ];
+
+  let messages : Vec< ChatCompletionRequestMessage > = conversation.iter().cloned().map( Into::into ).collect();
+  assert_eq!( messages[ 0 ].role, "user" );
+  assert_eq!( messages[ 1 ].role, "assistant" );
+  assert_eq!( messages[ 1 ].content, Some( ChatCompletionRequestMessageContent::Text( "Paris.".to_string() ) ) );
+
+  let back : Vec< UniversalMessage > = messages.into_iter().map( Into::into ).collect();
+  assert_eq!( back, conversation );
+}
+
+#[ test ]
+fn developer_role_maps_to_system()
+{
+  assert_eq!( UniversalRole::from_provider_role( "developer" ), UniversalRole::System );
+  assert_eq!( UniversalRole::from_provider_role( "model" ), UniversalRole::Assistant );
+  assert_eq!( UniversalRole::from_provider_role( "tool" ), UniversalRole::User );
+}
+
+#[ test ]
+fn content_parts_concatenate_text()
+{
+  let message = ChatCompletionRequestMessage
+  {
+    role : "user".to_string(),
+    content : Some( ChatCompletionRequestMessageContent::Parts( vec!
+    [
+      ChatCompletionRequestMessageContentPart::Text { text : "Describe ".to_string() },
+      ChatCompletionRequestMessageContentPart::Text { text : "this".to_string() },
+    ] ) ),
+    name : None,
+    tool_calls : None,
+    tool_call_id : None,
+  };
+
+  assert_eq!( UniversalMessage::from( message ), UniversalMessage::user( "Describe this" ) );
+}
+
+#[ test ]
+fn response_message_converts_to_assistant_turn()
+{
+  let reply = ChatCompletionResponseMessage
+  {
+    content : Some( "Paris.".to_string() ),
+    role : "assistant".to_string(),
+    tool_calls : None,
+  };
+
+  assert_eq!( UniversalMessage::from( reply ), UniversalMessage::assistant( "Paris." ) );
+}